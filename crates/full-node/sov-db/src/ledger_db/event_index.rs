@@ -0,0 +1,35 @@
+use sov_rollup_interface::stf::EventKey;
+
+/// Restricts which events the [`super::LedgerDb`] builds lookup indices for.
+///
+/// Each entry is either a module name (e.g. `Bank`), which matches all events
+/// emitted by that module, or a `Module/Variant` event key (e.g.
+/// `Bank/TokenCreated`), which matches a single event variant. Events that
+/// aren't allowlisted are still stored alongside their transaction, but they
+/// can't be looked up by key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventIndexAllowlist {
+    entries: Vec<String>,
+}
+
+impl EventIndexAllowlist {
+    /// Creates a new [`EventIndexAllowlist`] from a list of module names and
+    /// `Module/Variant` event keys.
+    pub fn new(entries: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            entries: entries.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns `true` if events with the given key should be indexed.
+    pub fn is_indexed(&self, key: &EventKey) -> bool {
+        let key = key.inner().as_slice();
+        self.entries.iter().any(|entry| {
+            let entry = entry.as_bytes();
+            match key.strip_prefix(entry) {
+                Some(rest) => rest.is_empty() || rest.starts_with(b"/"),
+                None => false,
+            }
+        })
+    }
+}
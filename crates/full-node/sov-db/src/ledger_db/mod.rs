@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
 
 use rockbound::cache::cache_db::CacheDb;
 use rockbound::{Schema, SchemaBatch, SeekKeyEncoder};
 use serde::Serialize;
+use sov_rollup_interface::da::{BlockHeaderTrait, Time};
 use sov_rollup_interface::rpc::AggregatedProofResponse;
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::{BatchReceipt, StoredEvent, TxReceiptContents};
@@ -10,7 +12,7 @@ use sov_rollup_interface::zk::aggregated_proof::AggregatedProof;
 
 use crate::schema::tables::{
     BatchByHash, BatchByNumber, EventByKey, EventByNumber, FinalizedSlots, ProofByUniqueId,
-    SlotByHash, SlotByNumber, TxByHash, TxByNumber, LEDGER_TABLES,
+    SlotByHash, SlotByNumber, SlotTimeByNumber, TxByHash, TxByNumber, LEDGER_TABLES,
 };
 use crate::schema::types::{
     split_tx_for_storage, BatchNumber, EventNumber, LatestFinalizedSlotSingleton, ProofUniqueId,
@@ -18,8 +20,11 @@ use crate::schema::types::{
 };
 use crate::DbOptions;
 
+pub use event_index::EventIndexAllowlist;
+
 /// Helper functions to query from events.
 pub mod event_helper;
+mod event_index;
 mod rpc;
 mod rpc_constants;
 
@@ -91,14 +96,14 @@ pub(crate) struct LedgerNotificationService {
 }
 
 impl LedgerNotificationService {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(capacity: usize) -> Self {
         LedgerNotificationService {
             slot_notifications: Default::default(),
-            slot_subscriptions: tokio::sync::broadcast::channel(10).0,
+            slot_subscriptions: tokio::sync::broadcast::channel(capacity).0,
             finalized_slot_notifications: Default::default(),
             finalized_slot_subscriptions: tokio::sync::watch::Sender::new(0),
             proof_notifications: Default::default(),
-            proof_subscriptions: tokio::sync::broadcast::channel(10).0,
+            proof_subscriptions: tokio::sync::broadcast::channel(capacity).0,
         }
     }
 
@@ -178,12 +183,18 @@ pub struct LedgerDb {
     /// requires transactions to be executed before being committed.
     db: Arc<CacheDb>,
     notification_service: LedgerNotificationService,
+    /// Events which are looked up by key. All events are indexed if not set.
+    event_index_allowlist: Option<EventIndexAllowlist>,
 }
 
 impl LedgerDb {
     const DB_PATH_SUFFIX: &'static str = "ledger";
     const DB_NAME: &'static str = "ledger-db";
 
+    /// The default capacity of the slot and aggregated proof subscription channels,
+    /// see [`LedgerDb::with_subscription_capacity`].
+    pub const DEFAULT_SUBSCRIPTION_CAPACITY: usize = 10;
+
     /// Create [`DbOptions`] for [`LedgerDb`].
     pub fn get_rockbound_options() -> DbOptions {
         DbOptions {
@@ -197,10 +208,41 @@ impl LedgerDb {
     pub fn with_cache_db(db: CacheDb) -> anyhow::Result<Self> {
         Ok(Self {
             db: Arc::new(db),
-            notification_service: LedgerNotificationService::new(),
+            notification_service: LedgerNotificationService::new(
+                Self::DEFAULT_SUBSCRIPTION_CAPACITY,
+            ),
+            event_index_allowlist: None,
         })
     }
 
+    /// Sets the number of notifications buffered by the slot and aggregated proof
+    /// subscription channels.
+    ///
+    /// # Slow subscribers
+    /// Notifications are never held back for a slow subscriber. Once a subscriber falls
+    /// more than `capacity` notifications behind, the oldest notifications are dropped
+    /// for that subscriber only, and its next receive reports how many were skipped.
+    /// Consumers should treat this as the end of the subscription and backfill the missed
+    /// items through the regular queries, which is what the REST API websockets do.
+    ///
+    /// The capacity should hence be sized for the burstiest expected notification rate;
+    /// it doesn't depend on the number of subscribers, which share the same buffer.
+    /// This must be called before subscribing, since it replaces the existing channels.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0.
+    pub fn with_subscription_capacity(mut self, capacity: usize) -> Self {
+        self.notification_service = LedgerNotificationService::new(capacity);
+        self
+    }
+
+    /// Only builds key lookup indices for the events matching `allowlist`.
+    /// All events are still stored and returned together with their transactions.
+    pub fn with_event_index_allowlist(mut self, allowlist: EventIndexAllowlist) -> Self {
+        self.event_index_allowlist = Some(allowlist);
+        self
+    }
+
     /// Replace underlying [`CacheDb`] with provided one.
     /// Keeps the underlying broadcast channel open.
     pub fn replace_db(&mut self, db: CacheDb) -> anyhow::Result<()> {
@@ -283,9 +325,11 @@ impl LedgerDb {
         &self,
         slot: &StoredSlot,
         slot_number: &SlotNumber,
+        time: &Time,
         schema_batch: &mut SchemaBatch,
     ) -> Result<(), anyhow::Error> {
         schema_batch.put::<SlotByNumber>(slot_number, slot)?;
+        schema_batch.put::<SlotTimeByNumber>(slot_number, time)?;
         schema_batch.put::<SlotByHash>(&slot.hash, slot_number)
     }
 
@@ -317,7 +361,15 @@ impl LedgerDb {
         schema_batch: &mut SchemaBatch,
     ) -> Result<(), anyhow::Error> {
         schema_batch.put::<EventByNumber>(event_number, event)?;
-        schema_batch.put::<EventByKey>(&(event.key().clone(), tx_number, *event_number), &())
+        let is_indexed = self
+            .event_index_allowlist
+            .as_ref()
+            .map_or(true, |allowlist| allowlist.is_indexed(event.key()));
+        if is_indexed {
+            schema_batch
+                .put::<EventByKey>(&(event.key().clone(), tx_number, *event_number), &())?;
+        }
+        Ok(())
     }
 
     /// Materializes [`SlotCommit`] into [`SchemaBatch`] by inserting its events,
@@ -385,6 +437,7 @@ impl LedgerDb {
         self.put_slot(
             &slot_to_store,
             &SlotNumber(current_item_numbers.slot_number),
+            &data_to_commit.slot_data.header().time(),
             &mut schema_batch,
         )?;
 
@@ -412,6 +465,80 @@ impl LedgerDb {
         Ok(schema_batch)
     }
 
+    /// Materializes the removal of all the slots starting from `first_slot`, together with
+    /// their batches, transactions and events. Used when these slots were rolled back by a
+    /// reorg, so that the ledger doesn't keep entries from an orphaned fork.
+    ///
+    /// The batches, transactions and events to remove are found through the ranges stored
+    /// in the rolled back slots, batches and transactions respectively.
+    pub async fn materialize_rollback(
+        &self,
+        first_slot: SlotNumber,
+    ) -> anyhow::Result<SchemaBatch> {
+        let mut schema_batch = SchemaBatch::new();
+        let next_items = self.get_next_items_numbers()?;
+
+        let slots = self
+            .db
+            .collect_in_range_async::<SlotByNumber, SlotNumber>(
+                first_slot..SlotNumber(next_items.slot_number),
+            )
+            .await?;
+        let batches = match (slots.first(), slots.last()) {
+            (Some((_, first)), Some((_, last))) => {
+                self.db
+                    .collect_in_range_async::<BatchByNumber, BatchNumber>(
+                        first.batches.start..last.batches.end,
+                    )
+                    .await?
+            }
+            _ => Vec::new(),
+        };
+        let txs = match (batches.first(), batches.last()) {
+            (Some((_, first)), Some((_, last))) => {
+                self.db
+                    .collect_in_range_async::<TxByNumber, TxNumber>(first.txs.start..last.txs.end)
+                    .await?
+            }
+            _ => Vec::new(),
+        };
+        let events = match (txs.first(), txs.last()) {
+            (Some((_, first)), Some((_, last))) => {
+                self.db
+                    .collect_in_range_async::<EventByNumber, EventNumber>(
+                        first.events.start..last.events.end,
+                    )
+                    .await?
+            }
+            _ => Vec::new(),
+        };
+
+        for (slot_number, slot) in &slots {
+            schema_batch.delete::<SlotByNumber>(slot_number)?;
+            schema_batch.delete::<SlotTimeByNumber>(slot_number)?;
+            schema_batch.delete::<SlotByHash>(&slot.hash)?;
+        }
+        for (batch_number, batch) in &batches {
+            schema_batch.delete::<BatchByNumber>(batch_number)?;
+            schema_batch.delete::<BatchByHash>(&batch.hash)?;
+        }
+        let events = events.into_iter().collect::<BTreeMap<_, _>>();
+        for (tx_number, tx) in &txs {
+            schema_batch.delete::<TxByNumber>(tx_number)?;
+            schema_batch.delete::<TxByHash>(&(tx.hash, *tx_number))?;
+            for (event_number, event) in events.range(tx.events.clone()) {
+                schema_batch.delete::<EventByNumber>(event_number)?;
+                schema_batch.delete::<EventByKey>(&(
+                    event.key().clone(),
+                    *tx_number,
+                    *event_number,
+                ))?;
+            }
+        }
+
+        Ok(schema_batch)
+    }
+
     fn last_version_written<T: Schema<Key = U>, U: Into<u64>>(
         db: &CacheDb,
         _schema: T,
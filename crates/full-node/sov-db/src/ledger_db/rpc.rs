@@ -1,10 +1,11 @@
 use anyhow::{bail, Context, Error};
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
+use sov_rollup_interface::da::Time;
 use sov_rollup_interface::rpc::{
     AggregatedProofResponse, BatchIdAndOffset, BatchIdentifier, BatchResponse, EventIdentifier,
     FinalityStatus, ItemOrHash, LedgerStateProvider, QueryMode, SlotIdAndOffset, SlotIdentifier,
-    SlotResponse, TxIdAndOffset, TxIdentifier, TxResponse,
+    SlotResponse, TimeRounding, TxIdAndOffset, TxIdentifier, TxResponse,
 };
 use sov_rollup_interface::stf::{StoredEvent, TxReceiptContents};
 use tokio::sync::broadcast::Receiver;
@@ -16,7 +17,7 @@ use crate::ledger_db::rpc_constants::{
 use crate::ledger_db::LedgerDb;
 use crate::schema::tables::{
     BatchByHash, BatchByNumber, EventByNumber, FinalizedSlots, ProofByUniqueId, SlotByHash,
-    SlotByNumber, TxByHash, TxByNumber,
+    SlotByNumber, SlotTimeByNumber, TxByHash, TxByNumber,
 };
 use crate::schema::types::{
     BatchNumber, EventNumber, LatestFinalizedSlotSingleton, SlotNumber, StoredBatch, StoredSlot,
@@ -42,6 +43,57 @@ impl LedgerStateProvider for LedgerDb {
         Ok(finalized_slot.map(|slot| slot.0).unwrap_or_default())
     }
 
+    async fn is_slot_finalized(&self, slot_number: u64) -> Result<Option<bool>, Self::Error> {
+        if self
+            .db
+            .read_async::<SlotByNumber>(&SlotNumber(slot_number))
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            self.get_latest_finalized_slot_number().await? >= slot_number,
+        ))
+    }
+
+    async fn find_slot_by_time(
+        &self,
+        time: Time,
+        rounding: TimeRounding,
+    ) -> Result<Option<u64>, Self::Error> {
+        let Some(head) = self.get_head_slot_number().await? else {
+            return Ok(None);
+        };
+
+        // Slot timestamps are non-decreasing, so we binary search for the
+        // first slot which lies strictly past `time` in the rounding direction.
+        let (mut low, mut high) = (0, head + 1);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let slot_time = self
+                .db
+                .read_async::<SlotTimeByNumber>(&SlotNumber(mid))
+                .await?
+                .with_context(|| format!("Slot {} isn't indexed by time", mid))?;
+            let is_before = match rounding {
+                TimeRounding::Before => slot_time <= time,
+                TimeRounding::After => slot_time < time,
+            };
+            if is_before {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(match rounding {
+            TimeRounding::Before => low.checked_sub(1),
+            TimeRounding::After => (low <= head).then_some(low),
+        })
+    }
+
     async fn get_slots<B, T>(
         &self,
         slot_ids: &[SlotIdentifier],
@@ -405,6 +457,54 @@ impl LedgerStateProvider for LedgerDb {
         Ok(events_response)
     }
 
+    async fn get_filtered_tx_events<E>(
+        &self,
+        txn_num: u64,
+        event_key_prefix_filter: Option<Vec<u8>>,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<E>, Error>
+    where
+        E: TryFrom<(u64, StoredEvent), Error = anyhow::Error> + Send + Sync,
+    {
+        let stored_txn = self
+            .db
+            .read_async::<TxByNumber>(&TxNumber(txn_num))
+            .await
+            .with_context(|| format!("Failed to query txn num: {} from storage", txn_num))?
+            .with_context(|| format!("Txn num: {} does not exist in storage", txn_num))?;
+
+        let EventNumber(start) = stored_txn.events.start;
+        let EventNumber(end) = stored_txn.events.end;
+        let start = match after {
+            Some(after) => start.max(after.saturating_add(1)),
+            None => start,
+        };
+
+        let mut events = vec![];
+        for event_num in start..end {
+            if events.len() >= limit {
+                break;
+            }
+
+            let event = self
+                .db
+                .read_async::<EventByNumber>(&EventNumber(event_num))
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Event not found but should be present"))?;
+
+            if let Some(prefix) = &event_key_prefix_filter {
+                if !event.key().inner().starts_with(prefix) {
+                    continue;
+                }
+            }
+
+            events.push((event_num, event).try_into()?);
+        }
+
+        Ok(events)
+    }
+
     async fn get_slots_range<B, T>(
         &self,
         start: u64,
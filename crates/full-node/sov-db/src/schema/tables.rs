@@ -4,6 +4,7 @@
 //! Slot Tables:
 //! - `SlotNumber -> StoredSlot`
 //! - `SlotNumber -> Vec<BatchNumber>`
+//! - `SlotNumber -> Time`
 //!
 //! Batch Tables:
 //! - `BatchNumber -> StoredBatch`
@@ -22,6 +23,9 @@
 //! - `(Key, Version) -> JmtValue`
 //! - `NodeKey -> Node`
 //!
+//! Fork Tables:
+//! - `ForkSnapshotId -> StoredForkSnapshot`
+//!
 //! Module Accessory State Table:
 //! - `(ModuleIdBytes, Key) -> Value`
 
@@ -31,13 +35,14 @@ use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use jmt::Version;
 use rockbound::schema::{ColumnFamilyName, KeyDecoder, KeyEncoder, ValueCodec};
 use rockbound::{CodecError, SeekKeyEncoder};
+use sov_rollup_interface::da::Time;
 use sov_rollup_interface::stf::{EventKey, StoredEvent};
 use sov_rollup_interface::zk::aggregated_proof::AggregatedProof;
 
 use super::types::{
-    AccessoryKey, AccessoryStateValue, BatchNumber, DbHash, EventNumber,
-    LatestFinalizedSlotSingleton, ProofUniqueId, SlotNumber, StoredBatch, StoredSlot,
-    StoredTransaction, TxNumber,
+    AccessoryKey, AccessoryStateValue, BatchNumber, DbHash, EventNumber, ForkSnapshotId,
+    LatestFinalizedSlotSingleton, ProofUniqueId, SlotNumber, StoredBatch, StoredForkSnapshot,
+    StoredSlot, StoredTransaction, TxNumber,
 };
 
 /* Other tables used by the Rollup */
@@ -47,6 +52,7 @@ use super::types::{
 pub const LEDGER_TABLES: &[ColumnFamilyName] = &[
     SlotByNumber::table_name(),
     SlotByHash::table_name(),
+    SlotTimeByNumber::table_name(),
     BatchByHash::table_name(),
     BatchByNumber::table_name(),
     TxByHash::table_name(),
@@ -55,6 +61,7 @@ pub const LEDGER_TABLES: &[ColumnFamilyName] = &[
     EventByNumber::table_name(),
     ProofByUniqueId::table_name(),
     FinalizedSlots::table_name(),
+    ForkSnapshotById::table_name(),
 ];
 
 /// A list of all tables used by the AccessoryDB. These tables store
@@ -211,6 +218,12 @@ define_table_with_seek_key_codec!(
     (SlotByNumber) SlotNumber => StoredSlot
 );
 
+define_table_with_seek_key_codec!(
+    /// The DA timestamp of each slot. Timestamps are non-decreasing with the slot number,
+    /// which allows looking up slots by time with a binary search
+    (SlotTimeByNumber) SlotNumber => Time
+);
+
 define_table_with_seek_key_codec!(
     /// A table containing a single entry with the slot number of the latest finalized slot
     (FinalizedSlots) LatestFinalizedSlotSingleton => SlotNumber
@@ -258,6 +271,12 @@ define_table_with_seek_key_codec!(
     (ProofByUniqueId) ProofUniqueId => AggregatedProof
 );
 
+define_table_with_seek_key_codec!(
+    /// The unfinalized blocks tracked by the storage manager, by the ID of their snapshot.
+    /// Used to restore the fork tree after a restart.
+    (ForkSnapshotById) ForkSnapshotId => StoredForkSnapshot
+);
+
 define_table_without_codec!(
     /// Non-JMT state stored by a module for JSON-RPC use.
     (ModuleAccessoryState) (AccessoryKey, Version) => AccessoryStateValue
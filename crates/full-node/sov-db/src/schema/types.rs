@@ -131,6 +131,23 @@ impl<R: TxReceiptContents> TryFrom<StoredTransaction> for TxResponse<R> {
     }
 }
 
+/// The on-disk format of an unfinalized block tracked by the storage manager, keyed by the ID
+/// of its snapshot. The block hashes are stored in the serialized form of the DA layer hash type,
+/// and the change set in the serialized form of the writes it was materialized from.
+#[derive(Debug, PartialEq, BorshDeserialize, BorshSerialize, Clone)]
+#[cfg_attr(
+    feature = "arbitrary",
+    derive(proptest_derive::Arbitrary, arbitrary::Arbitrary)
+)]
+pub struct StoredForkSnapshot {
+    /// The hash of the block.
+    pub block_hash: Vec<u8>,
+    /// The hash of the parent of the block.
+    pub parent_hash: Vec<u8>,
+    /// The change set of the block, if it has been saved.
+    pub change_set: Option<Vec<u8>>,
+}
+
 /// Split a `TransactionReceipt` into a `StoredTransaction` and a list of `Event`s for storage in the database.
 pub fn split_tx_for_storage<T: TxReceiptContents>(
     tx: TransactionReceipt<T>,
@@ -229,3 +246,4 @@ u64_wrapper!(BatchNumber);
 u64_wrapper!(TxNumber);
 u64_wrapper!(EventNumber);
 u64_wrapper!(ProofUniqueId);
+u64_wrapper!(ForkSnapshotId);
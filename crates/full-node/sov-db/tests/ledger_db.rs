@@ -1,7 +1,11 @@
-use sov_db::ledger_db::{LedgerDb, SlotCommit};
-use sov_mock_da::{MockBlob, MockBlock};
+use sov_db::ledger_db::event_helper::get_events_by_key_helper;
+use sov_db::ledger_db::{EventIndexAllowlist, LedgerDb, SlotCommit};
+use sov_db::schema::types::SlotNumber;
+use sov_mock_da::{MockBlob, MockBlock, MockBlockHeader, MockHash};
 use sov_mock_zkvm::MockZkvm;
-use sov_rollup_interface::rpc::LedgerStateProvider;
+use sov_rollup_interface::da::Time;
+use sov_rollup_interface::rpc::{LedgerStateProvider, TimeRounding};
+use sov_rollup_interface::stf::{BatchReceipt, StoredEvent, TransactionReceipt, TxEffect};
 use sov_rollup_interface::zk::aggregated_proof::{
     AggregatedProof, AggregatedProofPublicData, CodeCommitment, SerializedAggregatedProof,
 };
@@ -18,7 +22,7 @@ async fn get_filtered_slot_events() {
     let client = ledger_service.axum_client;
 
     let events = &client
-        .get_slot_filtered_events(&IntOrHash::Variant0(0), None)
+        .get_slot_filtered_events(&IntOrHash::Variant0(0), None, None)
         .await
         .unwrap()
         .data;
@@ -27,7 +31,7 @@ async fn get_filtered_slot_events() {
     assert_eq!(events[0].key, "foo");
 
     let events = &client
-        .get_slot_filtered_events(&IntOrHash::Variant0(0), Some("bar"))
+        .get_slot_filtered_events(&IntOrHash::Variant0(0), Some("bar"), None)
         .await
         .unwrap()
         .data;
@@ -36,7 +40,7 @@ async fn get_filtered_slot_events() {
     assert_eq!(events[0].key, "bar");
 
     let events = &client
-        .get_slot_filtered_events(&IntOrHash::Variant0(0), Some("")) // empty prefix
+        .get_slot_filtered_events(&IntOrHash::Variant0(0), Some(""), None) // empty prefix
         .await
         .unwrap()
         .data;
@@ -110,3 +114,250 @@ async fn test_save_aggregated_proof() {
         assert_eq!(&public_data, proof_from_db.proof.public_data());
     }
 }
+
+/// Distinct hashes for the slot, batch (`kind = 1`) and transaction (`kind = 2`)
+/// at `height` on a given `fork`.
+fn test_hash(height: u64, fork: u8, kind: u8) -> [u8; 32] {
+    let mut hash = [fork; 32];
+    hash[0] = height as u8;
+    hash[1] = kind;
+    hash
+}
+
+fn slot_with_single_tx(height: u64, fork: u8) -> SlotCommit<MockBlock, u32, ()> {
+    let mut slot = SlotCommit::new(MockBlock {
+        header: MockBlockHeader {
+            hash: MockHash(test_hash(height, fork, 0)),
+            ..MockBlockHeader::from_height(height)
+        },
+        ..Default::default()
+    });
+    slot.add_batch(BatchReceipt {
+        batch_hash: test_hash(height, fork, 1),
+        tx_receipts: vec![TransactionReceipt {
+            tx_hash: test_hash(height, fork, 2),
+            body_to_save: None,
+            events: vec![StoredEvent::new(b"key", &[height as u8, fork])],
+            receipt: TxEffect::Successful(()),
+            gas_used: vec![0, 0],
+            gas_limit: vec![0, 0],
+        }],
+        inner: 0,
+        gas_price: vec![0, 0],
+    });
+    slot
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_is_slot_finalized() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleLedgerStorageManager::new(temp_dir.path());
+    let ledger_storage = storage_manager.create_ledger_storage();
+    let ledger_db = LedgerDb::with_cache_db(ledger_storage).unwrap();
+
+    for height in 0..3 {
+        let ledger_data = ledger_db
+            .materialize_slot(slot_with_single_tx(height, 0), b"state-root")
+            .unwrap();
+        storage_manager.commit(ledger_data);
+    }
+    storage_manager.commit(ledger_db.materialize_latest_finalize_slot(1).unwrap());
+
+    assert_eq!(ledger_db.is_slot_finalized(0).await.unwrap(), Some(true));
+    assert_eq!(ledger_db.is_slot_finalized(1).await.unwrap(), Some(true));
+    assert_eq!(ledger_db.is_slot_finalized(2).await.unwrap(), Some(false));
+    assert_eq!(ledger_db.is_slot_finalized(3).await.unwrap(), None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rollback_removes_orphaned_entries() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleLedgerStorageManager::new(temp_dir.path());
+    let ledger_storage = storage_manager.create_ledger_storage();
+    let ledger_db = LedgerDb::with_cache_db(ledger_storage).unwrap();
+
+    // Slot 0 is canonical, slots 1 and 2 belong to a fork which is later orphaned.
+    for height in 0..3 {
+        let fork = if height == 0 { 0 } else { 1 };
+        let ledger_data = ledger_db
+            .materialize_slot(slot_with_single_tx(height, fork), b"state-root")
+            .unwrap();
+        storage_manager.commit(ledger_data);
+    }
+    assert_eq!(ledger_db.get_next_items_numbers().unwrap().slot_number, 3);
+
+    // The reorg rolls back everything starting from slot 1.
+    let rollback = ledger_db.materialize_rollback(SlotNumber(1)).await.unwrap();
+    storage_manager.commit(rollback);
+
+    let next_items = ledger_db.get_next_items_numbers().unwrap();
+    assert_eq!(next_items.slot_number, 1);
+    assert_eq!(next_items.batch_number, 1);
+    assert_eq!(next_items.tx_number, 1);
+    assert_eq!(next_items.event_number, 1);
+    assert_eq!(
+        ledger_db
+            .get_tx_numbers_by_hash(&test_hash(0, 0, 2))
+            .await
+            .unwrap(),
+        vec![0]
+    );
+    for height in 1..3 {
+        assert!(ledger_db
+            .get_tx_numbers_by_hash(&test_hash(height, 1, 2))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    // The canonical fork continues on top of the remaining entries.
+    let ledger_data = ledger_db
+        .materialize_slot(slot_with_single_tx(1, 2), b"state-root")
+        .unwrap();
+    storage_manager.commit(ledger_data);
+
+    let (head_number, _) = ledger_db.get_head_slot().unwrap().unwrap();
+    assert_eq!(head_number, SlotNumber(1));
+    assert_eq!(
+        ledger_db
+            .get_tx_numbers_by_hash(&test_hash(1, 2, 2))
+            .await
+            .unwrap(),
+        vec![1]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_find_slot_by_time() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleLedgerStorageManager::new(temp_dir.path());
+    let ledger_storage = storage_manager.create_ledger_storage();
+    let ledger_db = LedgerDb::with_cache_db(ledger_storage).unwrap();
+
+    assert_eq!(
+        ledger_db
+            .find_slot_by_time(Time::from_secs(10), TimeRounding::After)
+            .await
+            .unwrap(),
+        None
+    );
+
+    // Slots 1 and 2 share the same timestamp.
+    for (height, secs) in [10, 20, 20, 30].into_iter().enumerate() {
+        let slot = SlotCommit::<_, u32, ()>::new(MockBlock {
+            header: MockBlockHeader {
+                time: Time::from_secs(secs),
+                ..MockBlockHeader::from_height(height as u64)
+            },
+            ..Default::default()
+        });
+        let ledger_data = ledger_db.materialize_slot(slot, b"state-root").unwrap();
+        storage_manager.commit(ledger_data);
+    }
+
+    let cases = [
+        (5, TimeRounding::Before, None),
+        (5, TimeRounding::After, Some(0)),
+        (10, TimeRounding::Before, Some(0)),
+        (10, TimeRounding::After, Some(0)),
+        (20, TimeRounding::Before, Some(2)),
+        (20, TimeRounding::After, Some(1)),
+        (25, TimeRounding::Before, Some(2)),
+        (25, TimeRounding::After, Some(3)),
+        (30, TimeRounding::After, Some(3)),
+        (35, TimeRounding::Before, Some(3)),
+        (35, TimeRounding::After, None),
+    ];
+    for (secs, rounding, expected) in cases {
+        assert_eq!(
+            ledger_db
+                .find_slot_by_time(Time::from_secs(secs), rounding)
+                .await
+                .unwrap(),
+            expected,
+            "time={secs} rounding={rounding:?}"
+        );
+    }
+}
+
+/// Only keeps the key of the queried events.
+struct EventKeyResponse(String);
+
+impl TryFrom<(u64, StoredEvent)> for EventKeyResponse {
+    type Error = anyhow::Error;
+
+    fn try_from((_, event): (u64, StoredEvent)) -> Result<Self, Self::Error> {
+        Ok(Self(String::from_utf8(event.key().inner().clone())?))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_event_index_allowlist() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleLedgerStorageManager::new(temp_dir.path());
+    let ledger_storage = storage_manager.create_ledger_storage();
+    let ledger_db = LedgerDb::with_cache_db(ledger_storage)
+        .unwrap()
+        .with_event_index_allowlist(EventIndexAllowlist::new(["Bank/TokenCreated", "Value"]));
+
+    let event_keys = [
+        "Bank/TokenCreated",
+        "Bank/TokenFrozen",
+        "Value/NewValue",
+        "ValueSetter/NewValue",
+    ];
+    let mut slot = SlotCommit::<_, u32, ()>::new(MockBlock::default());
+    slot.add_batch(BatchReceipt {
+        batch_hash: test_hash(0, 0, 1),
+        tx_receipts: vec![TransactionReceipt {
+            tx_hash: test_hash(0, 0, 2),
+            body_to_save: None,
+            events: event_keys
+                .iter()
+                .map(|key| StoredEvent::new(key.as_bytes(), &[]))
+                .collect(),
+            receipt: TxEffect::Successful(()),
+            gas_used: vec![0, 0],
+            gas_limit: vec![0, 0],
+        }],
+        inner: 0,
+        gas_price: vec![0, 0],
+    });
+    storage_manager.commit(ledger_db.materialize_slot(slot, b"state-root").unwrap());
+
+    let events_by_key = |key: &'static str| {
+        let ledger_db = ledger_db.clone();
+        async move {
+            get_events_by_key_helper::<EventKeyResponse>(&ledger_db, key, None, 10, None)
+                .await
+                .unwrap()
+                .events_response
+                .into_iter()
+                .map(|event| event.0)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // Only the allowlisted events can be looked up by key...
+    assert_eq!(
+        events_by_key("Bank/TokenCreated").await,
+        vec!["Bank/TokenCreated"]
+    );
+    assert_eq!(
+        events_by_key("Value/NewValue").await,
+        vec!["Value/NewValue"]
+    );
+    assert!(events_by_key("Bank/TokenFrozen").await.is_empty());
+    // ...module names must match exactly, not as a prefix.
+    assert!(events_by_key("ValueSetter/NewValue").await.is_empty());
+
+    // ...but all of them are still stored with their transaction.
+    let tx_events = ledger_db
+        .get_events_by_txn_number::<EventKeyResponse>(0)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|event| event.0)
+        .collect::<Vec<_>>();
+    assert_eq!(tx_events, event_keys);
+}
@@ -32,15 +32,43 @@ impl EthBatchBuilder {
         }
     }
 
-    /// Attempts to create a blob with a minimum size of `min_blob_size`.
+    /// Attempts to create a blob with a minimum size of `min_blob_size`, falling back to the
+    /// configured minimum, or to a single transaction if neither is set. Returns an empty blob
+    /// if the mempool holds fewer transactions than that.
     pub fn get_next_blob(&mut self, min_blob_size: Option<usize>) -> Vec<Vec<u8>> {
-        let min_blob_size = min_blob_size.or(self.min_blob_size);
+        let min_blob_size = min_blob_size.or(self.min_blob_size).unwrap_or(1);
 
-        if let Some(min_blob_size) = min_blob_size {
-            if self.mempool.len() >= min_blob_size {
-                return self.make_blob();
-            }
+        if self.mempool.len() >= min_blob_size {
+            return self.make_blob();
         }
         Vec::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blobs_honor_the_minimum_size() {
+        let mut builder = EthBatchBuilder::new(Some(2));
+        builder.add_messages(vec![vec![1]]);
+        assert!(builder.get_next_blob(None).is_empty());
+
+        // A per-call minimum overrides the configured one.
+        assert_eq!(builder.get_next_blob(Some(1)), vec![vec![1]]);
+
+        builder.add_messages(vec![vec![2], vec![3]]);
+        assert!(builder.get_next_blob(Some(3)).is_empty());
+        assert_eq!(builder.get_next_blob(None), vec![vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn blobs_have_a_single_transaction_by_default() {
+        let mut builder = EthBatchBuilder::new(None);
+        assert!(builder.get_next_blob(None).is_empty());
+
+        builder.add_messages(vec![vec![1]]);
+        assert_eq!(builder.get_next_blob(None), vec![vec![1]]);
+    }
+}
@@ -3,9 +3,10 @@
 
 // Adopted from: https://github.com/paradigmxyz/reth/blob/main/crates/rpc/rpc/src/eth/gas_oracle.rs
 
+use jsonrpsee::core::RpcResult;
 use reth_primitives::constants::GWEI_TO_WEI;
 use reth_primitives::{B256, U256, U64};
-use reth_rpc_types::BlockTransactions;
+use reth_rpc_types::{BlockTransactions, FeeHistory};
 use serde::{Deserialize, Serialize};
 use sov_evm::{EthApiError, EthResult, Evm, RpcInvalidTransactionError};
 use sov_modules_api::ApiStateAccessor;
@@ -49,6 +50,16 @@ pub struct GasPriceOracleConfig {
 
     /// The minimum gas price, under which the sample will be ignored
     pub ignore_price: Option<U256>,
+
+    /// The lower bound of the tip suggested by `eth_gasPrice`, e.g. to keep it from dropping to
+    /// zero when blocks are empty
+    #[serde(default)]
+    pub min_suggested_tip: Option<U256>,
+
+    /// The upper bound of the tip suggested by `eth_gasPrice`, e.g. to keep it from overshooting
+    /// during spikes
+    #[serde(default)]
+    pub max_suggested_tip: Option<U256>,
 }
 
 impl Default for GasPriceOracleConfig {
@@ -61,6 +72,8 @@ impl Default for GasPriceOracleConfig {
             default: None,
             max_price: Some(DEFAULT_MAX_PRICE),
             ignore_price: Some(DEFAULT_IGNORE_PRICE),
+            min_suggested_tip: None,
+            max_suggested_tip: None,
         }
     }
 }
@@ -81,8 +94,16 @@ impl GasPriceOracleConfig {
             default: None,
             max_price: max_price.map(U256::from).or(Some(DEFAULT_MAX_PRICE)),
             ignore_price: ignore_price.map(U256::from).or(Some(DEFAULT_IGNORE_PRICE)),
+            min_suggested_tip: None,
+            max_suggested_tip: None,
         }
     }
+
+    /// Bounds a suggested tip to [`Self::min_suggested_tip`] and [`Self::max_suggested_tip`].
+    pub fn clamp_suggested_tip(&self, tip: U256) -> U256 {
+        let tip = self.min_suggested_tip.map_or(tip, |min| tip.max(min));
+        self.max_suggested_tip.map_or(tip, |max| tip.min(max))
+    }
 }
 
 /// Calculates a gas price depending on recent blocks.
@@ -106,6 +127,16 @@ impl<S: sov_modules_api::Spec> GasPriceOracle<S> {
             oracle_config.percentile = 100;
         }
 
+        if let (Some(min), Some(max)) = (
+            oracle_config.min_suggested_tip,
+            oracle_config.max_suggested_tip,
+        ) {
+            if min > max {
+                warn!(%min, %max, "Configured minimum suggested tip is above the maximum, assuming the maximum");
+                oracle_config.min_suggested_tip = Some(max);
+            }
+        }
+
         let max_header_history = oracle_config.max_header_history;
 
         Self {
@@ -116,6 +147,11 @@ impl<S: sov_modules_api::Spec> GasPriceOracle<S> {
         }
     }
 
+    /// The configuration of the oracle.
+    pub fn config(&self) -> &GasPriceOracleConfig {
+        &self.oracle_config
+    }
+
     /// Suggests a gas price estimate based on recent blocks, using the configured percentile.
     pub async fn suggest_tip_cap(&self, state: &mut ApiStateAccessor<S>) -> EthResult<U256> {
         let header = &self
@@ -195,6 +231,106 @@ impl<S: sov_modules_api::Spec> GasPriceOracle<S> {
         Ok(price)
     }
 
+    /// Returns the fee history of the `block_count` blocks up to and including `newest_block`, as
+    /// expected by `eth_feeHistory`. Requests for more blocks than are available, or than the
+    /// configured `max_block_history`, are clamped.
+    ///
+    /// The rewards are only computed if `reward_percentiles` are given, since they require
+    /// fetching the receipts of all the transactions in the range.
+    pub async fn fee_history(
+        &self,
+        block_count: u64,
+        newest_block: String,
+        reward_percentiles: Option<Vec<f64>>,
+        state: &mut ApiStateAccessor<S>,
+    ) -> RpcResult<FeeHistory> {
+        if let Some(percentiles) = &reward_percentiles {
+            validate_reward_percentiles(percentiles)?;
+        }
+
+        let head = convert_u256_to_u64(self.provider.block_number(state)?);
+        let newest = match newest_block.as_str() {
+            "latest" | "pending" => head,
+            "earliest" => 0,
+            number => u64::from_str_radix(number.trim_start_matches("0x"), 16)
+                .ok()
+                .filter(|number| *number <= head)
+                .ok_or(EthApiError::UnknownBlockNumber)?,
+        };
+        let block_count = block_count
+            .min(newest + 1)
+            .min(self.oracle_config.max_block_history);
+        let oldest = newest + 1 - block_count;
+
+        let mut base_fee_per_gas = Vec::with_capacity(block_count as usize + 1);
+        let mut gas_used_ratio = Vec::with_capacity(block_count as usize);
+        let mut reward = Vec::with_capacity(block_count as usize);
+
+        for number in (oldest..=newest).rev() {
+            let block = self
+                .provider
+                .get_block_by_number(
+                    Some(format!("0x{:x}", number)),
+                    Some(reward_percentiles.is_some()),
+                    state,
+                )?
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+
+            let gas_used = convert_u256_to_u64(block.header.gas_used);
+            let gas_limit = convert_u256_to_u64(block.header.gas_limit);
+            base_fee_per_gas.push(block.header.base_fee_per_gas.unwrap_or_default());
+            gas_used_ratio.push(if gas_limit == 0 {
+                0.0
+            } else {
+                gas_used as f64 / gas_limit as f64
+            });
+
+            if let Some(percentiles) = &reward_percentiles {
+                let txs = match &block.transactions {
+                    BlockTransactions::Full(txs) => txs.as_slice(),
+                    _ => &[],
+                };
+
+                let mut tips = Vec::with_capacity(txs.len());
+                for tx in txs {
+                    let receipt = self
+                        .provider
+                        .get_transaction_receipt(tx.hash, state)?
+                        .ok_or(EthApiError::TransactionNotFound)?;
+                    tips.push((
+                        effective_gas_tip(tx, block.header.base_fee_per_gas).unwrap_or_default(),
+                        convert_u256_to_u64(receipt.gas_used.unwrap_or_default()),
+                    ));
+                }
+
+                reward.push(calculate_reward_percentiles(gas_used, tips, percentiles));
+            }
+        }
+
+        // The blocks were walked from the newest, but the history is ordered from the oldest.
+        base_fee_per_gas.reverse();
+        gas_used_ratio.reverse();
+        reward.reverse();
+
+        // The history also includes the base fee of the block following the newest one.
+        if block_count > 0 {
+            let next_base_fee = self
+                .provider
+                .next_block_base_fee(Some(format!("0x{:x}", newest)), state);
+            base_fee_per_gas.push(U256::from(next_base_fee.unwrap_or_default()));
+        }
+
+        Ok(FeeHistory {
+            base_fee_per_gas,
+            gas_used_ratio,
+            oldest_block: U256::from(oldest),
+            reward: reward_percentiles.map(|_| reward),
+            // EIP-4844 related
+            base_fee_per_blob_gas: Default::default(),
+            blob_gas_used_ratio: Default::default(),
+        })
+    }
+
     /// Get the `limit` lowest effective tip values for the given block. If the oracle has a
     /// configured `ignore_price` threshold, then tip values under that threshold will be ignored
     /// before returning a result.
@@ -303,6 +439,52 @@ fn effective_gas_tip(
     }
 }
 
+/// Checks that the reward percentiles of `eth_feeHistory` are within `[0, 100]` and sorted.
+fn validate_reward_percentiles(percentiles: &[f64]) -> EthResult<()> {
+    let in_range = percentiles
+        .iter()
+        .all(|percentile| (0.0..=100.0).contains(percentile));
+    let sorted = percentiles.windows(2).all(|pair| pair[0] <= pair[1]);
+
+    if in_range && sorted {
+        Ok(())
+    } else {
+        Err(EthApiError::InvalidRewardPercentiles)
+    }
+}
+
+/// Computes the rewards of a block at the given percentiles, from the effective tip and the gas
+/// used by each of its transactions.
+///
+/// Each percentile is the tip of the cheapest transaction at which that share of the block's gas is
+/// used, when transactions are sorted by tip. Empty blocks have zero rewards.
+// Adopted from: https://github.com/paradigmxyz/reth/blob/main/crates/rpc/rpc/src/eth/api/fee_history.rs
+fn calculate_reward_percentiles(
+    block_gas_used: u64,
+    mut tips: Vec<(U256, u64)>,
+    percentiles: &[f64],
+) -> Vec<U256> {
+    if tips.is_empty() {
+        return vec![U256::ZERO; percentiles.len()];
+    }
+
+    tips.sort_unstable_by_key(|(tip, _)| *tip);
+
+    let mut tx_index = 0;
+    let mut cumulative_gas_used = tips[0].1;
+    percentiles
+        .iter()
+        .map(|percentile| {
+            let threshold = (block_gas_used as f64 * percentile / 100.0) as u64;
+            while cumulative_gas_used < threshold && tx_index < tips.len() - 1 {
+                tx_index += 1;
+                cumulative_gas_used += tips[tx_index].1;
+            }
+            tips[tx_index].0
+        })
+        .collect()
+}
+
 /// Takes only 8 least significant bytes
 fn convert_u256_to_u64(u256: U256) -> u64 {
     let bytes: [u8; 32] = u256.to_be_bytes();
@@ -331,6 +513,53 @@ mod tests {
         assert_eq!(DEFAULT_IGNORE_PRICE, U256::from(2u64));
     }
 
+    #[test]
+    fn suggested_tip_is_clamped() {
+        let config = GasPriceOracleConfig {
+            min_suggested_tip: Some(U256::from(10)),
+            max_suggested_tip: Some(U256::from(100)),
+            ..Default::default()
+        };
+
+        assert_eq!(config.clamp_suggested_tip(U256::ZERO), U256::from(10));
+        assert_eq!(config.clamp_suggested_tip(U256::from(50)), U256::from(50));
+        assert_eq!(
+            config.clamp_suggested_tip(U256::from(1000)),
+            U256::from(100)
+        );
+
+        let unbounded = GasPriceOracleConfig::default();
+        assert_eq!(unbounded.clamp_suggested_tip(U256::ZERO), U256::ZERO);
+        assert_eq!(unbounded.clamp_suggested_tip(U256::MAX), U256::MAX);
+    }
+
+    #[test]
+    fn reward_percentiles_are_validated() {
+        assert!(validate_reward_percentiles(&[]).is_ok());
+        assert!(validate_reward_percentiles(&[0.0, 25.0, 25.0, 100.0]).is_ok());
+        assert!(validate_reward_percentiles(&[50.0, 25.0]).is_err());
+        assert!(validate_reward_percentiles(&[-1.0]).is_err());
+        assert!(validate_reward_percentiles(&[100.5]).is_err());
+    }
+
+    #[test]
+    fn rewards_are_weighted_by_gas_used() {
+        let tips = vec![
+            (U256::from(30), 10_000),
+            (U256::from(10), 60_000),
+            (U256::from(20), 30_000),
+        ];
+
+        assert_eq!(
+            calculate_reward_percentiles(100_000, tips, &[0.0, 50.0, 60.0, 61.0, 90.0, 100.0]),
+            [10, 10, 10, 20, 20, 30].map(U256::from)
+        );
+        assert_eq!(
+            calculate_reward_percentiles(0, vec![], &[10.0, 90.0]),
+            [U256::ZERO; 2]
+        );
+    }
+
     proptest! {
 
         #[test]
@@ -7,12 +7,19 @@ mod gas_price;
 #[cfg(feature = "local")]
 mod signer;
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
 
+use jsonrpsee::core::SubscriptionResult;
 use jsonrpsee::types::ErrorObjectOwned;
-use jsonrpsee::RpcModule;
-use reth_primitives::{Bytes, TransactionSignedNoHash as RethTransactionSignedNoHash, B256, U256};
+use jsonrpsee::{PendingSubscriptionSink, RpcModule, SubscriptionMessage};
+use reth_primitives::{
+    Address, Bytes, TransactionSignedEcRecovered as RethTransactionSignedEcRecovered, B256, U256,
+    U64,
+};
+use reth_rpc_types::Filter;
+use serde::{Deserialize, Serialize};
 use sov_evm::{EthApiError, Evm, RlpEvmTransaction};
 use sov_modules_api::capabilities::Authenticator;
 use sov_modules_api::utils::to_jsonrpsee_error_object;
@@ -25,14 +32,101 @@ use crate::gas_price::gas_oracle::GasPriceOracle;
 
 const ETH_RPC_ERROR: &str = "ETH_RPC_ERROR";
 
+/// The gas price suggested after a new block, as sent by the `eth_subscribeGasPrice` subscription.
+/// The base fee and the suggested tip are kept apart so that clients can build EIP-1559 transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPriceUpdate {
+    /// The number of the latest block.
+    pub block_number: U256,
+    /// The base fee per gas of the latest block.
+    pub base_fee: U256,
+    /// The priority fee per gas suggested by the gas price oracle.
+    pub suggested_tip: U256,
+}
+
+impl GasPriceUpdate {
+    /// The suggested gas price, as returned by `eth_gasPrice`.
+    pub fn gas_price(&self) -> U256 {
+        self.suggested_tip + self.base_fee
+    }
+}
+
+/// Returned as the `data` of the error of `eth_sendRawTransactionBatch` when the nonces of a
+/// sender's transactions aren't consecutive within the batch. None of the batch is enqueued then.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonceGap {
+    /// The position of the offending transaction in the batch.
+    pub index: usize,
+    /// The sender of the offending transaction.
+    pub sender: Address,
+    /// The nonce following the one of the previous transaction of the sender.
+    pub expected_nonce: u64,
+    /// The nonce of the offending transaction.
+    pub nonce: u64,
+}
+
+impl From<NonceGap> for ErrorObjectOwned {
+    fn from(gap: NonceGap) -> Self {
+        ErrorObjectOwned::owned(
+            jsonrpsee::types::error::INVALID_PARAMS_CODE,
+            format!(
+                "Nonce gap in transaction batch: expected nonce {} for {} at index {}, got {}",
+                gap.expected_nonce, gap.sender, gap.index, gap.nonce
+            ),
+            Some(gap),
+        )
+    }
+}
+
+/// Returns the first transaction whose nonce doesn't follow the one of the previous
+/// transaction of the same sender, given the `(sender, nonce)` pairs in submission order.
+fn find_nonce_gap(txs: impl IntoIterator<Item = (Address, u64)>) -> Option<NonceGap> {
+    let mut expected_nonces = HashMap::new();
+
+    for (index, (sender, nonce)) in txs.into_iter().enumerate() {
+        if let Some(&expected_nonce) = expected_nonces.get(&sender) {
+            if nonce != expected_nonce {
+                return Some(NonceGap {
+                    index,
+                    sender,
+                    expected_nonce,
+                    nonce,
+                });
+            }
+        }
+        expected_nonces.insert(sender, nonce.saturating_add(1));
+    }
+
+    None
+}
+
+/// The default maximum number of blocks that a single `eth_getLogs` query can span.
+pub const DEFAULT_MAX_LOGS_BLOCK_RANGE: u64 = 10_000;
+
 #[derive(Clone)]
 pub struct EthRpcConfig {
     pub min_blob_size: Option<usize>,
     pub gas_price_oracle_config: GasPriceOracleConfig,
+    /// The maximum number of blocks that a single `eth_getLogs` query can span, to keep
+    /// queries from scanning the whole chain. See [`DEFAULT_MAX_LOGS_BLOCK_RANGE`].
+    pub max_logs_block_range: u64,
     #[cfg(feature = "local")]
     pub eth_signer: DevSigner,
+    /// Omits the methods that submit transactions, i.e. [`WRITE_METHODS`].
+    pub read_only: bool,
 }
 
+/// The RPC methods that submit transactions to the DA layer.
+pub const WRITE_METHODS: &[&str] = &[
+    "eth_publishBatch",
+    "eth_sendRawTransaction",
+    "eth_sendRawTransactionBatch",
+    #[cfg(feature = "local")]
+    "eth_sendTransaction",
+];
+
 pub fn get_ethereum_rpc<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator>(
     da_service: Da,
     eth_rpc_config: EthRpcConfig,
@@ -44,6 +138,8 @@ pub fn get_ethereum_rpc<S: sov_modules_api::Spec, Da: DaService, Auth: Authentic
         #[cfg(feature = "local")]
         eth_signer,
         gas_price_oracle_config,
+        max_logs_block_range,
+        read_only,
     } = eth_rpc_config;
 
     // Fetch nonce from storage
@@ -51,12 +147,16 @@ pub fn get_ethereum_rpc<S: sov_modules_api::Spec, Da: DaService, Auth: Authentic
         da_service,
         Arc::new(Mutex::new(EthBatchBuilder::new(min_blob_size))),
         gas_price_oracle_config,
+        max_logs_block_range,
         #[cfg(feature = "local")]
         eth_signer,
         storage,
     ));
 
     register_rpc_methods(&mut rpc).expect("Failed to register sequencer RPC methods");
+    if !read_only {
+        register_write_rpc_methods(&mut rpc).expect("Failed to register sequencer RPC methods");
+    }
     rpc
 }
 
@@ -64,6 +164,7 @@ pub struct Ethereum<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator
     da_service: Da,
     batch_builder: Arc<Mutex<EthBatchBuilder>>,
     gas_price_oracle: GasPriceOracle<S>,
+    max_logs_block_range: u64,
     #[cfg(feature = "local")]
     eth_signer: DevSigner,
     storage: watch::Receiver<S::Storage>,
@@ -75,6 +176,7 @@ impl<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator> Ethereum<S, D
         da_service: Da,
         batch_builder: Arc<Mutex<EthBatchBuilder>>,
         gas_price_oracle_config: GasPriceOracleConfig,
+        max_logs_block_range: u64,
         #[cfg(feature = "local")] eth_signer: DevSigner,
         storage: watch::Receiver<S::Storage>,
     ) -> Self {
@@ -84,6 +186,7 @@ impl<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator> Ethereum<S, D
             da_service,
             batch_builder,
             gas_price_oracle,
+            max_logs_block_range,
             #[cfg(feature = "local")]
             eth_signer,
             storage,
@@ -93,14 +196,55 @@ impl<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator> Ethereum<S, D
 }
 
 impl<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator> Ethereum<S, Da, Auth> {
+    /// Decodes the raw transaction, checking its signature. Decoding failures are reported
+    /// as [`EthApiError::RawTransactionConversion`], whose `data` details what went wrong.
     fn make_raw_tx(&self, raw_tx: RlpEvmTransaction) -> Result<(B256, Vec<u8>), ErrorObjectOwned> {
-        let signed_transaction: RethTransactionSignedNoHash =
+        let (signed_transaction, message) = self.recover_raw_tx(raw_tx)?;
+
+        Ok((signed_transaction.hash(), message))
+    }
+
+    /// Like [`Self::make_raw_tx`], but returns the whole transaction along with its signer.
+    fn recover_raw_tx(
+        &self,
+        raw_tx: RlpEvmTransaction,
+    ) -> Result<(RethTransactionSignedEcRecovered, Vec<u8>), ErrorObjectOwned> {
+        let signed_transaction: RethTransactionSignedEcRecovered =
             raw_tx.clone().try_into().map_err(EthApiError::from)?;
 
-        let tx_hash = signed_transaction.hash();
         let message = borsh::to_vec(&raw_tx).expect("Failed to serialize raw tx");
 
-        Ok((tx_hash, message))
+        Ok((signed_transaction, message))
+    }
+
+    /// Decodes all the raw transactions and enqueues them in submission order, unless
+    /// a sender's nonces aren't consecutive (see [`NonceGap`]).
+    fn make_raw_tx_batch(&self, raw_txs: Vec<Bytes>) -> Result<Vec<B256>, ErrorObjectOwned> {
+        if raw_txs.is_empty() {
+            return Err(EthApiError::InvalidParams("Empty transaction batch".to_string()).into());
+        }
+
+        let (transactions, messages): (Vec<_>, Vec<_>) = raw_txs
+            .into_iter()
+            .map(|rlp| self.recover_raw_tx(RlpEvmTransaction { rlp: rlp.to_vec() }))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        if let Some(gap) = find_nonce_gap(
+            transactions
+                .iter()
+                .map(|transaction| (transaction.signer(), transaction.nonce())),
+        ) {
+            return Err(gap.into());
+        }
+
+        self.add_messages(messages);
+
+        Ok(transactions
+            .iter()
+            .map(|transaction| transaction.hash())
+            .collect())
     }
 
     async fn build_and_submit_batch(
@@ -170,39 +314,177 @@ impl<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator> Ethereum<S, D
     fn add_messages(&self, messages: Vec<Vec<u8>>) {
         self.batch_builder.lock().unwrap().add_messages(messages);
     }
+
+    async fn latest_gas_price(&self) -> Result<GasPriceUpdate, ErrorObjectOwned> {
+        let mut state = ApiStateAccessor::<S>::new(self.storage.borrow().clone());
+
+        let suggested_tip = self.gas_price_oracle.suggest_tip_cap(&mut state).await?;
+        let suggested_tip = self
+            .gas_price_oracle
+            .config()
+            .clamp_suggested_tip(suggested_tip);
+
+        let evm = Evm::<S>::default();
+        let block = evm
+            .get_block_by_number(None, None, &mut state)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+
+        Ok(GasPriceUpdate {
+            block_number: block.header.number.unwrap_or_default(),
+            base_fee: block.header.base_fee_per_gas.unwrap_or_default(),
+            suggested_tip,
+        })
+    }
+
+    async fn fee_history(
+        &self,
+        block_count: U64,
+        newest_block: String,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> Result<reth_rpc_types::FeeHistory, ErrorObjectOwned> {
+        let mut state = ApiStateAccessor::<S>::new(self.storage.borrow().clone());
+
+        self.gas_price_oracle
+            .fee_history(
+                block_count.to(),
+                newest_block,
+                reward_percentiles,
+                &mut state,
+            )
+            .await
+    }
+
+    fn get_logs(&self, filter: Filter) -> Result<Vec<reth_rpc_types::Log>, ErrorObjectOwned> {
+        let mut state = ApiStateAccessor::<S>::new(self.storage.borrow().clone());
+
+        Ok(Evm::<S>::default().get_logs(filter, self.max_logs_block_range, &mut state)?)
+    }
+
+    /// Returns a [`GasPriceUpdate`] for each block sealed after `last_block_number`, or for the
+    /// latest block only if `last_block_number` is `None`. All the updates carry the tip
+    /// currently suggested by the gas price oracle.
+    async fn gas_price_updates_since(
+        &self,
+        last_block_number: Option<u64>,
+    ) -> Result<Vec<GasPriceUpdate>, ErrorObjectOwned> {
+        let mut state = ApiStateAccessor::<S>::new(self.storage.borrow().clone());
+
+        let suggested_tip = self.gas_price_oracle.suggest_tip_cap(&mut state).await?;
+        let suggested_tip = self
+            .gas_price_oracle
+            .config()
+            .clamp_suggested_tip(suggested_tip);
+
+        let evm = Evm::<S>::default();
+        let latest_block_number = evm
+            .get_block_by_number(None, None, &mut state)?
+            .ok_or(EthApiError::UnknownBlockNumber)?
+            .header
+            .number
+            .unwrap_or_default()
+            .to::<u64>();
+
+        let first_block_number = match last_block_number {
+            Some(number) => number + 1,
+            None => latest_block_number,
+        };
+
+        (first_block_number..=latest_block_number)
+            .map(|number| -> Result<GasPriceUpdate, ErrorObjectOwned> {
+                let block = evm
+                    .get_block_by_number(Some(format!("0x{:x}", number)), None, &mut state)?
+                    .ok_or(EthApiError::UnknownBlockNumber)?;
+
+                Ok(GasPriceUpdate {
+                    block_number: U256::from(number),
+                    base_fee: block.header.base_fee_per_gas.unwrap_or_default(),
+                    suggested_tip,
+                })
+            })
+            .collect()
+    }
+
+    /// Sends a [`GasPriceUpdate`] for the latest block to the subscriber, then one for every
+    /// block sealed afterwards, until the subscriber goes away or the node shuts down.
+    ///
+    /// Storage updates can be coalesced when several blocks are sealed in a row, so the updates
+    /// are sent for the whole range of blocks sealed since the last update.
+    async fn subscribe_to_gas_price(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        let mut storage = self.storage.clone();
+        let sink = pending.accept().await?;
+        let mut last_block_number = None;
+
+        loop {
+            let updates = self
+                .gas_price_updates_since(last_block_number)
+                .await
+                .map_err(|e| e.message().to_string())?;
+
+            for update in updates {
+                last_block_number = Some(update.block_number.to::<u64>());
+                sink.send(SubscriptionMessage::from_json(&update)?).await?;
+            }
+
+            tokio::select! {
+                changed = storage.changed() => {
+                    if changed.is_err() {
+                        return Ok(());
+                    }
+                }
+                _ = sink.closed() => return Ok(()),
+            }
+        }
+    }
 }
 
 fn register_rpc_methods<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator>(
     rpc: &mut RpcModule<Ethereum<S, Da, Auth>>,
 ) -> Result<(), jsonrpsee::core::client::Error> {
     rpc.register_async_method("eth_gasPrice", |_, ethereum| async move {
-        let price = {
-            let mut state = ApiStateAccessor::<S>::new(ethereum.storage.borrow().clone());
-
-            let suggested_tip = ethereum
-                .gas_price_oracle
-                .suggest_tip_cap(&mut state)
-                .await
-                .unwrap();
-
-            let evm = Evm::<S>::default();
-            let base_fee = evm
-                .get_block_by_number(None, None, &mut state)
-                .unwrap()
-                .unwrap()
-                .header
-                .base_fee_per_gas
-                .unwrap_or_default();
-
-            suggested_tip + base_fee
-        };
+        let price = ethereum.latest_gas_price().await?.gas_price();
 
         Ok::<U256, ErrorObjectOwned>(price)
     })?;
 
-    rpc.register_async_method("eth_publishBatch", |_params, ethereum| async move {
+    rpc.register_async_method("eth_feeHistory", |parameters, ethereum| async move {
+        let mut parameters = parameters.sequence();
+        let block_count: U64 = parameters.next()?;
+        let newest_block: String = parameters.next()?;
+        let reward_percentiles: Option<Vec<f64>> = parameters.optional_next()?;
+
         ethereum
-            .build_and_submit_batch(Some(1))
+            .fee_history(block_count, newest_block, reward_percentiles)
+            .await
+    })?;
+
+    rpc.register_async_method("eth_getLogs", |parameters, ethereum| async move {
+        let filter: Filter = parameters.one()?;
+
+        ethereum.get_logs(filter)
+    })?;
+
+    rpc.register_subscription(
+        "eth_subscribeGasPrice",
+        "eth_gasPriceSubscription",
+        "eth_unsubscribeGasPrice",
+        |_, pending, ethereum| async move { ethereum.subscribe_to_gas_price(pending).await },
+    )?;
+
+    #[cfg(feature = "local")]
+    signer::register_signer_rpc_methods::<_, _, Auth>(rpc)?;
+
+    Ok(())
+}
+
+fn register_write_rpc_methods<S: sov_modules_api::Spec, Da: DaService, Auth: Authenticator>(
+    rpc: &mut RpcModule<Ethereum<S, Da, Auth>>,
+) -> Result<(), jsonrpsee::core::client::Error> {
+    rpc.register_async_method("eth_publishBatch", |parameters, ethereum| async move {
+        // Overrides the configured minimum blob size for this call only.
+        let min_blob_size: Option<usize> = parameters.sequence().optional_next()?;
+
+        ethereum
+            .build_and_submit_batch(min_blob_size)
             .await
             .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
 
@@ -216,9 +498,7 @@ fn register_rpc_methods<S: sov_modules_api::Spec, Da: DaService, Auth: Authentic
 
             let raw_evm_tx = RlpEvmTransaction { rlp: data.to_vec() };
 
-            let (tx_hash, raw_message) = ethereum
-                .make_raw_tx(raw_evm_tx)
-                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+            let (tx_hash, raw_message) = ethereum.make_raw_tx(raw_evm_tx)?;
 
             ethereum.add_messages(vec![raw_message]);
 
@@ -226,8 +506,51 @@ fn register_rpc_methods<S: sov_modules_api::Spec, Da: DaService, Auth: Authentic
         },
     )?;
 
+    rpc.register_async_method(
+        "eth_sendRawTransactionBatch",
+        |parameters, ethereum| async move {
+            let raw_txs: Vec<Bytes> = parameters.one()?;
+
+            ethereum.make_raw_tx_batch(raw_txs)
+        },
+    )?;
+
     #[cfg(feature = "local")]
-    signer::register_signer_rpc_methods::<_, _, Auth>(rpc)?;
+    signer::register_signer_write_rpc_methods::<_, _, Auth>(rpc)?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_gaps_are_detected_per_sender() {
+        let alice = Address::repeat_byte(1);
+        let bob = Address::repeat_byte(2);
+
+        assert_eq!(
+            find_nonce_gap([(alice, 3), (bob, 0), (alice, 4), (bob, 1)]),
+            None
+        );
+        assert_eq!(
+            find_nonce_gap([(alice, 3), (bob, 0), (bob, 2), (alice, 4)]),
+            Some(NonceGap {
+                index: 2,
+                sender: bob,
+                expected_nonce: 1,
+                nonce: 2,
+            })
+        );
+        // Duplicated and decreasing nonces are reported as well.
+        assert_eq!(
+            find_nonce_gap([(alice, 3), (alice, 3)]).map(|gap| gap.index),
+            Some(1)
+        );
+        assert_eq!(
+            find_nonce_gap([(alice, 3), (alice, 2)]).map(|gap| gap.expected_nonce),
+            Some(4)
+        );
+    }
+}
@@ -22,6 +22,16 @@ pub(crate) fn register_signer_rpc_methods<
     rpc.register_async_method("eth_accounts", |_parameters, ethereum| async move {
         Ok::<_, ErrorObjectOwned>(ethereum.eth_signer.signers())
     })?;
+    Ok(())
+}
+
+pub(crate) fn register_signer_write_rpc_methods<
+    S: sov_modules_api::Spec,
+    Da: DaService,
+    Auth: Authenticator,
+>(
+    rpc: &mut RpcModule<Ethereum<S, Da, Auth>>,
+) -> Result<(), jsonrpsee::core::client::Error> {
     rpc.register_async_method("eth_sendTransaction", |parameters, ethereum| async move {
         let mut transaction_request: reth_rpc_types::TransactionRequest = parameters.one().unwrap();
 
@@ -64,9 +74,7 @@ pub(crate) fn register_signer_rpc_methods<
                 rlp: signed_tx.envelope_encoded().to_vec(),
             }
         };
-        let (tx_hash, raw_message) = ethereum
-            .make_raw_tx(raw_evm_tx)
-            .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+        let (tx_hash, raw_message) = ethereum.make_raw_tx(raw_evm_tx)?;
 
         ethereum.add_messages(vec![raw_message]);
 
@@ -10,8 +10,8 @@ use axum::extract::{ws, Request, State, WebSocketUpgrade};
 use axum::http::StatusCode;
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
-use axum::routing::get;
-use axum::{middleware, Extension};
+use axum::routing::{get, post};
+use axum::{middleware, Extension, Json};
 use borsh::{BorshDeserialize, BorshSerialize};
 use futures::StreamExt;
 use serde::de::DeserializeOwned;
@@ -22,14 +22,20 @@ use sov_modules_api::{EventModuleName, RuntimeEventResponse};
 use sov_rest_utils::errors::{
     self, database_error_response_500, internal_server_error_response_500, not_found_404,
 };
-use sov_rest_utils::{json_obj, preconfigured_router_layers, ApiResult, ErrorObject, Path, Query};
+use sov_rest_utils::{
+    conditional_get, json_numbers, json_obj, preconfigured_router_layers, ApiResult, ErrorObject,
+    PageSelection, Pagination, Path, Query,
+};
 use sov_rollup_interface::common::{HexHash, HexString};
+use sov_rollup_interface::da::Time;
 use sov_rollup_interface::rpc::{
     AggregatedProofResponse, BatchIdAndOffset, BatchIdentifier, BatchResponse, EventIdentifier,
     FinalityStatus, ItemOrHash, LedgerStateProvider, QueryMode, SlotIdAndOffset, SlotIdentifier,
-    SlotResponse, TxIdAndOffset, TxIdentifier, TxResponse,
+    SlotResponse, TimeRounding, TxIdAndOffset, TxIdentifier, TxResponse,
 };
 use sov_rollup_interface::stf::TxReceiptContents;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tokio_stream::wrappers::{BroadcastStream, WatchStream};
 use tracing::warn;
 use utoipa_swagger_ui::{Config, SwaggerUi};
@@ -57,6 +63,7 @@ fn bad_path_error(key: &str) -> Response {
         details: json_obj!({
             "message": format!("{} is missing or invalid", key),
         }),
+        retry_after: None,
     }
     .into_response()
 }
@@ -80,6 +87,35 @@ fn get_path_number(path_map: &PathMap, key: &str) -> Result<u64, Response> {
     }
 }
 
+/// Configuration for the routers returned by [`LedgerRoutes::axum_router`].
+///
+/// Full nodes read it from the `[runner.ledger_routes]` section of the rollup
+/// configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct LedgerRoutesConfig {
+    /// The maximum number of children (batches, transactions) that are
+    /// inlined in a single response when `children=1` is requested. Any
+    /// children past this limit are returned as hashes only.
+    pub max_inlined_children: usize,
+    /// The maximum number of finalized slots that a websocket subscription
+    /// replays when it is started from a past slot with `from`.
+    pub max_finalized_slots_replay: u64,
+    /// The maximum number of transaction identifiers accepted by a single
+    /// `POST /txs/batch` request.
+    pub max_txs_per_lookup: usize,
+}
+
+impl Default for LedgerRoutesConfig {
+    fn default() -> Self {
+        Self {
+            max_inlined_children: 100,
+            max_finalized_slots_replay: 1000,
+            max_txs_per_lookup: 100,
+        }
+    }
+}
+
 /// Use [`LedgerRoutes::axum_router`] to instantiate an [`axum::Router`] for
 /// a specific [`LedgerStateProvider`].
 ///
@@ -106,6 +142,16 @@ where
 {
     /// Returns an [`axum::Router`] that exposes ledger data.
     pub fn axum_router(ledger: T, path_prefix: &str) -> axum::Router<T> {
+        Self::axum_router_with_config(ledger, path_prefix, LedgerRoutesConfig::default())
+    }
+
+    /// Like [`LedgerRoutes::axum_router`], but with a custom
+    /// [`LedgerRoutesConfig`].
+    pub fn axum_router_with_config(
+        ledger: T,
+        path_prefix: &str,
+        config: LedgerRoutesConfig,
+    ) -> axum::Router<T> {
         preconfigured_router_layers(
             axum::Router::<T>::new()
                 // See:
@@ -124,8 +170,11 @@ where
                     "/aggregated-proofs/latest/ws",
                     get(Self::subscribe_to_aggregated_proofs),
                 )
+                .route("/slots/by-time", get(Self::get_slot_by_time))
                 .route("/slots/latest/ws", get(Self::subscribe_to_head))
                 .route("/slots/finalized/ws", get(Self::subscribe_to_finalized))
+                .route("/events/ws", get(Self::subscribe_to_events))
+                .route("/txs/batch", post(Self::get_txs_batch))
                 .nest(
                     "/slots/latest",
                     Self::router_slot(ledger.clone()).route_layer(middleware::from_fn_with_state(
@@ -160,7 +209,8 @@ where
                         ledger,
                         Self::resolve_event_id,
                     )),
-                ),
+                )
+                .layer(Extension(config)),
         )
     }
 
@@ -174,10 +224,16 @@ where
     // - /slots/latest
     // - /slots/latest/batches/2
     // - /txs/0x1337/events/42
+    //
+    // Slots, batches, transactions and events never change once finalized, so
+    // their handlers support conditional requests through ETags.
 
     fn router_slot(ledger: T) -> axum::Router<T> {
         axum::Router::new()
-            .route("/", get(Self::get_slot))
+            .route(
+                "/",
+                get(Self::get_slot).layer(middleware::from_fn(conditional_get)),
+            )
             .nest(
                 "/batches/:batchOffset",
                 Self::router_batch(ledger.clone()).layer(middleware::from_fn_with_state(
@@ -186,21 +242,30 @@ where
                 )),
             )
             .route("/events", get(Self::get_slot_events))
+            .route("/finalized", get(Self::get_slot_finalized))
     }
 
     fn router_batch(ledger: T) -> axum::Router<T> {
-        axum::Router::new().route("/", get(Self::get_batch)).nest(
-            "/txs/:txOffset",
-            Self::router_tx(ledger.clone()).layer(middleware::from_fn_with_state(
-                ledger.clone(),
-                Self::resolve_tx_offset,
-            )),
-        )
+        axum::Router::new()
+            .route(
+                "/",
+                get(Self::get_batch).layer(middleware::from_fn(conditional_get)),
+            )
+            .nest(
+                "/txs/:txOffset",
+                Self::router_tx(ledger.clone()).layer(middleware::from_fn_with_state(
+                    ledger.clone(),
+                    Self::resolve_tx_offset,
+                )),
+            )
     }
 
     fn router_tx(ledger: T) -> axum::Router<T> {
         axum::Router::new()
-            .route("/", get(Self::get_tx))
+            .route(
+                "/",
+                get(Self::get_tx).layer(middleware::from_fn(conditional_get)),
+            )
             .route("/events", get(Self::get_tx_events))
             .nest(
                 "/events/:eventOffset",
@@ -212,7 +277,10 @@ where
     }
 
     fn router_event() -> axum::Router<T> {
-        axum::Router::new().route("/", get(Self::get_event))
+        axum::Router::new().route(
+            "/",
+            get(Self::get_event).layer(middleware::from_fn(conditional_get)),
+        )
     }
 
     // HANDLERS
@@ -223,17 +291,52 @@ where
 
     async fn get_slot(
         State(ledger): State<T>,
+        Extension(config): Extension<LedgerRoutesConfig>,
         include_children_opt: Option<Query<IncludeChildren>>,
+        Query(finality): Query<FinalizedOnly>,
         Extension(SlotNumber(slot_number)): Extension<SlotNumber>,
     ) -> ApiResult<Slot<B, TxReceipt, E>> {
+        if finality.finalized {
+            Self::ensure_slot_finalized(&ledger, slot_number).await?;
+        }
+        let include_children = include_children_opt.map(|q| q.0).unwrap_or_default();
         match ledger
-            .get_slot_by_number::<B, TxReceipt>(
-                slot_number,
-                include_children_opt.map(|q| q.0).unwrap_or_default().into(),
-            )
+            .get_slot_by_number::<B, TxReceipt>(slot_number, include_children.into())
             .await
         {
-            Ok(Some(slot_response)) => Ok(Slot::new(slot_response).into()),
+            Ok(Some(slot_response)) => {
+                let mut budget = InlineBudget::new(config.max_inlined_children);
+                let slot = Slot::new(slot_response, &mut budget);
+                Ok(budget.into_response(slot, include_children))
+            }
+            Ok(None) => Err(errors::not_found_404("Slot", slot_number)),
+            Err(err) => Err(errors::database_error_response_500(err)),
+        }
+    }
+
+    async fn get_slot_by_time(
+        State(ledger): State<T>,
+        Query(query): Query<SlotByTime>,
+    ) -> ApiResult<SlotNumberObject> {
+        match ledger
+            .find_slot_by_time(Time::from_secs(query.t), query.round)
+            .await
+        {
+            Ok(Some(number)) => Ok(SlotNumberObject { number }.into()),
+            Ok(None) => Err(errors::not_found_404(
+                "Slot",
+                format!("{:?} {}", query.round, query.t),
+            )),
+            Err(err) => Err(errors::database_error_response_500(err)),
+        }
+    }
+
+    async fn get_slot_finalized(
+        State(ledger): State<T>,
+        Extension(SlotNumber(slot_number)): Extension<SlotNumber>,
+    ) -> ApiResult<bool> {
+        match ledger.is_slot_finalized(slot_number).await {
+            Ok(Some(finalized)) => Ok(finalized.into()),
             Ok(None) => Err(errors::not_found_404("Slot", slot_number)),
             Err(err) => Err(errors::database_error_response_500(err)),
         }
@@ -242,44 +345,45 @@ where
     async fn get_slot_events(
         State(ledger): State<T>,
         Extension(SlotNumber(slot_number)): Extension<SlotNumber>,
-        event_key_prefix_opt: Option<Query<EventFilter>>,
+        filter_opt: Option<Query<EventFilter>>,
     ) -> ApiResult<Vec<Event<E>>> {
-        let filter = event_key_prefix_opt.map(|q| q.0.prefix.into());
+        let filter = filter_opt.map(|q| q.0).unwrap_or_default();
         let events = ledger
             .get_filtered_slot_events::<B, TxReceipt, RuntimeEventResponse<E>>(
                 &SlotIdentifier::Number(slot_number),
-                filter,
+                filter.key_prefix(),
             )
             .await
             .map_err(database_error_response_500)?;
 
         Ok(events
             .into_iter()
-            .map(|e| Event {
-                number: e.event_number,
-                key: e.event_key,
-                value: e.event_value,
-                module: ModuleRef {
-                    name: e.module_name,
-                },
-            })
+            .filter(|event| filter.matches_module(&event.module_name))
+            .map(Event::from)
             .collect::<Vec<_>>()
             .into())
     }
 
     async fn get_batch(
         State(ledger): State<T>,
+        Extension(config): Extension<LedgerRoutesConfig>,
         include_children_opt: Option<Query<IncludeChildren>>,
+        Query(finality): Query<FinalizedOnly>,
         Extension(BatchNumber(batch_number)): Extension<BatchNumber>,
     ) -> ApiResult<Batch<B, TxReceipt, E>> {
+        if finality.finalized {
+            Self::ensure_batch_finalized(&ledger, batch_number).await?;
+        }
+        let include_children = include_children_opt.map(|q| q.0).unwrap_or_default();
         match ledger
-            .get_batch_by_number::<B, TxReceipt>(
-                batch_number,
-                include_children_opt.map(|q| q.0).unwrap_or_default().into(),
-            )
+            .get_batch_by_number::<B, TxReceipt>(batch_number, include_children.into())
             .await
         {
-            Ok(Some(batch_response)) => Ok(Batch::new(batch_response, batch_number).into()),
+            Ok(Some(batch_response)) => {
+                let mut budget = InlineBudget::new(config.max_inlined_children);
+                let batch = Batch::new(batch_response, batch_number, &mut budget);
+                Ok(budget.into_response(batch, include_children))
+            }
             Ok(None) => Err(errors::not_found_404("Batch", batch_number)),
             Err(err) => Err(errors::database_error_response_500(err)),
         }
@@ -288,8 +392,12 @@ where
     async fn get_tx(
         State(ledger): State<T>,
         include_children_opt: Option<Query<IncludeChildren>>,
+        Query(finality): Query<FinalizedOnly>,
         Extension(TxNumber(tx_number)): Extension<TxNumber>,
     ) -> ApiResult<Transaction<TxReceipt, E>> {
+        if finality.finalized {
+            Self::ensure_tx_finalized(&ledger, tx_number).await?;
+        }
         match ledger
             .get_tx_by_number::<TxReceipt>(
                 tx_number,
@@ -303,36 +411,95 @@ where
         }
     }
 
+    /// Returns a page of the events of a transaction. The cursor is the number
+    /// of the last returned event, and is only present in `meta` if there might
+    /// be more events after it.
     async fn get_tx_events(
         State(ledger): State<T>,
         Extension(TxNumber(tx_number)): Extension<TxNumber>,
-        event_key_prefix_opt: Option<Query<EventFilter>>,
+        filter_opt: Option<Query<EventFilter>>,
+        Query(pagination): Query<Pagination<u64>>,
     ) -> ApiResult<Vec<Event<E>>> {
-        match ledger
-            .get_events_by_txn_number::<RuntimeEventResponse<E>>(tx_number)
+        let after = match pagination.selection {
+            PageSelection::First => None,
+            PageSelection::Next { cursor } => Some(cursor),
+            PageSelection::Last => {
+                return Err(errors::bad_request_400(
+                    "Invalid page selection",
+                    "page=last is not supported for transaction events",
+                ))
+            }
+        };
+        let page_size = pagination.size as usize;
+        let filter = filter_opt.and_then(|q| q.0.key_prefix());
+
+        // Fetch one extra event to know whether there is a next page.
+        let mut events = ledger
+            .get_filtered_tx_events::<RuntimeEventResponse<E>>(
+                tx_number,
+                filter,
+                after,
+                page_size + 1,
+            )
             .await
-        {
-            Ok(events) => Ok(events
-                .into_iter()
-                .filter(|event| {
-                    if let Some(prefix) = &event_key_prefix_opt {
-                        event.event_key.starts_with(&prefix.prefix)
-                    } else {
-                        true
-                    }
-                })
-                .map(|e| Event {
-                    number: e.event_number,
-                    key: e.event_key,
-                    value: e.event_value,
-                    module: ModuleRef {
-                        name: e.module_name,
-                    },
-                })
-                .collect::<Vec<_>>()
-                .into()),
-            Err(err) => Err(errors::database_error_response_500(err)),
+            .map_err(database_error_response_500)?;
+
+        let cursor = if events.len() > page_size {
+            events.truncate(page_size);
+            events.last().map(|event| event.event_number.to_string())
+        } else {
+            None
+        };
+
+        Ok(sov_rest_utils::ResponseObject::from(
+            events.into_iter().map(Event::from).collect::<Vec<_>>(),
+        )
+        .with_meta("cursor", cursor))
+    }
+
+    /// Looks up many transactions at once, by number or hash. The results
+    /// preserve the order of the identifiers, with `null` for the
+    /// transactions that don't exist.
+    async fn get_txs_batch(
+        State(ledger): State<T>,
+        Extension(config): Extension<LedgerRoutesConfig>,
+        include_children_opt: Option<Query<IncludeChildren>>,
+        Json(identifiers): Json<Vec<NumberOrHash>>,
+    ) -> ApiResult<Vec<Option<Transaction<TxReceipt, E>>>> {
+        if identifiers.len() > config.max_txs_per_lookup {
+            return Err(errors::bad_request_400(
+                "Too many transaction identifiers",
+                format!(
+                    "at most {} transactions can be looked up at once, got {}",
+                    config.max_txs_per_lookup,
+                    identifiers.len()
+                ),
+            ));
+        }
+        let query_mode = QueryMode::from(include_children_opt.map(|q| q.0).unwrap_or_default());
+
+        let mut txs = Vec::with_capacity(identifiers.len());
+        for identifier in identifiers {
+            let identifier = match identifier {
+                NumberOrHash::Number(number) => TxIdentifier::Number(number),
+                NumberOrHash::Hash(hash) => TxIdentifier::Hash(hash.0),
+            };
+            let Some(tx_number) = ledger
+                .resolve_tx_identifier(&identifier)
+                .await
+                .map_err(database_error_response_500)?
+            else {
+                txs.push(None);
+                continue;
+            };
+            let tx = ledger
+                .get_tx_by_number::<TxReceipt>(tx_number, query_mode)
+                .await
+                .map_err(database_error_response_500)?;
+            txs.push(tx.map(|tx_response| Transaction::new(tx_response, tx_number)));
         }
+
+        Ok(txs.into())
     }
 
     async fn get_event(
@@ -357,6 +524,59 @@ where
         }
     }
 
+    // FINALITY CHECKS
+    // ---------------
+    // Used by the handlers when only finalized items are requested. Items above
+    // the finalized head are reported as not found, exactly like items that
+    // don't exist yet, since they may still be rolled back by a reorg. Slots,
+    // batches and transactions are numbered contiguously, so an item is
+    // finalized iff it comes before the end of the latest finalized slot.
+
+    async fn ensure_slot_finalized(ledger: &T, slot_number: u64) -> Result<(), Response> {
+        match ledger.is_slot_finalized(slot_number).await {
+            Ok(Some(true)) => Ok(()),
+            Ok(_) => Err(not_found_404("Slot", slot_number)),
+            Err(err) => Err(database_error_response_500(err)),
+        }
+    }
+
+    async fn ensure_batch_finalized(ledger: &T, batch_number: u64) -> Result<(), Response> {
+        if batch_number < Self::finalized_batches_end(ledger).await? {
+            Ok(())
+        } else {
+            Err(not_found_404("Batch", batch_number))
+        }
+    }
+
+    async fn ensure_tx_finalized(ledger: &T, tx_number: u64) -> Result<(), Response> {
+        let tx_end = match Self::finalized_batches_end(ledger).await? {
+            0 => 0,
+            batch_end => ledger
+                .get_batch_by_number::<B, TxReceipt>(batch_end - 1, QueryMode::Compact)
+                .await
+                .map_err(database_error_response_500)?
+                .map_or(0, |batch| batch.tx_range.end),
+        };
+        if tx_number < tx_end {
+            Ok(())
+        } else {
+            Err(not_found_404("Transaction", tx_number))
+        }
+    }
+
+    /// Returns the number of the first batch after the latest finalized slot.
+    async fn finalized_batches_end(ledger: &T) -> Result<u64, Response> {
+        let finalized_slot = ledger
+            .get_latest_finalized_slot_number()
+            .await
+            .map_err(database_error_response_500)?;
+        Ok(ledger
+            .get_slot_by_number::<B, TxReceipt>(finalized_slot, QueryMode::Compact)
+            .await
+            .map_err(database_error_response_500)?
+            .map_or(0, |slot| slot.batch_range.end))
+    }
+
     // ENTITY ID RESOLVERS
     // -------------------
     // These are middleware functions that resolve the entity ID (i.e.
@@ -608,20 +828,21 @@ where
         ws: WebSocketUpgrade,
     ) -> impl IntoResponse {
         ws.on_upgrade(|socket| async move {
-            let subscription = BroadcastStream::new(ledger.subscribe_proof_saved()).map(|data| {
-                data.context("Failed to subscribe to proofs")
-                    .and_then(|data| {
-                        AggregatedProof::try_from(data)
-                            .context("Failed to convert proof to REST API representation")
-                    })
-            });
+            let subscription =
+                broadcast_stream(ledger.subscribe_proof_saved(), "aggregated_proofs").map(|data| {
+                    data.context("Failed to subscribe to proofs")
+                        .and_then(|data| {
+                            AggregatedProof::try_from(data)
+                                .context("Failed to convert proof to REST API representation")
+                        })
+                });
             Self::internal_generic_subscribe(socket, subscription).await;
         })
     }
 
     async fn subscribe_to_head(State(ledger): State<T>, ws: WebSocketUpgrade) -> impl IntoResponse {
         ws.on_upgrade(|socket| async move {
-            let subscription = BroadcastStream::new(ledger.subscribe_slots())
+            let subscription = broadcast_stream(ledger.subscribe_slots(), "slots")
                 .then(|slot_num_res| async {
                     let slot_num = slot_num_res?;
                     let Ok(Some(slot)) = ledger
@@ -630,7 +851,10 @@ where
                     else {
                         anyhow::bail!("Slot with number {} does not exist", slot_num);
                     };
-                    Ok(Slot::<B, TxReceipt, E>::new(slot))
+                    Ok(Slot::<B, TxReceipt, E>::new(
+                        slot,
+                        &mut InlineBudget::unlimited(),
+                    ))
                 })
                 .boxed();
 
@@ -638,41 +862,113 @@ where
         })
     }
 
+    /// Streams the finalized slots, starting from the latest finalized slot.
+    /// When `from` is set, the finalized slots starting from `from` are
+    /// replayed first, so that reconnecting clients don't miss any. The
+    /// connection is closed with a policy violation if that's more than
+    /// [`LedgerRoutesConfig::max_finalized_slots_replay`] slots.
     async fn subscribe_to_finalized(
         State(ledger): State<T>,
+        Extension(config): Extension<LedgerRoutesConfig>,
+        Query(replay): Query<ReplayFrom>,
         ws: WebSocketUpgrade,
     ) -> impl IntoResponse {
-        ws.on_upgrade(|socket| async move {
-            let Ok(last_notified_slot) = ledger.get_latest_finalized_slot_number().await else {
+        ws.on_upgrade(move |mut socket| async move {
+            let Ok(latest_finalized_slot) = ledger.get_latest_finalized_slot_number().await else {
                 return;
             };
 
+            // Slot numbers start from 0 at genesis, so any `from` lies at or
+            // after the genesis slot.
+            let first_slot = replay.from.unwrap_or(latest_finalized_slot);
+            let replayed_slots = latest_finalized_slot.saturating_sub(first_slot);
+            if replayed_slots > config.max_finalized_slots_replay {
+                let close_frame = ws::CloseFrame {
+                    code: ws::close_code::POLICY,
+                    reason: format!(
+                        "Can't replay more than {} slots",
+                        config.max_finalized_slots_replay
+                    )
+                    .into(),
+                };
+                if let Err(err) = socket.send(ws::Message::Close(Some(close_frame))).await {
+                    warn!(?err, "Websocket error while sending data");
+                }
+                return;
+            }
+
+            let mut next_slot = first_slot;
             let subscription = WatchStream::new(ledger.subscribe_finalized_slots())
-                .zip(futures::stream::repeat((ledger, last_notified_slot)))
-                .then(move |(slot_num, (ledger, last_notified_slot))| async move {
-                    let mut slots = vec![];
-                    for slot_number in last_notified_slot..=slot_num {
-                        let slot_result = match ledger
-                            .get_slot_by_number::<B, TxReceipt>(slot_number, QueryMode::Compact)
-                            .await
-                        {
-                            Ok(Some(slot)) => Ok(slot),
-                            Ok(None) => Err(anyhow::anyhow!(
-                                "Slot with number {} does not exist",
-                                slot_number
-                            )),
-                            Err(err) => Err(anyhow::anyhow!(
-                                "Failed to query slot with number: {}",
-                                err.to_string()
-                            )),
-                        };
-
-                        slots.push(slot_result);
+                .map(move |finalized_slot| {
+                    let slots = next_slot..finalized_slot.saturating_add(1);
+                    next_slot = next_slot.max(slots.end);
+                    futures::stream::iter(slots)
+                })
+                .flatten()
+                .zip(futures::stream::repeat(ledger))
+                .then(|(slot_number, ledger)| async move {
+                    match ledger
+                        .get_slot_by_number::<B, TxReceipt>(slot_number, QueryMode::Compact)
+                        .await
+                    {
+                        Ok(Some(slot)) => Ok(slot),
+                        Ok(None) => Err(anyhow::anyhow!(
+                            "Slot with number {} does not exist",
+                            slot_number
+                        )),
+                        Err(err) => Err(anyhow::anyhow!(
+                            "Failed to query slot with number: {}",
+                            err.to_string()
+                        )),
                     }
+                })
+                .boxed();
+
+            Self::internal_generic_subscribe(socket, subscription).await;
+        })
+    }
 
-                    (slot_num, futures::stream::iter(slots))
+    async fn subscribe_to_events(
+        State(ledger): State<T>,
+        filter_opt: Option<Query<EventFilter>>,
+        ws: WebSocketUpgrade,
+    ) -> impl IntoResponse {
+        let filter = filter_opt.map(|q| q.0).unwrap_or_default();
+
+        ws.on_upgrade(|socket| async move {
+            // Filtering happens server-side: key prefixes are matched by the
+            // database query, modules right after decoding, so clients only
+            // ever receive the events they asked for.
+            let subscription = broadcast_stream(ledger.subscribe_slots(), "events")
+                .zip(futures::stream::repeat((ledger, filter)))
+                .then(|(slot_num_res, (ledger, filter))| async move {
+                    let events = match slot_num_res {
+                        Ok(slot_num) => ledger
+                            .get_filtered_slot_events::<B, TxReceipt, RuntimeEventResponse<E>>(
+                                &SlotIdentifier::Number(slot_num),
+                                filter.key_prefix(),
+                            )
+                            .await
+                            .map_err(|err| {
+                                anyhow::anyhow!(
+                                    "Failed to query events for slot {}: {}",
+                                    slot_num,
+                                    err.to_string()
+                                )
+                            }),
+                        Err(err) => Err(err.into()),
+                    };
+
+                    let events: Vec<anyhow::Result<Event<E>>> = match events {
+                        Ok(events) => events
+                            .into_iter()
+                            .filter(|event| filter.matches_module(&event.module_name))
+                            .map(|event| Ok(Event::from(event)))
+                            .collect(),
+                        Err(err) => vec![Err(err)],
+                    };
+                    futures::stream::iter(events)
                 })
-                .map(|tuple| tuple.1)
                 .flatten()
                 .boxed();
 
@@ -681,9 +977,76 @@ where
     }
 }
 
-#[derive(Deserialize)]
+/// Wraps a ledger subscription into a stream, counting the notifications dropped
+/// because the websocket client lagged behind in
+/// [`sov_metrics::Metrics::subscription_lagged_messages`].
+///
+/// A lagging client gets disconnected, see
+/// [`sov_db::ledger_db::LedgerDb::with_subscription_capacity`] for the policy.
+fn broadcast_stream<M: Clone + Send + 'static>(
+    receiver: broadcast::Receiver<M>,
+    subscription: &'static str,
+) -> impl futures::Stream<Item = Result<M, BroadcastStreamRecvError>> {
+    BroadcastStream::new(receiver).inspect(move |item| {
+        if let Err(BroadcastStreamRecvError::Lagged(skipped)) = item {
+            warn!(subscription, skipped, "Websocket subscriber lagged behind");
+            sov_metrics::update_metrics(|metrics| {
+                metrics
+                    .subscription_lagged_messages
+                    .with_label_values(&[subscription])
+                    .inc_by(*skipped);
+            });
+        }
+    })
+}
+
+/// Query parameters that filter events by emitting module and by key prefix.
+/// Both filters are optional and must both match when present; omitting them
+/// selects all events.
+#[derive(Clone, Default, Deserialize)]
 struct EventFilter {
-    prefix: String,
+    module: Option<String>,
+    prefix: Option<String>,
+}
+
+impl EventFilter {
+    fn key_prefix(&self) -> Option<Vec<u8>> {
+        self.prefix.clone().map(Into::into)
+    }
+
+    fn matches_module(&self, module_name: &str) -> bool {
+        self.module
+            .as_ref()
+            .map_or(true, |module| module == module_name)
+    }
+}
+
+/// Query parameter of the websocket subscriptions that can replay past items.
+#[derive(Debug, Copy, Clone, Default, Deserialize)]
+struct ReplayFrom {
+    from: Option<u64>,
+}
+
+/// Query parameters of the slot lookup by timestamp.
+#[derive(Debug, Copy, Clone, Deserialize)]
+struct SlotByTime {
+    /// The timestamp, in seconds since the unix epoch.
+    t: i64,
+    round: TimeRounding,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+struct SlotNumberObject {
+    #[serde(with = "json_numbers::as_string")]
+    number: u64,
+}
+
+/// Query parameter that restricts slots, batches and transactions to the
+/// finalized ones.
+#[derive(Debug, Copy, Clone, Default, Deserialize)]
+struct FinalizedOnly {
+    #[serde(default)]
+    finalized: bool,
 }
 
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -697,6 +1060,61 @@ impl IncludeChildren {
     }
 }
 
+/// Keeps track of how many children can still be inlined in a single
+/// response. Children that exceed the budget are returned as
+/// [`ItemOrHash::Hash`] instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InlineBudget {
+    remaining: usize,
+    inlined: usize,
+    referenced: usize,
+}
+
+impl InlineBudget {
+    fn new(max_inlined_children: usize) -> Self {
+        Self {
+            remaining: max_inlined_children,
+            inlined: 0,
+            referenced: 0,
+        }
+    }
+
+    fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Returns `true` and consumes one unit of the budget if the next child
+    /// can be inlined, `false` otherwise.
+    fn try_inline(&mut self) -> bool {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            self.inlined += 1;
+            true
+        } else {
+            self.referenced += 1;
+            false
+        }
+    }
+
+    fn into_response<T>(
+        self,
+        data: T,
+        include_children: IncludeChildren,
+    ) -> sov_rest_utils::ResponseObject<T> {
+        let mut response = sov_rest_utils::ResponseObject::from(data);
+        if include_children.includes_children() {
+            response.meta.insert(
+                "children".to_string(),
+                serde_json::json!({
+                    "inlined": self.inlined,
+                    "referenced": self.referenced,
+                }),
+            );
+        }
+        response
+    }
+}
+
 impl From<IncludeChildren> for QueryMode {
     fn from(value: IncludeChildren) -> Self {
         if value.includes_children() {
@@ -741,21 +1159,27 @@ impl Display for NumberOrHash {
     bound = "B: Serialize + DeserializeOwned, TxReceipt: TxReceiptContents, E: Serialize + DeserializeOwned"
 )]
 struct Slot<B, TxReceipt: TxReceiptContents, E> {
+    #[serde(with = "json_numbers::as_string")]
     pub number: u64,
     pub hash: HexHash,
     pub state_root: HexString,
+    #[serde(with = "json_numbers::range_as_string")]
     pub batch_range: Range<u64>,
-    pub batches: Vec<Batch<B, TxReceipt, E>>,
+    pub batches: Vec<ItemOrHash<Batch<B, TxReceipt, E>>>,
     pub finality_status: FinalityStatus,
 }
 
 impl<B, TxReceipt: TxReceiptContents, E> Slot<B, TxReceipt, E> {
-    fn new(slot: SlotResponse<B, TxReceipt>) -> Self {
+    fn new(slot: SlotResponse<B, TxReceipt>, budget: &mut InlineBudget) -> Self {
         let mut batches = vec![];
 
         for batch_response in slot.batches.unwrap_or_default().into_iter() {
             if let ItemOrHash::Full(batch) = batch_response {
-                batches.push(Batch::new(batch, slot.number));
+                if budget.try_inline() {
+                    batches.push(ItemOrHash::Full(Batch::new(batch, slot.number, budget)));
+                } else {
+                    batches.push(ItemOrHash::Hash(batch.hash));
+                }
             }
         }
 
@@ -778,20 +1202,30 @@ impl<B, TxReceipt: TxReceiptContents, E> Slot<B, TxReceipt, E> {
     bound = "B: Serialize + DeserializeOwned, TxReceipt: TxReceiptContents, E: Serialize + DeserializeOwned"
 )]
 struct Batch<B, TxReceipt: TxReceiptContents, E> {
+    #[serde(with = "json_numbers::as_string")]
     pub number: u64,
     pub hash: HexHash,
+    #[serde(with = "json_numbers::range_as_string")]
     pub tx_range: Range<u64>,
+    #[serde(
+        serialize_with = "json_numbers::serialize_wide_ints",
+        deserialize_with = "json_numbers::deserialize_wide_ints"
+    )]
     pub receipt: B,
-    pub txs: Vec<Transaction<TxReceipt, E>>,
+    pub txs: Vec<ItemOrHash<Transaction<TxReceipt, E>>>,
 }
 
 impl<B, TxReceipt: TxReceiptContents, E> Batch<B, TxReceipt, E> {
-    fn new(batch: BatchResponse<B, TxReceipt>, number: u64) -> Self {
+    fn new(batch: BatchResponse<B, TxReceipt>, number: u64, budget: &mut InlineBudget) -> Self {
         let mut txs = vec![];
 
         for tx_response in batch.txs.unwrap_or_default().into_iter() {
             if let ItemOrHash::Full(tx) = tx_response {
-                txs.push(Transaction::new(tx, number));
+                if budget.try_inline() {
+                    txs.push(ItemOrHash::Full(Transaction::new(tx, number)));
+                } else {
+                    txs.push(ItemOrHash::Hash(tx.hash));
+                }
             }
         }
 
@@ -814,11 +1248,17 @@ impl<B, TxReceipt: TxReceiptContents, E> Batch<B, TxReceipt, E> {
     bound = "TxReceipt: TxReceiptContents, E: Serialize + DeserializeOwned"
 )]
 struct Transaction<TxReceipt: TxReceiptContents, E> {
+    #[serde(with = "json_numbers::as_string")]
     pub number: u64,
     pub hash: HexHash,
+    #[serde(with = "json_numbers::range_as_string")]
     pub event_range: Range<u64>,
     #[serde_as(as = "serde_with::base64::Base64")]
     pub body: Vec<u8>,
+    #[serde(
+        serialize_with = "json_numbers::serialize_wide_ints",
+        deserialize_with = "json_numbers::deserialize_wide_ints"
+    )]
     pub receipt: TxEffect<TxReceipt>,
     pub events: Vec<Event<E>>,
 }
@@ -837,14 +1277,40 @@ impl<TxReceipt: TxReceiptContents, E> Transaction<TxReceipt, E> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename = "event", rename_all = "camelCase")]
+#[serde(
+    tag = "type",
+    rename = "event",
+    rename_all = "camelCase",
+    bound(serialize = "E: Serialize", deserialize = "E: DeserializeOwned")
+)]
 struct Event<E> {
+    #[serde(with = "json_numbers::as_string")]
     pub number: u64,
     pub key: String,
+    #[serde(
+        serialize_with = "json_numbers::serialize_wide_ints",
+        deserialize_with = "json_numbers::deserialize_wide_ints"
+    )]
     pub value: E,
     pub module: ModuleRef,
 }
 
+impl<E> From<RuntimeEventResponse<E>> for Event<E>
+where
+    E: EventModuleName + Clone + BorshSerialize + BorshDeserialize + Serialize + DeserializeOwned,
+{
+    fn from(event: RuntimeEventResponse<E>) -> Self {
+        Self {
+            number: event.event_number,
+            key: event.event_key,
+            value: event.event_value,
+            module: ModuleRef {
+                name: event.module_name,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "result", rename_all = "camelCase")]
 pub enum TxEffect<T: TxReceiptContents> {
@@ -928,7 +1394,9 @@ struct RewardedAddresses(#[serde_as(as = "serde_with::base64::Base64")] Vec<u8>)
 #[serde(rename_all = "camelCase")]
 struct AggregatedProofPublicData {
     pub validity_conditions: Vec<ValidityCondition>,
+    #[serde(with = "json_numbers::as_string")]
     pub initial_slot_number: u64,
+    #[serde(with = "json_numbers::as_string")]
     pub final_slot_number: u64,
     #[serde_as(as = "serde_with::base64::Base64")]
     pub genesis_state_root: Vec<u8>,
@@ -947,10 +1415,212 @@ struct AggregatedProofPublicData {
 
 #[cfg(test)]
 mod tests {
+    use sov_rest_utils::test_utils::unsafe_json_integers;
+
     use super::*;
 
     #[test]
     fn openapi_spec_is_valid() {
         let _spec = openapi_spec();
     }
+
+    #[tokio::test]
+    async fn lagged_subscriber_is_counted() {
+        let lagged_messages = || {
+            let mut lagged_messages = 0;
+            sov_metrics::update_metrics(|metrics| {
+                lagged_messages = metrics
+                    .subscription_lagged_messages
+                    .with_label_values(&["test"])
+                    .get();
+            });
+            lagged_messages
+        };
+        let initial_lagged_messages = lagged_messages();
+
+        let (sender, receiver) = broadcast::channel(2);
+        let mut subscription = broadcast_stream(receiver, "test");
+
+        // The consumer doesn't read anything until the channel overflows.
+        for slot_number in 0..5_u64 {
+            sender.send(slot_number).unwrap();
+        }
+
+        assert!(matches!(
+            subscription.next().await,
+            Some(Err(BroadcastStreamRecvError::Lagged(3)))
+        ));
+        assert_eq!(lagged_messages() - initial_lagged_messages, 3);
+
+        // The most recent notifications are still delivered.
+        assert_eq!(subscription.next().await.unwrap().unwrap(), 3);
+        assert_eq!(subscription.next().await.unwrap().unwrap(), 4);
+        assert_eq!(lagged_messages() - initial_lagged_messages, 3);
+    }
+
+    fn batch_response(hash_byte: u8, num_txs: u8) -> BatchResponse<u32, ()> {
+        let txs = (0..num_txs)
+            .map(|i| {
+                ItemOrHash::Full(TxResponse {
+                    hash: [hash_byte.wrapping_add(100).wrapping_add(i); 32],
+                    event_range: 0..0,
+                    body: None,
+                    receipt: sov_rollup_interface::stf::TxEffect::Successful(()),
+                })
+            })
+            .collect();
+
+        BatchResponse {
+            hash: [hash_byte; 32],
+            tx_range: 0..num_txs as u64,
+            txs: Some(txs),
+            receipt: 0,
+        }
+    }
+
+    #[test]
+    fn children_past_inline_cap_are_hashes() {
+        let slot_response = SlotResponse::<u32, ()> {
+            number: 1,
+            hash: [0; 32],
+            state_root: vec![],
+            batch_range: 0..3,
+            batches: Some(vec![
+                ItemOrHash::Full(batch_response(1, 2)),
+                ItemOrHash::Full(batch_response(2, 2)),
+                ItemOrHash::Full(batch_response(3, 2)),
+            ]),
+            finality_status: FinalityStatus::Finalized,
+        };
+
+        // Enough budget for the first batch with all of its transactions, plus
+        // the second batch without its transactions.
+        let mut budget = InlineBudget::new(4);
+        let slot = Slot::<u32, (), ()>::new(slot_response, &mut budget);
+
+        let ItemOrHash::Full(first_batch) = &slot.batches[0] else {
+            panic!("The first batch should be inlined");
+        };
+        assert!(first_batch
+            .txs
+            .iter()
+            .all(|tx| matches!(tx, ItemOrHash::Full(_))));
+
+        let ItemOrHash::Full(second_batch) = &slot.batches[1] else {
+            panic!("The second batch should be inlined");
+        };
+        assert_eq!(
+            second_batch.txs,
+            vec![ItemOrHash::Hash([102; 32]), ItemOrHash::Hash([103; 32])]
+        );
+
+        assert_eq!(slot.batches[2], ItemOrHash::Hash([3; 32]));
+
+        let response = budget.into_response(slot, IncludeChildren { children: 1 });
+        assert_eq!(
+            response.meta.get("children"),
+            Some(&serde_json::json!({ "inlined": 4, "referenced": 3 }))
+        );
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json["data"]["batches"][2],
+            serde_json::json!(format!("0x{}", hex::encode([3; 32])))
+        );
+    }
+
+    const ABOVE_SAFE_INTEGER: u64 = json_numbers::MAX_SAFE_INTEGER + 2;
+
+    fn wide_slot_response<B>(batch_receipt: B) -> SlotResponse<B, ()> {
+        let tx = TxResponse {
+            hash: [2; 32],
+            event_range: ABOVE_SAFE_INTEGER..u64::MAX,
+            body: None,
+            receipt: sov_rollup_interface::stf::TxEffect::Successful(()),
+        };
+        let batch = BatchResponse {
+            hash: [1; 32],
+            tx_range: ABOVE_SAFE_INTEGER..u64::MAX,
+            txs: Some(vec![ItemOrHash::Full(tx)]),
+            receipt: batch_receipt,
+        };
+
+        SlotResponse {
+            number: ABOVE_SAFE_INTEGER,
+            hash: [0; 32],
+            state_root: vec![],
+            batch_range: ABOVE_SAFE_INTEGER..u64::MAX,
+            batches: Some(vec![ItemOrHash::Full(batch)]),
+            finality_status: FinalityStatus::Finalized,
+        }
+    }
+
+    #[test]
+    fn wide_integers_are_serialized_as_strings() {
+        let slot =
+            Slot::<u64, (), u64>::new(wide_slot_response(u64::MAX), &mut InlineBudget::new(10));
+        let json = serde_json::to_value(&slot).unwrap();
+        assert_eq!(unsafe_json_integers(&json), Vec::<String>::new());
+        assert_eq!(json["number"], ABOVE_SAFE_INTEGER.to_string());
+        assert_eq!(json["batchRange"]["end"], u64::MAX.to_string());
+        assert_eq!(json["batches"][0]["receipt"], u64::MAX.to_string());
+        assert_eq!(
+            json["batches"][0]["txs"][0]["eventRange"]["start"],
+            ABOVE_SAFE_INTEGER.to_string()
+        );
+
+        let event = Event {
+            number: ABOVE_SAFE_INTEGER,
+            key: "key".to_string(),
+            value: u64::MAX,
+            module: ModuleRef {
+                name: "bank".to_string(),
+            },
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(unsafe_json_integers(&json), Vec::<String>::new());
+
+        let public_data = AggregatedProofPublicData {
+            validity_conditions: vec![],
+            initial_slot_number: ABOVE_SAFE_INTEGER,
+            final_slot_number: u64::MAX,
+            genesis_state_root: vec![],
+            initial_state_root: vec![],
+            final_state_root: vec![],
+            initial_slot_hash: vec![],
+            final_slot_hash: vec![],
+            code_commitment: vec![],
+            rewarded_addresses: vec![],
+        };
+        let json = serde_json::to_value(&public_data).unwrap();
+        assert_eq!(unsafe_json_integers(&json), Vec::<String>::new());
+        assert_eq!(
+            serde_json::from_value::<AggregatedProofPublicData>(json).unwrap(),
+            public_data
+        );
+    }
+
+    #[test]
+    fn slot_roundtrip_above_safe_integer() {
+        // Narrow integers in receipts are left as JSON numbers, so they
+        // roundtrip through the derived `Deserialize`.
+        let slot = Slot::<u32, (), u32>::new(wide_slot_response(7), &mut InlineBudget::new(10));
+
+        let json = serde_json::to_string(&slot).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Slot<u32, (), u32>>(&json).unwrap(),
+            slot
+        );
+
+        let event = Event {
+            number: u64::MAX,
+            key: "key".to_string(),
+            value: 7u32,
+            module: ModuleRef {
+                name: "bank".to_string(),
+            },
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<Event<u32>>(&json).unwrap(), event);
+    }
 }
@@ -0,0 +1,184 @@
+//! Queries the ledger APIs while the underlying ledger keeps finalizing slots
+//! and going through reorgs in the background.
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use sha2::Digest;
+use sov_test_utils::ledger_db::{mixed_module_events, LedgerTestService, LedgerTestServiceData};
+
+const ROUNDS: u64 = 50;
+const SLOTS_PER_ROUND: u64 = 3;
+const QUERY_TASKS: usize = 8;
+
+/// The REST API serializes wide integers as strings.
+fn as_u64(value: &serde_json::Value) -> u64 {
+    match value {
+        serde_json::Value::String(s) => s.parse().unwrap(),
+        other => other.as_u64().unwrap(),
+    }
+}
+
+/// Queries `/slots/:id` and `/txs/:id` for every number up to the current
+/// head, until `done` is set.
+async fn hammer_queries(addr: SocketAddr, done: Arc<AtomicBool>) -> usize {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    let mut successful_queries = 0;
+
+    while !done.load(Ordering::Acquire) {
+        let head = client
+            .get(format!("http://{}/slots/latest", addr))
+            .send()
+            .await
+            .unwrap();
+        assert!(
+            head.status() == 200 || head.status() == 404,
+            "Unexpected status for the latest slot: {}",
+            head.status()
+        );
+        if head.status() != 200 {
+            continue;
+        }
+        let head: serde_json::Value = head.json().await.unwrap();
+        let head_number = as_u64(&head["data"]["number"]);
+
+        for number in 0..=head_number {
+            // Slots and transactions are numbered in lockstep: slot `n` holds
+            // the transaction `n`.
+            for resource in ["slots", "txs"] {
+                let response = client
+                    .get(format!("http://{}/{}/{}", addr, resource, number))
+                    .send()
+                    .await
+                    .unwrap();
+                let status = response.status();
+                // The item may have been removed by a reorg in the meantime.
+                assert!(
+                    status == 200 || status == 404,
+                    "Unexpected status for /{}/{}: {}",
+                    resource,
+                    number,
+                    status
+                );
+                if status != 200 {
+                    continue;
+                }
+
+                let body: serde_json::Value = response.json().await.unwrap();
+                assert_eq!(as_u64(&body["data"]["number"]), number);
+                if resource == "txs" && number > 0 {
+                    let expected_hash: [u8; 32] =
+                        sha2::Sha256::digest(format!("tx-{}", number)).into();
+                    assert_eq!(
+                        body["data"]["hash"],
+                        format!("0x{}", hex::encode(expected_hash))
+                    );
+                }
+                successful_queries += 1;
+            }
+        }
+    }
+
+    successful_queries
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn queries_during_finalization_and_reorgs() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+    let client = ledger_service.axum_client.clone();
+
+    let mut slots_subscription = client.subscribe_slots().await.unwrap();
+    let mut events_subscription = client.subscribe_events(None, None).await.unwrap();
+
+    let done = Arc::new(AtomicBool::new(false));
+    let query_tasks: Vec<_> = (0..QUERY_TASKS)
+        .map(|_| tokio::spawn(hammer_queries(addr, done.clone())))
+        .collect();
+
+    let subscriptions_task = tokio::spawn(async move {
+        let mut received = 0;
+        loop {
+            tokio::select! {
+                Some(slot) = slots_subscription.next() => {
+                    slot.expect("Slot notifications must deserialize");
+                    received += 1;
+                }
+                Some(event) = events_subscription.next() => {
+                    event.expect("Event notifications must deserialize");
+                    received += 1;
+                }
+                else => break,
+            }
+        }
+        received
+    });
+
+    let chaos = async {
+        for _ in 0..ROUNDS {
+            for _ in 0..SLOTS_PER_ROUND {
+                ledger_service
+                    .process_slot_with_events(mixed_module_events())
+                    .unwrap();
+            }
+            let head = ledger_service.head_slot_number().unwrap().unwrap();
+            ledger_service.finalize_slot(head - 1).unwrap();
+
+            // The unfinalized head is replaced by a slot from another fork.
+            ledger_service.rollback_slots(head).await.unwrap();
+            ledger_service
+                .process_slot_with_events(mixed_module_events())
+                .unwrap();
+            tokio::task::yield_now().await;
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(120), chaos)
+        .await
+        .expect("Finalization and reorgs must not deadlock");
+    done.store(true, Ordering::Release);
+
+    for task in query_tasks {
+        let successful_queries = tokio::time::timeout(Duration::from_secs(60), task)
+            .await
+            .expect("Queries must not deadlock")
+            .expect("Queries must not panic");
+        assert!(successful_queries > 0);
+    }
+
+    // The server must still serve fresh subscriptions and queries once the
+    // dust settles.
+    let mut fresh_subscription = client.subscribe_slots().await.unwrap();
+    loop {
+        ledger_service
+            .process_slot_with_events(mixed_module_events())
+            .unwrap();
+        if let Ok(slot) =
+            tokio::time::timeout(Duration::from_millis(100), fresh_subscription.next()).await
+        {
+            slot.unwrap().unwrap();
+            break;
+        }
+    }
+    let head_number = ledger_service.head_slot_number().unwrap().unwrap();
+    let head: serde_json::Value = reqwest::get(format!("http://{}/slots/{}", addr, head_number))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(as_u64(&head["data"]["number"]), head_number);
+
+    subscriptions_task.abort();
+    match subscriptions_task.await {
+        Ok(received) => assert!(received > 0),
+        Err(err) => assert!(err.is_cancelled(), "Subscriptions must not panic"),
+    }
+}
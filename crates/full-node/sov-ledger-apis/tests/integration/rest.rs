@@ -3,11 +3,13 @@
 
 use std::future::Future;
 use std::str::FromStr;
+use std::time::Duration;
 
 use assert_json_diff::assert_json_eq;
+use futures::StreamExt;
 use sov_ledger_json_client::types;
 use sov_ledger_json_client::types::IntOrHash;
-use sov_test_utils::ledger_db::{LedgerTestService, LedgerTestServiceData};
+use sov_test_utils::ledger_db::{mixed_module_events, LedgerTestService, LedgerTestServiceData};
 use utils::ledger_response_body;
 
 /// We want 404s to return rich, JSON errors, like all the other kind of errors
@@ -34,7 +36,11 @@ async fn global_404_error() {
 #[tokio::test(flavor = "multi_thread")]
 async fn get_latest_slot() {
     let slot = ledger_response_body(|client| async move {
-        client.get_latest_slot(None).await.unwrap().into_inner()
+        client
+            .get_latest_slot(None, None)
+            .await
+            .unwrap()
+            .into_inner()
     })
     .await;
 
@@ -44,12 +50,16 @@ async fn get_latest_slot() {
     });
 
     // By number.
-    let slot_number = slot["data"]["number"].as_u64().unwrap();
+    let slot_number = slot["data"]["number"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
     assert_json_eq!(
         slot,
         ledger_response_body(move |client| async move {
             client
-                .get_slot_by_id(&IntOrHash::Variant0(slot_number), None)
+                .get_slot_by_id(&IntOrHash::Variant0(slot_number), None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -58,11 +68,126 @@ async fn get_latest_slot() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn get_slot_finalized() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    // Slot 1 is processed after the latest finalized slot, i.e. slot 0.
+    ledger_service
+        .process_slot_with_events(mixed_module_events())
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+
+    let get_finalized = |slot_number: u64| async move {
+        reqwest::get(format!("http://{}/slots/{}/finalized", addr, slot_number))
+            .await
+            .unwrap()
+    };
+
+    let response = get_finalized(0).await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["data"], true);
+
+    let response = get_finalized(1).await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["data"], false);
+
+    assert_eq!(get_finalized(42).await.status(), 404);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_finalized_only() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    // Slot 1, batch 1 and tx 1 come after the latest finalized slot, i.e. slot 0.
+    ledger_service
+        .process_slot_with_events(mixed_module_events())
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+
+    let get_status = |path: &'static str| async move {
+        reqwest::get(format!("http://{}{}", addr, path))
+            .await
+            .unwrap()
+            .status()
+    };
+
+    for path in [
+        "/slots/0",
+        "/batches/0",
+        "/txs/0",
+        "/slots/0/batches/0/txs/0",
+    ] {
+        assert_eq!(get_status(path).await, 200);
+    }
+    for path in [
+        "/slots/0?finalized=true",
+        "/batches/0?finalized=true",
+        "/txs/0?finalized=true&children=1",
+        "/slots/0/batches/0/txs/0?finalized=true",
+        "/slots/1?finalized=false",
+        "/batches/1",
+        "/txs/1",
+    ] {
+        assert_eq!(get_status(path).await, 200, "{}", path);
+    }
+    for path in [
+        "/slots/1?finalized=true",
+        "/slots/latest?finalized=true",
+        "/batches/1?finalized=true",
+        "/txs/1?finalized=true",
+        "/slots/1/batches/0/txs/0?finalized=true",
+    ] {
+        assert_eq!(get_status(path).await, 404, "{}", path);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_slot_by_time() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    ledger_service
+        .process_slot_with_events(mixed_module_events())
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+
+    let get_slot_by_time = |query: &'static str| async move {
+        reqwest::get(format!("http://{}/slots/by-time?{}", addr, query))
+            .await
+            .unwrap()
+    };
+
+    // Slots 0 and 1 are timestamped when they're processed, i.e. well after
+    // the epoch and well before 2100-01-01.
+    let response = get_slot_by_time("t=0&round=after").await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["data"]["number"], "0");
+
+    let response = get_slot_by_time("t=4102444800&round=before").await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["data"]["number"], "1");
+
+    assert_eq!(get_slot_by_time("t=0&round=before").await.status(), 404);
+    assert_eq!(
+        get_slot_by_time("t=4102444800&round=after").await.status(),
+        404
+    );
+    assert_eq!(get_slot_by_time("t=0&round=nearest").await.status(), 400);
+    assert_eq!(get_slot_by_time("round=after").await.status(), 400);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn get_batch() {
     let batch = ledger_response_body(|client| async move {
         client
-            .get_batch_by_id(&IntOrHash::Variant0(0), None)
+            .get_batch_by_id(&IntOrHash::Variant0(0), None, None)
             .await
             .unwrap()
             .into_inner()
@@ -80,7 +205,7 @@ async fn get_batch() {
         batch,
         ledger_response_body(|client| async move {
             client
-                .get_batch_by_id(&IntOrHash::Variant1(hash), None)
+                .get_batch_by_id(&IntOrHash::Variant1(hash), None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -88,12 +213,34 @@ async fn get_batch() {
         .await
     );
 
+    // By hash, without the `0x` prefix.
+    let bare_hash = batch["data"]["hash"]
+        .as_str()
+        .unwrap()
+        .strip_prefix("0x")
+        .unwrap()
+        .to_owned();
+    let batch_by_bare_hash = ledger_response_body(|client| async move {
+        reqwest::get(format!("{}/batches/{}", client.baseurl(), bare_hash))
+            .await
+            .unwrap()
+            .json::<serde_json::Value>()
+            .await
+            .unwrap()
+    })
+    .await;
+    assert_eq!(batch_by_bare_hash["data"]["hash"], batch["data"]["hash"]);
+    assert_eq!(
+        batch_by_bare_hash["data"]["number"],
+        batch["data"]["number"]
+    );
+
     // By slot offset.
     assert_json_eq!(
         batch,
         ledger_response_body(|client| async move {
             client
-                .get_batch_by_slot_id_and_offset(&IntOrHash::Variant0(0), 0, None)
+                .get_batch_by_slot_id_and_offset(&IntOrHash::Variant0(0), 0, None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -107,7 +254,7 @@ async fn get_batch() {
 async fn get_tx() {
     let tx = ledger_response_body(|client| async move {
         client
-            .get_tx_by_id(&IntOrHash::Variant0(0), None)
+            .get_tx_by_id(&IntOrHash::Variant0(0), None, None)
             .await
             .unwrap()
             .into_inner()
@@ -124,7 +271,7 @@ async fn get_tx() {
         tx,
         ledger_response_body(|client| async move {
             client
-                .get_tx_by_id(&IntOrHash::Variant0(0), None)
+                .get_tx_by_id(&IntOrHash::Variant0(0), None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -138,7 +285,7 @@ async fn get_tx() {
         tx,
         ledger_response_body(|client| async move {
             client
-                .get_tx_by_id(&IntOrHash::Variant1(hash), None)
+                .get_tx_by_id(&IntOrHash::Variant1(hash), None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -151,7 +298,7 @@ async fn get_tx() {
         tx,
         ledger_response_body(|client| async move {
             client
-                .get_tx_by_slot_id_and_offset(&IntOrHash::Variant0(0), 0, 0, None)
+                .get_tx_by_slot_id_and_offset(&IntOrHash::Variant0(0), 0, 0, None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -164,7 +311,7 @@ async fn get_tx() {
         tx,
         ledger_response_body(|client| async move {
             client
-                .get_tx_by_batch_id_and_offset(&IntOrHash::Variant0(0), 0, None)
+                .get_tx_by_batch_id_and_offset(&IntOrHash::Variant0(0), 0, None, None)
                 .await
                 .unwrap()
                 .into_inner()
@@ -173,6 +320,126 @@ async fn get_tx() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn get_txs_batch() {
+    let ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+    let tx = reqwest::get(format!("http://{}/txs/0", addr))
+        .await
+        .unwrap()
+        .json::<serde_json::Value>()
+        .await
+        .unwrap()["data"]
+        .clone();
+
+    let post_txs_batch = |identifiers: serde_json::Value| async move {
+        reqwest::Client::new()
+            .post(format!("http://{}/txs/batch", addr))
+            .json(&identifiers)
+            .send()
+            .await
+            .unwrap()
+    };
+
+    // Results preserve the input order, with nulls for unknown transactions.
+    let response = post_txs_batch(serde_json::json!(["1000", tx["hash"], "0"])).await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_json_eq!(body["data"], serde_json::json!([null, tx, tx]));
+
+    let too_many = vec!["0"; 101];
+    let response = post_txs_batch(serde_json::json!(too_many)).await;
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn conditional_get_with_etag() {
+    let ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+    let client = reqwest::Client::new();
+
+    for path in [
+        "/slots/0?finalized=true",
+        "/batches/0?finalized=true",
+        "/txs/0?finalized=true",
+        "/events/0",
+    ] {
+        let url = format!("http://{}{}", addr, path);
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.status(), 200);
+        let etag = response.headers()[reqwest::header::ETAG].clone();
+        assert!(etag.to_str().unwrap().starts_with("W/"));
+
+        let response = client
+            .get(&url)
+            .header(reqwest::header::IF_NONE_MATCH, etag.clone())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 304, "{}", path);
+        assert_eq!(response.headers()[reqwest::header::ETAG], etag);
+
+        let response = client
+            .get(&url)
+            .header(reqwest::header::IF_NONE_MATCH, "W/\"stale\"")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_tx_events_paginated() {
+    let ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+
+    let get_events = |query: &'static str| async move {
+        let response = reqwest::get(format!("http://{}/txs/0/events?{}", addr, query))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        response.json::<serde_json::Value>().await.unwrap()
+    };
+    let event_keys = |body: &serde_json::Value| {
+        body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|event| event["key"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    // Without pagination parameters, all the events fit in the first page.
+    let body = get_events("").await;
+    assert_eq!(event_keys(&body), vec!["foo", "bar"]);
+    assert_eq!(body["meta"]["cursor"], serde_json::Value::Null);
+
+    let body = get_events("page[size]=1").await;
+    assert_eq!(event_keys(&body), vec!["foo"]);
+    assert_eq!(body["meta"]["cursor"], "0");
+
+    let body = get_events("page[size]=1&page[after]=0").await;
+    assert_eq!(event_keys(&body), vec!["bar"]);
+    assert_eq!(body["meta"]["cursor"], serde_json::Value::Null);
+
+    // The prefix filter is applied before the page is cut.
+    let body = get_events("page[size]=1&prefix=bar").await;
+    assert_eq!(event_keys(&body), vec!["bar"]);
+    assert_eq!(body["meta"]["cursor"], serde_json::Value::Null);
+
+    let response = reqwest::get(format!("http://{}/txs/0/events?page[size]=0", addr))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn get_event() {
     let ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
@@ -204,9 +471,171 @@ async fn get_latest_aggregated_proof() {
     });
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn get_slot_events_filtered_by_module() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    ledger_service
+        .process_slot_with_events(mixed_module_events())
+        .unwrap();
+    let addr = ledger_service.axum_handle.listening().await.unwrap();
+
+    let get_event_keys = |query: &'static str| async move {
+        let response = reqwest::get(format!("http://{}/slots/latest/events?{}", addr, query))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = response.json().await.unwrap();
+        body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|event| event["key"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    // The latest slot emits "foo/bank", "bar/bank" and "foo/value" (from the
+    // value setter module).
+    assert_eq!(
+        get_event_keys("").await,
+        vec!["foo/bank", "bar/bank", "foo/value"]
+    );
+    assert_eq!(
+        get_event_keys("module=bank").await,
+        vec!["foo/bank", "bar/bank"]
+    );
+    assert_eq!(
+        get_event_keys("prefix=foo").await,
+        vec!["foo/bank", "foo/value"]
+    );
+    assert_eq!(
+        get_event_keys("module=bank&prefix=foo").await,
+        vec!["foo/bank"]
+    );
+    assert!(get_event_keys("module=bank&prefix=transfer")
+        .await
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn subscribe_to_filtered_events() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    let mut subscription = ledger_service
+        .axum_client
+        .subscribe_events(Some("bank"), Some("foo"))
+        .await
+        .unwrap();
+
+    // The server sets up the subscription asynchronously after the WebSocket
+    // handshake, so we keep processing slots until events start flowing.
+    let mut events = loop {
+        ledger_service
+            .process_slot_with_events(mixed_module_events())
+            .unwrap();
+        if let Ok(event) =
+            tokio::time::timeout(Duration::from_millis(100), subscription.next()).await
+        {
+            break vec![event.unwrap().unwrap()];
+        }
+    };
+
+    ledger_service
+        .process_slot_with_events(mixed_module_events())
+        .unwrap();
+    while let Ok(Some(event)) =
+        tokio::time::timeout(Duration::from_millis(500), subscription.next()).await
+    {
+        events.push(event.unwrap());
+    }
+
+    // Each slot emits "foo/bank", "bar/bank" and "foo/value" (from the value
+    // setter module), but only the first one matches both filters.
+    assert!(events.len() >= 2);
+    for event in events {
+        assert_eq!(event.key, "foo/bank");
+        assert_eq!(event.module.name, "bank");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replay_finalized_slots_from_height() {
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    for _ in 0..3 {
+        ledger_service.process_slot_with_events(vec![]).unwrap();
+    }
+    ledger_service.finalize_slot(2).unwrap();
+
+    let mut ws = utils::connect_finalized_slots_ws(&ledger_service, "?from=1").await;
+    assert_eq!(utils::next_slot_number(&mut ws).await, 1);
+    assert_eq!(utils::next_slot_number(&mut ws).await, 2);
+
+    // Once the backfill is over, new finalized slots are streamed live.
+    ledger_service.finalize_slot(3).unwrap();
+    assert_eq!(utils::next_slot_number(&mut ws).await, 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replay_finalized_slots_too_far_behind() {
+    use sov_ledger_json_client::tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+    use sov_ledger_json_client::tokio_tungstenite::tungstenite::Message;
+
+    let mut ledger_service = LedgerTestService::new(LedgerTestServiceData::Simple)
+        .await
+        .unwrap();
+    // Only the finalized slot number matters here, as the replay window is
+    // checked before any slot is fetched.
+    ledger_service.finalize_slot(2000).unwrap();
+
+    let mut ws = utils::connect_finalized_slots_ws(&ledger_service, "?from=0").await;
+    match ws.next().await {
+        Some(Ok(Message::Close(Some(frame)))) => assert_eq!(frame.code, CloseCode::Policy),
+        other => panic!("expected a close frame, got {:?}", other),
+    }
+}
+
 mod utils {
+    use sov_ledger_json_client::tokio_tungstenite::tungstenite::{
+        Error as WsError, Message as WsMessage,
+    };
+
     use super::*;
 
+    pub async fn connect_finalized_slots_ws(
+        ledger_service: &LedgerTestService,
+        query: &str,
+    ) -> impl futures::Stream<Item = Result<WsMessage, WsError>> + Unpin {
+        let url = format!(
+            "{}/slots/finalized/ws{}",
+            ledger_service.axum_client.baseurl(),
+            query
+        )
+        .replace("http://", "ws://");
+        let (ws, _) = sov_ledger_json_client::tokio_tungstenite::connect_async(url)
+            .await
+            .unwrap();
+        ws
+    }
+
+    pub async fn next_slot_number(
+        ws: &mut (impl futures::Stream<Item = Result<WsMessage, WsError>> + Unpin),
+    ) -> u64 {
+        let msg = tokio::time::timeout(Duration::from_secs(5), ws.next())
+            .await
+            .expect("timed out waiting for a finalized slot")
+            .unwrap()
+            .unwrap();
+        let WsMessage::Text(text) = msg else {
+            panic!("unexpected WebSocket message: {:?}", msg);
+        };
+        let slot: serde_json::Value = serde_json::from_str(&text).unwrap();
+        slot["number"].as_u64().unwrap()
+    }
+
     pub async fn ledger_response_body<T, F, Fut>(api_call: F) -> serde_json::Value
     where
         F: FnOnce(sov_ledger_json_client::Client) -> Fut + Send + Sync + 'static,
@@ -4,6 +4,6 @@ use sov_ledger_json_client::Client;
 async fn main() {
     let client = Client::new("http://example.com");
 
-    let latest_slot_response = client.get_latest_slot(None).await.unwrap();
+    let latest_slot_response = client.get_latest_slot(None, None).await.unwrap();
     println!("Latest slot: {:?}", latest_slot_response);
 }
@@ -31,13 +31,39 @@ impl Client {
         self.subscribe_to_ws("/aggregated-proofs/latest/ws").await
     }
 
+    /// Subscribes to the events of new slots, optionally filtered by module
+    /// name and/or event key prefix. Filtering is done by the server.
+    pub async fn subscribe_events(
+        &self,
+        module: Option<&str>,
+        prefix: Option<&str>,
+    ) -> WsSubscription<types::Event> {
+        let mut url = reqwest::Url::parse(&format!("{}/events/ws", self.baseurl()))
+            .expect("The client base URL is always valid");
+        if module.is_some() || prefix.is_some() {
+            let mut query = url.query_pairs_mut();
+            if let Some(module) = module {
+                query.append_pair("module", module);
+            }
+            if let Some(prefix) = prefix {
+                query.append_pair("prefix", prefix);
+            }
+        }
+
+        Self::subscribe_to_ws_url(url.as_str()).await
+    }
+
     async fn subscribe_to_ws<T: serde::de::DeserializeOwned>(
         &self,
         path: &str,
     ) -> WsSubscription<T> {
+        Self::subscribe_to_ws_url(&format!("{}{}", self.baseurl(), path)).await
+    }
+
+    async fn subscribe_to_ws_url<T: serde::de::DeserializeOwned>(url: &str) -> WsSubscription<T> {
         // The base URL can't be used for WebSocket connections; we need to
         // change the protocol.
-        let url = format!("{}{}", self.baseurl(), path).replace("http://", "ws://");
+        let url = url.replace("http://", "ws://");
 
         let (ws, _) = connect_async(url).await?;
 
@@ -71,6 +97,14 @@ impl Client {
     }
 }
 
+impl types::Uint64String {
+    /// Parses the decimal string representation into a [`u64`].
+    pub fn to_u64(&self) -> anyhow::Result<u64> {
+        self.parse()
+            .with_context(|| format!("Invalid 64-bit unsigned integer: {}", self.as_str()))
+    }
+}
+
 impl TryInto<aggregated_proof::AggregatedProof> for types::AggregatedProof {
     type Error = anyhow::Error;
 
@@ -83,8 +117,8 @@ impl TryInto<aggregated_proof::AggregatedProof> for types::AggregatedProof {
             aggregated_proof::AggregatedProofPublicData {
                 validity_conditions: decode_b64_vec(public_data.validity_conditions.iter())?,
                 rewarded_addresses: decode_b64_vec(public_data.rewarded_addresses.iter())?,
-                initial_slot_number: public_data.initial_slot_number,
-                final_slot_number: public_data.final_slot_number,
+                initial_slot_number: public_data.initial_slot_number.to_u64()?,
+                final_slot_number: public_data.final_slot_number.to_u64()?,
                 genesis_state_root: decode_b64(&public_data.genesis_state_root)?,
                 initial_state_root: decode_b64(&public_data.initial_state_root)?,
                 final_state_root: decode_b64(&public_data.final_state_root)?,
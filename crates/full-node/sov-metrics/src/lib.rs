@@ -2,31 +2,152 @@
 
 #![deny(missing_docs)]
 
+#[cfg(any(feature = "native", test))]
+mod module_metrics;
+#[cfg(any(feature = "native", test))]
+mod namespace;
+
 use std::sync::OnceLock;
 
+#[cfg(any(feature = "native", test))]
+pub use module_metrics::{module_metrics, ModuleMetrics};
+#[cfg(any(feature = "native", test))]
+pub use namespace::MetricsNamespace;
 use prometheus::{
-    register_int_counter_with_registry, register_int_gauge_with_registry, IntCounter, IntGauge,
+    register_histogram_with_registry, register_int_counter_vec_with_registry,
+    register_int_counter_with_registry, register_int_gauge_with_registry, Histogram, HistogramOpts,
+    IntCounter, IntCounterVec, IntGauge,
 };
 
+static METRICS: OnceLock<Metrics> = OnceLock::new();
+
+/// Returns a [`axum::Router`] which serves the metrics gathered from
+/// `registry` at `path`, in the Prometheus text exposition format.
+///
+/// The rollup metrics are exported through [`global_registry`].
+#[cfg(feature = "native")]
+pub fn metrics_router<S>(path: &str, registry: prometheus::Registry) -> axum::Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    use axum::http::{header, StatusCode};
+    use axum::response::IntoResponse;
+    use prometheus::Encoder;
+
+    axum::Router::new().route(
+        path,
+        axum::routing::get(move || async move {
+            let encoder = prometheus::TextEncoder::new();
+            let mut buffer = Vec::new();
+            match encoder.encode(&registry.gather(), &mut buffer) {
+                Ok(()) => (
+                    [(header::CONTENT_TYPE, encoder.format_type().to_string())],
+                    buffer,
+                )
+                    .into_response(),
+                Err(err) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("failed to encode metrics: {}", err),
+                )
+                    .into_response(),
+            }
+        }),
+    )
+}
+
 /// Applies a function to the global [`Metrics`] instance if and only if the
 /// `native` feature is enabled.
+///
+/// Unless the global instance was set with [`set_global_metrics`] or
+/// [`init_metrics`], it is registered into the default Prometheus registry
+/// on first use.
 pub fn update_metrics<F>(f: F)
 where
     F: FnOnce(&Metrics),
 {
     if cfg!(feature = "native") {
-        static METRICS: OnceLock<Metrics> = OnceLock::new();
+        f(global_metrics());
+    }
+}
+
+/// Returns the [`prometheus::Registry`] of the global [`Metrics`] instance,
+/// which the node serves. Custom metrics, e.g. the ones of a
+/// [`MetricsNamespace`], are registered into it as well.
+///
+/// Unless the global instance was set with [`set_global_metrics`] or
+/// [`init_metrics`], it is registered into the default Prometheus registry
+/// on first use.
+#[cfg(any(feature = "native", test))]
+pub fn global_registry() -> &'static prometheus::Registry {
+    global_metrics().registry()
+}
+
+fn global_metrics() -> &'static Metrics {
+    METRICS.get_or_init(|| {
+        Metrics::register_into(prometheus::default_registry())
+            .expect("failed to create new metrics; this is a bug in the Sovereign SDK")
+    })
+}
 
-        f(OnceLock::get_or_init(&METRICS, || {
-            Metrics::new(prometheus::default_registry())
-                .expect("failed to create new metrics; this is a bug in the Sovereign SDK")
-        }));
+/// Sets the global [`Metrics`] instance used by [`update_metrics`], e.g. to
+/// export the rollup metrics through a registry created by
+/// [`Metrics::register_into`].
+///
+/// This must be called before the first call to [`update_metrics`], and
+/// returns an error if the global instance is already initialized.
+pub fn set_global_metrics(metrics: Metrics) -> prometheus::Result<()> {
+    METRICS
+        .set(metrics)
+        .map_err(|_| prometheus::Error::Msg("metrics are already initialized".to_string()))
+}
+
+/// Initializes the global [`Metrics`] instance with a custom [`MetricsConfig`]
+/// if and only if the `native` feature is enabled.
+///
+/// This must be called before the first call to [`update_metrics`], which
+/// otherwise initializes the metrics with the default configuration.
+pub fn init_metrics(config: &MetricsConfig) -> prometheus::Result<()> {
+    if cfg!(feature = "native") {
+        if METRICS.get().is_some() {
+            return Err(prometheus::Error::Msg(
+                "metrics are already initialized".to_string(),
+            ));
+        }
+
+        set_global_metrics(Metrics::with_config(
+            prometheus::default_registry(),
+            config,
+        )?)?;
+    }
+    Ok(())
+}
+
+/// Configuration of the [`Metrics`].
+///
+/// Full nodes read it from the `[runner.metrics]` section of the rollup
+/// configuration.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// The upper bounds, in seconds, of the buckets of
+    /// [`Metrics::slot_processing_seconds`].
+    pub slot_processing_buckets: Vec<f64>,
+}
+
+impl Default for MetricsConfig {
+    /// Buckets range from 1ms to 10s.
+    fn default() -> Self {
+        Self {
+            slot_processing_buckets: vec![
+                0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+            ],
+        }
     }
 }
 
 /// Prometheus metrics for Sovereign rollups.
 ///
-/// Values of this type are only accessible through the [`update_metrics`] function.
+/// The global instance is only accessible through the [`update_metrics`] function.
 #[derive(Debug)]
 pub struct Metrics {
     /// Number of DA blocks processed.
@@ -39,10 +160,50 @@ pub struct Metrics {
     pub rollup_txns_per_da_block: IntGauge,
     /// Current DA height for the rollup.
     pub current_da_height: IntGauge,
+    /// Number of sequencer slashes recorded in the batches committed to the ledger.
+    pub sequencer_slashes_total: IntCounter,
+    /// Time spent applying a DA slot to the rollup state, in seconds.
+    pub slot_processing_seconds: Histogram,
+    /// Number of finalizations which discarded at least one sibling fork.
+    pub reorgs_total: IntCounter,
+    /// Number of blocks discarded by the last reorg.
+    pub last_reorg_depth: IntGauge,
+    /// Gas used by the last block, summed across all gas dimensions.
+    pub block_gas_used: IntGauge,
+    /// Gas limit of the last block, summed across all gas dimensions.
+    pub block_gas_limit: IntGauge,
+    /// Base fee per gas of the last block, summed across all gas dimensions.
+    pub block_base_fee_per_gas: IntGauge,
+    /// Number of notifications dropped because a subscriber lagged behind,
+    /// labelled by `subscription` type.
+    pub subscription_lagged_messages: IntCounterVec,
+    /// Number of batches executed in parallel and merged without conflicts.
+    pub parallel_batches_total: IntCounter,
+    /// Number of batches executed again sequentially because they conflicted
+    /// with a batch executed alongside them.
+    pub parallel_batch_conflicts_total: IntCounter,
+    registry: prometheus::Registry,
 }
 
 impl Metrics {
-    fn new(registry: &prometheus::Registry) -> prometheus::Result<Self> {
+    /// Creates the metrics and registers them into `registry`, using the
+    /// default [`MetricsConfig`].
+    pub fn new(registry: &prometheus::Registry) -> prometheus::Result<Self> {
+        Self::with_config(registry, &MetricsConfig::default())
+    }
+
+    /// Creates the metrics and registers them into `registry`, using the
+    /// default [`MetricsConfig`]. Same as [`Metrics::new`].
+    pub fn register_into(registry: &prometheus::Registry) -> prometheus::Result<Self> {
+        Self::new(registry)
+    }
+
+    /// Creates the metrics and registers them into `registry`, using the
+    /// provided [`MetricsConfig`].
+    pub fn with_config(
+        registry: &prometheus::Registry,
+        config: &MetricsConfig,
+    ) -> prometheus::Result<Self> {
         let da_blocks_processed = register_int_counter_with_registry!(
             "da_blocks_processed",
             "Number of DA blocks processed",
@@ -73,12 +234,149 @@ impl Metrics {
             registry,
         )?;
 
+        let sequencer_slashes_total = register_int_counter_with_registry!(
+            "sequencer_slashes_total",
+            "Number of sequencers slashed",
+            registry,
+        )?;
+
+        let slot_processing_seconds = register_histogram_with_registry!(
+            HistogramOpts::new(
+                "slot_processing_seconds",
+                "Time spent applying a DA slot to the rollup state, in seconds",
+            )
+            .buckets(config.slot_processing_buckets.clone()),
+            registry,
+        )?;
+
+        let reorgs_total = register_int_counter_with_registry!(
+            "reorgs_total",
+            "Number of finalizations which discarded at least one sibling fork",
+            registry,
+        )?;
+
+        let last_reorg_depth = register_int_gauge_with_registry!(
+            "last_reorg_depth",
+            "Number of blocks discarded by the last reorg",
+            registry,
+        )?;
+
+        let block_gas_used = register_int_gauge_with_registry!(
+            "block_gas_used",
+            "Gas used by the last block, summed across all gas dimensions",
+            registry,
+        )?;
+
+        let block_gas_limit = register_int_gauge_with_registry!(
+            "block_gas_limit",
+            "Gas limit of the last block, summed across all gas dimensions",
+            registry,
+        )?;
+
+        let block_base_fee_per_gas = register_int_gauge_with_registry!(
+            "block_base_fee_per_gas",
+            "Base fee per gas of the last block, summed across all gas dimensions",
+            registry,
+        )?;
+
+        let subscription_lagged_messages = register_int_counter_vec_with_registry!(
+            "subscription_lagged_messages",
+            "Number of notifications dropped because a subscriber lagged behind",
+            &["subscription"],
+            registry,
+        )?;
+
+        let parallel_batches_total = register_int_counter_with_registry!(
+            "parallel_batches_total",
+            "Number of batches executed in parallel and merged without conflicts",
+            registry,
+        )?;
+
+        let parallel_batch_conflicts_total = register_int_counter_with_registry!(
+            "parallel_batch_conflicts_total",
+            "Number of batches executed again sequentially because they conflicted with a batch executed alongside them",
+            registry,
+        )?;
+
         Ok(Self {
             da_blocks_processed,
             rollup_batches_processed,
             rollup_txns_processed,
             rollup_txns_per_da_block,
             current_da_height,
+            sequencer_slashes_total,
+            slot_processing_seconds,
+            reorgs_total,
+            last_reorg_depth,
+            block_gas_used,
+            block_gas_limit,
+            block_base_fee_per_gas,
+            subscription_lagged_messages,
+            parallel_batches_total,
+            parallel_batch_conflicts_total,
+            registry: registry.clone(),
         })
     }
+
+    /// Returns the registry the metrics are registered into.
+    pub fn registry(&self) -> &prometheus::Registry {
+        &self.registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::core::Collector;
+
+    use super::*;
+
+    #[test]
+    fn slot_processing_buckets_are_configurable() {
+        let registry = prometheus::Registry::new();
+        let config = MetricsConfig {
+            slot_processing_buckets: vec![0.5, 1.0],
+        };
+        let metrics = Metrics::with_config(&registry, &config).unwrap();
+
+        metrics.slot_processing_seconds.observe(0.7);
+
+        let families = metrics.slot_processing_seconds.collect();
+        let histogram = families[0].get_metric()[0].get_histogram();
+        let buckets = histogram.get_bucket();
+        assert_eq!(histogram.get_sample_count(), 1);
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].get_upper_bound(), 0.5);
+        assert_eq!(buckets[0].get_cumulative_count(), 0);
+        assert_eq!(buckets[1].get_upper_bound(), 1.0);
+        assert_eq!(buckets[1].get_cumulative_count(), 1);
+    }
+
+    #[test]
+    fn metrics_are_registered_into_custom_registry() {
+        let registry = prometheus::Registry::new();
+        let metrics = Metrics::register_into(&registry).unwrap();
+        metrics.current_da_height.set(7);
+
+        let families = registry.gather();
+        let current_da_height = families
+            .iter()
+            .find(|family| family.get_name() == "current_da_height")
+            .expect("metric not registered into the custom registry");
+        assert_eq!(
+            current_da_height.get_metric()[0].get_gauge().get_value(),
+            7.0
+        );
+
+        // Registering twice into the same registry is rejected.
+        assert!(Metrics::register_into(&registry).is_err());
+    }
+
+    #[test]
+    fn global_metrics_can_only_be_set_once() {
+        let registry = prometheus::Registry::new();
+        let _ = set_global_metrics(Metrics::register_into(&registry).unwrap());
+
+        let registry = prometheus::Registry::new();
+        assert!(set_global_metrics(Metrics::register_into(&registry).unwrap()).is_err());
+    }
 }
@@ -0,0 +1,86 @@
+//! Strongly-typed metrics bundles owned by modules.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// A strongly-typed bundle of metrics owned by a module, e.g. the counters of
+/// `sov-bank`, which doesn't need to be part of the core [`crate::Metrics`].
+///
+/// Bundles are accessed through [`module_metrics`], which hands out clones of
+/// a single instance, so they should only hold metric handles.
+pub trait ModuleMetrics: Clone + Send + Sync + 'static {
+    /// Creates the metrics of the bundle and registers them into `registry`.
+    fn register(registry: &prometheus::Registry) -> Self;
+}
+
+/// Returns the [`ModuleMetrics`] bundle of type `M`, creating it and
+/// registering it into the [`crate::global_registry`] on first use.
+///
+/// # Thread safety
+/// This function can be called concurrently from any thread. Bundles are
+/// created while holding a global lock, so [`ModuleMetrics::register`] is
+/// called exactly once per type, and every caller gets a clone of the same
+/// instance, sharing its metrics. As a consequence,
+/// [`ModuleMetrics::register`] must not call [`module_metrics`] itself,
+/// which would deadlock.
+pub fn module_metrics<M: ModuleMetrics>() -> M {
+    static MODULE_METRICS: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> =
+        OnceLock::new();
+
+    let mut bundles = MODULE_METRICS
+        .get_or_init(Default::default)
+        .lock()
+        .expect("module metrics lock poisoned");
+
+    bundles
+        .entry(TypeId::of::<M>())
+        .or_insert_with(|| Box::new(M::register(crate::global_registry())))
+        .downcast_ref::<M>()
+        .expect("module metrics are keyed by their type id")
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::{register_int_counter_with_registry, IntCounter};
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestModuleMetrics {
+        calls: IntCounter,
+    }
+
+    impl ModuleMetrics for TestModuleMetrics {
+        fn register(registry: &prometheus::Registry) -> Self {
+            Self {
+                calls: register_int_counter_with_registry!(
+                    "test_module_calls",
+                    "Number of calls to the test module",
+                    registry,
+                )
+                .unwrap(),
+            }
+        }
+    }
+
+    #[test]
+    fn module_metrics_are_initialized_once() {
+        let handles = (0..4)
+            .map(|_| std::thread::spawn(|| module_metrics::<TestModuleMetrics>().calls.inc()))
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(module_metrics::<TestModuleMetrics>().calls.get(), 4);
+
+        let families = crate::global_registry().gather();
+        let calls = families
+            .iter()
+            .find(|family| family.get_name() == "test_module_calls")
+            .expect("metric not registered into the global registry");
+        assert_eq!(calls.get_metric()[0].get_counter().get_value(), 4.0);
+    }
+}
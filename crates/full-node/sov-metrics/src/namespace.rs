@@ -0,0 +1,216 @@
+//! Custom metrics grouped under a common namespace.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use prometheus::{IntCounter, IntGauge, Opts, Registry};
+
+/// A handle to custom metrics that share a common name prefix and a set of
+/// constant labels, e.g. all the metrics emitted by a single module.
+///
+/// Metrics are lazily created and registered into the
+/// [`crate::global_registry`], which is served by the node, the first time
+/// they are requested, unless another registry is set with
+/// [`MetricsNamespace::with_registry`]. The same instance is returned on
+/// every subsequent request.
+#[derive(Debug, Clone)]
+pub struct MetricsNamespace {
+    namespace: String,
+    const_labels: BTreeMap<String, String>,
+    registry: Option<NamespaceRegistry>,
+}
+
+/// A registry set with [`MetricsNamespace::with_registry`], along with the
+/// metrics registered into it so far.
+#[derive(Debug, Clone)]
+struct NamespaceRegistry {
+    registry: Registry,
+    metrics: Arc<Mutex<CustomMetrics>>,
+}
+
+#[derive(Debug, Default)]
+struct CustomMetrics {
+    counters: HashMap<String, IntCounter>,
+    gauges: HashMap<String, IntGauge>,
+}
+
+fn custom_metrics() -> &'static Mutex<CustomMetrics> {
+    static CUSTOM_METRICS: OnceLock<Mutex<CustomMetrics>> = OnceLock::new();
+    CUSTOM_METRICS.get_or_init(Default::default)
+}
+
+impl MetricsNamespace {
+    /// Creates a new namespace. All metrics created through it will be named
+    /// `<namespace>_<name>`.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            const_labels: BTreeMap::new(),
+            registry: None,
+        }
+    }
+
+    /// Registers the metrics of this namespace into `registry` instead of the
+    /// [`crate::global_registry`]. They are shared by the clones of the
+    /// returned handle.
+    pub fn with_registry(mut self, registry: Registry) -> Self {
+        self.registry = Some(NamespaceRegistry {
+            registry,
+            metrics: Default::default(),
+        });
+        self
+    }
+
+    /// Adds a constant label which is attached to all metrics created through
+    /// this namespace.
+    pub fn with_label(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.const_labels.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns the namespace of this handle.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Returns the constant labels attached to all metrics of this handle.
+    pub fn const_labels(&self) -> &BTreeMap<String, String> {
+        &self.const_labels
+    }
+
+    /// Returns the [`IntCounter`] called `name` within this namespace,
+    /// creating it if necessary.
+    pub fn counter(&self, name: &str) -> IntCounter {
+        let key = self.metric_key(name);
+        let mut metrics = self
+            .custom_metrics()
+            .lock()
+            .expect("custom metrics lock poisoned");
+
+        metrics
+            .counters
+            .entry(key)
+            .or_insert_with(|| {
+                let counter = IntCounter::with_opts(self.opts(name))
+                    .expect("invalid metric name; this is a bug in the caller");
+                self.register(counter.clone());
+                counter
+            })
+            .clone()
+    }
+
+    /// Returns the [`IntGauge`] called `name` within this namespace,
+    /// creating it if necessary.
+    pub fn gauge(&self, name: &str) -> IntGauge {
+        let key = self.metric_key(name);
+        let mut metrics = self
+            .custom_metrics()
+            .lock()
+            .expect("custom metrics lock poisoned");
+
+        metrics
+            .gauges
+            .entry(key)
+            .or_insert_with(|| {
+                let gauge = IntGauge::with_opts(self.opts(name))
+                    .expect("invalid metric name; this is a bug in the caller");
+                self.register(gauge.clone());
+                gauge
+            })
+            .clone()
+    }
+
+    fn custom_metrics(&self) -> &Mutex<CustomMetrics> {
+        match &self.registry {
+            Some(registry) => &registry.metrics,
+            None => custom_metrics(),
+        }
+    }
+
+    fn register(&self, collector: impl prometheus::core::Collector + 'static) {
+        let registry = match &self.registry {
+            Some(registry) => &registry.registry,
+            None => crate::global_registry(),
+        };
+        registry
+            .register(Box::new(collector))
+            .expect("failed to register custom metric; this is a bug in the caller");
+    }
+
+    fn opts(&self, name: &str) -> Opts {
+        Opts::new(
+            name,
+            format!("Custom metric `{}` of `{}`", name, self.namespace),
+        )
+        .namespace(self.namespace.clone())
+        .const_labels(self.const_labels.clone().into_iter().collect())
+    }
+
+    fn metric_key(&self, name: &str) -> String {
+        let labels = self
+            .const_labels
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{}_{}{{{}}}", self.namespace, name, labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::core::Collector;
+
+    use super::*;
+
+    #[test]
+    fn namespaced_counter_is_labelled() {
+        let registry = Registry::new();
+        let metrics = MetricsNamespace::new("bank")
+            .with_label("module", "Bank")
+            .with_registry(registry.clone());
+
+        metrics.counter("transfers").inc();
+        metrics.counter("transfers").inc();
+
+        let counter = metrics.counter("transfers");
+        assert_eq!(counter.get(), 2);
+
+        let desc = &counter.desc()[0];
+        assert_eq!(desc.fq_name, "bank_transfers");
+
+        let families = registry.gather();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].get_name(), "bank_transfers");
+        let metric = &families[0].get_metric()[0];
+        assert_eq!(metric.get_counter().get_value(), 2.0);
+        let labels = metric.get_label();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].get_name(), "module");
+        assert_eq!(labels[0].get_value(), "Bank");
+    }
+
+    #[test]
+    fn namespaced_counters_are_shared_across_threads() {
+        let registry = Registry::new();
+        let namespace = MetricsNamespace::new("test_namespace").with_registry(registry.clone());
+        let handles = (0..4)
+            .map(|_| {
+                let namespace = namespace.clone();
+                std::thread::spawn(move || namespace.counter("calls").inc())
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(namespace.counter("calls").get(), 4);
+        assert_eq!(
+            registry.gather()[0].get_metric()[0]
+                .get_counter()
+                .get_value(),
+            4.0
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use sov_metrics::{metrics_router, Metrics};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn metrics_router_renders_text_exposition_format() {
+    let registry = prometheus::Registry::new();
+    let metrics = Metrics::register_into(&registry).unwrap();
+    metrics.current_da_height.set(7);
+    metrics
+        .subscription_lagged_messages
+        .with_label_values(&["slots"])
+        .inc();
+
+    let response = metrics_router::<()>("/custom/metrics", registry)
+        .oneshot(Request::get("/custom/metrics").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        prometheus::TEXT_FORMAT
+    );
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    for name in [
+        "da_blocks_processed",
+        "rollup_txns_processed",
+        "current_da_height",
+        "sequencer_slashes_total",
+        "slot_processing_seconds",
+        "reorgs_total",
+    ] {
+        assert!(
+            body.contains(&format!("# TYPE {} ", name)),
+            "missing metric `{}` in:\n{}",
+            name,
+            body
+        );
+    }
+    assert!(body.contains("current_da_height 7\n"));
+    assert!(body.contains("subscription_lagged_messages{subscription=\"slots\"} 1\n"));
+    assert!(body.contains("slot_processing_seconds_bucket{le=\"+Inf\"} 0\n"));
+}
@@ -51,6 +51,10 @@ fn setup_storage(
 ) -> TestData {
     let config = sov_state::config::Config {
         path: path.to_path_buf(),
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
 
     let mut storage_manager = ProverStorageManager::<Da, S>::new(config).unwrap();
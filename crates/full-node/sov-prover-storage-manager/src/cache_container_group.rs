@@ -4,6 +4,7 @@ use rockbound::cache::cache_container::CacheContainer;
 use rockbound::cache::cache_db::CacheDb;
 use rockbound::cache::change_set::ChangeSet;
 use rockbound::cache::SnapshotId;
+use rockbound::SchemaBatch;
 
 /// Group of cache containers. For consistent usage of all RwLocks.
 pub(crate) struct CacheContainerRwLockGroup {
@@ -123,6 +124,21 @@ impl<'a> CacheContainerGroupWriteGuard<'a> {
         Ok(())
     }
 
+    /// Writes `ledger_batch` straight to the ledger database, through a snapshot with the unused
+    /// `snapshot_id` and no parent.
+    pub(crate) fn write_ledger_batch(
+        &mut self,
+        snapshot_id: SnapshotId,
+        ledger_batch: SchemaBatch,
+    ) -> anyhow::Result<()> {
+        self.add_snapshot(
+            ChangeSet::new_with_operations(snapshot_id, SchemaBatch::new()),
+            ChangeSet::new_with_operations(snapshot_id, SchemaBatch::new()),
+            ChangeSet::new_with_operations(snapshot_id, ledger_batch),
+        )?;
+        self.commit_snapshot(&snapshot_id)
+    }
+
     // Returns true if snapshot was present and has been discarded
     // or false if it wasn't there.
     pub(crate) fn discard_snapshot(&mut self, snapshot_id: &SnapshotId) -> bool {
@@ -0,0 +1,24 @@
+/// Picks the canonical block among competing siblings, i.e. unfinalized blocks
+/// that share the same parent.
+///
+/// The [`crate::ProverStorageManager`] consults it whenever it has to pick a
+/// canonical child on its own, instead of being told which block to follow.
+pub trait ForkChoice<H>: Send + Sync {
+    /// Returns the canonical block among `siblings`, or `None` if there are none.
+    /// The choice must only depend on the set of `siblings`, and not on their order,
+    /// so that all nodes agree on it.
+    fn choose<'a>(&self, siblings: &'a [H]) -> Option<&'a H>;
+}
+
+/// The default [`ForkChoice`]: the sibling with the lowest block hash, compared
+/// byte-wise, wins.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LowestHashForkChoice;
+
+impl<H: AsRef<[u8]>> ForkChoice<H> for LowestHashForkChoice {
+    fn choose<'a>(&self, siblings: &'a [H]) -> Option<&'a H> {
+        siblings
+            .iter()
+            .min_by(|left, right| left.as_ref().cmp(right.as_ref()))
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, Weak};
 
 use rockbound::cache::cache_container::CacheContainer;
 use rockbound::cache::cache_db::CacheDb;
@@ -10,22 +10,30 @@ use rockbound::cache::SnapshotId;
 use rockbound::{ReadOnlyLock, SchemaBatch};
 use sov_db::accessory_db::AccessoryDb;
 use sov_db::ledger_db::LedgerDb;
+use sov_db::schema::tables::ForkSnapshotById;
+use sov_db::schema::types::{ForkSnapshotId, StoredForkSnapshot};
 use sov_db::state_db::StateDb;
-use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec};
+use sov_rollup_interface::da::{BlockHashTrait, BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::storage::HierarchicalStorageManager;
-use sov_state::{MerkleProofSpec, ProverChangeSet, ProverStorage};
+use sov_state::{MerkleProofSpec, ProverChangeSet, ProverStorage, StateWrites};
 
 use crate::cache_container_group::{CacheContainerRwLockGroup, CacheDbGroup};
 
 mod cache_container_group;
+mod fork_choice;
 #[cfg(feature = "test-utils")]
 mod test_utils;
 
+pub use fork_choice::{ForkChoice, LowestHashForkChoice};
 #[cfg(feature = "test-utils")]
 pub use test_utils::*;
 
 /// Implementation of [`HierarchicalStorageManager`] that handles relation between snapshots
 /// And reorgs on Data Availability layer.
+///
+/// The fork tree of unfinalized blocks and their saved change sets are persisted in the ledger
+/// database with each finalization, so they survive a restart. Blocks provided with state since
+/// the last finalization are lost on restart, and have to be executed again.
 pub struct ProverStorageManager<Da: DaSpec, S: MerkleProofSpec> {
     // L1 forks representation
     // Chain: prev_block -> child_blocks
@@ -43,11 +51,44 @@ pub struct ProverStorageManager<Da: DaSpec, S: MerkleProofSpec> {
     // Same reference for individual managers
     snapshot_id_to_parent: Arc<RwLock<HashMap<SnapshotId, SnapshotId>>>,
 
+    // Blocks discarded by finalization, because they were on a sibling fork, or by pruning.
+    // Their snapshots and parent pointers are kept until no state handed out for them
+    // or their descendants is alive anymore, so these states don't silently read newer
+    // finalized data.
+    orphaned_blocks: HashMap<Da::SlotHash, SnapshotId>,
+
+    // Leases held by the states handed out for each snapshot, see `Self::lease_snapshot`.
+    snapshot_leases: HashMap<SnapshotId, Weak<SnapshotLease>>,
+
+    // The serialized writes of the saved change sets of unfinalized blocks, to be persisted.
+    change_set_writes: HashMap<SnapshotId, Vec<u8>>,
+
+    // The unfinalized blocks persisted in the ledger database, and whether their change set
+    // was persisted with them.
+    persisted_forks: HashMap<SnapshotId, bool>,
+
+    // Snapshots restored after a restart. Their ledger change set is lost, so they are
+    // discarded when their block is executed again.
+    restored_snapshots: HashSet<SnapshotId>,
+
     cache_containers: CacheContainerRwLockGroup,
 
+    // Upper bound on the number of unfinalized fork tips, to bound memory usage.
+    // The oldest forks are discarded when it is exceeded.
+    max_unfinalized_forks: Option<usize>,
+
+    // Picks the canonical child when the manager has to choose one on its own.
+    fork_choice: Box<dyn ForkChoice<Da::SlotHash>>,
+
     phantom_mp_spec: PhantomData<S>,
 }
 
+/// Held by the states handed out by [`ProverStorageManager`] for a snapshot, which keeps the
+/// snapshot readable while it is orphaned.
+struct SnapshotLease {
+    _parent: Option<Arc<SnapshotLease>>,
+}
+
 impl<Da: DaSpec, S: MerkleProofSpec> ProverStorageManager<Da, S>
 where
     Da::SlotHash: Hash,
@@ -57,6 +98,8 @@ where
         accessory_rocksdb: rockbound::DB,
         ledger_rocksdb: rockbound::DB,
     ) -> Self {
+        let fork_snapshots = read_fork_snapshots(&ledger_rocksdb)
+            .expect("Unable to read the unfinalized blocks from the ledger database");
         let snapshot_id_to_parent = Arc::new(RwLock::new(HashMap::new()));
 
         let read_only_snapshot_id_to_parent = ReadOnlyLock::new(snapshot_id_to_parent.clone());
@@ -76,32 +119,282 @@ where
 
         jmt_init::<S>(&cache_containers);
 
-        Self {
+        let mut storage_manager = Self {
             chain_forks: Default::default(),
             blocks_to_parent: Default::default(),
             latest_snapshot_id: 0,
             block_hash_to_snapshot_id: Default::default(),
             dangled_snapshots: Default::default(),
             snapshot_id_to_parent,
+            orphaned_blocks: Default::default(),
+            snapshot_leases: Default::default(),
+            change_set_writes: Default::default(),
+            persisted_forks: Default::default(),
+            restored_snapshots: Default::default(),
             cache_containers,
+            max_unfinalized_forks: None,
+            fork_choice: Box::new(LowestHashForkChoice),
             phantom_mp_spec: Default::default(),
+        };
+        storage_manager.restore_forks(fork_snapshots);
+        storage_manager
+    }
+
+    /// Rebuilds the fork tree from the unfinalized blocks persisted before a restart, in the
+    /// order in which they were first provided with state, and materializes their saved change
+    /// sets again on top of their parents.
+    ///
+    /// Restored blocks keep their snapshot ids. The ledger change sets are not persisted, so
+    /// the restored snapshots have an empty one. They are discarded if their block is provided
+    /// with state again, to be executed and saved again.
+    fn restore_forks(&mut self, fork_snapshots: Vec<(SnapshotId, StoredForkSnapshot)>) {
+        if fork_snapshots.is_empty() {
+            return;
+        }
+        tracing::info!(
+            unfinalized_blocks = fork_snapshots.len(),
+            "Restoring the unfinalized blocks from the ledger database"
+        );
+
+        for (snapshot_id, fork_snapshot) in fork_snapshots {
+            let block_hash: Da::SlotHash = bincode::deserialize(&fork_snapshot.block_hash)
+                .expect("Unable to deserialize the hash of an unfinalized block");
+            let parent_block_hash: Da::SlotHash = bincode::deserialize(&fork_snapshot.parent_hash)
+                .expect("Unable to deserialize the hash of an unfinalized block");
+
+            if let Some(parent_snapshot_id) = self.block_hash_to_snapshot_id.get(&parent_block_hash)
+            {
+                self.snapshot_id_to_parent
+                    .write()
+                    .unwrap()
+                    .insert(snapshot_id, *parent_snapshot_id);
+            }
+            self.block_hash_to_snapshot_id
+                .insert(block_hash.clone(), snapshot_id);
+            self.chain_forks
+                .entry(parent_block_hash.clone())
+                .or_default()
+                .push(block_hash.clone());
+            self.blocks_to_parent.insert(block_hash, parent_block_hash);
+            self.latest_snapshot_id = self.latest_snapshot_id.max(snapshot_id);
+
+            self.persisted_forks
+                .insert(snapshot_id, fork_snapshot.change_set.is_some());
+            if let Some(change_set) = fork_snapshot.change_set {
+                self.restore_change_set(snapshot_id, &change_set);
+                self.change_set_writes.insert(snapshot_id, change_set);
+            }
+        }
+    }
+
+    /// Materializes the persisted writes of the change set of `snapshot_id` on top of its
+    /// parent, which must have been restored before.
+    fn restore_change_set(&mut self, snapshot_id: SnapshotId, change_set: &[u8]) {
+        let writes: StateWrites = bincode::deserialize(change_set)
+            .expect("Unable to deserialize the change set of an unfinalized block");
+        let (storage, _) = self
+            .get_storage_with_snapshot_id(snapshot_id)
+            .expect("Unable to open the storage of an unfinalized block");
+        let ProverChangeSet {
+            state_change_set,
+            accessory_change_set,
+            ..
+        } = storage
+            .rematerialize(&writes)
+            .expect("Unable to restore the change set of an unfinalized block");
+        drop(storage);
+
+        self.cache_containers
+            .write()
+            .add_snapshot(
+                ChangeSet::new_with_operations(snapshot_id, state_change_set),
+                ChangeSet::new_with_operations(snapshot_id, accessory_change_set),
+                ChangeSet::new_with_operations(snapshot_id, SchemaBatch::new()),
+            )
+            .expect("Restored snapshots are added only once");
+        self.restored_snapshots.insert(snapshot_id);
+    }
+
+    /// Collects the changes of the fork tree since the last call into a ledger batch, to be
+    /// committed along with the finalized snapshots, so the fork tree can be restored after a
+    /// restart, see [`Self::restore_forks`].
+    ///
+    /// Returns `None` if the persisted fork tree is up to date. Otherwise the batch comes with
+    /// the id of a fresh snapshot to commit it with.
+    fn fork_snapshots_batch(&mut self) -> Option<(SnapshotId, SchemaBatch)> {
+        let mut batch = SchemaBatch::new();
+        let mut is_empty = true;
+
+        let tracked_snapshot_ids: HashSet<SnapshotId> =
+            self.block_hash_to_snapshot_id.values().copied().collect();
+        self.change_set_writes
+            .retain(|snapshot_id, _| tracked_snapshot_ids.contains(snapshot_id));
+        self.restored_snapshots
+            .retain(|snapshot_id| tracked_snapshot_ids.contains(snapshot_id));
+        self.persisted_forks.retain(|snapshot_id, _| {
+            if tracked_snapshot_ids.contains(snapshot_id) {
+                return true;
+            }
+            batch
+                .delete::<ForkSnapshotById>(&ForkSnapshotId(*snapshot_id))
+                .expect("Unable to serialize the unfinalized block");
+            is_empty = false;
+            false
+        });
+
+        for (block_hash, snapshot_id) in &self.block_hash_to_snapshot_id {
+            let change_set = self.change_set_writes.get(snapshot_id);
+            if self.persisted_forks.get(snapshot_id) == Some(&change_set.is_some()) {
+                continue;
+            }
+            let parent_block_hash = self
+                .blocks_to_parent
+                .get(block_hash)
+                .expect("Inconsistent blocks_to_parent");
+            batch
+                .put::<ForkSnapshotById>(
+                    &ForkSnapshotId(*snapshot_id),
+                    &StoredForkSnapshot {
+                        block_hash: encode_block_hash(block_hash),
+                        parent_hash: encode_block_hash(parent_block_hash),
+                        change_set: change_set.cloned(),
+                    },
+                )
+                .expect("Unable to serialize the unfinalized block");
+            self.persisted_forks
+                .insert(*snapshot_id, change_set.is_some());
+            is_empty = false;
+        }
+
+        if is_empty {
+            return None;
         }
+        self.latest_snapshot_id = self.latest_snapshot_id.wrapping_add(1);
+        Some((self.latest_snapshot_id, batch))
     }
 
     /// Create new [`ProverStorageManager`] from state config.
+    ///
+    /// Each database is opened in its own path if one is configured, and in
+    /// [`sov_state::config::Config::path`] otherwise.
     pub fn new(config: sov_state::config::Config) -> anyhow::Result<Self> {
-        let path = config.path;
+        let state_rocksdb =
+            StateDb::get_rockbound_options().default_setup_db_in_path(config.state_db_path())?;
+        let accessory_rocksdb = AccessoryDb::get_rockbound_options()
+            .default_setup_db_in_path(config.accessory_db_path())?;
+        let ledger_rocksdb =
+            LedgerDb::get_rockbound_options().default_setup_db_in_path(config.ledger_db_path())?;
 
-        let state_rocksdb = StateDb::get_rockbound_options().default_setup_db_in_path(&path)?;
-        let accessory_rocksdb =
-            AccessoryDb::get_rockbound_options().default_setup_db_in_path(&path)?;
-        let ledger_rocksdb = LedgerDb::get_rockbound_options().default_setup_db_in_path(&path)?;
+        let mut storage_manager =
+            Self::with_db_handles(state_rocksdb, accessory_rocksdb, ledger_rocksdb);
+        storage_manager.max_unfinalized_forks = config.max_unfinalized_forks;
+        Ok(storage_manager)
+    }
 
-        Ok(Self::with_db_handles(
-            state_rocksdb,
-            accessory_rocksdb,
-            ledger_rocksdb,
-        ))
+    /// Replaces the [`ForkChoice`] rule, which is [`LowestHashForkChoice`] by default.
+    pub fn with_fork_choice(
+        mut self,
+        fork_choice: impl ForkChoice<Da::SlotHash> + 'static,
+    ) -> Self {
+        self.fork_choice = Box::new(fork_choice);
+        self
+    }
+
+    /// Returns the canonical child of `block_hash` among the blocks that have been provided
+    /// with state, according to the configured [`ForkChoice`].
+    /// Returns `None` if `block_hash` has no known children.
+    pub fn canonical_child(&self, block_hash: &Da::SlotHash) -> Option<&Da::SlotHash> {
+        let children = self.chain_forks.get(block_hash)?;
+        self.fork_choice.choose(children)
+    }
+
+    /// The number of unfinalized forks, i.e. blocks that have been provided with state but
+    /// have no children yet.
+    fn unfinalized_fork_count(&self) -> usize {
+        self.block_hash_to_snapshot_id
+            .keys()
+            .filter(|block_hash| !self.chain_forks.contains_key(block_hash))
+            .count()
+    }
+
+    /// The number of snapshots of blocks that have been provided with state and are not
+    /// finalized yet, including the ones orphaned by the last finalization.
+    pub fn live_snapshot_count(&self) -> usize {
+        self.block_hash_to_snapshot_id.len() + self.orphaned_blocks.len()
+    }
+
+    /// Discards the oldest forks until at most `max_unfinalized_forks` remain. The age of a
+    /// fork is the one of its tip, so forks which keep being extended are kept the longest.
+    /// The fork leading to `head` is never discarded.
+    ///
+    /// Pruned forks are orphaned like the ones discarded by finalization: their snapshots are
+    /// discarded right away unless a state handed out for them is still alive, in which case
+    /// they are discarded once it's dropped. Their blocks can be provided with state again,
+    /// starting from the fork point, once their snapshots are discarded.
+    fn prune_forks(&mut self, head: &Da::SlotHash) {
+        let Some(max_unfinalized_forks) = self.max_unfinalized_forks else {
+            return;
+        };
+        let mut fork_count = self.unfinalized_fork_count();
+        if fork_count <= max_unfinalized_forks {
+            return;
+        }
+
+        let mut tips: Vec<(SnapshotId, Da::SlotHash)> = self
+            .block_hash_to_snapshot_id
+            .iter()
+            .filter(|(block_hash, _)| {
+                *block_hash != head && !self.chain_forks.contains_key(*block_hash)
+            })
+            .map(|(block_hash, snapshot_id)| (*snapshot_id, block_hash.clone()))
+            .collect();
+        tips.sort_unstable_by_key(|(snapshot_id, _)| *snapshot_id);
+
+        let mut discarded_blocks = 0;
+        for (_, tip) in tips {
+            if fork_count <= max_unfinalized_forks {
+                break;
+            }
+            discarded_blocks += self.remove_fork(tip);
+            fork_count -= 1;
+        }
+
+        tracing::debug!(
+            discarded_blocks,
+            max_unfinalized_forks,
+            "Pruned the oldest unfinalized forks"
+        );
+        self.discard_orphaned_snapshots();
+    }
+
+    /// Orphans the blocks of the fork ending at `tip`, up to its fork point, and returns
+    /// their number.
+    fn remove_fork(&mut self, tip: Da::SlotHash) -> usize {
+        let mut discarded_blocks = 0;
+        let mut block_hash = tip;
+        while let Some(snapshot_id) = self.block_hash_to_snapshot_id.remove(&block_hash) {
+            tracing::debug!(snapshot_id, "Orphaning the snapshot");
+            self.orphaned_blocks.insert(block_hash.clone(), snapshot_id);
+            discarded_blocks += 1;
+
+            let parent_block_hash = self
+                .blocks_to_parent
+                .remove(&block_hash)
+                .expect("Inconsistent blocks_to_parent");
+            let siblings = self
+                .chain_forks
+                .get_mut(&parent_block_hash)
+                .expect("Inconsistent chain_forks");
+            siblings.retain(|sibling| sibling != &block_hash);
+            if !siblings.is_empty() {
+                break;
+            }
+
+            // The parent is either finalized or part of the same fork.
+            self.chain_forks.remove(&parent_block_hash);
+            block_hash = parent_block_hash;
+        }
+        discarded_blocks
     }
 
     #[cfg(test)]
@@ -110,11 +403,155 @@ where
             && self.blocks_to_parent.is_empty()
             && self.block_hash_to_snapshot_id.is_empty()
             && self.snapshot_id_to_parent.read().unwrap().is_empty()
+            && self.orphaned_blocks.is_empty()
             && self.cache_containers.is_empty()
     }
 
+    /// Discards all speculative state: every pending fork, saved snapshot and
+    /// observability view. Only data that has already been committed to the
+    /// database remains.
+    ///
+    /// This is a recovery mechanism for when the in-memory fork tree is found
+    /// to be inconsistent. Afterwards, blocks can be re-fetched and reprocessed
+    /// starting from the finalized tip. States previously returned by this
+    /// manager must not be used anymore.
+    pub fn reset_to_finalized(&mut self) -> anyhow::Result<()> {
+        tracing::warn!(
+            pending_blocks = self.block_hash_to_snapshot_id.len(),
+            "Resetting storage manager to the finalized state"
+        );
+
+        let block_snapshots: Vec<_> = self
+            .block_hash_to_snapshot_id
+            .drain()
+            .chain(self.orphaned_blocks.drain())
+            .collect();
+        let fork_snapshots = self.fork_snapshots_batch();
+
+        {
+            let mut cache_containers = self.cache_containers.write();
+            if let Some((snapshot_id, batch)) = fork_snapshots {
+                cache_containers.write_ledger_batch(snapshot_id, batch)?;
+            }
+            for (block_hash, snapshot_id) in block_snapshots {
+                let has_been_discarded = cache_containers.discard_snapshot(&snapshot_id);
+                tracing::debug!(
+                    ?block_hash,
+                    snapshot_id,
+                    ?has_been_discarded,
+                    "Discarding the snapshot"
+                );
+            }
+        }
+
+        self.chain_forks.clear();
+        self.blocks_to_parent.clear();
+        self.dangled_snapshots.clear();
+        self.snapshot_leases.clear();
+        self.snapshot_id_to_parent.write().unwrap().clear();
+        Ok(())
+    }
+
+    /// Discards the snapshots of the orphaned blocks which are not read anymore, i.e. once all
+    /// the states handed out for them and for their descendants have been dropped.
+    ///
+    /// Until then, states handed out for an orphaned fork keep seeing the data of that fork.
+    /// This is called at the beginning of every finalization and after pruning forks.
+    pub fn discard_orphaned_snapshots(&mut self) {
+        self.snapshot_leases
+            .retain(|_, lease| lease.strong_count() > 0);
+        if self.orphaned_blocks.is_empty() {
+            return;
+        }
+
+        let mut released_snapshot_ids = HashSet::new();
+        self.orphaned_blocks.retain(|_, snapshot_id| {
+            let is_leased = self.snapshot_leases.contains_key(snapshot_id);
+            if !is_leased {
+                released_snapshot_ids.insert(*snapshot_id);
+            }
+            is_leased
+        });
+        if !released_snapshot_ids.is_empty() {
+            self.discard_snapshots(released_snapshot_ids);
+        }
+    }
+
+    /// Returns a lease on `snapshot_id`, to be held by the states reading from it.
+    ///
+    /// The lease of a snapshot holds the leases of its unfinalized ancestors, so an orphaned
+    /// snapshot is only discarded once no state reads from it or from its descendants.
+    fn lease_snapshot(&mut self, snapshot_id: SnapshotId) -> Arc<SnapshotLease> {
+        // The snapshots without a live lease, from `snapshot_id` up to the first leased ancestor.
+        let mut unleased_snapshot_ids = Vec::new();
+        let mut parent_lease = None;
+        {
+            let snapshot_id_to_parent = self.snapshot_id_to_parent.read().unwrap();
+            let mut next_snapshot_id = Some(snapshot_id);
+            while let Some(snapshot_id) = next_snapshot_id {
+                if let Some(lease) = self
+                    .snapshot_leases
+                    .get(&snapshot_id)
+                    .and_then(Weak::upgrade)
+                {
+                    parent_lease = Some(lease);
+                    break;
+                }
+                unleased_snapshot_ids.push(snapshot_id);
+                next_snapshot_id = snapshot_id_to_parent.get(&snapshot_id).copied();
+            }
+        }
+
+        for snapshot_id in unleased_snapshot_ids.into_iter().rev() {
+            let lease = Arc::new(SnapshotLease {
+                _parent: parent_lease,
+            });
+            self.snapshot_leases
+                .insert(snapshot_id, Arc::downgrade(&lease));
+            parent_lease = Some(lease);
+        }
+        parent_lease.expect("The requested snapshot is always leased")
+    }
+
+    /// Discards the given snapshots, which must not be referenced by any tracked block anymore,
+    /// along with the parent pointers from and to them.
+    fn discard_snapshots(&self, snapshot_ids: HashSet<SnapshotId>) {
+        let mut cache_containers = self.cache_containers.write();
+        let mut snapshot_id_to_parent = self.snapshot_id_to_parent.write().unwrap();
+
+        for dangled_id in self.dangled_snapshots.iter() {
+            if snapshot_id_to_parent
+                .get(dangled_id)
+                .is_some_and(|parent_id| snapshot_ids.contains(parent_id))
+            {
+                snapshot_id_to_parent.remove(dangled_id);
+            }
+        }
+
+        for snapshot_id in snapshot_ids {
+            snapshot_id_to_parent.remove(&snapshot_id);
+
+            // TODO: This should be addressed in the future.
+            // Ideally non saved back snapshots should be discarded
+            let has_been_discarded = cache_containers.discard_snapshot(&snapshot_id);
+            tracing::debug!(snapshot_id, ?has_been_discarded, "Discarding the snapshot");
+        }
+    }
+
+    /// Fails if `block_hash` belongs to a fork discarded by finalization or pruning, whose
+    /// snapshots are still read.
+    fn ensure_not_orphaned(&self, block_hash: &Da::SlotHash) -> anyhow::Result<()> {
+        if self.orphaned_blocks.contains_key(block_hash) {
+            anyhow::bail!(
+                "Block {} belongs to a fork that has been discarded by finalization or pruning",
+                block_hash
+            );
+        }
+        Ok(())
+    }
+
     fn get_storage_with_snapshot_id(
-        &self,
+        &mut self,
         snapshot_id: SnapshotId,
     ) -> anyhow::Result<(ProverStorage<S>, CacheDb)> {
         let CacheDbGroup {
@@ -126,7 +563,8 @@ where
         let state_db = StateDb::with_cache_db(state_cache_db)?;
         let accessory_db = AccessoryDb::with_cache_db(accessory_cache_db)?;
         Ok((
-            ProverStorage::with_db_handles(state_db, accessory_db),
+            ProverStorage::with_db_handles(state_db, accessory_db)
+                .with_snapshot_lease(self.lease_snapshot(snapshot_id)),
             ledger_cache_db,
         ))
     }
@@ -168,6 +606,9 @@ where
             .remove(&current_block_hash)
             .ok_or(anyhow::anyhow!("Attempt to finalize non existing snapshot"))?;
 
+        self.orphan_siblings(&prev_block_hash, &current_block_hash);
+        let fork_snapshots = self.fork_snapshots_batch();
+
         let mut cache_containers = self.cache_containers.write();
 
         let mut snapshot_id_to_parent = self.snapshot_id_to_parent.write().unwrap();
@@ -177,6 +618,11 @@ where
         cache_containers
             .commit_snapshot(snapshot_id)
             .expect("Unable to commit snapshot");
+        if let Some((fork_snapshot_id, batch)) = fork_snapshots {
+            cache_containers
+                .write_ledger_batch(fork_snapshot_id, batch)
+                .expect("Unable to persist the unfinalized blocks");
+        }
 
         for orphan_id in self.dangled_snapshots.iter() {
             if snapshot_id_to_parent.get(orphan_id) == Some(snapshot_id) {
@@ -184,14 +630,36 @@ where
             }
         }
 
+        // Removing snapshot id pointers for children of this one
+        for child_block_hash in self.chain_forks.get(&current_block_hash).unwrap_or(&vec![]) {
+            let child_snapshot_id = self
+                .block_hash_to_snapshot_id
+                .get(child_block_hash)
+                .unwrap();
+            snapshot_id_to_parent.remove(child_snapshot_id);
+        }
+        drop(snapshot_id_to_parent);
+        drop(cache_containers);
+
+        Ok(())
+    }
+
+    /// Orphans the siblings of `finalized_block_hash` and all their descendants.
+    /// `parent_block_hash` is removed from the fork tree.
+    fn orphan_siblings(
+        &mut self,
+        parent_block_hash: &Da::SlotHash,
+        finalized_block_hash: &Da::SlotHash,
+    ) {
         // All siblings of current snapshot
         let mut to_discard: Vec<_> = self
             .chain_forks
-            .remove(&prev_block_hash)
+            .remove(parent_block_hash)
             .expect("Inconsistent chain_forks")
             .into_iter()
-            .filter(|bh| bh != &current_block_hash)
+            .filter(|bh| bh != finalized_block_hash)
             .collect();
+        let mut discarded_blocks = 0;
 
         while let Some(block_hash) = to_discard.pop() {
             let child_block_hashes = self.chain_forks.remove(&block_hash).unwrap_or_default();
@@ -199,30 +667,111 @@ where
 
             let snapshot_id = self.block_hash_to_snapshot_id.remove(&block_hash).unwrap();
 
-            for orphan_id in self.dangled_snapshots.iter() {
-                if snapshot_id_to_parent.get(orphan_id) == Some(&snapshot_id) {
-                    snapshot_id_to_parent.remove(orphan_id);
-                }
+            // The snapshot and the pointers to it are only dropped once the states reading
+            // from it are released, see `Self::discard_orphaned_snapshots`.
+            tracing::debug!(snapshot_id, "Orphaning the snapshot");
+            self.orphaned_blocks.insert(block_hash, snapshot_id);
+            discarded_blocks += 1;
+            to_discard.extend(child_block_hashes);
+        }
+
+        if discarded_blocks > 0 {
+            sov_metrics::update_metrics(|metrics| {
+                metrics.reorgs_total.inc();
+                metrics.last_reorg_depth.set(discarded_blocks);
+            });
+        }
+    }
+
+    /// Finalizes `block_header` along with all its unfinalized ancestors, in a single pass.
+    ///
+    /// This is equivalent to [`HierarchicalStorageManager::finalize`], which also finalizes
+    /// the ancestors of the block, but the chain of ancestors is collected once and all their
+    /// snapshots are committed while holding the cache containers lock only once. It is meant
+    /// for catching up with many blocks at once, e.g. when syncing historical blocks.
+    ///
+    /// Fails without finalizing anything if one of the blocks hasn't been saved yet.
+    pub fn finalize_up_to(&mut self, block_header: &Da::BlockHeader) -> anyhow::Result<()> {
+        tracing::debug!(block_header = %block_header.display(), "Finalizing blocks up to");
+        // The unfinalized chain ending at the block, from the oldest block.
+        let mut chain: Vec<(Da::SlotHash, Da::SlotHash, SnapshotId)> = Vec::new();
+        let mut block_hash = block_header.hash();
+        loop {
+            let snapshot_id = *self
+                .block_hash_to_snapshot_id
+                .get(&block_hash)
+                .ok_or(anyhow::anyhow!("Attempt to finalize non existing snapshot"))?;
+            if !self.cache_containers.contains_snapshot(&snapshot_id) {
+                anyhow::bail!("Attempt to finalize snapshot which hasn't been saved yet");
+            }
+            let parent_block_hash = self
+                .blocks_to_parent
+                .get(&block_hash)
+                .expect("Inconsistent blocks_to_parent")
+                .clone();
+            let is_oldest = !self
+                .block_hash_to_snapshot_id
+                .contains_key(&parent_block_hash);
+            chain.push((parent_block_hash.clone(), block_hash, snapshot_id));
+            if is_oldest {
+                break;
             }
+            block_hash = parent_block_hash;
+        }
+        chain.reverse();
 
-            snapshot_id_to_parent.remove(&snapshot_id);
+        self.discard_orphaned_snapshots();
 
-            // TODO: This should be addressed in the future.
-            // Ideally non saved back snapshots should be discarded
-            let has_been_discarded = cache_containers.discard_snapshot(&snapshot_id);
-            tracing::debug!(snapshot_id, ?has_been_discarded, "Discarding the snapshot");
-            to_discard.extend(child_block_hashes);
+        for (parent_block_hash, block_hash, _) in &chain {
+            self.blocks_to_parent.remove(block_hash);
+            self.block_hash_to_snapshot_id.remove(block_hash);
+            self.orphan_siblings(parent_block_hash, block_hash);
         }
+        let fork_snapshots = self.fork_snapshots_batch();
 
-        // Removing snapshot id pointers for children of this one
-        for child_block_hash in self.chain_forks.get(&current_block_hash).unwrap_or(&vec![]) {
-            let child_snapshot_id = self
-                .block_hash_to_snapshot_id
-                .get(child_block_hash)
-                .unwrap();
-            snapshot_id_to_parent.remove(child_snapshot_id);
+        {
+            let mut cache_containers = self.cache_containers.write();
+            let mut snapshot_id_to_parent = self.snapshot_id_to_parent.write().unwrap();
+            for (_, _, snapshot_id) in &chain {
+                snapshot_id_to_parent.remove(snapshot_id);
+                cache_containers
+                    .commit_snapshot(snapshot_id)
+                    .expect("Unable to commit snapshot");
+            }
+            if let Some((fork_snapshot_id, batch)) = fork_snapshots {
+                cache_containers
+                    .write_ledger_batch(fork_snapshot_id, batch)
+                    .expect("Unable to persist the unfinalized blocks");
+            }
+
+            let finalized_snapshot_ids: HashSet<SnapshotId> = chain
+                .iter()
+                .map(|(_, _, snapshot_id)| *snapshot_id)
+                .collect();
+            for orphan_id in self.dangled_snapshots.iter() {
+                if snapshot_id_to_parent
+                    .get(orphan_id)
+                    .is_some_and(|parent_id| finalized_snapshot_ids.contains(parent_id))
+                {
+                    snapshot_id_to_parent.remove(orphan_id);
+                }
+            }
+
+            // Removing snapshot id pointers for children of the last finalized block
+            let (_, last_block_hash, _) = chain.last().expect("The chain can't be empty");
+            for child_block_hash in self.chain_forks.get(last_block_hash).unwrap_or(&vec![]) {
+                let child_snapshot_id = self
+                    .block_hash_to_snapshot_id
+                    .get(child_block_hash)
+                    .expect("Inconsistent chain_forks");
+                snapshot_id_to_parent.remove(child_snapshot_id);
+            }
         }
 
+        tracing::debug!(
+            finalized_blocks = chain.len(),
+            "Finalized the chain of blocks"
+        );
         Ok(())
     }
 }
@@ -266,6 +815,8 @@ where
             current_block_hash, prev_block_hash,
             "Cannot provide storage for corrupt block: prev_hash == current_hash"
         );
+        self.ensure_not_orphaned(&prev_block_hash)?;
+        self.ensure_not_orphaned(&current_block_hash)?;
         if let Some(prev_snapshot_id) = self.block_hash_to_snapshot_id.get(&prev_block_hash) {
             if !self.cache_containers.contains_snapshot(prev_snapshot_id) {
                 anyhow::bail!("Snapshot for previous block has not been saved yet");
@@ -274,7 +825,14 @@ where
 
         let new_snapshot_id = match self.block_hash_to_snapshot_id.get(&current_block_hash) {
             // Storage for this block has been requested before
-            Some(snapshot_id) => *snapshot_id,
+            Some(snapshot_id) => {
+                // A restored block which is executed again is executed on top of its parent,
+                // and gets its change set saved again along with its ledger change set.
+                if self.restored_snapshots.remove(snapshot_id) {
+                    self.cache_containers.write().discard_snapshot(snapshot_id);
+                }
+                *snapshot_id
+            }
             // Storage requested first time
             None => {
                 let new_snapshot_id = self.latest_snapshot_id.wrapping_add(1);
@@ -294,10 +852,12 @@ where
                     .push(current_block_hash.clone());
 
                 self.blocks_to_parent
-                    .insert(current_block_hash, prev_block_hash);
+                    .insert(current_block_hash.clone(), prev_block_hash.clone());
 
                 // Update latest snapshot id
                 self.latest_snapshot_id = new_snapshot_id;
+
+                self.prune_forks(&current_block_hash);
                 new_snapshot_id
             }
         };
@@ -320,6 +880,7 @@ where
             current_block_hash, prev_block_hash,
             "Cannot provide storage for corrupt block: prev_hash == current_hash"
         );
+        self.ensure_not_orphaned(&current_block_hash)?;
 
         let parent_snapshot_id = match self.block_hash_to_snapshot_id.get(&current_block_hash) {
             None => anyhow::bail!("Snapshot for current block has been saved yet"),
@@ -345,19 +906,7 @@ where
         }
         self.dangled_snapshots.insert(new_snapshot_id);
 
-        let CacheDbGroup {
-            state: state_cache_db,
-            accessory: accessory_cache_db,
-            ledger: ledger_cache_db,
-        } = self.cache_containers.get_cache_db_group(new_snapshot_id);
-
-        let state_db = StateDb::with_cache_db(state_cache_db)?;
-        let accessory_db = AccessoryDb::with_cache_db(accessory_cache_db)?;
-
-        Ok((
-            ProverStorage::with_db_handles(state_db, accessory_db),
-            ledger_cache_db,
-        ))
+        self.get_storage_with_snapshot_id(new_snapshot_id)
     }
 
     fn save_change_set(
@@ -378,11 +927,6 @@ where
             "Saving the ProverChangeSet"
         );
 
-        let ProverChangeSet {
-            state_change_set,
-            accessory_change_set,
-        } = stf_change_set;
-
         let snapshot_id = *self
             .block_hash_to_snapshot_id
             .get(&block_header.hash())
@@ -391,6 +935,24 @@ where
                 block_header.display(),
             ))?;
 
+        match stf_change_set.writes() {
+            Some(writes) => {
+                self.change_set_writes.insert(
+                    snapshot_id,
+                    bincode::serialize(writes).expect("Serialization to vec is infallible"),
+                );
+            }
+            None => {
+                self.change_set_writes.remove(&snapshot_id);
+            }
+        }
+
+        let ProverChangeSet {
+            state_change_set,
+            accessory_change_set,
+            ..
+        } = stf_change_set;
+
         // Just wrapping in a ChangeSet with id for given block.
         // This is done for compatibility with existing ProverStorageManager.
         // It should be addressed in the future.
@@ -417,10 +979,28 @@ where
         tracing::debug!(block_header = %block_header.display(), "Finalizing block");
         let current_block_hash = block_header.hash();
         let prev_block_hash = block_header.prev_hash();
+        self.discard_orphaned_snapshots();
         self.finalize_by_hash_pair(prev_block_hash, current_block_hash)
     }
 }
 
+fn encode_block_hash<H: BlockHashTrait>(block_hash: &H) -> Vec<u8> {
+    bincode::serialize(block_hash).expect("Serialization to vec is infallible")
+}
+
+/// Reads the unfinalized blocks persisted by [`ProverStorageManager::fork_snapshots_batch`],
+/// ordered by snapshot id.
+fn read_fork_snapshots(
+    ledger_rocksdb: &rockbound::DB,
+) -> anyhow::Result<Vec<(SnapshotId, StoredForkSnapshot)>> {
+    let mut fork_snapshots = Vec::new();
+    for item in ledger_rocksdb.iter::<ForkSnapshotById>()? {
+        let item = item?;
+        fork_snapshots.push((item.key.0, item.value));
+    }
+    Ok(fork_snapshots)
+}
+
 pub(crate) fn jmt_init<S: MerkleProofSpec>(cache_containers: &CacheContainerRwLockGroup) {
     let CacheDbGroup {
         state: state_cache_db,
@@ -431,6 +1011,7 @@ pub(crate) fn jmt_init<S: MerkleProofSpec>(cache_containers: &CacheContainerRwLo
     if let Some(ProverChangeSet {
         state_change_set,
         accessory_change_set,
+        ..
     }) = ProverStorage::<S>::should_init_db(&StateDb::with_cache_db(state_cache_db).unwrap())
     {
         let state_change_set = ChangeSet::new_with_operations(0, state_change_set);
@@ -451,7 +1032,9 @@ mod tests {
     use sov_mock_da::{MockBlockHeader, MockHash};
     use sov_rollup_interface::da::Time;
     use sov_state::namespaces::User;
-    use sov_state::{ArrayWitness, OrderedReadsAndWrites, StateAccesses, StateUpdate, Storage};
+    use sov_state::{
+        ArrayWitness, NativeStorage, OrderedReadsAndWrites, StateAccesses, StateUpdate, Storage,
+    };
 
     use super::*;
 
@@ -568,6 +1151,28 @@ mod tests {
         validate_internal_consistency(&storage_manager);
     }
 
+    #[test]
+    fn databases_are_opened_in_their_configured_paths() {
+        let base_dir = tempfile::tempdir().unwrap();
+        let state_dir = tempfile::tempdir().unwrap();
+        let ledger_dir = tempfile::tempdir().unwrap();
+        let storage_config = sov_state::config::Config {
+            path: base_dir.path().to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: Some(state_dir.path().to_path_buf()),
+            accessory_path: None,
+            ledger_path: Some(ledger_dir.path().to_path_buf()),
+        };
+        let _storage_manager = ProverStorageManager::<Da, S>::new(storage_config).unwrap();
+
+        assert!(state_dir.path().join("state").is_dir());
+        assert!(ledger_dir.path().join("ledger").is_dir());
+        // The accessory database isn't overridden, so it stays in the shared path.
+        assert!(base_dir.path().join("accessory").is_dir());
+        assert!(!base_dir.path().join("state").exists());
+        assert!(!base_dir.path().join("ledger").exists());
+    }
+
     #[test]
     fn get_new_storage() {
         let tmpdir = tempfile::tempdir().unwrap();
@@ -800,28 +1405,158 @@ mod tests {
     }
 
     #[test]
-    fn try_create_storage_after_before_change_set_saved() {
+    fn fork_tree_is_restored_after_restart() {
+        // 1 -> A -> B -> D
+        //        \-> C
         let tmpdir = tempfile::tempdir().unwrap();
+        let block_a = MockBlockHeader::from_height(1);
+        let block_b = MockBlockHeader {
+            prev_hash: block_a.hash,
+            hash: MockHash::from([3; 32]),
+            height: 2,
+            time: Time::now(),
+        };
+        let block_c = MockBlockHeader {
+            prev_hash: block_a.hash,
+            hash: MockHash::from([4; 32]),
+            height: 2,
+            time: Time::now(),
+        };
+        let block_d = MockBlockHeader {
+            prev_hash: block_b.hash,
+            hash: MockHash::from([5; 32]),
+            height: 3,
+            time: Time::now(),
+        };
+        let witness = ArrayWitness::default();
 
-        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+        let (chain_forks, blocks_to_parent, block_hash_to_snapshot_id, snapshot_id_to_parent) = {
+            let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+            let mut storage_manager =
+                ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
 
-        let mut storage_manager =
-            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
-        assert!(storage_manager.is_empty());
+            let (stf_state, _) = storage_manager.create_state_for(&block_a).unwrap();
+            let change_set = materialize_change_set(&stf_state, &witness, &[(1, Some(10))], &[]);
+            storage_manager
+                .save_change_set(&block_a, change_set, SchemaBatch::new())
+                .unwrap();
+            let (stf_state, _) = storage_manager.create_state_for(&block_b).unwrap();
+            let change_set = materialize_change_set(&stf_state, &witness, &[(2, Some(20))], &[]);
+            storage_manager
+                .save_change_set(&block_b, change_set, SchemaBatch::new())
+                .unwrap();
+            storage_manager.create_state_for(&block_c).unwrap();
+            storage_manager.create_state_for(&block_d).unwrap();
+            storage_manager.finalize(&block_a).unwrap();
+            validate_internal_consistency(&storage_manager);
 
-        let block_header = MockBlockHeader {
-            prev_hash: MockHash::from([1; 32]),
-            hash: MockHash::from([2; 32]),
-            height: 1,
-            time: Time::now(),
+            let snapshot_id_to_parent = storage_manager
+                .snapshot_id_to_parent
+                .read()
+                .unwrap()
+                .clone();
+            (
+                storage_manager.chain_forks.clone(),
+                storage_manager.blocks_to_parent.clone(),
+                storage_manager.block_hash_to_snapshot_id.clone(),
+                snapshot_id_to_parent,
+            )
         };
 
-        assert!(storage_manager.is_empty());
-        let _storage = storage_manager.create_state_for(&block_header).unwrap();
-        assert!(!storage_manager.is_empty());
-
-        let result = storage_manager.create_state_after(&block_header);
-        validate_internal_consistency(&storage_manager);
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        validate_internal_consistency(&storage_manager);
+        assert_eq!(chain_forks, storage_manager.chain_forks);
+        assert_eq!(blocks_to_parent, storage_manager.blocks_to_parent);
+        assert_eq!(
+            block_hash_to_snapshot_id,
+            storage_manager.block_hash_to_snapshot_id
+        );
+        assert_eq!(
+            snapshot_id_to_parent,
+            *storage_manager.snapshot_id_to_parent.read().unwrap()
+        );
+        assert_eq!(
+            block_hash_to_snapshot_id.values().max(),
+            Some(&storage_manager.latest_snapshot_id)
+        );
+
+        // The change set of block B survived the restart.
+        let (stf_state_after, _) = storage_manager.create_state_after(&block_b).unwrap();
+        assert_eq!(
+            Some(value_from(20)),
+            stf_state_after.get::<User>(&key_from(2), None, &witness)
+        );
+        assert_eq!(
+            Some(value_from(10)),
+            stf_state_after.get::<User>(&key_from(1), None, &witness)
+        );
+        drop(stf_state_after);
+
+        // Block B can still be executed again, on top of block A only.
+        let (stf_state, _) = storage_manager.create_state_for(&block_b).unwrap();
+        assert_eq!(
+            Some(value_from(10)),
+            stf_state.get::<User>(&key_from(1), None, &witness)
+        );
+        assert_eq!(None, stf_state.get::<User>(&key_from(2), None, &witness));
+        let change_set = materialize_change_set(&stf_state, &witness, &[(2, Some(20))], &[]);
+        storage_manager
+            .save_change_set(&block_b, change_set, SchemaBatch::new())
+            .unwrap();
+        assert_eq!(
+            block_hash_to_snapshot_id,
+            storage_manager.block_hash_to_snapshot_id
+        );
+
+        let (stf_state_after, _) = storage_manager.create_state_after(&block_b).unwrap();
+        assert_eq!(
+            Some(value_from(20)),
+            stf_state_after.get::<User>(&key_from(2), None, &witness)
+        );
+        assert_eq!(
+            Some(value_from(10)),
+            stf_state_after.get::<User>(&key_from(1), None, &witness)
+        );
+        validate_internal_consistency(&storage_manager);
+
+        // Finalized blocks are not restored again.
+        storage_manager.finalize(&block_b).unwrap();
+        drop((stf_state, stf_state_after, storage_manager));
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+        let storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        assert_eq!(
+            HashMap::from([(block_d.hash, block_hash_to_snapshot_id[&block_d.hash])]),
+            storage_manager.block_hash_to_snapshot_id
+        );
+        validate_internal_consistency(&storage_manager);
+    }
+
+    #[test]
+    fn try_create_storage_after_before_change_set_saved() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        assert!(storage_manager.is_empty());
+
+        let block_header = MockBlockHeader {
+            prev_hash: MockHash::from([1; 32]),
+            hash: MockHash::from([2; 32]),
+            height: 1,
+            time: Time::now(),
+        };
+
+        assert!(storage_manager.is_empty());
+        let _storage = storage_manager.create_state_for(&block_header).unwrap();
+        assert!(!storage_manager.is_empty());
+
+        let result = storage_manager.create_state_after(&block_header);
+        validate_internal_consistency(&storage_manager);
         assert!(result.is_err());
     }
 
@@ -864,6 +1599,75 @@ mod tests {
         assert!(storage_manager.is_empty());
     }
 
+    #[test]
+    fn linear_progression_finalized_at_once() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        assert!(storage_manager.is_empty());
+
+        let block_from_i = |i: u8| MockBlockHeader {
+            prev_hash: MockHash::from([i; 32]),
+            hash: MockHash::from([i + 1; 32]),
+            height: i as u64 + 1,
+            time: Time::now(),
+        };
+        let witness = ArrayWitness::default();
+
+        for i in 0u8..100 {
+            let block = block_from_i(i);
+            let (stf_state, _) = storage_manager.create_state_for(&block).unwrap();
+            let state_change_set =
+                materialize_change_set(&stf_state, &witness, &[(i as u64, Some(i as u64))], &[]);
+            storage_manager
+                .save_change_set(&block, state_change_set, SchemaBatch::new())
+                .unwrap();
+        }
+
+        // The last block hasn't been saved, so nothing is finalized.
+        let unsaved_block = block_from_i(100);
+        storage_manager.create_state_for(&unsaved_block).unwrap();
+        let result = storage_manager.finalize_up_to(&unsaved_block);
+        assert_eq!(
+            "Attempt to finalize snapshot which hasn't been saved yet",
+            result.unwrap_err().to_string()
+        );
+        assert_eq!(101, storage_manager.block_hash_to_snapshot_id.len());
+
+        storage_manager.finalize_up_to(&block_from_i(99)).unwrap();
+        validate_internal_consistency(&storage_manager);
+        assert_eq!(
+            vec![&unsaved_block.hash],
+            storage_manager
+                .block_hash_to_snapshot_id
+                .keys()
+                .collect::<Vec<_>>()
+        );
+        assert!(storage_manager
+            .snapshot_id_to_parent
+            .read()
+            .unwrap()
+            .is_empty());
+
+        let (stf_state, _) = storage_manager.create_state_for(&unsaved_block).unwrap();
+        for i in 0u64..100 {
+            assert_eq!(
+                Some(value_from(i)),
+                stf_state.get::<User>(&key_from(i), None, &witness)
+            );
+        }
+        let state_change_set = materialize_change_set(&stf_state, &witness, &[], &[]);
+        storage_manager
+            .save_change_set(&unsaved_block, state_change_set, SchemaBatch::new())
+            .unwrap();
+        storage_manager.finalize_up_to(&unsaved_block).unwrap();
+        validate_internal_consistency(&storage_manager);
+        assert!(storage_manager.is_empty());
+    }
+
     #[test]
     fn parallel_forks() {
         let tmpdir = tempfile::tempdir().unwrap();
@@ -918,6 +1722,384 @@ mod tests {
         assert!(storage_manager.is_empty());
     }
 
+    #[test]
+    fn max_unfinalized_forks_prunes_oldest_forks() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        storage_manager.max_unfinalized_forks = Some(2);
+
+        let mut add_block = |height: u64, prev_hash: u8, hash: u8| {
+            let block = MockBlockHeader {
+                prev_hash: MockHash::from([prev_hash; 32]),
+                hash: MockHash::from([hash; 32]),
+                height,
+                time: Time::now(),
+            };
+            let (stf_state, _) = storage_manager.create_state_for(&block).unwrap();
+            let stf_change_set = materialize_change_set(&stf_state, &Default::default(), &[], &[]);
+            storage_manager
+                .save_change_set(&block, stf_change_set, SchemaBatch::new())
+                .unwrap();
+        };
+
+        // 1    2    3
+        // / -> D (pruned)
+        // A -> B -> C
+        // \ -> F
+        add_block(1, 0, 1); // A
+        add_block(2, 1, 2); // B
+        add_block(2, 1, 12); // D
+        add_block(3, 2, 3); // C
+
+        // Extending an existing fork doesn't prune anything.
+        assert_eq!(storage_manager.unfinalized_fork_count(), 2);
+        assert_eq!(storage_manager.live_snapshot_count(), 4);
+
+        // D is the oldest fork tip, C has been created after it.
+        add_block(2, 1, 22); // F
+        assert_eq!(storage_manager.unfinalized_fork_count(), 2);
+        assert_eq!(storage_manager.live_snapshot_count(), 4);
+        assert!(!storage_manager
+            .block_hash_to_snapshot_id
+            .contains_key(&MockHash::from([12; 32])));
+        validate_internal_consistency(&storage_manager);
+
+        // Finalizing B orphans F, which leaves C as the only fork.
+        for (height, prev_hash, hash) in [(1, 0, 1), (2, 1, 2)] {
+            let block = MockBlockHeader {
+                prev_hash: MockHash::from([prev_hash; 32]),
+                hash: MockHash::from([hash; 32]),
+                height,
+                time: Time::now(),
+            };
+            storage_manager.finalize(&block).unwrap();
+        }
+        assert_eq!(storage_manager.unfinalized_fork_count(), 1);
+
+        let block = MockBlockHeader {
+            prev_hash: MockHash::from([2; 32]),
+            hash: MockHash::from([32; 32]),
+            height: 3,
+            time: Time::now(),
+        };
+        storage_manager.create_state_for(&block).unwrap();
+        assert_eq!(storage_manager.unfinalized_fork_count(), 2);
+        validate_internal_consistency(&storage_manager);
+    }
+
+    #[test]
+    fn max_unfinalized_forks_bounds_live_snapshots() {
+        const MAX_UNFINALIZED_FORKS: usize = 10;
+        const FORKS: u64 = 1000;
+
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        storage_manager.max_unfinalized_forks = Some(MAX_UNFINALIZED_FORKS);
+
+        let fork_hash = |fork: u64| {
+            let mut hash = [0xff; 32];
+            hash[..8].copy_from_slice(&fork.to_be_bytes());
+            MockHash::from(hash)
+        };
+
+        let block_a = MockBlockHeader {
+            prev_hash: MockHash::from([0; 32]),
+            hash: MockHash::from([1; 32]),
+            height: 1,
+            time: Time::now(),
+        };
+        let (stf_state, _) = storage_manager.create_state_for(&block_a).unwrap();
+        let stf_change_set = materialize_change_set(&stf_state, &Default::default(), &[], &[]);
+        storage_manager
+            .save_change_set(&block_a, stf_change_set, SchemaBatch::new())
+            .unwrap();
+
+        // Every fork writes its own number, so forks can be told apart.
+        for fork in 0..FORKS {
+            let block = MockBlockHeader {
+                prev_hash: block_a.hash,
+                hash: fork_hash(fork),
+                height: 2,
+                time: Time::now(),
+            };
+            let (stf_state, _) = storage_manager.create_state_for(&block).unwrap();
+            let stf_change_set =
+                materialize_change_set(&stf_state, &Default::default(), &[(1, Some(fork))], &[]);
+            storage_manager
+                .save_change_set(&block, stf_change_set, SchemaBatch::new())
+                .unwrap();
+
+            assert!(storage_manager.unfinalized_fork_count() <= MAX_UNFINALIZED_FORKS);
+            assert!(storage_manager.live_snapshot_count() <= MAX_UNFINALIZED_FORKS + 1);
+        }
+        validate_internal_consistency(&storage_manager);
+
+        // The most recent forks are kept, on top of their common ancestor.
+        assert_eq!(
+            storage_manager.live_snapshot_count(),
+            MAX_UNFINALIZED_FORKS + 1
+        );
+        for fork in 0..FORKS {
+            let is_live = storage_manager
+                .block_hash_to_snapshot_id
+                .contains_key(&fork_hash(fork));
+            assert_eq!(is_live, fork >= FORKS - MAX_UNFINALIZED_FORKS as u64);
+        }
+
+        // The last fork still sees its own data and can be finalized.
+        let last_fork = MockBlockHeader {
+            prev_hash: block_a.hash,
+            hash: fork_hash(FORKS - 1),
+            height: 2,
+            time: Time::now(),
+        };
+        let (stf_state, _) = storage_manager.create_state_after(&last_fork).unwrap();
+        assert_eq!(
+            stf_state.get::<User>(&key_from(1), None, &Default::default()),
+            Some(value_from(FORKS - 1))
+        );
+
+        storage_manager.finalize(&block_a).unwrap();
+        storage_manager.finalize(&last_fork).unwrap();
+        storage_manager.discard_orphaned_snapshots();
+        assert_eq!(storage_manager.live_snapshot_count(), 0);
+        validate_internal_consistency(&storage_manager);
+    }
+
+    #[test]
+    fn pruned_fork_is_kept_while_its_state_is_alive() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        storage_manager.max_unfinalized_forks = Some(1);
+        let witness = ArrayWitness::default();
+
+        // 1    2
+        // A -> B (pruned)
+        // \ -> C
+        let block_a = MockBlockHeader::from_height(1);
+        let block_b = MockBlockHeader {
+            prev_hash: block_a.hash,
+            hash: MockHash::from([2; 32]),
+            height: 2,
+            time: Time::now(),
+        };
+        let block_c = MockBlockHeader {
+            prev_hash: block_a.hash,
+            hash: MockHash::from([12; 32]),
+            height: 2,
+            time: Time::now(),
+        };
+        let mut add_block = |block: &MockBlockHeader, key: u64| {
+            let (stf_state, _) = storage_manager.create_state_for(block).unwrap();
+            let stf_change_set =
+                materialize_change_set(&stf_state, &witness, &[(key, Some(key * 10))], &[]);
+            storage_manager
+                .save_change_set(block, stf_change_set, SchemaBatch::new())
+                .unwrap();
+        };
+        add_block(&block_a, 1);
+        add_block(&block_b, 2);
+
+        let (stf_state_after_b, _) = storage_manager.create_state_after(&block_b).unwrap();
+
+        // Adding C prunes B, whose snapshot is still read.
+        let (stf_state, _) = storage_manager.create_state_for(&block_c).unwrap();
+        let stf_change_set = materialize_change_set(&stf_state, &witness, &[(12, Some(120))], &[]);
+        storage_manager
+            .save_change_set(&block_c, stf_change_set, SchemaBatch::new())
+            .unwrap();
+        assert_eq!(storage_manager.unfinalized_fork_count(), 1);
+        assert!(!storage_manager
+            .block_hash_to_snapshot_id
+            .contains_key(&block_b.hash));
+        assert_eq!(storage_manager.live_snapshot_count(), 3);
+        assert!(storage_manager.create_state_after(&block_b).is_err());
+        validate_internal_consistency(&storage_manager);
+
+        assert_eq!(
+            Some(value_from(20)),
+            stf_state_after_b.get::<User>(&key_from(2), None, &witness)
+        );
+        assert_eq!(
+            Some(value_from(10)),
+            stf_state_after_b.get::<User>(&key_from(1), None, &witness)
+        );
+        assert_eq!(
+            None,
+            stf_state_after_b.get::<User>(&key_from(12), None, &witness)
+        );
+
+        // The snapshot of B is discarded once its state is dropped.
+        drop((stf_state, stf_state_after_b));
+        storage_manager.discard_orphaned_snapshots();
+        assert_eq!(storage_manager.live_snapshot_count(), 2);
+        validate_internal_consistency(&storage_manager);
+
+        storage_manager.finalize(&block_a).unwrap();
+        storage_manager.finalize(&block_c).unwrap();
+        storage_manager.discard_orphaned_snapshots();
+        assert!(storage_manager.is_empty());
+    }
+
+    #[test]
+    fn fork_choice_is_deterministic_among_siblings() {
+        /// Picks the highest block hash instead of the lowest one.
+        struct HighestHashForkChoice;
+
+        impl ForkChoice<MockHash> for HighestHashForkChoice {
+            fn choose<'a>(&self, siblings: &'a [MockHash]) -> Option<&'a MockHash> {
+                siblings.iter().max_by(|l, r| l.as_ref().cmp(r.as_ref()))
+            }
+        }
+
+        let add_siblings = |storage_manager: &mut ProverStorageManager<Da, S>,
+                            sibling_hashes: &[u8]| {
+            for (prev_hash, hash) in
+                std::iter::once((0, 1)).chain(sibling_hashes.iter().map(|hash| (1, *hash)))
+            {
+                let block = MockBlockHeader {
+                    prev_hash: MockHash::from([prev_hash; 32]),
+                    hash: MockHash::from([hash; 32]),
+                    height: u64::from(prev_hash) + 1,
+                    time: Time::now(),
+                };
+                let (stf_state, _) = storage_manager.create_state_for(&block).unwrap();
+                let stf_change_set =
+                    materialize_change_set(&stf_state, &Default::default(), &[], &[]);
+                storage_manager
+                    .save_change_set(&block, stf_change_set, SchemaBatch::new())
+                    .unwrap();
+            }
+        };
+
+        // 1    2
+        // / -> D
+        // A -> B
+        // \ -> F
+        // The siblings of A are received in different orders, but the choice is the same.
+        for sibling_hashes in [[2, 12, 22], [22, 2, 12], [12, 22, 2]] {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+            let mut storage_manager =
+                ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+            add_siblings(&mut storage_manager, &sibling_hashes);
+
+            assert_eq!(
+                storage_manager.canonical_child(&MockHash::from([1; 32])),
+                Some(&MockHash::from([2; 32]))
+            );
+            // Blocks without children have no canonical child.
+            assert_eq!(
+                storage_manager.canonical_child(&MockHash::from([2; 32])),
+                None
+            );
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+            let mut storage_manager =
+                ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db)
+                    .with_fork_choice(HighestHashForkChoice);
+            add_siblings(&mut storage_manager, &sibling_hashes);
+
+            assert_eq!(
+                storage_manager.canonical_child(&MockHash::from([1; 32])),
+                Some(&MockHash::from([22; 32]))
+            );
+        }
+    }
+
+    #[test]
+    fn reset_to_finalized_discards_forks() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let (state_db, accessory_db, ledger_db) = build_dbs(tmpdir.path());
+
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+
+        // / -> D -> E
+        // A -> B -> C
+        // \ -> F
+        // (height, prev_hash, current_hash, written key)
+        let blocks: Vec<(u8, u8, u8, u64)> = vec![
+            (1, 0, 1, 1),    // A
+            (2, 1, 2, 2),    // B
+            (2, 1, 12, 12),  // D
+            (2, 1, 22, 22),  // F
+            (3, 2, 3, 3),    // C
+            (3, 12, 13, 13), // E
+        ];
+        let header = |height: u8, prev_hash: u8, hash: u8| MockBlockHeader {
+            prev_hash: MockHash::from([prev_hash; 32]),
+            hash: MockHash::from([hash; 32]),
+            height: height as u64,
+            time: Time::now(),
+        };
+
+        let witness = ArrayWitness::default();
+        for (height, prev_hash, hash, key) in blocks.iter().copied() {
+            let block = header(height, prev_hash, hash);
+            let (stf_state, _) = storage_manager.create_state_for(&block).unwrap();
+            let stf_change_set = materialize_change_set(
+                &stf_state,
+                &witness,
+                &[(key, Some(key * 10))],
+                &[(key, Some(key * 100))],
+            );
+            storage_manager
+                .save_change_set(&block, stf_change_set, SchemaBatch::new())
+                .unwrap();
+        }
+        let block_c = header(3, 2, 3);
+        let _view = storage_manager.create_state_after(&block_c).unwrap();
+
+        // Only A is finalized.
+        storage_manager.finalize(&header(1, 0, 1)).unwrap();
+        assert!(!storage_manager.is_empty());
+
+        storage_manager.reset_to_finalized().unwrap();
+        assert!(storage_manager.is_empty());
+        assert!(storage_manager.dangled_snapshots.is_empty());
+        validate_internal_consistency(&storage_manager);
+
+        let (stf_state, _) = storage_manager.create_bootstrap_state().unwrap();
+        assert_eq!(
+            Some(value_from(10)),
+            stf_state.get::<User>(&key_from(1), None, &witness)
+        );
+        assert_eq!(
+            Some(value_from(100)),
+            stf_state.get_accessory(&key_from(1), None)
+        );
+        for (_, _, _, key) in blocks.iter().skip(1) {
+            assert_eq!(None, stf_state.get::<User>(&key_from(*key), None, &witness));
+            assert_eq!(None, stf_state.get_accessory(&key_from(*key), None));
+        }
+
+        // Blocks after the finalized tip can be processed again.
+        let block_b = header(2, 1, 2);
+        let (stf_state, _) = storage_manager.create_state_for(&block_b).unwrap();
+        let stf_change_set = materialize_change_set(&stf_state, &witness, &[(2, Some(21))], &[]);
+        storage_manager
+            .save_change_set(&block_b, stf_change_set, SchemaBatch::new())
+            .unwrap();
+        storage_manager.finalize(&block_b).unwrap();
+        validate_internal_consistency(&storage_manager);
+        assert!(storage_manager.is_empty());
+    }
+
     #[test]
     fn finalize_non_earliest_block() {
         let tmpdir = tempfile::tempdir().unwrap();
@@ -1281,6 +2463,16 @@ mod tests {
         storage_manager.finalize(&block_d).unwrap();
         validate_internal_consistency(&storage_manager);
         assert_main_fork();
+        // The orphaned forks are kept until their states are released.
+        assert_eq!(storage_manager.orphaned_blocks.len(), 6);
+        drop((
+            stf_state_f,
+            stf_state_g,
+            storage_l,
+            stf_state_m,
+            stf_state_h,
+            stf_state_k,
+        ));
         let stf_change_set_e = materialize_change_set(&stf_state_e, &Default::default(), &[], &[]);
         storage_manager
             .save_change_set(&block_e, stf_change_set_e, SchemaBatch::new())
@@ -1340,7 +2532,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "known problem"]
     fn removed_fork_data_view() {
         // Test aims to test what data will be seen be
 
@@ -1353,11 +2544,15 @@ mod tests {
         // B is finalized and then C.
 
         // Would F see data from E?
-        // In the current version, it won't, because it will not have a pointer to parent.
-        // Фnd will read from the database.
+        // It does: the orphaned E -> F fork keeps its snapshots and parent pointers
+        // as long as the state of F is alive.
         let tmpdir = tempfile::tempdir().unwrap();
         let storage_config = sov_state::config::Config {
             path: tmpdir.path().to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
         };
         let mut storage_manager = ProverStorageManager::<Da, S>::new(storage_config).unwrap();
 
@@ -1393,6 +2588,8 @@ mod tests {
         storage_manager
             .save_change_set(&block_e, change_set, SchemaBatch::new())
             .unwrap();
+        // Only the state of F is kept, which also reads from E.
+        drop(stf_state);
 
         let block_f = MockBlockHeader {
             prev_hash: block_e.hash(),
@@ -1445,9 +2642,34 @@ mod tests {
 
         // this is a very interesting thing happens
         storage_manager.finalize(&main_chain_blocks[2]).unwrap();
-        // E -> F fork becomes orphan and delete. But storage F is still here.
-        // But its underlying view changes,
+        // E -> F fork becomes orphan. But storage F is still here,
+        // and its underlying view does not change.
+        check_f_state();
+        validate_internal_consistency(&storage_manager);
+
+        // State cannot be requested for the orphaned fork anymore.
+        let block_g = MockBlockHeader {
+            prev_hash: block_f.hash(),
+            hash: MockHash([24; 32]),
+            height: 4,
+            time: Default::default(),
+        };
+        assert!(storage_manager.create_state_for(&block_g).is_err());
+        assert!(storage_manager.create_state_after(&block_e).is_err());
+
+        // The orphaned snapshots are kept by the next finalization while the state of F is alive,
+        // since it reads from both E and F.
+        storage_manager.finalize(&main_chain_blocks[3]).unwrap();
+        assert_eq!(storage_manager.orphaned_blocks.len(), 2);
         check_f_state();
+        validate_internal_consistency(&storage_manager);
+
+        // They are dropped once it is released.
+        drop(stf_state);
+        storage_manager.discard_orphaned_snapshots();
+        assert!(storage_manager.orphaned_blocks.is_empty());
+        assert_eq!(storage_manager.live_snapshot_count(), 1);
+        validate_internal_consistency(&storage_manager);
     }
 
     #[test]
@@ -1467,6 +2689,10 @@ mod tests {
         let tmpdir = tempfile::tempdir().unwrap();
         let storage_config = sov_state::config::Config {
             path: tmpdir.path().to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
         };
         let mut storage_manager = ProverStorageManager::<Da, S>::new(storage_config).unwrap();
 
@@ -1536,4 +2762,150 @@ mod tests {
             handle.join().expect("Thread panicked");
         }
     }
+
+    type SlotWrites = (&'static [(u64, Option<u64>)], &'static [(u64, Option<u64>)]);
+
+    // Overwrites and deletions of both provable and accessory values across slots.
+    const SLOTS: [SlotWrites; 3] = [
+        (
+            &[(1, Some(1)), (2, Some(2)), (3, Some(3))],
+            &[(10, Some(10)), (11, Some(11))],
+        ),
+        (&[(1, Some(100)), (2, None)], &[(10, None), (12, Some(12))]),
+        (&[(3, Some(300)), (4, Some(4))], &[(11, Some(110))]),
+    ];
+
+    fn block_at(i: u8) -> MockBlockHeader {
+        MockBlockHeader {
+            prev_hash: MockHash::from([i; 32]),
+            hash: MockHash::from([i + 1; 32]),
+            height: i as u64 + 1,
+            time: Time::now(),
+        }
+    }
+
+    /// Saves all slots except the last one into a fresh storage manager
+    /// and returns the change set of the last slot.
+    fn change_set_of_last_slot(path: &std::path::Path, slots: &[SlotWrites]) -> ProverChangeSet {
+        let (state_db, accessory_db, ledger_db) = build_dbs(path);
+        let mut storage_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+
+        let ((last_state_writes, last_accessory_writes), previous) = slots.split_last().unwrap();
+        for (i, (state_writes, accessory_writes)) in previous.iter().enumerate() {
+            let block = block_at(i as u8);
+            let (stf_state, _) = storage_manager.create_state_for(&block).unwrap();
+            let change_set = materialize_change_set(
+                &stf_state,
+                &Default::default(),
+                state_writes,
+                accessory_writes,
+            );
+            storage_manager
+                .save_change_set(&block, change_set, SchemaBatch::new())
+                .unwrap();
+        }
+
+        let (stf_state, _) = storage_manager
+            .create_state_for(&block_at(previous.len() as u8))
+            .unwrap();
+        materialize_change_set(
+            &stf_state,
+            &Default::default(),
+            last_state_writes,
+            last_accessory_writes,
+        )
+    }
+
+    #[test]
+    fn merged_change_sets_match_sequential_application() {
+        let witness = ArrayWitness::default();
+
+        // Each slot is saved and finalized separately.
+        let sequential_dir = tempfile::tempdir().unwrap();
+        let (state_db, accessory_db, ledger_db) = build_dbs(sequential_dir.path());
+        let mut sequential_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        for (i, (state_writes, accessory_writes)) in SLOTS.iter().enumerate() {
+            let block = block_at(i as u8);
+            let (stf_state, _) = sequential_manager.create_state_for(&block).unwrap();
+            let change_set =
+                materialize_change_set(&stf_state, &witness, state_writes, accessory_writes);
+            sequential_manager
+                .save_change_set(&block, change_set, SchemaBatch::new())
+                .unwrap();
+            sequential_manager.finalize(&block).unwrap();
+        }
+        let (sequential_state, _) = sequential_manager
+            .create_state_for(&block_at(SLOTS.len() as u8))
+            .unwrap();
+
+        // All slots are merged into a single change set and committed at once.
+        let merged = (1..=SLOTS.len())
+            .map(|n| {
+                let tmpdir = tempfile::tempdir().unwrap();
+                change_set_of_last_slot(tmpdir.path(), &SLOTS[..n])
+            })
+            .reduce(|merged, next| merged.merge(next).unwrap())
+            .unwrap();
+        let merged_dir = tempfile::tempdir().unwrap();
+        let (state_db, accessory_db, ledger_db) = build_dbs(merged_dir.path());
+        let mut merged_manager =
+            ProverStorageManager::<Da, S>::with_db_handles(state_db, accessory_db, ledger_db);
+        let bulk_block = block_at(0);
+        merged_manager.create_state_for(&bulk_block).unwrap();
+        merged_manager
+            .save_change_set(&bulk_block, merged, SchemaBatch::new())
+            .unwrap();
+        merged_manager.finalize(&bulk_block).unwrap();
+        let (merged_state, _) = merged_manager.create_state_for(&block_at(1)).unwrap();
+
+        assert_eq!(sequential_state.version(), merged_state.version());
+        let version = merged_state.version();
+        assert_eq!(
+            sequential_state.get_root_hash(version).unwrap(),
+            merged_state.get_root_hash(version).unwrap()
+        );
+        for key in 1..=4 {
+            let expected = sequential_state.get::<User>(&key_from(key), None, &witness);
+            assert_eq!(
+                expected,
+                merged_state.get::<User>(&key_from(key), None, &witness)
+            );
+        }
+        for key in 10..=12 {
+            let expected = sequential_state.get_accessory(&key_from(key), None);
+            assert_eq!(expected, merged_state.get_accessory(&key_from(key), None));
+        }
+
+        assert_eq!(
+            Some(value_from(100)),
+            merged_state.get::<User>(&key_from(1), None, &witness)
+        );
+        assert_eq!(None, merged_state.get::<User>(&key_from(2), None, &witness));
+        assert_eq!(None, merged_state.get_accessory(&key_from(10), None));
+        assert_eq!(
+            Some(value_from(110)),
+            merged_state.get_accessory(&key_from(11), None)
+        );
+    }
+
+    #[test]
+    fn merging_non_consecutive_change_sets_fails() {
+        let change_set_of_slot = |n: usize| {
+            let tmpdir = tempfile::tempdir().unwrap();
+            change_set_of_last_slot(tmpdir.path(), &SLOTS[..n])
+        };
+
+        // Overlapping change sets.
+        assert!(change_set_of_slot(2).merge(change_set_of_slot(2)).is_err());
+        assert!(change_set_of_slot(2).merge(change_set_of_slot(1)).is_err());
+        // A slot is missing in between.
+        assert!(change_set_of_slot(1).merge(change_set_of_slot(3)).is_err());
+
+        let merged = change_set_of_slot(1).merge(change_set_of_slot(2)).unwrap();
+        assert!(merged.merge(change_set_of_slot(2)).is_err());
+        let merged = change_set_of_slot(1).merge(change_set_of_slot(2)).unwrap();
+        assert!(merged.merge(change_set_of_slot(3)).is_ok());
+    }
 }
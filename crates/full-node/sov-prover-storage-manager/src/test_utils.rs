@@ -100,6 +100,7 @@ impl<S: MerkleProofSpec> SimpleStorageManager<S> {
         let ProverChangeSet {
             state_change_set,
             accessory_change_set,
+            ..
         } = prover_change_set;
         let state_change_set = ChangeSet::new_with_operations(0, state_change_set);
         let accessory_change_set = ChangeSet::new_with_operations(0, accessory_change_set);
@@ -36,6 +36,7 @@ impl<T: DeserializeOwned> Query<T> {
                 details: json_obj!({
                     "message": err.to_string(),
                 }),
+                retry_after: None,
             })
     }
 }
@@ -73,6 +74,7 @@ where
                 details: json_obj!({
                     "message": err.to_string(),
                 }),
+                retry_after: None,
             }),
         }
     }
@@ -108,6 +110,7 @@ mod tests {
                     details: json_obj!({
                         "message": "Failed to deserialize query string"
                     }),
+                    retry_after: None,
                 }
             );
         }
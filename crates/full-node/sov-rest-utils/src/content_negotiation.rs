@@ -0,0 +1,79 @@
+//! MessagePack content negotiation for JSON responses.
+
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::errors::internal_server_error_response_500;
+
+/// The media type of MessagePack-encoded responses.
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// Middleware that re-encodes JSON responses as MessagePack (with
+/// [`rmp_serde`]) whenever the request's `Accept` header lists
+/// [`MSGPACK_CONTENT_TYPE`]. All other responses are returned untouched.
+///
+/// JSON responses, and `304 Not Modified` responses to conditional requests
+/// (see [`conditional_get`](crate::conditional_get)), get a `Vary: Accept`
+/// header, so that caches don't serve one representation in place of the
+/// other.
+///
+/// [`axum::response::IntoResponse`] implementations can't inspect request
+/// headers, so [`ResponseObject`](crate::ResponseObject) and
+/// [`ErrorObject`](crate::ErrorObject) always render JSON and rely on this
+/// middleware for the negotiation. It's already part of
+/// [`preconfigured_router_layers`](crate::preconfigured_router_layers).
+pub async fn negotiate_content_type(request: Request, next: Next) -> Response {
+    let wants_msgpack = accepts_msgpack(request.headers());
+    let mut response = next.run(request).await;
+
+    if is_json(response.headers()) || response.status() == StatusCode::NOT_MODIFIED {
+        response
+            .headers_mut()
+            .append(header::VARY, HeaderValue::from_static("accept"));
+    }
+
+    if !wants_msgpack || !is_json(response.headers()) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let json = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => return internal_server_error_response_500(err),
+    };
+    let msgpack = match serde_json::from_slice::<serde_json::Value>(&json)
+        .map_err(|err| err.to_string())
+        .and_then(|value| rmp_serde::to_vec_named(&value).map_err(|err| err.to_string()))
+    {
+        Ok(msgpack) => msgpack,
+        // Leave the response as JSON rather than failing the whole request.
+        Err(_) => return Response::from_parts(parts, Body::from(json)),
+    };
+
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(MSGPACK_CONTENT_TYPE),
+    );
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(msgpack))
+}
+
+fn accepts_msgpack(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|media_range| media_range.split(';').next())
+        .any(|media_type| media_type.trim().eq_ignore_ascii_case(MSGPACK_CONTENT_TYPE))
+}
+
+pub(crate) fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
@@ -1,5 +1,7 @@
 //! Common error types.
 
+use std::time::Duration;
+
 use axum::extract::OriginalUri;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
@@ -15,6 +17,7 @@ pub async fn global_404(OriginalUri(uri): OriginalUri) -> Response {
         details: json_obj!({
             "url": uri.to_string(),
         }),
+        retry_after: None,
     }
     .into_response()
 }
@@ -25,6 +28,7 @@ pub fn not_implemented_501() -> Response {
         status: StatusCode::NOT_IMPLEMENTED,
         title: "Not implemented yet".to_string(),
         details: Default::default(),
+        retry_after: None,
     }
     .into_response()
 }
@@ -41,6 +45,7 @@ pub fn not_found_404(resource_name_capitalized: &str, resource_id: impl ToString
         details: json_obj!({
             "id": resource_id.to_string(),
         }),
+        retry_after: None,
     }
     .into_response()
 }
@@ -53,6 +58,36 @@ pub fn bad_request_400(message: &str, err: impl ToString) -> Response {
         details: json_obj!({
             "message": err.to_string(),
         }),
+        retry_after: None,
+    }
+    .into_response()
+}
+
+/// Returns a 429 error for throttled requests. Clients are told to retry after
+/// `retry_after` through the `Retry-After` header.
+pub fn too_many_requests_429(retry_after: Duration) -> Response {
+    ErrorObject {
+        status: StatusCode::TOO_MANY_REQUESTS,
+        title: "Too many requests".to_string(),
+        details: json_obj!({
+            "retryAfterSecs": retry_after.as_secs_f64(),
+        }),
+        retry_after: Some(retry_after),
+    }
+    .into_response()
+}
+
+/// Returns a 503 error for requests that can't be served until the node is
+/// ready, e.g. while it's still syncing. If known, `retry_after` is sent back
+/// as the `Retry-After` header.
+pub fn service_unavailable_503(reason: impl ToString, retry_after: Option<Duration>) -> Response {
+    ErrorObject {
+        status: StatusCode::SERVICE_UNAVAILABLE,
+        title: "Service unavailable".to_string(),
+        details: json_obj!({
+            "message": reason.to_string(),
+        }),
+        retry_after,
     }
     .into_response()
 }
@@ -72,6 +107,7 @@ pub fn database_error_response_500(err: impl ToString) -> Response {
         status: StatusCode::INTERNAL_SERVER_ERROR,
         title: "Database error".to_string(),
         details: json_obj!({}),
+        retry_after: None,
     }
     .into_response()
 }
@@ -86,6 +122,42 @@ pub fn internal_server_error_response_500(err: impl ToString) -> Response {
         details: json_obj!({
             "message": err.to_string(),
         }),
+        retry_after: None,
     }
     .into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::http::header;
+
+    use super::*;
+
+    fn retry_after_header(response: &Response) -> Option<&str> {
+        response
+            .headers()
+            .get(header::RETRY_AFTER)
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[test]
+    fn throttled_response_has_retry_after() {
+        let response = too_many_requests_429(Duration::from_secs(3));
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(retry_after_header(&response), Some("3"));
+
+        // Fractional delays are rounded up.
+        let response = too_many_requests_429(Duration::from_millis(1500));
+        assert_eq!(retry_after_header(&response), Some("2"));
+    }
+
+    #[test]
+    fn not_ready_response_has_retry_after() {
+        let response = service_unavailable_503("Node is syncing", Some(Duration::from_secs(10)));
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(retry_after_header(&response), Some("10"));
+
+        let response = service_unavailable_503("Node is syncing", None);
+        assert_eq!(retry_after_header(&response), None);
+    }
+}
@@ -0,0 +1,209 @@
+//! Conditional `GET` requests with weak entity tags.
+
+use axum::body::Body;
+use axum::extract::Request;
+use axum::http::{header, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+
+use crate::errors::internal_server_error_response_500;
+
+/// Middleware that sets a weak `ETag` on successful responses and answers
+/// `304 Not Modified` to `GET` and `HEAD` requests whose `If-None-Match`
+/// header lists it.
+///
+/// The tag is derived from a hash of the serialized body, so identical
+/// responses always get the same tag, even across nodes. Buffering and hashing
+/// every body isn't free, so this should only be enabled on handlers serving
+/// (mostly) immutable resources, e.g. with
+/// [`MethodRouter::layer`](axum::routing::MethodRouter::layer) and
+/// [`axum::middleware::from_fn`].
+///
+/// The tag is computed before [`negotiate_content_type`](crate::negotiate_content_type)
+/// re-encodes the body, so it's shared by all the representations of a
+/// resource, which are told apart by caches through the `Vary: Accept` header
+/// set by that middleware.
+pub async fn conditional_get(request: Request, next: Next) -> Response {
+    let is_get = matches!(*request.method(), Method::GET | Method::HEAD);
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+    let response = next.run(request).await;
+
+    if !is_get || response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => return internal_server_error_response_500(err),
+    };
+    let etag = weak_etag(&bytes);
+
+    if if_none_match.is_some_and(|value| etag_matches(&value, &etag)) {
+        let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+        not_modified.headers_mut().insert(header::ETAG, etag);
+        return not_modified;
+    }
+
+    parts.headers.insert(header::ETAG, etag);
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+fn weak_etag(body: &[u8]) -> HeaderValue {
+    let digest = Sha256::digest(body);
+    // 128 bits are plenty to tell apart the versions of a single resource.
+    let hex = digest[..16]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    HeaderValue::from_str(&format!("W/\"{}\"", hex)).expect("hex is a valid header value")
+}
+
+/// Weak comparison of `etag` against the tags listed in an `If-None-Match`
+/// header, as mandated by RFC 9110.
+fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let (Ok(if_none_match), Ok(etag)) = (if_none_match.to_str(), etag.to_str()) else {
+        return false;
+    };
+    let opaque_tag = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+
+    if_none_match.trim() == "*"
+        || if_none_match
+            .split(',')
+            .any(|tag| opaque_tag(tag) == opaque_tag(etag))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{errors, preconfigured_router_layers, ResponseObject};
+
+    fn test_router() -> Router {
+        preconfigured_router_layers(
+            Router::new()
+                .route(
+                    "/immutable",
+                    get(|| async { ResponseObject::from("foo") })
+                        .layer(axum::middleware::from_fn(conditional_get)),
+                )
+                .route(
+                    "/missing",
+                    get(|| async { errors::not_found_404("Foo", 1) })
+                        .layer(axum::middleware::from_fn(conditional_get)),
+                )
+                .route("/mutable", get(|| async { ResponseObject::from("foo") })),
+        )
+    }
+
+    fn etag_of(headers: &HeaderMap) -> Option<&str> {
+        headers.get(header::ETAG).and_then(|v| v.to_str().ok())
+    }
+
+    fn vary_of(headers: &HeaderMap) -> Vec<&str> {
+        headers
+            .get_all(header::VARY)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .collect()
+    }
+
+    async fn get_with(path: &str, if_none_match: Option<&str>) -> Response {
+        get_with_accept(path, if_none_match, None).await
+    }
+
+    async fn get_with_accept(
+        path: &str,
+        if_none_match: Option<&str>,
+        accept: Option<&str>,
+    ) -> Response {
+        let mut request = axum::http::Request::get(path);
+        if let Some(if_none_match) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, if_none_match);
+        }
+        if let Some(accept) = accept {
+            request = request.header(header::ACCEPT, accept);
+        }
+        test_router()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn etag_is_stable() {
+        let first = get_with("/immutable", None).await;
+        let second = get_with("/immutable", None).await;
+        assert_eq!(first.status(), StatusCode::OK);
+
+        let etag = etag_of(first.headers()).unwrap();
+        assert!(etag.starts_with("W/\""));
+        assert_eq!(Some(etag), etag_of(second.headers()));
+    }
+
+    #[tokio::test]
+    async fn matching_etag_is_not_modified() {
+        let response = get_with("/immutable", None).await;
+        let etag = etag_of(response.headers()).unwrap().to_string();
+        let strong_etag = etag.trim_start_matches("W/").to_string();
+
+        for if_none_match in [
+            etag.clone(),
+            strong_etag,
+            format!("W/\"other\", {}", etag),
+            "*".to_string(),
+        ] {
+            let response = get_with("/immutable", Some(&if_none_match)).await;
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+            assert_eq!(etag_of(response.headers()), Some(etag.as_str()));
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            assert!(body.is_empty());
+        }
+
+        let response = get_with("/immutable", Some("W/\"other\"")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn negotiated_responses_vary_on_accept() {
+        let json = get_with("/immutable", None).await;
+        let msgpack = get_with_accept("/immutable", None, Some(crate::MSGPACK_CONTENT_TYPE)).await;
+        assert_eq!(
+            msgpack.headers()[header::CONTENT_TYPE],
+            crate::MSGPACK_CONTENT_TYPE
+        );
+
+        let etag = etag_of(json.headers()).unwrap().to_string();
+        let not_modified =
+            get_with_accept("/immutable", Some(&etag), Some(crate::MSGPACK_CONTENT_TYPE)).await;
+        assert_eq!(not_modified.status(), StatusCode::NOT_MODIFIED);
+
+        for response in [json, msgpack, not_modified] {
+            assert!(
+                vary_of(response.headers()).contains(&"accept"),
+                "missing `Vary: Accept` in {:?}",
+                response.headers()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn etags_are_opt_in_and_only_for_successes() {
+        let response = get_with("/mutable", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(etag_of(response.headers()), None);
+
+        let response = get_with("/missing", Some("*")).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(etag_of(response.headers()), None);
+    }
+}
@@ -0,0 +1,658 @@
+//! Consistent JSON representation of wide integers.
+//!
+//! JavaScript numbers can only represent integers up to
+//! [`MAX_SAFE_INTEGER`] exactly, so any field that is wider than that (i.e.
+//! `u64`, `i64`, `u128`, `i128`, and `usize`) is serialized as a decimal
+//! string. Deserialization accepts both strings and plain JSON numbers, so
+//! that existing clients and configuration files keep working.
+//!
+//! - Use [`as_string`] and [`range_as_string`] with `#[serde(with = "...")]`
+//!   on concrete integer fields.
+//! - Use [`serialize_wide_ints`] and [`deserialize_wide_ints`] with
+//!   `#[serde(serialize_with = "...", deserialize_with = "...")]` for generic
+//!   payloads, e.g. module state values or events, whose integer fields we
+//!   don't control.
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::str::FromStr;
+
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The largest integer that JavaScript can represent exactly, i.e. `2^53 - 1`.
+pub const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// (De)serializes an integer as a decimal string. Deserialization also
+/// accepts plain JSON numbers.
+pub mod as_string {
+    use super::*;
+
+    /// See the [module-level documentation](self).
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    /// See the [module-level documentation](self).
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<u128>,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(StringOrNumberVisitor(PhantomData))
+    }
+}
+
+/// Like [`as_string`], but for the bounds of a [`Range`].
+pub mod range_as_string {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct RangeRef<'a, T: Display> {
+        #[serde(with = "as_string")]
+        start: &'a T,
+        #[serde(with = "as_string")]
+        end: &'a T,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(bound = "T: FromStr + TryFrom<u64> + TryFrom<u128>, <T as FromStr>::Err: Display")]
+    struct RangeOwned<T> {
+        #[serde(with = "as_string")]
+        start: T,
+        #[serde(with = "as_string")]
+        end: T,
+    }
+
+    /// See the [module-level documentation](self).
+    pub fn serialize<T, S>(range: &Range<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        RangeRef {
+            start: &range.start,
+            end: &range.end,
+        }
+        .serialize(serializer)
+    }
+
+    /// See the [module-level documentation](self).
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Range<T>, D::Error>
+    where
+        T: FromStr + TryFrom<u64> + TryFrom<u128>,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let range = RangeOwned::<T>::deserialize(deserializer)?;
+        Ok(range.start..range.end)
+    }
+}
+
+struct StringOrNumberVisitor<T>(PhantomData<T>);
+
+impl<'de, T> serde::de::Visitor<'de> for StringOrNumberVisitor<T>
+where
+    T: FromStr + TryFrom<u64> + TryFrom<u128>,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an integer or a string containing an integer")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<T, E> {
+        <T as TryFrom<u64>>::try_from(v)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<T, E> {
+        <T as TryFrom<u128>>::try_from(v)
+            .map_err(|_| E::custom(format!("integer {v} is out of range")))
+    }
+}
+
+/// Serializes `value` with all its `u64`, `i64`, `u128`, and `i128` values,
+/// however deeply nested, as decimal strings. Meant to be used with
+/// `#[serde(serialize_with = "...")]`.
+pub fn serialize_wide_ints<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + ?Sized,
+    S: Serializer,
+{
+    WideIntsAsStrings(value).serialize(serializer)
+}
+
+/// A [`Serialize`] wrapper that serializes all `u64`, `i64`, `u128`, and
+/// `i128` values found anywhere inside `T` as decimal strings. Narrower
+/// integers are left untouched.
+///
+/// This only applies to human-readable formats; binary formats are passed
+/// through as-is.
+struct WideIntsAsStrings<'a, T: ?Sized>(&'a T);
+
+impl<T> Serialize for WideIntsAsStrings<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            self.0.serialize(Wrapped(serializer))
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+/// Wraps a [`Serializer`] (or any of its compound serializers), and
+/// re-wraps every nested value with [`WideIntsAsStrings`].
+struct Wrapped<S>(S);
+
+/// Forwards the listed [`Serializer`] methods to the wrapped serializer.
+macro_rules! forward_serialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<S::Ok, S::Error> {
+                self.0.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+/// Serializes the listed integer types as decimal strings.
+macro_rules! stringify_serialize {
+    ($($method:ident: $ty:ty;)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<S::Ok, S::Error> {
+                self.0.collect_str(&v)
+            }
+        )*
+    };
+}
+
+/// Forwards the listed compound [`Serializer`] constructors to the wrapped
+/// serializer, and wraps the result.
+macro_rules! wrap_compound {
+    ($($method:ident($($arg:ident: $ty:ty),*) -> $assoc:ident;)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<Self::$assoc, S::Error> {
+                self.0.$method($($arg),*).map(Wrapped)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for Wrapped<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    type SerializeSeq = Wrapped<S::SerializeSeq>;
+    type SerializeTuple = Wrapped<S::SerializeTuple>;
+    type SerializeTupleStruct = Wrapped<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Wrapped<S::SerializeTupleVariant>;
+    type SerializeMap = Wrapped<S::SerializeMap>;
+    type SerializeStruct = Wrapped<S::SerializeStruct>;
+    type SerializeStructVariant = Wrapped<S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, variant_index: u32, variant: &'static str);
+    }
+
+    stringify_serialize! {
+        serialize_i64: i64;
+        serialize_i128: i128;
+        serialize_u64: u64;
+        serialize_u128: u128;
+    }
+
+    wrap_compound! {
+        serialize_seq(len: Option<usize>) -> SerializeSeq;
+        serialize_tuple(len: usize) -> SerializeTuple;
+        serialize_tuple_struct(name: &'static str, len: usize) -> SerializeTupleStruct;
+        serialize_tuple_variant(
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize
+        ) -> SerializeTupleVariant;
+        serialize_map(len: Option<usize>) -> SerializeMap;
+        serialize_struct(name: &'static str, len: usize) -> SerializeStruct;
+        serialize_struct_variant(
+            name: &'static str,
+            variant_index: u32,
+            variant: &'static str,
+            len: usize
+        ) -> SerializeStructVariant;
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0.serialize_some(&WideIntsAsStrings(value))
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0
+            .serialize_newtype_struct(name, &WideIntsAsStrings(value))
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0
+            .serialize_newtype_variant(name, variant_index, variant, &WideIntsAsStrings(value))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+/// Implements a compound serializer trait for [`Wrapped`], wrapping every
+/// element with [`WideIntsAsStrings`].
+macro_rules! wrap_compound_impl {
+    ($trait:ident, $method:ident($($key:ident: $key_ty:ty)?) $(, $skip:ident)?) => {
+        impl<S: $trait> $trait for Wrapped<S> {
+            type Ok = S::Ok;
+            type Error = S::Error;
+
+            fn $method<T>(&mut self, $($key: $key_ty,)? value: &T) -> Result<(), S::Error>
+            where
+                T: Serialize + ?Sized,
+            {
+                self.0.$method($($key,)? &WideIntsAsStrings(value))
+            }
+
+            $(
+                fn $skip(&mut self, key: &'static str) -> Result<(), S::Error> {
+                    self.0.$skip(key)
+                }
+            )?
+
+            fn end(self) -> Result<S::Ok, S::Error> {
+                self.0.end()
+            }
+        }
+    };
+}
+
+wrap_compound_impl!(SerializeSeq, serialize_element());
+wrap_compound_impl!(SerializeTuple, serialize_element());
+wrap_compound_impl!(SerializeTupleStruct, serialize_field());
+wrap_compound_impl!(SerializeTupleVariant, serialize_field());
+wrap_compound_impl!(SerializeStruct, serialize_field(key: &'static str), skip_field);
+wrap_compound_impl!(SerializeStructVariant, serialize_field(key: &'static str), skip_field);
+
+impl<S: SerializeMap> SerializeMap for Wrapped<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0.serialize_key(&WideIntsAsStrings(key))
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0.serialize_value(&WideIntsAsStrings(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+/// Deserializes a `T` serialized with [`serialize_wide_ints`]. Meant to be
+/// used with `#[serde(deserialize_with = "...")]`.
+///
+/// Integers are accepted both as decimal strings and as plain JSON numbers,
+/// anywhere inside `T`. Binary formats are passed through as-is.
+pub fn deserialize_wide_ints<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return T::deserialize(deserializer);
+    }
+
+    let value = serde_json::Value::deserialize(deserializer)?;
+    T::deserialize(Lenient(value)).map_err(serde::de::Error::custom)
+}
+
+/// A [`Deserializer`] over a [`serde_json::Value`] that parses integers out
+/// of strings when the deserialized type expects an integer.
+struct Lenient(serde_json::Value);
+
+/// Parses the listed integer types out of strings, and forwards any other
+/// value to [`serde_json::Value`].
+macro_rules! parse_deserialize {
+    ($($method:ident => $visit:ident: $ty:ty;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+                match self.0 {
+                    serde_json::Value::String(s) => {
+                        visitor.$visit(s.parse::<$ty>().map_err(serde::de::Error::custom)?)
+                    }
+                    value => value.$method(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Lenient {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        match self.0 {
+            serde_json::Value::Array(items) => {
+                let mut seq = SeqDeserializer::new(items.into_iter().map(Lenient));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            serde_json::Value::Object(entries) => {
+                let mut map = MapDeserializer::new(entries.into_iter().map(lenient_entry));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            // Serde buffers the content of untagged and internally tagged
+            // enums without knowing the type of their fields, so integers
+            // can't be told apart from strings there.
+            serde_json::Value::String(s) => match parse_integer(&s) {
+                Some(number) => number.deserialize_any(visitor),
+                None => visitor.visit_string(s),
+            },
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, serde_json::Error> {
+        self.0.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        self.0.deserialize_string(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        self.0.deserialize_identifier(visitor)
+    }
+
+    parse_deserialize! {
+        deserialize_i64 => visit_i64: i64;
+        deserialize_i128 => visit_i128: i128;
+        deserialize_u64 => visit_u64: u64;
+        deserialize_u128 => visit_u128: u128;
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        match self.0 {
+            serde_json::Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(Lenient(value)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, serde_json::Error> {
+        match self.0 {
+            serde_json::Value::Object(entries) => visitor.visit_enum(MapAccessDeserializer::new(
+                MapDeserializer::new(entries.into_iter().map(lenient_entry)),
+            )),
+            value => value.deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 u8 u16 u32 f32 f64 char bytes byte_buf unit unit_struct seq
+        tuple tuple_struct map struct ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Lenient {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Parses `s` as a JSON number if it only contains a decimal integer that
+/// fits in a `u64` or an `i64`.
+fn parse_integer(s: &str) -> Option<serde_json::Number> {
+    match s.parse::<u64>() {
+        Ok(n) => Some(n.into()),
+        Err(_) => s.parse::<i64>().ok().map(Into::into),
+    }
+}
+
+fn lenient_entry((key, value): (String, serde_json::Value)) -> (Lenient, Lenient) {
+    (Lenient(serde_json::Value::String(key)), Lenient(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_utils::unsafe_json_integers;
+
+    const ABOVE_SAFE: u64 = MAX_SAFE_INTEGER + 2;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Typed {
+        #[serde(with = "as_string")]
+        number: u64,
+        #[serde(with = "as_string")]
+        amount: u128,
+        #[serde(with = "range_as_string")]
+        range: Range<u64>,
+        small: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Payload {
+        Transfer {
+            amount: u64,
+            fee: Option<u128>,
+            tags: BTreeMap<u64, i64>,
+            small: u8,
+        },
+        Receipt(Outcome),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "result", rename_all = "camelCase")]
+    enum Outcome {
+        Reverted { gas_used: Vec<u64>, reason: String },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Generic {
+        #[serde(
+            serialize_with = "serialize_wide_ints",
+            deserialize_with = "deserialize_wide_ints"
+        )]
+        payloads: Vec<Payload>,
+    }
+
+    #[test]
+    fn typed_fields_roundtrip_above_safe_integer() {
+        let typed = Typed {
+            number: ABOVE_SAFE,
+            amount: u128::MAX,
+            range: ABOVE_SAFE..u64::MAX,
+            small: 7,
+        };
+
+        let json = serde_json::to_value(&typed).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "number": ABOVE_SAFE.to_string(),
+                "amount": u128::MAX.to_string(),
+                "range": { "start": ABOVE_SAFE.to_string(), "end": u64::MAX.to_string() },
+                "small": 7,
+            })
+        );
+        assert!(unsafe_json_integers(&json).is_empty());
+        assert_eq!(serde_json::from_value::<Typed>(json).unwrap(), typed);
+    }
+
+    #[test]
+    fn typed_fields_accept_plain_numbers() {
+        let json = json!({
+            "number": ABOVE_SAFE,
+            "amount": 1,
+            "range": { "start": 0, "end": "2" },
+            "small": 7,
+        });
+        let typed: Typed = serde_json::from_value(json).unwrap();
+        assert_eq!(typed.number, ABOVE_SAFE);
+        assert_eq!(typed.range, 0..2);
+    }
+
+    #[test]
+    fn generic_payloads_stringify_wide_ints() {
+        let payload = Payload::Transfer {
+            amount: ABOVE_SAFE,
+            fee: Some(u128::MAX),
+            tags: [(u64::MAX, i64::MIN)].into_iter().collect(),
+            small: 1,
+        };
+
+        let json = serde_json::to_value(WideIntsAsStrings(&payload)).unwrap();
+        let transfer = &json["Transfer"];
+        assert_eq!(transfer["amount"], ABOVE_SAFE.to_string());
+        assert_eq!(transfer["fee"], u128::MAX.to_string());
+        assert_eq!(transfer["tags"][u64::MAX.to_string()], i64::MIN.to_string());
+        assert_eq!(transfer["small"], 1);
+        assert!(unsafe_json_integers(&json).is_empty());
+    }
+
+    #[test]
+    fn generic_payloads_roundtrip() {
+        let generic = Generic {
+            payloads: vec![
+                Payload::Transfer {
+                    amount: ABOVE_SAFE,
+                    fee: Some(u128::MAX),
+                    tags: [(u64::MAX, i64::MIN), (1, -1)].into_iter().collect(),
+                    small: 1,
+                },
+                Payload::Transfer {
+                    amount: 0,
+                    fee: None,
+                    tags: BTreeMap::new(),
+                    small: 2,
+                },
+                Payload::Receipt(Outcome::Reverted {
+                    gas_used: vec![ABOVE_SAFE, 3],
+                    reason: "out of gas".to_string(),
+                }),
+            ],
+        };
+
+        let json = serde_json::to_value(&generic).unwrap();
+        assert!(unsafe_json_integers(&json).is_empty());
+        assert_eq!(serde_json::from_value::<Generic>(json).unwrap(), generic);
+
+        let plain = json!({
+            "payloads": [{
+                "Transfer": { "amount": 5, "fee": 6, "tags": { "7": 8 }, "small": 9 }
+            }]
+        });
+        assert_eq!(
+            serde_json::from_value::<Generic>(plain).unwrap().payloads,
+            vec![Payload::Transfer {
+                amount: 5,
+                fee: Some(6),
+                tags: [(7, 8)].into_iter().collect(),
+                small: 9,
+            }]
+        );
+    }
+
+    #[test]
+    fn unsafe_integers_are_detected() {
+        let json = json!({ "ok": MAX_SAFE_INTEGER, "nested": [{ "amount": ABOVE_SAFE }] });
+        assert_eq!(
+            unsafe_json_integers(&json),
+            vec!["/nested/0/amount".to_string()]
+        );
+    }
+}
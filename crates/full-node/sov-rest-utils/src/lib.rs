@@ -14,31 +14,37 @@
 //! - Query string parameters follow the bracket notation `foo[bar]` that was
 //!   popularized by [`qs`](https://github.com/ljharb/qs).
 //! - Pagination is cursor-based.
-//!
-//! # Missing features
-//! - Multi-column sorting (see <https://github.com/Sovereign-Labs/sovereign-sdk-wip/issues/449>).
+//! - Sorting supports multiple columns, e.g. `sort=foo,-bar`.
 
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
 mod axum_extractors;
+mod content_negotiation;
+mod etag;
 mod pagination;
+mod rate_limit;
 mod sorting;
 
 pub mod errors;
+pub mod json_numbers;
 
 #[doc(hidden)]
 pub mod test_utils;
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use axum::body::Body;
 use axum::extract::Request;
-use axum::http::{HeaderName, StatusCode};
+use axum::http::{header, HeaderName, HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::{Json, Router};
 pub use axum_extractors::{Path, Query};
+pub use content_negotiation::{negotiate_content_type, MSGPACK_CONTENT_TYPE};
+pub use etag::conditional_get;
 pub use pagination::{PageSelection, Pagination};
+pub use rate_limit::{rate_limit, RateLimitConfig, RateLimiter};
 pub use sorting::{Sorting, SortingOrder};
 use tower_http::compression::CompressionLayer;
 use tower_http::propagate_header::PropagateHeaderLayer;
@@ -57,19 +63,20 @@ pub type ApiResult<T, E = Response> = Result<ResponseObject<T>, E>;
 ///
 /// These two cases are usually but not always exclusive, notably in the case of
 /// partial success.
-#[derive(Debug, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseObject<T> {
     /// Core response data when successful.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<T>,
     /// A list of errors that occurred during the request. If the list is empty,
     /// the request was successful.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<ErrorObject>,
     /// Metadata about the response, if present or needed (e.g. remaining
     /// requests available in the current rate limit window). This will be empty
     /// in most cases.
+    #[serde(default)]
     pub meta: JsonObject,
 }
 
@@ -83,6 +90,18 @@ impl<T> From<T> for ResponseObject<T> {
     }
 }
 
+impl<T> ResponseObject<T> {
+    /// Inserts `key` with `value` into [`ResponseObject::meta`].
+    pub fn with_meta(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+}
+
 impl<T> IntoResponse for ResponseObject<T>
 where
     T: serde::Serialize,
@@ -94,23 +113,40 @@ where
             .first()
             .map(|err| err.status)
             .unwrap_or(StatusCode::OK);
+        let retry_after = self.errors.first().and_then(|err| err.retry_after);
 
-        (status, Json(self)).into_response()
+        with_retry_after((status, Json(self)).into_response(), retry_after)
     }
 }
 
 impl IntoResponse for ErrorObject {
     fn into_response(self) -> Response {
-        (
-            self.status,
+        let status = self.status;
+        let retry_after = self.retry_after;
+
+        let response = (
+            status,
             Json(ResponseObject::<()> {
                 data: None,
                 errors: vec![self],
                 meta: JsonObject::default(),
             }),
         )
-            .into_response()
+            .into_response();
+        with_retry_after(response, retry_after)
+    }
+}
+
+/// Sets the `Retry-After` header of `response`, if `retry_after` is present.
+/// The delay is rendered in whole seconds, rounded up.
+fn with_retry_after(mut response: Response, retry_after: Option<Duration>) -> Response {
+    if let Some(retry_after) = retry_after {
+        let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+        response
+            .headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
     }
+    response
 }
 
 /// A JSON object (mind you, not a *value*, but an
@@ -118,7 +154,7 @@ impl IntoResponse for ErrorObject {
 pub type JsonObject = serde_json::Map<String, serde_json::Value>;
 
 /// Inspired from <https://jsonapi.org/format/#error-objects>.
-#[derive(Debug, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct ErrorObject {
     /// The HTTP status that best describes the error.
     #[serde(with = "serde_status_code")]
@@ -126,7 +162,20 @@ pub struct ErrorObject {
     /// A short, human-readable description of the error.
     pub title: String,
     /// Structured details about the error, if available.
+    #[serde(default)]
     pub details: JsonObject,
+    /// How long the client should wait before retrying the request. Rendered
+    /// as the `Retry-After` header rather than as part of the body.
+    #[serde(skip)]
+    pub retry_after: Option<Duration>,
+}
+
+impl ErrorObject {
+    /// Sets [`ErrorObject::retry_after`].
+    pub fn with_retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = Some(retry_after);
+        self
+    }
 }
 
 mod serde_status_code {
@@ -138,6 +187,14 @@ mod serde_status_code {
     {
         serializer.serialize_u16(status_code.as_u16())
     }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<StatusCode, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let status_code = <u16 as serde::Deserialize>::deserialize(deserializer)?;
+        StatusCode::from_u16(status_code).map_err(serde::de::Error::custom)
+    }
 }
 
 /// Exactly like [`serde_json::Value`], but returns a JSON object instead of a
@@ -152,9 +209,34 @@ macro_rules! json_obj {
     };
 }
 
+/// Optional layers of [`preconfigured_router_layers_with_config`].
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+pub struct RouterLayersConfig {
+    /// Enables per-IP rate limiting, see [`rate_limit`].
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
 /// Customizes the given [`Router`] with a set of preconfigured "layers" that
 /// are a good starting point for building production-ready JSON APIs.
+///
+/// Responses are rendered as MessagePack instead of JSON when requested via
+/// the `Accept` header, see [`negotiate_content_type`].
 pub fn preconfigured_router_layers<S>(router: Router<S>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    preconfigured_router_layers_with_config(router, RouterLayersConfig::default())
+}
+
+/// Like [`preconfigured_router_layers`], but with the optional layers enabled
+/// by `config`.
+pub fn preconfigured_router_layers_with_config<S>(
+    router: Router<S>,
+    config: RouterLayersConfig,
+) -> Router<S>
 where
     S: Clone + Send + Sync + 'static,
 {
@@ -175,7 +257,10 @@ where
             uri = %request.uri(),
         )
     });
-    router
+    // Register the fallback first, so that 404s also go through the layers below.
+    optional_router_layers(router.fallback(errors::global_404), config)
+        // Must come before compression, and after the layers editing JSON bodies.
+        .layer(axum::middleware::from_fn(negotiate_content_type))
         .layer(trace_layer)
         // This layer creates a new id for each request and puts it into the request extensions.
         // Note that it should be added after the Trace layer. (Filippo: why? I
@@ -192,15 +277,110 @@ where
                     "x-request-id",
                 ))),
         )
-        .fallback(errors::global_404)
+}
+
+/// Applies the optional layers enabled by `config`, and only them, to `router`.
+///
+/// Unlike [`preconfigured_router_layers_with_config`], this can wrap routers
+/// which already went through [`preconfigured_router_layers`], e.g. the whole
+/// REST API of a node.
+pub fn optional_router_layers<S>(router: Router<S>, config: RouterLayersConfig) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    match config.rate_limit {
+        Some(rate_limit_config) => router.layer(axum::middleware::from_fn_with_state(
+            RateLimiter::new(rate_limit_config),
+            rate_limit,
+        )),
+        None => router,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use axum::routing::get;
     use proptest::proptest;
+    use tower::ServiceExt;
 
+    use super::*;
     use crate::test_utils::uri_with_query_params;
 
+    fn test_router() -> Router {
+        preconfigured_router_layers(
+            Router::new()
+                .route(
+                    "/data",
+                    get(|| async { ResponseObject::from(serde_json::json!({ "foo": [1, 2] })) }),
+                )
+                .route(
+                    "/error",
+                    get(|| async { errors::bad_request_400("Invalid foo", "bar") }),
+                ),
+        )
+    }
+
+    async fn get_response(path: &str, accept: Option<&str>) -> (Response, serde_json::Value) {
+        let mut request = axum::http::Request::get(path);
+        if let Some(accept) = accept {
+            request = request.header(header::ACCEPT, accept);
+        }
+        let response = test_router()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        let body = match parts.headers[header::CONTENT_TYPE].to_str().unwrap() {
+            MSGPACK_CONTENT_TYPE => rmp_serde::from_slice(&bytes).unwrap(),
+            _ => serde_json::from_slice(&bytes).unwrap(),
+        };
+        (Response::from_parts(parts, Body::empty()), body)
+    }
+
+    #[tokio::test]
+    async fn responses_default_to_json() {
+        for accept in [None, Some("application/json"), Some("*/*")] {
+            let (response, body) = get_response("/data", accept).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+            assert_eq!(body["data"], serde_json::json!({ "foo": [1, 2] }));
+        }
+    }
+
+    #[tokio::test]
+    async fn responses_are_msgpack_when_requested() {
+        let (response, body) =
+            get_response("/data", Some("application/json;q=0.5, application/msgpack")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            MSGPACK_CONTENT_TYPE
+        );
+        assert_eq!(body["data"], serde_json::json!({ "foo": [1, 2] }));
+    }
+
+    #[tokio::test]
+    async fn errors_follow_the_negotiated_format() {
+        for (path, status) in [
+            ("/error", StatusCode::BAD_REQUEST),
+            ("/missing", StatusCode::NOT_FOUND),
+        ] {
+            let (json_response, json_body) = get_response(path, None).await;
+            let (msgpack_response, msgpack_body) =
+                get_response(path, Some(MSGPACK_CONTENT_TYPE)).await;
+
+            assert_eq!(json_response.status(), status);
+            assert_eq!(msgpack_response.status(), status);
+            assert_eq!(
+                msgpack_response.headers()[header::CONTENT_TYPE],
+                MSGPACK_CONTENT_TYPE
+            );
+            assert_eq!(msgpack_body, json_body);
+            assert_eq!(msgpack_body["errors"][0]["status"], status.as_u16());
+        }
+    }
+
     proptest! {
         // Ideally we'd also test with types other than strings. E.g. integers?
         #[test]
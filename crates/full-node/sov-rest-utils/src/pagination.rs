@@ -11,6 +11,11 @@ const PAGE_SIZE_MAX: u32 = 100;
 ///
 /// Read more about the tradeoffs of cursor-based VS offset-based pagination in
 /// this great article: <https://slack.engineering/evolving-api-pagination-at-slack/>.
+///
+/// The page is selected with `page=first`, `page=last` or
+/// `page=next&page[cursor]=<cursor>`. `page[after]=<cursor>` is accepted as a
+/// shorthand for the latter, and the first page is selected if neither is
+/// present.
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
 pub struct Pagination<T> {
@@ -67,28 +72,38 @@ impl<'de, T: serde::Serialize + FromStr> serde::Deserialize<'de> for Pagination<
             )));
         }
 
-        let selection = match map.remove("page").as_deref() {
-            Some("next") => PageSelection::Next {
-                cursor: map
-                    .remove("page[cursor]")
-                    .ok_or_else(|| serde::de::Error::missing_field("page[cursor]"))?
-                    .parse::<T>()
-                    .map_err(|_| {
-                        serde::de::Error::invalid_value(
-                            serde::de::Unexpected::Str("page[cursor]"),
-                            &"T",
-                        )
-                    })?,
+        let selection = match (map.remove("page").as_deref(), map.remove("page[after]")) {
+            (Some("next"), None) => PageSelection::Next {
+                cursor: parse_cursor(
+                    "page[cursor]",
+                    map.remove("page[cursor]")
+                        .ok_or_else(|| serde::de::Error::missing_field("page[cursor]"))?,
+                )?,
+            },
+            (Some("first"), None) => PageSelection::First,
+            (Some("last"), None) => PageSelection::Last,
+            (None, Some(after)) => PageSelection::Next {
+                cursor: parse_cursor("page[after]", after)?,
             },
-            Some("first") => PageSelection::First,
-            Some("last") => PageSelection::Last,
-            _ => return Err(serde::de::Error::missing_field("page")),
+            (None, None) => PageSelection::First,
+            (Some(_), Some(_)) => {
+                return Err(serde::de::Error::custom(
+                    "page[after] can't be combined with page",
+                ))
+            }
+            (Some(_), None) => return Err(serde::de::Error::missing_field("page")),
         };
 
         Ok(Self { size, selection })
     }
 }
 
+fn parse_cursor<T: FromStr, E: serde::de::Error>(key: &str, cursor: String) -> Result<T, E> {
+    cursor
+        .parse::<T>()
+        .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(key), &"T"))
+}
+
 /// What kind of page a client can request.
 #[derive(Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
@@ -150,6 +165,33 @@ mod tests {
         try_deserialize(&[("page", "last"), ("page[size]", "10")]).unwrap();
     }
 
+    #[test]
+    fn after_is_a_shorthand_for_next() {
+        assert_eq!(
+            try_deserialize(&[("page[size]", "10"), ("page[after]", "foo")]).unwrap(),
+            try_deserialize(&[
+                ("page[size]", "10"),
+                ("page", "next"),
+                ("page[cursor]", "foo")
+            ])
+            .unwrap()
+        );
+        try_deserialize(&[("page", "first"), ("page[after]", "foo")]).unwrap_err();
+        try_deserialize(&[("page", "next"), ("page[after]", "foo")]).unwrap_err();
+    }
+
+    #[test]
+    fn first_page_is_the_default() {
+        assert_eq!(
+            try_deserialize(&[("page[size]", "10")]).unwrap(),
+            Pagination {
+                size: 10,
+                selection: PageSelection::First,
+            }
+        );
+        try_deserialize(&[("page", "foo")]).unwrap_err();
+    }
+
     #[test]
     fn bad_page_size() {
         try_deserialize(&[("page[size]", "-10")]).unwrap_err();
@@ -0,0 +1,270 @@
+//! Per-IP rate limiting with token buckets.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use tokio::time::Instant;
+
+use crate::content_negotiation::is_json;
+use crate::errors::{internal_server_error_response_500, too_many_requests_429};
+
+/// Full buckets are evicted once this many IP addresses are tracked, to keep
+/// memory usage in check.
+const MAX_TRACKED_BUCKETS: usize = 10_000;
+
+/// Configuration of the per-IP rate limit enforced by [`rate_limit`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+pub struct RateLimitConfig {
+    /// The maximum number of requests that a single IP address can send in a
+    /// burst, i.e. the capacity of its token bucket.
+    pub bucket_size: NonZeroU32,
+    /// How many tokens are added back to each bucket every second.
+    pub refill_per_second: NonZeroU32,
+}
+
+/// The state shared by all requests going through the [`rate_limit`]
+/// middleware, i.e. one token bucket per IP address.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Arc<Mutex<HashMap<Option<IpAddr>, Bucket>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// The outcome of a request for a token.
+#[derive(Debug, Clone, Copy)]
+struct Decision {
+    allowed: bool,
+    remaining: u32,
+    /// Time until the bucket is full again.
+    reset: Duration,
+    /// Time until the next token is available.
+    retry_after: Duration,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] where every IP address starts with a
+    /// full bucket.
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Default::default(),
+        }
+    }
+
+    fn acquire(&self, ip: Option<IpAddr>) -> Decision {
+        let now = Instant::now();
+        let bucket_size = f64::from(self.config.bucket_size.get());
+        let refill_per_second = f64::from(self.config.refill_per_second.get());
+
+        let mut buckets = self.buckets.lock().expect("rate limiter lock poisoned");
+        if buckets.len() >= MAX_TRACKED_BUCKETS && !buckets.contains_key(&ip) {
+            buckets.retain(|_, bucket| {
+                let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+                bucket.tokens + elapsed * refill_per_second < bucket_size
+            });
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: bucket_size,
+            refilled_at: now,
+        });
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_second).min(bucket_size);
+        bucket.refilled_at = now;
+
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+
+        Decision {
+            allowed,
+            remaining: bucket.tokens as u32,
+            reset: Duration::from_secs_f64((bucket_size - bucket.tokens) / refill_per_second),
+            retry_after: Duration::from_secs_f64(
+                (1.0 - bucket.tokens).max(0.0) / refill_per_second,
+            ),
+        }
+    }
+}
+
+/// Middleware that enforces a [`RateLimitConfig`] per IP address, to be used
+/// with [`axum::middleware::from_fn_with_state`] and a [`RateLimiter`].
+///
+/// Throttled requests get a 429 response (see
+/// [`errors::too_many_requests_429`](crate::errors::too_many_requests_429)).
+/// All JSON responses report the state of the client's bucket in
+/// [`ResponseObject::meta`](crate::ResponseObject::meta) as
+/// `rateLimit.remaining` (requests left) and `rateLimit.reset` (seconds until
+/// the bucket is full again).
+///
+/// IP addresses are only available if the server is started with
+/// [`axum::Router::into_make_service_with_connect_info`]; otherwise, all
+/// requests share the same bucket.
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let decision = limiter.acquire(ip);
+
+    let response = if decision.allowed {
+        next.run(request).await
+    } else {
+        too_many_requests_429(decision.retry_after)
+    };
+
+    with_rate_limit_meta(response, decision).await
+}
+
+async fn with_rate_limit_meta(response: Response, decision: Decision) -> Response {
+    if !is_json(response.headers()) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => return internal_server_error_response_500(err),
+    };
+    let mut json = match serde_json::from_slice::<serde_json::Value>(&bytes) {
+        Ok(serde_json::Value::Object(json)) => json,
+        _ => return Response::from_parts(parts, Body::from(bytes)),
+    };
+
+    let reset_secs = decision.reset.as_secs() + u64::from(decision.reset.subsec_nanos() > 0);
+    let meta = json
+        .entry("meta")
+        .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    if let serde_json::Value::Object(meta) = meta {
+        meta.insert(
+            "rateLimit".to_string(),
+            serde_json::json!({
+                "remaining": decision.remaining,
+                "reset": reset_secs,
+            }),
+        );
+    }
+
+    parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+    Response::from_parts(
+        parts,
+        Body::from(serde_json::Value::Object(json).to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{header, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        optional_router_layers, preconfigured_router_layers,
+        preconfigured_router_layers_with_config, ResponseObject, RouterLayersConfig,
+    };
+
+    fn test_config() -> RouterLayersConfig {
+        RouterLayersConfig {
+            rate_limit: Some(RateLimitConfig {
+                bucket_size: NonZeroU32::new(2).unwrap(),
+                refill_per_second: NonZeroU32::new(1).unwrap(),
+            }),
+        }
+    }
+
+    fn test_router() -> Router {
+        preconfigured_router_layers_with_config(
+            Router::new().route("/", get(|| async { ResponseObject::from(1) })),
+            test_config(),
+        )
+    }
+
+    async fn get_from(router: &Router, ip: [u8; 4]) -> (Response, serde_json::Value) {
+        let mut request = axum::http::Request::get("/").body(Body::empty()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from((ip, 1234))));
+
+        let response = router.clone().oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        (
+            Response::from_parts(parts, Body::empty()),
+            serde_json::from_slice(&body).unwrap(),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_are_throttled_per_ip() {
+        let router = test_router();
+
+        for remaining in [1, 0] {
+            let (response, body) = get_from(&router, [1, 1, 1, 1]).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(body["data"], 1);
+            assert_eq!(body["meta"]["rateLimit"]["remaining"], remaining);
+            assert_eq!(body["meta"]["rateLimit"]["reset"], 2 - remaining);
+        }
+
+        let (response, body) = get_from(&router, [1, 1, 1, 1]).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+        assert_eq!(body["errors"][0]["status"], 429);
+        assert_eq!(body["meta"]["rateLimit"]["remaining"], 0);
+        assert_eq!(body["meta"]["rateLimit"]["reset"], 2);
+
+        // Other clients have their own bucket.
+        let (response, body) = get_from(&router, [2, 2, 2, 2]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body["meta"]["rateLimit"]["remaining"], 1);
+
+        // Buckets are refilled over time.
+        tokio::time::advance(Duration::from_secs(1)).await;
+        let (response, body) = get_from(&router, [1, 1, 1, 1]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body["meta"]["rateLimit"]["remaining"], 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn optional_layers_throttle_preconfigured_routers() {
+        let router = optional_router_layers(
+            Router::new().merge(preconfigured_router_layers(
+                Router::new().route("/", get(|| async { ResponseObject::from(1) })),
+            )),
+            test_config(),
+        );
+
+        for remaining in [1, 0] {
+            let (response, body) = get_from(&router, [1, 1, 1, 1]).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(body["data"], 1);
+            assert_eq!(body["meta"]["rateLimit"]["remaining"], remaining);
+        }
+
+        let (response, body) = get_from(&router, [1, 1, 1, 1]).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(body["errors"][0]["status"], 429);
+    }
+}
@@ -3,15 +3,53 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
-/// Single-column sorting options.
+/// Multi-column sorting options, e.g. `sort=foo,-bar` to sort by `foo` in
+/// ascending order and then by `bar` in descending order.
+///
+/// Every sort key can be prefixed with `+` (ascending, the default) or `-`
+/// (descending).
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
 pub struct Sorting<T> {
-    /// The field(s) to sort by.
-    pub by: T,
+    keys: Vec<(T, SortingOrder)>,
+}
+
+impl<T> Sorting<T> {
+    /// Sorts by the field `by` in the given `order`.
+    pub fn new(by: T, order: SortingOrder) -> Self {
+        Self {
+            keys: vec![(by, order)],
+        }
+    }
 
-    /// The sorting order.
-    pub order: SortingOrder,
+    /// Breaks ties of the existing sort keys by sorting by the field `by` in
+    /// the given `order`.
+    pub fn then_by(mut self, by: T, order: SortingOrder) -> Self {
+        self.keys.push((by, order));
+        self
+    }
+
+    /// Returns the sort keys in order of precedence. There's always at least
+    /// one.
+    pub fn keys(&self) -> &[(T, SortingOrder)] {
+        &self.keys
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<T> proptest::arbitrary::Arbitrary for Sorting<T>
+where
+    T: proptest::arbitrary::Arbitrary + 'static,
+{
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::collection::vec(proptest::arbitrary::any::<(T, SortingOrder)>(), 1..8)
+            .prop_map(|keys| Self { keys })
+            .boxed()
+    }
 }
 
 impl<'a, T> serde::Deserialize<'a> for Sorting<T>
@@ -27,40 +65,49 @@ where
             serde::de::Error::custom(format!("failed to deserialize sorting string: {}", e))
         })?;
 
-        let mut chars = string.chars();
-        let (order, sort_by_str) = match chars.next() {
-            Some('-') => (SortingOrder::Descending, chars.as_str()),
-            Some('+') => (SortingOrder::Ascending, chars.as_str()),
-            Some(c) => {
-                return Err(serde::de::Error::custom(format!(
-                    "invalid sorting order character, must be either '+' or '-': {}",
-                    c
-                )));
-            }
-            None => {
-                return Err(serde::de::Error::custom(
-                    "empty sorting value is not allowed",
-                ));
-            }
-        };
-
-        // Restrict allowed characters to alphanumeric, hyphen, and underscore.
-        // If we don't do this, weird edge cases could come up.
-        if !sort_by_str
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
-        {
-            return Err(serde::de::Error::custom(
-                "the sort-by field can only contain alphanumeric characters, hyphens, and underscores",
-            ));
-        }
+        let keys = string
+            .split(',')
+            .map(parse_sort_key)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(serde::de::Error::custom)?;
 
-        let sort_by = T::from_str(sort_by_str).map_err(|e| {
-            serde::de::Error::custom(format!("failed to parse sorting string: {}", e))
-        })?;
+        Ok(Sorting { keys })
+    }
+}
 
-        Ok(Sorting { by: sort_by, order })
+fn parse_sort_key<T>(string: &str) -> Result<(T, SortingOrder), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (order, sort_by_str) = match string.strip_prefix('-') {
+        Some(rest) => (SortingOrder::Descending, rest),
+        None => (
+            SortingOrder::Ascending,
+            string.strip_prefix('+').unwrap_or(string),
+        ),
+    };
+
+    if sort_by_str.is_empty() {
+        return Err("empty sorting value is not allowed".to_string());
     }
+
+    // Restrict allowed characters to alphanumeric, hyphen, and underscore.
+    // If we don't do this, weird edge cases could come up.
+    if !sort_by_str
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(
+            "the sort-by field can only contain alphanumeric characters, hyphens, and underscores"
+                .to_string(),
+        );
+    }
+
+    let sort_by =
+        T::from_str(sort_by_str).map_err(|e| format!("failed to parse sorting string: {}", e))?;
+
+    Ok((sort_by, order))
 }
 
 impl<T> serde::Serialize for Sorting<T>
@@ -71,11 +118,18 @@ where
     where
         S: serde::Serializer,
     {
-        let sign = match self.order {
-            SortingOrder::Ascending => "+",
-            SortingOrder::Descending => "-",
-        };
-        format!("{}{}", sign, self.by.to_string()).serialize(serializer)
+        self.keys
+            .iter()
+            .map(|(by, order)| {
+                let sign = match order {
+                    SortingOrder::Ascending => "+",
+                    SortingOrder::Descending => "-",
+                };
+                format!("{}{}", sign, by.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+            .serialize(serializer)
     }
 }
 
@@ -92,6 +146,7 @@ pub enum SortingOrder {
 #[cfg(test)]
 mod tests {
     use axum::extract::Query;
+    use proptest::prelude::{any, prop_assert_eq};
     use proptest::proptest;
     use proptest_derive::Arbitrary;
 
@@ -112,6 +167,25 @@ mod tests {
         fn serialization_roundtrip(sorting: SortingQuery) {
             test_serialization_roundtrip_equality_urlencoded(sorting);
         }
+
+        #[test]
+        fn keys_are_parsed_in_order(
+            keys in proptest::collection::vec(any::<(i32, SortingOrder)>(), 1..8)
+        ) {
+            let value = keys
+                .iter()
+                .map(|(by, order)| match order {
+                    // Ascending is the default, unless the key itself starts with '-'.
+                    SortingOrder::Ascending if *by >= 0 => by.to_string(),
+                    SortingOrder::Ascending => format!("+{}", by),
+                    SortingOrder::Descending => format!("-{}", by),
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let sorting = try_deserialize(&[("sort", &value)]).unwrap().sort;
+            prop_assert_eq!(sorting.keys(), keys.as_slice());
+        }
     }
 
     fn try_deserialize(query_params: &[(&str, &str)]) -> anyhow::Result<SortingQuery> {
@@ -130,6 +204,29 @@ mod tests {
         try_deserialize(&[("sort", "+100")]).unwrap();
         try_deserialize(&[("sort", "--100")]).unwrap();
         try_deserialize(&[("sort", "+0")]).unwrap();
+        try_deserialize(&[("sort", "100")]).unwrap();
+        try_deserialize(&[("sort", "1,-2,+3")]).unwrap();
+    }
+
+    #[test]
+    fn multiple_columns() {
+        #[derive(Debug, serde::Deserialize)]
+        struct StringSortingQuery {
+            sort: Sorting<String>,
+        }
+
+        let uri = uri_with_query_params([("sort", "foo,-bar,+baz_1")]);
+        let sorting = Query::<StringSortingQuery>::try_from_uri(&uri)
+            .unwrap()
+            .0
+            .sort;
+
+        assert_eq!(
+            sorting,
+            Sorting::new("foo".to_string(), SortingOrder::Ascending)
+                .then_by("bar".to_string(), SortingOrder::Descending)
+                .then_by("baz_1".to_string(), SortingOrder::Ascending)
+        );
     }
 
     #[test]
@@ -138,6 +235,7 @@ mod tests {
         try_deserialize(&[("sort", "-a")]).unwrap_err();
         try_deserialize(&[("sort", "-#100")]).unwrap_err();
         try_deserialize(&[("sort", "-1.2")]).unwrap_err();
+        try_deserialize(&[("sort", "1;2")]).unwrap_err();
     }
 
     #[test]
@@ -146,5 +244,8 @@ mod tests {
         try_deserialize(&[("sort", "-")]).unwrap_err();
         try_deserialize(&[("sort", "$")]).unwrap_err();
         try_deserialize(&[("sort", "")]).unwrap_err();
+        try_deserialize(&[("sort", "1,")]).unwrap_err();
+        try_deserialize(&[("sort", ",-1")]).unwrap_err();
+        try_deserialize(&[("sort", "1,,2")]).unwrap_err();
     }
 }
@@ -31,3 +31,41 @@ where
     let deserialized: T = serde_urlencoded::from_str(&serialized).unwrap();
     assert_eq!(item, deserialized);
 }
+
+/// Returns the JSON pointers of all numbers in `value` that JavaScript can't
+/// represent exactly, i.e. integers outside of
+/// [`MAX_SAFE_INTEGER`](crate::json_numbers::MAX_SAFE_INTEGER). Such values
+/// should be serialized as strings; see [`crate::json_numbers`].
+pub fn unsafe_json_integers(value: &serde_json::Value) -> Vec<String> {
+    fn visit(value: &serde_json::Value, pointer: String, found: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Number(n) => {
+                let max = crate::json_numbers::MAX_SAFE_INTEGER;
+                let is_safe = match (n.as_u64(), n.as_i64()) {
+                    (Some(n), _) => n <= max,
+                    (None, Some(n)) => n.unsigned_abs() <= max,
+                    // Floats are not integers, so they're out of scope.
+                    (None, None) => true,
+                };
+                if !is_safe {
+                    found.push(pointer);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    visit(item, format!("{pointer}/{i}"), found);
+                }
+            }
+            serde_json::Value::Object(map) => {
+                for (key, item) in map {
+                    visit(item, format!("{pointer}/{key}"), found);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut found = vec![];
+    visit(value, String::new(), &mut found);
+    found
+}
@@ -6,8 +6,10 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::capabilities::Authenticator;
 use sov_modules_api::digest::Digest;
-use sov_modules_api::runtime::capabilities::Kernel;
-use sov_modules_api::{CryptoSpec, Gas, GasArray, KernelWorkingSet, RawTx, Spec, StateCheckpoint};
+use sov_modules_api::runtime::capabilities::KernelSlotHooks;
+use sov_modules_api::{
+    CryptoSpec, Gas, GasArray, KernelWorkingSet, RawTx, Spec, StateAccessBudget, StateCheckpoint,
+};
 use sov_modules_stf_blueprint::{process_tx, ApplyTxResult, Runtime, TxEffect, TxProcessingError};
 use sov_rollup_interface::da::DaSpec;
 use sov_rollup_interface::services::batch_builder::{BatchBuilder, TxWithHash};
@@ -101,6 +103,8 @@ where
             },
             &self.sequencer,
             &ctx.gas_price,
+            &ctx.block_gas_limit,
+            &ctx.state_access_budget,
             ctx.visible_height,
             tx_scratchpad,
         );
@@ -149,7 +153,7 @@ where
     S: Spec,
     Da: DaSpec,
     R: Runtime<S, Da>,
-    K: Kernel<S, Da> + Send + Sync,
+    K: KernelSlotHooks<S, Da> + Send + Sync,
     Auth: Authenticator<Spec = S, DispatchCall = R>,
 {
     /// Attempt to add transaction to the mempool.
@@ -208,18 +212,7 @@ where
         //     state_checkpoint,
         // ););
 
-        let mut state_checkpoint = StateCheckpoint::new(self.current_storage.borrow().clone());
-        let gas_price = <S::Gas as Gas>::Price::ZEROED;
-        let kernel_working_set = KernelWorkingSet::from_kernel(&self.kernel, &mut state_checkpoint);
-        let visible_height = kernel_working_set.virtual_slot();
-
-        let mut ctx = BatchConstructionContext {
-            visible_height,
-            reward: 0,
-            gas_price,
-            state_checkpoint: Some(state_checkpoint),
-            current_batch_size_in_bytes: 0,
-        };
+        let mut ctx = self.new_construction_context();
 
         let mut txs = Vec::new();
 
@@ -288,12 +281,72 @@ where
 
         Ok(txs)
     }
+
+    /// Executes the transaction on top of the current state and returns the gas it used.
+    /// Neither the mempool nor the state are modified.
+    async fn estimate_gas(&self, raw: Vec<u8>) -> anyhow::Result<Vec<u64>> {
+        tracing::trace!(raw_tx = hex::encode(&raw), "`estimate_gas` has been called");
+
+        let mut ctx = self.new_construction_context();
+        let state_checkpoint = ctx.state_checkpoint.take().unwrap();
+        let res = process_tx(
+            &self.runtime,
+            &RawTx { data: raw },
+            &self.sequencer,
+            &ctx.gas_price,
+            &ctx.block_gas_limit,
+            &ctx.state_access_budget,
+            ctx.visible_height,
+            state_checkpoint.to_tx_scratchpad(),
+        );
+
+        // The changes made by the transaction are discarded along with its scratchpad.
+        match res {
+            Err(TxProcessingError { reason, .. }) => {
+                bail!("The transaction cannot be executed: {reason}")
+            }
+            Ok(ApplyTxResult { receipt, .. }) => match receipt.receipt {
+                TxEffect::Successful(_) => Ok(receipt.gas_used),
+                effect => bail!("The transaction would not be executed successfully: {effect:?}"),
+            },
+        }
+    }
+}
+
+impl<S, Da, R, K, Auth> FairBatchBuilder<S, Da, R, K, Auth>
+where
+    S: Spec,
+    Da: DaSpec,
+    R: Runtime<S, Da>,
+    K: KernelSlotHooks<S, Da>,
+    Auth: Authenticator<Spec = S, DispatchCall = R>,
+{
+    fn new_construction_context(&self) -> BatchConstructionContext<S> {
+        let mut state_checkpoint = StateCheckpoint::new(self.current_storage.borrow().clone());
+        let gas_price = <S::Gas as Gas>::Price::ZEROED;
+        let block_gas_limit = self.kernel.block_gas_limit(&mut state_checkpoint);
+        let state_access_budget = self.kernel.state_access_budget(&mut state_checkpoint);
+        let kernel_working_set = KernelWorkingSet::from_kernel(&self.kernel, &mut state_checkpoint);
+        let visible_height = kernel_working_set.virtual_slot();
+
+        BatchConstructionContext {
+            visible_height,
+            reward: 0,
+            gas_price,
+            block_gas_limit,
+            state_access_budget,
+            state_checkpoint: Some(state_checkpoint),
+            current_batch_size_in_bytes: 0,
+        }
+    }
 }
 
 struct BatchConstructionContext<S: Spec> {
     visible_height: u64,
     reward: u64,
     gas_price: <S::Gas as Gas>::Price,
+    block_gas_limit: S::Gas,
+    state_access_budget: StateAccessBudget,
     state_checkpoint: Option<StateCheckpoint<S>>,
     current_batch_size_in_bytes: usize,
 }
@@ -737,4 +790,47 @@ mod tests {
             assert_eq!(2, batch_builder.mempool.len());
         }
     }
+
+    mod estimate_gas {
+        use sov_prover_storage_manager::SimpleStorageManager;
+        use sov_rollup_interface::services::batch_builder::BatchBuilder;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn estimates_gas_without_touching_the_mempool() {
+            let value_setter_admin = TestPrivateKey::generate();
+            let additional_account = TestPrivateKey::generate();
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let mut storage_manager = SimpleStorageManager::new(tmpdir.path());
+            let storage = setup_runtime(
+                &mut storage_manager,
+                Some(value_setter_admin.pub_key()),
+                vec![(additional_account.pub_key(), 1_000_000_000)],
+                DEFAULT_SEQUENCER_DA_ADDRESS,
+                DEFAULT_SEQUENCER_ROLLUP_ADDRESS,
+            );
+            let batch_builder =
+                create_batch_builder(10_000, &tmpdir, Some(storage), DEFAULT_SEQUENCER_DA_ADDRESS);
+
+            let gas_used = batch_builder
+                .estimate_gas(generate_valid_tx(&value_setter_admin, 1))
+                .await
+                .unwrap();
+            assert_eq!(gas_used.len(), <S as Spec>::Gas::ZEROED.as_slice().len());
+            assert!(gas_used.iter().any(|gas| *gas > 0));
+
+            // The sender is not the admin of the value setter, so the transaction would revert.
+            let error = batch_builder
+                .estimate_gas(generate_valid_tx(&additional_account, 2))
+                .await
+                .unwrap_err();
+            assert!(error
+                .to_string()
+                .starts_with("The transaction would not be executed successfully"));
+
+            assert_eq!(0, batch_builder.mempool.len());
+        }
+    }
 }
@@ -28,6 +28,15 @@ pub struct SubmittedBatchInfo {
     pub num_txs: usize,
 }
 
+/// The response type to REST API calls that estimate the gas used by a transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimate {
+    /// The gas that the transaction would use if it were executed on top of the current
+    /// state, for each dimension of the gas.
+    pub gas_used: Vec<u64>,
+}
+
 /// The response type to the RPC method `sequencer_acceptTx`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcceptTxResponse {
@@ -10,7 +10,7 @@ use sov_rollup_interface::services::da::DaService;
 use tokio::sync::Mutex;
 
 use super::tx_status::{TxStatus, TxStatusNotifier};
-use super::{AcceptTxResponse, SubmittedBatchInfo};
+use super::{AcceptTxResponse, GasEstimate, SubmittedBatchInfo};
 
 /// Single data structure that manages mempool and batch producing.
 pub struct Sequencer<B: BatchBuilder, Da: DaService, Auth: Authenticator>(Arc<Inner<B, Da, Auth>>);
@@ -134,6 +134,15 @@ where
         Ok(tx_hash)
     }
 
+    async fn estimate_gas(&self, tx: Vec<u8>) -> anyhow::Result<GasEstimate> {
+        let batch_builder = self.0.batch_builder.lock().await;
+
+        tracing::debug!(tx = hex::encode(&tx), "Estimating the gas of a transaction");
+        let gas_used = batch_builder.estimate_gas(tx).await?;
+
+        Ok(GasEstimate { gas_used })
+    }
+
     async fn tx_status(
         &self,
         tx_hash: &TxHash,
@@ -227,7 +236,11 @@ mod axum_router {
                     .route("/txs", axum::routing::post(Self::axum_accept_tx))
                     .route("/txs/:tx_hash", axum::routing::get(Self::axum_get_tx))
                     .route("/txs/:tx_hash/ws", axum::routing::get(Self::axum_get_tx_ws))
-                    .route("/batches", axum::routing::post(Self::axum_submit_batch)),
+                    .route("/batches", axum::routing::post(Self::axum_submit_batch))
+                    .route(
+                        "/gas-estimates",
+                        axum::routing::post(Self::axum_estimate_gas),
+                    ),
             )
         }
 
@@ -313,6 +326,7 @@ mod axum_router {
                         details: json_obj!({
                             "message": err.to_string(),
                         }),
+                        retry_after: None,
                     }
                     .into_response());
                 }
@@ -325,6 +339,20 @@ mod axum_router {
             .into())
         }
 
+        async fn axum_estimate_gas(
+            sequencer: State<Self>,
+            tx: Json<AcceptTx>,
+        ) -> ApiResult<GasEstimate> {
+            let tx = tx.0.body.blob;
+            let authed_tx = Auth::encode(tx)
+                .map_err(|e| errors::bad_request_400("Failed to encode transaction", e))?;
+
+            match sequencer.estimate_gas(authed_tx.data).await {
+                Ok(estimate) => Ok(estimate.into()),
+                Err(err) => Err(errors::bad_request_400("Failed to estimate gas", err)),
+            }
+        }
+
         async fn axum_submit_batch(
             sequencer: State<Self>,
             batch: Json<SubmitBatch>,
@@ -345,6 +373,7 @@ mod axum_router {
                     details: json_obj!({
                         "message": err.to_string(),
                     }),
+                    retry_after: None,
                 }
                 .into_response()),
             }
@@ -410,6 +439,13 @@ mod tests {
                 .collect();
             Ok(txs)
         }
+
+        async fn estimate_gas(&self, tx: Vec<u8>) -> anyhow::Result<Vec<u64>> {
+            if tx.is_empty() {
+                anyhow::bail!("Empty transaction");
+            }
+            Ok(vec![tx.len() as u64, 0])
+        }
     }
 
     #[test]
@@ -462,7 +498,7 @@ mod tests {
                 assert_eq!(tx1, batch.txs[0].data);
                 assert_eq!(tx2, batch.txs[1].data);
             }
-            BlobData::Proof(_) => panic!("Expected a batch, but got a proof"),
+            other => panic!("Expected a batch, but got {other:?}"),
         }
     }
 
@@ -506,7 +542,42 @@ mod tests {
             BlobData::Batch(batch) => {
                 assert_eq!(tx, batch.txs[0].data);
             }
-            BlobData::Proof(_) => panic!("Expected a batch, but got a proof"),
+            other => panic!("Expected a batch, but got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_estimate_gas() {
+        let sequencer = new_sequencer(MockBatchBuilder::default()).await;
+        let client = sequencer.client();
+
+        let tx: Vec<u8> = vec![1, 2, 3, 4, 5];
+
+        let estimate = client
+            .estimate_gas(&types::EstimateGasBody {
+                body: BASE64_STANDARD.encode(&tx),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(estimate.data.gas_used, vec![5, 0]);
+
+        let error_response = client
+            .estimate_gas(&types::EstimateGasBody {
+                body: BASE64_STANDARD.encode(Vec::<u8>::new()),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(error_response.status().map(|s| s.as_u16()), Some(400));
+
+        // Estimating the gas leaves the mempool untouched.
+        let error_response = client
+            .publish_batch(&types::PublishBatchBody {
+                transactions: vec![],
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(error_response.status().map(|s| s.as_u16()), Some(409));
+    }
 }
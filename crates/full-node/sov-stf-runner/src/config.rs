@@ -1,10 +1,14 @@
 use std::fs::File;
 use std::io::Read;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use sov_ledger_apis::LedgerRoutesConfig;
+use sov_metrics::MetricsConfig;
+use sov_rest_utils::RouterLayersConfig;
 
 /// Configuration for StateTransitionRunner.
 #[derive(Debug, Clone, PartialEq, Deserialize, JsonSchema)]
@@ -17,6 +21,31 @@ pub struct RunnerConfig {
     pub rpc_config: HttpServerConfig,
     /// Axum server configuration.
     pub axum_config: HttpServerConfig,
+    /// Limits enforced by the JSON-RPC server.
+    #[serde(default)]
+    pub rpc_limits: RpcLimitsConfig,
+    /// Only exposes the read and query endpoints of the API servers, omitting the ones
+    /// that submit transactions. Meant for archive and query nodes.
+    #[serde(default)]
+    pub read_only: bool,
+    /// The path (e.g. `/metrics`) at which the Axum server exposes the Prometheus metrics,
+    /// in the text exposition format. The metrics aren't exposed if not set.
+    #[serde(default)]
+    pub metrics_path: Option<String>,
+    /// Configuration of the Prometheus metrics of the node.
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    /// Limits enforced by the ledger REST API.
+    #[serde(default)]
+    pub ledger_routes: LedgerRoutesConfig,
+    /// Optional layers applied to the whole REST API of the Axum server, e.g. a per-IP
+    /// rate limit.
+    #[serde(default)]
+    pub rest_layers: RouterLayersConfig,
+    /// The maximum number of batches of a slot which are executed concurrently. Batches are
+    /// executed sequentially if not set.
+    #[serde(default)]
+    pub batch_parallelism: Option<NonZeroUsize>,
 }
 
 /// Configuration for HTTP server(s) exposed by the node.
@@ -28,11 +57,54 @@ pub struct HttpServerConfig {
     pub bind_port: u16,
 }
 
+/// Limits enforced by the JSON-RPC server on incoming requests.
+///
+/// They protect the node against requests that are cheap to send but expensive to serve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+pub struct RpcLimitsConfig {
+    /// Maximum number of calls in a single JSON-RPC batch request.
+    pub max_batch_size: u32,
+    /// Maximum nesting depth of the parameters of a single JSON-RPC call.
+    pub max_params_depth: usize,
+}
+
+impl Default for RpcLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 100,
+            max_params_depth: 32,
+        }
+    }
+}
+
 /// Simple storage configuration
 #[derive(Debug, Clone, PartialEq, Deserialize, JsonSchema)]
 pub struct StorageConfig {
     /// Path that can be utilized by concrete implementation
     pub path: PathBuf,
+    /// The maximum number of competing unfinalized forks that the storage manager keeps in
+    /// memory. The oldest forks are discarded beyond this limit. Unbounded if not set.
+    #[serde(default)]
+    pub max_unfinalized_forks: Option<usize>,
+    /// Overrides [`StorageConfig::path`] for the state database.
+    #[serde(default)]
+    pub state_path: Option<PathBuf>,
+    /// Overrides [`StorageConfig::path`] for the accessory database.
+    #[serde(default)]
+    pub accessory_path: Option<PathBuf>,
+    /// Overrides [`StorageConfig::path`] for the ledger database.
+    #[serde(default)]
+    pub ledger_path: Option<PathBuf>,
+    /// Module names (e.g. `Bank`) and event keys (e.g. `Bank/TokenCreated`) of
+    /// the events that can be looked up by key. All events remain available
+    /// through their transactions. All events are indexed if not set.
+    #[serde(default)]
+    pub indexed_events: Option<Vec<String>>,
+    /// The number of notifications buffered by the websocket subscriptions to new slots,
+    /// events and aggregated proofs. Subscribers falling further behind are disconnected.
+    /// Defaults to `LedgerDb::DEFAULT_SUBSCRIPTION_CAPACITY` if not set.
+    #[serde(default)]
+    pub subscription_capacity: Option<usize>,
 }
 
 /// Prover service configuration.
@@ -78,6 +150,7 @@ pub fn from_toml_path<P: AsRef<Path>, R: DeserializeOwned>(path: P) -> anyhow::R
 #[cfg(test)]
 mod tests {
     use std::io::Write;
+    use std::num::NonZeroU32;
     use std::path::PathBuf;
     use std::str::FromStr;
 
@@ -135,6 +208,13 @@ mod tests {
                     bind_host: "127.0.0.1".to_string(),
                     bind_port: 12346,
                 },
+                rpc_limits: Default::default(),
+                read_only: false,
+                metrics_path: None,
+                metrics: Default::default(),
+                ledger_routes: Default::default(),
+                rest_layers: Default::default(),
+                batch_parallelism: None,
             },
 
             da: sov_celestia_adapter::CelestiaConfig {
@@ -149,6 +229,12 @@ mod tests {
             },
             storage: StorageConfig {
                 path: PathBuf::from("/tmp"),
+                max_unfinalized_forks: None,
+                state_path: None,
+                accessory_path: None,
+                ledger_path: None,
+                indexed_events: None,
+                subscription_capacity: None,
             },
             proof_manager: ProofManagerConfig {
                 aggregated_proof_block_jump: 22,
@@ -160,4 +246,87 @@ mod tests {
         };
         assert_eq!(config, expected);
     }
+
+    #[test]
+    fn test_ledger_routes_config() {
+        let config = r#"
+            genesis_height = 31337
+            da_polling_interval_ms = 10000
+            [rpc_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12345
+            [axum_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12346
+            [ledger_routes]
+            max_inlined_children = 10
+        "#;
+
+        let config_file = create_config_from(config);
+
+        let config: RunnerConfig = from_toml_path(config_file.path()).unwrap();
+        assert_eq!(
+            config.ledger_routes,
+            LedgerRoutesConfig {
+                max_inlined_children: 10,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_metrics_config() {
+        let config = r#"
+            genesis_height = 31337
+            da_polling_interval_ms = 10000
+            [rpc_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12345
+            [axum_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12346
+            [metrics]
+            slot_processing_buckets = [0.5, 1.0]
+        "#;
+
+        let config_file = create_config_from(config);
+
+        let config: RunnerConfig = from_toml_path(config_file.path()).unwrap();
+        assert_eq!(
+            config.metrics,
+            MetricsConfig {
+                slot_processing_buckets: vec![0.5, 1.0],
+            }
+        );
+    }
+
+    #[test]
+    fn test_rest_layers_config() {
+        let config = r#"
+            genesis_height = 31337
+            da_polling_interval_ms = 10000
+            [rpc_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12345
+            [axum_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12346
+            [rest_layers.rate_limit]
+            bucket_size = 100
+            refill_per_second = 10
+        "#;
+
+        let config_file = create_config_from(config);
+
+        let config: RunnerConfig = from_toml_path(config_file.path()).unwrap();
+        assert_eq!(
+            config.rest_layers,
+            RouterLayersConfig {
+                rate_limit: Some(sov_rest_utils::RateLimitConfig {
+                    bucket_size: NonZeroU32::new(100).unwrap(),
+                    refill_per_second: NonZeroU32::new(10).unwrap(),
+                }),
+            }
+        );
+    }
 }
@@ -8,6 +8,8 @@ mod config;
 pub mod mock;
 #[cfg(feature = "native")]
 mod prover_service;
+#[cfg(feature = "native")]
+mod rpc_limits;
 
 #[cfg(feature = "native")]
 use std::path::Path;
@@ -15,7 +17,7 @@ use std::path::Path;
 #[cfg(feature = "native")]
 use anyhow::Context;
 #[cfg(feature = "native")]
-pub use config::HttpServerConfig;
+pub use config::{HttpServerConfig, RpcLimitsConfig};
 #[cfg(feature = "native")]
 pub use prover_service::*;
 #[cfg(feature = "native")]
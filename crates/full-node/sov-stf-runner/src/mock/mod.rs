@@ -55,6 +55,7 @@ impl<InnerVm: Zkvm, OuterVm: Zkvm, Cond: ValidityCondition, Da: DaSpec>
                 inner: (),
                 gas_price: vec![],
             }],
+            blob_results: vec![],
             witness: (),
         }
     }
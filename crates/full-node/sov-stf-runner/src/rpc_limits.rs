@@ -0,0 +1,82 @@
+//! JSON-RPC middleware enforcing [`RpcLimitsConfig::max_params_depth`](crate::RpcLimitsConfig).
+use futures::future::{Either, Ready};
+use jsonrpsee::server::middleware::rpc::RpcServiceT;
+use jsonrpsee::types::error::{ErrorObject, INVALID_PARAMS_CODE};
+use jsonrpsee::types::Request;
+use jsonrpsee::MethodResponse;
+
+/// Rejects calls whose parameters are nested deeper than `max_depth`
+/// with a standard "Invalid params" error, before they reach the method handler.
+#[derive(Clone)]
+pub(crate) struct ParamsDepthLimit<S> {
+    service: S,
+    max_depth: usize,
+}
+
+impl<S> ParamsDepthLimit<S> {
+    pub(crate) fn new(service: S, max_depth: usize) -> Self {
+        Self { service, max_depth }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for ParamsDepthLimit<S>
+where
+    S: RpcServiceT<'a>,
+{
+    type Future = Either<S::Future, Ready<MethodResponse>>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        let depth = request
+            .params
+            .as_ref()
+            .map_or(0, |params| json_depth(params.get()));
+
+        if depth > self.max_depth {
+            let error = ErrorObject::owned(
+                INVALID_PARAMS_CODE,
+                format!(
+                    "Parameters are nested too deeply: depth {} exceeds the limit of {}",
+                    depth, self.max_depth
+                ),
+                None::<()>,
+            );
+            return Either::Right(futures::future::ready(MethodResponse::error(
+                request.id, error,
+            )));
+        }
+
+        Either::Left(self.service.call(request))
+    }
+}
+
+/// Returns the maximum nesting depth of arrays and objects in a valid JSON document.
+fn json_depth(json: &str) -> usize {
+    let mut depth = 0usize;
+    let mut max_depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for byte in json.bytes() {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'[' | b'{' => {
+                depth += 1;
+                max_depth = max_depth.max(depth);
+            }
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    max_depth
+}
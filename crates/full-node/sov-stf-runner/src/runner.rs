@@ -1,11 +1,14 @@
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use jsonrpsee::server::middleware::rpc::RpcServiceBuilder;
+use jsonrpsee::server::BatchRequestConfig;
 use jsonrpsee::RpcModule;
 use sov_db::ledger_db::{LedgerDb, SlotCommit};
 use sov_db::schema::{CacheDb, SchemaBatch};
+use sov_rest_utils::RouterLayersConfig;
 use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::services::da::{DaService, SlotData};
 use sov_rollup_interface::stf::StateTransitionFunction;
@@ -14,8 +17,9 @@ use sov_rollup_interface::zk::{StateTransitionWitness, Zkvm, ZkvmGuest, ZkvmHost
 use tokio::sync::watch;
 use tracing::{debug, error, info};
 
+use crate::rpc_limits::ParamsDepthLimit;
 use crate::state_manager::StateManager;
-use crate::{ProofManager, ProverService, RunnerConfig, StateTransitionInfo};
+use crate::{ProofManager, ProverService, RpcLimitsConfig, RunnerConfig, StateTransitionInfo};
 
 type GenesisParams<ST, InnerVm, OuterVm, Da> =
     <ST as StateTransitionFunction<InnerVm, OuterVm, Da>>::GenesisParams;
@@ -44,6 +48,9 @@ where
     state_manager: StateManager<Stf::StateRoot, Stf::Witness, Sm, Da>,
     listen_address_rpc: SocketAddr,
     listen_address_axum: SocketAddr,
+    rpc_limits: RpcLimitsConfig,
+    metrics_path: Option<String>,
+    rest_layers: RouterLayersConfig,
     proof_manager: ProofManager<Ps>,
     sync_state: Arc<DaSyncState>,
 }
@@ -245,6 +252,9 @@ where
             state_manager,
             listen_address_rpc,
             listen_address_axum,
+            rpc_limits: runner_config.rpc_limits,
+            metrics_path: runner_config.metrics_path,
+            rest_layers: runner_config.rest_layers,
             proof_manager,
 
             sync_state: Arc::new(DaSyncState {
@@ -258,8 +268,16 @@ where
     ///  # Arguments:
     ///   * methods: [`RpcModule`] with all RPC methods.
     ///   * channel: If `Some`, notification with actual [`SocketAddr`] where RPC server listens to.
+    ///
+    /// Batch requests and call parameters are bounded by the [`RpcLimitsConfig`] of the runner.
     pub async fn start_rpc_server(&self, methods: RpcModule<()>) -> anyhow::Result<SocketAddr> {
+        let max_params_depth = self.rpc_limits.max_params_depth;
+        let rpc_middleware = RpcServiceBuilder::new()
+            .layer_fn(move |service| ParamsDepthLimit::new(service, max_params_depth));
+
         let server = jsonrpsee::server::ServerBuilder::default()
+            .set_batch_request_config(BatchRequestConfig::Limit(self.rpc_limits.max_batch_size))
+            .set_rpc_middleware(rpc_middleware)
             .build([self.listen_address_rpc].as_ref())
             .await?;
         let rpc_address = server.local_addr()?;
@@ -275,13 +293,33 @@ where
     }
 
     /// Starts an Axum server with the provided router.
-    pub async fn start_axum_server(&self, router: axum::Router<()>) -> anyhow::Result<SocketAddr> {
+    ///
+    /// The router goes through the [`RunnerConfig::rest_layers`], and the Prometheus metrics
+    /// are served alongside it if [`RunnerConfig::metrics_path`] is set.
+    pub async fn start_axum_server(
+        &self,
+        mut router: axum::Router<()>,
+    ) -> anyhow::Result<SocketAddr> {
+        router = sov_rest_utils::optional_router_layers(router, self.rest_layers.clone());
+        if let Some(metrics_path) = &self.metrics_path {
+            router = router.merge(sov_metrics::metrics_router(
+                metrics_path,
+                sov_metrics::global_registry().clone(),
+            ));
+        }
+
         let listener = tokio::net::TcpListener::bind(self.listen_address_axum).await?;
         let rest_address = listener.local_addr()?;
 
         tokio::spawn(async move {
             info!(%rest_address, "Starting REST API server");
-            axum::serve(listener, router).await.unwrap();
+            // The peer addresses are needed by the per-IP rate limit.
+            axum::serve(
+                listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
         });
 
         Ok(rest_address)
@@ -385,6 +423,7 @@ where
                 "Extracted relevant blobs"
             );
 
+            let slot_processing_start = Instant::now();
             let slot_result = self.stf.apply_slot(
                 self.state_manager.get_state_root(),
                 stf_pre_state,
@@ -393,6 +432,11 @@ where
                 &filtered_block.validity_condition(),
                 relevant_blobs.as_iters(),
             );
+            sov_metrics::update_metrics(|metrics| {
+                metrics
+                    .slot_processing_seconds
+                    .observe(slot_processing_start.elapsed().as_secs_f64());
+            });
 
             // Getting relevant proofs
             let relevant_proofs = self
@@ -2,6 +2,7 @@
 use std::collections::VecDeque;
 
 use sov_db::ledger_db::{LedgerDb, SlotCommit};
+use sov_db::schema::types::SlotNumber;
 use sov_db::schema::{CacheDb, SchemaBatch};
 use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::services::da::{DaService, SlotData};
@@ -19,6 +20,8 @@ struct ForkPoint<Da: DaService, StateRoot> {
     block: Da::FilteredBlock,
     /// Last observed state root before the fork.
     pre_state_root: StateRoot,
+    /// The first slot number which has been rolled back by the fork.
+    slot_number: u64,
 }
 
 /// StateManager controls storage lifecycle for [`StateTransitionFunction`],
@@ -37,6 +40,9 @@ where
     state_root: StateRoot,
     seen_state_transitions: VecDeque<StateTransitionInfo<StateRoot, Witness, Da::Spec>>,
     rpc_storage_sender: watch::Sender<Sm::StfState>,
+    // Removal of the ledger entries of the slots rolled back by the last reorg.
+    // It is saved together with the first slot of the new fork.
+    pending_ledger_rollback: Option<SchemaBatch>,
 }
 
 impl<StateRoot, Witness, Sm, Da> StateManager<StateRoot, Witness, Sm, Da>
@@ -58,6 +64,7 @@ where
             state_root: initial_state_root,
             seen_state_transitions: Default::default(),
             rpc_storage_sender,
+            pending_ledger_rollback: None,
         }
     }
 
@@ -74,12 +81,19 @@ where
         let reorg_happened = if let Some(ForkPoint {
             block: new_block,
             pre_state_root,
+            slot_number,
         }) = self
             .has_reorg_happened(filtered_block.header(), da_service)
             .await?
         {
             filtered_block = new_block;
             self.state_root = pre_state_root;
+            // The ledger entries of the orphaned fork are collected before the ledger is switched to the new fork.
+            self.pending_ledger_rollback = Some(
+                self.ledger_db
+                    .materialize_rollback(SlotNumber(slot_number))
+                    .await?,
+            );
             tracing::info!(
                 header = %filtered_block.header().display(),
                 "Resuming execution at fork point's height"
@@ -141,8 +155,13 @@ where
             .await?;
 
         let mut ledger_change_set = self
-            .ledger_db
-            .materialize_slot(slot_commit, new_state_root.as_ref())?;
+            .pending_ledger_rollback
+            .take()
+            .unwrap_or_else(SchemaBatch::new);
+        ledger_change_set.merge(
+            self.ledger_db
+                .materialize_slot(slot_commit, new_state_root.as_ref())?,
+        );
         for aggregated_proof in aggregated_proofs {
             let this_height_data = self
                 .ledger_db
@@ -195,6 +214,7 @@ where
                         return Ok(Some(ForkPoint {
                             block,
                             pre_state_root: state_transition.initial_state_root().clone(),
+                            slot_number: state_transition.slot_number,
                         }));
                     }
                 }
@@ -318,6 +338,10 @@ mod tests {
     async fn setup_state_manager(path: &std::path::Path) -> anyhow::Result<TestStateManager> {
         let storage_config = sov_state::config::Config {
             path: path.to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
         };
 
         let mut storage_manager: ProverStorageManager<MockDaSpec, S> =
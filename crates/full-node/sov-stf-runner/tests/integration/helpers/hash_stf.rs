@@ -2,7 +2,10 @@ use borsh::BorshDeserialize;
 use sha2::Digest;
 use sov_modules_api::{BlobData, ProofOutcome, ProofReceipt};
 use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec, RelevantBlobIters};
-use sov_rollup_interface::stf::{ApplySlotOutput, StateTransitionFunction};
+use sov_rollup_interface::stf::{
+    ApplySlotOutput, BatchReceipt, StateTransitionFunction, StoredEvent, TransactionReceipt,
+    TxEffect,
+};
 use sov_rollup_interface::zk::aggregated_proof::SerializedAggregatedProof;
 use sov_rollup_interface::zk::{ValidityCondition, Zkvm};
 use sov_state::namespaces::User;
@@ -26,6 +29,30 @@ impl<Cond> HashStf<Cond> {
         }
     }
 
+    /// Returns the key of the event emitted by the batch `data`.
+    pub fn event_key(data: &[u8]) -> String {
+        hex::encode(sha2::Sha256::digest(data))
+    }
+
+    /// Every batch is saved in the ledger as a single transaction, whose hash is the hash of
+    /// the batch, emitting a single event keyed by [`HashStf::event_key`].
+    fn batch_receipt(data: &[u8]) -> BatchReceipt<[u8; 32], ()> {
+        let hash: [u8; 32] = sha2::Sha256::digest(data).into();
+        BatchReceipt {
+            batch_hash: hash,
+            tx_receipts: vec![TransactionReceipt {
+                tx_hash: hash,
+                body_to_save: None,
+                events: vec![StoredEvent::new(Self::event_key(data).as_bytes(), data)],
+                receipt: TxEffect::Successful(()),
+                gas_used: vec![],
+                gas_limit: vec![],
+            }],
+            gas_price: vec![],
+            inner: hash,
+        }
+    }
+
     fn hash_key() -> SlotKey {
         let prefix = Prefix::new(b"root".to_vec());
         SlotKey::singleton(&prefix)
@@ -130,13 +157,17 @@ impl<InnerVm: Zkvm, OuterVm: Zkvm, Cond: ValidityCondition, Da: DaSpec>
         );
         hasher.update(existing_cache.value());
 
+        let mut batch_receipts = Vec::new();
         let mut proof_receipts = Vec::new();
         for blob in all_blobs {
             let data = blob.full_data();
 
             if !data.is_empty() {
                 match BlobData::try_from_slice(data).unwrap() {
-                    BlobData::Batch(_) => hasher.update(data),
+                    BlobData::Batch(_) | BlobData::SequencedBatch { .. } => {
+                        hasher.update(data);
+                        batch_receipts.push(HashStf::<Cond>::batch_receipt(data));
+                    }
                     BlobData::Proof(raw_proof) => proof_receipts.push(ProofReceipt {
                         raw_proof: SerializedAggregatedProof {
                             raw_aggregated_proof: raw_proof,
@@ -162,8 +193,8 @@ impl<InnerVm: Zkvm, OuterVm: Zkvm, Cond: ValidityCondition, Da: DaSpec>
             state_root,
             change_set,
             proof_receipts,
-            // TODO: Add batch receipts to inspection
-            batch_receipts: vec![],
+            batch_receipts,
+            blob_results: vec![],
             witness,
         }
     }
@@ -94,6 +94,10 @@ impl TestNode {
         Ok(self.agg_proof_saved_in_db_sub.recv().await?)
     }
 
+    pub fn ledger_db(&self) -> &LedgerDb {
+        &self.ledger_db
+    }
+
     pub async fn get_latest_public_data(
         &self,
     ) -> Result<Option<AggregatedProofPublicData>, anyhow::Error> {
@@ -140,6 +144,12 @@ pub fn initialize_runner(
     let rollup_config = RollupConfig::<_, MockDaConfig> {
         storage: StorageConfig {
             path: path.to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
+            indexed_events: None,
+            subscription_capacity: None,
         },
         runner: RunnerConfig {
             genesis_height: 0,
@@ -152,6 +162,13 @@ pub fn initialize_runner(
                 bind_host: "127.0.0.1".to_string(),
                 bind_port: 0,
             },
+            rpc_limits: Default::default(),
+            read_only: false,
+            metrics_path: None,
+            metrics: Default::default(),
+            ledger_routes: Default::default(),
+            rest_layers: Default::default(),
+            batch_parallelism: None,
         },
         da: MockDaConfig::instant_with_sender(da_service.da_service().sequencer_address()),
         proof_manager: ProofManagerConfig {
@@ -167,6 +184,10 @@ pub fn initialize_runner(
 
     let storage_config = sov_state::config::Config {
         path: path.to_path_buf(),
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
     let mut storage_manager = ProverStorageManager::new(storage_config).unwrap();
     let genesis_block = MockBlockHeader::from_height(0);
@@ -1,5 +1,6 @@
 mod aggregated_proof_tests;
 mod helpers;
 mod prover_service_tests;
+mod rpc_limits_tests;
 mod runner_initialization_tests;
 mod runner_reorg_tests;
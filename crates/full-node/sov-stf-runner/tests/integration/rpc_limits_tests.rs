@@ -0,0 +1,94 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use jsonrpsee::RpcModule;
+use serde_json::{json, Value};
+use sov_mock_da::{MockAddress, MockBlock, MockDaService};
+use sov_rollup_interface::services::da::DaServiceWithRetries;
+use sov_stf_runner::{InitVariant, RpcLimitsConfig};
+
+use crate::helpers::runner_init::initialize_runner;
+
+async fn start_rpc_server(tmpdir: &tempfile::TempDir) -> SocketAddr {
+    let da_service = Arc::new(DaServiceWithRetries::new_fast(MockDaService::new(
+        MockAddress::new([11u8; 32]),
+    )));
+    let init_variant = InitVariant::Genesis {
+        block: MockBlock::default(),
+        genesis_params: vec![1],
+    };
+    let (runner, _) = initialize_runner(da_service, tmpdir.path(), init_variant, 1, None);
+
+    let mut methods = RpcModule::new(());
+    methods
+        .register_method("echo", |params, _| params.one::<Value>())
+        .unwrap();
+
+    runner.start_rpc_server(methods).await.unwrap()
+}
+
+async fn post(addr: SocketAddr, body: Value) -> Value {
+    let response = reqwest::Client::new()
+        .post(format!("http://{}", addr))
+        .header("content-type", "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .unwrap();
+    serde_json::from_str(&response.text().await.unwrap()).unwrap()
+}
+
+fn echo_call(id: u32, param: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": "echo", "params": [param] })
+}
+
+fn nested_array(depth: usize) -> Value {
+    (0..depth).fold(json!(0), |inner, _| json!([inner]))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn batch_size_is_limited() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let addr = start_rpc_server(&tmpdir).await;
+    let max_batch_size = RpcLimitsConfig::default().max_batch_size;
+
+    let batch: Vec<_> = (0..max_batch_size)
+        .map(|id| echo_call(id, json!(id)))
+        .collect();
+    let response = post(addr, Value::Array(batch)).await;
+    let responses = response
+        .as_array()
+        .expect("Batch response must be an array");
+    assert_eq!(responses.len(), max_batch_size as usize);
+    assert!(responses.iter().all(|r| r.get("result").is_some()));
+
+    let batch: Vec<_> = (0..=max_batch_size)
+        .map(|id| echo_call(id, json!(id)))
+        .collect();
+    let response = post(addr, Value::Array(batch)).await;
+    assert!(
+        response["error"]["code"].is_i64(),
+        "An over-limit batch must be rejected with a single error: {}",
+        response
+    );
+    assert!(response.get("result").is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn params_depth_is_limited() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let addr = start_rpc_server(&tmpdir).await;
+    // The params array itself adds one level of nesting.
+    let max_depth = RpcLimitsConfig::default().max_params_depth - 1;
+
+    let param = nested_array(max_depth);
+    let response = post(addr, echo_call(1, param.clone())).await;
+    assert_eq!(response["result"], param);
+
+    let response = post(addr, echo_call(2, nested_array(max_depth + 1))).await;
+    assert_eq!(response["id"], 2);
+    assert_eq!(
+        response["error"]["code"],
+        jsonrpsee::types::error::INVALID_PARAMS_CODE
+    );
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
+use sha2::Digest;
+use sov_db::ledger_db::event_helper::get_events_by_key_helper;
 use sov_db::ledger_db::LedgerDb;
+use sov_db::schema::types::BatchNumber;
 use sov_mock_da::{
     MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaService, MockDaSpec, MockValidityCond,
     PlannedFork,
@@ -8,7 +11,9 @@ use sov_mock_da::{
 use sov_mock_zkvm::MockZkVerifier;
 use sov_modules_api::{BlobData, RawTx, StateTransitionFunction};
 use sov_prover_storage_manager::{ProverStorageManager, SimpleStorageManager};
+use sov_rollup_interface::rpc::LedgerStateProvider;
 use sov_rollup_interface::services::da::{DaService, DaServiceWithRetries};
+use sov_rollup_interface::stf::StoredEvent;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_state::storage::NativeStorage;
 use sov_state::{ArrayWitness, ProverStorage, Storage};
@@ -88,6 +93,90 @@ async fn test_simple_reorg_case() {
     assert_eq!(expected_committed_root_hash.unwrap(), committed_root_hash);
 }
 
+#[tokio::test]
+async fn test_reorg_rolls_back_ledger_entries() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let sequencer_address = MockAddress::new([11u8; 32]);
+
+    let main_chain_blobs = vec![
+        batch(vec![1, 1, 1, 1]),
+        batch(vec![2, 2, 2, 2]),
+        batch(vec![3, 3, 3, 3]),
+        batch(vec![4, 4, 4, 4]),
+    ];
+    let fork_blobs = vec![
+        batch(vec![13, 13, 13, 13]),
+        batch(vec![14, 14, 14, 14]),
+        batch(vec![15, 15, 15, 15]),
+    ];
+    let orphaned_blobs = main_chain_blobs[2..].to_vec();
+    let expected_final_blobs = main_chain_blobs[..2]
+        .iter()
+        .chain(&fork_blobs)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut da_service = DaServiceWithRetries::new_fast(
+        MockDaService::new(sequencer_address)
+            .with_finality(4)
+            .with_wait_attempts(2),
+    );
+    let genesis_block = da_service.get_block_at(0).await.unwrap();
+    da_service
+        .da_service_mut()
+        .set_planned_fork(PlannedFork::new(5, 2, fork_blobs))
+        .await
+        .unwrap();
+
+    let da_service = Arc::new(da_service);
+    for data in main_chain_blobs {
+        let fee = da_service.estimate_fee(data.len()).await.unwrap();
+        da_service.send_transaction(&data, fee).await.unwrap();
+    }
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block: genesis_block,
+        genesis_params: vec![1, 2, 3, 4, 5],
+    };
+    let (mut runner, test_node) =
+        initialize_runner(da_service, tmp_dir.path(), init_variant, 1, None);
+    assert!(runner.run_in_process().await.is_err());
+    let ledger_db = test_node.ledger_db();
+
+    // Every batch of the canonical chain holds a single transaction with a single event,
+    // numbered contiguously after the rollback of the orphaned slots.
+    let next_items = ledger_db.get_next_items_numbers().unwrap();
+    let expected_count = expected_final_blobs.len() as u64;
+    assert_eq!(next_items.batch_number, expected_count);
+    assert_eq!(next_items.tx_number, expected_count);
+    assert_eq!(next_items.event_number, expected_count);
+
+    let (head_number, head_slot) = ledger_db.get_head_slot().unwrap().unwrap();
+    assert_eq!(head_number.0 + 1, next_items.slot_number);
+    assert_eq!(head_slot.batches.end, BatchNumber(expected_count));
+
+    for (number, blob) in (0..).zip(&expected_final_blobs) {
+        assert_eq!(
+            ledger_db
+                .get_tx_numbers_by_hash(&sha2::Sha256::digest(blob).into())
+                .await
+                .unwrap(),
+            vec![number]
+        );
+        assert_eq!(events_by_key(ledger_db, blob).await, vec![number]);
+    }
+
+    // No entry of the orphaned fork can be looked up anymore.
+    for blob in &orphaned_blobs {
+        assert!(ledger_db
+            .get_tx_numbers_by_hash(&sha2::Sha256::digest(blob).into())
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(events_by_key(ledger_db, blob).await.is_empty());
+    }
+}
+
 #[tokio::test]
 #[ignore = "TBD"]
 async fn test_several_reorgs() {}
@@ -163,6 +252,10 @@ fn get_saved_root_hash(
 ) -> anyhow::Result<Option<<ProverStorage<S> as Storage>::Root>> {
     let storage_config = sov_state::config::Config {
         path: path.to_path_buf(),
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
     let mut storage_manager = ProverStorageManager::<MockDaSpec, S>::new(storage_config).unwrap();
     let mock_block_header = MockBlockHeader::from_height(1000000);
@@ -249,6 +342,28 @@ fn get_result_from_blocks(
     (state_root, root_hash)
 }
 
+struct EventNumber(u64);
+
+impl TryFrom<(u64, StoredEvent)> for EventNumber {
+    type Error = anyhow::Error;
+
+    fn try_from((number, _): (u64, StoredEvent)) -> Result<Self, Self::Error> {
+        Ok(Self(number))
+    }
+}
+
+/// Returns the numbers of the events emitted by the batch `blob`, looked up by their key.
+async fn events_by_key(ledger_db: &LedgerDb, blob: &[u8]) -> Vec<u64> {
+    let key = HashStf::<MockValidityCond>::event_key(blob);
+    get_events_by_key_helper::<EventNumber>(ledger_db, &key, None, 10, None)
+        .await
+        .unwrap()
+        .events_response
+        .into_iter()
+        .map(|event| event.0)
+        .collect()
+}
+
 fn batch(data: Vec<u8>) -> Vec<u8> {
     borsh::to_vec(&BlobData::new_batch(vec![RawTx { data }])).unwrap()
 }
@@ -99,7 +99,7 @@ pub(crate) struct ExecutionSimulationVars {
     pub state_root: <<S as Spec>::Storage as Storage>::Root,
     pub batch_receipts: Vec<BatchReceipt>,
     pub state_proof: Option<StorageProof<<<S as Spec>::Storage as Storage>::Proof>>,
-    gas_consumed_slot: Vec<<S as Spec>::Gas>,
+    pub gas_consumed_slot: Vec<<S as Spec>::Gas>,
 }
 
 impl ExecutionSimulationVars {
@@ -173,14 +173,20 @@ impl TestRollup {
                 seq_da_address: seq_params.da_address,
                 minimum_bond: seq_params.stake_amount,
                 is_preferred_sequencer: true,
+                require_preferred_sequencer: false,
+                unbonding_period: 0,
+                slash_fraction_bips: sov_sequencer_registry::MAX_SLASH_FRACTION_BIPS,
             },
             bank: BankConfig {
                 gas_token_config: GasTokenConfig {
                     token_name: bank_params.token_name.clone(),
                     address_and_balances: bank_params.addresses_and_balances,
                     authorized_minters: vec![seq_params.rollup_address],
+                    metadata: None,
                 },
                 tokens: vec![],
+                max_tokens_per_account: None,
+                freeze_admin: None,
             },
             attester_incentives: AttesterIncentivesConfig {
                 initial_attesters: attester_params.initial_attesters,
@@ -201,6 +207,11 @@ impl TestRollup {
                     inner_code_commitment: attester_params.commitment_to_allowed_challenge_method,
                     outer_code_commitment: MockCodeCommitment::default(),
                     genesis_da_height: 0,
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
                 },
             };
         GenesisParams {
@@ -1,18 +1,21 @@
-use sov_mock_da::MockDaSpec;
+use std::rc::Rc;
+
+use sov_mock_da::{MockBlockHeader, MockDaSpec, MockValidityCond};
 use sov_modules_api::capabilities::{
     AuthorizationData, AuthorizeSequencerError, SequencerAuthorization,
 };
+use sov_modules_api::da::Time;
 use sov_modules_api::macros::config_value;
 use sov_modules_api::runtime::capabilities::RuntimeAuthorization;
 use sov_modules_api::transaction::{Credentials, UnsignedTransaction};
 use sov_modules_api::{
-    Batch, Context, CryptoSpec, DaSpec, EncodeCall, Gas, GasArray, KernelWorkingSet, PrivateKey,
-    Spec, StateCheckpoint,
+    Batch, BatchWithId, Context, CryptoSpec, DaSpec, EncodeCall, Gas, GasArray, KernelWorkingSet,
+    PrivateKey, Spec, StateCheckpoint,
 };
 use sov_modules_stf_blueprint::TxEffect;
 use sov_rollup_interface::crypto::PublicKey;
 use sov_test_utils::auth::TestAuth;
-use sov_test_utils::generators::value_setter::ValueSetterMessages;
+use sov_test_utils::generators::value_setter::{ValueSetterMessage, ValueSetterMessages};
 use sov_test_utils::runtime::genesis::User;
 use sov_test_utils::runtime::optimistic::{HighLevelOptimisticGenesisConfig, TestRuntime};
 use sov_test_utils::runtime::{MessageType, SlotTestCase, TestRunner, TxOutcome, TxTestCase};
@@ -176,6 +179,120 @@ fn test_stf_internal_updates() {
     );
 }
 
+/// Simulates a batch mixing successful, reverted and skipped transactions, and checks that the
+/// simulation matches the actual execution of the batch.
+#[test]
+fn test_simulate_batch_matches_execution() {
+    let mut rollup = TestRollup::new();
+
+    let generate_key = || Rc::new(<<S as Spec>::CryptoSpec as CryptoSpec>::PrivateKey::generate());
+    let admin = generate_key();
+    let non_admin = generate_key();
+    let value_setter_messages = ValueSetterMessages::new(vec![
+        ValueSetterMessage {
+            admin: admin.clone(),
+            messages: vec![99, 33],
+        },
+        // Only the admin can set the value, so this transaction is reverted.
+        ValueSetterMessage {
+            admin: non_admin.clone(),
+            messages: vec![7],
+        },
+        // This account has no funds to pay for gas, so its transaction is skipped.
+        ValueSetterMessage {
+            admin: generate_key(),
+            messages: vec![5],
+        },
+    ]);
+    let batch = Batch {
+        txs: value_setter_messages
+            .create_default_raw_txs::<TestRuntime<S, MockDaSpec>, TestAuth<S, MockDaSpec>>(),
+    };
+
+    let admin_addr = admin.to_address::<<S as Spec>::Address>();
+    let seq_params = SequencerParams::default();
+    let seq_da_addr = seq_params.da_address;
+    let bank_params = BankParams::with_addresses_and_balances(vec![
+        (seq_params.rollup_address, TEST_DEFAULT_USER_BALANCE),
+        (admin_addr, TEST_DEFAULT_USER_BALANCE),
+        (
+            non_admin.to_address::<<S as Spec>::Address>(),
+            TEST_DEFAULT_USER_BALANCE,
+        ),
+    ]);
+    let init_root_hash = rollup.genesis(
+        admin_addr,
+        seq_params,
+        bank_params,
+        AttesterIncentivesParams::default(),
+    );
+
+    let slot_header = MockBlockHeader {
+        prev_hash: [0; 32].into(),
+        hash: [10; 32].into(),
+        height: 0,
+        time: Time::now(),
+    };
+    let storage = rollup.storage();
+    let simulation = rollup.stf().simulate_batch(
+        storage,
+        &init_root_hash,
+        &slot_header,
+        &MockValidityCond::default(),
+        BatchWithId {
+            batch: batch.clone(),
+            id: [0; 32],
+        },
+        &seq_da_addr,
+    );
+
+    let outcomes: Vec<_> = simulation
+        .receipt
+        .tx_receipts
+        .iter()
+        .map(|tx_receipt| tx_receipt.receipt.clone())
+        .collect();
+    assert!(
+        matches!(
+            outcomes[..],
+            [
+                TxEffect::Successful(()),
+                TxEffect::Successful(()),
+                TxEffect::Reverted(_),
+                TxEffect::Skipped(_),
+            ]
+        ),
+        "Unexpected simulated outcomes: {outcomes:?}"
+    );
+
+    // Nothing was committed by the simulation, so the batch is executed on the same state.
+    let blob = new_test_blob_from_batch(batch, seq_da_addr.as_ref(), [0; 32]);
+    let execution = rollup
+        .execution_simulation(1, init_root_hash, vec![blob], 0, None)
+        .pop()
+        .unwrap();
+    let receipt = &execution.batch_receipts[0];
+
+    assert_eq!(simulation.receipt.batch_hash, receipt.batch_hash);
+    assert_eq!(simulation.receipt.inner, receipt.inner);
+    assert_eq!(simulation.receipt.gas_price, receipt.gas_price);
+    assert_eq!(
+        simulation.receipt.tx_receipts.len(),
+        receipt.tx_receipts.len()
+    );
+    for (simulated, executed) in simulation
+        .receipt
+        .tx_receipts
+        .iter()
+        .zip(receipt.tx_receipts.iter())
+    {
+        assert_eq!(simulated.tx_hash, executed.tx_hash);
+        assert_eq!(simulated.receipt, executed.receipt);
+        assert_eq!(simulated.gas_used, executed.gas_used);
+    }
+    assert_eq!(simulation.gas_used, execution.gas_consumed_slot[0].to_vec());
+}
+
 #[test]
 fn test_enforces_chain_id() {
     generate_optimistic_runtime!(IntegTestRuntime <= value_setter: ValueSetter<S>);
@@ -1,7 +1,10 @@
 use anyhow::{anyhow, Result};
-use sov_modules_api::{CallResponse, Context, CredentialId, Spec, StateReader, TxState};
+use sov_modules_api::{
+    CallResponse, Context, CredentialId, EventEmitter, Spec, StateReader, TxState,
+};
 use sov_state::namespaces::User;
 
+use crate::event::Event;
 use crate::{Account, Accounts};
 
 /// Represents the available call messages for interacting with the sov-accounts module.
@@ -25,6 +28,12 @@ pub enum CallMessage {
         /// The new credential id.
         CredentialId,
     ),
+    /// Removes a credential id of the sender's account, for instance when the corresponding
+    /// key is compromised. The last credential id of an account can't be removed.
+    RemoveCredentialId(
+        /// The credential id to remove.
+        CredentialId,
+    ),
 }
 
 impl<S: Spec> Accounts<S> {
@@ -55,16 +64,58 @@ impl<S: Spec> Accounts<S> {
         Ok(CallResponse::default())
     }
 
+    pub(crate) fn remove_credential_id(
+        &self,
+        credential_id: CredentialId,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse> {
+        let account = self
+            .accounts
+            .get(&credential_id, state)
+            .map_err(|err| anyhow!("Error raised while getting account: {err:?}"))?;
+        anyhow::ensure!(
+            account.is_some_and(|account| &account.addr == context.sender()),
+            "CredentialId {credential_id} doesn't belong to the sender {}",
+            context.sender()
+        );
+
+        let mut credential_ids = self
+            .credential_ids
+            .get_or_err(context.sender(), state)
+            .map_err(|e| anyhow::anyhow!("Error raised while getting credential ids: {e:?}"))??;
+        anyhow::ensure!(
+            credential_ids.len() > 1,
+            "Cannot remove the last CredentialId of the account {}",
+            context.sender()
+        );
+
+        credential_ids.retain(|id| id != &credential_id);
+        self.credential_ids
+            .set(context.sender(), &credential_ids, state)?;
+        self.accounts.delete(&credential_id, state)?;
+
+        self.emit_event(
+            state,
+            Event::CredentialRemoved {
+                address: context.sender().clone(),
+                credential_id,
+            },
+        );
+
+        Ok(CallResponse::default())
+    }
+
     fn exit_if_account_exists(
         &self,
         new_credential_id: &CredentialId,
         state: &mut impl StateReader<User>,
     ) -> Result<()> {
         anyhow::ensure!(
-            self.accounts
-                .get(new_credential_id, state)
-                .map_err(|err| anyhow!("Error raised while getting account: {err:?}"))?
-                .is_none(),
+            !self
+                .accounts
+                .contains_key(new_credential_id, state)
+                .map_err(|err| anyhow!("Error raised while getting account: {err:?}"))?,
             "New CredentialId already exists"
         );
         Ok(())
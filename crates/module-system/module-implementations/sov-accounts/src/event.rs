@@ -1,4 +1,6 @@
-/// Sample Event
+use sov_modules_api::{CredentialId, Spec};
+
+/// Accounts Event
 #[derive(
     borsh::BorshDeserialize,
     borsh::BorshSerialize,
@@ -8,9 +10,17 @@
     PartialEq,
     Clone,
 )]
-pub enum Event {
+#[serde(bound = "S::Address: serde::Serialize + serde::de::DeserializeOwned")]
+pub enum Event<S: Spec> {
     /// Sample event variant 1
     Event1,
     /// Sample event variant 2
     Event2,
+    /// A credential id was removed from an account.
+    CredentialRemoved {
+        /// The address of the account.
+        address: S::Address,
+        /// The removed credential id.
+        credential_id: CredentialId,
+    },
 }
@@ -5,8 +5,12 @@ use crate::{Account, AccountConfig, AccountData, Accounts, CallMessage};
 
 impl<'a> Arbitrary<'a> for CallMessage {
     fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
-        let credential_id = <[u8; 32]>::arbitrary(u)?;
-        Ok(Self::InsertCredentialId(CredentialId(credential_id)))
+        let credential_id = CredentialId(<[u8; 32]>::arbitrary(u)?);
+        if u.arbitrary()? {
+            Ok(Self::InsertCredentialId(credential_id))
+        } else {
+            Ok(Self::RemoveCredentialId(credential_id))
+        }
     }
 }
 
@@ -36,7 +36,7 @@ impl<S: Spec> Accounts<S> {
         state: &mut impl GenesisState<S>,
     ) -> Result<()> {
         for acc in config.accounts.iter() {
-            if self.accounts.get(&acc.credential_id, state)?.is_some() {
+            if self.accounts.contains_key(&acc.credential_id, state)? {
                 bail!("Account already exists")
             }
 
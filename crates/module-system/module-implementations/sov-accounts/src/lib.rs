@@ -11,14 +11,18 @@ mod query;
 #[cfg(feature = "native")]
 pub use query::*;
 mod event;
+#[cfg(feature = "native")]
+mod rest;
 #[cfg(test)]
 mod tests;
 pub use call::CallMessage;
 use sov_modules_api::{
-    Context, CredentialId, Error, GenesisState, ModuleId, ModuleInfo, Spec, TxState,
+    Context, CredentialId, Error, GenesisState, ModuleId, ModuleInfo, Spec, StateAccessor,
+    StateReader, TxState,
 };
+use sov_state::User;
 
-use crate::event::Event;
+pub use crate::event::Event;
 
 /// An account on the rollup.
 #[derive(
@@ -60,7 +64,7 @@ impl<S: Spec> sov_modules_api::Module for Accounts<S> {
 
     type CallMessage = call::CallMessage;
 
-    type Event = Event;
+    type Event = Event<S>;
 
     fn genesis(
         &self,
@@ -80,6 +84,21 @@ impl<S: Spec> sov_modules_api::Module for Accounts<S> {
             call::CallMessage::InsertCredentialId(new_credential_id) => {
                 Ok(self.insert_credential_id(new_credential_id, context, state)?)
             }
+            call::CallMessage::RemoveCredentialId(credential_id) => {
+                Ok(self.remove_credential_id(credential_id, context, state)?)
+            }
         }
     }
 }
+
+impl<S: Spec> Accounts<S> {
+    /// Returns the credential ids which control the account with the given `address`, or an
+    /// empty list if the address has no account.
+    pub fn credentials_for<Accessor: StateAccessor>(
+        &self,
+        address: &S::Address,
+        state: &mut Accessor,
+    ) -> Result<Vec<CredentialId>, <Accessor as StateReader<User>>::Error> {
+        Ok(self.credential_ids.get(address, state)?.unwrap_or_default())
+    }
+}
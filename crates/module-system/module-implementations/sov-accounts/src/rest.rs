@@ -0,0 +1,36 @@
+//! Custom REST API routes exposing the credentials of the accounts.
+use sov_modules_api::prelude::axum::routing::get;
+use sov_modules_api::prelude::{axum, UnwrapInfallible};
+use sov_modules_api::rest::utils::{errors, ApiResult, Path};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::{CredentialId, Spec};
+
+use crate::Accounts;
+
+impl<S: Spec> HasCustomRestApi<S> for Accounts<S> {
+    fn custom_rest_api(&self, state: ApiState<Self, S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route(
+                "/accounts/:address/credentials",
+                get(Self::route_credentials),
+            )
+            .with_state(state)
+    }
+}
+
+impl<S: Spec> Accounts<S> {
+    async fn route_credentials(
+        state: ApiState<Self, S>,
+        Path(address): Path<S::Address>,
+    ) -> ApiResult<Vec<CredentialId>> {
+        let credentials = state
+            .credentials_for(&address, &mut state.api_state_accessor())
+            .unwrap_infallible();
+
+        if credentials.is_empty() {
+            return Err(errors::not_found_404("Account", address));
+        }
+
+        Ok(credentials.into())
+    }
+}
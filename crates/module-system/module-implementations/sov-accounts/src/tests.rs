@@ -1,14 +1,18 @@
 use std::convert::Infallible;
 
+use sov_modules_api::prelude::axum::body::Body;
+use sov_modules_api::prelude::axum::http::{Request, StatusCode};
 use sov_modules_api::prelude::*;
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
 use sov_modules_api::{
-    Address, Context, CredentialId, Module, PrivateKey, PublicKey, StateCheckpoint,
+    Address, Context, CredentialId, Module, PrivateKey, PublicKey, StateCheckpoint, Storage,
 };
-use sov_prover_storage_manager::new_orphan_storage;
-use sov_test_utils::{TestHasher, TestPrivateKey};
+use sov_prover_storage_manager::{new_orphan_storage, SimpleStorageManager};
+use sov_test_utils::{TestHasher, TestPrivateKey, TestStorageSpec};
+use tower::ServiceExt;
 
 use crate::query::Response;
-use crate::{call, Account, AccountConfig, AccountData, Accounts};
+use crate::{call, Account, AccountConfig, AccountData, Accounts, Event};
 
 type S = sov_test_utils::TestSpec;
 
@@ -295,3 +299,194 @@ fn test_response_deserialization_on_wrong_hrp() {
         }
     }
 }
+
+#[test]
+fn test_remove_credential_id() -> Result<(), Infallible> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let state = StateCheckpoint::<S>::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let accounts = &mut Accounts::<S>::default();
+
+    let sender = TestPrivateKey::generate().pub_key();
+    let sender_addr = sender.to_address::<<S as Spec>::Address>();
+    let sender_credential_id: CredentialId = sender.credential_id::<TestHasher>();
+
+    let other = TestPrivateKey::generate().pub_key();
+    let other_addr = other.to_address::<<S as Spec>::Address>();
+    let other_credential_id: CredentialId = other.credential_id::<TestHasher>();
+
+    let sequencer_addr = TestPrivateKey::generate()
+        .pub_key()
+        .to_address::<<S as Spec>::Address>();
+    let sender_context = Context::<S>::new(sender_addr, Default::default(), sequencer_addr, 1);
+
+    let config = AccountConfig {
+        accounts: vec![
+            AccountData {
+                credential_id: sender_credential_id,
+                address: sender_addr,
+            },
+            AccountData {
+                credential_id: other_credential_id,
+                address: other_addr,
+            },
+        ],
+    };
+
+    let mut genesis = state.to_genesis_state_accessor::<Accounts<S>>(&config);
+    accounts.init_module(&config, &mut genesis).unwrap();
+    let mut state = genesis.checkpoint().to_working_set_unmetered();
+
+    // The last credential of an account can't be removed.
+    assert!(accounts
+        .call(
+            call::CallMessage::RemoveCredentialId(sender_credential_id),
+            &sender_context,
+            &mut state,
+        )
+        .is_err());
+
+    let new_credential_id: CredentialId = TestPrivateKey::generate()
+        .pub_key()
+        .credential_id::<TestHasher>();
+    accounts
+        .call(
+            call::CallMessage::InsertCredentialId(new_credential_id),
+            &sender_context,
+            &mut state,
+        )
+        .unwrap();
+
+    // A credential of another account can't be removed.
+    assert!(accounts
+        .call(
+            call::CallMessage::RemoveCredentialId(other_credential_id),
+            &sender_context,
+            &mut state,
+        )
+        .is_err());
+
+    // The compromised credential is removed from both maps.
+    accounts
+        .call(
+            call::CallMessage::RemoveCredentialId(sender_credential_id),
+            &sender_context,
+            &mut state,
+        )
+        .unwrap();
+
+    assert!(state.inner().events().iter().any(|event| matches!(
+        event.downcast_ref::<Event<S>>(),
+        Some(Event::CredentialRemoved { address, credential_id })
+            if *address == sender_addr && *credential_id == sender_credential_id
+    )));
+
+    let mut state = state.checkpoint().0;
+    assert_eq!(
+        accounts.accounts.get(&sender_credential_id, &mut state)?,
+        None
+    );
+    assert_eq!(
+        accounts.credential_ids.get(&sender_addr, &mut state)?,
+        Some(vec![new_credential_id])
+    );
+    assert_eq!(
+        accounts
+            .accounts
+            .get(&other_credential_id, &mut state)?
+            .map(|Account { addr }| addr),
+        Some(other_addr)
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_credentials_for() -> Result<(), Infallible> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleStorageManager::<TestStorageSpec>::new(tmpdir.path());
+    let storage = storage_manager.create_storage();
+    let accounts = &mut Accounts::<S>::default();
+
+    let sender = TestPrivateKey::generate().pub_key();
+    let sender_addr = sender.to_address::<<S as Spec>::Address>();
+    let sender_credential_id: CredentialId = sender.credential_id::<TestHasher>();
+    let unknown_addr = TestPrivateKey::generate()
+        .pub_key()
+        .to_address::<<S as Spec>::Address>();
+
+    let sequencer_addr = TestPrivateKey::generate()
+        .pub_key()
+        .to_address::<<S as Spec>::Address>();
+    let sender_context = Context::<S>::new(sender_addr, Default::default(), sequencer_addr, 1);
+
+    let config = AccountConfig {
+        accounts: vec![AccountData {
+            credential_id: sender_credential_id,
+            address: sender_addr,
+        }],
+    };
+
+    let state = StateCheckpoint::<S>::new(storage.clone());
+    let mut genesis = state.to_genesis_state_accessor::<Accounts<S>>(&config);
+    accounts.init_module(&config, &mut genesis).unwrap();
+    let mut state = genesis.checkpoint().to_working_set_unmetered();
+
+    let new_credential_id: CredentialId = TestPrivateKey::generate()
+        .pub_key()
+        .credential_id::<TestHasher>();
+    accounts
+        .call(
+            call::CallMessage::InsertCredentialId(new_credential_id),
+            &sender_context,
+            &mut state,
+        )
+        .unwrap();
+
+    let mut state = state.checkpoint().0;
+    let expected_credentials = vec![sender_credential_id, new_credential_id];
+    assert_eq!(
+        accounts.credentials_for(&sender_addr, &mut state)?,
+        expected_credentials
+    );
+    assert!(accounts
+        .credentials_for(&unknown_addr, &mut state)?
+        .is_empty());
+
+    let (cache_log, _, witness) = state.freeze();
+    let (_, change_set) = storage
+        .validate_and_materialize(cache_log, &witness)
+        .expect("Native JMT validation should succeed");
+    storage_manager.commit(change_set);
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = accounts.custom_rest_api(ApiState::new(accounts.clone(), receiver));
+
+    let (status, body) = get(
+        router.clone(),
+        &format!("/accounts/{sender_addr}/credentials"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["data"],
+        serde_json::to_value(&expected_credentials).unwrap()
+    );
+
+    let (status, _) = get(router, &format!("/accounts/{unknown_addr}/credentials")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+async fn get(router: axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = router
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
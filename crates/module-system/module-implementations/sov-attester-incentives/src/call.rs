@@ -6,6 +6,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use derivative::Derivative;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sov_bank::event::BurnReason;
 use sov_bank::{Amount, BurnRate, Coins, IntoPayable, GAS_TOKEN_ID};
 use sov_modules_api::hooks::TransitionHeight;
 use sov_modules_api::macros::config_value;
@@ -215,6 +216,12 @@ pub enum AttesterIncentiveErrors {
     /// An error occurred when transferred funds
     RewardTransferFailure,
 
+    #[error(
+        "Error occurred when trying to burn tokens. The `AttesterIncentives` module may not have enough funds. This is a bug."
+    )]
+    /// An error occurred when burning the slashed bond or a share of the reward
+    BurnFailure,
+
     /// An error occurred when accessing the state
     #[error("Error occurred when accessing the state, error: {0}")]
     StateAccessError(String),
@@ -316,14 +323,19 @@ where
         reason: SlashingReason,
         state: &mut impl TxState<S>,
     ) -> Result<CallResponse, AttesterIncentiveErrors> {
-        if let Err(e) = self.slash_user(user, role, reason, state) {
-            error!(
-                error = ?e,
-                "Error raised when trying to slash the attester. Attester not slashed and transaction reverted"
-            );
-            return Err(e.into());
+        let slashed_bond = match self.slash_user(user, role, reason, state) {
+            Ok(slashed_bond) => slashed_bond,
+            Err(e) => {
+                error!(
+                    error = ?e,
+                    "Error raised when trying to slash the attester. Attester not slashed and transaction reverted"
+                );
+                return Err(e.into());
+            }
         };
 
+        self.burn_from_module(slashed_bond, BurnReason::Slashing, state)?;
+
         self.emit_event(
             state,
             Event::UserSlashed {
@@ -363,18 +375,41 @@ where
 
     /// A helper function that rewards the sender with a given amount of tokens
     /// Some of the tokens need to be burnt to avoid the system participants to be incentivized to prove and submit empty blocks.
+    /// The `burn_reason` records where the burnt tokens come from.
     fn reward_sender(
         &self,
         context: &Context<S>,
         amount: u64,
-        state: &mut impl StateAccessor,
+        burn_reason: BurnReason,
+        state: &mut impl TxState<S>,
     ) -> Result<CallResponse, AttesterIncentiveErrors> {
-        self.transfer_tokens_to_sender(
-            context,
-            // Note: if we have an empty block, the attester will pay more than the reward (because of the transaction cost)
-            self.burn_rate().apply(amount),
-            state,
-        )
+        // Note: if we have an empty block, the attester will pay more than the reward (because of the transaction cost)
+        let reward = self.burn_rate().apply(amount);
+        self.transfer_tokens_to_sender(context, reward, state)?;
+        self.burn_from_module(amount - reward, burn_reason, state)?;
+
+        Ok(CallResponse::default())
+    }
+
+    /// Burns `amount` gas tokens from the module's account for the given `reason`.
+    fn burn_from_module(
+        &self,
+        amount: u64,
+        reason: BurnReason,
+        state: &mut impl TxState<S>,
+    ) -> Result<(), AttesterIncentiveErrors> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let coins = Coins {
+            token_id: GAS_TOKEN_ID,
+            amount,
+        };
+
+        self.bank
+            .burn(coins, self.id.to_payable(), reason, state)
+            .map_err(|_err| AttesterIncentiveErrors::BurnFailure)
     }
 
     fn transfer_tokens_to_sender(
@@ -409,9 +444,8 @@ where
         if role == Role::Attester
             && self
                 .unbonding_attesters
-                .get(user_address, state)
+                .contains_key(user_address, state)
                 .map_err(|e| AttesterIncentiveErrors::StateAccessError(e.to_string()))?
-                .is_some()
         {
             return Err(AttesterIncentiveErrors::AttesterIsUnbonding);
         }
@@ -424,7 +458,7 @@ where
         };
 
         self.bank
-            .transfer_from(user_address, self.id.to_payable(), coins, state)
+            .transfer_from_ignoring_freeze(user_address, self.id.to_payable(), coins, state)
             .map_err(|_err| AttesterIncentiveErrors::BondTransferFailure)?;
 
         let balances = match role {
@@ -792,10 +826,9 @@ where
     ) -> anyhow::Result<CallResponse, AttesterIncentiveErrors> {
         let attestation = attestation.inner;
         // We first need to check that the attester is still in the bonding set
-        if self
+        if !self
             .bonded_attesters
-            .get(context.sender(), state)?
-            .is_none()
+            .contains_key(context.sender(), state)?
         {
             return Err(AttesterIncentiveErrors::UserNotBonded);
         }
@@ -896,7 +929,7 @@ where
             self.maximum_attested_height
                 .set(&(new_height_to_attest), state)?;
 
-            self.reward_sender(context, reward, state)?;
+            self.reward_sender(context, reward, BurnReason::GasFee, state)?;
         }
 
         // Then we can optimistically process the transaction
@@ -1015,7 +1048,8 @@ where
                 };
 
                 // Reward the sender
-                self.reward_sender(context, attestation_reward, state)?;
+                // The reward comes from the bonds slashed for this transition
+                self.reward_sender(context, attestation_reward, BurnReason::Slashing, state)?;
 
                 // Now remove the bad transition from the pool
                 self.bad_transition_pool.remove(transition_num, state)?;
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 use anyhow::Result;
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
-use sov_bank::Amount;
+use sov_bank::{Amount, GenesisBond};
 use sov_modules_api::hooks::TransitionHeight;
 use sov_modules_api::{DaSpec, GenesisState, Spec};
 use sov_state::Storage;
@@ -33,6 +33,21 @@ where
     pub phantom_data: PhantomData<Da::ValidityCondition>,
 }
 
+impl<S: Spec, Da: DaSpec> AttesterIncentivesConfig<S, Da> {
+    /// Returns the bonds transferred from the bank balances of the initial attesters at
+    /// genesis, see [`sov_bank::BankConfig::validate_bonds`].
+    pub fn genesis_bonds(&self) -> Vec<GenesisBond<S>> {
+        self.initial_attesters
+            .iter()
+            .map(|(attester, bond)| GenesisBond {
+                module: "AttesterIncentives",
+                address: attester.clone(),
+                amount: *bond,
+            })
+            .collect()
+    }
+}
+
 impl<S, Store, P, Da> AttesterIncentives<S, Da>
 where
     S: sov_modules_api::Spec<Storage = Store>,
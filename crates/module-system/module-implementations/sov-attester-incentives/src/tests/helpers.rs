@@ -66,12 +66,15 @@ pub(crate) fn create_bank_config_with_token(
         token_name,
         address_and_balances: address_and_balances.clone(),
         authorized_minters: vec![address_and_balances.first().unwrap().0],
+        metadata: None,
     };
 
     (
         BankConfig {
             gas_token_config: token_config,
             tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: None,
         },
         address_and_balances
             .into_iter()
@@ -114,6 +117,11 @@ pub(crate) fn setup(
         genesis_da_height: 0,
         inner_code_commitment: Default::default(),
         outer_code_commitment: Default::default(),
+        state_access_budget: Default::default(),
+        base_fee_policy: Default::default(),
+        gas_limit_policy: Default::default(),
+        transition_retention_window: None,
+        admin: None,
     };
 
     let chain_state = sov_chain_state::ChainState::<S, MockDaSpec>::default();
@@ -215,6 +223,7 @@ impl ExecutionSimulationVars {
                     max_fee: TEST_DEFAULT_MAX_FEE,
                     gas_limit: Some(<S as Spec>::Gas::from_slice(&TEST_DEFAULT_GAS_LIMIT)),
                     chain_id: 0,
+                    valid_until_slot: None,
                 },
             )
             .into();
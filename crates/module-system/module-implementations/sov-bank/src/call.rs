@@ -4,9 +4,10 @@ use sov_modules_api::macros::CliWalletArg;
 use sov_modules_api::{CallResponse, Context, EventEmitter, StateAccessor, StateReader, TxState};
 use sov_state::User;
 
-use crate::event::Event;
-use crate::utils::{Payable, TokenHolderRef};
-use crate::{Amount, Bank, Coins, Token, TokenId};
+use crate::event::{BurnReason, Event};
+use crate::token::HoldersDelta;
+use crate::utils::{Payable, TokenHolder, TokenHolderRef};
+use crate::{get_token_id, Amount, Bank, Coins, Token, TokenId, TokenMetadata, GAS_TOKEN_ID};
 /// This enumeration represents the available call messages for interacting with the sov-bank module.
 #[cfg_attr(
     feature = "native",
@@ -36,6 +37,9 @@ pub enum CallMessage<S: sov_modules_api::Spec> {
         mint_to_address: S::Address,
         /// Authorized minter list.
         authorized_minters: Vec<S::Address>,
+        /// The symbol and number of decimals of the new token, if any.
+        #[serde(default)]
+        metadata: Option<TokenMetadata>,
     },
 
     /// Transfers a specified amount of tokens to the specified address.
@@ -65,6 +69,67 @@ pub enum CallMessage<S: sov_modules_api::Spec> {
         /// Address of the token to be frozen
         token_id: TokenId,
     },
+
+    /// Allows `spender` to transfer up to `amount` tokens on behalf of the sender.
+    /// Replaces any previous allowance of `spender` for the same token.
+    Approve {
+        /// The address allowed to spend the tokens.
+        spender: S::Address,
+        /// The ID of the token that can be spent.
+        token_id: TokenId,
+        /// The maximum amount of tokens that can be spent.
+        amount: Amount,
+    },
+
+    /// Transfers tokens on behalf of `from`, using the allowance given to the sender.
+    TransferFrom {
+        /// The address from which the tokens will be transferred.
+        from: S::Address,
+        /// The address to which the tokens will be transferred.
+        to: S::Address,
+        /// The amount of tokens to transfer.
+        coins: Coins,
+    },
+
+    /// Freezes the transfers of a token: [`CallMessage::Transfer`] and [`CallMessage::TransferFrom`]
+    /// calls moving this token fail until it is unfrozen. Unlike [`CallMessage::Freeze`], this
+    /// doesn't affect the supply of the token.
+    ///
+    /// Only the freeze admin set at genesis can freeze the transfers of a token.
+    FreezeToken {
+        /// The ID of the token whose transfers are frozen
+        token_id: TokenId,
+    },
+
+    /// Unfreezes the transfers of a token frozen by [`CallMessage::FreezeToken`].
+    ///
+    /// Only the freeze admin set at genesis can unfreeze the transfers of a token.
+    UnfreezeToken {
+        /// The ID of the token whose transfers are unfrozen
+        token_id: TokenId,
+    },
+
+    /// Freezes the transfers of a token from and to an account: [`CallMessage::Transfer`] and
+    /// [`CallMessage::TransferFrom`] calls moving this token from or to `address` fail until
+    /// the account is unfrozen.
+    ///
+    /// Only the freeze admin set at genesis can freeze an account.
+    FreezeAccount {
+        /// The address of the account to freeze
+        address: S::Address,
+        /// The ID of the token whose transfers are frozen
+        token_id: TokenId,
+    },
+
+    /// Unfreezes an account frozen by [`CallMessage::FreezeAccount`].
+    ///
+    /// Only the freeze admin set at genesis can unfreeze an account.
+    UnfreezeAccount {
+        /// The address of the account to unfreeze
+        address: S::Address,
+        /// The ID of the token whose transfers are unfrozen
+        token_id: TokenId,
+    },
 }
 
 impl<S: sov_modules_api::Spec> Bank<S> {
@@ -78,6 +143,7 @@ impl<S: sov_modules_api::Spec> Bank<S> {
         initial_balance: Amount,
         minter: impl Payable<S>,
         authorized_minters: Vec<impl Payable<S>>,
+        metadata: Option<TokenMetadata>,
         originator: impl Payable<S>,
         state: &mut impl TxState<S>,
     ) -> Result<TokenId> {
@@ -88,7 +154,12 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             .map(|minter| minter.as_token_holder())
             .collect::<Vec<_>>();
 
-        let (token_id, token) = Token::<S>::create(
+        let token_id = get_token_id::<S>(&token_name, originator.as_token_holder(), salt);
+        if initial_balance > 0 {
+            self.ensure_can_receive(&token_id, None, minter.as_token_holder(), state)?;
+        }
+
+        let (token_id, token, holders_delta) = Token::<S>::create(
             &token_name,
             &[(minter.as_token_holder(), initial_balance)],
             &authorized_minters,
@@ -98,7 +169,7 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             state,
         )?;
 
-        if self.tokens.get(&token_id, state)?.is_some() {
+        if self.tokens.contains_key(&token_id, state)? {
             bail!(
                 "Token {} at {} address already exists",
                 token_name,
@@ -107,6 +178,10 @@ impl<S: sov_modules_api::Spec> Bank<S> {
         }
 
         self.tokens.set(&token_id, &token, state)?;
+        self.update_holder_count(&token_id, holders_delta, state)?;
+        if let Some(metadata) = &metadata {
+            self.token_metadata.set(&token_id, metadata, state)?;
+        }
         self.emit_event(
             state,
             Event::TokenCreated {
@@ -149,7 +224,7 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             })
     }
 
-    /// Burns the set of `coins`.
+    /// Burns the set of `coins`, recording the `reason` of the burn in the emitted event.
     ///
     /// If there is no token at the address specified in the
     /// [`Coins`] structure, return an error; on success it updates the total
@@ -158,6 +233,7 @@ impl<S: sov_modules_api::Spec> Bank<S> {
         &self,
         coins: Coins,
         owner: impl Payable<S>,
+        reason: BurnReason,
         state: &mut impl TxState<S>,
     ) -> Result<()> {
         let owner = owner.as_token_holder();
@@ -166,7 +242,7 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             .tokens
             .get_or_err(&coins.token_id, state)?
             .with_context(context_logger)?;
-        token
+        let holders_delta = token
             .burn(owner, coins.amount, state)
             .with_context(context_logger)?;
         token.total_supply = token
@@ -179,12 +255,14 @@ impl<S: sov_modules_api::Spec> Bank<S> {
                 )
             })?;
         self.tokens.set(&coins.token_id, &token, state)?;
+        self.update_holder_count(&coins.token_id, holders_delta, state)?;
 
         self.emit_event(
             state,
             Event::TokenBurned {
                 owner: owner.into(),
                 coins,
+                reason,
             },
         );
 
@@ -198,7 +276,7 @@ impl<S: sov_modules_api::Spec> Bank<S> {
         context: &Context<S>,
         state: &mut impl TxState<S>,
     ) -> Result<CallResponse> {
-        self.burn(coins, context.sender(), state)?;
+        self.burn(coins, context.sender(), BurnReason::Manual, state)?;
         Ok(CallResponse::default())
     }
 
@@ -246,10 +324,15 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             .with_context(context_logger)??;
 
         let authorizer = authorizer.as_token_holder();
-        token
+        if coins.amount > 0 {
+            self.ensure_can_receive(&coins.token_id, Some(&token), mint_to_identity, state)
+                .with_context(context_logger)?;
+        }
+        let holders_delta = token
             .mint(authorizer, mint_to_identity, coins.amount, state)
             .with_context(context_logger)?;
         self.tokens.set(&coins.token_id, &token, state)?;
+        self.update_holder_count(&coins.token_id, holders_delta, state)?;
         self.emit_event(
             state,
             Event::TokenMinted {
@@ -298,18 +381,268 @@ impl<S: sov_modules_api::Spec> Bank<S> {
 
         Ok(CallResponse::default())
     }
+
+    /// Freezes (if `frozen`) or unfreezes the transfers of the token ID `token_id`, or only the ones
+    /// from and to `account` if it is provided.
+    /// Returns an error if the token ID doesn't exist, if the sender is not the freeze admin set at
+    /// genesis, or if the transfers are already in the requested state.
+    pub(crate) fn set_transfers_frozen(
+        &self,
+        token_id: TokenId,
+        account: Option<S::Address>,
+        frozen: bool,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse> {
+        let context_logger = || {
+            let action = if frozen { "freeze" } else { "unfreeze" };
+            match &account {
+                Some(account) => format!(
+                    "Failed to {} account {} for token_id={} by sender {}",
+                    action,
+                    account,
+                    token_id,
+                    context.sender()
+                ),
+                None => format!(
+                    "Failed to {} transfers of token_id={} by sender {}",
+                    action,
+                    token_id,
+                    context.sender()
+                ),
+            }
+        };
+
+        let token = self
+            .tokens
+            .get_or_err(&token_id, state)
+            .with_context(context_logger)??;
+        let sender_ref = context.sender();
+        let sender = sender_ref.as_token_holder();
+        match self.freeze_admin.get(state)? {
+            Some(freeze_admin) if &freeze_admin == sender_ref => {}
+            Some(_) => {
+                return Err(
+                    anyhow::anyhow!("Sender {} is not the freeze admin", sender_ref)
+                        .context(context_logger()),
+                )
+            }
+            None => {
+                return Err(
+                    anyhow::anyhow!("No freeze admin was set at genesis").context(context_logger())
+                )
+            }
+        }
+
+        let event = match account {
+            Some(account) => {
+                let key = (account.clone(), token_id);
+                if self.frozen_accounts.contains_key(&key, state)? == frozen {
+                    let state_name = if frozen {
+                        "already frozen"
+                    } else {
+                        "not frozen"
+                    };
+                    return Err(anyhow::anyhow!(
+                        "Account {} is {} for token {}",
+                        account,
+                        state_name,
+                        token.name
+                    )
+                    .context(context_logger()));
+                }
+
+                let account = TokenHolderRef::<S>::User(&account).into();
+                if frozen {
+                    self.frozen_accounts.set(&key, &(), state)?;
+                    Event::AccountFrozen {
+                        freezer: sender.into(),
+                        account,
+                        token_id,
+                    }
+                } else {
+                    self.frozen_accounts.delete(&key, state)?;
+                    Event::AccountUnfrozen {
+                        freezer: sender.into(),
+                        account,
+                        token_id,
+                    }
+                }
+            }
+            None => {
+                if self.frozen_tokens.contains_key(&token_id, state)? == frozen {
+                    let state_name = if frozen {
+                        "already frozen"
+                    } else {
+                        "not frozen"
+                    };
+                    return Err(anyhow::anyhow!(
+                        "Transfers of token {} are {}",
+                        token.name,
+                        state_name
+                    )
+                    .context(context_logger()));
+                }
+
+                if frozen {
+                    self.frozen_tokens.set(&token_id, &(), state)?;
+                    Event::TokenTransfersFrozen {
+                        freezer: sender.into(),
+                        token_id,
+                    }
+                } else {
+                    self.frozen_tokens.delete(&token_id, state)?;
+                    Event::TokenTransfersUnfrozen {
+                        freezer: sender.into(),
+                        token_id,
+                    }
+                }
+            }
+        };
+        self.emit_event(state, event);
+
+        Ok(CallResponse::default())
+    }
+
+    fn ensure_transfers_unfrozen(
+        &self,
+        from: TokenHolderRef<'_, S>,
+        to: TokenHolderRef<'_, S>,
+        token_id: &TokenId,
+        state: &mut impl StateAccessor,
+    ) -> Result<()> {
+        if self.frozen_tokens.contains_key(token_id, state)? {
+            bail!("Transfers of token {} are frozen", token_id);
+        }
+        for holder in [from, to] {
+            if let TokenHolderRef::User(address) = holder {
+                if self
+                    .frozen_accounts
+                    .contains_key(&(address.clone(), *token_id), state)?
+                {
+                    bail!("Account {} is frozen for token {}", address, token_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the amount of `token_id` tokens that `spender` can transfer on behalf of `context.sender()`.
+    pub(crate) fn approve(
+        &self,
+        spender: S::Address,
+        token_id: TokenId,
+        amount: Amount,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse> {
+        let owner = context.sender();
+        self.tokens.get_or_err(&token_id, state).with_context(|| {
+            format!(
+                "Failed to approve spender {} of token_id={}",
+                spender, token_id
+            )
+        })??;
+
+        let key = (owner.clone(), spender.clone(), token_id);
+        if amount == 0 {
+            self.allowances.delete(&key, state)?;
+        } else {
+            self.allowances.set(&key, &amount, state)?;
+        }
+
+        self.emit_event(
+            state,
+            Event::AllowanceApproved {
+                owner: owner.as_token_holder().into(),
+                spender: TokenHolderRef::User(&spender).into(),
+                token_id,
+                amount,
+            },
+        );
+
+        Ok(CallResponse::default())
+    }
+
+    /// Transfers the set of `coins` from `from` to `to` on behalf of `context.sender()`,
+    /// debiting the allowance given by `from` to the sender.
+    ///
+    /// Returns an error if the transferred amount exceeds the allowance.
+    pub(crate) fn transfer_from_allowance(
+        &self,
+        from: S::Address,
+        to: S::Address,
+        coins: Coins,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse> {
+        let spender = context.sender();
+        let key = (from.clone(), spender.clone(), coins.token_id);
+        let allowance = self.allowances.get(&key, state)?.unwrap_or_default();
+        let remaining = allowance.checked_sub(coins.amount).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Spender {} is not allowed to transfer coins({}) from {}: the allowance is {}",
+                spender,
+                coins,
+                from,
+                allowance
+            )
+        })?;
+
+        self.transfer_from(&from, &to, coins.clone(), state)?;
+        if remaining == 0 {
+            self.allowances.delete(&key, state)?;
+        } else {
+            self.allowances.set(&key, &remaining, state)?;
+        }
+
+        self.emit_event(
+            state,
+            Event::TokenTransferred {
+                from: TokenHolderRef::User(&from).into(),
+                to: TokenHolderRef::User(&to).into(),
+                coins,
+            },
+        );
+
+        Ok(CallResponse::default())
+    }
 }
 
 impl<S: sov_modules_api::Spec> Bank<S> {
     /// Transfers the set of `coins` from the address `from` to the address `to`.
     ///
-    /// Returns an error if the token ID doesn't exist.
+    /// Returns an error if the token ID doesn't exist, or if the transfers of the token, or of
+    /// the token from or to one of the accounts, are frozen.
     pub fn transfer_from(
         &self,
         from: impl Payable<S>,
         to: impl Payable<S>,
         coins: Coins,
         state: &mut impl StateAccessor,
+    ) -> Result<CallResponse> {
+        let from = from.as_token_holder();
+        let to = to.as_token_holder();
+        self.ensure_transfers_unfrozen(from, to, &coins.token_id, state)
+            .with_context(|| {
+                format!(
+                    "Failed transfer from={} to={} of coins({})",
+                    &from, &to, coins
+                )
+            })?;
+        self.transfer_from_ignoring_freeze(from, to, coins, state)
+    }
+
+    /// Same as [`Bank::transfer_from`], but ignores frozen transfers.
+    ///
+    /// Reserved for the transfers the rollup relies on to make progress, i.e. gas payments and
+    /// bonds, so that freezing a token or an account can't halt the rollup.
+    pub fn transfer_from_ignoring_freeze(
+        &self,
+        from: impl Payable<S>,
+        to: impl Payable<S>,
+        coins: Coins,
+        state: &mut impl StateAccessor,
     ) -> Result<CallResponse> {
         let from = from.as_token_holder();
         let to = to.as_token_holder();
@@ -324,12 +657,123 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             .get_or_err(&coins.token_id, state)
             .map(|token| token.with_context(context_logger))
             .with_context(context_logger)??;
-        token
+        if from != to && coins.amount > 0 {
+            self.ensure_can_receive(&coins.token_id, Some(&token), to, state)
+                .with_context(context_logger)?;
+        }
+        let holders_delta = token
             .transfer(from, to, coins.amount, state)
             .with_context(context_logger)?;
+        self.update_holder_count(&coins.token_id, holders_delta, state)
+            .with_context(context_logger)?;
         Ok(CallResponse::default())
     }
 
+    /// Checks that `to` can receive some of the token `token_id` without holding more
+    /// distinct tokens than allowed by `max_tokens_per_account`. Receiving more of a token
+    /// that is already held is always allowed, and the gas token isn't subject to this limit.
+    ///
+    /// Must be called before updating the balance of `to`. `token` is `None` if the token
+    /// doesn't exist yet, i.e. when it's being created.
+    pub(crate) fn ensure_can_receive(
+        &self,
+        token_id: &TokenId,
+        token: Option<&Token<S>>,
+        to: TokenHolderRef<'_, S>,
+        state: &mut impl StateAccessor,
+    ) -> Result<()> {
+        if *token_id == GAS_TOKEN_ID {
+            return Ok(());
+        }
+        let TokenHolderRef::User(address) = to else {
+            return Ok(());
+        };
+        let Some(max_tokens_per_account) = self.max_tokens_per_account.get(state)? else {
+            return Ok(());
+        };
+
+        if let Some(token) = token {
+            if token.balances.get(&to, state)?.unwrap_or_default() > 0 {
+                return Ok(());
+            }
+        }
+
+        let token_count = self
+            .account_token_counts
+            .get(address, state)?
+            .unwrap_or_default();
+        if token_count >= max_tokens_per_account {
+            bail!(
+                "Account {} can't hold more than {} distinct tokens, failed to receive token id={}",
+                address,
+                max_tokens_per_account,
+                token_id
+            );
+        }
+        Ok(())
+    }
+
+    /// Applies `holders_delta` to the holder count of `token_id` and to the token counts of
+    /// the accounts that started or stopped holding it.
+    ///
+    /// The limit on the number of distinct tokens per account is checked beforehand by
+    /// [`Bank::ensure_can_receive`].
+    pub(crate) fn update_holder_count(
+        &self,
+        token_id: &TokenId,
+        holders_delta: HoldersDelta<S>,
+        state: &mut impl StateAccessor,
+    ) -> Result<()> {
+        if holders_delta.is_empty() {
+            return Ok(());
+        }
+
+        let holder_count = self
+            .holder_counts
+            .get(token_id, state)?
+            .unwrap_or_default()
+            .checked_add(holders_delta.added.len() as u64)
+            .and_then(|count| count.checked_sub(holders_delta.removed.len() as u64))
+            .with_context(|| format!("The holder count of token id={} is invalid", token_id))?;
+        self.holder_counts.set(token_id, &holder_count, state)?;
+
+        if *token_id == GAS_TOKEN_ID {
+            return Ok(());
+        }
+
+        for holder in &holders_delta.removed {
+            if let TokenHolder::User(address) = holder {
+                let token_count = self
+                    .account_token_counts
+                    .get(address, state)?
+                    .unwrap_or_default()
+                    .checked_sub(1)
+                    .with_context(|| {
+                        format!("The token count of account {} is invalid", address)
+                    })?;
+                if token_count == 0 {
+                    self.account_token_counts.delete(address, state)?;
+                } else {
+                    self.account_token_counts
+                        .set(address, &token_count, state)?;
+                }
+            }
+        }
+
+        for holder in &holders_delta.added {
+            if let TokenHolder::User(address) = holder {
+                let token_count = self
+                    .account_token_counts
+                    .get(address, state)?
+                    .unwrap_or_default()
+                    .saturating_add(1);
+                self.account_token_counts
+                    .set(address, &token_count, state)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Helper function used by the rpc method [`balance_of`](Bank::balance_of) to return the balance of the token stored at `token_id`
     /// for the user having the address `user_address` from the underlying storage. If the token ID doesn't exist, or
     /// if the user doesn't have tokens of that type, return `None`. Otherwise, wrap the resulting balance in `Some`.
@@ -346,6 +790,20 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             .transpose()
     }
 
+    /// Returns the amount of `token_id` tokens that `spender` can still transfer on behalf of `owner`.
+    pub fn get_allowance<Accessor: StateReader<User>>(
+        &self,
+        owner: &S::Address,
+        spender: &S::Address,
+        token_id: TokenId,
+        state: &mut Accessor,
+    ) -> Result<Amount, Accessor::Error> {
+        Ok(self
+            .allowances
+            .get(&(owner.clone(), spender.clone(), token_id), state)?
+            .unwrap_or_default())
+    }
+
     /// Get the name of a token by ID
     pub fn get_token_name<Accessor: StateReader<User>>(
         &self,
@@ -367,6 +825,32 @@ impl<S: sov_modules_api::Spec> Bank<S> {
             .get(token_id, state)?
             .map(|token| token.total_supply))
     }
+
+    /// Returns the [`TokenMetadata`] of the token with the given `token_id`, or `None` if the
+    /// token doesn't exist or was created without metadata.
+    pub fn get_token_metadata<Accessor: StateReader<User>>(
+        &self,
+        token_id: &TokenId,
+        state: &mut Accessor,
+    ) -> Result<Option<TokenMetadata>, Accessor::Error> {
+        self.token_metadata.get(token_id, state)
+    }
+
+    /// Returns the number of accounts holding a non-zero balance of the token with the given
+    /// `token_id`, or `None` if the token doesn't exist.
+    pub fn get_holder_count<Accessor: StateAccessor>(
+        &self,
+        token_id: &TokenId,
+        state: &mut Accessor,
+    ) -> Result<Option<u64>, <Accessor as StateReader<User>>::Error> {
+        if !self.tokens.contains_key(token_id, state)? {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            self.holder_counts.get(token_id, state)?.unwrap_or_default(),
+        ))
+    }
 }
 
 /// Creates a new prefix from an already existing prefix `parent_prefix` and a `token_id`
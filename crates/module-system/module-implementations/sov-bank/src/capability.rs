@@ -103,7 +103,7 @@ impl<S: Spec> Bank<S> {
 
         // We lock the `max_fee` amount into the `Bank` module.
         // We actually **need** to do that transfer because the payer account balance may change during the execution of the transaction.
-        if let Err(err) = self.transfer_from(
+        if let Err(err) = self.transfer_from_ignoring_freeze(
             payer,
             self.id.to_payable(),
             Coins {
@@ -154,7 +154,7 @@ impl<S: Spec> Bank<S> {
         tx_consumption: &TransactionConsumption<S::Gas>,
         tx_scratchpad: &mut TxScratchpad<S>,
     ) {
-        self.transfer_from(
+        self.transfer_from_ignoring_freeze(
             self.id.to_payable(),
             base_fee_recipient.as_token_holder(),
             Coins {
@@ -165,7 +165,7 @@ impl<S: Spec> Bank<S> {
         )
         .expect("Transferring the consumed base fee gas is infallible");
 
-        self.transfer_from(
+        self.transfer_from_ignoring_freeze(
             self.id.to_payable(),
             tip_recipient.as_token_holder(),
             Coins {
@@ -186,7 +186,7 @@ impl<S: Spec> Bank<S> {
     ) {
         // We refund the payer. We need to give back the remaining funds on the gas meter, plus the unspent tip.
         // This is also the maximum fee minus everything that was spent for the tip and base fee (ie the total reward).
-        self.transfer_from(
+        self.transfer_from_ignoring_freeze(
             self.id.to_payable(),
             payer,
             Coins {
@@ -1,5 +1,5 @@
 use crate::utils::TokenHolder;
-use crate::{Coins, TokenId};
+use crate::{Amount, Coins, TokenId};
 
 /// Bank Event
 #[derive(
@@ -39,6 +39,8 @@ pub enum Event<S: sov_modules_api::Spec> {
         owner: TokenHolder<S>,
         /// The tokens that were burned.
         coins: Coins,
+        /// Why the tokens were burned.
+        reason: BurnReason,
     },
     /// The supply of a token was frozen
     TokenFrozen {
@@ -47,6 +49,49 @@ pub enum Event<S: sov_modules_api::Spec> {
         /// The ID of the token that was transferred
         token_id: TokenId,
     },
+    /// The transfers of a token were frozen
+    TokenTransfersFrozen {
+        /// The token holder that froze the transfers
+        freezer: TokenHolder<S>,
+        /// The ID of the token whose transfers were frozen
+        token_id: TokenId,
+    },
+    /// The transfers of a token were unfrozen
+    TokenTransfersUnfrozen {
+        /// The token holder that unfroze the transfers
+        freezer: TokenHolder<S>,
+        /// The ID of the token whose transfers were unfrozen
+        token_id: TokenId,
+    },
+    /// The transfers of a token from and to an account were frozen
+    AccountFrozen {
+        /// The token holder that froze the account
+        freezer: TokenHolder<S>,
+        /// The frozen account
+        account: TokenHolder<S>,
+        /// The ID of the token whose transfers were frozen
+        token_id: TokenId,
+    },
+    /// The transfers of a token from and to an account were unfrozen
+    AccountUnfrozen {
+        /// The token holder that unfroze the account
+        freezer: TokenHolder<S>,
+        /// The unfrozen account
+        account: TokenHolder<S>,
+        /// The ID of the token whose transfers were unfrozen
+        token_id: TokenId,
+    },
+    /// An owner set the allowance of a spender
+    AllowanceApproved {
+        /// The token holder whose tokens can be spent.
+        owner: TokenHolder<S>,
+        /// The token holder allowed to spend the tokens.
+        spender: TokenHolder<S>,
+        /// The ID of the token that can be spent.
+        token_id: TokenId,
+        /// The maximum amount of tokens that can be spent.
+        amount: Amount,
+    },
     /// Event for Token Minting
     TokenMinted {
         /// The identity to mint the tokens to
@@ -55,3 +100,25 @@ pub enum Event<S: sov_modules_api::Spec> {
         coins: Coins,
     },
 }
+
+/// The reason why tokens were burned, recorded in [`Event::TokenBurned`].
+#[derive(
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum BurnReason {
+    /// The bond of a slashed user was burned.
+    Slashing,
+    /// A share of the gas fees was burned when rewarding a user, see [`crate::BurnRate`].
+    GasFee,
+    /// The owner of the tokens burned them with a [`crate::CallMessage::Burn`] call.
+    Manual,
+}
@@ -1,11 +1,14 @@
+use std::collections::{HashMap, HashSet};
+
 use anyhow::{bail, Result};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::GenesisState;
+use thiserror::Error;
 
 use crate::token::Token;
 use crate::utils::TokenHolderRef;
-use crate::{Bank, TokenId, GAS_TOKEN_ID};
+use crate::{Amount, Bank, TokenId, TokenMetadata, GAS_TOKEN_ID};
 
 /// Initial configuration for sov-bank module.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -20,6 +23,183 @@ pub struct BankConfig<S: sov_modules_api::Spec> {
     pub gas_token_config: GasTokenConfig<S>,
     /// A list of configurations for any other tokens to create at genesis
     pub tokens: Vec<TokenConfig<S>>,
+    /// The maximum number of distinct tokens, other than the gas token, that an
+    /// account can hold. Unlimited if not set.
+    #[serde(default)]
+    pub max_tokens_per_account: Option<u64>,
+    /// The address allowed to freeze and unfreeze the transfers of tokens and accounts.
+    /// Freezing is disabled if not set.
+    #[serde(default)]
+    pub freeze_admin: Option<S::Address>,
+}
+
+impl<S: sov_modules_api::Spec> BankConfig<S> {
+    /// Checks the initial token allocations of the config: addresses must be unique within
+    /// a token, balances must sum up to a valid total supply, and the zero address can't be
+    /// an authorized minter.
+    pub fn validate(&self) -> Result<(), GenesisValidationError> {
+        validate_token_allocation::<S>(
+            GAS_TOKEN_ID,
+            &self.gas_token_config.address_and_balances,
+            &self.gas_token_config.authorized_minters,
+        )?;
+        for token_config in &self.tokens {
+            validate_token_allocation::<S>(
+                token_config.token_id,
+                &token_config.address_and_balances,
+                &token_config.authorized_minters,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the gas token allocation of every address covers all the `bonds` that
+    /// other modules transfer from it at genesis.
+    ///
+    /// This check spans several modules, so it has to be run once all their configs are
+    /// known. Unlike the module geneses, which stop at the first failed bond transfer, it
+    /// reports every address whose bonds aren't backed by its allocation.
+    pub fn validate_bonds(&self, bonds: &[GenesisBond<S>]) -> Result<(), UnbackedBondsError> {
+        let allocations = self
+            .gas_token_config
+            .address_and_balances
+            .iter()
+            .map(|(address, balance)| (address, *balance))
+            .collect::<HashMap<_, _>>();
+
+        // Sums up the bonds of each address, in order of first appearance.
+        let mut bonded_by_address: Vec<(&S::Address, Vec<&GenesisBond<S>>)> = Vec::new();
+        for bond in bonds {
+            match bonded_by_address
+                .iter_mut()
+                .find(|(address, _)| **address == bond.address)
+            {
+                Some((_, address_bonds)) => address_bonds.push(bond),
+                None => bonded_by_address.push((&bond.address, vec![bond])),
+            }
+        }
+
+        let unbacked = bonded_by_address
+            .into_iter()
+            .filter_map(|(address, address_bonds)| {
+                let allocated = allocations.get(address).copied().unwrap_or_default();
+                let bonded = address_bonds
+                    .iter()
+                    .try_fold(0u64, |total, bond| total.checked_add(bond.amount));
+                match bonded {
+                    Some(bonded) if bonded <= allocated => None,
+                    _ => Some(UnbackedBond {
+                        address: address.to_string(),
+                        allocated,
+                        bonds: address_bonds
+                            .iter()
+                            .map(|bond| (bond.module.to_owned(), bond.amount))
+                            .collect(),
+                    }),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if unbacked.is_empty() {
+            Ok(())
+        } else {
+            Err(UnbackedBondsError { unbacked })
+        }
+    }
+}
+
+/// An amount of gas tokens that a module bonds on behalf of `address` at genesis, by
+/// transferring it from the gas token allocation of `address` in the [`BankConfig`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisBond<S: sov_modules_api::Spec> {
+    /// The name of the module holding the bond.
+    pub module: &'static str,
+    /// The bonded address.
+    pub address: S::Address,
+    /// The bonded amount.
+    pub amount: Amount,
+}
+
+/// The bonds of an address that exceed its gas token allocation, see
+/// [`BankConfig::validate_bonds`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnbackedBond {
+    /// The bonded address.
+    pub address: String,
+    /// The gas token allocation of the address.
+    pub allocated: Amount,
+    /// The modules holding a bond of the address, with the bonded amounts.
+    pub bonds: Vec<(String, Amount)>,
+}
+
+impl core::fmt::Display for UnbackedBond {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bonds = self
+            .bonds
+            .iter()
+            .map(|(module, amount)| format!("{} in {}", amount, module))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "address {} bonds {} but is only allocated {} gas tokens",
+            self.address, bonds, self.allocated
+        )
+    }
+}
+
+/// The error returned by [`BankConfig::validate_bonds`], listing all the unbacked bonds.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error(
+    "Genesis bonds aren't backed by the gas token allocation: {}",
+    .unbacked.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+)]
+pub struct UnbackedBondsError {
+    /// The addresses whose bonds exceed their allocation.
+    pub unbacked: Vec<UnbackedBond>,
+}
+
+/// The errors that might arise when validating a [`BankConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GenesisValidationError {
+    /// The same address has more than one initial balance for a token.
+    #[error("Address {address} has more than one initial balance for token {token_id}")]
+    DuplicateAddress { token_id: TokenId, address: String },
+    /// The initial balances of a token don't fit in its total supply.
+    #[error("The initial balances of token {token_id} overflow its total supply")]
+    SupplyOverflow { token_id: TokenId },
+    /// The zero address is an authorized minter of a token.
+    #[error("The zero address can't be an authorized minter of token {token_id}")]
+    ZeroAddressMinter { token_id: TokenId },
+}
+
+fn validate_token_allocation<S: sov_modules_api::Spec>(
+    token_id: TokenId,
+    address_and_balances: &[(S::Address, u64)],
+    authorized_minters: &[S::Address],
+) -> Result<(), GenesisValidationError> {
+    let mut addresses = HashSet::new();
+    let mut total_supply: u64 = 0;
+    for (address, balance) in address_and_balances {
+        if !addresses.insert(address) {
+            return Err(GenesisValidationError::DuplicateAddress {
+                token_id,
+                address: address.to_string(),
+            });
+        }
+        total_supply = total_supply
+            .checked_add(*balance)
+            .ok_or(GenesisValidationError::SupplyOverflow { token_id })?;
+    }
+
+    if authorized_minters
+        .iter()
+        .any(|minter| minter.as_ref().iter().all(|byte| *byte == 0))
+    {
+        return Err(GenesisValidationError::ZeroAddressMinter { token_id });
+    }
+
+    Ok(())
 }
 
 /// [`TokenConfig`] specifies a configuration used when generating a token for the bank
@@ -40,6 +220,9 @@ pub struct TokenConfig<S: sov_modules_api::Spec> {
     pub address_and_balances: Vec<(S::Address, u64)>,
     /// The addresses that are authorized to mint the token.
     pub authorized_minters: Vec<S::Address>,
+    /// The symbol and number of decimals of the token, if any.
+    #[serde(default)]
+    pub metadata: Option<TokenMetadata>,
 }
 
 impl<S: sov_modules_api::Spec> core::fmt::Display for TokenConfig<S> {
@@ -84,6 +267,9 @@ pub struct GasTokenConfig<S: sov_modules_api::Spec> {
     pub address_and_balances: Vec<(S::Address, u64)>,
     /// The addresses that are authorized to mint the token.
     pub authorized_minters: Vec<S::Address>,
+    /// The symbol and number of decimals of the token, if any.
+    #[serde(default)]
+    pub metadata: Option<TokenMetadata>,
 }
 
 impl<S: sov_modules_api::Spec> From<GasTokenConfig<S>> for TokenConfig<S> {
@@ -93,6 +279,7 @@ impl<S: sov_modules_api::Spec> From<GasTokenConfig<S>> for TokenConfig<S> {
             token_id: crate::GAS_TOKEN_ID,
             address_and_balances: gas_token_config.address_and_balances,
             authorized_minters: gas_token_config.authorized_minters,
+            metadata: gas_token_config.metadata,
         }
     }
 }
@@ -123,6 +310,16 @@ impl<S: sov_modules_api::Spec> Bank<S> {
         config: &<Self as sov_modules_api::Module>::Config,
         state: &mut impl GenesisState<S>,
     ) -> Result<()> {
+        config.validate()?;
+
+        if let Some(max_tokens_per_account) = config.max_tokens_per_account {
+            self.max_tokens_per_account
+                .set(&max_tokens_per_account, state)?;
+        }
+        if let Some(freeze_admin) = &config.freeze_admin {
+            self.freeze_admin.set(freeze_admin, state)?;
+        }
+
         let parent_prefix = self.tokens.prefix();
         let gas_token_config: TokenConfig<S> = config.gas_token_config.clone().into();
         tracing::debug!(token_id = %GAS_TOKEN_ID, token_name = %gas_token_config.token_name, "Gas token");
@@ -145,7 +342,13 @@ impl<S: sov_modules_api::Spec> Bank<S> {
                 .map(|(address, balance)| (TokenHolderRef::<'_, S>::from(&address), *balance))
                 .collect::<Vec<_>>();
 
-            let token = Token::<S>::create_with_token_id(
+            for (address, balance) in &address_and_balances {
+                if *balance > 0 {
+                    self.ensure_can_receive(token_id, None, *address, state)?;
+                }
+            }
+
+            let (token, holders_delta) = Token::<S>::create_with_token_id(
                 &token_config.token_name,
                 &address_and_balances,
                 &authorized_minters,
@@ -154,11 +357,15 @@ impl<S: sov_modules_api::Spec> Bank<S> {
                 state,
             )?;
 
-            if self.tokens.get(token_id, state)?.is_some() {
+            if self.tokens.contains_key(token_id, state)? {
                 bail!("token ID {} already exists", token_config.token_id);
             }
 
             self.tokens.set(token_id, &token, state)?;
+            self.update_holder_count(token_id, holders_delta, state)?;
+            if let Some(metadata) = &token_config.metadata {
+                self.token_metadata.set(token_id, metadata, state)?;
+            }
             tracing::debug!(
                 token_name = %token.name,
                 token_id = %token_id,
@@ -174,6 +381,7 @@ mod tests {
     use std::str::FromStr;
 
     use sov_modules_api::prelude::serde_json;
+    use sov_modules_api::utils::generate_address;
     use sov_modules_api::{AddressBech32, Spec};
     use sov_test_utils::TestSpec;
 
@@ -195,13 +403,17 @@ mod tests {
                 token_name: "sov-gas-token".to_owned(),
                 address_and_balances: vec![(sender_address, 100000000)],
                 authorized_minters: vec![sender_address],
+                metadata: None,
             },
             tokens: vec![TokenConfig {
                 token_name: "sov-demo-token".to_owned(),
                 token_id,
                 address_and_balances: vec![(sender_address, 1000)],
                 authorized_minters: vec![sender_address],
+                metadata: None,
             }],
+            max_tokens_per_account: None,
+            freeze_admin: None,
         };
 
         let data = r#"
@@ -225,4 +437,55 @@ mod tests {
 
         assert_eq!(config, parsed_config);
     }
+
+    fn config_with_allocations(
+        address_and_balances: Vec<(<TestSpec as Spec>::Address, u64)>,
+        authorized_minters: Vec<<TestSpec as Spec>::Address>,
+    ) -> BankConfig<TestSpec> {
+        BankConfig {
+            gas_token_config: GasTokenConfig {
+                token_name: "sov-gas-token".to_owned(),
+                address_and_balances,
+                authorized_minters,
+                metadata: None,
+            },
+            tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: None,
+        }
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let alice = generate_address::<TestSpec>("alice");
+        let bob = generate_address::<TestSpec>("bob");
+
+        let valid = config_with_allocations(vec![(alice, u64::MAX - 1), (bob, 1)], vec![alice]);
+        assert_eq!(valid.validate(), Ok(()));
+
+        let duplicate = config_with_allocations(vec![(alice, 1), (bob, 1), (alice, 1)], vec![]);
+        assert_eq!(
+            duplicate.validate(),
+            Err(GenesisValidationError::DuplicateAddress {
+                token_id: GAS_TOKEN_ID,
+                address: alice.to_string(),
+            })
+        );
+
+        let overflowing = config_with_allocations(vec![(alice, u64::MAX), (bob, 1)], vec![]);
+        assert_eq!(
+            overflowing.validate(),
+            Err(GenesisValidationError::SupplyOverflow {
+                token_id: GAS_TOKEN_ID
+            })
+        );
+
+        let zero_minter = config_with_allocations(vec![(alice, 1)], vec![[0; 32].into()]);
+        assert_eq!(
+            zero_minter.validate(),
+            Err(GenesisValidationError::ZeroAddressMinter {
+                token_id: GAS_TOKEN_ID
+            })
+        );
+    }
 }
@@ -10,6 +10,8 @@ mod genesis;
 mod query;
 #[cfg(feature = "native")]
 pub use query::*;
+#[cfg(feature = "native")]
+mod rest;
 mod token;
 /// Util functions for bank
 pub mod utils;
@@ -21,7 +23,7 @@ use sov_modules_api::{
 };
 use token::Token;
 /// Specifies an interface to interact with tokens.
-pub use token::{Amount, BurnRate, Coins, TokenId, TokenIdBech32};
+pub use token::{Amount, BurnRate, Coins, TokenId, TokenIdBech32, TokenMetadata};
 use utils::TokenHolderRef;
 /// Methods to get a token ID.
 pub use utils::{get_token_id, IntoPayable, Payable};
@@ -51,6 +53,9 @@ pub struct BankGasConfig<GU: Gas> {
 
     /// Gas price multiplier for the freeze operation
     pub freeze: GU,
+
+    /// Gas price multiplier for the approve operation
+    pub approve: GU,
 }
 
 /// The sov-bank module manages user balances. It provides functionality for:
@@ -70,6 +75,41 @@ pub struct Bank<S: sov_modules_api::Spec> {
     /// A mapping of [`TokenId`]s to tokens in the sov-bank.
     #[state]
     pub(crate) tokens: sov_modules_api::StateMap<TokenId, Token<S>>,
+
+    /// Allowances given by owners to spenders, keyed by `(owner, spender, token_id)`.
+    #[state]
+    pub(crate) allowances: sov_modules_api::StateMap<(S::Address, S::Address, TokenId), Amount>,
+
+    /// The number of accounts with a non-zero balance, for each [`TokenId`].
+    #[state]
+    pub(crate) holder_counts: sov_modules_api::StateMap<TokenId, u64>,
+
+    /// The [`TokenMetadata`] of the tokens which were created with one.
+    #[state]
+    pub(crate) token_metadata: sov_modules_api::StateMap<TokenId, TokenMetadata>,
+
+    /// The tokens whose transfers are frozen, see [`CallMessage::FreezeToken`].
+    #[state]
+    pub(crate) frozen_tokens: sov_modules_api::StateMap<TokenId, ()>,
+
+    /// The accounts whose transfers of a token are frozen, keyed by `(address, token_id)`,
+    /// see [`CallMessage::FreezeAccount`].
+    #[state]
+    pub(crate) frozen_accounts: sov_modules_api::StateMap<(S::Address, TokenId), ()>,
+
+    /// The maximum number of distinct tokens, other than the gas token, that an account
+    /// can hold. Unlimited if not set.
+    #[state]
+    pub(crate) max_tokens_per_account: sov_modules_api::StateValue<u64>,
+
+    /// The address allowed to freeze and unfreeze transfers. Freezing is disabled if not set.
+    #[state]
+    pub(crate) freeze_admin: sov_modules_api::StateValue<S::Address>,
+
+    /// The number of distinct tokens, other than the gas token, with a non-zero balance
+    /// for each user account.
+    #[state]
+    pub(crate) account_token_counts: sov_modules_api::StateMap<S::Address, u64>,
 }
 
 impl<S: sov_modules_api::Spec> sov_modules_api::Module for Bank<S> {
@@ -102,6 +142,7 @@ impl<S: sov_modules_api::Spec> sov_modules_api::Module for Bank<S> {
                 initial_balance,
                 mint_to_address,
                 authorized_minters,
+                metadata,
             } => {
                 self.charge_gas(state, &self.gas.create_token)?;
 
@@ -116,6 +157,7 @@ impl<S: sov_modules_api::Spec> sov_modules_api::Module for Bank<S> {
                     initial_balance,
                     &mint_to_address,
                     authorized_minters,
+                    metadata,
                     context.sender(),
                     state,
                 )?;
@@ -145,6 +187,40 @@ impl<S: sov_modules_api::Spec> sov_modules_api::Module for Bank<S> {
                 self.charge_gas(state, &self.gas.freeze)?;
                 Ok(self.freeze(token_id, context, state)?)
             }
+
+            call::CallMessage::Approve {
+                spender,
+                token_id,
+                amount,
+            } => {
+                self.charge_gas(state, &self.gas.approve)?;
+                Ok(self.approve(spender, token_id, amount, context, state)?)
+            }
+
+            call::CallMessage::TransferFrom { from, to, coins } => {
+                self.charge_gas(state, &self.gas.transfer)?;
+                Ok(self.transfer_from_allowance(from, to, coins, context, state)?)
+            }
+
+            call::CallMessage::FreezeToken { token_id } => {
+                self.charge_gas(state, &self.gas.freeze)?;
+                Ok(self.set_transfers_frozen(token_id, None, true, context, state)?)
+            }
+
+            call::CallMessage::UnfreezeToken { token_id } => {
+                self.charge_gas(state, &self.gas.freeze)?;
+                Ok(self.set_transfers_frozen(token_id, None, false, context, state)?)
+            }
+
+            call::CallMessage::FreezeAccount { address, token_id } => {
+                self.charge_gas(state, &self.gas.freeze)?;
+                Ok(self.set_transfers_frozen(token_id, Some(address), true, context, state)?)
+            }
+
+            call::CallMessage::UnfreezeAccount { address, token_id } => {
+                self.charge_gas(state, &self.gas.freeze)?;
+                Ok(self.set_transfers_frozen(token_id, Some(address), false, context, state)?)
+            }
         }
     }
 }
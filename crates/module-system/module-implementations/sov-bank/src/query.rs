@@ -2,7 +2,8 @@
 use jsonrpsee::core::RpcResult;
 use sov_modules_api::macros::rpc_gen;
 use sov_modules_api::prelude::UnwrapInfallible;
-use sov_modules_api::ApiStateAccessor;
+use sov_modules_api::{ApiStateAccessor, StateReader};
+use sov_state::User;
 
 use crate::{get_token_id, Amount, Bank, TokenId};
 
@@ -20,6 +21,45 @@ pub struct TotalSupplyResponse {
     pub amount: Option<Amount>,
 }
 
+/// Structure returned by the `holder_count_of` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct HolderCountResponse {
+    /// The number of accounts with a non-zero balance for a given token ID.
+    pub count: Option<u64>,
+}
+
+/// Structure returned by the `metadataOf` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct TokenMetadataResponse {
+    /// The name of the token.
+    pub name: String,
+    /// The ticker symbol of the token, if it was created with metadata.
+    pub symbol: Option<String>,
+    /// The number of decimals of the token, if it was created with metadata.
+    pub decimals: Option<u8>,
+}
+
+impl<S: sov_modules_api::Spec> Bank<S> {
+    /// Returns the name and metadata of the token with the given `token_id`, or `None` if
+    /// the token doesn't exist.
+    pub(crate) fn token_metadata_response<Accessor: StateReader<User>>(
+        &self,
+        token_id: &TokenId,
+        state: &mut Accessor,
+    ) -> Result<Option<TokenMetadataResponse>, Accessor::Error> {
+        let Some(name) = self.get_token_name(token_id, state)? else {
+            return Ok(None);
+        };
+        let metadata = self.get_token_metadata(token_id, state)?;
+
+        Ok(Some(TokenMetadataResponse {
+            name,
+            symbol: metadata.as_ref().map(|metadata| metadata.symbol.clone()),
+            decimals: metadata.map(|metadata| metadata.decimals),
+        }))
+    }
+}
+
 #[rpc_gen(client, server, namespace = "bank")]
 impl<S: sov_modules_api::Spec> Bank<S> {
     #[rpc_method(name = "balanceOf")]
@@ -58,6 +98,39 @@ impl<S: sov_modules_api::Spec> Bank<S> {
         Ok(TotalSupplyResponse { amount })
     }
 
+    #[rpc_method(name = "holderCountOf")]
+    /// Rpc method that returns the number of accounts holding the token stored at the address `token_id`.
+    pub fn holder_count_of(
+        &self,
+        version: Option<u64>,
+        token_id: TokenId,
+        state: &mut ApiStateAccessor<S>,
+    ) -> RpcResult<HolderCountResponse> {
+        let count = if let Some(v) = version {
+            self.get_holder_count(&token_id, &mut state.get_archival_at(v))
+        } else {
+            self.get_holder_count(&token_id, state)
+        }
+        .unwrap_infallible();
+        Ok(HolderCountResponse { count })
+    }
+
+    #[rpc_method(name = "metadataOf")]
+    /// Rpc method that returns the name, symbol and decimals of the token stored at the address `token_id`.
+    pub fn metadata_of(
+        &self,
+        version: Option<u64>,
+        token_id: TokenId,
+        state: &mut ApiStateAccessor<S>,
+    ) -> RpcResult<Option<TokenMetadataResponse>> {
+        Ok(if let Some(v) = version {
+            self.token_metadata_response(&token_id, &mut state.get_archival_at(v))
+        } else {
+            self.token_metadata_response(&token_id, state)
+        }
+        .unwrap_infallible())
+    }
+
     #[rpc_method(name = "tokenId")]
     /// RPC method that returns the token ID for a given token name, sender, and salt.
     pub fn token_id(
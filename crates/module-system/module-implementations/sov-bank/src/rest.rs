@@ -0,0 +1,68 @@
+//! Custom REST API routes exposing token-level aggregates.
+use sov_modules_api::prelude::axum::routing::get;
+use sov_modules_api::prelude::{axum, UnwrapInfallible};
+use sov_modules_api::rest::utils::{errors, ApiResult, Path};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::Spec;
+
+use crate::{Bank, TokenId, TokenMetadataResponse};
+
+impl<S: Spec> HasCustomRestApi<S> for Bank<S> {
+    fn custom_rest_api(&self, state: ApiState<Self, S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route(
+                "/tokens/:tokenId/total-supply",
+                get(Self::route_total_supply),
+            )
+            .route(
+                "/tokens/:tokenId/holder-count",
+                get(Self::route_holder_count),
+            )
+            .route("/tokens/:tokenId/metadata", get(Self::route_metadata))
+            .with_state(state)
+    }
+}
+
+impl<S: Spec> Bank<S> {
+    async fn route_total_supply(
+        state: ApiState<Self, S>,
+        Path(token_id): Path<TokenId>,
+    ) -> ApiResult<u64> {
+        let total_supply = state
+            .get_total_supply_of(&token_id, &mut state.api_state_accessor())
+            .unwrap_infallible();
+
+        match total_supply {
+            Some(amount) => Ok(amount.into()),
+            None => Err(errors::not_found_404("Token", token_id)),
+        }
+    }
+
+    async fn route_holder_count(
+        state: ApiState<Self, S>,
+        Path(token_id): Path<TokenId>,
+    ) -> ApiResult<u64> {
+        let holder_count = state
+            .get_holder_count(&token_id, &mut state.api_state_accessor())
+            .unwrap_infallible();
+
+        match holder_count {
+            Some(count) => Ok(count.into()),
+            None => Err(errors::not_found_404("Token", token_id)),
+        }
+    }
+
+    async fn route_metadata(
+        state: ApiState<Self, S>,
+        Path(token_id): Path<TokenId>,
+    ) -> ApiResult<TokenMetadataResponse> {
+        let metadata = state
+            .token_metadata_response(&token_id, &mut state.api_state_accessor())
+            .unwrap_infallible();
+
+        match metadata {
+            Some(metadata) => Ok(metadata.into()),
+            None => Err(errors::not_found_404("Token", token_id)),
+        }
+    }
+}
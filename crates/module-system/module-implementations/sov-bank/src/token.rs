@@ -133,6 +133,96 @@ impl std::fmt::Display for Coins {
     }
 }
 
+/// Human-readable metadata of a token, used by wallets and explorers to render amounts,
+/// e.g. `1.5 SOV` instead of `1500000000 <token_id>`.
+#[cfg_attr(feature = "native", derive(schemars::JsonSchema))]
+#[derive(
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+)]
+pub struct TokenMetadata {
+    /// The ticker symbol of the token, e.g. `SOV`.
+    pub symbol: String,
+    /// The number of decimals used to display an [`Amount`] of the token.
+    pub decimals: u8,
+}
+
+/// The errors that might arise when parsing a `TokenMetadata` struct from a string.
+#[cfg(feature = "native")]
+#[derive(Debug, Error)]
+pub enum TokenMetadataFromStrError {
+    /// The input string was malformed, so the `decimals` substring could not be extracted.
+    #[error("No decimals were provided. Make sure that your input is in the format: symbol,decimals. Example: SOV,9")]
+    NoDecimalsProvided,
+    /// The number of decimals could not be parsed as an u8.
+    #[error("Could not parse {input} as a valid number of decimals: {err}")]
+    InvalidDecimals { input: String, err: ParseIntError },
+}
+
+#[cfg(feature = "native")]
+impl FromStr for TokenMetadata {
+    type Err = TokenMetadataFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (symbol, decimals_str) = s
+            .split_once(',')
+            .ok_or(TokenMetadataFromStrError::NoDecimalsProvided)?;
+
+        let decimals = decimals_str.parse::<u8>().map_err(|err| {
+            TokenMetadataFromStrError::InvalidDecimals {
+                input: decimals_str.into(),
+                err,
+            }
+        })?;
+
+        Ok(Self {
+            symbol: symbol.to_owned(),
+            decimals,
+        })
+    }
+}
+
+/// The accounts that started or stopped holding a token, i.e. whose
+/// balance went from zero to non-zero or the other way around.
+pub(crate) struct HoldersDelta<S: Spec> {
+    /// The accounts whose balance became non-zero.
+    pub(crate) added: Vec<TokenHolder<S>>,
+    /// The accounts whose balance became zero.
+    pub(crate) removed: Vec<TokenHolder<S>>,
+}
+
+// Manually implement Default because derive infers a spurious `Spec: Default` bound
+impl<S: Spec> Default for HoldersDelta<S> {
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+}
+
+impl<S: Spec> HoldersDelta<S> {
+    /// Records a balance update of `holder` from `old_balance` to `new_balance`.
+    fn record(&mut self, holder: TokenHolderRef<'_, S>, old_balance: Amount, new_balance: Amount) {
+        match (old_balance == 0, new_balance == 0) {
+            (true, false) => self.added.push(holder.into()),
+            (false, true) => self.removed.push(holder.into()),
+            _ => {}
+        }
+    }
+
+    /// Returns `true` if no account started or stopped holding the token.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
 /// This struct represents a token in the sov-bank module.
 #[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq, Clone)]
 pub struct Token<S: sov_modules_api::Spec> {
@@ -155,50 +245,57 @@ impl<S: sov_modules_api::Spec> Token<S> {
     /// Transfer the amount `amount` of tokens from the address `from` to the address `to`.
     /// First checks that there is enough token of that type stored in `from`. If so, update
     /// the balances of the `from` and `to` accounts.
+    ///
+    /// Returns the resulting change in the number of holders of the token.
     pub(crate) fn transfer(
         &self,
         from: TokenHolderRef<'_, S>,
         to: TokenHolderRef<'_, S>,
         amount: Amount,
         state: &mut impl StateAccessor,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<HoldersDelta<S>> {
+        let mut holders_delta = HoldersDelta::default();
+
         if from == to {
             tracing::debug!("Token transfer succeeded because it was transferring tokens to self.");
-            return Ok(());
+            return Ok(holders_delta);
         }
 
         if amount == 0 {
             tracing::debug!("Token transfer succeeded because the transfer amount was zero.");
-            return Ok(());
+            return Ok(holders_delta);
         }
 
         let from_balance = self.decrease_balance_checked(from, amount, state)?;
 
-        let to_balance = self
-            .balances
-            .get(&to, state)?
-            .unwrap_or_default()
-            .checked_add(amount)
-            .with_context(|| {
-                format!("Account balance overflow on={} for token={}", to, self.name)
-            })?;
+        let previous_to_balance = self.balances.get(&to, state)?.unwrap_or_default();
+        let to_balance = previous_to_balance.checked_add(amount).with_context(|| {
+            format!("Account balance overflow on={} for token={}", to, self.name)
+        })?;
 
         self.balances.set(&from, &from_balance, state)?;
         self.balances.set(&to, &to_balance, state)?;
-        Ok(())
+
+        holders_delta.record(from, from_balance + amount, from_balance);
+        holders_delta.record(to, previous_to_balance, to_balance);
+        Ok(holders_delta)
     }
     /// Burns a specified `amount` of token from the address `from`. First check that the address has enough token to burn,
     /// if not returns an error. Otherwise, update the balances by substracting the amount burnt.
+    ///
+    /// Returns the resulting change in the number of holders of the token.
     pub(crate) fn burn(
         &mut self,
         from: TokenHolderRef<'_, S>,
         amount: Amount,
         state: &mut impl StateAccessor,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<HoldersDelta<S>> {
         let new_balance = self.decrease_balance_checked(from, amount, state)?;
         self.balances.set(&from, &new_balance, state)?;
 
-        Ok(())
+        let mut holders_delta = HoldersDelta::default();
+        holders_delta.record(from, new_balance + amount, new_balance);
+        Ok(holders_delta)
     }
 
     /// Freezing a token requires emptying the authorized_minter vector
@@ -218,23 +315,26 @@ impl<S: sov_modules_api::Spec> Token<S> {
     /// Checks that the `authorized_minters` set is not empty for the token and that the `sender`
     /// is an `authorized_minter`. If so, update the balances of token for the `mint_to_address` by
     /// adding the minted tokens. Updates the `total_supply` of that token.
+    ///
+    /// Returns the resulting change in the number of holders of the token.
     pub(crate) fn mint(
         &mut self,
         authorizer: TokenHolderRef<'_, S>,
         mint_to_identity: TokenHolderRef<'_, S>,
         amount: Amount,
         state: &mut impl StateAccessor,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<HoldersDelta<S>> {
         if self.authorized_minters.is_empty() {
             bail!("Attempt to mint frozen token {}", self.name)
         }
 
         self.is_authorized_minter(authorizer)?;
 
-        let to_balance: Amount = self
+        let previous_balance = self
             .balances
             .get(&mint_to_identity, state)?
-            .unwrap_or_default()
+            .unwrap_or_default();
+        let to_balance: Amount = previous_balance
             .checked_add(amount)
             .ok_or(anyhow::Error::msg(
                 "Account balance overflow in the mint method of bank module",
@@ -247,10 +347,13 @@ impl<S: sov_modules_api::Spec> Token<S> {
             .ok_or(anyhow::Error::msg(
                 "Total Supply overflow in the mint method of bank module",
             ))?;
-        Ok(())
+
+        let mut holders_delta = HoldersDelta::default();
+        holders_delta.record(mint_to_identity, previous_balance, to_balance);
+        Ok(holders_delta)
     }
 
-    fn is_authorized_minter(&self, sender: TokenHolderRef<'_, S>) -> anyhow::Result<()> {
+    pub(crate) fn is_authorized_minter(&self, sender: TokenHolderRef<'_, S>) -> anyhow::Result<()> {
         for minter in self.authorized_minters.iter() {
             if sender == minter.as_token_holder() {
                 return Ok(());
@@ -289,7 +392,8 @@ impl<S: sov_modules_api::Spec> Token<S> {
     /// The `token_name`, `originator`  (as a `u8` slice), and the `salt` (`u64` number) are used as an input
     /// to an hash function that computes the token ID. Then the initial accounts and balances are populated
     /// from the `identities_and_balances` slice and the `total_supply` of tokens is updated each time.
-    /// Returns a tuple containing the computed `token_id` and the created `token` object.
+    /// Returns a tuple containing the computed `token_id`, the created `token` object and its
+    /// initial holders.
     pub(crate) fn create(
         token_name: &str,
         identities_and_balances: &[(TokenHolderRef<'_, S>, u64)],
@@ -298,9 +402,9 @@ impl<S: sov_modules_api::Spec> Token<S> {
         salt: u64,
         parent_prefix: &Prefix,
         state: &mut impl StateReaderAndWriter<User>,
-    ) -> anyhow::Result<(TokenId, Self)> {
+    ) -> anyhow::Result<(TokenId, Self, HoldersDelta<S>)> {
         let token_id = super::get_token_id::<S>(token_name, originator, salt);
-        let token = Self::create_with_token_id(
+        let (token, holders_delta) = Self::create_with_token_id(
             token_name,
             identities_and_balances,
             authorized_minters,
@@ -308,10 +412,11 @@ impl<S: sov_modules_api::Spec> Token<S> {
             parent_prefix,
             state,
         )?;
-        Ok((token_id, token))
+        Ok((token_id, token, holders_delta))
     }
 
-    /// Shouldn't be used directly, only by genesis call
+    /// Shouldn't be used directly, only by genesis call.
+    /// The addresses in `identities_and_balances` are expected to be unique.
     pub(crate) fn create_with_token_id(
         token_name: &str,
         identities_and_balances: &[(TokenHolderRef<'_, S>, u64)],
@@ -319,14 +424,16 @@ impl<S: sov_modules_api::Spec> Token<S> {
         token_id: &TokenId,
         parent_prefix: &Prefix,
         state: &mut impl StateReaderAndWriter<User>,
-    ) -> anyhow::Result<Token<S>> {
+    ) -> anyhow::Result<(Token<S>, HoldersDelta<S>)> {
         let token_prefix = prefix_from_address_with_parent(parent_prefix, token_id);
         let balances = sov_modules_api::StateMap::new(token_prefix);
 
         let mut total_supply: Option<u64> = Some(0);
+        let mut holders_delta = HoldersDelta::default();
         for (address, balance) in identities_and_balances.iter() {
             balances.set(address, balance, state)?;
             total_supply = total_supply.and_then(|ts| ts.checked_add(*balance));
+            holders_delta.record(*address, 0, *balance);
         }
 
         let total_supply = match total_supply {
@@ -336,12 +443,13 @@ impl<S: sov_modules_api::Spec> Token<S> {
 
         let authorized_minters = unique_minters(authorized_minters);
 
-        Ok(Token::<S> {
+        let token = Token::<S> {
             name: token_name.to_owned(),
             total_supply,
             balances,
             authorized_minters,
-        })
+        };
+        Ok((token, holders_delta))
     }
 }
 
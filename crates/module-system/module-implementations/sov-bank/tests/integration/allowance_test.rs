@@ -0,0 +1,180 @@
+use std::convert::Infallible;
+
+use sov_bank::event::Event;
+use sov_bank::utils::TokenHolder;
+use sov_bank::{Bank, CallMessage, Coins, GAS_TOKEN_ID};
+use sov_modules_api::{Context, Module, StateCheckpoint};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::helpers::*;
+
+type S = sov_test_utils::TestSpec;
+
+#[test]
+fn approve_and_transfer_from() -> Result<(), Infallible> {
+    let initial_balance = 100;
+    let allowance = 30;
+    let bank_config = create_bank_config_with_token(4, initial_balance);
+    let tmpdir = tempfile::tempdir().unwrap();
+    let state = StateCheckpoint::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let bank = Bank::default();
+    let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
+    bank.genesis(&bank_config, &mut genesis_state).unwrap();
+    let mut state = genesis_state.checkpoint().to_working_set_unmetered();
+
+    let owner = bank_config.gas_token_config.address_and_balances[0].0;
+    let spender = bank_config.gas_token_config.address_and_balances[1].0;
+    let receiver = bank_config.gas_token_config.address_and_balances[2].0;
+    let sequencer = bank_config.gas_token_config.address_and_balances[3].0;
+    let owner_context = Context::<S>::new(owner, Default::default(), sequencer, 1);
+    let spender_context = Context::<S>::new(spender, Default::default(), sequencer, 1);
+
+    // Approve
+    bank.call(
+        CallMessage::Approve {
+            spender,
+            token_id: GAS_TOKEN_ID,
+            amount: allowance,
+        },
+        &owner_context,
+        &mut state,
+    )
+    .expect("Approve call failed");
+    assert_eq!(
+        bank.get_allowance(&owner, &spender, GAS_TOKEN_ID, &mut state.to_unmetered())?,
+        allowance
+    );
+    assert_eq!(
+        state
+            .events()
+            .last()
+            .unwrap()
+            .downcast_ref::<Event<S>>()
+            .unwrap(),
+        &Event::AllowanceApproved {
+            owner: TokenHolder::User(owner),
+            spender: TokenHolder::User(spender),
+            token_id: GAS_TOKEN_ID,
+            amount: allowance,
+        }
+    );
+
+    // Transfer from within the allowance
+    let transfer_amount = 20;
+    bank.call(
+        CallMessage::TransferFrom {
+            from: owner,
+            to: receiver,
+            coins: Coins {
+                amount: transfer_amount,
+                token_id: GAS_TOKEN_ID,
+            },
+        },
+        &spender_context,
+        &mut state,
+    )
+    .expect("TransferFrom call within the allowance failed");
+    assert_eq!(
+        state
+            .events()
+            .last()
+            .unwrap()
+            .downcast_ref::<Event<S>>()
+            .unwrap(),
+        &Event::TokenTransferred {
+            from: TokenHolder::User(owner),
+            to: TokenHolder::User(receiver),
+            coins: Coins {
+                amount: transfer_amount,
+                token_id: GAS_TOKEN_ID,
+            },
+        }
+    );
+
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&owner, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance - transfer_amount)
+    );
+    assert_eq!(
+        bank.get_balance_of(&receiver, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance + transfer_amount)
+    );
+    assert_eq!(
+        bank.get_balance_of(&spender, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance)
+    );
+    assert_eq!(
+        bank.get_allowance(&owner, &spender, GAS_TOKEN_ID, &mut unmetered)?,
+        allowance - transfer_amount
+    );
+
+    Ok(())
+}
+
+#[test]
+fn transfer_from_over_allowance_fails() -> Result<(), Infallible> {
+    let initial_balance = 100;
+    let allowance = 30;
+    let bank_config = create_bank_config_with_token(4, initial_balance);
+    let tmpdir = tempfile::tempdir().unwrap();
+    let state = StateCheckpoint::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let bank = Bank::default();
+    let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
+    bank.genesis(&bank_config, &mut genesis_state).unwrap();
+    let mut state = genesis_state.checkpoint().to_working_set_unmetered();
+
+    let owner = bank_config.gas_token_config.address_and_balances[0].0;
+    let spender = bank_config.gas_token_config.address_and_balances[1].0;
+    let receiver = bank_config.gas_token_config.address_and_balances[2].0;
+    let sequencer = bank_config.gas_token_config.address_and_balances[3].0;
+    let owner_context = Context::<S>::new(owner, Default::default(), sequencer, 1);
+    let spender_context = Context::<S>::new(spender, Default::default(), sequencer, 1);
+
+    let transfer_from = |amount| CallMessage::TransferFrom {
+        from: owner,
+        to: receiver,
+        coins: Coins {
+            amount,
+            token_id: GAS_TOKEN_ID,
+        },
+    };
+
+    // Without any allowance
+    assert!(bank
+        .call(transfer_from(1), &spender_context, &mut state)
+        .is_err());
+
+    bank.call(
+        CallMessage::Approve {
+            spender,
+            token_id: GAS_TOKEN_ID,
+            amount: allowance,
+        },
+        &owner_context,
+        &mut state,
+    )
+    .expect("Approve call failed");
+
+    // Over the allowance
+    let error = bank
+        .call(transfer_from(allowance + 1), &spender_context, &mut state)
+        .unwrap_err();
+    assert!(error.to_string().contains("is not allowed to transfer"));
+
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&owner, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance)
+    );
+    assert_eq!(
+        bank.get_balance_of(&receiver, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance)
+    );
+    assert_eq!(
+        bank.get_allowance(&owner, &spender, GAS_TOKEN_ID, &mut unmetered)?,
+        allowance
+    );
+
+    Ok(())
+}
@@ -34,8 +34,11 @@ fn burn_deployed_tokens() -> Result<(), Infallible> {
             token_name: token_name.clone(),
             address_and_balances: vec![(minter, initial_balance)],
             authorized_minters: vec![minter],
+            metadata: None,
         },
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     };
 
     let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
@@ -1,6 +1,6 @@
 use std::convert::Infallible;
 
-use sov_bank::{get_token_id, Bank, CallMessage};
+use sov_bank::{get_token_id, Bank, CallMessage, TokenMetadata, GAS_TOKEN_ID};
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Context, Module, StateCheckpoint};
 use sov_prover_storage_manager::new_orphan_storage;
@@ -36,6 +36,7 @@ fn initial_and_deployed_token() -> Result<(), Infallible> {
         initial_balance,
         mint_to_address: minter,
         authorized_minters: vec![minter],
+        metadata: None,
     };
 
     let mut state = checkpoint.to_working_set_unmetered();
@@ -82,3 +83,66 @@ fn overflow_max_supply() {
         genesis_result.unwrap_err().to_string()
     );
 }
+
+#[test]
+fn token_metadata_is_stored_at_creation() -> Result<(), Infallible> {
+    let gas_token_metadata = TokenMetadata {
+        symbol: "GAS".to_owned(),
+        decimals: 6,
+    };
+    let mut bank_config = create_bank_config_with_token(1, 100);
+    bank_config.gas_token_config.metadata = Some(gas_token_metadata.clone());
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let state = StateCheckpoint::<S>::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
+    let bank = Bank::default();
+    bank.genesis(&bank_config, &mut genesis_state).unwrap();
+
+    let sender_address = generate_address::<S>("sender");
+    let sequencer_address = generate_address::<S>("sequencer");
+    let sender_context =
+        Context::<S>::new(sender_address, Default::default(), sequencer_address, 1);
+    let metadata = TokenMetadata {
+        symbol: "SOV".to_owned(),
+        decimals: 9,
+    };
+    let create_token =
+        |token_name: &str, metadata: Option<TokenMetadata>| CallMessage::CreateToken::<S> {
+            salt: 1,
+            token_name: token_name.to_owned(),
+            initial_balance: TEST_DEFAULT_USER_BALANCE,
+            mint_to_address: sender_address,
+            authorized_minters: vec![],
+            metadata,
+        };
+
+    let mut state = genesis_state.checkpoint().to_working_set_unmetered();
+    bank.call(
+        create_token("Sovereign", Some(metadata.clone())),
+        &sender_context,
+        &mut state,
+    )
+    .expect("Failed to create token");
+    bank.call(create_token("Plain", None), &sender_context, &mut state)
+        .expect("Failed to create token");
+
+    let (mut state, _, _) = state.checkpoint();
+
+    let token_id = get_token_id::<S>("Sovereign", &sender_address, 1);
+    assert_eq!(
+        Some(metadata),
+        bank.get_token_metadata(&token_id, &mut state)?
+    );
+
+    let plain_token_id = get_token_id::<S>("Plain", &sender_address, 1);
+    assert!(bank.get_token_name(&plain_token_id, &mut state)?.is_some());
+    assert_eq!(None, bank.get_token_metadata(&plain_token_id, &mut state)?);
+
+    assert_eq!(
+        Some(gas_token_metadata),
+        bank.get_token_metadata(&GAS_TOKEN_ID, &mut state)?
+    );
+
+    Ok(())
+}
@@ -30,8 +30,11 @@ fn freeze_token() -> Result<(), Infallible> {
             token_name: token_name.clone(),
             authorized_minters: vec![minter],
             address_and_balances: vec![(minter, initial_balance)],
+            metadata: None,
         },
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     };
 
     let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
@@ -78,6 +81,7 @@ fn freeze_token() -> Result<(), Infallible> {
         initial_balance,
         mint_to_address: minter,
         authorized_minters: vec![minter],
+        metadata: None,
     };
     let _minted = bank
         .call(mint_message, &minter_context, &mut state)
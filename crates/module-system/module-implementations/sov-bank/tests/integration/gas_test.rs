@@ -127,8 +127,11 @@ impl BankGasTestCase {
                 token_name: base_token_name.to_string(),
                 address_and_balances,
                 authorized_minters: vec![],
+                metadata: None,
             },
             tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: None,
         };
 
         // create a context using the generated account as sender
@@ -167,6 +170,7 @@ impl BankGasTestCase {
             initial_balance,
             mint_to_address: minter,
             authorized_minters: vec![minter],
+            metadata: None,
         };
 
         let tx: AuthenticatedTransactionData<S> = AuthenticatedTransactionData {
@@ -174,6 +178,7 @@ impl BankGasTestCase {
             chain_id: config_value!("CHAIN_ID"),
             max_priority_fee_bips: PriorityFeeBips::ZERO,
             gas_limit: None,
+            valid_until_slot: None,
         };
 
         let state = state.to_working_set_deprecated(&tx, &gas_price);
@@ -194,6 +199,7 @@ impl BankGasTestCase {
             burn: Gas::zero(),
             mint: Gas::zero(),
             freeze: Gas::zero(),
+            approve: Gas::zero(),
         });
         self
     }
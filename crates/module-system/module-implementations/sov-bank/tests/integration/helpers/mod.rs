@@ -29,7 +29,10 @@ pub fn create_bank_config_with_token(
             token_name,
             address_and_balances,
             authorized_minters: vec![],
+            metadata: None,
         },
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     }
 }
@@ -0,0 +1,208 @@
+use std::convert::Infallible;
+
+use sov_bank::{
+    Bank, BankConfig, CallMessage, Coins, GasTokenConfig, TokenId, TokenMetadata, GAS_TOKEN_ID,
+};
+use sov_modules_api::prelude::axum::body::Body;
+use sov_modules_api::prelude::axum::http::{Request, StatusCode};
+use sov_modules_api::prelude::{axum, serde_json};
+use sov_modules_api::rest::HasRestApi;
+use sov_modules_api::{Context, Module, Spec, StateCheckpoint, WorkingSet};
+use sov_prover_storage_manager::{new_orphan_storage, SimpleStorageManager};
+use sov_state::Storage;
+use tower::ServiceExt;
+
+use crate::helpers::generate_address;
+
+type S = sov_test_utils::TestSpec;
+
+fn bank_config(minter: <S as Spec>::Address, initial_balance: u64) -> BankConfig<S> {
+    BankConfig::<S> {
+        gas_token_config: GasTokenConfig {
+            token_name: "Token1".to_owned(),
+            address_and_balances: vec![(minter, initial_balance)],
+            authorized_minters: vec![minter],
+            metadata: Some(TokenMetadata {
+                symbol: "TK1".to_owned(),
+                decimals: 2,
+            }),
+        },
+        tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
+    }
+}
+
+#[test]
+fn holder_count_changes_when_balances_cross_zero() -> Result<(), Infallible> {
+    let bank = Bank::<S>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let state = StateCheckpoint::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let minter = generate_address("minter");
+    let receiver = generate_address("receiver");
+    let sequencer_address = generate_address("sequencer");
+    let minter_context = Context::<S>::new(minter, Default::default(), sequencer_address, 1);
+    let receiver_context = Context::<S>::new(receiver, Default::default(), sequencer_address, 1);
+
+    let initial_balance = 100;
+    let token_id = GAS_TOKEN_ID;
+    let bank_config = bank_config(minter, initial_balance);
+
+    let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
+    bank.genesis(&bank_config, &mut genesis_state).unwrap();
+    let mut state = genesis_state.checkpoint().to_working_set_unmetered();
+
+    let query_holder_count = |state: &mut WorkingSet<S>| -> Result<Option<u64>, Infallible> {
+        bank.get_holder_count(&token_id, &mut state.to_unmetered())
+    };
+    let query_total_supply = |state: &mut WorkingSet<S>| -> Result<Option<u64>, Infallible> {
+        bank.get_total_supply_of(&token_id, &mut state.to_unmetered())
+    };
+    let transfer = |to: <S as Spec>::Address, amount: u64| CallMessage::<S>::Transfer {
+        to,
+        coins: Coins { amount, token_id },
+    };
+
+    assert_eq!(Some(1), query_holder_count(&mut state)?);
+    assert_eq!(Some(initial_balance), query_total_supply(&mut state)?);
+
+    // Transferring zero tokens doesn't create a new holder.
+    bank.call(transfer(receiver, 0), &minter_context, &mut state)
+        .expect("Failed to transfer token");
+    assert_eq!(Some(1), query_holder_count(&mut state)?);
+
+    // The receiver's balance goes from zero to non-zero.
+    bank.call(transfer(receiver, 40), &minter_context, &mut state)
+        .expect("Failed to transfer token");
+    assert_eq!(Some(2), query_holder_count(&mut state)?);
+
+    // Both balances stay non-zero.
+    bank.call(transfer(receiver, 10), &minter_context, &mut state)
+        .expect("Failed to transfer token");
+    assert_eq!(Some(2), query_holder_count(&mut state)?);
+
+    // The receiver's balance goes back to zero.
+    bank.call(transfer(minter, 50), &receiver_context, &mut state)
+        .expect("Failed to transfer token");
+    assert_eq!(Some(1), query_holder_count(&mut state)?);
+    assert_eq!(Some(initial_balance), query_total_supply(&mut state)?);
+
+    // Minting to the receiver makes it a holder again and increases the supply.
+    let mint_amount = 25;
+    let mint_message = CallMessage::<S>::Mint {
+        coins: Coins {
+            amount: mint_amount,
+            token_id,
+        },
+        mint_to_address: receiver,
+    };
+    bank.call(mint_message, &minter_context, &mut state)
+        .expect("Failed to mint token");
+    assert_eq!(Some(2), query_holder_count(&mut state)?);
+    assert_eq!(
+        Some(initial_balance + mint_amount),
+        query_total_supply(&mut state)?
+    );
+
+    // Burning part of the minted tokens only decreases the supply.
+    let burn = |amount: u64| CallMessage::<S>::Burn {
+        coins: Coins { amount, token_id },
+    };
+    bank.call(burn(5), &receiver_context, &mut state)
+        .expect("Failed to burn token");
+    assert_eq!(Some(2), query_holder_count(&mut state)?);
+    assert_eq!(
+        Some(initial_balance + mint_amount - 5),
+        query_total_supply(&mut state)?
+    );
+
+    // Burning the whole balance removes the holder.
+    bank.call(burn(mint_amount - 5), &receiver_context, &mut state)
+        .expect("Failed to burn token");
+    assert_eq!(Some(1), query_holder_count(&mut state)?);
+    assert_eq!(Some(initial_balance), query_total_supply(&mut state)?);
+
+    // Unknown tokens have no holder count.
+    let unknown_token_id = TokenId::from([1; 32]);
+    assert_eq!(
+        None,
+        bank.get_holder_count(&unknown_token_id, &mut state.to_unmetered())?
+    );
+
+    Ok(())
+}
+
+async fn get(router: axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = router
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn token_aggregates_rest_api() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleStorageManager::new(tmpdir.path());
+    let storage = storage_manager.create_storage();
+    let bank = Bank::<S>::default();
+
+    let initial_balance = 100;
+    let bank_config = bank_config(generate_address("minter"), initial_balance);
+
+    let mut genesis_state = StateCheckpoint::<S>::new(storage.clone())
+        .to_genesis_state_accessor::<Bank<S>>(&bank_config);
+    bank.genesis(&bank_config, &mut genesis_state).unwrap();
+    let (cache_log, _, witness) = genesis_state.checkpoint().freeze();
+    let (_, change_set) = storage
+        .validate_and_materialize(cache_log, &witness)
+        .expect("Native jmt validation should succeed");
+    storage_manager.commit(change_set);
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = bank.rest_api(receiver);
+
+    let (status, body) = get(
+        router.clone(),
+        &format!("/tokens/{}/total-supply", GAS_TOKEN_ID),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"], initial_balance);
+
+    let (status, body) = get(
+        router.clone(),
+        &format!("/tokens/{}/holder-count", GAS_TOKEN_ID),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"], 1);
+
+    let (status, body) = get(
+        router.clone(),
+        &format!("/tokens/{}/metadata", GAS_TOKEN_ID),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body["data"],
+        serde_json::json!({ "name": "Token1", "symbol": "TK1", "decimals": 2 })
+    );
+
+    let unknown_token_id = TokenId::from([1; 32]);
+    let (status, _) = get(
+        router.clone(),
+        &format!("/tokens/{}/holder-count", unknown_token_id),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = get(router, &format!("/tokens/{}/metadata", unknown_token_id)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -1,3 +1,4 @@
+mod allowance_test;
 mod archival_query_test;
 mod burn_test;
 mod capability_test;
@@ -5,5 +6,8 @@ mod create_token_test;
 mod freeze_test;
 mod gas_test;
 mod helpers;
+mod holder_count_test;
+mod max_tokens_test;
 mod mint_test;
+mod transfer_freeze_test;
 mod transfer_test;
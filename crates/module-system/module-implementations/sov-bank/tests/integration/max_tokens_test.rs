@@ -0,0 +1,204 @@
+use std::convert::Infallible;
+
+use sov_bank::{get_token_id, Bank, BankConfig, CallMessage, Coins, TokenId};
+use sov_modules_api::{Context, Error, Module, Spec, StateCheckpoint, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::helpers::*;
+
+type S = sov_test_utils::TestSpec;
+
+const MAX_TOKENS_PER_ACCOUNT: u64 = 2;
+
+fn setup(bank_config: &BankConfig<S>, path: &std::path::Path) -> (Bank<S>, WorkingSet<S>) {
+    let state = StateCheckpoint::new(new_orphan_storage(path).unwrap());
+    let bank = Bank::default();
+    let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(bank_config);
+    bank.genesis(bank_config, &mut genesis_state).unwrap();
+    (bank, genesis_state.checkpoint().to_working_set_unmetered())
+}
+
+/// Creates a token named `token_name` on behalf of `context.sender()` and mints its
+/// whole initial balance to the sender.
+fn create_token(
+    bank: &Bank<S>,
+    token_name: &str,
+    context: &Context<S>,
+    state: &mut WorkingSet<S>,
+) -> TokenId {
+    let salt = 0;
+    let sender = *context.sender();
+    bank.call(
+        CallMessage::CreateToken {
+            salt,
+            token_name: token_name.to_owned(),
+            initial_balance: 100,
+            mint_to_address: sender,
+            authorized_minters: vec![sender],
+            metadata: None,
+        },
+        context,
+        state,
+    )
+    .expect("Failed to create token");
+    get_token_id::<S>(token_name, &sender, salt)
+}
+
+fn transfer(to: <S as Spec>::Address, token_id: TokenId, amount: u64) -> CallMessage<S> {
+    CallMessage::Transfer {
+        to,
+        coins: Coins { amount, token_id },
+    }
+}
+
+#[test]
+fn transfers_within_the_cap_are_accepted() -> Result<(), Infallible> {
+    let mut bank_config = create_bank_config_with_token(2, 100);
+    bank_config.max_tokens_per_account = Some(MAX_TOKENS_PER_ACCOUNT);
+    let sender = bank_config.gas_token_config.address_and_balances[0].0;
+    let receiver = bank_config.gas_token_config.address_and_balances[1].0;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (bank, mut state) = setup(&bank_config, tmpdir.path());
+
+    let sender_context = Context::<S>::new(sender, Default::default(), sender, 1);
+    let first_token = create_token(&bank, "FirstToken", &sender_context, &mut state);
+    let second_token = create_token(&bank, "SecondToken", &sender_context, &mut state);
+
+    // The gas token held by the receiver doesn't count towards the cap
+    for token_id in [first_token, second_token] {
+        bank.call(
+            transfer(receiver, token_id, 10),
+            &sender_context,
+            &mut state,
+        )
+        .expect("Transfer within the cap failed");
+        assert_eq!(
+            bank.get_balance_of(&receiver, token_id, &mut state.to_unmetered())?,
+            Some(10)
+        );
+    }
+
+    // Receiving more of a token that is already held doesn't count either
+    bank.call(
+        transfer(receiver, first_token, 10),
+        &sender_context,
+        &mut state,
+    )
+    .expect("Transfer of an already held token failed");
+    assert_eq!(
+        bank.get_balance_of(&receiver, first_token, &mut state.to_unmetered())?,
+        Some(20)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn transfers_over_the_cap_are_rejected() -> Result<(), Infallible> {
+    let mut bank_config = create_bank_config_with_token(3, 100);
+    bank_config.max_tokens_per_account = Some(MAX_TOKENS_PER_ACCOUNT);
+    let sender = bank_config.gas_token_config.address_and_balances[0].0;
+    let issuer = bank_config.gas_token_config.address_and_balances[1].0;
+    let receiver = bank_config.gas_token_config.address_and_balances[2].0;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (bank, mut state) = setup(&bank_config, tmpdir.path());
+
+    let sender_context = Context::<S>::new(sender, Default::default(), sender, 1);
+    let issuer_context = Context::<S>::new(issuer, Default::default(), sender, 1);
+    let receiver_context = Context::<S>::new(receiver, Default::default(), sender, 1);
+    let first_token = create_token(&bank, "FirstToken", &sender_context, &mut state);
+    let second_token = create_token(&bank, "SecondToken", &sender_context, &mut state);
+    let third_token = create_token(&bank, "ThirdToken", &issuer_context, &mut state);
+
+    for token_id in [first_token, second_token] {
+        bank.call(
+            transfer(receiver, token_id, 10),
+            &sender_context,
+            &mut state,
+        )
+        .expect("Transfer within the cap failed");
+    }
+
+    let transferred = bank.call(
+        transfer(receiver, third_token, 10),
+        &issuer_context,
+        &mut state,
+    );
+    let Error::ModuleError(err) = transferred.expect_err("Transfer over the cap should fail");
+    assert_eq!(
+        err.chain().map(|err| err.to_string()).collect::<Vec<_>>(),
+        vec![
+            format!(
+                "Failed transfer from={} to={} of coins({})",
+                issuer,
+                receiver,
+                Coins {
+                    amount: 10,
+                    token_id: third_token,
+                }
+            ),
+            format!(
+                "Account {} can't hold more than {} distinct tokens, failed to receive token id={}",
+                receiver, MAX_TOKENS_PER_ACCOUNT, third_token
+            ),
+        ]
+    );
+
+    // The cap is checked before any balance is updated
+    {
+        let mut unmetered = state.to_unmetered();
+        assert_eq!(
+            bank.get_balance_of(&issuer, third_token, &mut unmetered)?,
+            Some(100)
+        );
+        assert_eq!(
+            bank.get_balance_of(&receiver, third_token, &mut unmetered)?,
+            None
+        );
+    }
+
+    // Creating a token can't push its first holder over the cap either
+    let created = bank.call(
+        CallMessage::CreateToken {
+            salt: 0,
+            token_name: "FourthToken".to_owned(),
+            initial_balance: 100,
+            mint_to_address: receiver,
+            authorized_minters: vec![sender],
+            metadata: None,
+        },
+        &sender_context,
+        &mut state,
+    );
+    assert!(created.is_err());
+    let fourth_token = get_token_id::<S>("FourthToken", &sender, 0);
+    assert_eq!(
+        bank.get_balance_of(&receiver, fourth_token, &mut state.to_unmetered())?,
+        None
+    );
+
+    // Once the receiver stops holding a token, it can receive a new one
+    bank.call(
+        transfer(sender, first_token, 10),
+        &receiver_context,
+        &mut state,
+    )
+    .expect("Transfer back to the sender failed");
+    bank.call(
+        transfer(receiver, third_token, 10),
+        &issuer_context,
+        &mut state,
+    )
+    .expect("Transfer within the cap failed");
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&receiver, first_token, &mut unmetered)?,
+        Some(0)
+    );
+    assert_eq!(
+        bank.get_balance_of(&receiver, third_token, &mut unmetered)?,
+        Some(10)
+    );
+
+    Ok(())
+}
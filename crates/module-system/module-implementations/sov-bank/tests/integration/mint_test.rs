@@ -31,9 +31,12 @@ fn mint_token() -> Result<(), Infallible> {
             token_name: token_name.clone(),
             address_and_balances: vec![(minter, initial_balance)],
             authorized_minters: vec![minter],
+            metadata: None,
         },
 
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     };
 
     let mut genesis = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
@@ -135,6 +138,7 @@ fn mint_token() -> Result<(), Infallible> {
         initial_balance,
         mint_to_address: minter,
         authorized_minters: vec![authorized_minter_address_1, authorized_minter_address_2],
+        metadata: None,
     };
     let _minted = bank
         .call(mint_message, &minter_context, &mut state)
@@ -328,6 +332,7 @@ fn mint_token_from_module_and_address() -> Result<(), Infallible> {
             initial_balance,
             mod_minter,
             vec![mod_minter, addr_minter],
+            None,
             sender_context.sender(),
             &mut state,
         )
@@ -407,6 +412,7 @@ fn create_token_from_module() -> Result<(), Infallible> {
             initial_balance,
             addr_minter,
             vec![addr_minter],
+            None,
             mod_originator,
             &mut state,
         )
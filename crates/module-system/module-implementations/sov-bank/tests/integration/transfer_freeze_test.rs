@@ -0,0 +1,348 @@
+use std::convert::Infallible;
+
+use sov_bank::event::Event;
+use sov_bank::utils::TokenHolder;
+use sov_bank::{Bank, BankConfig, CallMessage, Coins, GAS_TOKEN_ID};
+use sov_modules_api::{Context, Error, Module, StateCheckpoint, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::helpers::*;
+
+type S = sov_test_utils::TestSpec;
+
+fn setup(bank_config: &BankConfig<S>, path: &std::path::Path) -> (Bank<S>, WorkingSet<S>) {
+    let state = StateCheckpoint::new(new_orphan_storage(path).unwrap());
+    let bank = Bank::default();
+    let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(bank_config);
+    bank.genesis(bank_config, &mut genesis_state).unwrap();
+    (bank, genesis_state.checkpoint().to_working_set_unmetered())
+}
+
+fn transfer(to: <S as sov_modules_api::Spec>::Address, amount: u64) -> CallMessage<S> {
+    CallMessage::Transfer {
+        to,
+        coins: Coins {
+            amount,
+            token_id: GAS_TOKEN_ID,
+        },
+    }
+}
+
+fn error_chain(result: Result<sov_modules_api::CallResponse, Error>) -> Vec<String> {
+    let Error::ModuleError(err) = result.expect_err("The call should have failed");
+    err.chain().map(|err| err.to_string()).collect()
+}
+
+fn last_event(state: &WorkingSet<S>) -> &Event<S> {
+    state
+        .events()
+        .last()
+        .unwrap()
+        .downcast_ref::<Event<S>>()
+        .unwrap()
+}
+
+#[test]
+fn frozen_token_transfers_are_rejected() -> Result<(), Infallible> {
+    let initial_balance = 100;
+    let mut bank_config = create_bank_config_with_token(3, initial_balance);
+    let admin = bank_config.gas_token_config.address_and_balances[0].0;
+    let sender = bank_config.gas_token_config.address_and_balances[1].0;
+    let receiver = bank_config.gas_token_config.address_and_balances[2].0;
+    bank_config.freeze_admin = Some(admin);
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (bank, mut state) = setup(&bank_config, tmpdir.path());
+
+    let admin_context = Context::<S>::new(admin, Default::default(), admin, 1);
+    let sender_context = Context::<S>::new(sender, Default::default(), admin, 1);
+
+    // Only the freeze admin can freeze the transfers of a token
+    let freeze = bank.call(
+        CallMessage::FreezeToken {
+            token_id: GAS_TOKEN_ID,
+        },
+        &sender_context,
+        &mut state,
+    );
+    assert_eq!(
+        error_chain(freeze),
+        vec![
+            format!(
+                "Failed to freeze transfers of token_id={} by sender {}",
+                GAS_TOKEN_ID, sender
+            ),
+            format!("Sender {} is not the freeze admin", sender),
+        ]
+    );
+
+    bank.call(
+        CallMessage::FreezeToken {
+            token_id: GAS_TOKEN_ID,
+        },
+        &admin_context,
+        &mut state,
+    )
+    .expect("Failed to freeze the transfers of the token");
+    assert_eq!(
+        last_event(&state),
+        &Event::TokenTransfersFrozen {
+            freezer: TokenHolder::User(admin),
+            token_id: GAS_TOKEN_ID,
+        }
+    );
+
+    let transferred = bank.call(transfer(receiver, 10), &sender_context, &mut state);
+    assert_eq!(
+        error_chain(transferred),
+        vec![
+            format!(
+                "Failed transfer from={} to={} of coins({})",
+                sender,
+                receiver,
+                Coins {
+                    amount: 10,
+                    token_id: GAS_TOKEN_ID,
+                }
+            ),
+            format!("Transfers of token {} are frozen", GAS_TOKEN_ID),
+        ]
+    );
+    assert_eq!(
+        bank.get_balance_of(&sender, GAS_TOKEN_ID, &mut state.to_unmetered())?,
+        Some(initial_balance)
+    );
+
+    // Unfreezing restores the transfers
+    bank.call(
+        CallMessage::UnfreezeToken {
+            token_id: GAS_TOKEN_ID,
+        },
+        &admin_context,
+        &mut state,
+    )
+    .expect("Failed to unfreeze the transfers of the token");
+    assert_eq!(
+        last_event(&state),
+        &Event::TokenTransfersUnfrozen {
+            freezer: TokenHolder::User(admin),
+            token_id: GAS_TOKEN_ID,
+        }
+    );
+    assert!(bank
+        .call(
+            CallMessage::UnfreezeToken {
+                token_id: GAS_TOKEN_ID,
+            },
+            &admin_context,
+            &mut state,
+        )
+        .is_err());
+
+    bank.call(transfer(receiver, 10), &sender_context, &mut state)
+        .expect("Transfer of an unfrozen token failed");
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&sender, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance - 10)
+    );
+    assert_eq!(
+        bank.get_balance_of(&receiver, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance + 10)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frozen_account_transfers_are_rejected() -> Result<(), Infallible> {
+    let initial_balance = 100;
+    let mut bank_config = create_bank_config_with_token(4, initial_balance);
+    let admin = bank_config.gas_token_config.address_and_balances[0].0;
+    let frozen = bank_config.gas_token_config.address_and_balances[1].0;
+    let spender = bank_config.gas_token_config.address_and_balances[2].0;
+    let other = bank_config.gas_token_config.address_and_balances[3].0;
+    bank_config.freeze_admin = Some(admin);
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (bank, mut state) = setup(&bank_config, tmpdir.path());
+
+    let admin_context = Context::<S>::new(admin, Default::default(), admin, 1);
+    let frozen_context = Context::<S>::new(frozen, Default::default(), admin, 1);
+    let spender_context = Context::<S>::new(spender, Default::default(), admin, 1);
+    let other_context = Context::<S>::new(other, Default::default(), admin, 1);
+
+    bank.call(
+        CallMessage::Approve {
+            spender,
+            token_id: GAS_TOKEN_ID,
+            amount: 50,
+        },
+        &frozen_context,
+        &mut state,
+    )
+    .expect("Approve call failed");
+
+    let freeze_account = CallMessage::FreezeAccount {
+        address: frozen,
+        token_id: GAS_TOKEN_ID,
+    };
+    bank.call(freeze_account.clone(), &admin_context, &mut state)
+        .expect("Failed to freeze the account");
+    assert_eq!(
+        last_event(&state),
+        &Event::AccountFrozen {
+            freezer: TokenHolder::User(admin),
+            account: TokenHolder::User(frozen),
+            token_id: GAS_TOKEN_ID,
+        }
+    );
+    assert_eq!(
+        error_chain(bank.call(freeze_account, &admin_context, &mut state)),
+        vec![
+            format!(
+                "Failed to freeze account {} for token_id={} by sender {}",
+                frozen, GAS_TOKEN_ID, admin
+            ),
+            format!(
+                "Account {} is already frozen for token {}",
+                frozen, bank_config.gas_token_config.token_name
+            ),
+        ]
+    );
+
+    let frozen_account_error = format!("Account {} is frozen for token {}", frozen, GAS_TOKEN_ID);
+    // From the frozen account
+    let transferred = bank.call(transfer(other, 10), &frozen_context, &mut state);
+    assert_eq!(error_chain(transferred)[1], frozen_account_error);
+    // To the frozen account
+    let transferred = bank.call(transfer(frozen, 10), &other_context, &mut state);
+    assert_eq!(error_chain(transferred)[1], frozen_account_error);
+    // On behalf of the frozen account
+    let transfer_from_frozen = CallMessage::TransferFrom {
+        from: frozen,
+        to: other,
+        coins: Coins {
+            amount: 10,
+            token_id: GAS_TOKEN_ID,
+        },
+    };
+    let transferred = bank.call(transfer_from_frozen.clone(), &spender_context, &mut state);
+    assert_eq!(error_chain(transferred)[1], frozen_account_error);
+
+    // The other accounts are not affected
+    bank.call(transfer(spender, 10), &other_context, &mut state)
+        .expect("Transfer between unfrozen accounts failed");
+
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&frozen, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance)
+    );
+    assert_eq!(
+        bank.get_allowance(&frozen, &spender, GAS_TOKEN_ID, &mut unmetered)?,
+        50
+    );
+
+    // Unfreezing restores the transfers
+    bank.call(
+        CallMessage::UnfreezeAccount {
+            address: frozen,
+            token_id: GAS_TOKEN_ID,
+        },
+        &admin_context,
+        &mut state,
+    )
+    .expect("Failed to unfreeze the account");
+    assert_eq!(
+        last_event(&state),
+        &Event::AccountUnfrozen {
+            freezer: TokenHolder::User(admin),
+            account: TokenHolder::User(frozen),
+            token_id: GAS_TOKEN_ID,
+        }
+    );
+
+    bank.call(transfer(other, 10), &frozen_context, &mut state)
+        .expect("Transfer from an unfrozen account failed");
+    bank.call(transfer_from_frozen, &spender_context, &mut state)
+        .expect("TransferFrom an unfrozen account failed");
+
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&frozen, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance - 20)
+    );
+    assert_eq!(
+        bank.get_balance_of(&other, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance + 10)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frozen_transfers_are_only_allowed_for_gas_and_bonds() -> Result<(), Infallible> {
+    let initial_balance = 100;
+    let mut bank_config = create_bank_config_with_token(3, initial_balance);
+    let admin = bank_config.gas_token_config.address_and_balances[0].0;
+    let sender = bank_config.gas_token_config.address_and_balances[1].0;
+    let receiver = bank_config.gas_token_config.address_and_balances[2].0;
+    bank_config.freeze_admin = Some(admin);
+    bank_config.gas_token_config.authorized_minters = vec![sender];
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (bank, mut state) = setup(&bank_config, tmpdir.path());
+
+    let admin_context = Context::<S>::new(admin, Default::default(), admin, 1);
+    let sender_context = Context::<S>::new(sender, Default::default(), admin, 1);
+
+    // Being an authorized minter of the token doesn't allow freezing its transfers
+    let freeze = bank.call(
+        CallMessage::FreezeAccount {
+            address: receiver,
+            token_id: GAS_TOKEN_ID,
+        },
+        &sender_context,
+        &mut state,
+    );
+    assert_eq!(
+        error_chain(freeze)[1],
+        format!("Sender {} is not the freeze admin", sender)
+    );
+
+    bank.call(
+        CallMessage::FreezeAccount {
+            address: sender,
+            token_id: GAS_TOKEN_ID,
+        },
+        &admin_context,
+        &mut state,
+    )
+    .expect("Failed to freeze the account");
+
+    // Transfers requested by other modules are frozen as well
+    let coins = Coins {
+        amount: 10,
+        token_id: GAS_TOKEN_ID,
+    };
+    let err = bank
+        .transfer_from(&sender, &receiver, coins.clone(), &mut state)
+        .expect_err("The transfer from a frozen account should have failed");
+    assert_eq!(
+        err.chain().map(|err| err.to_string()).collect::<Vec<_>>()[1],
+        format!("Account {} is frozen for token {}", sender, GAS_TOKEN_ID)
+    );
+
+    // Gas payments and bonds ignore the freeze, so that it can't halt the rollup
+    bank.transfer_from_ignoring_freeze(&sender, &receiver, coins, &mut state)
+        .expect("Transfers ignoring the freeze should succeed");
+    let mut unmetered = state.to_unmetered();
+    assert_eq!(
+        bank.get_balance_of(&sender, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance - 10)
+    );
+    assert_eq!(
+        bank.get_balance_of(&receiver, GAS_TOKEN_ID, &mut unmetered)?,
+        Some(initial_balance + 10)
+    );
+
+    Ok(())
+}
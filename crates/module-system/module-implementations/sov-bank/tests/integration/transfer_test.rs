@@ -263,8 +263,11 @@ fn transfer_deployed_token() -> Result<(), Infallible> {
             token_name: token_name.clone(),
             authorized_minters: vec![sender_address],
             address_and_balances: vec![(sender_address, initial_balance)],
+            metadata: None,
         },
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     };
     let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&bank_config);
     bank.genesis(&bank_config, &mut genesis_state).unwrap();
@@ -2,16 +2,16 @@ use std::cmp::Ordering;
 
 use borsh::BorshDeserialize;
 use sov_modules_api::prelude::UnwrapInfallible;
-use sov_modules_api::runtime::capabilities::BlobSelector;
+use sov_modules_api::runtime::capabilities::{BlobSelection, BlobSelector};
 use sov_modules_api::{
-    Batch, BlobData, BlobDataWithId, BlobReaderTrait, DaSpec, KernelWorkingSet, Spec,
-    StateCheckpoint,
+    Batch, BlobData, BlobDataWithId, BlobOutcome, BlobReaderTrait, BlobResult, DaSpec,
+    KernelWorkingSet, MalformedBlob, Spec, StateCheckpoint, MAX_BLOB_DATA_SIZE,
 };
-use sov_sequencer_registry::AllowedSequencerError;
+use sov_sequencer_registry::{AllowedSequencerError, SlashingReason};
 use tracing::{error, info, warn};
 
 use crate::{
-    BlobStorage, PreferredBlobData, PreferredBlobDataWithId, SequenceNumber, DEFERRED_SLOTS_COUNT,
+    BlobStorage, PreferredBlobData, PreferredBlobDataWithId, SequenceNumber,
     UNREGISTERED_BLOBS_PER_SLOT,
 };
 
@@ -20,12 +20,29 @@ use crate::{
 enum BlobDiscardReason {
     /// More complicated case for preferred sequencer. Ping @prestonevans__ at Twitter for more info
     SequenceNumberTooLow,
+    /// The sequence number of a sequenced batch is further ahead than the gap tolerance allows
+    SequenceNumberTooHigh,
     /// Sender doesn't have enough staked sequencer funds
     SenderInsufficientStake,
     /// The max amount of unregistered blobs allowed to be processed per slot
     MaxAllowedUnregisteredBlobs,
 }
 
+impl std::fmt::Display for BlobDiscardReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlobDiscardReason::SequenceNumberTooLow => write!(f, "sequence number too low"),
+            BlobDiscardReason::SequenceNumberTooHigh => write!(f, "sequence number too high"),
+            BlobDiscardReason::SenderInsufficientStake => {
+                write!(f, "sender has insufficient stake")
+            }
+            BlobDiscardReason::MaxAllowedUnregisteredBlobs => {
+                write!(f, "too many blobs from unregistered senders in this slot")
+            }
+        }
+    }
+}
+
 enum SequencerStatus {
     Registered,
     Unregistered,
@@ -39,10 +56,13 @@ enum ValidateBlobOutcome {
 impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
     /// Select the blobs to execute this slot using "based sequencing". In this mode,
     /// blobs are processed in the order that they appear on the DA layer.
+    ///
+    /// The outcome of the blobs which are not selected is appended to `unselected`.
     pub fn select_blobs_as_based_sequencer<'a, 'k, I>(
         &self,
         current_blobs: I,
         state: &mut KernelWorkingSet<'k, S>,
+        unselected: &mut Vec<BlobResult>,
     ) -> Vec<(BlobDataWithId, Da::Address)>
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
@@ -51,13 +71,14 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         self.chain_state
             .set_next_visible_slot_number(&(state.current_slot().saturating_add(1)), state)
             .unwrap_infallible();
-        self.select_blobs_da_ordering(current_blobs, state)
+        self.select_blobs_da_ordering(current_blobs, state, unselected)
     }
 
     fn select_blobs_da_ordering<'a, 'k, I>(
         &self,
         current_blobs: I,
         state: &mut KernelWorkingSet<'k, S>,
+        unselected: &mut Vec<BlobResult>,
     ) -> Vec<(BlobDataWithId, Da::Address)>
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
@@ -67,7 +88,7 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         for (idx, blob) in current_blobs.into_iter().enumerate() {
             tracing::trace!("processing blob {}", idx);
             match self.validate_blob_and_sender(blob, unregistered_blobs, state) {
-                ValidateBlobOutcome::Discard(reason) => self.log_discarded_blob(blob, reason),
+                ValidateBlobOutcome::Discard(reason) => self.discard_blob(blob, reason, unselected),
                 ValidateBlobOutcome::Accept(sequencer_status) => {
                     let from_registered_sequencer =
                         matches!(sequencer_status, SequencerStatus::Registered);
@@ -77,30 +98,36 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
                     }
                     tracing::trace!(%from_registered_sequencer);
 
-                    if let Some(mut data) = self.deserialize_or_try_slash_sender::<BlobData>(
-                        blob,
-                        from_registered_sequencer,
-                        state.inner,
-                    ) {
+                    if let Some(mut data) =
+                        self.deserialize_blob_data(blob, from_registered_sequencer, state.inner)
+                    {
                         tracing::trace!(
                             "Successfully deserialized blob {} ({}). Adding to batch.",
                             idx,
                             hex::encode(blob.hash()),
                         );
                         if !from_registered_sequencer {
-                            if let BlobData::Batch(ref mut batch) = data {
+                            if let Some(
+                                BlobData::Batch(ref mut batch)
+                                | BlobData::SequencedBatch { ref mut batch, .. },
+                            ) = data
+                            {
                                 self.process_unregistered_batch(blob, batch);
                             }
                         }
 
-                        batches.push((
+                        batches.extend(self.enforce_sequenced_blob_ordering(
                             BlobDataWithId {
                                 data,
                                 id: blob.hash(),
                                 from_registered_sequencer,
                             },
-                            blob.sender(),
+                            blob,
+                            state,
+                            unselected,
                         ));
+                    } else {
+                        unselected.push(malformed_blob_result(blob));
                     }
                 }
             };
@@ -147,13 +174,24 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         }
     }
 
-    fn log_discarded_blob(&self, blob: &Da::BlobTransaction, reason: BlobDiscardReason) {
+    fn discard_blob(
+        &self,
+        blob: &Da::BlobTransaction,
+        reason: BlobDiscardReason,
+        unselected: &mut Vec<BlobResult>,
+    ) {
         info!(
             blob_hash = hex::encode(blob.hash()),
             sender = hex::encode(blob.sender()),
             ?reason,
             "Discarding blob"
         );
+        unselected.push(BlobResult {
+            blob_hash: blob.hash(),
+            outcome: BlobOutcome::Ignored {
+                reason: reason.to_string(),
+            },
+        });
     }
 
     /// Enforce the ordering constraints on preferred blobs by discarding or deferring blobs that arrive
@@ -164,6 +202,7 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         next_sequence_number: SequenceNumber,
         blob: &Da::BlobTransaction,
         state: &mut StateCheckpoint<S>,
+        unselected: &mut Vec<BlobResult>,
     ) -> Option<PreferredBlobDataWithId> {
         match preferred_blob.sequence_number.cmp(&next_sequence_number) {
             Ordering::Equal => {
@@ -186,16 +225,92 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
                         state,
                     )
                     .unwrap_infallible();
+                unselected.push(deferred_blob_result(blob.hash()));
                 None
             }
             Ordering::Less => {
                 // If the sequence number is less than the expected one, we discard the blob
-                self.log_discarded_blob(blob, BlobDiscardReason::SequenceNumberTooLow);
+                self.discard_blob(blob, BlobDiscardReason::SequenceNumberTooLow, unselected);
                 None
             }
         }
     }
 
+    /// Enforce the per-sequencer ordering of [`BlobData::SequencedBatch`]es from registered sequencers.
+    ///
+    /// A sequenced batch with the next expected sequence number is returned, followed by any stored
+    /// batches with the sequence numbers that follow it. Batches that are ahead of the next sequence
+    /// number by at most the gap tolerance are stored until their predecessors arrive, and all other
+    /// sequenced batches are discarded. Any other blob is returned as is.
+    fn enforce_sequenced_blob_ordering(
+        &self,
+        blob_data: BlobDataWithId,
+        blob: &Da::BlobTransaction,
+        state: &mut KernelWorkingSet<S>,
+        unselected: &mut Vec<BlobResult>,
+    ) -> Vec<(BlobDataWithId, Da::Address)> {
+        let sender = blob.sender();
+        let Some(BlobData::SequencedBatch {
+            sequence_number,
+            ref batch,
+        }) = blob_data.data
+        else {
+            return vec![(blob_data, sender)];
+        };
+        // Unregistered senders can't be held to any ordering, so their sequence numbers are ignored.
+        if !blob_data.from_registered_sequencer {
+            return vec![(
+                BlobDataWithId {
+                    data: Some(BlobData::Batch(batch.clone())),
+                    ..blob_data
+                },
+                sender,
+            )];
+        }
+
+        let next_sequence_number = self.next_sequence_number_of(&sender, state.inner);
+        if sequence_number < next_sequence_number {
+            self.discard_blob(blob, BlobDiscardReason::SequenceNumberTooLow, unselected);
+            return Vec::new();
+        }
+        if sequence_number - next_sequence_number > self.sequence_number_gap_tolerance(state) {
+            self.discard_blob(blob, BlobDiscardReason::SequenceNumberTooHigh, unselected);
+            return Vec::new();
+        }
+        if sequence_number > next_sequence_number {
+            self.deferred_sequenced_blobs
+                .set(&(sender, sequence_number), &blob_data, state.inner)
+                .unwrap_infallible();
+            unselected.push(deferred_blob_result(blob.hash()));
+            return Vec::new();
+        }
+
+        let mut ready = vec![blob_data];
+        let mut next_sequence_number = next_sequence_number.saturating_add(1);
+        while let Some(deferred) = self
+            .deferred_sequenced_blobs
+            .remove(&(sender.clone(), next_sequence_number), state.inner)
+            .unwrap_infallible()
+        {
+            ready.push(deferred);
+            next_sequence_number = next_sequence_number.saturating_add(1);
+        }
+        self.next_sequencer_sequence_numbers
+            .set(&sender, &next_sequence_number, state.inner)
+            .unwrap_infallible();
+        tracing::debug!(
+            sender = %sender,
+            batches = ready.len(),
+            next_sequence_number,
+            "Selected sequenced batches"
+        );
+
+        ready
+            .into_iter()
+            .map(|blob_data| (blob_data, sender.clone()))
+            .collect()
+    }
+
     /// Select blobs when transitioning from a preferred sequencer back to normal operation.
     /// This occurs when the preferred sequencer was slashed for malicious behavior. In recovery mode,
     /// the rollup processes two virtual slots at a time until it catches up to the current slot, after
@@ -204,6 +319,7 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         &self,
         current_blobs: I,
         state: &mut KernelWorkingSet<'k, S>,
+        unselected: &mut Vec<BlobResult>,
     ) -> Vec<(BlobDataWithId, Da::Address)>
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
@@ -216,17 +332,23 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
             match state.current_slot().saturating_sub(state.virtual_slot()) {
                 // If the virtual slot has caught up to the current slot, we don't need any stored blobs.
                 // In this case, we act like a normal "based" rollup
-                0 => return self.select_blobs_as_based_sequencer(current_blobs, state),
+                0 => return self.select_blobs_as_based_sequencer(current_blobs, state, unselected),
                 // If the virtual slot is only trailing by one, we process one stored slot (to catch up) and
                 // then process the new blobs from this slot
                 1 => {
-                    self.select_blobs_as_based_sequencer(current_blobs, state);
+                    self.select_blobs_as_based_sequencer(current_blobs, state, unselected);
                     1
                 }
                 // Otherwise, we need to process two slots from storage  - which means that we need to save the new blobs
                 _ => {
-                    let new_batches = self.select_blobs_da_ordering(current_blobs, state);
+                    let new_batches =
+                        self.select_blobs_da_ordering(current_blobs, state, unselected);
                     self.store_batches(state.current_slot(), &new_batches, state.inner);
+                    unselected.extend(
+                        new_batches
+                            .iter()
+                            .map(|(blob, _)| deferred_blob_result(blob.id)),
+                    );
                     2
                 }
             };
@@ -260,6 +382,7 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         current_blobs: I,
         state: &mut KernelWorkingSet<'k, S>,
         preferred_sender: &Da::Address,
+        unselected: &mut Vec<BlobResult>,
     ) -> Vec<(BlobDataWithId, Da::Address)>
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
@@ -282,7 +405,7 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         for (idx, blob) in current_blobs.into_iter().enumerate() {
             tracing::trace!("Checking blob {}", idx);
             match self.validate_blob_and_sender(blob, unregistered_blobs, state) {
-                ValidateBlobOutcome::Discard(reason) => self.log_discarded_blob(blob, reason),
+                ValidateBlobOutcome::Discard(reason) => self.discard_blob(blob, reason, unselected),
                 ValidateBlobOutcome::Accept(sequencer_status) => {
                     let from_registered_sequencer =
                         matches!(sequencer_status, SequencerStatus::Registered);
@@ -293,20 +416,24 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
 
                     // Check if the blob is from the preferred sequencer
                     if &blob.sender() == preferred_sender {
-                        let maybe_batch = self
+                        let maybe_batch = match self
                             .deserialize_or_try_slash_sender::<PreferredBlobData>(
                                 blob,
                                 from_registered_sequencer,
                                 state.inner,
-                            )
-                            .and_then(|batch| {
-                                self.enforce_preferred_blob_ordering(
-                                    batch,
-                                    next_sequence_number,
-                                    blob,
-                                    state.inner,
-                                )
-                            });
+                            ) {
+                            Some(batch) => self.enforce_preferred_blob_ordering(
+                                batch,
+                                next_sequence_number,
+                                blob,
+                                state.inner,
+                                unselected,
+                            ),
+                            None => {
+                                unselected.push(malformed_blob_result(blob));
+                                None
+                            }
+                        };
 
                         // Even if we retrieved `preferred_blob`` in `step0``, we override it because it has the same `sequence_number`.
                         if let Some(next_preferred_blob) = maybe_batch {
@@ -315,26 +442,34 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
                     } else {
                         // Otherwise, the batch is from a valid sender (checked in step 1) but not the preferred sender
                         // Deserialize it as a normal batch and store it in memory
-                        let data = self.deserialize_or_try_slash_sender::<BlobData>(
+                        let data = self.deserialize_blob_data(
                             blob,
                             from_registered_sequencer,
                             state.inner,
                         );
                         if let Some(mut data) = data {
                             if !from_registered_sequencer {
-                                if let BlobData::Batch(ref mut batch) = data {
+                                if let Some(
+                                    BlobData::Batch(ref mut batch)
+                                    | BlobData::SequencedBatch { ref mut batch, .. },
+                                ) = data
+                                {
                                     self.process_unregistered_batch(blob, batch);
                                 }
                             }
 
-                            new_forced_blobs.push((
+                            new_forced_blobs.extend(self.enforce_sequenced_blob_ordering(
                                 BlobDataWithId {
                                     data,
                                     id: blob.hash(),
                                     from_registered_sequencer,
                                 },
-                                blob.sender(),
+                                blob,
+                                state,
+                                unselected,
                             ));
+                        } else {
+                            unselected.push(malformed_blob_result(blob));
                         }
                     }
                 }
@@ -356,7 +491,7 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
                 .unwrap_infallible();
 
             let first_batch = BlobDataWithId {
-                data: preferred_blob.inner.data,
+                data: Some(preferred_blob.inner.data),
                 id: preferred_blob.id,
                 // This is a preferred blob so it is from the preferred sequencer
                 // hence the sequencer is a registered one
@@ -381,7 +516,8 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
             }
         } else {
             // If there's no preferred blob, advance only if the we would otherwise exceed the maximum deferred slots count
-            if state.virtual_slot().saturating_add(DEFERRED_SLOTS_COUNT) <= state.current_slot() {
+            let deferred_slots_count = self.deferred_slots_count(state);
+            if state.virtual_slot().saturating_add(deferred_slots_count) <= state.current_slot() {
                 1
             } else {
                 0
@@ -412,6 +548,11 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
             batches_to_process.extend(new_forced_blobs);
         } else {
             self.store_batches(state.current_slot(), &new_forced_blobs, state.inner);
+            unselected.extend(
+                new_forced_blobs
+                    .iter()
+                    .map(|(blob, _)| deferred_blob_result(blob.id)),
+            );
         }
         self.chain_state
             .set_next_visible_slot_number(&next_virtual_height, state)
@@ -419,38 +560,84 @@ impl<S: Spec, Da: DaSpec> BlobStorage<S, Da> {
         batches_to_process
     }
 
+    /// Deserializes a [`BlobData`]. Blobs from unregistered senders which can't be deserialized
+    /// are kept as `Some(None)`, so that they show up as ignored in the receipts.
+    fn deserialize_blob_data(
+        &self,
+        blob: &mut Da::BlobTransaction,
+        registered_sender: bool,
+        state: &mut StateCheckpoint<S>,
+    ) -> Option<Option<BlobData>> {
+        let data = self.deserialize_or_try_slash_sender::<BlobData>(blob, registered_sender, state);
+        if data.is_none() && !registered_sender {
+            return Some(None);
+        }
+        data.map(Some)
+    }
+
     /// Deserialize a blob into a `Batch` or slash the sender if it's malformed.
     /// The sequencer might not exist if we're processing a blob submitted by an unregistered
     /// sequencer - in the case of direct sequencer registration via DA.
-    fn deserialize_or_try_slash_sender<B: BorshDeserialize>(
+    fn deserialize_or_try_slash_sender<B: BorshDeserialize + PreValidate>(
         &self,
         blob: &mut Da::BlobTransaction,
         registered_sender: bool,
         state: &mut StateCheckpoint<S>,
     ) -> Option<B> {
-        match B::try_from_slice(data_for_deserialization(blob)) {
+        // Oversized blobs are rejected without reading their data.
+        if blob.total_len() > MAX_BLOB_DATA_SIZE {
+            self.reject_malformed_blob(
+                blob,
+                registered_sender,
+                MalformedBlob::TooLarge(blob.total_len()),
+                state,
+            );
+            return None;
+        }
+
+        // The layout pre-check is cheap, and ensures that the full decoding never
+        // allocates based on lengths which aren't backed by actual data.
+        let data = data_for_deserialization(blob);
+        let result = match B::pre_validate(data) {
+            Ok(()) => B::try_from_slice(data).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
             Ok(batch) => Some(batch),
             // if the blob is malformed, slash the sequencer
             Err(e) => {
                 assert_eq!(blob.verified_data().len(), blob.total_len(), "Batch deserialization failed and some data was not provided. The prover might be malicious");
-                error!(
-                    blob_hash = hex::encode(blob.hash()),
-                    slashed_sender = %blob.sender(),
-                    error = ?e,
-                    "Unable to deserialize blob. slashing sender if they are registered"
-                );
-
-                if registered_sender {
-                    self.sequencer_registry
-                        .slash_sequencer(&blob.sender(), state);
-                } else {
-                    info!("Unable to slash sequencer, they were not registered");
-                }
-
+                self.reject_malformed_blob(blob, registered_sender, e, state);
                 None
             }
         }
     }
+
+    fn reject_malformed_blob(
+        &self,
+        blob: &Da::BlobTransaction,
+        registered_sender: bool,
+        error: impl std::fmt::Display,
+        state: &mut StateCheckpoint<S>,
+    ) {
+        error!(
+            blob_hash = hex::encode(blob.hash()),
+            slashed_sender = %blob.sender(),
+            error = %error,
+            "Unable to deserialize blob. slashing sender if they are registered"
+        );
+
+        if registered_sender {
+            self.sequencer_registry.slash_sequencer(
+                &blob.sender(),
+                SlashingReason::InvalidBatchEncoding,
+                state,
+            );
+        } else {
+            info!("Unable to slash sequencer, they were not registered");
+        }
+    }
 }
 
 impl<S: Spec, Da: DaSpec> BlobSelector<Da> for BlobStorage<S, Da> {
@@ -460,7 +647,7 @@ impl<S: Spec, Da: DaSpec> BlobSelector<Da> for BlobStorage<S, Da> {
 
     // This implementation returns three categories of blobs:
     // 1. Any blobs sent by the preferred sequencer ("prority blobs")
-    // 2. Any non-priority blobs which were sent `DEFERRED_SLOTS_COUNT` slots ago ("expiring deferred blobs")
+    // 2. Any non-priority blobs which were sent `deferred_slots_count` slots ago ("expiring deferred blobs")
     // 3. Some additional deferred blobs needed to fill the total requested by the sequencer, if applicable. ("bonus blobs")
     fn get_blobs_for_this_slot<'a, 'k, I>(
         &self,
@@ -470,24 +657,76 @@ impl<S: Spec, Da: DaSpec> BlobSelector<Da> for BlobStorage<S, Da> {
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
     {
-        // If `DEFERRED_SLOTS_COUNT` is 0, we treat the rollup as having no preferred sequencer.
-        // In this case, we just process blobs in the order that they appeared on the DA layer
-        if DEFERRED_SLOTS_COUNT == 0 {
-            return Ok(self.select_blobs_as_based_sequencer(current_blobs, state));
-        }
+        Ok(self
+            .get_blobs_for_this_slot_with_outcomes(current_blobs, state)?
+            .selected)
+    }
 
-        // If there's a preferred sequencer, sequence accordingly.
-        if let Some(preferred_sender) = self.get_preferred_sequencer(state.inner) {
-            return Ok(self.select_blobs_for_preferred_sequencer(
+    fn get_blobs_for_this_slot_with_outcomes<'a, 'k, I>(
+        &self,
+        current_blobs: I,
+        state: &mut KernelWorkingSet<'k, S>,
+    ) -> anyhow::Result<BlobSelection<Self::BlobType, Da::Address>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        let mut unselected = Vec::new();
+
+        // If the deferred slots count is 0, we treat the rollup as having no preferred sequencer.
+        // In this case, we just process blobs in the order that they appeared on the DA layer
+        let selected = if self.deferred_slots_count(state) == 0 {
+            self.select_blobs_as_based_sequencer(current_blobs, state, &mut unselected)
+        } else if let Some(preferred_sender) = self.get_preferred_sequencer(state.inner) {
+            // If there's a preferred sequencer, sequence accordingly.
+            self.select_blobs_for_preferred_sequencer(
                 current_blobs,
                 state,
                 &preferred_sender,
-            ));
-        }
+                &mut unselected,
+            )
+        } else {
+            // Otherwise, we're configured for a preferred sequencer but one doesn't exist. This usually means that the preferred sequencer was slashed.
+            // Entery recovery mode.
+            self.select_blobs_in_recovery_mode(current_blobs, state, &mut unselected)
+        };
+
+        Ok(BlobSelection {
+            selected,
+            unselected,
+        })
+    }
+}
+
+fn deferred_blob_result(blob_hash: [u8; 32]) -> BlobResult {
+    BlobResult {
+        blob_hash,
+        outcome: BlobOutcome::Deferred,
+    }
+}
+
+fn malformed_blob_result(blob: &impl BlobReaderTrait) -> BlobResult {
+    BlobResult {
+        blob_hash: blob.hash(),
+        outcome: BlobOutcome::Ignored {
+            reason: "malformed".to_string(),
+        },
+    }
+}
+
+/// A cheap layout check which runs before the full deserialization of a blob.
+trait PreValidate {
+    fn pre_validate(bytes: &[u8]) -> Result<(), MalformedBlob>;
+}
+
+impl PreValidate for BlobData {
+    fn pre_validate(bytes: &[u8]) -> Result<(), MalformedBlob> {
+        BlobData::pre_validate(bytes)
+    }
+}
 
-        // Otherwise, we're configured for a preferred sequencer but one doesn't exist. This usually means that the preferred sequencer was slashed.
-        // Entery recovery mode.
-        Ok(self.select_blobs_in_recovery_mode(current_blobs, state))
+impl PreValidate for PreferredBlobData {
+    fn pre_validate(bytes: &[u8]) -> Result<(), MalformedBlob> {
+        PreferredBlobData::pre_validate(bytes)
     }
 }
 
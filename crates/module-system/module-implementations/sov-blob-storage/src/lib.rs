@@ -8,11 +8,12 @@ use sov_modules_api::macros::config_value;
 use sov_modules_api::prelude::UnwrapInfallible;
 use sov_modules_api::{
     BlobData, BlobDataWithId, KernelModule, KernelModuleInfo, KernelStateValue, KernelWorkingSet,
-    ModuleId, StateCheckpoint, StateMap,
+    MalformedBlob, ModuleId, StateCheckpoint, StateMap, MAX_BLOB_DATA_SIZE,
 };
 use sov_state::codec::BcsCodec;
 
-/// For how many slots deferred blobs are stored before being executed
+/// For how many slots deferred blobs are stored before being executed.
+/// Can be overridden at genesis with [`BlobStorageConfig::deferred_slots_count`].
 pub const DEFERRED_SLOTS_COUNT: u64 = config_value!("DEFERRED_SLOTS_COUNT");
 
 /// How many blobs from unregistered sequencers we will accept per slot
@@ -20,7 +21,14 @@ pub const DEFERRED_SLOTS_COUNT: u64 = config_value!("DEFERRED_SLOTS_COUNT");
 /// this serves as protection against spam.
 pub const UNREGISTERED_BLOBS_PER_SLOT: u64 = config_value!("UNREGISTERED_BLOBS_PER_SLOT");
 
-/// The sequence number for a batch from the preferred sequencer.   
+/// How far ahead of the next expected sequence number a [`BlobData::SequencedBatch`] from a
+/// registered sequencer can be. Batches within this window are stored until their predecessors
+/// arrive, and batches past it are discarded.
+/// Can be overridden at genesis with [`BlobStorageConfig::sequence_number_gap_tolerance`].
+pub const SEQUENCE_NUMBER_GAP_TOLERANCE: u64 = config_value!("SEQUENCE_NUMBER_GAP_TOLERANCE");
+
+/// The sequence number for a batch from the preferred sequencer, or for a
+/// [`BlobData::SequencedBatch`] from any registered sequencer.
 pub type SequenceNumber = u64;
 
 /// Blob storage contains only address and vector of blobs
@@ -45,6 +53,24 @@ pub struct BlobStorage<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> {
     #[state]
     next_sequence_number: KernelStateValue<SequenceNumber>,
 
+    /// Any sequenced batches from registered sequencers which were received out of order,
+    /// mapped from the sender and sequence number.
+    #[state]
+    pub(crate) deferred_sequenced_blobs:
+        StateMap<(Da::Address, SequenceNumber), BlobDataWithId, BcsCodec>,
+
+    /// The next sequence number of each registered sequencer which sent sequenced batches.
+    #[state]
+    pub(crate) next_sequencer_sequence_numbers: StateMap<Da::Address, SequenceNumber, BcsCodec>,
+
+    /// The genesis override of [`SEQUENCE_NUMBER_GAP_TOLERANCE`], if any.
+    #[state]
+    sequence_number_gap_tolerance: KernelStateValue<u64>,
+
+    /// The genesis override of [`DEFERRED_SLOTS_COUNT`], if any.
+    #[state]
+    deferred_slots_count: KernelStateValue<u64>,
+
     #[module]
     pub(crate) sequencer_registry: sov_sequencer_registry::SequencerRegistry<S, Da>,
 
@@ -79,6 +105,39 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> BlobStorage<S, Da> {
             .unwrap_or_default()
     }
 
+    /// For how many slots deferred blobs are stored before being executed. The value set
+    /// at genesis in [`BlobStorageConfig::deferred_slots_count`] takes precedence over
+    /// the compile-time [`DEFERRED_SLOTS_COUNT`].
+    pub fn deferred_slots_count(&self, state: &mut KernelWorkingSet<'_, S>) -> u64 {
+        self.deferred_slots_count
+            .get(state)
+            .unwrap_infallible()
+            .unwrap_or(DEFERRED_SLOTS_COUNT)
+    }
+
+    /// How far ahead of the next expected sequence number a sequenced batch can be. The value
+    /// set at genesis in [`BlobStorageConfig::sequence_number_gap_tolerance`] takes precedence
+    /// over the compile-time [`SEQUENCE_NUMBER_GAP_TOLERANCE`].
+    pub fn sequence_number_gap_tolerance(&self, state: &mut KernelWorkingSet<'_, S>) -> u64 {
+        self.sequence_number_gap_tolerance
+            .get(state)
+            .unwrap_infallible()
+            .unwrap_or(SEQUENCE_NUMBER_GAP_TOLERANCE)
+    }
+
+    /// Returns the sequence number that the next [`BlobData::SequencedBatch`] from `sequencer`
+    /// must have to be executed.
+    pub fn next_sequence_number_of(
+        &self,
+        sequencer: &Da::Address,
+        state: &mut StateCheckpoint<S>,
+    ) -> SequenceNumber {
+        self.next_sequencer_sequence_numbers
+            .get(sequencer, state)
+            .unwrap_infallible()
+            .unwrap_or(0)
+    }
+
     pub(crate) fn get_preferred_sequencer(
         &self,
         state: &mut StateCheckpoint<S>,
@@ -89,16 +148,37 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> BlobStorage<S, Da> {
     }
 }
 
-/// Empty module implementation
+/// Initial configuration of the blob storage.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct BlobStorageConfig {
+    /// Overrides [`DEFERRED_SLOTS_COUNT`], which mostly allows tests to use a small deferral window
+    /// without recompiling. If unset, the compile-time constant is used.
+    #[serde(default)]
+    pub deferred_slots_count: Option<u64>,
+    /// Overrides [`SEQUENCE_NUMBER_GAP_TOLERANCE`]. If unset, the compile-time constant is used.
+    #[serde(default)]
+    pub sequence_number_gap_tolerance: Option<u64>,
+}
+
 impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> KernelModule for BlobStorage<S, Da> {
     type Spec = S;
-    type Config = ();
+    type Config = BlobStorageConfig;
 
     fn genesis_unchecked(
         &self,
-        _config: &Self::Config,
-        _state: &mut KernelWorkingSet<'_, Self::Spec>,
+        config: &Self::Config,
+        state: &mut KernelWorkingSet<'_, Self::Spec>,
     ) -> Result<(), sov_modules_api::Error> {
+        if let Some(deferred_slots_count) = config.deferred_slots_count {
+            self.deferred_slots_count
+                .set(&deferred_slots_count, state)
+                .unwrap_infallible();
+        }
+        if let Some(gap_tolerance) = config.sequence_number_gap_tolerance {
+            self.sequence_number_gap_tolerance
+                .set(&gap_tolerance, state)
+                .unwrap_infallible();
+        }
         Ok(())
     }
 }
@@ -115,6 +195,28 @@ pub struct PreferredBlobData {
     pub virtual_slots_to_advance: u8,
 }
 
+impl PreferredBlobData {
+    /// Cheaply checks that `bytes` is a well-formed borsh encoding of a [`PreferredBlobData`].
+    /// See [`BlobData::pre_validate`].
+    pub fn pre_validate(bytes: &[u8]) -> Result<(), MalformedBlob> {
+        if bytes.len() > MAX_BLOB_DATA_SIZE {
+            return Err(MalformedBlob::TooLarge(bytes.len()));
+        }
+
+        let (_sequence_number, rest) = bytes
+            .split_first_chunk::<8>()
+            .ok_or(MalformedBlob::Truncated)?;
+        let (_virtual_slots_to_advance, rest) = BlobData::skip_encoded(rest)?
+            .split_first()
+            .ok_or(MalformedBlob::Truncated)?;
+
+        match rest {
+            [] => Ok(()),
+            rest => Err(MalformedBlob::TrailingBytes(rest.len())),
+        }
+    }
+}
+
 /// A preferred blob and the ID (hash) of the blob that it was deserialized from
 #[derive(Debug, PartialEq, Clone, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct PreferredBlobDataWithId {
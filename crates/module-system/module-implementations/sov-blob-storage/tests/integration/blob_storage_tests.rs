@@ -21,6 +21,11 @@ fn empty_test() {
         genesis_da_height: 0,
         inner_code_commitment: Default::default(),
         outer_code_commitment: Default::default(),
+        state_access_budget: Default::default(),
+        base_fee_policy: Default::default(),
+        gas_limit_policy: Default::default(),
+        transition_retention_window: None,
+        admin: None,
     };
     chain_state
         .genesis_unchecked(
@@ -47,6 +52,11 @@ fn store_and_retrieve_standard() {
         genesis_da_height: 0,
         inner_code_commitment: Default::default(),
         outer_code_commitment: Default::default(),
+        state_access_budget: Default::default(),
+        base_fee_policy: Default::default(),
+        gas_limit_policy: Default::default(),
+        transition_retention_window: None,
+        admin: None,
     };
     chain_state
         .genesis_unchecked(
@@ -79,7 +89,7 @@ fn store_and_retrieve_standard() {
         }];
 
         let batch = BlobDataWithId {
-            data: BlobData::new_batch(txs),
+            data: Some(BlobData::new_batch(txs)),
             id: [i; 32],
             from_registered_sequencer: true,
         };
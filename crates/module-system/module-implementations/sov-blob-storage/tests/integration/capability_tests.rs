@@ -3,7 +3,10 @@ use std::convert::Infallible;
 
 use borsh::BorshDeserialize;
 use sov_bank::GasTokenConfig;
-use sov_blob_storage::{PreferredBlobData, DEFERRED_SLOTS_COUNT, UNREGISTERED_BLOBS_PER_SLOT};
+use sov_blob_storage::{
+    BlobStorageConfig, PreferredBlobData, DEFERRED_SLOTS_COUNT, SEQUENCE_NUMBER_GAP_TOLERANCE,
+    UNREGISTERED_BLOBS_PER_SLOT,
+};
 use sov_chain_state::ChainStateConfig;
 use sov_kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
 use sov_kernels::soft_confirmations::{
@@ -13,11 +16,12 @@ use sov_mock_da::{MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaSpec}
 use sov_modules_api::da::Time;
 use sov_modules_api::runtime::capabilities::{BlobSelector, Kernel, KernelSlotHooks};
 use sov_modules_api::{
-    Address, BlobData, BlobDataWithId, BlobReaderTrait, Context, DaSpec, DispatchCall,
-    KernelWorkingSet, MessageCodec, Module, RawTx, Spec, StateCheckpoint,
+    Address, BlobData, BlobDataWithId, BlobOutcome, BlobReaderTrait, BlobResult, Context, DaSpec,
+    DispatchCall, KernelWorkingSet, MalformedBlob, MessageCodec, Module, RawTx, Spec,
+    StateCheckpoint, MAX_BLOB_DATA_SIZE,
 };
 use sov_prover_storage_manager::SimpleStorageManager;
-use sov_sequencer_registry::SequencerConfig;
+use sov_sequencer_registry::{SequencerConfig, SlashingReason};
 use sov_state::{ProverStorage, Storage};
 use sov_test_utils::{
     new_test_blob_from_batch, TestStorageSpec as StorageSpec, TEST_DEFAULT_USER_STAKE,
@@ -48,11 +52,14 @@ fn get_bank_config(
             (regular_sequencer, TEST_DEFAULT_USER_STAKE * 3),
         ],
         authorized_minters: vec![],
+        metadata: None,
     };
 
     sov_bank::BankConfig {
         gas_token_config,
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     }
 }
 
@@ -126,6 +133,18 @@ fn make_blobs_by_slot(
         .collect()
 }
 
+fn make_sequenced_blob(sequence_number: u64, sender: MockAddress, id: [u8; 32]) -> MockBlob {
+    MockBlob::new(
+        borsh::to_vec(&BlobData::new_sequenced_batch(
+            sequence_number,
+            vec![RawTx { data: id.to_vec() }],
+        ))
+        .unwrap(),
+        sender,
+        id,
+    )
+}
+
 fn make_blob(tx_data: Vec<u8>, sender: MockAddress, id: [u8; 32]) -> MockBlob {
     MockBlob::new(
         borsh::to_vec(&BlobData::new_batch(vec![RawTx { data: tx_data }])).unwrap(),
@@ -169,7 +188,13 @@ fn do_deferred_blob_test(
                     genesis_da_height: 0,
                     inner_code_commitment: Default::default(),
                     outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
                 },
+                blob_storage: Default::default(),
             },
             &mut kernel_working_set,
         )
@@ -418,6 +443,312 @@ fn test_virtual_slot_stays_in_range() {
     );
 }
 
+#[test]
+fn test_deferred_slots_count_override() {
+    const DEFERRED_SLOTS_OVERRIDE: u64 = 2;
+
+    let (current_storage, _runtime, genesis_root) = TestRuntime::pre_initialized(true);
+
+    // Define the kernel
+    let mut state_checkpoint = StateCheckpoint::new(current_storage.clone());
+    let mut kernel_working_set = KernelWorkingSet::uninitialized(&mut state_checkpoint);
+    let test_kernel = SoftConfirmationsKernel::<S, Da>::default();
+    test_kernel
+        .genesis(
+            &SoftConfirmationsKernelGenesisConfig {
+                chain_state: ChainStateConfig {
+                    current_time: Default::default(),
+                    genesis_da_height: 0,
+                    inner_code_commitment: Default::default(),
+                    outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
+                },
+                blob_storage: BlobStorageConfig {
+                    deferred_slots_count: Some(DEFERRED_SLOTS_OVERRIDE),
+                    ..Default::default()
+                },
+            },
+            &mut kernel_working_set,
+        )
+        .unwrap();
+
+    let blob = make_blob(vec![1], REGULAR_SEQUENCER_DA, [1u8; 32]);
+    let mut blobs_by_slot = vec![vec![blob.clone()]].into_iter();
+
+    for slot_number in 1..=DEFERRED_SLOTS_OVERRIDE + 2 {
+        let slot_number_u8 = slot_number as u8;
+        let mut slot_data = MockBlock {
+            header: MockBlockHeader {
+                prev_hash: [slot_number_u8; 32].into(),
+                hash: [slot_number_u8 + 1; 32].into(),
+                height: slot_number,
+                time: Time::now(),
+            },
+            validity_cond: Default::default(),
+            batch_blobs: blobs_by_slot.next().unwrap_or_default(),
+            proof_blobs: Default::default(),
+        };
+        test_kernel.begin_slot_hook(
+            &slot_data.header,
+            &slot_data.validity_cond,
+            &genesis_root, // For this test, we don't actually execute blocks - so keep reusing the genesis root hash as a placeholder
+            &mut state_checkpoint,
+        );
+
+        kernel_working_set = KernelWorkingSet::from_kernel(&test_kernel, &mut state_checkpoint);
+        assert_eq!(
+            test_kernel
+                .get_blob_storage()
+                .deferred_slots_count(&mut kernel_working_set),
+            DEFERRED_SLOTS_OVERRIDE
+        );
+
+        let mut batches_to_execute = test_kernel
+            .get_blobs_for_this_slot(&mut slot_data.batch_blobs, &mut kernel_working_set)
+            .unwrap();
+
+        // The blob appeared in slot 1, so it must be executed exactly `DEFERRED_SLOTS_OVERRIDE` slots later.
+        if slot_number == 1 + DEFERRED_SLOTS_OVERRIDE {
+            assert_eq!(batches_to_execute.len(), 1);
+            assert_blob_matches_batch(
+                blob.clone(),
+                batches_to_execute.remove(0),
+                &format!("Slot {}", slot_number),
+                false,
+            );
+        } else {
+            assert!(
+                batches_to_execute.is_empty(),
+                "No blobs should be executed in slot {}",
+                slot_number
+            );
+        }
+    }
+}
+
+#[test]
+fn test_blob_selection_reports_unselected_blobs() {
+    let blobs_by_slot = make_blobs_by_slot(&[
+        vec![
+            SequencerInfo::Regular,
+            // Arrives before its predecessor, so it's deferred
+            SequencerInfo::Preferred {
+                slots_to_advance: 2,
+                sequence_number: 1,
+            },
+            SequencerInfo::Preferred {
+                slots_to_advance: 1,
+                sequence_number: 0,
+            },
+        ],
+        vec![
+            SequencerInfo::Regular,
+            // Replayed, so it's ignored
+            SequencerInfo::Preferred {
+                slots_to_advance: 1,
+                sequence_number: 0,
+            },
+        ],
+    ]);
+    let hashes: Vec<Vec<[u8; 32]>> = blobs_by_slot
+        .iter()
+        .map(|slot| slot.iter().map(|b| b.blob.hash()).collect())
+        .collect();
+
+    let expected_by_slot = [
+        (
+            vec![hashes[0][2], hashes[0][0]],
+            vec![BlobResult {
+                blob_hash: hashes[0][1],
+                outcome: BlobOutcome::Deferred,
+            }],
+        ),
+        (
+            vec![hashes[0][1], hashes[1][0]],
+            vec![BlobResult {
+                blob_hash: hashes[1][1],
+                outcome: BlobOutcome::Ignored {
+                    reason: "sequence number too low".to_string(),
+                },
+            }],
+        ),
+    ];
+
+    let (current_storage, _runtime, genesis_root) = TestRuntime::pre_initialized(true);
+
+    // Define the kernel
+    let mut state_checkpoint = StateCheckpoint::new(current_storage.clone());
+    let mut kernel_working_set = KernelWorkingSet::uninitialized(&mut state_checkpoint);
+    let test_kernel = SoftConfirmationsKernel::<S, Da>::default();
+    test_kernel
+        .genesis(
+            &SoftConfirmationsKernelGenesisConfig {
+                chain_state: ChainStateConfig {
+                    current_time: Default::default(),
+                    genesis_da_height: 0,
+                    inner_code_commitment: Default::default(),
+                    outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
+                },
+                blob_storage: Default::default(),
+            },
+            &mut kernel_working_set,
+        )
+        .unwrap();
+
+    for (slot_number, (blobs, (expected_selected, expected_unselected))) in
+        (1..).zip(blobs_by_slot.into_iter().zip(expected_by_slot))
+    {
+        let slot_number_u8 = slot_number as u8;
+        let mut slot_data = MockBlock {
+            header: MockBlockHeader {
+                prev_hash: [slot_number_u8; 32].into(),
+                hash: [slot_number_u8 + 1; 32].into(),
+                height: slot_number,
+                time: Time::now(),
+            },
+            validity_cond: Default::default(),
+            batch_blobs: blobs.into_iter().map(|b| b.blob).collect(),
+            proof_blobs: Default::default(),
+        };
+        test_kernel.begin_slot_hook(
+            &slot_data.header,
+            &slot_data.validity_cond,
+            &genesis_root, // For this test, we don't actually execute blocks - so keep reusing the genesis root hash as a placeholder
+            &mut state_checkpoint,
+        );
+
+        kernel_working_set = KernelWorkingSet::from_kernel(&test_kernel, &mut state_checkpoint);
+        let selection = test_kernel
+            .get_blobs_for_this_slot_with_outcomes(
+                &mut slot_data.batch_blobs,
+                &mut kernel_working_set,
+            )
+            .unwrap();
+
+        let selected: Vec<_> = selection.selected.iter().map(|(blob, _)| blob.id).collect();
+        assert_eq!(selected, expected_selected, "Slot {}", slot_number);
+        assert_eq!(
+            selection.unselected, expected_unselected,
+            "Slot {}",
+            slot_number
+        );
+    }
+}
+
+#[test]
+fn test_regular_sequencer_batches_are_executed_in_sequence_order() {
+    let (current_storage, _runtime, genesis_root) = TestRuntime::pre_initialized(false);
+    let mut state_checkpoint = StateCheckpoint::new(current_storage.clone());
+
+    let mut kernel_working_set = KernelWorkingSet::uninitialized(&mut state_checkpoint);
+    let test_kernel = BasicKernel::<S, Da>::default();
+    test_kernel
+        .genesis(
+            &BasicKernelGenesisConfig {
+                chain_state: ChainStateConfig {
+                    current_time: Default::default(),
+                    genesis_da_height: 0,
+                    inner_code_commitment: Default::default(),
+                    outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
+                },
+            },
+            &mut kernel_working_set,
+        )
+        .unwrap();
+
+    // When this blob arrives, the next expected sequence number is 2.
+    let too_high = 2 + SEQUENCE_NUMBER_GAP_TOLERANCE + 1;
+    let ignored = |id: u8, reason: &str| BlobResult {
+        blob_hash: [id; 32],
+        outcome: BlobOutcome::Ignored {
+            reason: reason.to_string(),
+        },
+    };
+    let deferred = |id: u8| BlobResult {
+        blob_hash: [id; 32],
+        outcome: BlobOutcome::Deferred,
+    };
+    // Blob IDs are the sequence numbers plus one, except for the duplicate and the blob that is too
+    // far ahead.
+    let slots = vec![
+        (
+            vec![
+                make_sequenced_blob(1, REGULAR_SEQUENCER_DA, [2; 32]),
+                make_sequenced_blob(3, REGULAR_SEQUENCER_DA, [4; 32]),
+            ],
+            vec![],
+            vec![deferred(2), deferred(4)],
+        ),
+        (
+            vec![
+                make_sequenced_blob(0, REGULAR_SEQUENCER_DA, [1; 32]),
+                make_sequenced_blob(0, REGULAR_SEQUENCER_DA, [100; 32]),
+            ],
+            vec![[1; 32], [2; 32]],
+            vec![ignored(100, "sequence number too low")],
+        ),
+        (
+            vec![
+                make_sequenced_blob(too_high, REGULAR_SEQUENCER_DA, [101; 32]),
+                make_sequenced_blob(2, REGULAR_SEQUENCER_DA, [3; 32]),
+            ],
+            vec![[3; 32], [4; 32]],
+            vec![ignored(101, "sequence number too high")],
+        ),
+    ];
+
+    for (slot_number, (blobs, expected_selected, expected_unselected)) in (1..).zip(slots) {
+        let slot_number_u8 = slot_number as u8;
+        let mut slot_data = MockBlock {
+            header: MockBlockHeader {
+                prev_hash: [slot_number_u8; 32].into(),
+                hash: [slot_number_u8 + 1; 32].into(),
+                height: slot_number,
+                time: Time::now(),
+            },
+            validity_cond: Default::default(),
+            batch_blobs: blobs,
+            proof_blobs: Default::default(),
+        };
+        test_kernel.begin_slot_hook(
+            &slot_data.header,
+            &slot_data.validity_cond,
+            &genesis_root, // For this test, we don't actually execute blocks - so keep reusing the genesis root hash as a placeholder
+            &mut state_checkpoint,
+        );
+
+        kernel_working_set = KernelWorkingSet::from_kernel(&test_kernel, &mut state_checkpoint);
+        let selection = test_kernel
+            .get_blobs_for_this_slot_with_outcomes(
+                &mut slot_data.batch_blobs,
+                &mut kernel_working_set,
+            )
+            .unwrap();
+
+        let selected: Vec<_> = selection.selected.iter().map(|(blob, _)| blob.id).collect();
+        assert_eq!(selected, expected_selected, "Slot {}", slot_number);
+        assert_eq!(
+            selection.unselected, expected_unselected,
+            "Slot {}",
+            slot_number
+        );
+    }
+}
+
 #[test]
 fn test_recovery_mode() -> Result<(), Infallible> {
     // Initialize the rollup
@@ -435,7 +766,13 @@ fn test_recovery_mode() -> Result<(), Infallible> {
                     genesis_da_height: 0,
                     inner_code_commitment: Default::default(),
                     outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
                 },
+                blob_storage: Default::default(),
             },
             &mut kernel_working_set,
         )
@@ -481,9 +818,11 @@ fn test_recovery_mode() -> Result<(), Infallible> {
     }
     // Slash the preferred sequencer and run one block to enter recovery mode
     {
-        runtime
-            .sequencer_registry
-            .slash_sequencer(&PREFERRED_SEQUENCER_DA, &mut state_checkpoint);
+        runtime.sequencer_registry.slash_sequencer(
+            &PREFERRED_SEQUENCER_DA,
+            SlashingReason::InvalidBatchEncoding,
+            &mut state_checkpoint,
+        );
     }
 
     // Ensure that the virtual slot advances two-at a time until it catches up
@@ -553,6 +892,11 @@ fn test_blobs_from_non_registered_sequencers_are_limited_to_set_amount() {
                     genesis_da_height: 0,
                     inner_code_commitment: Default::default(),
                     outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
                 },
             },
             &mut kernel_working_set,
@@ -631,6 +975,11 @@ fn test_based_sequencing() -> Result<(), Infallible> {
                     genesis_da_height: 0,
                     inner_code_commitment: Default::default(),
                     outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
                 },
             },
             &mut kernel_working_set,
@@ -738,6 +1087,11 @@ fn test_based_sequencing_unregistered_blobs() -> Result<(), Infallible> {
                     genesis_da_height: 0,
                     inner_code_commitment: Default::default(),
                     outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
                 },
             },
             &mut kernel_working_set,
@@ -821,6 +1175,96 @@ fn test_based_sequencing_unregistered_blobs() -> Result<(), Infallible> {
     Ok(())
 }
 
+#[test]
+fn test_malformed_blobs_are_rejected_before_deserialization() -> Result<(), Infallible> {
+    let (current_storage, runtime, genesis_root) = TestRuntime::pre_initialized(false);
+    let mut state_checkpoint = StateCheckpoint::new(current_storage.clone());
+
+    // Define the kernel
+    let mut kernel_working_set = KernelWorkingSet::uninitialized(&mut state_checkpoint);
+    let test_kernel = BasicKernel::<S, Da>::default();
+    test_kernel
+        .genesis(
+            &BasicKernelGenesisConfig {
+                chain_state: ChainStateConfig {
+                    current_time: Default::default(),
+                    genesis_da_height: 0,
+                    inner_code_commitment: Default::default(),
+                    outer_code_commitment: Default::default(),
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
+                },
+            },
+            &mut kernel_working_set,
+        )
+        .unwrap();
+
+    let mut truncated_data =
+        borsh::to_vec(&BlobData::new_batch(vec![RawTx { data: vec![1; 10] }])).unwrap();
+    truncated_data.truncate(truncated_data.len() - 3);
+    assert_eq!(
+        BlobData::pre_validate(&truncated_data),
+        Err(MalformedBlob::Truncated)
+    );
+
+    let oversized_data = borsh::to_vec(&BlobData::new_batch(vec![RawTx {
+        data: vec![1; MAX_BLOB_DATA_SIZE],
+    }]))
+    .unwrap();
+    assert_eq!(
+        BlobData::pre_validate(&oversized_data),
+        Err(MalformedBlob::TooLarge(oversized_data.len()))
+    );
+
+    let unregistered_sequencer = MockAddress::from([7; 32]);
+    let valid_blob = make_blob(vec![1], PREFERRED_SEQUENCER_DA, [1u8; 32]);
+    let blobs = vec![
+        MockBlob::new(truncated_data, unregistered_sequencer, [2u8; 32]),
+        MockBlob::new(oversized_data, REGULAR_SEQUENCER_DA, [3u8; 32]),
+        valid_blob.clone(),
+    ];
+
+    let mut slot_1_data = MockBlock {
+        header: MockBlockHeader::from_height(1),
+        validity_cond: Default::default(),
+        batch_blobs: blobs,
+        proof_blobs: Default::default(),
+    };
+    test_kernel.begin_slot_hook(
+        &slot_1_data.header,
+        &slot_1_data.validity_cond,
+        &genesis_root, // For this test, we don't actually execute blocks - so keep reusing the genesis root hash as a placeholder
+        &mut state_checkpoint,
+    );
+    kernel_working_set = KernelWorkingSet::from_kernel(&test_kernel, &mut state_checkpoint);
+    let mut execute_in_slot_1 = test_kernel
+        .get_blobs_for_this_slot(&mut slot_1_data.batch_blobs, &mut kernel_working_set)
+        .unwrap();
+
+    // The unregistered blob is passed on as malformed, so that it can be ignored with a receipt.
+    // The oversized blob from the registered sequencer is dropped entirely.
+    assert_eq!(execute_in_slot_1.len(), 2);
+    let (malformed, sender) = execute_in_slot_1.remove(0);
+    assert_eq!(malformed.data, None);
+    assert_eq!(malformed.id, [2u8; 32]);
+    assert!(!malformed.from_registered_sequencer);
+    assert_eq!(sender, unregistered_sequencer);
+    assert_blob_matches_batch(valid_blob, execute_in_slot_1.remove(0), "slot 1", false);
+
+    // The registered sequencer is still slashed for the invalid encoding.
+    assert!(!runtime
+        .sequencer_registry
+        .is_registered_sequencer(&REGULAR_SEQUENCER_DA, &mut state_checkpoint)?);
+    assert!(runtime
+        .sequencer_registry
+        .is_registered_sequencer(&PREFERRED_SEQUENCER_DA, &mut state_checkpoint)?);
+
+    Ok(())
+}
+
 /// Check hashes and data of two blobs.
 fn assert_blob_matches_batch<B: BlobReaderTrait>(
     mut expected: B,
@@ -833,11 +1277,11 @@ fn assert_blob_matches_batch<B: BlobReaderTrait>(
     if is_preferred {
         assert_eq!(expected.hash(), actual.0.id);
         let expected = PreferredBlobData::try_from_slice(expected.full_data()).unwrap();
-        assert_eq!(expected.data, actual.0.data);
+        assert_eq!(Some(expected.data), actual.0.data);
     } else {
         let batch = match actual.0.data {
-            BlobData::Batch(batch) => batch,
-            BlobData::Proof(_) => panic!("Expected a batch, but got a proof"),
+            Some(BlobData::Batch(batch)) => batch,
+            other => panic!("Expected a batch, but got {other:?}"),
         };
 
         let mut actual_inner = new_test_blob_from_batch(batch, actual.1.as_ref(), actual_id);
@@ -912,6 +1356,7 @@ impl TestRuntime<S, MockDaSpec> {
         let register_message = sov_sequencer_registry::CallMessage::Register {
             da_address: REGULAR_SEQUENCER_DA.as_ref().to_vec(),
             amount: TEST_DEFAULT_USER_STAKE,
+            minimum_bond: None,
         };
         runtime
             .sequencer_registry
@@ -946,6 +1391,9 @@ impl TestRuntime<S, MockDaSpec> {
             seq_da_address: PREFERRED_SEQUENCER_DA,
             minimum_bond: TEST_DEFAULT_USER_STAKE,
             is_preferred_sequencer: with_preferred_sequencer,
+            require_preferred_sequencer: false,
+            unbonding_period: 0,
+            slash_fraction_bips: sov_sequencer_registry::MAX_SLASH_FRACTION_BIPS,
         };
 
         GenesisConfig {
@@ -0,0 +1,185 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use sov_modules_api::prelude::UnwrapInfallible;
+use sov_modules_api::{Context, KernelWorkingSet, Spec, StateAccessor, StateReader, TxState, Zkvm};
+use sov_state::namespaces::Accessory;
+use sov_state::User;
+
+use crate::{ChainState, TransitionHeight};
+
+/// A privileged operation of the kernel, along with its parameters.
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Debug(bound = "S: Spec"),
+    Clone(bound = "S: Spec"),
+    PartialEq(bound = "S: Spec"),
+    Eq(bound = "S: Spec")
+)]
+#[serde(bound = "", rename_all = "snake_case")]
+pub enum PrivilegedAction<S: Spec> {
+    /// The code commitments used to verify the rollup's execution were replaced.
+    RotateCodeCommitments {
+        /// The new inner code commitment.
+        inner_code_commitment: <S::InnerZkvm as Zkvm>::CodeCommitment,
+        /// The new outer code commitment.
+        outer_code_commitment: <S::OuterZkvm as Zkvm>::CodeCommitment,
+    },
+    /// The preferred sequencer exited the sequencer registry, so batches are no longer
+    /// prioritized until a new preferred sequencer is set.
+    PreferredSequencerRemoved {
+        /// The raw DA address of the sequencer which was preferred.
+        da_address: Vec<u8>,
+    },
+}
+
+/// An entry of the audit log of privileged operations.
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Debug(bound = "S: Spec"),
+    Clone(bound = "S: Spec"),
+    PartialEq(bound = "S: Spec"),
+    Eq(bound = "S: Spec")
+)]
+#[serde(bound = "")]
+pub struct AuditLogEntry<S: Spec> {
+    /// The visible slot number at which the operation was performed.
+    pub slot: TransitionHeight,
+    /// The address which performed the operation.
+    pub actor: S::Address,
+    /// The operation itself.
+    pub action: PrivilegedAction<S>,
+}
+
+/// An error returned when a privileged operation is rejected.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PrivilegedActionError {
+    /// No admin was set at genesis, so privileged operations are disabled.
+    #[error("No admin was set at genesis, privileged operations are disabled")]
+    NoAdmin,
+    /// The sender isn't the admin set at genesis.
+    #[error("{sender} is not allowed to perform privileged operations")]
+    Unauthorized {
+        /// The sender of the rejected operation.
+        sender: String,
+    },
+}
+
+impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
+    /// Returns the address allowed to perform privileged operations, if any was set at genesis.
+    pub fn admin<Accessor: StateAccessor>(
+        &self,
+        state: &mut Accessor,
+    ) -> Result<Option<S::Address>, <Accessor as StateReader<User>>::Error> {
+        self.admin.get(state)
+    }
+
+    /// Replaces the code commitments used to verify the rollup's execution.
+    /// Only the admin set at genesis can rotate them, and the rotation is recorded in the audit log.
+    pub fn rotate_code_commitments(
+        &self,
+        inner_code_commitment: <S::InnerZkvm as Zkvm>::CodeCommitment,
+        outer_code_commitment: <S::OuterZkvm as Zkvm>::CodeCommitment,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> anyhow::Result<()> {
+        self.ensure_admin(context, state)?;
+
+        self.inner_code_commitment
+            .set(&inner_code_commitment, state)?;
+        self.outer_code_commitment
+            .set(&outer_code_commitment, state)?;
+
+        self.record_privileged_action(
+            PrivilegedAction::RotateCodeCommitments {
+                inner_code_commitment,
+                outer_code_commitment,
+            },
+            context,
+            state,
+        )
+    }
+
+    /// Records a privileged operation performed by the sender of `context` in the audit log.
+    ///
+    /// The entry is appended to the audit log at the end of the slot, by the [`ChainState::end_slot_hook`] hook,
+    /// since the audit log lives in the accessory state which can't be read during transaction execution.
+    pub fn record_privileged_action(
+        &self,
+        action: PrivilegedAction<S>,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> anyhow::Result<()> {
+        let entry = AuditLogEntry {
+            slot: context.visible_slot_number(),
+            actor: context.sender().clone(),
+            action,
+        };
+        tracing::info!(?entry, "Recording a privileged operation");
+
+        self.pending_audit_entries.push(&entry, state)?;
+        Ok(())
+    }
+
+    fn ensure_admin(
+        &self,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> anyhow::Result<()> {
+        match self.admin.get(state)? {
+            Some(admin) if &admin == context.sender() => Ok(()),
+            Some(_) => Err(PrivilegedActionError::Unauthorized {
+                sender: context.sender().to_string(),
+            }
+            .into()),
+            None => Err(PrivilegedActionError::NoAdmin.into()),
+        }
+    }
+
+    /// Appends the privileged operations recorded during the slot to the audit log.
+    pub(crate) fn flush_audit_entries(&self, state: &mut KernelWorkingSet<S>) {
+        let entries: Vec<AuditLogEntry<S>> = self.pending_audit_entries.iter(state.inner).collect();
+        if entries.is_empty() {
+            return;
+        }
+
+        self.pending_audit_entries
+            .clear(state.inner)
+            .unwrap_infallible();
+
+        #[cfg(feature = "native")]
+        {
+            let mut accessory_state = state.inner.accessory_state();
+            for entry in &entries {
+                self.audit_log
+                    .push(entry, &mut accessory_state)
+                    .unwrap_infallible();
+            }
+        }
+    }
+
+    /// Returns the number of entries of the audit log.
+    pub fn audit_log_len<Reader: StateReader<Accessory>>(
+        &self,
+        state: &mut Reader,
+    ) -> Result<u64, Reader::Error> {
+        Ok(self.audit_log.len(state)? as u64)
+    }
+
+    /// Returns the entries of the audit log with indexes in `from..to`, i.e. `from` is inclusive
+    /// and `to` is exclusive, along with their indexes. Entries are sorted from the oldest to the newest.
+    pub fn audit_log_entries<Reader: StateReader<Accessory>>(
+        &self,
+        from: u64,
+        to: u64,
+        state: &mut Reader,
+    ) -> Result<Vec<(u64, AuditLogEntry<S>)>, Reader::Error> {
+        let mut entries = Vec::new();
+        for index in from..to {
+            if let Some(entry) = self.audit_log.get(index as usize, state)? {
+                entries.push((index, entry));
+            }
+        }
+
+        Ok(entries)
+    }
+}
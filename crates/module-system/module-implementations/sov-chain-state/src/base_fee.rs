@@ -0,0 +1,169 @@
+use std::cmp::max;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sov_modules_api::macros::config_value;
+use sov_modules_api::{Gas, GasArray, GasPrice, GasUnit};
+
+use crate::gas::NonZeroRatio;
+use crate::BlockGasInfo;
+
+/// Computes the base fee per gas of a block at the beginning of the slot, see [`crate::ChainState::begin_slot_hook`].
+pub trait BaseFeeOracle<GU: Gas> {
+    /// Computes the base fee per gas of the next block from the gas info of its parent.
+    fn next_base_fee_per_gas(&self, parent_gas_info: &BlockGasInfo<GU>) -> GU::Price;
+}
+
+/// Updates the base fee according to the fullness of the parent block, following the
+/// EIP-1559 specification (`<https://eips.ethereum.org/EIPS/eip-1559>`). Each dimension of the
+/// multi-dimensional gas price is updated independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eip1559BaseFeeOracle {
+    /// The ratio of the gas limit to the gas target.
+    pub elasticity_multiplier: NonZeroRatio,
+    /// The higher this value is, the less the base fee changes between two blocks.
+    pub base_fee_max_change_denominator: NonZeroRatio,
+}
+
+impl Default for Eip1559BaseFeeOracle {
+    /// Uses the `ELASTICITY_MULTIPLIER` and `BASE_FEE_MAX_CHANGE_DENOMINATOR` values from the config file.
+    fn default() -> Self {
+        Self {
+            elasticity_multiplier: NonZeroRatio::from_u8_unwrap(config_value!(
+                "ELASTICITY_MULTIPLIER"
+            )),
+            base_fee_max_change_denominator: NonZeroRatio::from_u8_unwrap(config_value!(
+                "BASE_FEE_MAX_CHANGE_DENOMINATOR"
+            )),
+        }
+    }
+}
+
+impl Eip1559BaseFeeOracle {
+    /// Computes the updated gas price following a block execution for a single dimension.
+    /// Note that here we drop the `parent` prefix and call the state variables `gas_limit`, `gas_used` and `base_fee_per_gas`.
+    pub(crate) fn next_base_fee_per_gas_unidimensional(
+        &self,
+        parent_gas_info: &BlockGasInfo<GasUnit<1>>,
+    ) -> GasPrice<1> {
+        let BlockGasInfo {
+            gas_limit,
+            gas_used,
+            mut base_fee_per_gas,
+        } = parent_gas_info.clone();
+
+        // The gas target is equal to `gas_limit // ELASTICITY_MULTIPLIER`
+        let gas_target = GasUnit::<1>::from(self.elasticity_multiplier.apply_div(gas_limit.into()));
+
+        if gas_used == gas_target {
+            // We reached the gas target, so we don't need to update the base fee
+            base_fee_per_gas
+        } else {
+            // We need to update the base fee because we didn't reach the gas target.
+
+            // Compute the difference in absolute value between the gas target and the gas used.
+            // This value is the delta between the gas target and the gas used. We need to then apply the `base_fee_per_gas` to compute its value in tokens.
+            let gas_used_delta_as_u64 =
+                u64::from(gas_target.clone()).abs_diff(gas_used.clone().into());
+            let gas_used_delta = GasUnit::<1>::from(gas_used_delta_as_u64);
+            let gas_used_delta_value = gas_used_delta.value(&base_fee_per_gas);
+
+            // This division expresses the `base_fee_per_gas` delta as the ration (gas_used_delta_value / gas_target).
+            // If the division underflows, the delta is set to zero
+            //
+            // Note here that this operation gives a value that can be expressed as a `GasPrice<1>` because we do
+            // `base_fee_per_gas * (gas_used_delta / gas_target)`.
+            let base_fee_per_gas_delta_u64 = gas_used_delta_value
+                .checked_div(gas_target.clone().into())
+                .unwrap_or_default();
+
+            // We normalize the result, the same way as in the EIP-1559 specification (`<https://eips.ethereum.org/EIPS/eip-1559>`)
+            let base_fee_per_gas_delta_normalized = self
+                .base_fee_max_change_denominator
+                .apply_div(base_fee_per_gas_delta_u64);
+
+            if gas_used > gas_target {
+                // In that case, we take the maximum with `1` to make sure the `base_fee_per_gas` is always increased
+                let base_fee_per_gas_delta_normalized =
+                    GasPrice::<1>::from(max(base_fee_per_gas_delta_normalized, 1));
+
+                base_fee_per_gas.combine(&base_fee_per_gas_delta_normalized);
+
+                base_fee_per_gas
+            } else {
+                // Although unlikely, the `base_fee_per_gas` can reach zero. We cannot have a negative value for gas price
+                // so we saturate at zero.
+                base_fee_per_gas
+                    .checked_sub(&GasPrice::<1>::from(base_fee_per_gas_delta_normalized))
+                    .unwrap_or(GasPrice::<1>::ZEROED)
+            }
+        }
+    }
+}
+
+impl<GU: Gas> BaseFeeOracle<GU> for Eip1559BaseFeeOracle {
+    fn next_base_fee_per_gas(&self, parent_gas_info: &BlockGasInfo<GU>) -> GU::Price {
+        let res: Vec<u64> = parent_gas_info
+            .base_fee_per_gas
+            .as_slice()
+            .iter()
+            .zip(parent_gas_info.gas_limit.as_slice())
+            .zip(parent_gas_info.gas_used.as_slice())
+            .map(|((base_fee_per_gas, gas_limit), gas_used)| {
+                self.next_base_fee_per_gas_unidimensional(&BlockGasInfo {
+                    gas_limit: GasUnit::<1>::from(*gas_limit),
+                    gas_used: GasUnit::<1>::from(*gas_used),
+                    base_fee_per_gas: GasPrice::<1>::from(*base_fee_per_gas),
+                })
+                .into()
+            })
+            .collect();
+
+        GU::Price::from_slice(res.as_slice())
+    }
+}
+
+/// Keeps the base fee of the parent block, regardless of its fullness. The base fee hence stays
+/// at its initial value, see [`crate::ChainState::initial_base_fee_per_gas`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConstantBaseFeeOracle;
+
+impl<GU: Gas> BaseFeeOracle<GU> for ConstantBaseFeeOracle {
+    fn next_base_fee_per_gas(&self, parent_gas_info: &BlockGasInfo<GU>) -> GU::Price {
+        parent_gas_info.base_fee_per_gas.clone()
+    }
+}
+
+/// The [`BaseFeeOracle`] used by the rollup, selected at genesis.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum BaseFeePolicy {
+    /// Uses the [`Eip1559BaseFeeOracle`] with the parameters from the config file.
+    #[default]
+    Eip1559,
+    /// Uses the [`ConstantBaseFeeOracle`].
+    Constant,
+}
+
+impl<GU: Gas> BaseFeeOracle<GU> for BaseFeePolicy {
+    fn next_base_fee_per_gas(&self, parent_gas_info: &BlockGasInfo<GU>) -> GU::Price {
+        match self {
+            BaseFeePolicy::Eip1559 => {
+                Eip1559BaseFeeOracle::default().next_base_fee_per_gas(parent_gas_info)
+            }
+            BaseFeePolicy::Constant => ConstantBaseFeeOracle.next_base_fee_per_gas(parent_gas_info),
+        }
+    }
+}
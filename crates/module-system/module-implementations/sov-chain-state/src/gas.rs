@@ -1,11 +1,9 @@
-use std::cmp::max;
-
 use serde::{Deserialize, Serialize};
 use sov_modules_api::macros::config_value;
 use sov_modules_api::{DaSpec, Gas, GasArray, GasPrice, GasUnit, Spec};
 use thiserror::Error;
 
-use crate::{BlockGasInfo, ChainState};
+use crate::{BaseFeeOracle, BlockGasInfo, ChainState, Eip1559BaseFeeOracle};
 
 /// A non-zero `u8` ratio, useful for defining ratios and multiplicative constants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -150,64 +148,21 @@ impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
 }
 
 impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
+    /// The [`Eip1559BaseFeeOracle`] parametrized with [`ChainState::ELASTICITY_MULTIPLIER`] and
+    /// [`ChainState::BASE_FEE_MAX_CHANGE_DENOMINATOR`].
+    fn eip1559_base_fee_oracle() -> Eip1559BaseFeeOracle {
+        Eip1559BaseFeeOracle {
+            elasticity_multiplier: Self::ELASTICITY_MULTIPLIER,
+            base_fee_max_change_denominator: Self::BASE_FEE_MAX_CHANGE_DENOMINATOR,
+        }
+    }
+
     /// Computes the updated gas price following a block execution for a single dimension.
     /// This reproduces the logic of the EIP-1559 specification to compute the updated `base_fee_per_gas` (`<https://eips.ethereum.org/EIPS/eip-1559>`).
-    /// Note that here we drop the `parent` prefix and call the state variables `gas_limit`, `gas_used` and `base_fee_per_gas`.
     pub(crate) fn compute_base_fee_per_gas_unidimensional(
         parent_gas_info: &BlockGasInfo<GasUnit<1>>,
     ) -> GasPrice<1> {
-        let BlockGasInfo {
-            gas_limit,
-            gas_used,
-            mut base_fee_per_gas,
-        } = parent_gas_info.clone();
-
-        // The gas target is equal to `gas_limit // ELASTICITY_MULTIPLIER`
-        let gas_target =
-            GasUnit::<1>::from(Self::ELASTICITY_MULTIPLIER.apply_div(gas_limit.into()));
-
-        if gas_used == gas_target {
-            // We reached the gas target, so we don't need to update the base fee
-            base_fee_per_gas
-        } else {
-            // We need to update the base fee because we didn't reach the gas target.
-
-            // Compute the difference in absolute value between the gas target and the gas used.
-            // This value is the delta between the gas target and the gas used. We need to then apply the `base_fee_per_gas` to compute its value in tokens.
-            let gas_used_delta_as_u64 =
-                u64::from(gas_target.clone()).abs_diff(gas_used.clone().into());
-            let gas_used_delta = GasUnit::<1>::from(gas_used_delta_as_u64);
-            let gas_used_delta_value = gas_used_delta.value(&base_fee_per_gas);
-
-            // This division expresses the `base_fee_per_gas` delta as the ration (gas_used_delta_value / gas_target).
-            // If the division underflows, the delta is set to zero
-            //
-            // Note here that this operation gives a value that can be expressed as a `GasPrice<1>` because we do
-            // `base_fee_per_gas * (gas_used_delta / gas_target)`.
-            let base_fee_per_gas_delta_u64 = gas_used_delta_value
-                .checked_div(gas_target.clone().into())
-                .unwrap_or_default();
-
-            // We normalize the result, the same way as in the EIP-1559 specification (`<https://eips.ethereum.org/EIPS/eip-1559>`)
-            let base_fee_per_gas_delta_normalized =
-                Self::BASE_FEE_MAX_CHANGE_DENOMINATOR.apply_div(base_fee_per_gas_delta_u64);
-
-            if gas_used > gas_target {
-                // In that case, we take the maximum with `1` to make sure the `base_fee_per_gas` is always increased
-                let base_fee_per_gas_delta_normalized =
-                    GasPrice::<1>::from(max(base_fee_per_gas_delta_normalized, 1));
-
-                base_fee_per_gas.combine(&base_fee_per_gas_delta_normalized);
-
-                base_fee_per_gas
-            } else {
-                // Although unlikely, the `base_fee_per_gas` can reach zero. We cannot have a negative value for gas price
-                // so we saturate at zero.
-                base_fee_per_gas
-                    .checked_sub(&GasPrice::<1>::from(base_fee_per_gas_delta_normalized))
-                    .unwrap_or(GasPrice::<1>::ZEROED)
-            }
-        }
+        Self::eip1559_base_fee_oracle().next_base_fee_per_gas_unidimensional(parent_gas_info)
     }
 
     /// Computes the updated gas price following a block execution, provided the arguments.
@@ -220,22 +175,6 @@ impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
     pub fn compute_base_fee_per_gas(
         parent_gas_info: &BlockGasInfo<S::Gas>,
     ) -> <S::Gas as Gas>::Price {
-        let res: Vec<u64> = parent_gas_info
-            .base_fee_per_gas
-            .as_slice()
-            .iter()
-            .zip(parent_gas_info.gas_limit.as_slice())
-            .zip(parent_gas_info.gas_used.as_slice())
-            .map(|((base_fee_per_gas, gas_limit), gas_used)| {
-                Self::compute_base_fee_per_gas_unidimensional(&BlockGasInfo {
-                    gas_limit: GasUnit::<1>::from(*gas_limit),
-                    gas_used: GasUnit::<1>::from(*gas_used),
-                    base_fee_per_gas: GasPrice::<1>::from(*base_fee_per_gas),
-                })
-                .into()
-            })
-            .collect();
-
-        <S::Gas as Gas>::Price::from_slice(res.as_slice())
+        Self::eip1559_base_fee_oracle().next_base_fee_per_gas(parent_gas_info)
     }
 }
@@ -0,0 +1,147 @@
+use anyhow::{bail, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sov_modules_api::{Gas, GasArray};
+
+use crate::gas::NonZeroRatio;
+use crate::BlockGasInfo;
+
+/// Moves the gas limit of each block toward the limit at which its parent would have exactly
+/// hit the gas target, i.e. `gas_used * ELASTICITY_MULTIPLIER`, in the spirit of EIP-1559.
+/// Each dimension of the multi-dimensional gas limit is updated independently.
+///
+/// Sustained full blocks hence raise the gas limit up to `max_gas_limit`, while sustained empty
+/// blocks lower it down to `min_gas_limit`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize, Serialize, Deserialize,
+)]
+pub struct AdaptiveGasLimit {
+    /// Bounds the change of the gas limit between two blocks: each dimension can move by at most
+    /// `gas_limit / max_change_denominator` (and at least by one gas unit). The higher this value
+    /// is, the slower the gas limit changes. Ethereum uses `1024`.
+    pub max_change_denominator: u64,
+    /// The lowest gas limit of a block, for each dimension.
+    pub min_gas_limit: Vec<u64>,
+    /// The highest gas limit of a block, for each dimension.
+    pub max_gas_limit: Vec<u64>,
+}
+
+impl AdaptiveGasLimit {
+    /// Computes the gas limit of the next block for a single dimension.
+    fn next_gas_limit_unidimensional(
+        &self,
+        gas_limit: u64,
+        gas_used: u64,
+        elasticity_multiplier: NonZeroRatio,
+        (min_gas_limit, max_gas_limit): (u64, u64),
+    ) -> u64 {
+        let max_change = (gas_limit / self.max_change_denominator).max(1);
+        let desired_gas_limit = gas_used.saturating_mul(u8::from(elasticity_multiplier).into());
+
+        let next_gas_limit = if desired_gas_limit > gas_limit {
+            gas_limit.saturating_add((desired_gas_limit - gas_limit).min(max_change))
+        } else {
+            gas_limit - (gas_limit - desired_gas_limit).min(max_change)
+        };
+
+        next_gas_limit.clamp(min_gas_limit, max_gas_limit)
+    }
+
+    fn next_gas_limit<GU: Gas>(
+        &self,
+        parent_gas_info: &BlockGasInfo<GU>,
+        elasticity_multiplier: NonZeroRatio,
+    ) -> GU {
+        let res: Vec<u64> = parent_gas_info
+            .gas_limit
+            .as_slice()
+            .iter()
+            .zip(parent_gas_info.gas_used.as_slice())
+            .zip(self.min_gas_limit.iter().zip(&self.max_gas_limit))
+            .map(|((gas_limit, gas_used), (min, max))| {
+                self.next_gas_limit_unidimensional(
+                    *gas_limit,
+                    *gas_used,
+                    elasticity_multiplier,
+                    (*min, *max),
+                )
+            })
+            .collect();
+
+        GU::from_slice(res.as_slice())
+    }
+}
+
+/// The policy used to compute the gas limit of each block, selected at genesis.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GasLimitPolicy {
+    /// Keeps the gas limit at its initial value, see [`crate::ChainState::initial_gas_limit`].
+    #[default]
+    Constant,
+    /// Adjusts the gas limit according to the fullness of the parent block, see [`AdaptiveGasLimit`].
+    Adaptive(AdaptiveGasLimit),
+}
+
+impl GasLimitPolicy {
+    /// Computes the gas limit of the next block from the gas info of its parent.
+    pub fn next_gas_limit<GU: Gas>(
+        &self,
+        parent_gas_info: &BlockGasInfo<GU>,
+        elasticity_multiplier: NonZeroRatio,
+    ) -> GU {
+        match self {
+            GasLimitPolicy::Constant => parent_gas_info.gas_limit.clone(),
+            GasLimitPolicy::Adaptive(adaptive) => {
+                adaptive.next_gas_limit(parent_gas_info, elasticity_multiplier)
+            }
+        }
+    }
+
+    /// Checks that the policy parameters are consistent with each other and with the
+    /// dimensions of the gas of the rollup.
+    pub(crate) fn validate<GU: Gas>(&self) -> Result<()> {
+        let GasLimitPolicy::Adaptive(adaptive) = self else {
+            return Ok(());
+        };
+
+        let dimensions = GU::ZEROED.as_slice().len();
+        if adaptive.max_change_denominator == 0 {
+            bail!("The max change denominator of the gas limit policy cannot be zero");
+        }
+        if adaptive.min_gas_limit.len() != dimensions || adaptive.max_gas_limit.len() != dimensions
+        {
+            bail!(
+                "The gas limit bounds must have {} dimensions, got {} and {}",
+                dimensions,
+                adaptive.min_gas_limit.len(),
+                adaptive.max_gas_limit.len()
+            );
+        }
+        if adaptive
+            .min_gas_limit
+            .iter()
+            .zip(&adaptive.max_gas_limit)
+            .any(|(min, max)| min > max)
+        {
+            bail!(
+                "The minimum gas limit {:?} cannot exceed the maximum gas limit {:?}",
+                adaptive.min_gas_limit,
+                adaptive.max_gas_limit
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,10 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use sov_modules_api::da::Time;
 use sov_modules_api::hooks::TransitionHeight;
-use sov_modules_api::{KernelWorkingSet, Zkvm};
+use sov_modules_api::{KernelWorkingSet, StateAccessBudget, Zkvm};
 
-use crate::ChainState;
+use crate::{BaseFeePolicy, ChainState, GasLimitPolicy};
 
 /// Initial configuration of the chain state
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -23,6 +23,29 @@ pub struct ChainStateConfig<S: sov_modules_api::Spec> {
 
     /// The height of the first DA block.
     pub genesis_da_height: TransitionHeight,
+
+    /// The maximum number of state reads and writes that a single transaction can perform.
+    /// Transactions exceeding it are reverted. Unlimited if omitted.
+    #[serde(default)]
+    pub state_access_budget: StateAccessBudget,
+
+    /// The policy used to compute the base fee per gas of each block. EIP-1559 if omitted.
+    #[serde(default)]
+    pub base_fee_policy: BaseFeePolicy,
+
+    /// The policy used to compute the gas limit of each block. Constant if omitted.
+    #[serde(default)]
+    pub gas_limit_policy: GasLimitPolicy,
+
+    /// The number of most recent transitions kept in the historical transitions. Older
+    /// transitions are pruned, except for the first one. All transitions are kept if omitted.
+    #[serde(default)]
+    pub transition_retention_window: Option<u64>,
+
+    /// The address allowed to perform privileged operations, such as rotating the code commitments.
+    /// Privileged operations are disabled if omitted.
+    #[serde(default)]
+    pub admin: Option<S::Address>,
 }
 
 impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
@@ -36,6 +59,11 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
             genesis_da_height = config.genesis_da_height,
             inner_code_commitment = ?config.inner_code_commitment,
             outer_code_commitment = ?config.outer_code_commitment,
+            state_access_budget = ?config.state_access_budget,
+            base_fee_policy = ?config.base_fee_policy,
+            gas_limit_policy = ?config.gas_limit_policy,
+            transition_retention_window = ?config.transition_retention_window,
+            admin = ?config.admin,
             "Starting chain state genesis...",
         );
         self.true_slot_number.set(&0, state)?;
@@ -52,6 +80,25 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
         self.genesis_da_height
             .set(&config.genesis_da_height, state)?;
 
+        self.state_access_budget
+            .set(&config.state_access_budget, state)?;
+
+        self.base_fee_policy.set(&config.base_fee_policy, state)?;
+
+        config.gas_limit_policy.validate::<S::Gas>()?;
+        self.gas_limit_policy.set(&config.gas_limit_policy, state)?;
+
+        if let Some(window) = config.transition_retention_window {
+            if window == 0 {
+                bail!("The transition retention window cannot be zero");
+            }
+            self.transition_retention_window.set(&window, state)?;
+        }
+
+        if let Some(admin) = &config.admin {
+            self.admin.set(admin, state)?;
+        }
+
         Ok(())
     }
 }
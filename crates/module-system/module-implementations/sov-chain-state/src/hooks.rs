@@ -3,10 +3,17 @@ use sov_modules_api::prelude::UnwrapInfallible;
 use sov_modules_api::{Gas, KernelWorkingSet, Spec};
 use sov_state::Storage;
 
-use crate::{BlockGasInfo, ChainState, StateTransition, TransitionInProgress};
+use crate::{
+    BaseFeeOracle, BlockGasInfo, ChainState, StateTransition, TransitionHeight,
+    TransitionInProgress, GENESIS_TRANSITION_HEIGHT,
+};
 
 impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
     /// Update the chain state at the beginning of the slot. Compute the next gas price
+    ///
+    /// If the slot header is the one of the transition in progress (for instance when a slot is
+    /// replayed after a crash), the hook is a no-op and returns the base fee of that transition,
+    /// so that the slot numbers are not advanced twice.
     pub fn begin_slot_hook(
         &self,
         slot_header: &Da::BlockHeader,
@@ -14,6 +21,20 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
         pre_state_root: &<S::Storage as Storage>::Root,
         state: &mut KernelWorkingSet<S>,
     ) -> <S::Gas as Gas>::Price {
+        if let Some(in_progress) = self
+            .in_progress_transition
+            .get_current(state)
+            .unwrap_infallible()
+        {
+            if in_progress.slot_hash == slot_header.hash() {
+                tracing::warn!(
+                    slot_hash = %slot_header.hash(),
+                    "The slot has already been started, not advancing the slot number again"
+                );
+                return in_progress.gas_info.base_fee_per_gas;
+            }
+        }
+
         let gas_info = if self
             .genesis_root
             .get(state.inner)
@@ -23,8 +44,9 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
             // The genesis hash is not set, hence this is the
             // first transition right after the genesis block
             self.genesis_root
-                .set(pre_state_root, state.inner)
-                .unwrap_infallible();
+                .set_once(pre_state_root, state.inner)
+                .unwrap_infallible()
+                .expect("The genesis root can only be set once");
 
             BlockGasInfo::new(Self::initial_gas_limit(), Self::initial_base_fee_per_gas())
         } else {
@@ -51,15 +73,21 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
             self.historical_transitions
                 .set(&slot_number, &transition, state.inner)
                 .unwrap_infallible();
+            self.prune_historical_transitions(slot_number, state);
+
+            // The base fee per gas is updated according to the policy chosen at genesis
+            let computed_base_fee = self
+                .base_fee_policy(state)
+                .next_base_fee_per_gas(&transition.gas_info);
 
-            // The base fee per gas is updated according to the EIP-1559 specification
-            let computed_base_fee = Self::compute_base_fee_per_gas(&transition.gas_info);
+            // The gas limit is computed by the `end_slot_hook` of the previous slot
+            let gas_limit = self
+                .next_gas_limit
+                .get(state)
+                .unwrap_infallible()
+                .unwrap_or_else(Self::initial_gas_limit);
 
-            BlockGasInfo::new(
-                // TODO(@theochap): the gas limit should be updated dynamically `<https://github.com/Sovereign-Labs/sovereign-sdk-wip/issues/271`
-                Self::initial_gas_limit(),
-                computed_base_fee,
-            )
+            BlockGasInfo::new(gas_limit, computed_base_fee)
         };
 
         // Since we increment the true slot number, we have to update the working set.
@@ -81,7 +109,45 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
         new_base_fee
     }
 
-    /// Updates the gas used by the transition in progress at the end of each slot
+    /// Removes the transition which falls out of the retention window once the transition at
+    /// `slot_number` is recorded. The transition at [`GENESIS_TRANSITION_HEIGHT`] is never removed.
+    fn prune_historical_transitions(
+        &self,
+        slot_number: TransitionHeight,
+        state: &mut KernelWorkingSet<S>,
+    ) {
+        let Some(window) = self
+            .transition_retention_window
+            .get(state)
+            .unwrap_infallible()
+        else {
+            return;
+        };
+
+        if let Some(height) = slot_number
+            .checked_sub(window)
+            .filter(|height| *height > GENESIS_TRANSITION_HEIGHT)
+        {
+            self.historical_transitions
+                .delete(&height, state.inner)
+                .unwrap_infallible();
+        }
+    }
+
+    /// Returns the gas limit of the transition in progress. Falls back to the initial gas limit
+    /// if no transition has started yet.
+    pub fn block_gas_limit(&self, state: &mut KernelWorkingSet<S>) -> S::Gas {
+        self.in_progress_transition
+            .get_current(state)
+            .unwrap_infallible()
+            .map_or_else(Self::initial_gas_limit, |transition| {
+                transition.gas_info.gas_limit
+            })
+    }
+
+    /// Updates the gas used by the transition in progress at the end of each slot, and computes
+    /// the gas limit of the next slot according to the policy chosen at genesis.
+    /// The privileged operations performed during the slot are appended to the audit log.
     pub fn end_slot_hook(&self, gas_used: &S::Gas, state: &mut KernelWorkingSet<S>) {
         let mut in_progress_transition = self
             .in_progress_transition
@@ -93,7 +159,17 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ChainState<S, Da> {
             .gas_info
             .update_gas_used(gas_used.clone());
 
+        let next_gas_limit = self.gas_limit_policy(state).next_gas_limit(
+            &in_progress_transition.gas_info,
+            Self::ELASTICITY_MULTIPLIER,
+        );
+        self.next_gas_limit
+            .set(&next_gas_limit, state)
+            .unwrap_infallible();
+
         self.in_progress_transition
             .set_true_current(&in_progress_transition, state);
+
+        self.flush_audit_entries(state);
     }
 }
@@ -1,9 +1,12 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+mod audit;
+mod base_fee;
 /// Contains the call methods used by the module
 mod call;
 mod gas;
+mod gas_limit;
 #[cfg(test)]
 mod tests;
 use sov_modules_api::{
@@ -11,6 +14,10 @@ use sov_modules_api::{
 };
 
 mod genesis;
+pub use audit::{AuditLogEntry, PrivilegedAction, PrivilegedActionError};
+pub use base_fee::{BaseFeeOracle, BaseFeePolicy, ConstantBaseFeeOracle, Eip1559BaseFeeOracle};
+pub use gas::{NonZeroRatio, NonZeroRatioConversionError};
+pub use gas_limit::{AdaptiveGasLimit, GasLimitPolicy};
 pub use genesis::*;
 use serde::de::DeserializeOwned;
 
@@ -20,14 +27,18 @@ pub mod hooks;
 /// The query interface with the module
 #[cfg(feature = "native")]
 mod query;
+/// The REST API of the module
+#[cfg(feature = "native")]
+pub mod rest;
 use borsh::{BorshDeserialize, BorshSerialize};
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::da::Time;
 pub use sov_modules_api::hooks::TransitionHeight;
+use sov_modules_api::prelude::UnwrapInfallible;
 use sov_modules_api::{
-    DaSpec, Error, Gas, KernelModule, KernelModuleInfo, KernelWorkingSet, ValidityConditionChecker,
-    VersionReader,
+    CryptoSpec, DaSpec, Error, Gas, KernelModule, KernelModuleInfo, KernelWorkingSet,
+    StateAccessBudget, ValidityCondition, ValidityConditionChecker, VersionReader,
 };
 use sov_state::codec::BcsCodec;
 use sov_state::namespaces::Kernel;
@@ -36,6 +47,10 @@ use sov_state::{Storage, User};
 /// Type alias that contains the height of a given transition
 pub type VirtualSlotNumber = u64;
 
+/// The height of the first transition of the rollup, which starts from the genesis root.
+/// This transition is never pruned from the [`ChainState`] historical transitions.
+pub const GENESIS_TRANSITION_HEIGHT: TransitionHeight = 1;
+
 /// A structure that contains block gas information.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, BorshSerialize, BorshDeserialize)]
 #[serde(bound = "GU: DeserializeOwned")]
@@ -86,6 +101,32 @@ impl<GU: Gas> BlockGasInfo<GU> {
     }
 }
 
+/// An error returned by [`ChainState::combined_validity_condition`].
+#[derive(Debug, thiserror::Error)]
+pub enum ValidityConditionRangeError {
+    /// The range doesn't contain any height.
+    #[error("The range {from}..{to} doesn't contain any transition height")]
+    EmptyRange {
+        /// The first height of the range.
+        from: TransitionHeight,
+        /// The height right after the last one of the range.
+        to: TransitionHeight,
+    },
+    /// No transition is recorded at the height, either because it was pruned or because it
+    /// hasn't been completed yet.
+    #[error("No transition is recorded at height {0}")]
+    MissingTransition(TransitionHeight),
+    /// The validity condition of the transition at the height can't be combined with the
+    /// ones of the previous heights.
+    #[error("The validity condition at height {height} can't be combined: {reason}")]
+    Combine {
+        /// The height of the transition.
+        height: TransitionHeight,
+        /// The reason reported by the validity condition.
+        reason: anyhow::Error,
+    },
+}
+
 #[derive(Derivative, BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
 // We need to use derivative here because `Storage` doesn't implement `Eq` and `PartialEq`
 #[derivative(
@@ -232,7 +273,9 @@ pub struct ChainState<S: Spec, Da: DaSpec> {
     time: sov_modules_api::VersionedStateValue<Time>,
 
     /// A record of all previous state transitions which are available to the VM.
-    /// Currently, this includes *all* historical state transitions, but that may change in the future.
+    /// This includes *all* historical state transitions, unless a transition retention window
+    /// was set at genesis, in which case only the transition at [`GENESIS_TRANSITION_HEIGHT`]
+    /// and the most recent ones are kept.
     /// This state map is delayed by one transition. In other words - the transition that happens in time i
     /// is stored during transition i+1. This is mainly due to the fact that this structure depends on the
     /// rollup's root hash which is only stored once the transition has completed.
@@ -247,9 +290,9 @@ pub struct ChainState<S: Spec, Da: DaSpec> {
 
     /// The genesis root hash.
     /// Set after the first transaction of the rollup is executed, using the [`ChainState::begin_slot_hook`] hook.
-    // TODO: This should be made read-only
+    /// The value is write-once, so that it can't be overwritten afterwards.
     #[state]
-    genesis_root: sov_modules_api::StateValue<<S::Storage as Storage>::Root>,
+    genesis_root: sov_modules_api::WriteOnceStateValue<<S::Storage as Storage>::Root>,
 
     /// The height of the first DA block.
     /// Set at the rollup genesis. Since the rollup is always delayed by a constant amount of blocks,
@@ -274,6 +317,45 @@ pub struct ChainState<S: Spec, Da: DaSpec> {
     #[state]
     outer_code_commitment:
         sov_modules_api::StateValue<<S::OuterZkvm as Zkvm>::CodeCommitment, BcsCodec>,
+
+    /// The maximum number of state accesses a single transaction can perform.
+    /// This value is initialized at genesis and bounds the witness size, and hence the proving cost, of each transaction.
+    #[state]
+    state_access_budget: sov_modules_api::KernelStateValue<StateAccessBudget>,
+
+    /// The policy used to compute the base fee per gas of each block.
+    /// This value is initialized at genesis.
+    #[state]
+    base_fee_policy: sov_modules_api::KernelStateValue<BaseFeePolicy>,
+
+    /// The policy used to compute the gas limit of each block.
+    /// This value is initialized at genesis.
+    #[state]
+    gas_limit_policy: sov_modules_api::KernelStateValue<GasLimitPolicy>,
+
+    /// The gas limit of the next block, computed by the [`ChainState::end_slot_hook`] hook
+    /// from the gas used by the current block.
+    #[state]
+    next_gas_limit: sov_modules_api::KernelStateValue<S::Gas>,
+
+    /// The number of most recent transitions kept in the historical transitions, if set.
+    /// This value is initialized at genesis.
+    #[state]
+    transition_retention_window: sov_modules_api::KernelStateValue<u64>,
+
+    /// The address allowed to perform privileged operations.
+    /// This value is initialized at genesis, and privileged operations are disabled if it is unset.
+    #[state]
+    admin: sov_modules_api::StateValue<S::Address>,
+
+    /// The privileged operations performed during the current slot. They are appended to the
+    /// audit log by the [`ChainState::end_slot_hook`] hook.
+    #[state]
+    pending_audit_entries: sov_modules_api::StateVec<AuditLogEntry<S>, BcsCodec>,
+
+    /// The append-only log of all privileged operations, from the oldest to the newest.
+    #[state]
+    audit_log: sov_modules_api::AccessoryStateVec<AuditLogEntry<S>, BcsCodec>,
 }
 
 impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
@@ -366,6 +448,39 @@ impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
         self.genesis_da_height.get(state)
     }
 
+    /// Returns the per-transaction state access budget. Falls back to an unlimited budget if none was set at genesis.
+    pub fn state_access_budget(&self, state: &mut KernelWorkingSet<S>) -> StateAccessBudget {
+        self.state_access_budget
+            .get(state)
+            .unwrap_infallible()
+            .unwrap_or_default()
+    }
+
+    /// Returns the policy used to compute the base fee per gas. Falls back to the EIP-1559 policy if none was set at genesis.
+    pub fn base_fee_policy(&self, state: &mut KernelWorkingSet<S>) -> BaseFeePolicy {
+        self.base_fee_policy
+            .get(state)
+            .unwrap_infallible()
+            .unwrap_or_default()
+    }
+
+    /// Returns the policy used to compute the gas limit of each block. Falls back to a constant gas limit if none was set at genesis.
+    pub fn gas_limit_policy(&self, state: &mut KernelWorkingSet<S>) -> GasLimitPolicy {
+        self.gas_limit_policy
+            .get(state)
+            .unwrap_infallible()
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of most recent transitions kept in the historical transitions, or
+    /// [`None`] if all transitions are kept.
+    pub fn transition_retention_window<Reader: StateReader<Kernel>>(
+        &self,
+        state: &mut Reader,
+    ) -> Result<Option<u64>, Reader::Error> {
+        self.transition_retention_window.get(state)
+    }
+
     /// Returns the transition in progress of the module.
     pub fn get_in_progress_transition<Reader: VersionReader>(
         &self,
@@ -382,6 +497,74 @@ impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
     ) -> Result<Option<StateTransition<S, Da>>, <Accessor as StateReader<User>>::Error> {
         self.historical_transitions.get(&transition_num, state)
     }
+
+    /// Returns the completed transitions with heights in `from..to`, i.e. `from` is inclusive
+    /// and `to` is exclusive, sorted by height.
+    ///
+    /// Heights without a recorded transition are skipped. This is the case for the transitions
+    /// pruned because they fell out of the retention window (see
+    /// [`ChainState::transition_retention_window`]): if the range exceeds the window, only the
+    /// transition at [`GENESIS_TRANSITION_HEIGHT`] and the retained transitions are returned.
+    /// Every height of the range is read, so callers should bound its length.
+    pub fn get_historical_transitions_range<Accessor: StateAccessor>(
+        &self,
+        from: TransitionHeight,
+        to: TransitionHeight,
+        state: &mut Accessor,
+    ) -> Result<
+        Vec<(TransitionHeight, StateTransition<S, Da>)>,
+        <Accessor as StateReader<User>>::Error,
+    > {
+        let mut transitions = Vec::new();
+        for height in from..to {
+            if let Some(transition) = self.historical_transitions.get(&height, state)? {
+                transitions.push((height, transition));
+            }
+        }
+
+        Ok(transitions)
+    }
+
+    /// Combines the validity conditions of the transitions with heights in `from..to`, i.e.
+    /// `from` is inclusive and `to` is exclusive, following [`ValidityCondition::combine`].
+    /// The combined condition can then be checked at once with the `Da::Checker`, for instance
+    /// when aggregating the proofs of the range.
+    ///
+    /// Fails if the range is empty, or if a height of the range has no recorded transition.
+    pub fn combined_validity_condition<Accessor: StateAccessor>(
+        &self,
+        from: TransitionHeight,
+        to: TransitionHeight,
+        state: &mut Accessor,
+    ) -> Result<
+        Result<Da::ValidityCondition, ValidityConditionRangeError>,
+        <Accessor as StateReader<User>>::Error,
+    > {
+        let mut combined: Option<Da::ValidityCondition> = None;
+
+        for height in from..to {
+            let Some(transition) = self.historical_transitions.get(&height, state)? else {
+                return Ok(Err(ValidityConditionRangeError::MissingTransition(height)));
+            };
+
+            combined = Some(match combined {
+                None => transition.validity_condition,
+                Some(combined) => match combined
+                    .combine::<<S::CryptoSpec as CryptoSpec>::Hasher>(transition.validity_condition)
+                {
+                    Ok(combined) => combined,
+                    Err(err) => {
+                        return Ok(Err(ValidityConditionRangeError::Combine {
+                            height,
+                            reason: err.into(),
+                        }))
+                    }
+                },
+            });
+        }
+
+        Ok(combined.ok_or(ValidityConditionRangeError::EmptyRange { from, to }))
+    }
 }
 
 impl<S: Spec, Da: DaSpec> KernelModule for ChainState<S, Da> {
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use sov_modules_api::prelude::axum::routing::get;
+use sov_modules_api::prelude::{axum, UnwrapInfallible};
+use sov_modules_api::rest::utils::{
+    errors, ApiResult, PageSelection, Pagination, Path, Query, ResponseObject,
+};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::{DaSpec, Spec};
+use sov_state::codec::{BcsCodec, StateItemEncoder};
+
+use crate::{
+    AuditLogEntry, ChainState, StateTransition, TransitionHeight, GENESIS_TRANSITION_HEIGHT,
+};
+
+/// A recorded [`StateTransition`], as returned by `GET /transitions/:height`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "StateTransition<S, Da>: Serialize")]
+pub struct StateTransitionResponse<S: Spec, Da: DaSpec> {
+    /// The height of the transition.
+    pub height: TransitionHeight,
+    /// The transition itself.
+    pub transition: StateTransition<S, Da>,
+    /// The hex-encoded bytes of the transition, exactly as they're stored in
+    /// the rollup state. Clients can check them against a state proof.
+    pub encoded: String,
+}
+
+impl<S: Spec, Da: DaSpec> StateTransitionResponse<S, Da> {
+    fn new(height: TransitionHeight, transition: StateTransition<S, Da>) -> Self {
+        Self {
+            height,
+            encoded: format!("0x{}", hex::encode(BcsCodec.encode(&transition))),
+            transition,
+        }
+    }
+}
+
+/// An entry of the audit log, as returned by `GET /admin/audit-log`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "")]
+pub struct AuditLogEntryResponse<S: Spec> {
+    /// The position of the entry in the audit log, starting from zero.
+    pub index: u64,
+    /// The entry itself.
+    #[serde(flatten)]
+    pub entry: AuditLogEntry<S>,
+}
+
+/// The heights of the transitions returned by `GET /transitions`: `from` is inclusive and
+/// defaults to [`GENESIS_TRANSITION_HEIGHT`], `to` is exclusive and defaults to the current slot.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct TransitionsRange {
+    /// The first height of the range.
+    pub from: Option<TransitionHeight>,
+    /// The height right after the last one of the range.
+    pub to: Option<TransitionHeight>,
+}
+
+impl<S: Spec, Da: DaSpec> HasCustomRestApi<S> for ChainState<S, Da> {
+    fn custom_rest_api(&self, state: ApiState<Self, S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route("/transitions", get(Self::route_transitions))
+            .route("/transitions/:height", get(Self::route_transition))
+            .route("/admin/audit-log", get(Self::route_audit_log))
+            .with_state(state)
+    }
+}
+
+impl<S: Spec, Da: DaSpec> ChainState<S, Da> {
+    async fn route_transition(
+        state: ApiState<Self, S>,
+        Path(height): Path<TransitionHeight>,
+    ) -> ApiResult<StateTransitionResponse<S, Da>> {
+        let transition = state
+            .get_historical_transitions(height, &mut state.api_state_accessor())
+            .unwrap_infallible();
+
+        match transition {
+            Some(transition) => Ok(StateTransitionResponse::new(height, transition).into()),
+            None => Err(errors::not_found_404("Transition", height)),
+        }
+    }
+
+    /// Returns a page of the recorded transitions within a [`TransitionsRange`], skipping the
+    /// pruned heights. Each page covers at most `page[size]` heights, so pages can hold fewer
+    /// transitions when heights were pruned. The cursor is the last height covered by the page,
+    /// and is only present in `meta` if the range goes on after it.
+    async fn route_transitions(
+        state: ApiState<Self, S>,
+        Query(range): Query<TransitionsRange>,
+        Query(pagination): Query<Pagination<u64>>,
+    ) -> ApiResult<Vec<StateTransitionResponse<S, Da>>> {
+        let mut accessor = state.api_state_accessor();
+        let from = range.from.unwrap_or(GENESIS_TRANSITION_HEIGHT);
+        let to = match range.to {
+            Some(to) => to,
+            None => state.true_slot_number(&mut accessor).unwrap_infallible(),
+        };
+        if from > to {
+            return Err(errors::bad_request_400(
+                "Invalid transitions range",
+                format!("from ({}) can't be greater than to ({})", from, to),
+            ));
+        }
+
+        let page_size = u64::from(pagination.size);
+        let start = match pagination.selection {
+            PageSelection::First => from,
+            PageSelection::Next { cursor } => cursor.saturating_add(1).max(from),
+            PageSelection::Last => to.saturating_sub(page_size).max(from),
+        };
+        let end = start.saturating_add(page_size).min(to);
+
+        let transitions = state
+            .get_historical_transitions_range(start, end, &mut accessor)
+            .unwrap_infallible();
+        let cursor = (end < to).then(|| (end - 1).to_string());
+
+        Ok(ResponseObject::from(
+            transitions
+                .into_iter()
+                .map(|(height, transition)| StateTransitionResponse::new(height, transition))
+                .collect::<Vec<_>>(),
+        )
+        .with_meta("cursor", cursor))
+    }
+
+    /// Returns a page of the audit log of privileged operations, from the oldest to the newest
+    /// entry. The cursor is the index of the last entry of the page, and is only present in `meta`
+    /// if more entries follow it.
+    async fn route_audit_log(
+        state: ApiState<Self, S>,
+        Query(pagination): Query<Pagination<u64>>,
+    ) -> ApiResult<Vec<AuditLogEntryResponse<S>>> {
+        let mut accessor = state.api_state_accessor();
+        let len = state.audit_log_len(&mut accessor).unwrap_infallible();
+
+        let page_size = u64::from(pagination.size);
+        let start = match pagination.selection {
+            PageSelection::First => 0,
+            PageSelection::Next { cursor } => cursor.saturating_add(1),
+            PageSelection::Last => len.saturating_sub(page_size),
+        };
+        let end = start.saturating_add(page_size).min(len);
+
+        let entries = state
+            .audit_log_entries(start, end, &mut accessor)
+            .unwrap_infallible();
+        let cursor = (end < len).then(|| (end - 1).to_string());
+
+        Ok(ResponseObject::from(
+            entries
+                .into_iter()
+                .map(|(index, entry)| AuditLogEntryResponse { index, entry })
+                .collect::<Vec<_>>(),
+        )
+        .with_meta("cursor", cursor))
+    }
+}
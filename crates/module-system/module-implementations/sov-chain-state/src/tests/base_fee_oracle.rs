@@ -0,0 +1,87 @@
+use sov_mock_da::MockDaSpec;
+use sov_modules_api::{Gas, GasArray, Spec};
+use sov_test_utils::TestSpec;
+
+use crate::{BaseFeeOracle, BaseFeePolicy, BlockGasInfo, ChainState, ConstantBaseFeeOracle};
+
+const INITIAL_BASE_FEE_PER_GAS: [u64; 2] = [100, 100];
+
+/// Builds the gas info of a parent block that consumed `gas_used`, starting from the initial gas limit.
+fn parent_gas_info(gas_used: <TestSpec as Spec>::Gas) -> BlockGasInfo<<TestSpec as Spec>::Gas> {
+    let mut parent_gas_info = BlockGasInfo::new(
+        ChainState::<TestSpec, MockDaSpec>::initial_gas_limit(),
+        INITIAL_BASE_FEE_PER_GAS.into(),
+    );
+
+    parent_gas_info.update_gas_used(gas_used);
+
+    parent_gas_info
+}
+
+fn full_block() -> BlockGasInfo<<TestSpec as Spec>::Gas> {
+    parent_gas_info(ChainState::<TestSpec, MockDaSpec>::initial_gas_limit())
+}
+
+fn empty_block() -> BlockGasInfo<<TestSpec as Spec>::Gas> {
+    parent_gas_info(<TestSpec as Spec>::Gas::ZEROED)
+}
+
+/// Checks that the EIP-1559 policy increases every dimension of the base fee after a full block.
+#[test]
+fn test_eip1559_base_fee_rises_after_full_block() {
+    let next_base_fee = BaseFeePolicy::Eip1559.next_base_fee_per_gas(&full_block());
+
+    for (next, initial) in next_base_fee
+        .as_slice()
+        .iter()
+        .zip(INITIAL_BASE_FEE_PER_GAS)
+    {
+        assert!(
+            *next > initial,
+            "The base fee per gas should increase after a full block"
+        );
+    }
+}
+
+/// Checks that the EIP-1559 policy decreases every dimension of the base fee after an empty block.
+#[test]
+fn test_eip1559_base_fee_falls_after_empty_block() {
+    let next_base_fee = BaseFeePolicy::Eip1559.next_base_fee_per_gas(&empty_block());
+
+    for (next, initial) in next_base_fee
+        .as_slice()
+        .iter()
+        .zip(INITIAL_BASE_FEE_PER_GAS)
+    {
+        assert!(
+            *next < initial,
+            "The base fee per gas should decrease after an empty block"
+        );
+    }
+}
+
+/// Checks that the default policy matches the EIP-1559 computation of the chain state module.
+#[test]
+fn test_default_policy_is_eip1559() {
+    for parent in [full_block(), empty_block()] {
+        assert_eq!(
+            BaseFeePolicy::default().next_base_fee_per_gas(&parent),
+            ChainState::<TestSpec, MockDaSpec>::compute_base_fee_per_gas(&parent)
+        );
+    }
+}
+
+/// Checks that the constant policy keeps the parent base fee regardless of the block fullness.
+#[test]
+fn test_constant_base_fee_does_not_change() {
+    for parent in [full_block(), empty_block()] {
+        assert_eq!(
+            BaseFeePolicy::Constant.next_base_fee_per_gas(&parent),
+            INITIAL_BASE_FEE_PER_GAS.into()
+        );
+        assert_eq!(
+            ConstantBaseFeeOracle.next_base_fee_per_gas(&parent),
+            INITIAL_BASE_FEE_PER_GAS.into()
+        );
+    }
+}
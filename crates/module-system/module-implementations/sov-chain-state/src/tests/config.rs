@@ -12,6 +12,11 @@ fn test_config_serialization() {
         genesis_da_height: 0,
         inner_code_commitment: Default::default(),
         outer_code_commitment: Default::default(),
+        state_access_budget: Default::default(),
+        base_fee_policy: Default::default(),
+        gas_limit_policy: Default::default(),
+        transition_retention_window: None,
+        admin: None,
     };
 
     let data = r#"
@@ -0,0 +1,125 @@
+use sov_mock_da::MockDaSpec;
+use sov_modules_api::{GasArray, Spec};
+use sov_test_utils::TestSpec;
+
+use crate::{AdaptiveGasLimit, BlockGasInfo, ChainState, GasLimitPolicy};
+
+type Gas = <TestSpec as Spec>::Gas;
+
+const MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+fn initial_gas_limit() -> Gas {
+    ChainState::<TestSpec, MockDaSpec>::initial_gas_limit()
+}
+
+fn adaptive_policy() -> GasLimitPolicy {
+    let initial_gas_limit = initial_gas_limit();
+    GasLimitPolicy::Adaptive(AdaptiveGasLimit {
+        max_change_denominator: MAX_CHANGE_DENOMINATOR,
+        min_gas_limit: initial_gas_limit.as_slice().iter().map(|g| g / 4).collect(),
+        max_gas_limit: initial_gas_limit.as_slice().iter().map(|g| g * 4).collect(),
+    })
+}
+
+/// Returns the gas limit of the next block after a block with the given `gas_limit`
+/// which used `gas_used(gas_limit)`.
+fn next_gas_limit(policy: &GasLimitPolicy, gas_limit: &Gas, gas_used: impl Fn(&Gas) -> Gas) -> Gas {
+    let mut gas_info = BlockGasInfo::new(gas_limit.clone(), [100, 100].into());
+    gas_info.update_gas_used(gas_used(gas_limit));
+
+    policy.next_gas_limit(
+        &gas_info,
+        ChainState::<TestSpec, MockDaSpec>::ELASTICITY_MULTIPLIER,
+    )
+}
+
+/// Applies the policy to `blocks` consecutive blocks, checking that the per-block change of
+/// the gas limit stays within the max change ratio, and returns the final gas limit.
+fn run_blocks(policy: &GasLimitPolicy, blocks: usize, gas_used: impl Fn(&Gas) -> Gas) -> Gas {
+    let mut gas_limit = initial_gas_limit();
+
+    for _ in 0..blocks {
+        let next = next_gas_limit(policy, &gas_limit, &gas_used);
+
+        for (next, current) in next.as_slice().iter().zip(gas_limit.as_slice()) {
+            assert!(
+                next.abs_diff(*current) <= (current / MAX_CHANGE_DENOMINATOR).max(1),
+                "The gas limit changed by more than the max change ratio"
+            );
+        }
+
+        gas_limit = next;
+    }
+
+    gas_limit
+}
+
+/// Checks that sustained full blocks raise the gas limit up to its maximum, where it stays.
+#[test]
+fn test_gas_limit_converges_to_max_under_full_blocks() {
+    let policy = adaptive_policy();
+    let GasLimitPolicy::Adaptive(adaptive) = &policy else {
+        unreachable!()
+    };
+
+    let gas_limit = run_blocks(&policy, 100, |gas_limit| gas_limit.clone());
+
+    assert_eq!(gas_limit.as_slice(), adaptive.max_gas_limit.as_slice());
+}
+
+/// Checks that sustained empty blocks lower the gas limit down to its minimum, where it stays.
+#[test]
+fn test_gas_limit_converges_to_min_under_empty_blocks() {
+    let policy = adaptive_policy();
+    let GasLimitPolicy::Adaptive(adaptive) = &policy else {
+        unreachable!()
+    };
+
+    let gas_limit = run_blocks(&policy, 100, |_| Gas::ZEROED);
+
+    assert_eq!(gas_limit.as_slice(), adaptive.min_gas_limit.as_slice());
+}
+
+/// Checks that the gas limit doesn't change when blocks exactly hit the gas target, and that the
+/// constant policy never changes it.
+#[test]
+fn test_gas_limit_is_stable_at_target() {
+    let at_target = |gas_limit: &Gas| ChainState::<TestSpec, MockDaSpec>::gas_target(gas_limit);
+
+    assert_eq!(
+        run_blocks(&adaptive_policy(), 10, at_target),
+        initial_gas_limit()
+    );
+    assert_eq!(
+        run_blocks(&GasLimitPolicy::Constant, 10, |gas_limit| gas_limit.clone()),
+        initial_gas_limit()
+    );
+}
+
+/// Checks that inconsistent policies are rejected.
+#[test]
+fn test_invalid_gas_limit_policy() {
+    let GasLimitPolicy::Adaptive(valid) = adaptive_policy() else {
+        unreachable!()
+    };
+
+    for invalid in [
+        AdaptiveGasLimit {
+            max_change_denominator: 0,
+            ..valid.clone()
+        },
+        AdaptiveGasLimit {
+            min_gas_limit: valid.max_gas_limit.clone(),
+            max_gas_limit: valid.min_gas_limit.clone(),
+            ..valid.clone()
+        },
+        AdaptiveGasLimit {
+            min_gas_limit: vec![0],
+            ..valid.clone()
+        },
+    ] {
+        assert!(GasLimitPolicy::Adaptive(invalid).validate::<Gas>().is_err());
+    }
+
+    assert!(GasLimitPolicy::Adaptive(valid).validate::<Gas>().is_ok());
+}
@@ -1,4 +1,6 @@
+mod base_fee_oracle;
 mod config;
 mod gas_elasticity_multidimensional;
+mod gas_limit_policy;
 
 mod gas_elasticity_unidimensional;
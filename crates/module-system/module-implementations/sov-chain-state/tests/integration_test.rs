@@ -1,16 +1,29 @@
 use std::convert::Infallible;
 
 use sov_chain_state::{
-    BlockGasInfo, ChainState, ChainStateConfig, StateTransition, TransitionInProgress,
+    AuditLogEntry, BlockGasInfo, ChainState, ChainStateConfig, PrivilegedAction,
+    PrivilegedActionError, StateTransition, TransitionInProgress, ValidityConditionRangeError,
+    GENESIS_TRANSITION_HEIGHT,
+};
+use sov_mock_da::{
+    MockBlock, MockBlockHeader, MockDaSpec, MockValidityCond, MockValidityCondChecker,
 };
-use sov_mock_da::{MockBlock, MockBlockHeader, MockDaSpec, MockValidityCond};
 use sov_mock_zkvm::MockCodeCommitment;
 use sov_modules_api::da::Time;
+use sov_modules_api::prelude::axum::body::Body;
+use sov_modules_api::prelude::axum::http::{Request, StatusCode};
+use sov_modules_api::prelude::{axum, serde_json, UnwrapInfallible};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
 use sov_modules_api::runtime::capabilities::mocks::MockKernel;
-use sov_modules_api::{Gas, GasArray, KernelModule, KernelWorkingSet, Spec, StateCheckpoint};
+use sov_modules_api::{
+    Context, Gas, GasArray, KernelModule, KernelWorkingSet, Spec, StateCheckpoint,
+    ValidityConditionChecker,
+};
 use sov_prover_storage_manager::SimpleStorageManager;
+use sov_state::codec::{BcsCodec, StateItemEncoder};
 use sov_state::{Storage, StorageRoot};
-use sov_test_utils::{TestSpec, TestStorageSpec as StorageSpec};
+use sov_test_utils::{TestAddress, TestSpec, TestStorageSpec as StorageSpec};
+use tower::ServiceExt;
 
 const INITIAL_BASE_FEE_PER_GAS: [u64; 2] = [100, 100];
 const NUM_ROUNDS: u8 = 4;
@@ -44,6 +57,21 @@ type InitVars = (
 /// Helper function that initializes the hooks test. It creates and configures a simple chain state with [`INITIAL_BASE_FEE_PER_GAS`] base fee per gas.
 /// Then it runs and commits the genesis state and returns a [`ChainState`] object,  the `genesis_root` (as a [`StorageRoot`]) and the `storage` (which is a [`ProverStorage`]).
 fn init_test() -> Result<InitVars, Infallible> {
+    init_test_with_config(ChainStateConfig {
+        current_time: Default::default(),
+        genesis_da_height: 0,
+        inner_code_commitment: MockCodeCommitment::default(),
+        outer_code_commitment: MockCodeCommitment::default(),
+        state_access_budget: Default::default(),
+        base_fee_policy: Default::default(),
+        gas_limit_policy: Default::default(),
+        transition_retention_window: None,
+        admin: None,
+    })
+}
+
+/// Same as [`init_test`], with the provided genesis configuration.
+fn init_test_with_config(config: ChainStateConfig<TestSpec>) -> Result<InitVars, Infallible> {
     // The initial height can be any value.
     // Initialize the module.
     let tmpdir = tempfile::tempdir().unwrap();
@@ -53,12 +81,6 @@ fn init_test() -> Result<InitVars, Infallible> {
     let mut state_checkpoint = StateCheckpoint::new(storage.clone());
 
     let chain_state = ChainState::<TestSpec, MockDaSpec>::default();
-    let config = ChainStateConfig {
-        current_time: Default::default(),
-        genesis_da_height: 0,
-        inner_code_commitment: MockCodeCommitment::default(),
-        outer_code_commitment: MockCodeCommitment::default(),
-    };
 
     // Genesis, initialize and then commit the state
     chain_state
@@ -313,7 +335,7 @@ fn simulate_chain_state_execution_n_rounds(
     genesis_root: StorageRoot<StorageSpec>,
     test_batch_infos: Vec<TestBatchInfo>,
     chain_state: &ChainState<TestSpec, MockDaSpec>,
-    mut storage_manager: SimpleStorageManager<StorageSpec>,
+    storage_manager: &mut SimpleStorageManager<StorageSpec>,
 ) -> Result<(), Infallible> {
     assert!(!test_batch_infos.is_empty());
 
@@ -378,7 +400,7 @@ fn simulate_chain_state_execution_n_rounds(
 fn test_simple_chain_state_one_round_at_gas_target() -> Result<(), Infallible> {
     // Initialize the test: create and configure a simple chain state with [`INITIAL_BASE_FEE_PER_GAS`] base fee per gas.
     // Then run and commit the genesis state and return the `storage_manager` and `genesis_root`.
-    let (chain_state, genesis_root, storage_manager) = init_test()?;
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
 
     // Then simulate a transaction execution: call the begin_slot hook on a mock slot_data.
     simulate_chain_state_execution_n_rounds(
@@ -388,7 +410,7 @@ fn test_simple_chain_state_one_round_at_gas_target() -> Result<(), Infallible> {
             validity_cond: MockValidityCond { is_valid: true },
         }],
         &chain_state,
-        storage_manager,
+        &mut storage_manager,
     )
 }
 
@@ -404,7 +426,7 @@ fn test_simple_chain_state_one_round() -> Result<(), Infallible> {
 
     // Initialize the test: create and configure a simple chain state with [`INITIAL_BASE_FEE_PER_GAS`] base fee per gas.
     // Then run and commit the genesis state and returns the `storage` and `genesis_root`.
-    let (chain_state, genesis_root, storage_manager) = init_test()?;
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
 
     // Then simulate a transaction execution: call the begin_slot hook on a mock slot_data.
     simulate_chain_state_execution_n_rounds(
@@ -416,7 +438,7 @@ fn test_simple_chain_state_one_round() -> Result<(), Infallible> {
             validity_cond: MockValidityCond { is_valid: true },
         }],
         &chain_state,
-        storage_manager,
+        &mut storage_manager,
     )
 }
 
@@ -430,7 +452,7 @@ fn test_simple_chain_state_one_round() -> Result<(), Infallible> {
 fn test_simple_chain_state_at_gas_target() -> Result<(), Infallible> {
     // Initialize the test: create and configure a simple chain state with [`INITIAL_BASE_FEE_PER_GAS`] base fee per gas.
     // Then run and commit the genesis state and returns the `storage` and `genesis_root`.
-    let (chain_state, genesis_root, storage_manager) = init_test()?;
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
 
     let test_batch_info = vec![
         TestBatchInfo {
@@ -445,7 +467,7 @@ fn test_simple_chain_state_at_gas_target() -> Result<(), Infallible> {
         genesis_root,
         test_batch_info,
         &chain_state,
-        storage_manager,
+        &mut storage_manager,
     )
 }
 
@@ -459,7 +481,7 @@ fn test_simple_chain_state_at_gas_target() -> Result<(), Infallible> {
 fn test_simple_chain_state() -> Result<(), Infallible> {
     // Initialize the test: create and configure a simple chain state with [`INITIAL_BASE_FEE_PER_GAS`] base fee per gas.
     // Then run and commit the genesis state and returns the `storage` and `genesis_root`.
-    let (chain_state, genesis_root, storage_manager) = init_test()?;
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
 
     let mut test_batch_info = vec![
         TestBatchInfo {
@@ -486,6 +508,448 @@ fn test_simple_chain_state() -> Result<(), Infallible> {
         genesis_root,
         test_batch_info,
         &chain_state,
-        storage_manager,
+        &mut storage_manager,
     )
 }
+
+/// Checks that calling [`ChainState::begin_slot_hook`] twice with the same slot header (e.g. when a slot is replayed
+/// after a crash) only advances the slot number once and keeps the transition in progress unchanged.
+#[test]
+fn test_begin_slot_twice_with_same_header_advances_once() -> Result<(), Infallible> {
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
+
+    let slot_header = MockBlockHeader {
+        prev_hash: [0; 32].into(),
+        hash: [1; 32].into(),
+        height: 1,
+        time: Time::now(),
+    };
+    let validity_cond = MockValidityCond { is_valid: true };
+
+    let storage = storage_manager.create_storage();
+    let mut state_checkpoint = StateCheckpoint::new(storage.clone());
+    let mut kernel_working_set = build_kernel_working_set(1, &mut state_checkpoint);
+
+    let first_base_fee_per_gas = chain_state.begin_slot_hook(
+        &slot_header,
+        &validity_cond,
+        &genesis_root,
+        &mut kernel_working_set,
+    );
+    let first_transition = chain_state.get_in_progress_transition(&mut kernel_working_set)?;
+
+    let second_base_fee_per_gas = chain_state.begin_slot_hook(
+        &slot_header,
+        &validity_cond,
+        &genesis_root,
+        &mut kernel_working_set,
+    );
+
+    assert_eq!(
+        chain_state.true_slot_number(&mut kernel_working_set)?,
+        1,
+        "The slot number should only be advanced once"
+    );
+    assert_eq!(first_base_fee_per_gas, second_base_fee_per_gas);
+    assert_eq!(
+        chain_state.get_in_progress_transition(&mut kernel_working_set)?,
+        first_transition,
+        "The transition in progress should not be modified by the second call"
+    );
+    assert_eq!(
+        chain_state.get_historical_transitions(1, kernel_working_set.inner)?,
+        None,
+        "No transition should be recorded for the replayed slot"
+    );
+
+    // The next slot still advances the slot number.
+    let next_slot_header = MockBlockHeader {
+        prev_hash: [1; 32].into(),
+        hash: [2; 32].into(),
+        height: 2,
+        time: Time::now(),
+    };
+    chain_state.begin_slot_hook(
+        &next_slot_header,
+        &validity_cond,
+        &genesis_root,
+        &mut kernel_working_set,
+    );
+
+    assert_eq!(chain_state.true_slot_number(&mut kernel_working_set)?, 2);
+
+    Ok(())
+}
+
+/// Checks that, with a retention window, only the most recent transitions and the first one are kept.
+#[test]
+fn test_transition_retention_window() -> Result<(), Infallible> {
+    const RETENTION_WINDOW: u64 = 2;
+
+    let (chain_state, genesis_root, mut storage_manager) =
+        init_test_with_config(ChainStateConfig {
+            current_time: Default::default(),
+            genesis_da_height: 0,
+            inner_code_commitment: MockCodeCommitment::default(),
+            outer_code_commitment: MockCodeCommitment::default(),
+            state_access_budget: Default::default(),
+            base_fee_policy: Default::default(),
+            gas_limit_policy: Default::default(),
+            transition_retention_window: Some(RETENTION_WINDOW),
+            admin: None,
+        })?;
+
+    // Transitions are recorded with a delay of one slot, so this records transitions 1 to 5.
+    let test_batch_info = TestBatchInfo {
+        gas_to_use: ChainState::<TestSpec, MockDaSpec>::initial_gas_target(),
+        validity_cond: MockValidityCond { is_valid: true },
+    };
+    simulate_chain_state_execution_n_rounds(
+        genesis_root,
+        vec![test_batch_info; 6],
+        &chain_state,
+        &mut storage_manager,
+    )?;
+
+    let mut state = StateCheckpoint::<TestSpec>::new(storage_manager.create_storage());
+    let retained_heights = (1..=6)
+        .filter(|height| {
+            chain_state
+                .get_historical_transitions(*height, &mut state)
+                .unwrap_infallible()
+                .is_some()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(retained_heights, vec![GENESIS_TRANSITION_HEIGHT, 4, 5]);
+
+    Ok(())
+}
+
+/// Checks that combining the validity conditions of a range of transitions detects a single invalid condition.
+#[test]
+fn test_combined_validity_condition() -> Result<(), Infallible> {
+    const INVALID_HEIGHT: u64 = 3;
+
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
+    let storage = storage_manager.create_storage();
+    let mut state_checkpoint = StateCheckpoint::new(storage.clone());
+    let mut kernel_working_set = build_kernel_working_set(1, &mut state_checkpoint);
+
+    // Transitions are recorded with a delay of one slot, so this records transitions 1 to 4.
+    for height in 1..=5u8 {
+        let slot_header = MockBlockHeader {
+            prev_hash: [height - 1; 32].into(),
+            hash: [height; 32].into(),
+            height: height.into(),
+            time: Time::now(),
+        };
+        chain_state.begin_slot_hook(
+            &slot_header,
+            &MockValidityCond {
+                is_valid: u64::from(height) != INVALID_HEIGHT,
+            },
+            &genesis_root,
+            &mut kernel_working_set,
+        );
+    }
+
+    let mut checker = MockValidityCondChecker::<MockValidityCond>::new();
+    let mut combined = |from, to| {
+        chain_state
+            .combined_validity_condition(from, to, kernel_working_set.inner)
+            .unwrap_infallible()
+    };
+
+    let before_invalid = combined(1, INVALID_HEIGHT).unwrap();
+    assert!(checker.check(&before_invalid).is_ok());
+    let after_invalid = combined(INVALID_HEIGHT + 1, 5).unwrap();
+    assert!(checker.check(&after_invalid).is_ok());
+
+    let with_invalid = combined(1, 5).unwrap();
+    assert!(checker.check(&with_invalid).is_err());
+    let only_invalid = combined(INVALID_HEIGHT, INVALID_HEIGHT + 1).unwrap();
+    assert!(checker.check(&only_invalid).is_err());
+
+    // The transition of the last slot isn't recorded yet.
+    assert!(matches!(
+        combined(1, 6),
+        Err(ValidityConditionRangeError::MissingTransition(5))
+    ));
+    assert!(matches!(
+        combined(2, 2),
+        Err(ValidityConditionRangeError::EmptyRange { from: 2, to: 2 })
+    ));
+
+    Ok(())
+}
+
+async fn get(router: axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = router
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+/// Checks that the recorded state transitions are served by `GET /transitions/:height`, and that
+/// heights without a recorded transition return a 404.
+#[tokio::test(flavor = "multi_thread")]
+async fn transitions_rest_api() -> Result<(), Infallible> {
+    let (chain_state, genesis_root, mut storage_manager) = init_test()?;
+
+    // Transitions are recorded with a delay of one slot, so this records transitions 1 and 2.
+    let test_batch_info = TestBatchInfo {
+        gas_to_use: ChainState::<TestSpec, MockDaSpec>::initial_gas_target(),
+        validity_cond: MockValidityCond { is_valid: true },
+    };
+    simulate_chain_state_execution_n_rounds(
+        genesis_root,
+        vec![test_batch_info; 3],
+        &chain_state,
+        &mut storage_manager,
+    )?;
+
+    let storage = storage_manager.create_storage();
+    let expected_transition = chain_state
+        .get_historical_transitions(2, &mut StateCheckpoint::<TestSpec>::new(storage.clone()))?
+        .unwrap();
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage);
+    let router = chain_state.custom_rest_api(ApiState::new(chain_state.clone(), receiver));
+
+    let (status, body) = get(router.clone(), "/transitions/2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["height"], 2);
+    assert_eq!(
+        body["data"]["transition"],
+        serde_json::to_value(&expected_transition).unwrap()
+    );
+    assert_eq!(
+        body["data"]["encoded"],
+        format!("0x{}", hex::encode(BcsCodec.encode(&expected_transition)))
+    );
+
+    // The transition of the last slot isn't recorded yet.
+    let (status, _) = get(router.clone(), "/transitions/3").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = get(router, "/transitions/100").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+/// Checks that transition ranges skip the pruned heights, and that `GET /transitions` pages over them.
+#[tokio::test(flavor = "multi_thread")]
+async fn transitions_range() -> Result<(), Infallible> {
+    let (chain_state, genesis_root, mut storage_manager) =
+        init_test_with_config(ChainStateConfig {
+            current_time: Default::default(),
+            genesis_da_height: 0,
+            inner_code_commitment: MockCodeCommitment::default(),
+            outer_code_commitment: MockCodeCommitment::default(),
+            state_access_budget: Default::default(),
+            base_fee_policy: Default::default(),
+            gas_limit_policy: Default::default(),
+            transition_retention_window: Some(2),
+            admin: None,
+        })?;
+
+    // This records transitions 1 to 5, out of which 2 and 3 are pruned.
+    let test_batch_info = TestBatchInfo {
+        gas_to_use: ChainState::<TestSpec, MockDaSpec>::initial_gas_target(),
+        validity_cond: MockValidityCond { is_valid: true },
+    };
+    simulate_chain_state_execution_n_rounds(
+        genesis_root,
+        vec![test_batch_info; 6],
+        &chain_state,
+        &mut storage_manager,
+    )?;
+
+    let storage = storage_manager.create_storage();
+    let mut state = StateCheckpoint::<TestSpec>::new(storage.clone());
+    let heights = |transitions: Vec<(u64, StateTransition<TestSpec, MockDaSpec>)>| {
+        transitions
+            .into_iter()
+            .map(|(height, _)| height)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        heights(chain_state.get_historical_transitions_range(1, 6, &mut state)?),
+        vec![1, 4, 5]
+    );
+    // The upper bound is exclusive.
+    assert_eq!(
+        heights(chain_state.get_historical_transitions_range(4, 5, &mut state)?),
+        vec![4]
+    );
+    assert!(chain_state
+        .get_historical_transitions_range(2, 4, &mut state)?
+        .is_empty());
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage);
+    let router = chain_state.custom_rest_api(ApiState::new(chain_state.clone(), receiver));
+
+    // Pages cover `page[size]` heights, up to the current slot by default.
+    let mut pages = Vec::new();
+    let mut uri = "/transitions?page%5Bsize%5D=2".to_string();
+    loop {
+        let (status, body) = get(router.clone(), &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        pages.push(
+            body["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|transition| transition["height"].as_u64().unwrap())
+                .collect::<Vec<_>>(),
+        );
+
+        match body["meta"]["cursor"].as_str() {
+            Some(cursor) => {
+                uri = format!("/transitions?page%5Bsize%5D=2&page%5Bafter%5D={}", cursor)
+            }
+            None => break,
+        }
+    }
+    assert_eq!(pages, vec![vec![1], vec![4], vec![5]]);
+
+    let (status, body) = get(router.clone(), "/transitions?from=4&to=5").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(body["data"][0]["height"], 4);
+
+    let (status, _) = get(router, "/transitions?from=5&to=4").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+/// Checks that rotating the code commitments is restricted to the admin, and that the rotation
+/// appends an entry to the audit log at the end of the slot, served by `GET /admin/audit-log`.
+#[tokio::test(flavor = "multi_thread")]
+async fn privileged_operations_are_audited() -> Result<(), Infallible> {
+    let admin = TestAddress::from([1; 32]);
+    let (chain_state, genesis_root, mut storage_manager) =
+        init_test_with_config(ChainStateConfig {
+            current_time: Default::default(),
+            genesis_da_height: 0,
+            inner_code_commitment: MockCodeCommitment::default(),
+            outer_code_commitment: MockCodeCommitment::default(),
+            state_access_budget: Default::default(),
+            base_fee_policy: Default::default(),
+            gas_limit_policy: Default::default(),
+            transition_retention_window: None,
+            admin: Some(admin),
+        })?;
+
+    let storage = storage_manager.create_storage();
+    let mut state_checkpoint = StateCheckpoint::new(storage.clone());
+    chain_state.begin_slot_hook(
+        &MockBlockHeader {
+            prev_hash: [0; 32].into(),
+            hash: [1; 32].into(),
+            height: 1,
+            time: Time::now(),
+        },
+        &MockValidityCond { is_valid: true },
+        &genesis_root,
+        &mut build_kernel_working_set(1, &mut state_checkpoint),
+    );
+
+    let new_commitment = MockCodeCommitment([2; 32]);
+    let mut working_set = state_checkpoint.to_working_set_unmetered();
+
+    // Only the admin can rotate the code commitments.
+    let intruder = TestAddress::from([3; 32]);
+    let err = chain_state
+        .rotate_code_commitments(
+            new_commitment.clone(),
+            new_commitment.clone(),
+            &Context::new(intruder, Default::default(), intruder, 1),
+            &mut working_set,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<PrivilegedActionError>(),
+        Some(&PrivilegedActionError::Unauthorized {
+            sender: intruder.to_string()
+        })
+    );
+
+    chain_state
+        .rotate_code_commitments(
+            new_commitment.clone(),
+            new_commitment.clone(),
+            &Context::new(admin, Default::default(), admin, 1),
+            &mut working_set,
+        )
+        .unwrap();
+
+    let mut state_checkpoint = working_set.checkpoint().0;
+    assert_eq!(
+        chain_state.inner_code_commitment(&mut state_checkpoint)?,
+        Some(new_commitment.clone())
+    );
+
+    // The entry is only appended to the audit log at the end of the slot.
+    assert_eq!(
+        chain_state.audit_log_len(&mut state_checkpoint.accessory_state())?,
+        0
+    );
+    chain_state.end_slot_hook(
+        &<TestSpec as Spec>::Gas::zero(),
+        &mut build_kernel_working_set(1, &mut state_checkpoint),
+    );
+
+    let expected_entry = AuditLogEntry::<TestSpec> {
+        slot: 1,
+        actor: admin,
+        action: PrivilegedAction::RotateCodeCommitments {
+            inner_code_commitment: new_commitment.clone(),
+            outer_code_commitment: new_commitment,
+        },
+    };
+    assert_eq!(
+        chain_state.audit_log_entries(0, 1, &mut state_checkpoint.accessory_state())?,
+        vec![(0, expected_entry.clone())]
+    );
+
+    let (reads_writes, accessory_writes, witness) = state_checkpoint.freeze();
+    let (_, change_set) = storage
+        .validate_and_materialize_with_accessory_update(
+            reads_writes,
+            &witness,
+            accessory_writes.freeze(),
+        )
+        .unwrap();
+    storage_manager.commit(change_set);
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = chain_state.custom_rest_api(ApiState::new(chain_state.clone(), receiver));
+
+    let (status, body) = get(router, "/admin/audit-log").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"][0]["index"], 0);
+    assert_eq!(body["data"][0]["slot"], 1);
+    assert_eq!(
+        body["data"][0]["actor"],
+        serde_json::to_value(admin).unwrap()
+    );
+    assert_eq!(
+        body["data"][0]["action"],
+        serde_json::to_value(&expected_entry.action).unwrap()
+    );
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(body["meta"]["cursor"], serde_json::Value::Null);
+
+    Ok(())
+}
@@ -47,6 +47,7 @@ pub fn authenticate<S: Spec, Meter: GasMeter<S::Gas>>(
         max_priority_fee_bips,
         max_fee,
         gas_limit,
+        valid_until_slot: None,
     };
 
     let tx_and_raw_hash = AuthenticatedTransactionAndRawHash {
@@ -49,24 +49,35 @@ pub(crate) fn create_tx_env(tx: &TransactionSignedNoHash, signer: Address) -> Tx
     }
 }
 
-/// Error that happened during conversion between types
-#[derive(Debug, Error)]
+/// Error that happened during conversion between types.
+///
+/// Serialized as the `data` of the JSON-RPC error returned by `eth_sendRawTransaction`,
+/// so that users can tell what exactly is wrong with their transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Error, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum RlpConversionError {
     /// Raw transaction is empty.
+    #[error("Empty raw transaction")]
     EmptyRawTx,
-    /// Deserialization has failed.
-    DeserializationFailed,
+    /// The transaction envelope has a type that is not supported.
+    #[error("Unsupported transaction type: {tx_type:#04x}")]
+    UnsupportedTxType {
+        /// The type byte of the envelope.
+        tx_type: u8,
+    },
+    /// The transaction is not valid RLP, or one of its fields is malformed.
+    #[error("Malformed RLP: {reason}")]
+    MalformedRlp {
+        /// The decoding error.
+        reason: String,
+    },
+    /// The signer cannot be recovered from the transaction signature.
+    #[error("Invalid transaction signature")]
+    InvalidSignature,
 }
 
-impl core::fmt::Display for RlpConversionError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            RlpConversionError::EmptyRawTx => write!(f, "Empty raw transaction"),
-            RlpConversionError::DeserializationFailed => write!(f, "Deserialization failed"),
-        }
-    }
-}
-// And convert it to original EthApiError ourselves or directly to RPC
+/// Typed transaction envelopes supported by the decoder: EIP-2930, EIP-1559 and EIP-4844.
+const SUPPORTED_TX_TYPES: [u8; 3] = [0x01, 0x02, 0x03];
 
 impl TryFrom<RlpEvmTransaction> for TransactionSignedNoHash {
     type Error = RlpConversionError;
@@ -78,8 +89,20 @@ impl TryFrom<RlpEvmTransaction> for TransactionSignedNoHash {
             return Err(RlpConversionError::EmptyRawTx);
         }
 
-        let transaction = TransactionSigned::decode_enveloped(&mut data.as_ref())
-            .map_err(|_| RlpConversionError::DeserializationFailed)?;
+        // Legacy transactions are RLP lists, which start with a byte >= 0xc0.
+        // Anything below 0x80 is the type byte of an EIP-2718 envelope.
+        let first_byte = data[0];
+        if first_byte < 0x80 && !SUPPORTED_TX_TYPES.contains(&first_byte) {
+            return Err(RlpConversionError::UnsupportedTxType {
+                tx_type: first_byte,
+            });
+        }
+
+        let transaction = TransactionSigned::decode_enveloped(&mut data.as_ref()).map_err(|e| {
+            RlpConversionError::MalformedRlp {
+                reason: e.to_string(),
+            }
+        })?;
 
         Ok(transaction.into())
     }
@@ -93,7 +116,7 @@ impl TryFrom<RlpEvmTransaction> for TransactionSignedEcRecovered {
         let tx: TransactionSigned = tx.into();
         let tx = tx
             .into_ecrecovered()
-            .ok_or(RlpConversionError::DeserializationFailed)?;
+            .ok_or(RlpConversionError::InvalidSignature)?;
 
         Ok(tx)
     }
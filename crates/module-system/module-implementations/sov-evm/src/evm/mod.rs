@@ -20,6 +20,7 @@ pub(crate) mod primitive_types;
 #[cfg(test)]
 mod tests;
 
+pub use conversions::RlpConversionError;
 pub use primitive_types::RlpEvmTransaction;
 use sov_state::codec::BcsCodec;
 
@@ -22,6 +22,10 @@ pub enum EthApiError {
     /// When decoding a signed transaction fails
     #[error("Failed to decode signed transaction")]
     FailedToDecodeSignedTransaction,
+    /// When a raw transaction cannot be converted into a signed transaction. Unlike
+    /// [`EthApiError::FailedToDecodeSignedTransaction`], this carries the exact reason.
+    #[error(transparent)]
+    RawTransactionConversion(RlpConversionError),
     /// When the transaction signature is invalid
     #[error("Invalid transaction signature")]
     InvalidTransactionSignature,
@@ -41,6 +45,9 @@ pub enum EthApiError {
     /// When an invalid block range is provided
     #[error("Invalid block range")]
     InvalidBlockRange,
+    /// When a log query spans more blocks than the configured maximum
+    #[error("query exceeds max block range {0}")]
+    QueryExceedsMaxBlocks(u64),
     /// An internal error where prevrandao is not set in the evm's environment
     #[error("Prevrandao not in the EVM's environment after merge")]
     PrevrandaoNotSet,
@@ -112,6 +119,11 @@ impl From<EthApiError> for jsonrpsee::types::ErrorObject<'static> {
             | EthApiError::Signing(_)
             | EthApiError::BothStateAndStateDiffInOverride(_)
             | EthApiError::InvalidTracerConfig => invalid_params_rpc_err(error.to_string()),
+            EthApiError::RawTransactionConversion(err) => rpc_err_with_json_data(
+                jsonrpsee::types::error::INVALID_PARAMS_CODE,
+                err.to_string(),
+                &err,
+            ),
             EthApiError::InvalidTransaction(err) => err.into(),
             EthApiError::PoolError(err) => err.into(),
             EthApiError::PrevrandaoNotSet
@@ -126,6 +138,10 @@ impl From<EthApiError> for jsonrpsee::types::ErrorObject<'static> {
                     error.to_string(),
                 )
             }
+            err @ EthApiError::QueryExceedsMaxBlocks(_) => rpc_error_with_code(
+                reth_rpc_types::error::EthRpcErrorCode::InvalidInput.code(),
+                err.to_string(),
+            ),
             EthApiError::UnknownSafeOrFinalizedBlock => rpc_error_with_code(
                 reth_rpc_types::error::EthRpcErrorCode::UnknownBlock.code(),
                 error.to_string(),
@@ -163,12 +179,7 @@ impl From<EVMError<Infallible>> for EthApiError {
 
 impl From<RlpConversionError> for EthApiError {
     fn from(value: RlpConversionError) -> Self {
-        match value {
-            RlpConversionError::EmptyRawTx => EthApiError::EmptyRawTransactionData,
-            RlpConversionError::DeserializationFailed => {
-                EthApiError::FailedToDecodeSignedTransaction
-            }
-        }
+        EthApiError::RawTransactionConversion(value)
     }
 }
 
@@ -597,6 +608,15 @@ fn rpc_err(
     )
 }
 
+/// Constructs a JSON-RPC error whose `data` is the JSON serialization of `data`.
+fn rpc_err_with_json_data(
+    code: i32,
+    msg: impl Into<String>,
+    data: &impl serde::Serialize,
+) -> jsonrpsee::types::error::ErrorObject<'static> {
+    jsonrpsee::types::error::ErrorObject::owned(code, msg.into(), Some(data))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
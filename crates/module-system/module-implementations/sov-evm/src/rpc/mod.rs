@@ -2,6 +2,7 @@ use error::{ensure_success, RevertError};
 pub use error::{EthApiError, EthResult, RpcInvalidTransactionError};
 use jsonrpsee::core::RpcResult;
 use reth_primitives::{TransactionKind, TransactionSignedEcRecovered, U128, U64};
+use reth_rpc_types::{BlockNumberOrTag, Filter, FilterBlockOption, FilteredParams};
 use revm::primitives::{
     Address, EVMError, ExecutionResult, HaltReason, InvalidTransaction, TransactTo, B256,
     KECCAK_EMPTY, U256,
@@ -244,23 +245,6 @@ impl<S: sov_modules_api::Spec> Evm<S> {
         Ok(code)
     }
 
-    /// Handler for: `eth_feeHistory`
-    // TODO https://github.com/Sovereign-Labs/sovereign-sdk/issues/502
-    #[rpc_method(name = "eth_feeHistory")]
-    pub fn fee_history(&self) -> RpcResult<reth_rpc_types::FeeHistory> {
-        debug!("EVM module JSON-RPC request to `eth_feeHistory`");
-
-        Ok(reth_rpc_types::FeeHistory {
-            base_fee_per_gas: Default::default(),
-            gas_used_ratio: Default::default(),
-            oldest_block: Default::default(),
-            reward: Default::default(),
-            blob_gas_used_ratio: Default::default(),
-            // EIP-4844 related
-            base_fee_per_blob_gas: Default::default(),
-        })
-    }
-
     /// Handler for: `eth_getTransactionByHash`
     #[rpc_method(name = "eth_getTransactionByHash")]
     pub fn get_transaction_by_hash(
@@ -617,6 +601,144 @@ impl<S: sov_modules_api::Spec> Evm<S> {
 }
 
 impl<S: sov_modules_api::Spec> Evm<S> {
+    /// Returns the logs matching `filter`, as expected by `eth_getLogs`.
+    ///
+    /// Blocks whose logs bloom doesn't match the filter are skipped without reading their
+    /// receipts. Ranges ending after the head block are clamped to it, and ranges starting
+    /// after it match no logs. Queries spanning more than `max_block_range` blocks are
+    /// rejected with [`EthApiError::QueryExceedsMaxBlocks`].
+    pub fn get_logs(
+        &self,
+        filter: Filter,
+        max_block_range: u64,
+        state: &mut ApiStateAccessor<S>,
+    ) -> EthResult<Vec<reth_rpc_types::Log>> {
+        let head = self
+            .blocks
+            .last(state)
+            .unwrap_infallible()
+            .ok_or(EthApiError::UnknownBlockNumber)?
+            .header
+            .number;
+
+        let (from_block, to_block) = match filter.block_option {
+            FilterBlockOption::AtBlockHash(hash) => {
+                let number = self
+                    .block_hashes
+                    .get(&hash, state)
+                    .unwrap_infallible()
+                    .ok_or(EthApiError::UnknownBlockNumber)?;
+                (number, number)
+            }
+            FilterBlockOption::Range {
+                from_block,
+                to_block,
+            } => {
+                let resolve = |block: Option<BlockNumberOrTag>| match block {
+                    Some(BlockNumberOrTag::Earliest) => 0,
+                    Some(BlockNumberOrTag::Number(number)) => number,
+                    // safe, finalized and pending are the same as latest
+                    _ => head,
+                };
+                (resolve(from_block), resolve(to_block))
+            }
+        };
+
+        if from_block > head {
+            return Ok(Vec::new());
+        }
+        let to_block = to_block.min(head);
+
+        if from_block > to_block {
+            return Err(EthApiError::InvalidBlockRange);
+        }
+        if to_block - from_block >= max_block_range {
+            return Err(EthApiError::QueryExceedsMaxBlocks(max_block_range));
+        }
+
+        let address_filter = FilteredParams::address_filter(&filter.address);
+        let topics_filter = FilteredParams::topics_filter(&filter.topics);
+
+        let mut logs = Vec::new();
+        for block_number in from_block..=to_block {
+            let block = self
+                .blocks
+                .get(block_number as usize, state)
+                .unwrap_infallible()
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+
+            if !FilteredParams::matches_address(block.header.logs_bloom, &address_filter)
+                || !FilteredParams::matches_topics(block.header.logs_bloom, &topics_filter)
+            {
+                continue;
+            }
+
+            let block_hash = Some(block.header.hash());
+            for tx_number in block.transactions.clone() {
+                let receipt = self
+                    .receipts
+                    .get(tx_number as usize, state)
+                    .unwrap_infallible()
+                    .ok_or(EthApiError::InternalEthError)?;
+                let matching_logs = receipt
+                    .receipt
+                    .logs
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, log)| log_matches_filter(&filter, log))
+                    .collect::<Vec<_>>();
+                if matching_logs.is_empty() {
+                    continue;
+                }
+
+                let tx = self
+                    .transactions
+                    .get(tx_number as usize, state)
+                    .unwrap_infallible()
+                    .ok_or(EthApiError::InternalEthError)?;
+                let transaction_hash = Some(tx.signed_transaction.hash());
+                let transaction_index = tx_number - block.transactions.start;
+
+                logs.extend(
+                    matching_logs
+                        .into_iter()
+                        .map(|(idx, log)| reth_rpc_types::Log {
+                            address: log.address,
+                            topics: log.topics,
+                            data: log.data,
+                            block_hash,
+                            block_number: Some(U256::from(block.header.number)),
+                            transaction_hash,
+                            transaction_index: Some(U256::from(transaction_index)),
+                            log_index: Some(U256::from(receipt.log_index_start + idx as u64)),
+                            removed: false,
+                        }),
+                );
+            }
+        }
+
+        Ok(logs)
+    }
+
+    /// Returns the base fee per gas of the block following the given one, as computed when that
+    /// block is started. Used by `eth_feeHistory`, which also reports the base fee of the block
+    /// after the newest one.
+    pub fn next_block_base_fee(
+        &self,
+        block_number: Option<String>,
+        state: &mut ApiStateAccessor<S>,
+    ) -> Option<u64> {
+        let cfg = self
+            .cfg
+            .get(state)
+            .unwrap_infallible()
+            .expect("EVM config must be set at genesis");
+
+        self.get_sealed_block_by_number(block_number, state)
+            .header
+            .next_block_base_fee(cfg.base_fee_params)
+    }
+
     fn get_sealed_block_by_number(
         &self,
         block_number: Option<String>,
@@ -731,6 +853,19 @@ pub(crate) fn build_rpc_receipt(
     }
 }
 
+/// Checks a log against the address and topics of a filter, the bloom of its block having matched
+/// already. An empty set matches anything, and a topic is matched by position.
+fn log_matches_filter(filter: &Filter, log: &reth_primitives::Log) -> bool {
+    filter.address.matches(&log.address)
+        && filter.topics.iter().enumerate().all(|(position, topics)| {
+            topics.is_empty()
+                || log
+                    .topics
+                    .get(position)
+                    .is_some_and(|topic| topics.matches(topic))
+        })
+}
+
 fn map_out_of_gas_err<Ws: InfallibleStateAccessor>(
     block_env: BlockEnv,
     mut tx_env: revm::primitives::TxEnv,
@@ -753,3 +888,44 @@ fn map_out_of_gas_err<Ws: InfallibleStateAccessor>(
         ExecutionResult::Halt { reason, .. } => RpcInvalidTransactionError::EvmHalt(reason).into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reth_primitives::{Bytes, Log};
+
+    use super::*;
+
+    #[test]
+    fn logs_are_matched_by_address_and_topic_position() {
+        let address = Address::repeat_byte(1);
+        let (event, arg) = (B256::repeat_byte(2), B256::repeat_byte(3));
+        let log = Log {
+            address,
+            topics: vec![event, arg],
+            data: Bytes::default(),
+        };
+
+        for matching in [
+            Filter::new(),
+            Filter::new().address(address),
+            Filter::new().address(vec![Address::ZERO, address]),
+            Filter::new().event_signature(event).topic1(arg),
+            Filter::new().topic1(arg),
+        ] {
+            assert!(log_matches_filter(&matching, &log), "{:?}", matching);
+        }
+
+        for not_matching in [
+            Filter::new().address(Address::ZERO),
+            Filter::new().event_signature(arg),
+            Filter::new().topic1(event),
+            Filter::new().topic2(arg),
+        ] {
+            assert!(
+                !log_matches_filter(&not_matching, &log),
+                "{:?}",
+                not_matching
+            );
+        }
+    }
+}
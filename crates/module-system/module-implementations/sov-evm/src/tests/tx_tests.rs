@@ -4,7 +4,12 @@ use ethers_core::types::transaction::eip2718::TypedTransaction;
 use ethers_core::types::{Bytes, Eip1559TransactionRequest};
 use ethers_core::utils::rlp::Rlp;
 use ethers_signers::{LocalWallet, Signer};
-use reth_primitives::{Address, TransactionSignedEcRecovered, U256, U64, U8};
+use jsonrpsee::types::error::INVALID_PARAMS_CODE;
+use jsonrpsee::types::ErrorObjectOwned;
+use reth_primitives::{
+    Address, Signature, Transaction, TransactionSigned, TransactionSignedEcRecovered, TxLegacy,
+    U256, U64, U8,
+};
 use reth_rpc_types::request::TransactionInput;
 use reth_rpc_types::TransactionRequest;
 use revm::primitives::{BlockEnv, TransactTo, TxEnv};
@@ -13,6 +18,7 @@ use sov_modules_api::macros::config_value;
 use crate::evm::primitive_types::TransactionSignedAndRecovered;
 use crate::helpers::prepare_call_env;
 use crate::primitive_types::Block;
+use crate::{EthApiError, RlpConversionError, RlpEvmTransaction};
 
 #[tokio::test]
 async fn tx_rlp_encoding_test() -> Result<(), Box<dyn std::error::Error>> {
@@ -65,6 +71,61 @@ fn tx_conversion() {
     assert_eq!(signer, reth_tx.signer());
 }
 
+/// Decodes the given raw transaction and returns the JSON-RPC error it produces.
+fn rlp_conversion_error(rlp: Vec<u8>) -> (RlpConversionError, ErrorObjectOwned) {
+    let err = TransactionSignedEcRecovered::try_from(RlpEvmTransaction { rlp }).unwrap_err();
+    (err.clone(), EthApiError::from(err).into())
+}
+
+#[test]
+fn rlp_conversion_errors_are_descriptive() {
+    let unsigned_legacy_tx = TransactionSigned::from_transaction_and_signature(
+        Transaction::Legacy(TxLegacy::default()),
+        Signature {
+            r: U256::ZERO,
+            s: U256::ZERO,
+            odd_y_parity: false,
+        },
+    );
+
+    let cases = [
+        (
+            vec![],
+            RlpConversionError::EmptyRawTx,
+            r#"{"kind":"empty_raw_tx"}"#,
+        ),
+        (
+            vec![0x05, 0xc0],
+            RlpConversionError::UnsupportedTxType { tx_type: 0x05 },
+            r#"{"kind":"unsupported_tx_type","tx_type":5}"#,
+        ),
+        (
+            // A legacy transaction whose list header announces more bytes than provided.
+            vec![0xc5, 0x01],
+            RlpConversionError::MalformedRlp {
+                reason: "input too short".to_string(),
+            },
+            r#"{"kind":"malformed_rlp","reason":"input too short"}"#,
+        ),
+        (
+            unsigned_legacy_tx.envelope_encoded().to_vec(),
+            RlpConversionError::InvalidSignature,
+            r#"{"kind":"invalid_signature"}"#,
+        ),
+    ];
+
+    let mut messages = std::collections::HashSet::new();
+    for (rlp, expected, expected_data) in cases {
+        let (err, rpc_err) = rlp_conversion_error(rlp);
+        assert_eq!(err, expected);
+
+        assert_eq!(rpc_err.code(), INVALID_PARAMS_CODE);
+        assert_eq!(rpc_err.message(), expected.to_string());
+        assert_eq!(rpc_err.data().unwrap().get(), expected_data);
+        assert!(messages.insert(rpc_err.message().to_string()));
+    }
+}
+
 // TODO: Needs more complex tests later
 #[test]
 fn prepare_call_env_conversion() {
@@ -59,8 +59,7 @@ impl<S: Spec> Collection<S> {
     ) -> anyhow::Result<(CollectionId, Collection<S>)> {
         let creator = context.sender();
         let collection_id = get_collection_id::<S>(collection_name, creator.as_ref());
-        let collection = collections.get(&collection_id, state)?;
-        if collection.is_some() {
+        if collections.contains_key(&collection_id, state)? {
             Err(anyhow!(
                 "Collection with name: {} already exists creator {}",
                 collection_name,
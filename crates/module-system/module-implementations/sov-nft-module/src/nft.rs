@@ -92,10 +92,7 @@ impl<S: Spec> Nft<S> {
         nfts: &StateMap<NftIdentifier, Nft<S>>,
         state: &mut impl StateAccessor,
     ) -> anyhow::Result<Self> {
-        if nfts
-            .get(&NftIdentifier(token_id, *collection_id), state)?
-            .is_some()
-        {
+        if nfts.contains_key(&NftIdentifier(token_id, *collection_id), state)? {
             bail!(
                 "NFT with id {} already exists for collection id {}",
                 token_id,
@@ -1,44 +1,99 @@
 use sov_modules_api::prelude::UnwrapInfallible;
-use sov_modules_api::{CredentialId, Spec, StateAccessor, TxScratchpad};
+use sov_modules_api::{CredentialId, Spec, StateAccessor, StateReader, TxScratchpad};
+use sov_state::User;
 
-use crate::Nonces;
+use crate::{NonceSpace, Nonces, DEFAULT_NONCE_SPACE};
+
+/// An error returned by [`Nonces::validate_nonce_sequence`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NonceError {
+    /// The nonce at `index` doesn't follow the stored nonce or the previous nonce of the sequence.
+    #[error(
+        "Bad nonce at index {index} of the sequence, expected: {expected}, but found: {found}"
+    )]
+    BadNonce {
+        /// The index of the first offending nonce in the sequence.
+        index: usize,
+        /// The nonce expected at `index`.
+        expected: u64,
+        /// The nonce found at `index`.
+        found: u64,
+    },
+}
 
 impl<S: Spec> Nonces<S> {
-    /// Checks the provided nonce.
+    /// Checks the provided nonce against the nonce of the credential in `nonce_space`.
     pub fn check_nonce(
         &self,
         credential_id: &CredentialId,
+        nonce_space: NonceSpace,
         nonce_to_check: u64,
         state_checkpoint: &mut impl StateAccessor,
     ) -> Result<(), anyhow::Error> {
         let senders_expected_nonce = self
-            .nonces
-            .get(credential_id, state_checkpoint)?
+            .nonce_in_space(credential_id, nonce_space, state_checkpoint)?
             .unwrap_or_default();
 
         anyhow::ensure!(
             senders_expected_nonce == nonce_to_check,
-            "Tx bad nonce for credential id: {credential_id}, expected: {senders_expected_nonce}, but found: {nonce_to_check}",
+            "Tx bad nonce for credential id: {credential_id} in nonce space: {nonce_space}, expected: {senders_expected_nonce}, but found: {nonce_to_check}",
         );
         Ok(())
     }
 
-    /// Marks a transaction as attempted, ensuring that future attempts at execution will fail
+    /// Marks a transaction as attempted, ensuring that future attempts at execution will fail.
+    /// Only the nonce of the credential in `nonce_space` is incremented.
     pub fn mark_tx_attempted(
         &self,
         credential_id: &CredentialId,
+        nonce_space: NonceSpace,
         tx_scratchpad: &mut TxScratchpad<S>,
     ) {
         let nonce = self
-            .nonces
-            .get(credential_id, tx_scratchpad)
+            .nonce_in_space(credential_id, nonce_space, tx_scratchpad)
             .unwrap_infallible()
             .unwrap_or_default();
 
         let nonce = nonce + 1;
 
-        self.nonces
-            .set(credential_id, &nonce, tx_scratchpad)
-            .unwrap_infallible();
+        if nonce_space == DEFAULT_NONCE_SPACE {
+            self.nonces
+                .set(credential_id, &nonce, tx_scratchpad)
+                .unwrap_infallible();
+        } else {
+            self.space_nonces
+                .set(&(*credential_id, nonce_space), &nonce, tx_scratchpad)
+                .unwrap_infallible();
+        }
+    }
+
+    /// Checks that `nonces` is a contiguous sequence starting at the stored nonce of the
+    /// credential in `nonce_space`, i.e. that transactions with these nonces can be executed in order. This lets
+    /// a sequencer validate the transactions of a credential before posting a batch.
+    ///
+    /// An empty sequence is always valid.
+    pub fn validate_nonce_sequence<Reader: StateReader<User>>(
+        &self,
+        credential_id: &CredentialId,
+        nonce_space: NonceSpace,
+        nonces: &[u64],
+        state: &mut Reader,
+    ) -> Result<Result<(), NonceError>, Reader::Error> {
+        let mut expected = self
+            .nonce_in_space(credential_id, nonce_space, state)?
+            .unwrap_or_default();
+
+        for (index, &found) in nonces.iter().enumerate() {
+            if found != expected {
+                return Ok(Err(NonceError::BadNonce {
+                    index,
+                    expected,
+                    found,
+                }));
+            }
+            expected = expected.saturating_add(1);
+        }
+
+        Ok(Ok(()))
     }
 }
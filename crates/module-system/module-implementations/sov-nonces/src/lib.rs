@@ -5,6 +5,7 @@ mod capabilities;
 #[cfg(feature = "native")]
 mod query;
 use call::NotInstantiable;
+pub use capabilities::NonceError;
 #[cfg(feature = "native")]
 pub use query::*;
 use sov_modules_api::{
@@ -12,6 +13,13 @@ use sov_modules_api::{
 };
 use sov_state::User;
 
+/// An independent space of nonces of a credential, for instance for rollups which keep
+/// separate nonces per chain id or per application.
+pub type NonceSpace = u32;
+
+/// The nonce space used by the standard rollup capabilities.
+pub const DEFAULT_NONCE_SPACE: NonceSpace = 0;
+
 /// A module responsible for managing nonces on the rollup.
 #[derive(Clone, ModuleInfo, sov_modules_api::macros::ModuleRestApi)]
 pub struct Nonces<S: Spec> {
@@ -19,23 +27,43 @@ pub struct Nonces<S: Spec> {
     #[id]
     pub id: ModuleId,
 
-    /// Mapping from a credential id to a nonce.
+    /// Mapping from a credential id to its nonce in the [`DEFAULT_NONCE_SPACE`].
     #[state]
     pub(crate) nonces: sov_modules_api::StateMap<CredentialId, u64>,
 
+    /// Mapping from a credential id and a nonce space to a nonce, for all the nonce spaces
+    /// except the [`DEFAULT_NONCE_SPACE`].
+    #[state]
+    #[rest_api(skip)]
+    pub(crate) space_nonces: sov_modules_api::StateMap<(CredentialId, NonceSpace), u64>,
+
     /// PhantomData
     #[phantom]
     phantom: std::marker::PhantomData<S>,
 }
 
 impl<S: Spec> Nonces<S> {
-    /// Retrieves the nonce for a given credential id.
+    /// Retrieves the nonce for a given credential id in the [`DEFAULT_NONCE_SPACE`].
     pub fn nonce<Reader: StateReader<User>>(
         &self,
         credential_id: &CredentialId,
         state: &mut Reader,
     ) -> Result<Option<u64>, Reader::Error> {
-        self.nonces.get(credential_id, state)
+        self.nonce_in_space(credential_id, DEFAULT_NONCE_SPACE, state)
+    }
+
+    /// Retrieves the nonce for a given credential id in the given nonce space.
+    pub fn nonce_in_space<Reader: StateReader<User>>(
+        &self,
+        credential_id: &CredentialId,
+        nonce_space: NonceSpace,
+        state: &mut Reader,
+    ) -> Result<Option<u64>, Reader::Error> {
+        if nonce_space == DEFAULT_NONCE_SPACE {
+            self.nonces.get(credential_id, state)
+        } else {
+            self.space_nonces.get(&(*credential_id, nonce_space), state)
+        }
     }
 }
 
@@ -1,5 +1,6 @@
-use sov_modules_api::{CredentialId, PrivateKey, PublicKey, WorkingSet};
-use sov_nonces::Nonces;
+use sov_modules_api::prelude::UnwrapInfallible;
+use sov_modules_api::{CredentialId, ModulePrefix, PrivateKey, PublicKey, StateMap, WorkingSet};
+use sov_nonces::{NonceError, Nonces, DEFAULT_NONCE_SPACE};
 use sov_prover_storage_manager::new_orphan_storage;
 use sov_test_utils::{TestHasher, TestPrivateKey};
 
@@ -17,23 +18,185 @@ fn check_hooks_test() {
     let sender_credential_id: CredentialId = sender.credential_id::<TestHasher>();
 
     assert!(nonces
-        .check_nonce(&sender_credential_id, 0, &mut working_set)
+        .check_nonce(
+            &sender_credential_id,
+            DEFAULT_NONCE_SPACE,
+            0,
+            &mut working_set
+        )
         .is_ok());
 
     assert!(nonces
-        .check_nonce(&sender_credential_id, 1, &mut working_set)
+        .check_nonce(
+            &sender_credential_id,
+            DEFAULT_NONCE_SPACE,
+            1,
+            &mut working_set
+        )
         .is_err());
 
     let (mut scratchpad, _, _) = working_set.finalize();
-    nonces.mark_tx_attempted(&sender_credential_id, &mut scratchpad);
+    nonces.mark_tx_attempted(&sender_credential_id, DEFAULT_NONCE_SPACE, &mut scratchpad);
 
     let mut working_set = scratchpad.commit().to_working_set_unmetered();
 
     assert!(nonces
-        .check_nonce(&sender_credential_id, 0, &mut working_set)
+        .check_nonce(
+            &sender_credential_id,
+            DEFAULT_NONCE_SPACE,
+            0,
+            &mut working_set
+        )
         .is_err());
 
     assert!(nonces
-        .check_nonce(&sender_credential_id, 1, &mut working_set)
+        .check_nonce(
+            &sender_credential_id,
+            DEFAULT_NONCE_SPACE,
+            1,
+            &mut working_set
+        )
+        .is_ok());
+}
+
+#[test]
+fn validate_nonce_sequence_test() {
+    let nonces = Nonces::<S>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = WorkingSet::<S>::new_deprecated(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let sender_credential_id: CredentialId = TestPrivateKey::generate()
+        .pub_key()
+        .credential_id::<TestHasher>();
+
+    let (mut scratchpad, _, _) = working_set.finalize();
+    nonces.mark_tx_attempted(&sender_credential_id, DEFAULT_NONCE_SPACE, &mut scratchpad);
+    nonces.mark_tx_attempted(&sender_credential_id, DEFAULT_NONCE_SPACE, &mut scratchpad);
+    let mut state = scratchpad.commit();
+
+    let mut validate = |sequence: &[u64]| {
+        nonces
+            .validate_nonce_sequence(
+                &sender_credential_id,
+                DEFAULT_NONCE_SPACE,
+                sequence,
+                &mut state,
+            )
+            .unwrap_infallible()
+    };
+
+    assert_eq!(validate(&[]), Ok(()));
+    assert_eq!(validate(&[2, 3, 4]), Ok(()));
+    // The sequence must start at the stored nonce.
+    assert_eq!(
+        validate(&[1, 2, 3]),
+        Err(NonceError::BadNonce {
+            index: 0,
+            expected: 2,
+            found: 1
+        })
+    );
+    // The first gap is reported.
+    assert_eq!(
+        validate(&[2, 3, 5, 7]),
+        Err(NonceError::BadNonce {
+            index: 2,
+            expected: 4,
+            found: 5
+        })
+    );
+    assert_eq!(
+        validate(&[2, 2]),
+        Err(NonceError::BadNonce {
+            index: 1,
+            expected: 3,
+            found: 2
+        })
+    );
+}
+
+#[test]
+fn nonce_spaces_test() {
+    const OTHER_NONCE_SPACE: u32 = 7;
+
+    let nonces = Nonces::<S>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = WorkingSet::<S>::new_deprecated(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let sender_credential_id: CredentialId = TestPrivateKey::generate()
+        .pub_key()
+        .credential_id::<TestHasher>();
+
+    let (mut scratchpad, _, _) = working_set.finalize();
+    nonces.mark_tx_attempted(&sender_credential_id, OTHER_NONCE_SPACE, &mut scratchpad);
+    nonces.mark_tx_attempted(&sender_credential_id, OTHER_NONCE_SPACE, &mut scratchpad);
+    let mut working_set = scratchpad.commit().to_working_set_unmetered();
+
+    // Each nonce space is incremented independently.
+    assert!(nonces
+        .check_nonce(
+            &sender_credential_id,
+            OTHER_NONCE_SPACE,
+            2,
+            &mut working_set
+        )
+        .is_ok());
+    assert!(nonces
+        .check_nonce(
+            &sender_credential_id,
+            DEFAULT_NONCE_SPACE,
+            0,
+            &mut working_set
+        )
+        .is_ok());
+
+    // The single-argument accessor reads the default nonce space.
+    assert_eq!(
+        nonces
+            .nonce(&sender_credential_id, &mut working_set)
+            .unwrap_infallible(),
+        None
+    );
+    assert_eq!(
+        nonces
+            .nonce_in_space(&sender_credential_id, OTHER_NONCE_SPACE, &mut working_set)
+            .unwrap_infallible(),
+        Some(2)
+    );
+}
+
+#[test]
+fn default_nonce_space_keeps_stored_nonces() {
+    let nonces = Nonces::<S>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set =
+        WorkingSet::<S>::new_deprecated(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let sender_credential_id: CredentialId = TestPrivateKey::generate()
+        .pub_key()
+        .credential_id::<TestHasher>();
+
+    // A nonce stored with the layout used before nonce spaces were introduced.
+    let prefix = ModulePrefix::new_storage("sov_nonces", "Nonces", "nonces");
+    StateMap::<CredentialId, u64>::new(prefix.into())
+        .set(&sender_credential_id, &5, &mut working_set)
+        .unwrap_infallible();
+
+    assert_eq!(
+        nonces
+            .nonce(&sender_credential_id, &mut working_set)
+            .unwrap_infallible(),
+        Some(5)
+    );
+    assert!(nonces
+        .check_nonce(
+            &sender_credential_id,
+            DEFAULT_NONCE_SPACE,
+            4,
+            &mut working_set
+        )
+        .is_err());
+    assert!(nonces
+        .check_nonce(&sender_credential_id, 7, 0, &mut working_set)
         .is_ok());
 }
@@ -4,6 +4,7 @@ use std::fmt::Debug;
 use anyhow::{Context as AnyhowContext, Result};
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
+use sov_bank::event::BurnReason;
 use sov_bank::{BurnRate, Coins, IntoPayable, GAS_TOKEN_ID};
 use sov_modules_api::macros::config_value;
 use sov_modules_api::{
@@ -50,6 +51,12 @@ pub enum ProverIncentiveError {
     /// An error occurred when trying to mint the reward token
     TransferFailure(String),
 
+    #[error(
+        "Error occurred when burning funds from this module's account. This is a bug. Error: {0}"
+    )]
+    /// An error occurred when trying to burn the slashed bond or a share of the reward
+    BurnFailure(String),
+
     /// An error when total bond value overflow or underflow
     #[error("Error when trying to top up bonded amount and it overflow or underflow")]
     BondArithmeticsError,
@@ -105,7 +112,7 @@ impl<S: Spec, Da: DaSpec> ProverIncentives<S, Da> {
             amount: bond_amount,
         };
         self.bank
-            .transfer_from(prover, self.id.to_payable(), coins, state)
+            .transfer_from_ignoring_freeze(prover, self.id.to_payable(), coins, state)
             .map_err(|_| ProverIncentiveError::BondTransferFailure)?;
 
         // Check that total balance does not overflow before doing transfer.
@@ -286,6 +293,27 @@ impl<S: Spec, Da: DaSpec> ProverIncentives<S, Da> {
         Ok(())
     }
 
+    /// Burns `amount` gas tokens from the module's account for the given `reason`.
+    fn burn_from_module(
+        &self,
+        amount: u64,
+        reason: BurnReason,
+        state: &mut impl TxState<S>,
+    ) -> Result<(), ProverIncentiveError> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let coins = Coins {
+            token_id: GAS_TOKEN_ID,
+            amount,
+        };
+
+        self.bank
+            .burn(coins, self.id.to_payable(), reason, state)
+            .map_err(|err| ProverIncentiveError::BurnFailure(err.to_string()))
+    }
+
     /// Computes the total reward from the aggregated state transition and rewards the prover with the unclaimed
     /// transition rewards. If all the rewards were already claimed, the prover is fined by a constant amount.
     fn try_reward_prover(
@@ -333,6 +361,7 @@ impl<S: Spec, Da: DaSpec> ProverIncentives<S, Da> {
             // to avoid the provers to collude to prove empty blocks.
             let reward_amount = self.burn_rate().apply(total_reward);
             self.transfer_to_prover(reward_amount, sender, state)?;
+            self.burn_from_module(total_reward - reward_amount, BurnReason::GasFee, state)?;
 
             self.emit_event(
                 state,
@@ -408,6 +437,9 @@ impl<S: Spec, Da: DaSpec> ProverIncentives<S, Da> {
         let public_outputs = match verification_result {
             Ok(public_outputs) => public_outputs,
             Err(_) => {
+                // The locked bond is not given back to the prover
+                self.burn_from_module(minimum_bond, BurnReason::Slashing, state)?;
+
                 self.emit_event(
                     state,
                     Event::<S>::ProverSlashed {
@@ -425,6 +457,8 @@ impl<S: Spec, Da: DaSpec> ProverIncentives<S, Da> {
             match err {
                 ErrorOrSlashed::Error(err) => return Err(err),
                 ErrorOrSlashed::Slashed(reason) => {
+                    self.burn_from_module(minimum_bond, BurnReason::Slashing, state)?;
+
                     self.emit_event(
                         state,
                         Event::<S>::ProverSlashed {
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sov_bank::GenesisBond;
 use sov_modules_api::{DaSpec, GenesisState};
 
 use crate::{Amount, ProverIncentives};
@@ -27,6 +28,21 @@ pub struct ProverIncentivesConfig<S: sov_modules_api::Spec> {
     pub initial_provers: Vec<(S::Address, u64)>,
 }
 
+impl<S: sov_modules_api::Spec> ProverIncentivesConfig<S> {
+    /// Returns the bonds transferred from the bank balances of the initial provers at
+    /// genesis, see [`sov_bank::BankConfig::validate_bonds`].
+    pub fn genesis_bonds(&self) -> Vec<GenesisBond<S>> {
+        self.initial_provers
+            .iter()
+            .map(|(prover, bond)| GenesisBond {
+                module: "ProverIncentives",
+                address: prover.clone(),
+                amount: *bond,
+            })
+            .collect()
+    }
+}
+
 impl<S: sov_modules_api::Spec, Da: DaSpec> ProverIncentives<S, Da> {
     /// Init the [`ProverIncentives`] module using the provided `config`.
     /// Sets the minimum amount necessary to bond, the commitment to the verifier circuit
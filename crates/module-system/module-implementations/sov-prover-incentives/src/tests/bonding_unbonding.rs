@@ -1,5 +1,6 @@
-use sov_bank::GAS_TOKEN_ID;
+use sov_bank::{CallMessage, GAS_TOKEN_ID};
 use sov_mock_zkvm::MockZkvm;
+use sov_modules_api::{Context, Module};
 use sov_test_utils::TEST_DEFAULT_USER_STAKE;
 
 use crate::tests::helpers::setup;
@@ -40,6 +41,36 @@ fn test_unbonding() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+/// Tests that a prover whose account is frozen can't withdraw their bond
+fn test_unbonding_frozen_account() -> anyhow::Result<()> {
+    let (module, prover_address, sequencer, state) = setup();
+
+    let mut working_set = state.to_working_set_unmetered();
+
+    // The sequencer is the freeze admin of the bank
+    module.bank.call(
+        CallMessage::FreezeAccount {
+            address: prover_address,
+            token_id: GAS_TOKEN_ID,
+        },
+        &Context::new(sequencer, Default::default(), sequencer, 1),
+        &mut working_set,
+    )?;
+
+    // Unbonding fails, and the prover keeps their bond
+    assert!(matches!(
+        module.unbond_prover(&prover_address, &mut working_set),
+        Err(ProverIncentiveError::TransferFailure(_))
+    ));
+    assert_eq!(
+        module.get_bond_amount(prover_address, &mut working_set)?,
+        TEST_DEFAULT_USER_STAKE
+    );
+
+    Ok(())
+}
+
 #[test]
 /// Tests that the prover cannot submit proofs if unbonded
 fn test_prover_not_bonded() -> Result<(), anyhow::Error> {
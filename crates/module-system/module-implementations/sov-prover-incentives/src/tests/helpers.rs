@@ -66,12 +66,15 @@ fn create_bank_config() -> (
             (sequencer_address, INITIAL_SEQUENCER_BALANCE),
         ],
         authorized_minters: vec![],
+        metadata: None,
     };
 
     (
         sov_bank::BankConfig {
             gas_token_config: token_config,
             tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: Some(sequencer_address),
         },
         prover_address,
         sequencer_address,
@@ -107,6 +110,7 @@ pub(crate) fn simulate_chain_state_execution(
                 max_fee: MAX_TX_GAS_AMOUNT,
                 gas_limit: Some(max_gas_used_per_step.clone()),
                 chain_id: 0,
+                valid_until_slot: None,
             },
         )
         .into();
@@ -198,6 +202,11 @@ fn setup_helper(
         genesis_da_height: 0,
         inner_code_commitment: MockCodeCommitment::default(),
         outer_code_commitment: MockCodeCommitment::default(),
+        state_access_budget: Default::default(),
+        base_fee_policy: Default::default(),
+        gas_limit_policy: Default::default(),
+        transition_retention_window: None,
+        admin: None,
     };
 
     let chain_state = sov_chain_state::ChainState::<S, Da>::default();
@@ -1,5 +1,6 @@
 use std::convert::Infallible;
 
+use sov_bank::event::{BurnReason, Event as BankEvent};
 use sov_bank::GAS_TOKEN_ID;
 use sov_mock_da::MockValidityCond;
 use sov_mock_zkvm::MockZkvm;
@@ -97,8 +98,8 @@ fn check_reward(
     // Reward = total_gas_used * (1-burn_rate)%
     let reward = module.burn_rate().apply(total_gas_used);
 
-    // Assert that the working set contains a rewarded event
-    assert_eq!(events.len(), 1);
+    // Assert that the working set contains a rewarded event, preceded by the burn of the rest of the gas fees
+    assert_eq!(events.len(), 2);
     let event: Event<S> = events.pop().unwrap().downcast().unwrap();
 
     assert_eq!(
@@ -109,6 +110,17 @@ fn check_reward(
         }
     );
 
+    let burn_event: BankEvent<S> = events.pop().unwrap().downcast().unwrap();
+    assert!(
+        matches!(
+            burn_event,
+            BankEvent::TokenBurned { coins, reason: BurnReason::GasFee, .. }
+                if coins.amount == total_gas_used - reward
+        ),
+        "Unexpected burn event: {:?}",
+        burn_event
+    );
+
     // Assert that the prover has been rewarded on his account
     // The outstanding balance is the initial balance plus the reward minus the bond amount
     let token_addr = GAS_TOKEN_ID;
@@ -2,6 +2,7 @@
 //! We are using the unmetered working set to test the slashing conditions so that we can keep these tests simple.
 use std::convert::Infallible;
 
+use sov_bank::event::{BurnReason, Event as BankEvent};
 use sov_mock_da::MockValidityCond;
 use sov_mock_zkvm::MockZkvm;
 use sov_modules_api::{
@@ -65,8 +66,21 @@ fn check_prover_slashed(
     module: &crate::ProverIncentives<S, sov_mock_da::MockDaSpec>,
     state: &mut WorkingSet<S>,
 ) -> Result<(), Infallible> {
-    // Check that the prover is slashed
-    assert_eq!(state.events().len(), 1);
+    // Check that the locked bond is burned and the prover is slashed
+    assert_eq!(state.events().len(), 2);
+    let burn_event: BankEvent<S> = state.take_event(0).unwrap().downcast().unwrap();
+    assert!(
+        matches!(
+            burn_event,
+            BankEvent::TokenBurned {
+                reason: BurnReason::Slashing,
+                ..
+            }
+        ),
+        "Unexpected burn event: {:?}",
+        burn_event
+    );
+
     let event: Event<S> = state.take_event(0).unwrap().downcast().unwrap();
     assert_eq!(
         event,
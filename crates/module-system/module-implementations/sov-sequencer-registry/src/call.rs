@@ -1,4 +1,5 @@
 use sov_bank::{Amount, Coins, IntoPayable, GAS_TOKEN_ID};
+use sov_chain_state::PrivilegedAction;
 #[cfg(feature = "native")]
 use sov_modules_api::macros::CliWalletArg;
 use sov_modules_api::prelude::UnwrapInfallible;
@@ -10,15 +11,17 @@ use sov_state::User;
 use thiserror::Error;
 
 use crate::event::Event;
-use crate::{AllowedSequencer, SequencerRegistry};
+use crate::{AllowedSequencer, SequencerRegistry, UnbondingInfo};
 
 /// This enumeration represents the available call messages for interacting with
 /// the `sov-sequencer-registry` module.
-#[cfg_attr(feature = "native", derive(schemars::JsonSchema), derive(CliWalletArg))]
 #[cfg_attr(
-    feature = "arbitrary",
-    derive(arbitrary::Arbitrary, proptest_derive::Arbitrary)
+    feature = "native",
+    derive(CliWalletArg),
+    derive(schemars::JsonSchema),
+    schemars(bound = "S::Address: ::schemars::JsonSchema", rename = "CallMessage")
 )]
+#[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
 #[derive(
     Debug,
     PartialEq,
@@ -28,13 +31,16 @@ use crate::{AllowedSequencer, SequencerRegistry};
     serde::Serialize,
     serde::Deserialize,
 )]
-pub enum CallMessage {
+pub enum CallMessage<S: Spec> {
     /// Add a new sequencer to the sequencer registry.
     Register {
         /// The raw Da address of the sequencer you're registering.
         da_address: Vec<u8>,
         /// The initial balance of the sequencer.
         amount: Amount,
+        /// The minimum bond the sequencer commits to keep staked. The global minimum bond
+        /// applies if it is higher, or if this is `None`.
+        minimum_bond: Option<Amount>,
     },
     /// Increases the balance of the sequencer, transferring the funds from the sequencer account
     /// to the rollup.
@@ -44,11 +50,35 @@ pub enum CallMessage {
         /// The amount to increase.
         amount: Amount,
     },
-    /// Remove a sequencer from the sequencer registry.
+    /// Remove a sequencer from the sequencer registry. Its bond can be withdrawn with
+    /// [`CallMessage::WithdrawBond`] once the unbonding period has elapsed.
     Exit {
         /// The raw Da address of the sequencer you're removing.
         da_address: Vec<u8>,
     },
+    /// Set the rollup address which receives the rewards of the sequencer registered with
+    /// `da_address`.
+    SetRewardRecipient {
+        /// The raw Da address of the sequencer.
+        da_address: Vec<u8>,
+        /// The address credited with the sequencer rewards. If `None`, the rewards
+        /// are paid to the sequencer's own address.
+        reward_recipient: Option<S::Address>,
+    },
+    /// Return the bond of a sequencer which exited the registry, once the unbonding
+    /// period has elapsed.
+    WithdrawBond {
+        /// The raw Da address of the sequencer which exited.
+        da_address: Vec<u8>,
+    },
+    /// Change the minimum bond the sequencer commits to keep staked.
+    SetMinimumBond {
+        /// The raw Da address of the sequencer.
+        da_address: Vec<u8>,
+        /// The new minimum bond of the sequencer. The global minimum bond applies if it is
+        /// higher, or if this is `None`.
+        minimum_bond: Option<Amount>,
+    },
 }
 
 /// Errors that can be raised by the `SequencerRegistry` module
@@ -98,6 +128,23 @@ pub enum SequencerRegistryError<S: Spec, Da: DaSpec> {
         amount_to_add: u64,
     },
 
+    #[error("The sequencer is unbonding and must withdraw its bond before registering again")]
+    /// The sequencer exited and hasn't withdrawn its bond yet.
+    SequencerIsUnbonding(Da::Address),
+
+    #[error("The provided address has no bond to withdraw")]
+    /// The provided address didn't exit the registry, or already withdrew its bond.
+    NoBondToWithdraw(Da::Address),
+
+    #[error("The bond can only be withdrawn from slot {withdrawable_from}, the current slot is {current_slot}")]
+    /// The unbonding period of the sequencer hasn't elapsed yet.
+    UnbondingPeriodNotElapsed {
+        /// The first slot at which the bond can be withdrawn.
+        withdrawable_from: u64,
+        /// The current slot.
+        current_slot: u64,
+    },
+
     #[error("The sequencer is already registered")]
     /// The sequencer is already registered.
     SequencerAlreadyRegistered(S::Address),
@@ -142,7 +189,7 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
     /// # Errors
     /// Will error
     ///
-    /// - If the provided amount is below the minimum required to register a sequencer.
+    /// - If the provided amount is below the minimum bond of the sequencer.
     /// - If the minimum bond is not set.
     /// - If the sender's account does not have enough funds to register itself as a sequencer.
     /// - If the sequencer is already registered.
@@ -150,16 +197,20 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         &self,
         da_address: &Da::Address,
         amount: Amount,
+        minimum_bond: Option<Amount>,
         context: &Context<S>,
         state: &mut impl TxState<S>,
     ) -> Result<CallResponse, SequencerRegistryError<S, Da>> {
         let sequencer = context.sender();
-        self.register_sequencer(da_address, sequencer, amount, state)?;
+        self.register_sequencer(da_address, sequencer, amount, minimum_bond, state)?;
         Ok(CallResponse::default())
     }
 
-    /// Tries to remove a sequencer by unstaking the provided amount of gas tokens.
+    /// Tries to remove a sequencer from the registry. Its staked amount is kept by the module until
+    /// the unbonding period elapses, see [`Self::withdraw_bond`].
     /// This method uses the context's sender as the sequencer's address.
+    /// If the sequencer was the preferred sequencer, its removal is recorded in the audit log of
+    /// the chain state module.
     ///
     /// # Errors
     /// Will error
@@ -167,7 +218,6 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
     /// - If the sequencer is not registered.
     /// - If the sequencer tries to unregister itself during the execution of its own batch.
     /// - If the supplied `da_address` does not match the transaction sender.
-    /// - If the module balance is not high enough to refund the sequencer's staked amount (this is a bug).
     pub(crate) fn exit(
         &self,
         da_address: &Da::Address,
@@ -198,28 +248,107 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         }
 
         let sender_balance = self.get_sender_balance(da_address, state)?.unwrap_or(0);
+        let unbonding_period = self.unbonding_period.get(state)?.unwrap_or(0);
+
+        self.unbonding_sequencers.set(
+            da_address,
+            &UnbondingInfo {
+                address: sender.clone(),
+                amount: sender_balance,
+                withdrawable_from: context
+                    .visible_slot_number()
+                    .saturating_add(unbonding_period),
+            },
+            state,
+        )?;
+
+        let was_preferred = self.preferred_sequencer.get(state)?.as_ref() == Some(da_address);
+        self.delete(da_address, state)?;
+
+        if was_preferred {
+            self.chain_state
+                .record_privileged_action(
+                    PrivilegedAction::PreferredSequencerRemoved {
+                        da_address: da_address.as_ref().to_vec(),
+                    },
+                    context,
+                    state,
+                )
+                .map_err(|e| SequencerRegistryError::StateAccessorError(e.to_string()))?;
+        }
+
+        self.emit_event(
+            state,
+            Event::<S>::Exited {
+                sequencer: sender.clone(),
+            },
+        );
+
+        Ok(CallResponse::default())
+    }
+
+    /// Returns the bond of a sequencer which exited the registry.
+    /// This method uses the context's sender as the sequencer's address.
+    ///
+    /// # Errors
+    /// Will error
+    ///
+    /// - If the sequencer has no bond to withdraw.
+    /// - If the supplied `da_address` does not belong to the transaction sender.
+    /// - If the unbonding period hasn't elapsed, as of the context's visible slot.
+    /// - If the module balance is not high enough to refund the sequencer's staked amount (this is a bug).
+    pub(crate) fn withdraw_bond(
+        &self,
+        da_address: &Da::Address,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse, SequencerRegistryError<S, Da>> {
+        let sender = context.sender();
+
+        let unbonding = self
+            .unbonding_sequencers
+            .get(da_address, state)?
+            .ok_or_else(|| SequencerRegistryError::NoBondToWithdraw(da_address.clone()))?;
+
+        if sender != &unbonding.address {
+            return Err(
+                SequencerRegistryError::SuppliedAddressDoesNotMatchTxSender {
+                    parameter: unbonding.address,
+                    sender: sender.clone(),
+                },
+            );
+        }
+
+        let current_slot = context.visible_slot_number();
+        if current_slot < unbonding.withdrawable_from {
+            return Err(SequencerRegistryError::UnbondingPeriodNotElapsed {
+                withdrawable_from: unbonding.withdrawable_from,
+                current_slot,
+            });
+        }
 
         self.bank
-            .transfer_from(
+            .transfer_from_ignoring_freeze(
                 self.id().to_payable(),
                 sender,
                 Coins {
-                    amount: sender_balance,
+                    amount: unbonding.amount,
                     token_id: GAS_TOKEN_ID,
                 },
                 state,
             )
             .map_err(|_| {
-                SequencerRegistryError::InsufficientFundsToRefundStakedAmount(sender_balance)
+                SequencerRegistryError::InsufficientFundsToRefundStakedAmount(unbonding.amount)
             })?;
 
-        // we remove the sequencer from the registry *once the sequencer has received its staked amount*
-        self.delete(da_address, state)?;
+        // we forget the bond *once the sequencer has received it*
+        self.unbonding_sequencers.delete(da_address, state)?;
 
         self.emit_event(
             state,
-            Event::<S>::Exited {
+            Event::<S>::BondWithdrawn {
                 sequencer: sender.clone(),
+                amount: unbonding.amount,
             },
         );
 
@@ -232,6 +361,8 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         state: &mut Accessor,
     ) -> Result<(), <Accessor as StateWriter<User>>::Error> {
         self.allowed_sequencers.delete(da_address, state)?;
+        self.last_active_slot.delete(da_address, state)?;
+        self.remove_sequencer_address(da_address, state)?;
 
         if let Some(preferred_sequencer) = self.preferred_sequencer.get(state)? {
             if da_address == &preferred_sequencer {
@@ -242,6 +373,32 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         Ok(())
     }
 
+    /// Removes `da_address` from [`SequencerRegistry::sequencer_addresses`] by swapping it
+    /// with the last address, which doesn't preserve the order of the addresses.
+    fn remove_sequencer_address<Accessor: StateAccessor>(
+        &self,
+        da_address: &Da::Address,
+        state: &mut Accessor,
+    ) -> Result<(), <Accessor as StateWriter<User>>::Error> {
+        let len = self.sequencer_addresses.len(state)?;
+        for index in 0..len {
+            if self.sequencer_addresses.get(index, state)?.as_ref() != Some(da_address) {
+                continue;
+            }
+
+            if let Some(last) = self.sequencer_addresses.pop(state)? {
+                if index + 1 < len {
+                    self.sequencer_addresses
+                        .set(index, &last, state)?
+                        .expect("The index is within the bounds of the vector");
+                }
+            }
+            break;
+        }
+
+        Ok(())
+    }
+
     /// Increases the balance of the provided sender, updating the state of the registry.
     ///
     /// # Errors
@@ -257,10 +414,14 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         amount: Amount,
         state: &mut impl TxState<S>,
     ) -> Result<CallResponse, SequencerRegistryError<S, Da>> {
-        let AllowedSequencer { address, balance } =
-            self.allowed_sequencers.get(sender, state)?.ok_or(
-                SequencerRegistryError::IsNotRegisteredSequencer(sender.clone()),
-            )?;
+        let AllowedSequencer {
+            address,
+            balance,
+            reward_recipient,
+            minimum_bond,
+        } = self.allowed_sequencers.get(sender, state)?.ok_or(
+            SequencerRegistryError::IsNotRegisteredSequencer(sender.clone()),
+        )?;
 
         let balance = balance.checked_add(amount).ok_or(
             SequencerRegistryError::ToppingAccountMakesBalanceOverflow {
@@ -276,7 +437,7 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         };
 
         self.bank
-            .transfer_from(&address, self.id().to_payable(), coins, state)
+            .transfer_from_ignoring_freeze(&address, self.id().to_payable(), coins, state)
             .map_err(
                 |_| SequencerRegistryError::<S, Da>::InsufficientFundsToTopUpAccount {
                     address: address.clone(),
@@ -289,6 +450,8 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
             &AllowedSequencer {
                 address: address.clone(),
                 balance,
+                reward_recipient,
+                minimum_bond,
             },
             state,
         )?;
@@ -304,8 +467,128 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
         Ok(CallResponse::default())
     }
 
+    /// Sets the address which receives the rewards of the sequencer registered with `da_address`.
+    /// The recipient is stored in the registry entry of that DA address, so a sequencer
+    /// registered with several DA addresses sets a recipient for each of them.
+    /// Only the rollup address of the sequencer, i.e. the context's sender, can change it.
+    ///
+    /// # Errors
+    /// Will error
+    ///
+    /// - If the sequencer is not registered.
+    /// - If the supplied `da_address` does not belong to the transaction sender.
+    pub(crate) fn set_reward_recipient(
+        &self,
+        da_address: &Da::Address,
+        reward_recipient: Option<S::Address>,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse, SequencerRegistryError<S, Da>> {
+        let sender = context.sender();
+
+        let sequencer = self
+            .allowed_sequencers
+            .get_or_err(da_address, state)?
+            .map_err(|_| SequencerRegistryError::IsNotRegisteredSequencer(da_address.clone()))?;
+
+        if sender != &sequencer.address {
+            return Err(
+                SequencerRegistryError::SuppliedAddressDoesNotMatchTxSender {
+                    parameter: sequencer.address,
+                    sender: sender.clone(),
+                },
+            );
+        }
+
+        self.allowed_sequencers.set(
+            da_address,
+            &AllowedSequencer {
+                reward_recipient: reward_recipient.clone(),
+                ..sequencer
+            },
+            state,
+        )?;
+
+        self.emit_event(
+            state,
+            Event::<S>::RewardRecipientSet {
+                sequencer: sender.clone(),
+                reward_recipient,
+            },
+        );
+
+        Ok(CallResponse::default())
+    }
+
+    /// Sets the minimum bond the sequencer commits to keep staked.
+    /// This method uses the context's sender as the sequencer's address.
+    ///
+    /// # Errors
+    /// Will error
+    ///
+    /// - If the sequencer is not registered.
+    /// - If the supplied `da_address` does not belong to the transaction sender.
+    /// - If the staked balance of the sequencer is below its new minimum bond.
+    pub(crate) fn set_minimum_bond(
+        &self,
+        da_address: &Da::Address,
+        minimum_bond: Option<Amount>,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<CallResponse, SequencerRegistryError<S, Da>> {
+        let sender = context.sender();
+
+        let sequencer = self
+            .allowed_sequencers
+            .get_or_err(da_address, state)?
+            .map_err(|_| SequencerRegistryError::IsNotRegisteredSequencer(da_address.clone()))?;
+
+        if sender != &sequencer.address {
+            return Err(
+                SequencerRegistryError::SuppliedAddressDoesNotMatchTxSender {
+                    parameter: sequencer.address,
+                    sender: sender.clone(),
+                },
+            );
+        }
+
+        let global_minimum_bond = self
+            .minimum_bond
+            .get(state)?
+            .ok_or(SequencerRegistryError::NoMinimumBondSet)?;
+        let previous_minimum_bond = sequencer.effective_minimum_bond(global_minimum_bond);
+
+        let sequencer = AllowedSequencer {
+            minimum_bond: minimum_bond.unwrap_or_default(),
+            ..sequencer
+        };
+        let new_minimum_bond = sequencer.effective_minimum_bond(global_minimum_bond);
+
+        if sequencer.balance < new_minimum_bond {
+            return Err(SequencerRegistryError::InsufficientStakeAmount {
+                bond_amount: sequencer.balance,
+                minimum_bond_amount: new_minimum_bond,
+            });
+        }
+
+        self.allowed_sequencers.set(da_address, &sequencer, state)?;
+
+        if new_minimum_bond != previous_minimum_bond {
+            self.emit_event(
+                state,
+                Event::<S>::MinimumBondChanged {
+                    sequencer: sender.clone(),
+                    minimum_bond: new_minimum_bond,
+                },
+            );
+        }
+
+        Ok(CallResponse::default())
+    }
+
     /// Rewards the sequencer with the `amount` of gas tokens.
-    /// Transfers the reward from the module's account to the sequencer's account.
+    /// Transfers the reward from the module's account to the sequencer's reward recipient,
+    /// or to the sequencer's account if no recipient is set.
     ///
     /// # Safety note:
     /// This method panics if:
@@ -313,19 +596,16 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
     /// - The module account does not have enough funds to pay for the reward (the module balance should be populated in the `GasEnforcer` capability hook).
     pub(crate) fn reward_sequencer(
         &self,
-        sequencer: &Da::Address,
+        da_address: &Da::Address,
         amount: u64,
         state: &mut StateCheckpoint<S>,
     ) {
-        let AllowedSequencer {
-            address: rollup_address,
-            balance: _,
-        } = self.allowed_sequencers.get(sequencer, state).unwrap_infallible().expect("Sequencer must be allowed. This should have been checked in the `begin_batch_hook`. This is a bug");
+        let sequencer = self.allowed_sequencers.get(da_address, state).unwrap_infallible().expect("Sequencer must be allowed. This should have been checked in the `begin_batch_hook`. This is a bug");
 
         self.bank
-            .transfer_from(
+            .transfer_from_ignoring_freeze(
                 self.id().to_payable(),
-                &rollup_address,
+                sequencer.reward_address(),
                 Coins {
                     amount,
                     token_id: GAS_TOKEN_ID,
@@ -126,28 +126,33 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
 
         let mut scratchpad = pre_exec_working_set.into();
 
-        if let Some(AllowedSequencer {
-            address,
-            balance: _,
-        }) = self
+        if let Some(sequencer) = self
             .allowed_sequencers
             .get(sender, &mut scratchpad)
             .unwrap_infallible()
         {
             tracing::info!(
-                sequencer = %address,
+                sequencer = %sequencer.address,
                 penalty_amount = ?penalty_amount,
                 remaining_stake = %remaining_stake,
                 reason = %reason,
                 "The sequencer was penalized",
             );
 
+            let minimum_bond = sequencer.effective_minimum_bond(
+                self.minimum_bond
+                    .get(&mut scratchpad)
+                    .unwrap_infallible()
+                    .unwrap_or_default(),
+            );
+            sequencer.report_if_became_ineligible(remaining_stake, minimum_bond);
+
             self.allowed_sequencers
                 .set(
                     sender,
                     &AllowedSequencer {
-                        address,
                         balance: remaining_stake,
+                        ..sequencer
                     },
                     &mut scratchpad,
                 )
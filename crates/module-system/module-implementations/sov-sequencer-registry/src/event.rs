@@ -32,4 +32,39 @@ pub enum Event<S: Spec> {
         /// The amount of the deposit.
         amount: u64,
     },
+
+    /// A sequencer set the address which receives its rewards.
+    RewardRecipientSet {
+        /// The address of the sequencer.
+        sequencer: S::Address,
+        /// The address which receives the rewards, if any.
+        reward_recipient: Option<S::Address>,
+    },
+
+    /// A sequencer withdrew its bond once the unbonding period elapsed.
+    BondWithdrawn {
+        /// The address of the sequencer.
+        sequencer: S::Address,
+        /// The amount withdrawn.
+        amount: u64,
+    },
+
+    /// The minimum bond a sequencer has to keep staked changed.
+    MinimumBondChanged {
+        /// The address of the sequencer.
+        sequencer: S::Address,
+        /// The new effective minimum bond of the sequencer.
+        minimum_bond: u64,
+    },
+
+    /// The staked balance of a sequencer dropped below its minimum bond, so it can no
+    /// longer send batches.
+    BecameIneligible {
+        /// The address of the sequencer.
+        sequencer: S::Address,
+        /// The new staked balance of the sequencer.
+        balance: u64,
+        /// The effective minimum bond of the sequencer.
+        minimum_bond: u64,
+    },
 }
@@ -0,0 +1,35 @@
+use arbitrary::{Arbitrary, Unstructured};
+use sov_modules_api::Spec;
+
+use crate::CallMessage;
+
+impl<'a, S> Arbitrary<'a> for CallMessage<S>
+where
+    S: Spec,
+    S::Address: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let da_address = u.arbitrary()?;
+        Ok(match u.int_in_range(0..=5)? {
+            0 => Self::Register {
+                da_address,
+                amount: u.arbitrary()?,
+                minimum_bond: u.arbitrary()?,
+            },
+            1 => Self::Deposit {
+                da_address,
+                amount: u.arbitrary()?,
+            },
+            2 => Self::Exit { da_address },
+            3 => Self::SetRewardRecipient {
+                da_address,
+                reward_recipient: u.arbitrary()?,
+            },
+            4 => Self::WithdrawBond { da_address },
+            _ => Self::SetMinimumBond {
+                da_address,
+                minimum_bond: u.arbitrary()?,
+            },
+        })
+    }
+}
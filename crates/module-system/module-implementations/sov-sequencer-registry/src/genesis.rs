@@ -1,9 +1,9 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use sov_bank::Amount;
+use sov_bank::{Amount, GenesisBond};
 use sov_modules_api::GenesisState;
 
-use crate::SequencerRegistry;
+use crate::{SequencerRegistry, MAX_SLASH_FRACTION_BIPS};
 
 /// Genesis configuration for the [`SequencerRegistry`] module.
 ///
@@ -34,6 +34,34 @@ pub struct SequencerConfig<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec
     /// block, which means the preferred sequencer can guarantee soft
     /// confirmation time for transactions.
     pub is_preferred_sequencer: bool,
+    /// When set, genesis fails unless a preferred sequencer is provided
+    /// (i.e. [`SequencerConfig::is_preferred_sequencer`] is `true`).
+    #[serde(default)]
+    pub require_preferred_sequencer: bool,
+    /// The number of slots a sequencer has to wait after exiting the registry before it can
+    /// withdraw its bond. Until then, the bond can still be slashed.
+    #[serde(default)]
+    pub unbonding_period: u64,
+    /// The fraction of the bond, in basis points, which is slashed when the sequencer submits an
+    /// invalid batch. Defaults to [`MAX_SLASH_FRACTION_BIPS`], i.e. the whole bond.
+    #[serde(default = "default_slash_fraction_bips")]
+    pub slash_fraction_bips: u64,
+}
+
+fn default_slash_fraction_bips() -> u64 {
+    MAX_SLASH_FRACTION_BIPS
+}
+
+impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerConfig<S, Da> {
+    /// Returns the bond transferred from the bank balance of the sequencer at genesis,
+    /// see [`sov_bank::BankConfig::validate_bonds`].
+    pub fn genesis_bonds(&self) -> Vec<GenesisBond<S>> {
+        vec![GenesisBond {
+            module: "SequencerRegistry",
+            address: self.seq_rollup_address.clone(),
+            amount: self.minimum_bond,
+        }]
+    }
 }
 
 impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
@@ -46,15 +74,33 @@ impl<S: sov_modules_api::Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S,
             sequencer_rollup_address = %config.seq_rollup_address,
             sequencer_da_address = %config.seq_da_address,
             is_preferred_sequencer = config.is_preferred_sequencer,
+            require_preferred_sequencer = config.require_preferred_sequencer,
             minimum_bond = config.minimum_bond,
+            unbonding_period = config.unbonding_period,
+            slash_fraction_bips = config.slash_fraction_bips,
             "Starting sequencer registry genesis..."
         );
+        anyhow::ensure!(
+            config.is_preferred_sequencer || !config.require_preferred_sequencer,
+            "A preferred sequencer is required at genesis, but sequencer {} is not marked as preferred",
+            config.seq_da_address
+        );
+        anyhow::ensure!(
+            config.slash_fraction_bips <= MAX_SLASH_FRACTION_BIPS,
+            "The slash fraction must be at most {} basis points, got {}",
+            MAX_SLASH_FRACTION_BIPS,
+            config.slash_fraction_bips
+        );
         self.minimum_bond.set(&config.minimum_bond, state)?;
+        self.unbonding_period.set(&config.unbonding_period, state)?;
+        self.slash_fraction_bips
+            .set(&config.slash_fraction_bips, state)?;
 
         self.register_sequencer(
             &config.seq_da_address,
             &config.seq_rollup_address,
             config.minimum_bond,
+            None,
             state,
         )?;
 
@@ -76,7 +122,7 @@ mod tests {
     use sov_modules_api::AddressBech32;
     use sov_test_utils::TestSpec;
 
-    use crate::SequencerConfig;
+    use crate::{SequencerConfig, MAX_SLASH_FRACTION_BIPS};
 
     #[test]
     fn test_config_serialization() {
@@ -96,6 +142,9 @@ mod tests {
             seq_da_address: seq_da_addreess,
             minimum_bond: 50,
             is_preferred_sequencer: true,
+            require_preferred_sequencer: false,
+            unbonding_period: 0,
+            slash_fraction_bips: MAX_SLASH_FRACTION_BIPS,
         };
 
         let data = r#"
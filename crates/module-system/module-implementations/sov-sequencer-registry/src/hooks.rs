@@ -1,9 +1,12 @@
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use risc0_cycle_macros::cycle_tracker;
-use sov_modules_api::hooks::ApplyBatchHooks;
+use sov_modules_api::hooks::{ApplyBatchHooks, TransitionHeight};
+use sov_modules_api::prelude::UnwrapInfallible;
 use sov_modules_api::{BatchWithId, Spec, StateCheckpoint};
 
-use crate::{AllowedSequencerError, BatchSequencerOutcome, SequencerRegistry};
+use crate::{
+    AllowedSequencerError, BatchSequencerOutcome, SequencerRegistry, MAX_SLASH_FRACTION_BIPS,
+};
 
 impl<S: Spec, Da: sov_modules_api::DaSpec> ApplyBatchHooks<Da> for SequencerRegistry<S, Da> {
     type Spec = S;
@@ -14,10 +17,17 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ApplyBatchHooks<Da> for SequencerRegi
         &self,
         _batch: &BatchWithId,
         sender: &Da::Address,
+        visible_slot_number: TransitionHeight,
         state: &mut StateCheckpoint<S>,
     ) -> anyhow::Result<()> {
         match self.is_sender_allowed(sender, state) {
-            Ok(_) | Err(AllowedSequencerError::NotRegistered) => Ok(()),
+            Ok(_) => {
+                self.last_active_slot
+                    .set(sender, &visible_slot_number, state)
+                    .unwrap_infallible();
+                Ok(())
+            }
+            Err(AllowedSequencerError::NotRegistered) => Ok(()),
             Err(AllowedSequencerError::InsufficientStakeAmount { .. }) => {
                 anyhow::bail!(
                     "sender {} is not allowed to submit blobs, they are not sufficiently staked",
@@ -37,8 +47,18 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> ApplyBatchHooks<Da> for SequencerRegi
             BatchSequencerOutcome::Rewarded(amount) => {
                 self.reward_sequencer(sender, amount.into(), state_checkpoint);
             }
-            BatchSequencerOutcome::Slashed(_) => {
-                self.slash_sequencer(sender, state_checkpoint);
+            BatchSequencerOutcome::Slashed(error) => {
+                let slash_fraction_bips = self
+                    .slash_fraction_bips
+                    .get(state_checkpoint)
+                    .unwrap_infallible()
+                    .unwrap_or(MAX_SLASH_FRACTION_BIPS);
+                self.slash_sequencer_partial(
+                    sender,
+                    slash_fraction_bips,
+                    (&error).into(),
+                    state_checkpoint,
+                );
             }
             BatchSequencerOutcome::Ignored(_) | BatchSequencerOutcome::NotRewardable => {}
         };
@@ -8,6 +8,8 @@
 mod call;
 mod capabilities;
 mod event;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
 mod genesis;
 mod hooks;
 
@@ -17,6 +19,9 @@ mod tests;
 use sov_modules_api::prelude::UnwrapInfallible;
 #[cfg(feature = "native")]
 mod query;
+/// The REST API of the module
+#[cfg(feature = "native")]
+pub mod rest;
 use borsh::{BorshDeserialize, BorshSerialize};
 pub use call::*;
 pub use capabilities::SequencerStakeMeter;
@@ -26,10 +31,12 @@ pub use query::*;
 use serde::{Deserialize, Serialize};
 use sov_bank::{Amount, Coins, IntoPayable, GAS_TOKEN_ID};
 use sov_modules_api::capabilities::FatalError;
+use sov_modules_api::hooks::TransitionHeight;
 use sov_modules_api::transaction::SequencerReward;
 use sov_modules_api::{
     CallResponse, Context, Error, EventEmitter, GenesisState, InfallibleStateAccessor, ModuleId,
-    ModuleInfo, Spec, StateAccessor, StateCheckpoint, StateMap, StateReader, StateValue, TxState,
+    ModuleInfo, Spec, StateAccessor, StateCheckpoint, StateMap, StateReader, StateValue, StateVec,
+    TxState,
 };
 use sov_state::codec::BcsCodec;
 use sov_state::{EventContainer, User};
@@ -37,6 +44,15 @@ use thiserror::Error;
 
 use crate::event::Event;
 
+/// The slash fraction, in basis points, which forfeits the whole bond of a sequencer.
+pub const MAX_SLASH_FRACTION_BIPS: u64 = 10_000;
+
+/// Returns the part of `amount` forfeited by a slash of `slash_fraction_bips` basis points.
+fn fraction_of_bips(amount: Amount, slash_fraction_bips: u64) -> Amount {
+    (u128::from(amount) * u128::from(slash_fraction_bips) / u128::from(MAX_SLASH_FRACTION_BIPS))
+        as Amount
+}
+
 /// An allowed sequencer for a rollup.
 #[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize, Eq, PartialEq)]
 #[serde(bound = "S::Address: serde::Serialize + serde::de::DeserializeOwned")]
@@ -45,6 +61,67 @@ pub struct AllowedSequencer<S: Spec> {
     pub address: S::Address,
     /// The staked balance of the sequencer.
     pub balance: Amount,
+    /// The rollup address which receives the sequencer rewards.
+    /// If not set, the rewards are paid to [`AllowedSequencer::address`].
+    #[serde(default)]
+    pub reward_recipient: Option<S::Address>,
+    /// The minimum bond the sequencer has to keep staked to send batches. The global
+    /// [`SequencerRegistry::minimum_bond`] applies as a floor, so `0` means the sequencer
+    /// only has to meet the global minimum.
+    #[serde(default)]
+    pub minimum_bond: Amount,
+}
+
+impl<S: Spec> AllowedSequencer<S> {
+    /// Returns the rollup address which is credited with the sequencer rewards.
+    pub fn reward_address(&self) -> &S::Address {
+        self.reward_recipient.as_ref().unwrap_or(&self.address)
+    }
+
+    /// Returns the minimum bond the sequencer has to keep staked, given the global minimum bond.
+    pub fn effective_minimum_bond(&self, global_minimum_bond: Amount) -> Amount {
+        self.minimum_bond.max(global_minimum_bond)
+    }
+
+    /// Returns the [`Event::BecameIneligible`] to report if reducing the balance of the
+    /// sequencer to `new_balance` makes it cross below `minimum_bond`.
+    fn became_ineligible(&self, new_balance: Amount, minimum_bond: Amount) -> Option<Event<S>> {
+        (self.balance >= minimum_bond && new_balance < minimum_bond).then(|| {
+            Event::BecameIneligible {
+                sequencer: self.address.clone(),
+                balance: new_balance,
+                minimum_bond,
+            }
+        })
+    }
+
+    /// Logs the [`Event::BecameIneligible`] of a balance reduction, if any. The paths which
+    /// reduce the balance of a sequencer run outside of a transaction, where module events
+    /// can't be emitted.
+    fn report_if_became_ineligible(&self, new_balance: Amount, minimum_bond: Amount) {
+        if let Some(event) = self.became_ineligible(new_balance, minimum_bond) {
+            tracing::warn!(
+                sequencer = %self.address,
+                balance = new_balance,
+                minimum_bond,
+                event = ?event,
+                "Sequencer became ineligible"
+            );
+        }
+    }
+}
+
+/// The bond of a sequencer which exited the registry. It is kept by the module until the
+/// unbonding period elapses, so that the sequencer can still be slashed in the meantime.
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize, Eq, PartialEq)]
+#[serde(bound = "S::Address: serde::Serialize + serde::de::DeserializeOwned")]
+pub struct UnbondingInfo<S: Spec> {
+    /// The rollup address of the sequencer, which receives the bond.
+    pub address: S::Address,
+    /// The amount of gas tokens to be withdrawn.
+    pub amount: Amount,
+    /// The first slot at which the bond can be withdrawn.
+    pub withdrawable_from: u64,
 }
 
 /// Errors that can be raised by the [`SequencerRegistry`] module during hooks execution.
@@ -81,8 +158,10 @@ pub enum BatchSequencerOutcome {
         String,
     ),
     /// The sequencer is not rewardable for the submitted batch.
-    /// This occurs when an unregistered sequencer submits a batch directly to the DA.
-    /// The batch might be applied but there is nobody to reward.
+    /// This occurs when an unregistered sequencer submits a batch directly to the DA,
+    /// in which case the batch might be applied but there is nobody to reward.
+    /// This also occurs when the batch is empty: the sequencer did no work, so it is
+    /// neither rewarded nor slashed.
     NotRewardable,
 }
 
@@ -97,6 +176,19 @@ pub enum SlashingReason {
     InvalidTransactionEncoding,
 }
 
+impl From<&FatalError> for SlashingReason {
+    fn from(error: &FatalError) -> Self {
+        match error {
+            FatalError::DeserializationFailed(_) | FatalError::MessageDecodingFailed(..) => {
+                SlashingReason::InvalidTransactionEncoding
+            }
+            FatalError::SigVerificationFailed(_)
+            | FatalError::InvalidChainId { .. }
+            | FatalError::Other(_) => SlashingReason::StatelessVerificationFailed,
+        }
+    }
+}
+
 /// The `sov-sequencer-registry` module `struct`.
 #[derive(Clone, ModuleInfo, sov_modules_api::macros::ModuleRestApi)]
 pub struct SequencerRegistry<S: Spec, Da: sov_modules_api::DaSpec> {
@@ -108,6 +200,11 @@ pub struct SequencerRegistry<S: Spec, Da: sov_modules_api::DaSpec> {
     #[module]
     pub(crate) bank: sov_bank::Bank<S>,
 
+    /// Reference to the chain state module, used to record changes of the preferred sequencer
+    /// in the audit log of privileged operations.
+    #[kernel_module]
+    pub(crate) chain_state: sov_chain_state::ChainState<S, Da>,
+
     /// The minimum bond for a sequencer to send transactions.
     /// TODO(@theochap): This should be expressed in gas units.
     #[state]
@@ -119,11 +216,35 @@ pub struct SequencerRegistry<S: Spec, Da: sov_modules_api::DaSpec> {
     #[state]
     pub(crate) allowed_sequencers: StateMap<Da::Address, AllowedSequencer<S>, BcsCodec>,
 
+    /// The DA addresses of the [`SequencerRegistry::allowed_sequencers`], in no particular
+    /// order, so that the registered sequencers can be enumerated.
+    #[state]
+    #[rest_api(skip)]
+    pub(crate) sequencer_addresses: StateVec<Da::Address, BcsCodec>,
+
     /// Optional preferred sequencer.
     /// If set, batches from this sequencer will be processed first in block,
     /// So this sequencer can guarantee soft confirmation time for transactions
     #[state]
     pub(crate) preferred_sequencer: StateValue<Da::Address, BcsCodec>,
+
+    /// The number of slots a sequencer has to wait after exiting before withdrawing its bond.
+    #[state]
+    pub(crate) unbonding_period: StateValue<u64>,
+
+    /// The fraction of the bond, in basis points, which is slashed when a sequencer submits an
+    /// invalid batch.
+    #[state]
+    pub(crate) slash_fraction_bips: StateValue<u64>,
+
+    /// The bonds of the sequencers which exited, waiting for the unbonding period to elapse.
+    #[state]
+    pub(crate) unbonding_sequencers: StateMap<Da::Address, UnbondingInfo<S>, BcsCodec>,
+
+    /// The last slot at which a batch of each registered sequencer was processed.
+    #[state]
+    #[rest_api(include)]
+    pub(crate) last_active_slot: StateMap<Da::Address, TransitionHeight, BcsCodec>,
 }
 
 impl<S: Spec, Da: sov_modules_api::DaSpec> sov_modules_api::Module for SequencerRegistry<S, Da> {
@@ -131,7 +252,7 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> sov_modules_api::Module for Sequencer
 
     type Config = SequencerConfig<S, Da>;
 
-    type CallMessage = CallMessage;
+    type CallMessage = CallMessage<S>;
 
     type Event = Event<S>;
 
@@ -150,9 +271,13 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> sov_modules_api::Module for Sequencer
         state: &mut impl TxState<S>,
     ) -> Result<CallResponse, Error> {
         Ok(match message {
-            CallMessage::Register { da_address, amount } => {
+            CallMessage::Register {
+                da_address,
+                amount,
+                minimum_bond,
+            } => {
                 let da_address = Da::Address::try_from(&da_address)?;
-                self.register(&da_address, amount, context, state)
+                self.register(&da_address, amount, minimum_bond, context, state)
                     .map_err(|e| Error::ModuleError(e.into()))?
             }
             CallMessage::Deposit { da_address, amount } => {
@@ -165,6 +290,27 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> sov_modules_api::Module for Sequencer
                 self.exit(&da_address, context, state)
                     .map_err(|e| Error::ModuleError(e.into()))?
             }
+            CallMessage::WithdrawBond { da_address } => {
+                let da_address = Da::Address::try_from(&da_address)?;
+                self.withdraw_bond(&da_address, context, state)
+                    .map_err(|e| Error::ModuleError(e.into()))?
+            }
+            CallMessage::SetRewardRecipient {
+                da_address,
+                reward_recipient,
+            } => {
+                let da_address = Da::Address::try_from(&da_address)?;
+                self.set_reward_recipient(&da_address, reward_recipient, context, state)
+                    .map_err(|e| Error::ModuleError(e.into()))?
+            }
+            CallMessage::SetMinimumBond {
+                da_address,
+                minimum_bond,
+            } => {
+                let da_address = Da::Address::try_from(&da_address)?;
+                self.set_minimum_bond(&da_address, minimum_bond, context, state)
+                    .map_err(|e| Error::ModuleError(e.into()))?
+            }
         })
     }
 }
@@ -186,10 +332,13 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
     }
 
     /// Tries to register a sequencer by staking the provided amount of gas tokens.
+    /// The sequencer commits to keep at least `minimum_bond` staked, or the global minimum bond
+    /// if it is higher or if `minimum_bond` is [`None`].
+    ///
     /// # Errors
     /// Will error
     ///
-    /// - If the provided amount is below the minimum required to register a sequencer.
+    /// - If the provided amount is below the minimum bond of the sequencer.
     /// - If the minimum bond is not set.
     /// - If the sender's account does not have enough funds to register itself as a sequencer.
     /// - If the sequencer is already registered.
@@ -198,29 +347,47 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
         da_address: &Da::Address,
         address: &S::Address,
         amount: Amount,
+        minimum_bond: Option<Amount>,
         state: &mut (impl StateAccessor + EventContainer),
     ) -> Result<(), SequencerRegistryError<S, Da>> {
         if self
             .allowed_sequencers
-            .get(da_address, state)
+            .contains_key(da_address, state)
             .map_err(|e| SequencerRegistryError::StateAccessorError(e.to_string()))?
-            .is_some()
         {
             return Err(SequencerRegistryError::SequencerAlreadyRegistered(
                 address.clone(),
             ));
         }
 
-        let minimum_bond = self
+        if self
+            .unbonding_sequencers
+            .contains_key(da_address, state)
+            .map_err(|e| SequencerRegistryError::StateAccessorError(e.to_string()))?
+        {
+            return Err(SequencerRegistryError::SequencerIsUnbonding(
+                da_address.clone(),
+            ));
+        }
+
+        let global_minimum_bond = self
             .minimum_bond
             .get(state)
             .map_err(|e| SequencerRegistryError::StateAccessorError(e.to_string()))?
             .ok_or(SequencerRegistryError::NoMinimumBondSet)?;
 
-        if amount < minimum_bond {
+        let sequencer = AllowedSequencer {
+            address: address.clone(),
+            balance: amount,
+            reward_recipient: None,
+            minimum_bond: minimum_bond.unwrap_or_default(),
+        };
+
+        let effective_minimum_bond = sequencer.effective_minimum_bond(global_minimum_bond);
+        if amount < effective_minimum_bond {
             return Err(SequencerRegistryError::InsufficientStakeAmount {
                 bond_amount: amount,
-                minimum_bond_amount: minimum_bond,
+                minimum_bond_amount: effective_minimum_bond,
             });
         }
 
@@ -232,18 +399,14 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
         };
 
         self.bank
-            .transfer_from(address, locker.to_payable(), coins, state)
+            .transfer_from_ignoring_freeze(address, locker.to_payable(), coins, state)
             .map_err(|_| SequencerRegistryError::<S, Da>::InsufficientFundsToRegister(amount))?;
 
         self.allowed_sequencers
-            .set(
-                da_address,
-                &AllowedSequencer {
-                    address: address.clone(),
-                    balance: amount,
-                },
-                state,
-            )
+            .set(da_address, &sequencer, state)
+            .map_err(|e| SequencerRegistryError::StateAccessorError(e.to_string()))?;
+        self.sequencer_addresses
+            .push(da_address, state)
             .map_err(|e| SequencerRegistryError::StateAccessorError(e.to_string()))?;
 
         self.emit_event(
@@ -298,7 +461,8 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
         })
     }
 
-    /// Checks whether `sender` is a registered sequencer with enough staked amount.
+    /// Checks whether `sender` is a registered sequencer with enough staked amount, as required by
+    /// [`AllowedSequencer::effective_minimum_bond`].
     /// If so, returns the allowed sequencer in a [`AllowedSequencer`] object.
     /// Otherwise, returns a [`AllowedSequencerError`].
     pub fn is_sender_allowed(
@@ -311,11 +475,12 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
             .get(sender, state)
             .unwrap_infallible()
         {
-            let min_bond = self
-                .minimum_bond
-                .get(state)
-                .unwrap_infallible()
-                .expect("The minimum bond should be set at genesis");
+            let min_bond = sequencer.effective_minimum_bond(
+                self.minimum_bond
+                    .get(state)
+                    .unwrap_infallible()
+                    .expect("The minimum bond should be set at genesis"),
+            );
 
             if sequencer.balance < min_bond {
                 return Err(AllowedSequencerError::InsufficientStakeAmount {
@@ -342,6 +507,26 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
             .map(|s| s.balance))
     }
 
+    /// Returns the bond of the sequencer with the given DA address if it exited the registry
+    /// and didn't withdraw it yet.
+    pub fn get_unbonding_info<Reader: StateReader<User>>(
+        &self,
+        da_address: &Da::Address,
+        state: &mut Reader,
+    ) -> Result<Option<UnbondingInfo<S>>, Reader::Error> {
+        self.unbonding_sequencers.get(da_address, state)
+    }
+
+    /// Returns the last slot at which a batch of the sequencer with the given DA address was
+    /// processed, or [`None`] if it never sent a batch since it registered.
+    pub fn last_active_slot<Reader: StateReader<User>>(
+        &self,
+        da_address: &Da::Address,
+        state: &mut Reader,
+    ) -> Result<Option<TransitionHeight>, Reader::Error> {
+        self.last_active_slot.get(da_address, state)
+    }
+
     /// Returns the rollup address of the sequencer with the given DA address.
     pub fn get_sequencer_address<Reader: StateReader<User>>(
         &self,
@@ -354,9 +539,128 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
             .map(|s| s.address))
     }
 
-    /// Slash the sequencer with the given address.
-    pub fn slash_sequencer(&self, da_address: &Da::Address, state: &mut StateCheckpoint<S>) {
-        self.delete(da_address, state).unwrap_infallible();
+    /// Slash the sequencer with the given address. Its whole staked balance is forfeited,
+    /// including a bond that is still unbonding after an exit.
+    pub fn slash_sequencer(
+        &self,
+        da_address: &Da::Address,
+        reason: SlashingReason,
+        state: &mut StateCheckpoint<S>,
+    ) {
+        self.slash_sequencer_partial(da_address, MAX_SLASH_FRACTION_BIPS, reason, state);
+    }
+
+    /// Slash `slash_fraction_bips` basis points of the bond of the sequencer with the given address,
+    /// including a bond that is still unbonding after an exit. Fractions above
+    /// [`MAX_SLASH_FRACTION_BIPS`] are capped.
+    ///
+    /// The sequencer is deregistered if its remaining balance drops below its minimum bond.
+    /// In that case the remaining balance can be withdrawn with [`CallMessage::WithdrawBond`]
+    /// once the unbonding period has elapsed, counting from the last slot at which the sequencer
+    /// was active.
+    pub fn slash_sequencer_partial(
+        &self,
+        da_address: &Da::Address,
+        slash_fraction_bips: u64,
+        reason: SlashingReason,
+        state: &mut StateCheckpoint<S>,
+    ) {
+        let slash_fraction_bips = slash_fraction_bips.min(MAX_SLASH_FRACTION_BIPS);
+        let mut forfeited_amount: Amount = 0;
+
+        if let Some(unbonding) = self
+            .unbonding_sequencers
+            .get(da_address, state)
+            .unwrap_infallible()
+        {
+            let slashed_amount = fraction_of_bips(unbonding.amount, slash_fraction_bips);
+            forfeited_amount = forfeited_amount.saturating_add(slashed_amount);
+
+            if slashed_amount == unbonding.amount {
+                self.unbonding_sequencers
+                    .delete(da_address, state)
+                    .unwrap_infallible();
+            } else {
+                self.unbonding_sequencers
+                    .set(
+                        da_address,
+                        &UnbondingInfo {
+                            amount: unbonding.amount - slashed_amount,
+                            ..unbonding
+                        },
+                        state,
+                    )
+                    .unwrap_infallible();
+            }
+        }
+
+        if let Some(sequencer) = self
+            .allowed_sequencers
+            .get(da_address, state)
+            .unwrap_infallible()
+        {
+            let slashed_amount = fraction_of_bips(sequencer.balance, slash_fraction_bips);
+            let remaining_balance = sequencer.balance - slashed_amount;
+            forfeited_amount = forfeited_amount.saturating_add(slashed_amount);
+
+            let minimum_bond = sequencer.effective_minimum_bond(
+                self.minimum_bond
+                    .get(state)
+                    .unwrap_infallible()
+                    .unwrap_or_default(),
+            );
+
+            sequencer.report_if_became_ineligible(remaining_balance, minimum_bond);
+
+            if remaining_balance == 0 || remaining_balance < minimum_bond {
+                let withdrawable_from = self
+                    .last_active_slot
+                    .get(da_address, state)
+                    .unwrap_infallible()
+                    .unwrap_or_default()
+                    .saturating_add(
+                        self.unbonding_period
+                            .get(state)
+                            .unwrap_infallible()
+                            .unwrap_or_default(),
+                    );
+
+                self.delete(da_address, state).unwrap_infallible();
+
+                if remaining_balance > 0 {
+                    self.unbonding_sequencers
+                        .set(
+                            da_address,
+                            &UnbondingInfo {
+                                address: sequencer.address,
+                                amount: remaining_balance,
+                                withdrawable_from,
+                            },
+                            state,
+                        )
+                        .unwrap_infallible();
+                }
+            } else {
+                self.allowed_sequencers
+                    .set(
+                        da_address,
+                        &AllowedSequencer {
+                            balance: remaining_balance,
+                            ..sequencer
+                        },
+                        state,
+                    )
+                    .unwrap_infallible();
+            }
+        }
+
+        tracing::warn!(
+            sequencer_da_address = %da_address,
+            reason = ?reason,
+            slash_fraction_bips,
+            forfeited_amount,
+            "Sequencer slashed"
+        );
     }
 
     /// Check if the provided `Da::Address` belongs to a registered sequencer.
@@ -365,6 +669,6 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
         da_address: &Da::Address,
         state: &mut Reader,
     ) -> Result<bool, Reader::Error> {
-        Ok(self.allowed_sequencers.get(da_address, state)?.is_some())
+        self.allowed_sequencers.contains_key(da_address, state)
     }
 }
@@ -2,9 +2,10 @@
 use jsonrpsee::core::RpcResult;
 use sov_modules_api::macros::rpc_gen;
 use sov_modules_api::prelude::UnwrapInfallible;
-use sov_modules_api::{ApiStateAccessor, Spec};
+use sov_modules_api::{ApiStateAccessor, Spec, StateReader};
+use sov_state::User;
 
-use crate::SequencerRegistry;
+use crate::{AllowedSequencer, SequencerRegistry};
 
 /// The response type to the `getSequencerAddress` RPC method.
 #[cfg_attr(
@@ -35,3 +36,40 @@ impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
         })
     }
 }
+
+impl<S: Spec, Da: sov_modules_api::DaSpec> SequencerRegistry<S, Da> {
+    /// Returns the number of registered sequencers.
+    pub fn sequencer_count<Reader: StateReader<User>>(
+        &self,
+        state: &mut Reader,
+    ) -> Result<usize, Reader::Error> {
+        self.sequencer_addresses.len(state)
+    }
+
+    /// Returns up to `limit` registered sequencers along with their stake, starting from the
+    /// sequencer at position `start`. Positions range from `0` to
+    /// [`SequencerRegistry::sequencer_count`], and aren't stable: the last sequencer takes the
+    /// position of a sequencer which leaves the registry.
+    pub fn list_sequencers<Reader: StateReader<User>>(
+        &self,
+        start: usize,
+        limit: usize,
+        state: &mut Reader,
+    ) -> Result<Vec<(Da::Address, AllowedSequencer<S>)>, Reader::Error> {
+        let end = start
+            .saturating_add(limit)
+            .min(self.sequencer_addresses.len(state)?);
+
+        let mut sequencers = Vec::with_capacity(end.saturating_sub(start));
+        for index in start..end {
+            let Some(da_address) = self.sequencer_addresses.get(index, state)? else {
+                continue;
+            };
+            if let Some(sequencer) = self.allowed_sequencers.get(&da_address, state)? {
+                sequencers.push((da_address, sequencer));
+            }
+        }
+
+        Ok(sequencers)
+    }
+}
@@ -0,0 +1,69 @@
+//! Custom REST API routes to enumerate the registered sequencers.
+use serde::Serialize;
+use sov_modules_api::prelude::axum::routing::get;
+use sov_modules_api::prelude::{axum, UnwrapInfallible};
+use sov_modules_api::rest::utils::{
+    errors, ApiResult, PageSelection, Pagination, Query, ResponseObject,
+};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::{DaSpec, Spec};
+
+use crate::{AllowedSequencer, SequencerRegistry};
+
+/// A registered sequencer, as returned by `GET /sequencers`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "Da::Address: Serialize, AllowedSequencer<S>: Serialize")]
+pub struct SequencerResponse<S: Spec, Da: DaSpec> {
+    /// The DA address of the sequencer.
+    pub da_address: Da::Address,
+    /// The rollup address, stake and settings of the sequencer.
+    pub sequencer: AllowedSequencer<S>,
+}
+
+impl<S: Spec, Da: DaSpec> HasCustomRestApi<S> for SequencerRegistry<S, Da> {
+    fn custom_rest_api(&self, state: ApiState<Self, S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route("/sequencers", get(Self::route_sequencers))
+            .with_state(state)
+    }
+}
+
+impl<S: Spec, Da: DaSpec> SequencerRegistry<S, Da> {
+    /// Returns a page of the registered sequencers. The cursor is the position
+    /// of the last returned sequencer, and is only present in `meta` if there
+    /// are more sequencers after it.
+    async fn route_sequencers(
+        state: ApiState<Self, S>,
+        Query(pagination): Query<Pagination<u64>>,
+    ) -> ApiResult<Vec<SequencerResponse<S, Da>>> {
+        let mut accessor = state.api_state_accessor();
+        let count = state.sequencer_count(&mut accessor).unwrap_infallible();
+        let page_size = pagination.size as usize;
+
+        let start = match pagination.selection {
+            PageSelection::First => 0,
+            PageSelection::Next { cursor } => usize::try_from(cursor)
+                .map_err(|err| errors::bad_request_400("Invalid page cursor", err))?
+                .saturating_add(1),
+            PageSelection::Last => count.saturating_sub(page_size),
+        };
+
+        let sequencers = state
+            .list_sequencers(start, page_size, &mut accessor)
+            .unwrap_infallible();
+
+        let end = start.saturating_add(page_size);
+        let cursor = (end < count).then(|| (end - 1).to_string());
+
+        Ok(ResponseObject::from(
+            sequencers
+                .into_iter()
+                .map(|(da_address, sequencer)| SequencerResponse {
+                    da_address,
+                    sequencer,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .with_meta("cursor", cursor))
+    }
+}
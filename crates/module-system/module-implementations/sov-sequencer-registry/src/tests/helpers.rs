@@ -11,7 +11,7 @@ use sov_prover_storage_manager::new_orphan_storage;
 use sov_state::User;
 use sov_test_utils::TEST_DEFAULT_USER_STAKE;
 
-use crate::{AllowedSequencer, SequencerConfig, SequencerRegistry};
+use crate::{AllowedSequencer, SequencerConfig, SequencerRegistry, MAX_SLASH_FRACTION_BIPS};
 
 pub type S = sov_test_utils::TestSpec;
 pub type Da = MockDaSpec;
@@ -70,19 +70,20 @@ impl TestSequencer {
     }
 
     pub fn genesis(&self, state: StateCheckpoint<S>) -> StateCheckpoint<S> {
+        self.try_genesis(state).unwrap()
+    }
+
+    pub fn try_genesis(&self, state: StateCheckpoint<S>) -> anyhow::Result<StateCheckpoint<S>> {
         let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&self.bank_config);
-        self.bank
-            .genesis(&self.bank_config, &mut genesis_state)
-            .unwrap();
+        self.bank.genesis(&self.bank_config, &mut genesis_state)?;
         let state = genesis_state.checkpoint();
 
         let mut genesis_state = state
             .to_genesis_state_accessor::<SequencerRegistry<S, MockDaSpec>>(&self.sequencer_config);
         self.registry
-            .genesis(&self.sequencer_config, &mut genesis_state)
-            .unwrap();
+            .genesis(&self.sequencer_config, &mut genesis_state)?;
 
-        genesis_state.checkpoint()
+        Ok(genesis_state.checkpoint())
     }
 
     pub fn query_sequencer_balance<Reader: StateAccessor>(
@@ -152,12 +153,15 @@ pub fn create_bank_config(initial_balance: u64) -> (sov_bank::BankConfig<S>, <S
             (generate_address(LOW_FUND_KEY), 3),
         ],
         authorized_minters: vec![],
+        metadata: None,
     };
 
     (
         sov_bank::BankConfig {
             gas_token_config,
             tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: None,
         },
         seq_address,
     )
@@ -172,6 +176,9 @@ pub fn create_sequencer_config(
         seq_da_address: MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS),
         minimum_bond: TEST_DEFAULT_USER_STAKE,
         is_preferred_sequencer,
+        require_preferred_sequencer: false,
+        unbonding_period: 0,
+        slash_fraction_bips: MAX_SLASH_FRACTION_BIPS,
     }
 }
 
@@ -35,7 +35,7 @@ fn begin_batch_hook_known_sequencer() -> Result<(), Infallible> {
 
     test_sequencer
         .registry
-        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, &mut state)
+        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, 1, &mut state)
         .unwrap();
 
     let resp = test_sequencer.query_sequencer_balance(&mut state)?.unwrap();
@@ -61,6 +61,7 @@ fn begin_batch_hook_unknown_sequencer() -> Result<(), Infallible> {
     let result = test_sequencer.registry.begin_batch_hook(
         &test_batch,
         &MockAddress::from(UNKNOWN_SEQUENCER_DA_ADDRESS),
+        1,
         &mut state,
     );
     assert!(result.is_ok());
@@ -77,6 +78,8 @@ fn begin_batch_hook_unbonded_sequencer() -> Result<(), Infallible> {
         address: generate_address("sequencer"),
         // LOCKED_AMOUNT = required amount for bond
         balance: TEST_DEFAULT_USER_STAKE - 10,
+        reward_recipient: None,
+        minimum_bond: 0,
     };
     let _ = test_sequencer.set_allowed_sequencer(
         insufficient_bond_sequencer_address.into(),
@@ -92,6 +95,7 @@ fn begin_batch_hook_unbonded_sequencer() -> Result<(), Infallible> {
     let result = test_sequencer.registry.begin_batch_hook(
         &test_batch,
         &MockAddress::from(UNKNOWN_SEQUENCER_DA_ADDRESS),
+        1,
         &mut state,
     );
     assert!(result.is_err());
@@ -119,7 +123,7 @@ fn end_batch_hook_success() -> Result<(), Infallible> {
 
     test_sequencer
         .registry
-        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, &mut state)
+        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, 1, &mut state)
         .unwrap();
 
     <SequencerRegistry<S, Da> as ApplyBatchHooks<MockDaSpec>>::end_batch_hook(
@@ -137,3 +141,49 @@ fn end_batch_hook_success() -> Result<(), Infallible> {
 
     Ok(())
 }
+
+/// Tests that the `begin_batch_hook` records the last slot at which a registered sequencer was active.
+#[test]
+fn begin_batch_hook_tracks_last_active_slot() -> Result<(), Infallible> {
+    let (test_sequencer, mut state) =
+        TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let unknown_sequencer_da_address = MockAddress::from(UNKNOWN_SEQUENCER_DA_ADDRESS);
+    let test_batch = BatchWithId {
+        batch: Batch { txs: vec![] },
+        id: [0u8; 32],
+    };
+
+    assert_eq!(
+        test_sequencer
+            .registry
+            .last_active_slot(&genesis_sequencer_da_address, &mut state)?,
+        None
+    );
+
+    for slot in [3, 7] {
+        test_sequencer
+            .registry
+            .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, slot, &mut state)
+            .unwrap();
+        assert_eq!(
+            test_sequencer
+                .registry
+                .last_active_slot(&genesis_sequencer_da_address, &mut state)?,
+            Some(slot)
+        );
+    }
+
+    test_sequencer
+        .registry
+        .begin_batch_hook(&test_batch, &unknown_sequencer_da_address, 8, &mut state)
+        .unwrap();
+    assert_eq!(
+        test_sequencer
+            .registry
+            .last_active_slot(&unknown_sequencer_da_address, &mut state)?,
+        None
+    );
+
+    Ok(())
+}
@@ -1,14 +1,22 @@
 use std::convert::Infallible;
 
 use sov_bank::Payable;
-use sov_mock_da::MockAddress;
-use sov_modules_api::{Context, Module};
+use sov_chain_state::{AuditLogEntry, ChainStateConfig, PrivilegedAction};
+use sov_mock_da::{MockAddress, MockBlockHeader, MockValidityCond};
+use sov_modules_api::da::Time;
+use sov_modules_api::runtime::capabilities::mocks::MockKernel;
+use sov_modules_api::{
+    Context, Gas, GasArray, KernelModule, KernelWorkingSet, Module, Spec, StateCheckpoint,
+};
+use sov_prover_storage_manager::new_orphan_storage;
+use sov_state::jmt::RootHash;
+use sov_state::StorageRoot;
 use sov_test_utils::{TEST_DEFAULT_USER_BALANCE, TEST_DEFAULT_USER_STAKE};
 
 use crate::tests::helpers::{
-    generate_address, TestSequencer, ANOTHER_SEQUENCER_DA_ADDRESS, ANOTHER_SEQUENCER_KEY,
-    GENESIS_SEQUENCER_DA_ADDRESS, GENESIS_SEQUENCER_KEY, LOW_FUND_KEY, REWARD_SEQUENCER_KEY,
-    UNKNOWN_SEQUENCER_DA_ADDRESS,
+    create_test_sequencer, generate_address, Da, TestSequencer, ANOTHER_SEQUENCER_DA_ADDRESS,
+    ANOTHER_SEQUENCER_KEY, GENESIS_SEQUENCER_DA_ADDRESS, GENESIS_SEQUENCER_KEY, LOW_FUND_KEY,
+    REWARD_SEQUENCER_KEY, UNKNOWN_SEQUENCER_DA_ADDRESS,
 };
 use crate::{CallMessage, SequencerRegistryError};
 
@@ -18,7 +26,8 @@ type S = sov_test_utils::TestSpec;
 // This test checks:
 //  - genesis sequencer is present after genesis
 //  - registration works, and funds are deducted
-//  - exit works and funds are returned
+//  - exit works and funds are locked until the bond is withdrawn
+//  - withdrawal works and funds are returned
 #[test]
 fn test_registration_lifecycle() -> Result<(), Infallible> {
     let (test_sequencer, mut state) =
@@ -46,6 +55,7 @@ fn test_registration_lifecycle() -> Result<(), Infallible> {
     let register_message = CallMessage::Register {
         da_address: da_address.as_ref().to_vec(),
         amount: TEST_DEFAULT_USER_STAKE,
+        minimum_bond: None,
     };
     test_sequencer
         .registry
@@ -82,13 +92,138 @@ fn test_registration_lifecycle() -> Result<(), Infallible> {
     let balance_after_exit = test_sequencer
         .query_balance((&sequencer_address).as_token_holder(), &mut state)?
         .unwrap();
-    assert_eq!(balance_before, balance_after_exit);
+    assert_eq!(balance_after_registration, balance_after_exit);
 
     let registry_response_after_exit = test_sequencer
         .registry
         .get_sequencer_address(da_address, &mut state)?;
     assert!(registry_response_after_exit.is_none());
 
+    let unbonding_info = test_sequencer
+        .registry
+        .get_unbonding_info(&da_address, &mut state)?
+        .expect("The exited sequencer should be unbonding");
+    assert_eq!(unbonding_info.address, sequencer_address);
+    assert_eq!(unbonding_info.amount, TEST_DEFAULT_USER_STAKE);
+
+    let withdraw_message = CallMessage::WithdrawBond {
+        da_address: da_address.as_ref().to_vec(),
+    };
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .call(withdraw_message, &sender_context, &mut state)
+        .expect("Bond withdrawal has failed");
+    let mut state = state.checkpoint().0;
+
+    let balance_after_withdrawal = test_sequencer
+        .query_balance((&sequencer_address).as_token_holder(), &mut state)?
+        .unwrap();
+    assert_eq!(balance_before, balance_after_withdrawal);
+    assert!(test_sequencer
+        .registry
+        .get_unbonding_info(&da_address, &mut state)?
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_withdraw_bond_after_unbonding_period() -> Result<(), Infallible> {
+    const UNBONDING_PERIOD: u64 = 10;
+
+    let mut test_sequencer = create_test_sequencer(TEST_DEFAULT_USER_BALANCE, false);
+    test_sequencer.sequencer_config.unbonding_period = UNBONDING_PERIOD;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let state = test_sequencer.genesis(StateCheckpoint::new(
+        new_orphan_storage(tmpdir.path()).unwrap(),
+    ));
+
+    let da_address = MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS);
+    let sequencer_address = generate_address(ANOTHER_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+    let context_at = |height| {
+        Context::<S>::new(
+            sequencer_address,
+            Default::default(),
+            reward_address,
+            height,
+        )
+    };
+
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .register(
+            &da_address,
+            TEST_DEFAULT_USER_STAKE,
+            None,
+            &context_at(1),
+            &mut state,
+        )
+        .expect("Sequencer registration has failed");
+    test_sequencer
+        .registry
+        .exit(&da_address, &context_at(1), &mut state)
+        .expect("Sequencer exit has failed");
+
+    let withdrawable_from = 1 + UNBONDING_PERIOD;
+    assert_eq!(
+        test_sequencer
+            .registry
+            .withdraw_bond(&da_address, &context_at(withdrawable_from - 1), &mut state)
+            .unwrap_err(),
+        SequencerRegistryError::UnbondingPeriodNotElapsed {
+            withdrawable_from,
+            current_slot: withdrawable_from - 1,
+        }
+    );
+
+    // The sequencer can't come back before withdrawing its bond.
+    assert_eq!(
+        test_sequencer
+            .registry
+            .register(
+                &da_address,
+                TEST_DEFAULT_USER_STAKE,
+                None,
+                &context_at(withdrawable_from - 1),
+                &mut state,
+            )
+            .unwrap_err(),
+        SequencerRegistryError::SequencerIsUnbonding(da_address)
+    );
+
+    let attacker_address = generate_address("some_random_key");
+    let attacker_context = Context::<S>::new(
+        attacker_address,
+        Default::default(),
+        reward_address,
+        withdrawable_from,
+    );
+    assert_eq!(
+        test_sequencer
+            .registry
+            .withdraw_bond(&da_address, &attacker_context, &mut state)
+            .unwrap_err(),
+        SequencerRegistryError::SuppliedAddressDoesNotMatchTxSender {
+            parameter: sequencer_address,
+            sender: attacker_address,
+        }
+    );
+
+    test_sequencer
+        .registry
+        .withdraw_bond(&da_address, &context_at(withdrawable_from), &mut state)
+        .expect("Bond withdrawal has failed");
+    assert_eq!(
+        test_sequencer
+            .registry
+            .withdraw_bond(&da_address, &context_at(withdrawable_from), &mut state)
+            .unwrap_err(),
+        SequencerRegistryError::NoBondToWithdraw(da_address)
+    );
+
     Ok(())
 }
 
@@ -107,6 +242,7 @@ fn test_registration_not_enough_funds() -> Result<(), Infallible> {
     let response = test_sequencer.registry.register(
         &da_address,
         TEST_DEFAULT_USER_STAKE,
+        None,
         &sender_context,
         &mut state,
     );
@@ -140,6 +276,7 @@ fn test_registration_second_time() -> Result<(), Infallible> {
     let response = test_sequencer.registry.register(
         &da_address,
         TEST_DEFAULT_USER_STAKE,
+        None,
         &sender_context,
         &mut state,
     );
@@ -173,6 +310,7 @@ fn test_exit_different_sender() -> Result<(), Infallible> {
         .register(
             &MockAddress::new(ANOTHER_SEQUENCER_DA_ADDRESS),
             TEST_DEFAULT_USER_STAKE,
+            None,
             &sender_context,
             &mut state,
         )
@@ -277,6 +415,114 @@ fn test_preferred_sequencer_returned_and_removed() -> Result<(), Infallible> {
     Ok(())
 }
 
+#[test]
+fn test_preferred_sequencer_exit_is_audited() -> Result<(), Infallible> {
+    let (test_sequencer, mut state) =
+        TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, true)?;
+    let chain_state = &test_sequencer.registry.chain_state;
+    chain_state
+        .genesis_unchecked(
+            &ChainStateConfig {
+                current_time: Default::default(),
+                genesis_da_height: 0,
+                inner_code_commitment: Default::default(),
+                outer_code_commitment: Default::default(),
+                state_access_budget: Default::default(),
+                base_fee_policy: Default::default(),
+                gas_limit_policy: Default::default(),
+                transition_retention_window: None,
+                admin: None,
+            },
+            &mut KernelWorkingSet::uninitialized(&mut state),
+        )
+        .unwrap();
+
+    let mock_kernel = MockKernel::<S, Da>::new(0, 0);
+    chain_state.begin_slot_hook(
+        &MockBlockHeader {
+            prev_hash: [0; 32].into(),
+            hash: [1; 32].into(),
+            height: 1,
+            time: Time::now(),
+        },
+        &MockValidityCond { is_valid: true },
+        &StorageRoot::new(RootHash([0; 32]), RootHash([0; 32])),
+        &mut KernelWorkingSet::from_kernel(&mock_kernel, &mut state),
+    );
+
+    let sequencer_address = generate_address(GENESIS_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+    let sender_context =
+        Context::<S>::new(sequencer_address, Default::default(), reward_address, 1);
+    let exit_message = CallMessage::Exit {
+        da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+    };
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .call(exit_message, &sender_context, &mut state)
+        .expect("Preferred sequencer exit has failed");
+    let mut state = state.checkpoint().0;
+
+    chain_state.end_slot_hook(
+        &<S as Spec>::Gas::zero(),
+        &mut KernelWorkingSet::from_kernel(&mock_kernel, &mut state),
+    );
+
+    assert_eq!(
+        chain_state.audit_log_entries(0, 2, &mut state.accessory_state())?,
+        vec![(
+            0,
+            AuditLogEntry {
+                slot: 1,
+                actor: sequencer_address,
+                action: PrivilegedAction::PreferredSequencerRemoved {
+                    da_address: GENESIS_SEQUENCER_DA_ADDRESS.to_vec(),
+                },
+            }
+        )]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_genesis_require_preferred_sequencer() -> Result<(), Infallible> {
+    for (require_preferred_sequencer, is_preferred_sequencer, should_succeed) in [
+        // Required but missing
+        (true, false, false),
+        // Required and present
+        (true, true, true),
+        // Not required
+        (false, false, true),
+    ] {
+        let mut test_sequencer =
+            create_test_sequencer(TEST_DEFAULT_USER_BALANCE, is_preferred_sequencer);
+        test_sequencer.sequencer_config.require_preferred_sequencer = require_preferred_sequencer;
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let state = StateCheckpoint::new(new_orphan_storage(tmpdir.path()).unwrap());
+        let result = test_sequencer.try_genesis(state);
+        assert_eq!(
+            result.is_ok(),
+            should_succeed,
+            "require_preferred_sequencer: {require_preferred_sequencer}, is_preferred_sequencer: {is_preferred_sequencer}"
+        );
+
+        if let Ok(mut state) = result {
+            assert_eq!(
+                test_sequencer
+                    .registry
+                    .get_preferred_sequencer(&mut state)?
+                    .is_some(),
+                is_preferred_sequencer
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_registration_balance_increase() -> Result<(), Infallible> {
     let (test_sequencer, state) = TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
@@ -303,6 +549,7 @@ fn test_registration_balance_increase() -> Result<(), Infallible> {
     let register_message = CallMessage::Register {
         da_address: da_address.as_ref().to_vec(),
         amount: TEST_DEFAULT_USER_STAKE,
+        minimum_bond: None,
     };
 
     let mut state = state.to_working_set_unmetered();
@@ -393,6 +640,7 @@ fn test_balance_increase_fails_if_insufficient_funds() -> Result<(), Infallible>
     let register_message = CallMessage::Register {
         da_address: da_address.as_ref().to_vec(),
         amount: TEST_DEFAULT_USER_STAKE,
+        minimum_bond: None,
     };
 
     let mut state = state.to_working_set_unmetered();
@@ -464,6 +712,7 @@ fn test_balance_increase_fails_for_unknown_sequencer() -> Result<(), Infallible>
     let register_message = CallMessage::Register {
         da_address: da_address.as_ref().to_vec(),
         amount: TEST_DEFAULT_USER_STAKE,
+        minimum_bond: None,
     };
 
     let mut state = state.to_working_set_unmetered();
@@ -511,3 +760,194 @@ fn test_balance_increase_fails_for_unknown_sequencer() -> Result<(), Infallible>
 
     Ok(())
 }
+
+#[test]
+fn test_per_sequencer_minimum_bond() -> Result<(), Infallible> {
+    let (test_sequencer, state) = TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+
+    let da_address = MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS);
+    let sequencer_address = generate_address(ANOTHER_SEQUENCER_KEY);
+    let reward_address = generate_address(REWARD_SEQUENCER_KEY);
+    let sender_context =
+        Context::<S>::new(sequencer_address, Default::default(), reward_address, 1);
+
+    let mut state = state.to_working_set_unmetered();
+    assert_eq!(
+        test_sequencer
+            .registry
+            .register(
+                &da_address,
+                TEST_DEFAULT_USER_STAKE,
+                Some(2 * TEST_DEFAULT_USER_STAKE),
+                &sender_context,
+                &mut state,
+            )
+            .unwrap_err(),
+        SequencerRegistryError::InsufficientStakeAmount {
+            bond_amount: TEST_DEFAULT_USER_STAKE,
+            minimum_bond_amount: 2 * TEST_DEFAULT_USER_STAKE,
+        }
+    );
+
+    // The global minimum bond is a floor for the per-sequencer minimum bond.
+    assert_eq!(
+        test_sequencer
+            .registry
+            .register(
+                &da_address,
+                TEST_DEFAULT_USER_STAKE - 1,
+                Some(1),
+                &sender_context,
+                &mut state,
+            )
+            .unwrap_err(),
+        SequencerRegistryError::InsufficientStakeAmount {
+            bond_amount: TEST_DEFAULT_USER_STAKE - 1,
+            minimum_bond_amount: TEST_DEFAULT_USER_STAKE,
+        }
+    );
+
+    test_sequencer
+        .registry
+        .register(
+            &da_address,
+            2 * TEST_DEFAULT_USER_STAKE,
+            Some(2 * TEST_DEFAULT_USER_STAKE),
+            &sender_context,
+            &mut state,
+        )
+        .expect("Sequencer registration has failed");
+
+    assert_eq!(
+        test_sequencer
+            .registry
+            .set_minimum_bond(
+                &da_address,
+                Some(3 * TEST_DEFAULT_USER_STAKE),
+                &sender_context,
+                &mut state,
+            )
+            .unwrap_err(),
+        SequencerRegistryError::InsufficientStakeAmount {
+            bond_amount: 2 * TEST_DEFAULT_USER_STAKE,
+            minimum_bond_amount: 3 * TEST_DEFAULT_USER_STAKE,
+        }
+    );
+    let mut state = state.checkpoint().0;
+    assert!(test_sequencer.query_if_sequencer_is_allowed(&da_address, &mut state));
+
+    // Raising the global minimum bond above the sequencer's own minimum bond takes precedence.
+    test_sequencer.set_coins_amount_to_lock(3 * TEST_DEFAULT_USER_STAKE, &mut state)?;
+    assert!(!test_sequencer.query_if_sequencer_is_allowed(&da_address, &mut state));
+    test_sequencer.set_coins_amount_to_lock(TEST_DEFAULT_USER_STAKE, &mut state)?;
+
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .call(
+            CallMessage::SetMinimumBond {
+                da_address: da_address.as_ref().to_vec(),
+                minimum_bond: None,
+            },
+            &sender_context,
+            &mut state,
+        )
+        .expect("Setting the minimum bond has failed");
+    let mut state = state.checkpoint().0;
+    assert_eq!(
+        test_sequencer
+            .registry
+            .allowed_sequencers
+            .get(&da_address, &mut state)?
+            .unwrap()
+            .effective_minimum_bond(TEST_DEFAULT_USER_STAKE),
+        TEST_DEFAULT_USER_STAKE
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_list_sequencers() -> Result<(), Infallible> {
+    let (test_sequencer, mut state) =
+        TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let genesis_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let another_da_address = MockAddress::from(ANOTHER_SEQUENCER_DA_ADDRESS);
+
+    let sequencers = test_sequencer.registry.list_sequencers(0, 10, &mut state)?;
+    assert_eq!(sequencers.len(), 1);
+    assert_eq!(sequencers[0].0, genesis_da_address);
+    assert_eq!(
+        sequencers[0].1.address,
+        generate_address(GENESIS_SEQUENCER_KEY)
+    );
+    assert_eq!(sequencers[0].1.balance, TEST_DEFAULT_USER_STAKE);
+
+    let another_address = generate_address(ANOTHER_SEQUENCER_KEY);
+    let another_context = Context::<S>::new(
+        another_address,
+        Default::default(),
+        generate_address(REWARD_SEQUENCER_KEY),
+        1,
+    );
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .call(
+            CallMessage::Register {
+                da_address: another_da_address.as_ref().to_vec(),
+                amount: TEST_DEFAULT_USER_STAKE,
+                minimum_bond: None,
+            },
+            &another_context,
+            &mut state,
+        )
+        .expect("Sequencer registration has failed");
+    let mut state = state.checkpoint().0;
+
+    assert_eq!(test_sequencer.registry.sequencer_count(&mut state)?, 2);
+    let sequencers = test_sequencer.registry.list_sequencers(0, 10, &mut state)?;
+    assert_eq!(
+        sequencers
+            .iter()
+            .map(|(da_address, _)| *da_address)
+            .collect::<Vec<_>>(),
+        vec![genesis_da_address, another_da_address]
+    );
+
+    // Pages are truncated at the end of the registry.
+    let second_page = test_sequencer.registry.list_sequencers(1, 1, &mut state)?;
+    assert_eq!(second_page, sequencers[1..]);
+    assert!(test_sequencer
+        .registry
+        .list_sequencers(2, 10, &mut state)?
+        .is_empty());
+
+    // Exited sequencers are no longer listed.
+    let genesis_context = Context::<S>::new(
+        generate_address(GENESIS_SEQUENCER_KEY),
+        Default::default(),
+        generate_address(REWARD_SEQUENCER_KEY),
+        1,
+    );
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .call(
+            CallMessage::Exit {
+                da_address: genesis_da_address.as_ref().to_vec(),
+            },
+            &genesis_context,
+            &mut state,
+        )
+        .expect("Sequencer exit has failed");
+    let mut state = state.checkpoint().0;
+
+    assert_eq!(test_sequencer.registry.sequencer_count(&mut state)?, 1);
+    let sequencers = test_sequencer.registry.list_sequencers(0, 10, &mut state)?;
+    assert_eq!(sequencers.len(), 1);
+    assert_eq!(sequencers[0].0, another_da_address);
+    assert_eq!(sequencers[0].1.address, another_address);
+
+    Ok(())
+}
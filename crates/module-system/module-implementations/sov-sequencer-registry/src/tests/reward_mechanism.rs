@@ -4,12 +4,12 @@ use sov_bank::{IntoPayable, Payable, ReserveGasError};
 use sov_modules_api::hooks::ApplyBatchHooks;
 use sov_modules_api::transaction::{PriorityFeeBips, SequencerReward};
 use sov_modules_api::{
-    Batch, BatchWithId, Gas, GasArray, GasMeter, GasUnit, ModuleInfo, RawTx, Spec,
+    Batch, BatchWithId, Context, Gas, GasArray, GasMeter, GasUnit, Module, ModuleInfo, RawTx, Spec,
 };
 use sov_test_utils::{generate_empty_tx, TEST_DEFAULT_USER_BALANCE, TEST_DEFAULT_USER_STAKE};
 
-use super::helpers::{TestSequencer, S};
-use crate::BatchSequencerOutcome;
+use super::helpers::{generate_address, TestSequencer, REWARD_SEQUENCER_KEY, S};
+use crate::{BatchSequencerOutcome, CallMessage};
 
 /// Tests that the sequencer gets correctly rewarded when it processes a batch and:
 /// - the `GasEnforcer` capability is correctly used (hence the module has enough funds to pay for the reward)
@@ -49,7 +49,7 @@ fn test_reward_sequencer() -> Result<(), Infallible> {
 
     sequencer_test
         .registry
-        .begin_batch_hook(&test_batch, &seq_da_address, &mut state)
+        .begin_batch_hook(&test_batch, &seq_da_address, 1, &mut state)
         .expect("The begin batch hook should succeed");
 
     let transaction_scratchpad = state.to_tx_scratchpad();
@@ -162,3 +162,136 @@ fn test_penalize_sequencer() -> Result<(), Infallible> {
 
     Ok(())
 }
+
+/// Tests that the rewards are paid to the sequencer's own address when no reward recipient is set.
+#[test]
+fn test_reward_without_recipient_override() -> Result<(), Infallible> {
+    let (sequencer_test, mut state) =
+        TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let seq_da_address = sequencer_test.sequencer_config.seq_da_address;
+    let reward = TEST_DEFAULT_USER_STAKE / 2;
+
+    let balance_before = sequencer_test.query_sequencer_balance(&mut state)?.unwrap();
+
+    sequencer_test.registry.end_batch_hook(
+        BatchSequencerOutcome::Rewarded(SequencerReward(reward)),
+        &seq_da_address,
+        &mut state,
+    );
+
+    assert_eq!(
+        sequencer_test.query_sequencer_balance(&mut state)?.unwrap(),
+        balance_before + reward
+    );
+
+    Ok(())
+}
+
+/// Tests that the rewards are paid to the reward recipient when it is set, and to the sequencer
+/// again once the override is removed.
+#[test]
+fn test_reward_with_recipient_override() -> Result<(), Infallible> {
+    let (sequencer_test, state) = TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let seq_address = sequencer_test.sequencer_config.seq_rollup_address;
+    let seq_da_address = sequencer_test.sequencer_config.seq_da_address;
+    let recipient = generate_address("reward_recipient");
+    let reward = TEST_DEFAULT_USER_STAKE / 4;
+
+    let sender_context = Context::<S>::new(
+        seq_address,
+        Default::default(),
+        generate_address(REWARD_SEQUENCER_KEY),
+        1,
+    );
+    let set_recipient = |reward_recipient| CallMessage::SetRewardRecipient {
+        da_address: seq_da_address.as_ref().to_vec(),
+        reward_recipient,
+    };
+
+    let mut state = state.to_working_set_unmetered();
+    sequencer_test
+        .registry
+        .call(set_recipient(Some(recipient)), &sender_context, &mut state)
+        .expect("Setting the reward recipient has failed");
+    let mut state = state.checkpoint().0;
+
+    let sequencer_balance_before = sequencer_test.query_sequencer_balance(&mut state)?.unwrap();
+    assert_eq!(sequencer_test.query_balance(&recipient, &mut state)?, None);
+
+    sequencer_test.registry.end_batch_hook(
+        BatchSequencerOutcome::Rewarded(SequencerReward(reward)),
+        &seq_da_address,
+        &mut state,
+    );
+
+    assert_eq!(
+        sequencer_test.query_balance(&recipient, &mut state)?,
+        Some(reward)
+    );
+    assert_eq!(
+        sequencer_test.query_sequencer_balance(&mut state)?.unwrap(),
+        sequencer_balance_before
+    );
+
+    // Removing the override pays the rewards to the sequencer again.
+    let mut state = state.to_working_set_unmetered();
+    sequencer_test
+        .registry
+        .call(set_recipient(None), &sender_context, &mut state)
+        .expect("Removing the reward recipient has failed");
+    let mut state = state.checkpoint().0;
+
+    sequencer_test.registry.end_batch_hook(
+        BatchSequencerOutcome::Rewarded(SequencerReward(reward)),
+        &seq_da_address,
+        &mut state,
+    );
+
+    assert_eq!(
+        sequencer_test.query_balance(&recipient, &mut state)?,
+        Some(reward)
+    );
+    assert_eq!(
+        sequencer_test.query_sequencer_balance(&mut state)?.unwrap(),
+        sequencer_balance_before + reward
+    );
+
+    Ok(())
+}
+
+/// Tests that only the sequencer itself can set its reward recipient.
+#[test]
+fn test_set_reward_recipient_wrong_sender() -> Result<(), Infallible> {
+    let (sequencer_test, state) = TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let seq_da_address = sequencer_test.sequencer_config.seq_da_address;
+    let attacker = generate_address("attacker");
+
+    let sender_context = Context::<S>::new(
+        attacker,
+        Default::default(),
+        generate_address(REWARD_SEQUENCER_KEY),
+        1,
+    );
+
+    let mut state = state.to_working_set_unmetered();
+    assert!(sequencer_test
+        .registry
+        .call(
+            CallMessage::SetRewardRecipient {
+                da_address: seq_da_address.as_ref().to_vec(),
+                reward_recipient: Some(attacker),
+            },
+            &sender_context,
+            &mut state,
+        )
+        .is_err());
+    let mut state = state.checkpoint().0;
+
+    let sequencer = sequencer_test
+        .registry
+        .is_sender_allowed(&seq_da_address, &mut state)
+        .unwrap();
+    assert_eq!(sequencer.reward_recipient, None);
+
+    Ok(())
+}
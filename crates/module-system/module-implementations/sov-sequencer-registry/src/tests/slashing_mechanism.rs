@@ -1,17 +1,24 @@
 use std::convert::Infallible;
+use std::io;
+use std::sync::{Arc, Mutex};
 
 use sov_bank::GAS_TOKEN_ID;
 use sov_mock_da::{MockAddress, MockDaSpec};
 use sov_modules_api::capabilities::FatalError;
 use sov_modules_api::hooks::ApplyBatchHooks;
-use sov_modules_api::{Batch, BatchWithId, Context, Module};
+use sov_modules_api::{Batch, BatchWithId, Context, Module, StateCheckpoint};
+use sov_prover_storage_manager::new_orphan_storage;
 use sov_test_utils::{TEST_DEFAULT_USER_BALANCE, TEST_DEFAULT_USER_STAKE};
+use tracing_subscriber::util::SubscriberInitExt;
 
 use crate::tests::helpers::{
-    generate_address, Da, TestSequencer, GENESIS_SEQUENCER_DA_ADDRESS, GENESIS_SEQUENCER_KEY,
-    REWARD_SEQUENCER_KEY, S, UNKNOWN_SEQUENCER_DA_ADDRESS,
+    create_test_sequencer, generate_address, Da, TestSequencer, GENESIS_SEQUENCER_DA_ADDRESS,
+    GENESIS_SEQUENCER_KEY, REWARD_SEQUENCER_KEY, S, UNKNOWN_SEQUENCER_DA_ADDRESS,
+};
+use crate::{
+    BatchSequencerOutcome, CallMessage, SequencerRegistry, SequencerRegistryError, SlashingReason,
+    UnbondingInfo,
 };
-use crate::{BatchSequencerOutcome, CallMessage, SequencerRegistry};
 
 /// Tests the slashing mechanism on the `end_batch_hook` method.
 #[test]
@@ -30,7 +37,7 @@ fn end_batch_hook_slash() -> Result<(), Infallible> {
 
     test_sequencer
         .registry
-        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, &mut state)
+        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, 1, &mut state)
         .unwrap();
 
     let result = BatchSequencerOutcome::Slashed(FatalError::Other("error".to_string()));
@@ -51,6 +58,236 @@ fn end_batch_hook_slash() -> Result<(), Infallible> {
     Ok(())
 }
 
+/// Tests that the bond of a sequencer which exited can still be slashed until it is withdrawn.
+#[test]
+fn slash_unbonding_sequencer() -> Result<(), Infallible> {
+    let (test_sequencer, state) = TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let sender_context = Context::<S>::new(
+        generate_address(GENESIS_SEQUENCER_KEY),
+        Default::default(),
+        generate_address(REWARD_SEQUENCER_KEY),
+        1,
+    );
+
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .exit(&genesis_sequencer_da_address, &sender_context, &mut state)
+        .expect("Sequencer exit has failed");
+    let mut state = state.checkpoint().0;
+    assert!(test_sequencer
+        .registry
+        .get_unbonding_info(&genesis_sequencer_da_address, &mut state)?
+        .is_some());
+
+    test_sequencer.registry.slash_sequencer(
+        &genesis_sequencer_da_address,
+        SlashingReason::InvalidBatchEncoding,
+        &mut state,
+    );
+
+    let mut state = state.to_working_set_unmetered();
+    assert_eq!(
+        test_sequencer
+            .registry
+            .withdraw_bond(&genesis_sequencer_da_address, &sender_context, &mut state)
+            .unwrap_err(),
+        SequencerRegistryError::NoBondToWithdraw(genesis_sequencer_da_address)
+    );
+
+    Ok(())
+}
+
+/// Tests that a partial slash only deducts the slashed fraction from the bond of a sequencer which
+/// stays above its minimum bond.
+#[test]
+fn slash_sequencer_partial_above_minimum_bond() -> Result<(), Infallible> {
+    let (test_sequencer, state) = TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+
+    let mut state = state.to_working_set_unmetered();
+    test_sequencer
+        .registry
+        .increase_sender_balance(
+            &genesis_sequencer_da_address,
+            TEST_DEFAULT_USER_STAKE,
+            &mut state,
+        )
+        .expect("The deposit should succeed");
+    let mut state = state.checkpoint().0;
+
+    // A quarter of twice the minimum bond is slashed.
+    test_sequencer.registry.slash_sequencer_partial(
+        &genesis_sequencer_da_address,
+        2_500,
+        SlashingReason::InvalidBatchEncoding,
+        &mut state,
+    );
+
+    assert_eq!(
+        test_sequencer
+            .registry
+            .get_sender_balance(&genesis_sequencer_da_address, &mut state)?,
+        Some(3 * TEST_DEFAULT_USER_STAKE / 2)
+    );
+    assert!(test_sequencer.query_if_sequencer_is_allowed(&genesis_sequencer_da_address, &mut state));
+    assert!(test_sequencer
+        .registry
+        .get_unbonding_info(&genesis_sequencer_da_address, &mut state)?
+        .is_none());
+
+    Ok(())
+}
+
+/// Tests that a partial slash with the configured fraction deregisters a sequencer whose remaining
+/// bond drops below the minimum bond, and lets it withdraw the remaining bond after unbonding.
+#[test]
+fn slash_sequencer_partial_below_minimum_bond() -> Result<(), Infallible> {
+    const SLASH_FRACTION_BIPS: u64 = 2_500;
+    const UNBONDING_PERIOD: u64 = 10;
+    const SLOT: u64 = 5;
+
+    let mut test_sequencer = create_test_sequencer(TEST_DEFAULT_USER_BALANCE, false);
+    test_sequencer.sequencer_config.slash_fraction_bips = SLASH_FRACTION_BIPS;
+    test_sequencer.sequencer_config.unbonding_period = UNBONDING_PERIOD;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut state = test_sequencer.genesis(StateCheckpoint::new(
+        new_orphan_storage(tmpdir.path()).unwrap(),
+    ));
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+
+    let test_batch = BatchWithId {
+        batch: Batch { txs: vec![] },
+        id: [0u8; 32],
+    };
+    test_sequencer
+        .registry
+        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, SLOT, &mut state)
+        .unwrap();
+    <SequencerRegistry<S, Da> as ApplyBatchHooks<MockDaSpec>>::end_batch_hook(
+        &test_sequencer.registry,
+        BatchSequencerOutcome::Slashed(FatalError::Other("error".to_string())),
+        &genesis_sequencer_da_address,
+        &mut state,
+    );
+
+    assert!(test_sequencer
+        .registry
+        .resolve_da_address(&genesis_sequencer_da_address, &mut state)?
+        .is_none());
+    assert_eq!(
+        test_sequencer
+            .registry
+            .get_unbonding_info(&genesis_sequencer_da_address, &mut state)?,
+        Some(UnbondingInfo {
+            address: generate_address(GENESIS_SEQUENCER_KEY),
+            amount: 3 * TEST_DEFAULT_USER_STAKE / 4,
+            withdrawable_from: SLOT + UNBONDING_PERIOD,
+        })
+    );
+
+    Ok(())
+}
+
+/// Collects everything written by a [`tracing_subscriber`] formatter.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Tests that slashing emits a structured log with the sequencer, the reason and the forfeited amount.
+#[test]
+fn slash_sequencer_is_logged() -> Result<(), Infallible> {
+    let (test_sequencer, mut state) =
+        TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+
+    let logs = CapturedLogs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer({
+            let logs = logs.clone();
+            move || logs.clone()
+        })
+        .with_ansi(false)
+        .finish();
+
+    {
+        let _guard = subscriber.set_default();
+        test_sequencer.registry.slash_sequencer(
+            &genesis_sequencer_da_address,
+            SlashingReason::InvalidBatchEncoding,
+            &mut state,
+        );
+    }
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    let slash_log = logs
+        .lines()
+        .find(|line| line.contains("Sequencer slashed"))
+        .expect("Slashing must be logged");
+    assert!(slash_log.contains(&format!(
+        "sequencer_da_address={}",
+        genesis_sequencer_da_address
+    )));
+    assert!(slash_log.contains("reason=InvalidBatchEncoding"));
+    assert!(slash_log.contains(&format!("forfeited_amount={}", TEST_DEFAULT_USER_STAKE)));
+
+    Ok(())
+}
+
+/// Tests that a partial slash dropping the balance below the minimum bond reports the
+/// sequencer as ineligible.
+#[test]
+fn slash_sequencer_partial_reports_ineligibility() -> Result<(), Infallible> {
+    let (test_sequencer, mut state) =
+        TestSequencer::initialize_test(TEST_DEFAULT_USER_BALANCE, false)?;
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+
+    let logs = CapturedLogs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer({
+            let logs = logs.clone();
+            move || logs.clone()
+        })
+        .with_ansi(false)
+        .finish();
+
+    {
+        let _guard = subscriber.set_default();
+        test_sequencer.registry.slash_sequencer_partial(
+            &genesis_sequencer_da_address,
+            2_500,
+            SlashingReason::InvalidBatchEncoding,
+            &mut state,
+        );
+    }
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    let ineligible_log = logs
+        .lines()
+        .find(|line| line.contains("Sequencer became ineligible"))
+        .expect("Ineligibility must be reported");
+    assert!(ineligible_log.contains(&format!(
+        "sequencer={}",
+        generate_address(GENESIS_SEQUENCER_KEY)
+    )));
+    assert!(ineligible_log.contains(&format!("balance={}", 3 * TEST_DEFAULT_USER_STAKE / 4)));
+    assert!(ineligible_log.contains(&format!("minimum_bond={}", TEST_DEFAULT_USER_STAKE)));
+    assert!(ineligible_log.contains("BecameIneligible"));
+
+    Ok(())
+}
+
 /// Tests the slashing mechanism for a preferred sequencer on the `end_batch_hook`
 #[test]
 fn end_batch_hook_slash_preferred_sequencer() -> Result<(), Infallible> {
@@ -67,7 +304,7 @@ fn end_batch_hook_slash_preferred_sequencer() -> Result<(), Infallible> {
 
     test_sequencer
         .registry
-        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, &mut state)
+        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, 1, &mut state)
         .unwrap();
 
     <SequencerRegistry<S, Da> as ApplyBatchHooks<MockDaSpec>>::end_batch_hook(
@@ -108,6 +345,7 @@ fn end_batch_hook_slash_unknown_sequencer() -> Result<(), Infallible> {
         .begin_batch_hook(
             &test_batch,
             &MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS),
+            1,
             &mut state,
         )
         .unwrap();
@@ -149,6 +387,7 @@ fn begin_batch_hook_without_enough_stake() -> Result<(), Infallible> {
     let res = test_sequencer.registry.begin_batch_hook(
         &test_batch,
         &genesis_sequencer_da_address,
+        1,
         &mut state,
     );
 
@@ -249,7 +488,7 @@ fn slashed_sequencer_should_not_preserve_balance() -> Result<(), Infallible> {
 
     test_sequencer
         .registry
-        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, &mut state)
+        .begin_batch_hook(&test_batch, &genesis_sequencer_da_address, 1, &mut state)
         .unwrap();
 
     test_sequencer
@@ -282,6 +521,7 @@ fn slashed_sequencer_should_not_preserve_balance() -> Result<(), Infallible> {
     let register_message = CallMessage::Register {
         da_address: genesis_sequencer_da_address.as_ref().to_vec(),
         amount: TEST_DEFAULT_USER_STAKE,
+        minimum_bond: None,
     };
 
     let mut state = state.to_working_set_unmetered();
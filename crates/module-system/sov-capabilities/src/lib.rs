@@ -11,6 +11,7 @@ use sov_modules_api::{
     Context, DaSpec, Gas, GasMeter, ModuleInfo, PreExecWorkingSet, ProofOutcome, ProofReceipt,
     Spec, StateCheckpoint, Storage, TxScratchpad, UnlimitedGasMeter, WorkingSet,
 };
+use sov_nonces::DEFAULT_NONCE_SPACE;
 use sov_rollup_interface::zk::aggregated_proof::SerializedAggregatedProof;
 use sov_sequencer_registry::{SequencerRegistry, SequencerStakeMeter};
 
@@ -103,6 +104,7 @@ impl<'a, S: Spec, Da: DaSpec> RuntimeAuthorization<S, Da>
     ) -> Result<(), anyhow::Error> {
         self.nonces.check_nonce(
             &auth_data.credential_id,
+            DEFAULT_NONCE_SPACE,
             auth_data.nonce,
             pre_exec_working_set,
         )
@@ -116,7 +118,7 @@ impl<'a, S: Spec, Da: DaSpec> RuntimeAuthorization<S, Da>
         tx_scratchpad: &mut TxScratchpad<S>,
     ) {
         self.nonces
-            .mark_tx_attempted(&auth_data.credential_id, tx_scratchpad);
+            .mark_tx_attempted(&auth_data.credential_id, DEFAULT_NONCE_SPACE, tx_scratchpad);
     }
 
     /// Resolves the context for a transaction.
@@ -65,6 +65,7 @@ where
                 max_fee,
                 gas_limit,
                 chain_id,
+                valid_until_slot: None,
             },
         }
     }
@@ -72,13 +73,25 @@ where
     /// Creates a new [`UnsignedTransaction`] from this [`UnsignedTransactionWithoutNonce`] when
     /// given a nonce.
     pub fn with_nonce(&self, nonce: u64) -> UnsignedTransaction<S> {
-        UnsignedTransaction::new(
+        UnsignedTransaction::new_with_details(
             borsh::to_vec(&self.tx).unwrap(),
-            self.details.chain_id,
-            self.details.max_priority_fee_bips,
-            self.details.max_fee,
             nonce,
-            self.details.gas_limit.clone(),
+            self.details.clone(),
         )
     }
+
+    /// Returns the ID of the chain targeted by the transaction.
+    pub fn chain_id(&self) -> u64 {
+        self.details.chain_id
+    }
+
+    /// Returns the gas limit of the transaction, if any.
+    pub fn gas_limit(&self) -> Option<&S::Gas> {
+        self.details.gas_limit.as_ref()
+    }
+
+    /// Sets the gas limit of the transaction.
+    pub fn set_gas_limit(&mut self, gas_limit: Option<S::Gas>) {
+        self.details.gas_limit = gas_limit;
+    }
 }
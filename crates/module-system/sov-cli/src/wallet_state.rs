@@ -27,6 +27,10 @@ where
     pub rpc_url: Option<String>,
     /// The REST API URL
     pub rest_api_url: Option<String>,
+    /// The URL of the sequencer API, used to estimate the gas of transactions. Defaults to the
+    /// `/sequencer` endpoint of the REST API if unset.
+    #[serde(default)]
+    pub sequencer_url: Option<String>,
     /// The version of the library that serialized the state.
     pub version: String,
 }
@@ -44,6 +48,7 @@ where
             },
             rpc_url: None,
             rest_api_url: None,
+            sequencer_url: None,
             version: env!("CARGO_PKG_VERSION").to_string(),
         }
     }
@@ -106,6 +111,19 @@ This discrepancy may result in data layout inconsistency. Consider one of the fo
         }
     }
 
+    /// Returns the URL of the sequencer API: either the one set explicitly, or the `/sequencer`
+    /// endpoint of the REST API.
+    pub fn sequencer_url(&self) -> Result<String, anyhow::Error> {
+        if let Some(url) = &self.sequencer_url {
+            return Ok(url.clone());
+        }
+
+        let rest_api_url = self.rest_api_url.as_ref().ok_or(anyhow::format_err!(
+            "No REST API URL set. Use the `rpc set-url` subcommand to set one"
+        ))?;
+        Ok(format!("{}/sequencer", rest_api_url))
+    }
+
     /// Save the wallet state to the given path on disk
     pub fn save(&self, path: impl AsRef<Path>) -> Result<(), anyhow::Error> {
         let data = serde_json::to_string_pretty(self)?;
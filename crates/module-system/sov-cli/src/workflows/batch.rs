@@ -0,0 +1,153 @@
+//! Build batches of transactions out of several call messages
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use borsh::{BorshDeserialize, BorshSerialize};
+use jsonrpsee::http_client::HttpClientBuilder;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sov_modules_api::{clap, BlobData, CryptoSpec, RawTx, Spec};
+
+use crate::wallet_state::{sign_tx, AddressEntry, KeyIdentifier, WalletState};
+use crate::workflows::keys::load_key;
+use crate::workflows::rpc::get_nonce_for_account;
+use crate::workflows::NO_ACCOUNTS_FOUND;
+use crate::UnsignedTransactionWithoutNonce;
+
+/// Build batches of transactions out of several call messages
+#[derive(clap::Subcommand)]
+pub enum BatchWorkflow<S: Spec> {
+    /// Sign the transactions of a JSON file with sequential nonces, and write them to a file as a
+    /// single borsh encoded batch, ready to be submitted to the DA layer.
+    Build {
+        /// The path to a JSON array of transactions. Each transaction is made of a call message
+        /// (`tx`) and its `details` (chain ID, fees and gas limit), as listed by `transactions list`.
+        path: PathBuf,
+        /// The file to write the batch to.
+        #[arg(short, long)]
+        output: PathBuf,
+        /// (Optional) The nonce to use for the first transaction of the batch (default: the current nonce for the account,
+        /// queried from the RPC server). The other transactions are signed with sequential nonces starting from this value.
+        #[arg(short, long)]
+        nonce: Option<u64>,
+        /// (Optional) The account to sign the transactions with (default: the active account)
+        #[clap(subcommand)]
+        account: Option<KeyIdentifier<S>>,
+    },
+}
+
+impl<S: Spec + Serialize + DeserializeOwned + Send + Sync> BatchWorkflow<S> {
+    /// Run the batch workflow
+    pub async fn run<Tx>(
+        &self,
+        wallet_state: &mut WalletState<Tx, S>,
+        _app_dir: impl AsRef<Path>,
+    ) -> Result<(), anyhow::Error>
+    where
+        Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
+    {
+        match self {
+            BatchWorkflow::Build {
+                path,
+                output,
+                nonce,
+                account,
+            } => {
+                let data = std::fs::read_to_string(path)
+                    .with_context(|| format!("Unable to read {}", path.display()))?;
+                let txs: Vec<UnsignedTransactionWithoutNonce<S, Tx>> = serde_json::from_str(&data)
+                    .with_context(|| {
+                        format!(
+                            "{} is not a valid JSON array of transactions",
+                            path.display()
+                        )
+                    })?;
+
+                let account: &AddressEntry<S> = match account {
+                    Some(id) => wallet_state.addresses.get_address(id).ok_or_else(|| {
+                        anyhow::format_err!("No account found matching identifier: {}", id)
+                    })?,
+                    None => wallet_state
+                        .addresses
+                        .default_address()
+                        .ok_or_else(|| anyhow::format_err!(NO_ACCOUNTS_FOUND))?,
+                };
+                let private_key = load_key::<S>(&account.location).with_context(|| {
+                    format!("Unable to load key {}", account.location.display())
+                })?;
+
+                let nonce = match nonce {
+                    Some(nonce) => *nonce,
+                    None => {
+                        let rpc_url = wallet_state.rpc_url.as_ref().ok_or(anyhow::format_err!(
+                            "No RPC URL set. Use the `rpc set-url` subcommand to set one, or provide a nonce"
+                        ))?;
+                        let client = HttpClientBuilder::default().build(rpc_url)?;
+                        get_nonce_for_account(&client, account).await?
+                    }
+                };
+
+                let batch = build_batch(&txs, &private_key, nonce)?;
+                std::fs::write(output, batch)
+                    .with_context(|| format!("Unable to write to {}", output.display()))?;
+
+                println!(
+                    "Batch of {} transactions signed by {} with nonces starting from {} written to {}",
+                    txs.len(),
+                    account.address,
+                    nonce,
+                    output.display()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Signs the transactions with the provided key and sequential nonces starting from `nonce`, and
+/// returns them as a borsh encoded [`BlobData::Batch`].
+///
+/// Fails if the batch is empty or if the transactions don't all target the same chain.
+pub fn build_batch<S, Tx>(
+    txs: &[UnsignedTransactionWithoutNonce<S, Tx>],
+    signing_key: &<S::CryptoSpec as CryptoSpec>::PrivateKey,
+    nonce: u64,
+) -> anyhow::Result<Vec<u8>>
+where
+    S: Spec,
+    Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
+{
+    let chain_id = txs
+        .first()
+        .ok_or_else(|| anyhow::format_err!("The batch must contain at least one transaction"))?
+        .chain_id();
+    if let Some((index, tx)) = txs
+        .iter()
+        .enumerate()
+        .find(|(_, tx)| tx.chain_id() != chain_id)
+    {
+        anyhow::bail!(
+            "All the transactions of a batch must target the same chain, but transaction {} targets chain {} instead of {}",
+            index,
+            tx.chain_id(),
+            chain_id
+        );
+    }
+
+    let raw_txs = txs
+        .iter()
+        .enumerate()
+        .map(|(offset, tx)| {
+            let nonce = nonce
+                .checked_add(offset as u64)
+                .ok_or_else(|| anyhow::format_err!("Nonce overflow"))?;
+            Ok(RawTx {
+                data: sign_tx(signing_key, tx, nonce)?,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(borsh::to_vec(&BlobData::new_batch(raw_txs))?)
+}
@@ -0,0 +1,78 @@
+//! Estimate the gas used by transactions before signing them
+
+use base64::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sov_modules_api::{CryptoSpec, GasArray, Spec};
+use sov_sequencer_json_client::types;
+
+use crate::wallet_state::{sign_tx, WalletState};
+use crate::UnsignedTransactionWithoutNonce;
+
+/// Estimates the gas used by each unsent transaction of the wallet, and sets it as the gas limit
+/// of the transaction. Transactions whose gas can't be estimated keep their configured gas limit.
+///
+/// Each transaction is simulated on its own on top of the current state, with the provided
+/// `nonce`. Hence, the estimates don't account for the changes made by the preceding
+/// transactions of the batch.
+pub async fn estimate_gas_limits<S, Tx>(
+    wallet_state: &mut WalletState<Tx, S>,
+    sequencer_client: &sov_sequencer_json_client::Client,
+    signing_key: &<S::CryptoSpec as CryptoSpec>::PrivateKey,
+    nonce: u64,
+) where
+    S: Spec,
+    Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
+{
+    for (i, tx) in wallet_state.unsent_transactions.iter_mut().enumerate() {
+        match estimate_gas(tx, sequencer_client, signing_key, nonce).await {
+            Ok(gas_limit) => {
+                println!("Estimated gas for transaction {}: {}", i, gas_limit);
+                tx.set_gas_limit(Some(gas_limit));
+            }
+            Err(e) => {
+                println!(
+                    "Unable to estimate the gas of transaction {}, keeping the configured gas limit {:?}: {}",
+                    i,
+                    tx.gas_limit(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+async fn estimate_gas<S, Tx>(
+    tx: &mut UnsignedTransactionWithoutNonce<S, Tx>,
+    sequencer_client: &sov_sequencer_json_client::Client,
+    signing_key: &<S::CryptoSpec as CryptoSpec>::PrivateKey,
+    nonce: u64,
+) -> anyhow::Result<S::Gas>
+where
+    S: Spec,
+    Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
+{
+    // The transaction is simulated without a gas limit, so that it is executed with the gas
+    // limit of the block rather than with a possibly too low configured one.
+    let configured_gas_limit = tx.gas_limit().cloned();
+    tx.set_gas_limit(None);
+    let signed_tx = sign_tx(signing_key, tx, nonce);
+    tx.set_gas_limit(configured_gas_limit);
+
+    let response = sequencer_client
+        .estimate_gas(&types::EstimateGasBody {
+            body: BASE64_STANDARD.encode(signed_tx?),
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("The sequencer failed to estimate the gas: {}", e))?;
+
+    let gas_used = &response.data.gas_used;
+    anyhow::ensure!(
+        gas_used.len() == S::Gas::ZEROED.as_slice().len(),
+        "The gas estimate {:?} doesn't match the dimensions of the gas",
+        gas_used
+    );
+
+    Ok(S::Gas::from_slice(gas_used))
+}
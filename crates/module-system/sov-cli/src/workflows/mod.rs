@@ -2,6 +2,9 @@
 
 pub(crate) const NO_ACCOUNTS_FOUND: &str =
     "No accounts found. You can generate one with the `keys generate` subcommand";
+pub mod batch;
+pub mod gas;
 pub mod keys;
+pub mod proofs;
 pub mod rpc;
 pub mod transactions;
@@ -0,0 +1,82 @@
+//! Proof verification workflows for the sov CLI wallet
+use std::io::Write;
+
+use borsh::BorshDeserialize;
+use sov_modules_api::{clap, Spec};
+use sov_state::{ProvableNamespace, SlotKey, SlotValue, Storage, StorageProof};
+
+#[derive(clap::Args, Debug, Clone)]
+/// Verify a storage proof returned by the proof-enabled REST endpoints against a state root.
+pub struct VerifyProofWorkflow {
+    /// The hex-encoded storage key which is proven.
+    #[clap(long)]
+    pub key: String,
+    /// The hex-encoded value which is proven. Omit it to verify that the key is absent.
+    #[clap(long)]
+    pub value: Option<String>,
+    /// The JSON-encoded sparse merkle proof.
+    #[clap(long)]
+    pub proof: String,
+    /// The hex-encoded state root to verify the proof against.
+    #[clap(long)]
+    pub root: String,
+    /// Verify the key against the kernel state instead of the user state.
+    #[clap(long)]
+    pub kernel: bool,
+}
+
+impl VerifyProofWorkflow {
+    /// Verifies the proof and prints the outcome to `out`.
+    /// Returns an error if the proof is invalid.
+    pub fn run<S: Spec>(self, mut out: impl Write) -> Result<(), anyhow::Error> {
+        match self.verify::<S>() {
+            Ok(()) => {
+                writeln!(out, "The proof is valid")?;
+                Ok(())
+            }
+            Err(e) => {
+                writeln!(out, "The proof is invalid: {}", e)?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Verifies the proof against the provided state root.
+    pub fn verify<S: Spec>(&self) -> Result<(), anyhow::Error> {
+        let root = <<S::Storage as Storage>::Root as BorshDeserialize>::try_from_slice(
+            &decode_hex(&self.root)?,
+        )
+        .map_err(|e| anyhow::anyhow!("Invalid state root: {}", e))?;
+        let proof: <S::Storage as Storage>::Proof = serde_json::from_str(&self.proof)
+            .map_err(|e| anyhow::anyhow!("Invalid proof: {}", e))?;
+        let key = SlotKey::from(decode_hex(&self.key)?);
+        let value = self
+            .value
+            .as_deref()
+            .map(decode_hex)
+            .transpose()?
+            .map(SlotValue::from);
+        let namespace = if self.kernel {
+            ProvableNamespace::Kernel
+        } else {
+            ProvableNamespace::User
+        };
+
+        S::Storage::open_proof(
+            root,
+            StorageProof {
+                key,
+                value,
+                proof,
+                namespace,
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+fn decode_hex(input: &str) -> Result<Vec<u8>, anyhow::Error> {
+    hex::decode(input.strip_prefix("0x").unwrap_or(input))
+        .map_err(|e| anyhow::anyhow!("Invalid hex string {}: {}", input, e))
+}
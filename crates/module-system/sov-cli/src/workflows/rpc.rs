@@ -20,6 +20,7 @@ use sov_rollup_interface::digest::Digest;
 use sov_sequencer_json_client::types;
 
 use crate::wallet_state::{AddressEntry, KeyIdentifier, WalletState};
+use crate::workflows::gas::estimate_gas_limits;
 use crate::workflows::keys::load_key;
 use crate::workflows::NO_ACCOUNTS_FOUND;
 
@@ -37,6 +38,10 @@ pub enum RpcWorkflows<S: sov_modules_api::Spec> {
         /// A URL like http://localhost:8546
         #[arg(long)]
         rest_api: String,
+        /// (Optional) The URL of the sequencer API, used to estimate gas
+        /// (default: the `/sequencer` endpoint of the REST API)
+        #[arg(long)]
+        sequencer: Option<String>,
     },
     /// Query the RPC server for the nonce of the provided account. If no account is provided, the active account is used
     GetNonce {
@@ -72,6 +77,10 @@ pub enum RpcWorkflows<S: sov_modules_api::Spec> {
         /// (Optional) Waits for given batch to be processed by the rollup node.
         #[arg(short, long)]
         wait_for_processing: bool,
+        /// (Optional) Estimates the gas used by each transaction and uses it as its gas limit. The configured
+        /// gas limit is kept for the transactions whose gas can't be estimated.
+        #[arg(long)]
+        estimate_gas: bool,
         /// (Optional) The nonce to use for the first transaction in the batch (default: the current nonce for the account). Any other transactions will
         /// be signed with sequential nonces starting from this value.
         nonce_override: Option<u64>,
@@ -121,6 +130,7 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
         if let RpcWorkflows::SetUrl {
             rpc: rpc_url,
             rest_api: rest_api_url,
+            sequencer: sequencer_url,
         } = self
         {
             let _client = HttpClientBuilder::default()
@@ -129,10 +139,19 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
             let _client = HttpClientBuilder::default()
                 .build(rest_api_url)
                 .context("Invalid REST API URL: ")?;
+            if let Some(sequencer_url) = sequencer_url {
+                let _client = HttpClientBuilder::default()
+                    .build(sequencer_url)
+                    .context("Invalid sequencer URL: ")?;
+            }
             wallet_state.rpc_url = Some(rpc_url.clone());
             wallet_state.rest_api_url = Some(rest_api_url.clone());
+            wallet_state.sequencer_url = sequencer_url.clone();
             println!("Set RPC URL to {}", rpc_url);
             println!("Set REST API URL to {}", rest_api_url);
+            if let Some(sequencer_url) = sequencer_url {
+                println!("Set sequencer URL to {}", sequencer_url);
+            }
             return Ok(());
         }
 
@@ -145,15 +164,8 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
             ))?
             .clone();
         let client = HttpClientBuilder::default().build(&rpc_url)?;
-        let sequencer_client = sov_sequencer_json_client::Client::new(&format!(
-            "{}/sequencer",
-            wallet_state
-                .rest_api_url
-                .as_ref()
-                .ok_or(anyhow::format_err!(
-                    "No REST API URL set. Use the `rpc set-url` subcommand to set one"
-                ))?
-        ));
+        let sequencer_client =
+            sov_sequencer_json_client::Client::new(&wallet_state.sequencer_url()?);
 
         let rest_api_url = wallet_state
             .rest_api_url
@@ -216,6 +228,7 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
             RpcWorkflows::SubmitBatch {
                 nonce_override,
                 wait_for_processing,
+                estimate_gas,
                 ..
             } => {
                 let private_key = load_key::<S>(&account.location).with_context(|| {
@@ -227,6 +240,10 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
                     None => get_nonce_for_account(&client, account).await?,
                 };
 
+                if *estimate_gas {
+                    estimate_gas_limits(wallet_state, &sequencer_client, &private_key, nonce).await;
+                }
+
                 let txs = wallet_state.take_signed_transactions(&private_key, nonce);
 
                 for (i, tx) in txs.iter().enumerate() {
@@ -281,8 +298,8 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
                     while start_wait.elapsed() < max_waiting_time {
                         jsonrpsee::tokio::select! {
                             _ = interval.tick() => {
-                                let latest_slot_response = client.get_latest_slot(None).await.unwrap();
-                                let latest_slot_number = latest_slot_response.data.number;
+                                let latest_slot_response = client.get_latest_slot(None, None).await.unwrap();
+                                let latest_slot_number = latest_slot_response.data.number.to_u64()?;
                                 if latest_slot_number >= target_da_height {
                                     println!(
                                         "Rollup has processed target DA height={}!",
@@ -324,7 +341,7 @@ impl<S: sov_modules_api::Spec + Serialize + DeserializeOwned + Send + Sync> RpcW
     }
 }
 
-async fn get_nonce_for_account<S: sov_modules_api::Spec + Send + Sync + Serialize>(
+pub(crate) async fn get_nonce_for_account<S: sov_modules_api::Spec + Send + Sync + Serialize>(
     client: &(impl ClientT + Send + Sync),
     account: &AddressEntry<S>,
 ) -> Result<u64, anyhow::Error> {
@@ -1,6 +1,6 @@
 //! Workflows for transaction management
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -46,6 +46,30 @@ pub enum TransactionWorkflow<File: Subcommand, Json: Subcommand> {
     },
     /// List the current batch of transactions
     List,
+    /// Export a transaction of the current batch along with a nonce to a file, so that it can be
+    /// signed on another machine with the `sign-offline` subcommand.
+    ExportUnsigned {
+        /// The index of the transaction to export, starting from 0
+        index: usize,
+        /// Nonce to sign the transaction with.
+        #[clap(short, long)]
+        nonce: u64,
+        /// The file to write the borsh encoded transaction and nonce to.
+        #[clap(short, long)]
+        output: PathBuf,
+    },
+    /// Sign a transaction exported with the `export-unsigned` subcommand with the provided key
+    /// file, without using the keys of the wallet.
+    SignOffline {
+        /// The file containing the exported transaction.
+        input: PathBuf,
+        /// The JSON key file to sign the transaction with.
+        #[clap(short, long)]
+        key_file: PathBuf,
+        /// The file to write the borsh encoded signed transaction to.
+        #[clap(short, long)]
+        output: PathBuf,
+    },
 }
 
 impl<File, Json> TransactionWorkflow<File, Json>
@@ -96,6 +120,59 @@ where
                 wallet_state.unsent_transactions.remove(index);
                 Ok(())
             }
+            TransactionWorkflow::ExportUnsigned {
+                index,
+                nonce,
+                output,
+            } => {
+                let tx = wallet_state.unsent_transactions.get(index).ok_or_else(|| {
+                    anyhow::format_err!("No transaction found at index {}", index)
+                })?;
+
+                std::fs::write(&output, borsh::to_vec(&(tx, nonce))?)
+                    .with_context(|| format!("Unable to write to {}", output.display()))?;
+                writeln!(
+                    &mut out,
+                    "Exported transaction {} with nonce {} to {}",
+                    index,
+                    nonce,
+                    output.display()
+                )?;
+                Ok(())
+            }
+            TransactionWorkflow::SignOffline {
+                input,
+                key_file,
+                output,
+            } => {
+                let data = std::fs::read(&input)
+                    .with_context(|| format!("Unable to read {}", input.display()))?;
+                let (tx, nonce) = <(
+                    UnsignedTransactionWithoutNonce<S, <RT as DispatchCall>::Decodable>,
+                    u64,
+                )>::try_from_slice(&data)
+                .with_context(|| {
+                    format!("{} is not a valid exported transaction", input.display())
+                })?;
+
+                let private_key = load_key::<S>(&key_file)
+                    .with_context(|| format!("Unable to load key {}", key_file.display()))?;
+
+                std::fs::write(&output, sign_tx(&private_key, &tx, nonce)?)
+                    .with_context(|| format!("Unable to write to {}", output.display()))?;
+                writeln!(
+                    &mut out,
+                    "Signed the following transaction with nonce {}:",
+                    nonce
+                )?;
+                writeln!(&mut out, "{}", serde_json::to_string_pretty(&tx)?)?;
+                writeln!(
+                    &mut out,
+                    "Signed transaction (borsh encoded) written to {}",
+                    output.display()
+                )?;
+                Ok(())
+            }
             TransactionWorkflow::Sign {
                 transaction,
                 key_nickname,
@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+use demo_stf::runtime::RuntimeCall;
+use sov_cli::wallet_state::WalletState;
+use sov_cli::workflows::batch::{build_batch, BatchWorkflow};
+use sov_cli::workflows::keys::{load_key, KeyWorkflow};
+use sov_cli::UnsignedTransactionWithoutNonce;
+use sov_mock_da::MockDaSpec;
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{BlobData, CryptoSpec, PrivateKey, Spec, UnlimitedGasMeter};
+use sov_test_utils::{TestSpec, TEST_DEFAULT_MAX_FEE, TEST_DEFAULT_MAX_PRIORITY_FEE};
+
+type Da = MockDaSpec;
+type Tx = UnsignedTransactionWithoutNonce<TestSpec, RuntimeCall<TestSpec, Da>>;
+
+fn tx_from_file(path: impl AsRef<Path>, chain_id: u64) -> Tx {
+    let mut call_message_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    call_message_path.push("test-data");
+    call_message_path.push(path);
+    let call_message_json = std::fs::read_to_string(call_message_path).unwrap();
+
+    UnsignedTransactionWithoutNonce::new(
+        RuntimeCall::bank(serde_json::from_str(&call_message_json).unwrap()),
+        chain_id,
+        TEST_DEFAULT_MAX_PRIORITY_FEE,
+        TEST_DEFAULT_MAX_FEE,
+        None,
+    )
+}
+
+fn batch_txs(batch: &[u8]) -> Vec<Transaction<TestSpec>> {
+    match borsh::from_slice::<BlobData>(batch).unwrap() {
+        BlobData::Batch(batch) => batch
+            .txs
+            .iter()
+            .map(|tx| borsh::from_slice(&tx.data).unwrap())
+            .collect(),
+        other => panic!("Expected a batch, but got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_build_batch_from_file() {
+    let app_dir = tempfile::tempdir().unwrap();
+    let mut wallet_state = WalletState::<RuntimeCall<TestSpec, Da>, TestSpec>::default();
+    KeyWorkflow::Generate {
+        nickname: Some("key1".into()),
+    }
+    .run(&mut wallet_state, &app_dir)
+    .unwrap();
+
+    let chain_id = 4321;
+    let txs = [
+        tx_from_file("requests/create_token.json", chain_id),
+        tx_from_file("requests/mint.json", chain_id),
+    ];
+    let txs_path = app_dir.path().join("txs.json");
+    std::fs::write(&txs_path, serde_json::to_string(&txs).unwrap()).unwrap();
+
+    let batch_path = app_dir.path().join("batch");
+    let nonce = 5;
+    BatchWorkflow::Build {
+        path: txs_path,
+        output: batch_path.clone(),
+        nonce: Some(nonce),
+        account: None,
+    }
+    .run(&mut wallet_state, &app_dir)
+    .await
+    .unwrap();
+
+    let signed_txs = batch_txs(&std::fs::read(batch_path).unwrap());
+    let key =
+        load_key::<TestSpec>(&wallet_state.addresses.default_address().unwrap().location).unwrap();
+    assert_eq!(signed_txs.len(), txs.len());
+    for (i, (signed_tx, tx)) in signed_txs.iter().zip(&txs).enumerate() {
+        signed_tx
+            .verify(&mut UnlimitedGasMeter::new())
+            .expect("the computed signature is incorrect");
+        assert_eq!(
+            signed_tx,
+            &Transaction::new_signed_tx(&key, tx.with_nonce(nonce + i as u64))
+        );
+        assert_eq!(chain_id, signed_tx.details.chain_id);
+    }
+}
+
+#[test]
+fn test_build_batch_rejects_mixed_chain_ids() {
+    let key = <<TestSpec as Spec>::CryptoSpec as CryptoSpec>::PrivateKey::generate();
+
+    let txs = [
+        tx_from_file("requests/create_token.json", 1),
+        tx_from_file("requests/mint.json", 2),
+    ];
+    let error = build_batch(&txs, &key, 0).unwrap_err();
+    assert_eq!(
+        "All the transactions of a batch must target the same chain, but transaction 1 targets chain 2 instead of 1",
+        error.to_string()
+    );
+
+    let error = build_batch::<TestSpec, RuntimeCall<TestSpec, Da>>(&[], &key, 0).unwrap_err();
+    assert_eq!(
+        "The batch must contain at least one transaction",
+        error.to_string()
+    );
+}
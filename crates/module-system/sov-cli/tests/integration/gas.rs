@@ -0,0 +1,74 @@
+use std::io;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sov_cli::wallet_state::WalletState;
+use sov_cli::workflows::gas::estimate_gas_limits;
+use sov_cli::UnsignedTransactionWithoutNonce;
+use sov_mock_da::MockDaSpec;
+use sov_modules_api::macros::config_value;
+use sov_modules_api::{EncodeCall, GasArray, PrivateKey, Spec};
+use sov_test_utils::runtime::optimistic::TestRuntime;
+use sov_test_utils::runtime::ValueSetter;
+use sov_test_utils::sequencer::TestSequencerSetup;
+use sov_test_utils::{
+    TestPrivateKey, TestSpec, TEST_DEFAULT_MAX_FEE, TEST_DEFAULT_MAX_PRIORITY_FEE,
+};
+use sov_value_setter::CallMessage;
+
+type Da = MockDaSpec;
+type Gas = <TestSpec as Spec>::Gas;
+
+/// A call message which is already encoded for the [`TestRuntime`], and is hence serialized as is.
+#[derive(Serialize, Deserialize)]
+struct EncodedCall(Vec<u8>);
+
+impl BorshSerialize for EncodedCall {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl BorshDeserialize for EncodedCall {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_estimate_gas_limits() {
+    let sequencer = TestSequencerSetup::with_real_batch_builder().await.unwrap();
+    let client = sequencer.client();
+
+    let configured_gas_limit = Gas::from_slice(&[1, 1]);
+    let mut wallet_state = WalletState::<EncodedCall, TestSpec>::default();
+    wallet_state
+        .unsent_transactions
+        .push(UnsignedTransactionWithoutNonce::new(
+            EncodedCall(<TestRuntime<TestSpec, Da> as EncodeCall<
+                ValueSetter<TestSpec>,
+            >>::encode_call(CallMessage::SetValue(8))),
+            config_value!("CHAIN_ID"),
+            TEST_DEFAULT_MAX_PRIORITY_FEE,
+            TEST_DEFAULT_MAX_FEE,
+            Some(configured_gas_limit.clone()),
+        ));
+
+    // Only the admin can set the value, so the estimation fails for other senders and the
+    // configured gas limit is kept.
+    estimate_gas_limits(&mut wallet_state, &client, &TestPrivateKey::generate(), 0).await;
+    assert_eq!(
+        wallet_state.unsent_transactions[0].gas_limit(),
+        Some(&configured_gas_limit)
+    );
+
+    estimate_gas_limits(&mut wallet_state, &client, &sequencer.admin_private_key, 0).await;
+    let estimated_gas_limit = wallet_state.unsent_transactions[0]
+        .gas_limit()
+        .cloned()
+        .unwrap();
+    assert_ne!(estimated_gas_limit, configured_gas_limit);
+    assert!(estimated_gas_limit.as_slice().iter().any(|gas| *gas > 0));
+}
@@ -1,2 +1,5 @@
+mod batch;
+mod gas;
 mod keys;
+mod proofs;
 mod transactions;
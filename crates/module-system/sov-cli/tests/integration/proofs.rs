@@ -0,0 +1,54 @@
+use sov_cli::workflows::proofs::VerifyProofWorkflow;
+use sov_modules_api::prelude::UnwrapInfallible;
+use sov_modules_api::{StateCheckpoint, StateValue};
+use sov_prover_storage_manager::SimpleStorageManager;
+use sov_state::{NativeStorage, Prefix, Storage, User};
+use sov_test_utils::TestSpec;
+
+fn proven_value_workflow() -> VerifyProofWorkflow {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleStorageManager::new(tmpdir.path());
+    let storage = storage_manager.create_storage();
+    let value = StateValue::<u64>::new(Prefix::new(b"value".to_vec()));
+
+    let mut state = StateCheckpoint::<TestSpec>::new(storage.clone());
+    value.set(&42, &mut state).unwrap_infallible();
+    let (cache_log, _, witness) = state.freeze();
+    let (root, change_set) = storage
+        .validate_and_materialize(cache_log, &witness)
+        .expect("Native jmt validation should succeed");
+    storage_manager.commit(change_set);
+
+    let proof = storage_manager
+        .create_storage()
+        .get_with_proof::<User>(value.slot_key(), None);
+
+    VerifyProofWorkflow {
+        key: hex::encode(proof.key.key_ref()),
+        value: proof.value.map(|v| hex::encode(v.value())),
+        proof: serde_json::to_string(&proof.proof).unwrap(),
+        root: format!("0x{}", hex::encode(root.as_ref())),
+        kernel: false,
+    }
+}
+
+#[test]
+fn test_verify_valid_proof() {
+    let workflow = proven_value_workflow();
+    let mut out = Vec::new();
+
+    workflow.run::<TestSpec>(&mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "The proof is valid\n");
+}
+
+#[test]
+fn test_verify_tampered_value() {
+    let mut workflow = proven_value_workflow();
+    workflow.value = Some(hex::encode(borsh::to_vec(&43u64).unwrap()));
+    let mut out = Vec::new();
+
+    assert!(workflow.run::<TestSpec>(&mut out).is_err());
+    assert!(String::from_utf8(out)
+        .unwrap()
+        .starts_with("The proof is invalid"));
+}
@@ -332,6 +332,83 @@ fn transaction_outputs_json() {
     signed_tx.verify(&mut UnlimitedGasMeter::new()).unwrap();
 }
 
+#[test]
+fn transaction_signed_offline_from_exported_file() {
+    let app_dir = tempfile::tempdir().unwrap();
+    let mut wallet_state = WalletState::<RuntimeCall<TestSpec, Da>, TestSpec>::default();
+    import_key(&mut wallet_state, &app_dir);
+    let signer = wallet_state.addresses.default_address().unwrap();
+    let key_file = signer.location.clone();
+    let pub_key = signer.pub_key.clone();
+
+    let runtime_call =
+        RuntimeCall::<TestSpec, Da>::bank(call_message_from_file("requests/create_token.json"));
+    let runtime_call_bytes = borsh::to_vec(&runtime_call).unwrap();
+    let chain_id = 4321;
+    let max_fee = TEST_DEFAULT_MAX_FEE + 1;
+    wallet_state
+        .unsent_transactions
+        .push(UnsignedTransactionWithoutNonce::new(
+            runtime_call,
+            chain_id,
+            TEST_DEFAULT_MAX_PRIORITY_FEE,
+            max_fee,
+            None,
+        ));
+
+    let unsigned_tx_path = app_dir.path().join("unsigned_tx");
+    let nonce = 7;
+    TransactionWorkflow::<
+        RuntimeSubcommand<FileNameArg, TestSpec, Da>,
+        RuntimeSubcommand<JsonStringArg, TestSpec, Da>,
+    >::ExportUnsigned {
+        index: 0,
+        nonce,
+        output: unsigned_tx_path.clone(),
+    }
+    .run::<Runtime<TestSpec, Da>, _, _, _, _, _>(&mut wallet_state, &app_dir, std::io::sink())
+    .unwrap();
+    assert_eq!(
+        wallet_state.unsent_transactions.len(),
+        1,
+        "Exporting a transaction should not remove it from the batch"
+    );
+
+    // The offline machine only has the key file, not the wallet.
+    let mut offline_wallet_state = WalletState::<RuntimeCall<TestSpec, Da>, TestSpec>::default();
+    let signed_tx_path = app_dir.path().join("signed_tx");
+    TransactionWorkflow::<
+        RuntimeSubcommand<FileNameArg, TestSpec, Da>,
+        RuntimeSubcommand<JsonStringArg, TestSpec, Da>,
+    >::SignOffline {
+        input: unsigned_tx_path,
+        key_file,
+        output: signed_tx_path.clone(),
+    }
+    .run::<Runtime<TestSpec, Da>, _, _, _, _, _>(
+        &mut offline_wallet_state,
+        &app_dir,
+        std::io::sink(),
+    )
+    .unwrap();
+
+    let signed_tx =
+        Transaction::<TestSpec>::try_from_slice(&std::fs::read(signed_tx_path).unwrap()).unwrap();
+    signed_tx
+        .verify(&mut UnlimitedGasMeter::new())
+        .expect("the computed signature is incorrect");
+
+    assert_eq!(pub_key, signed_tx.pub_key);
+    assert_eq!(nonce, signed_tx.nonce);
+    assert_eq!(runtime_call_bytes, signed_tx.runtime_msg);
+    assert_eq!(chain_id, signed_tx.details.chain_id);
+    assert_eq!(max_fee, signed_tx.details.max_fee);
+    assert_eq!(
+        TEST_DEFAULT_MAX_PRIORITY_FEE,
+        signed_tx.details.max_priority_fee_bips
+    );
+}
+
 fn make_test_path<P: AsRef<Path>>(path: P) -> PathBuf {
     let mut sender_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     sender_path.push("test-data");
@@ -4,9 +4,12 @@ use std::path::PathBuf;
 use sov_blob_storage::BlobStorage;
 use sov_chain_state::ChainState;
 use sov_modules_api::prelude::UnwrapInfallible;
-use sov_modules_api::runtime::capabilities::{BlobSelector, Kernel, KernelSlotHooks};
+use sov_modules_api::runtime::capabilities::{
+    BlobSelection, BlobSelector, Kernel, KernelSlotHooks,
+};
 use sov_modules_api::{
     BlobDataWithId, BootstrapWorkingSet, DaSpec, Gas, KernelModule, KernelWorkingSet, Spec,
+    StateAccessBudget,
 };
 use sov_state::Storage;
 
@@ -70,7 +73,8 @@ impl<S: Spec, Da: DaSpec> Kernel<S, Da> for BasicKernel<S, Da> {
     ) -> Result<(), anyhow::Error> {
         self.chain_state
             .genesis_unchecked(&config.chain_state, state)?;
-        self.blob_storage.genesis_unchecked(&(), state)?;
+        self.blob_storage
+            .genesis_unchecked(&Default::default(), state)?;
         Ok(())
     }
 }
@@ -89,8 +93,28 @@ impl<S: Spec, Da: DaSpec> BlobSelector<Da> for BasicKernel<S, Da> {
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
     {
         Ok(self
-            .blob_storage
-            .select_blobs_as_based_sequencer(current_blobs, state))
+            .get_blobs_for_this_slot_with_outcomes(current_blobs, state)?
+            .selected)
+    }
+
+    fn get_blobs_for_this_slot_with_outcomes<'a, 'k, I>(
+        &self,
+        current_blobs: I,
+        state: &mut sov_modules_api::KernelWorkingSet<'k, Self::Spec>,
+    ) -> anyhow::Result<BlobSelection<Self::BlobType, Da::Address>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        let mut unselected = Vec::new();
+        let selected = self.blob_storage.select_blobs_as_based_sequencer(
+            current_blobs,
+            state,
+            &mut unselected,
+        );
+        Ok(BlobSelection {
+            selected,
+            unselected,
+        })
     }
 }
 
@@ -107,6 +131,22 @@ impl<S: Spec, Da: DaSpec> KernelSlotHooks<S, Da> for BasicKernel<S, Da> {
             .begin_slot_hook(slot_header, validity_condition, pre_state_root, &mut ws)
     }
 
+    fn block_gas_limit(
+        &self,
+        state_checkpoint: &mut sov_modules_api::StateCheckpoint<Self::Spec>,
+    ) -> S::Gas {
+        let mut ws = sov_modules_api::KernelWorkingSet::from_kernel(self, state_checkpoint);
+        self.chain_state.block_gas_limit(&mut ws)
+    }
+
+    fn state_access_budget(
+        &self,
+        state_checkpoint: &mut sov_modules_api::StateCheckpoint<Self::Spec>,
+    ) -> StateAccessBudget {
+        let mut ws = sov_modules_api::KernelWorkingSet::from_kernel(self, state_checkpoint);
+        self.chain_state.state_access_budget(&mut ws)
+    }
+
     fn end_slot_hook(
         &self,
         gas_used: &S::Gas,
@@ -4,9 +4,12 @@ use std::path::PathBuf;
 use sov_blob_storage::BlobStorage;
 use sov_chain_state::ChainState;
 use sov_modules_api::prelude::UnwrapInfallible;
-use sov_modules_api::runtime::capabilities::{BlobSelector, Kernel, KernelSlotHooks};
+use sov_modules_api::runtime::capabilities::{
+    BlobSelection, BlobSelector, Kernel, KernelSlotHooks,
+};
 use sov_modules_api::{
     BlobDataWithId, BootstrapWorkingSet, DaSpec, Gas, KernelModule, KernelWorkingSet, Spec,
+    StateAccessBudget,
 };
 use sov_state::Storage;
 
@@ -36,6 +39,8 @@ pub struct SoftConfirmationsKernelGenesisPaths {
 pub struct SoftConfirmationsKernelGenesisConfig<S: Spec, Da: DaSpec> {
     /// The chain state genesis config
     pub chain_state: <ChainState<S, Da> as KernelModule>::Config,
+    /// The blob storage genesis config
+    pub blob_storage: <BlobStorage<S, Da> as KernelModule>::Config,
 }
 
 impl<S: Spec, Da: DaSpec> Kernel<S, Da> for SoftConfirmationsKernel<S, Da> {
@@ -58,9 +63,11 @@ impl<S: Spec, Da: DaSpec> Kernel<S, Da> for SoftConfirmationsKernel<S, Da> {
         config: &Self::GenesisConfig,
         state: &mut KernelWorkingSet<'_, S>,
     ) -> Result<(), anyhow::Error> {
-        Ok(self
-            .chain_state
-            .genesis_unchecked(&config.chain_state, state)?)
+        self.chain_state
+            .genesis_unchecked(&config.chain_state, state)?;
+        self.blob_storage
+            .genesis_unchecked(&config.blob_storage, state)?;
+        Ok(())
     }
 }
 
@@ -79,6 +86,18 @@ impl<S: Spec, Da: DaSpec> BlobSelector<Da> for SoftConfirmationsKernel<S, Da> {
         self.blob_storage
             .get_blobs_for_this_slot(current_blobs, state)
     }
+
+    fn get_blobs_for_this_slot_with_outcomes<'a, 'k, I>(
+        &self,
+        current_blobs: I,
+        state: &mut KernelWorkingSet<'k, Self::Spec>,
+    ) -> anyhow::Result<BlobSelection<Self::BlobType, Da::Address>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        self.blob_storage
+            .get_blobs_for_this_slot_with_outcomes(current_blobs, state)
+    }
 }
 
 impl<S: Spec, Da: DaSpec> KernelSlotHooks<S, Da> for SoftConfirmationsKernel<S, Da> {
@@ -98,6 +117,22 @@ impl<S: Spec, Da: DaSpec> KernelSlotHooks<S, Da> for SoftConfirmationsKernel<S,
         )
     }
 
+    fn block_gas_limit(
+        &self,
+        state_checkpoint: &mut sov_modules_api::StateCheckpoint<Self::Spec>,
+    ) -> S::Gas {
+        let mut state = KernelWorkingSet::from_kernel(self, state_checkpoint);
+        self.chain_state.block_gas_limit(&mut state)
+    }
+
+    fn state_access_budget(
+        &self,
+        state_checkpoint: &mut sov_modules_api::StateCheckpoint<Self::Spec>,
+    ) -> StateAccessBudget {
+        let mut state = KernelWorkingSet::from_kernel(self, state_checkpoint);
+        self.chain_state.state_access_budget(&mut state)
+    }
+
     fn end_slot_hook(
         &self,
         gas_used: &S::Gas,
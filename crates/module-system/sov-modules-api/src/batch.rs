@@ -1,5 +1,11 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
+use sov_modules_macros::config_value;
+use thiserror::Error;
+
+/// The maximum size of a blob, in bytes, which is considered for deserialization.
+/// Larger blobs are rejected without being read.
+pub const MAX_BLOB_DATA_SIZE: usize = config_value!("MAX_BLOB_DATA_SIZE");
 
 /// RawTx represents a serialized rollup transaction received from the DA.
 #[derive(Debug, PartialEq, Clone, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
@@ -30,6 +36,31 @@ pub enum BlobData {
     Batch(Batch),
     /// Aggregated proof posted on the DA.
     Proof(Vec<u8>),
+    /// Batch of transactions tagged with a per-sequencer sequence number. Batches from a
+    /// registered sequencer are executed in order of their sequence numbers.
+    SequencedBatch {
+        /// The sequence number of the batch, starting at 0 for each sequencer.
+        sequence_number: u64,
+        /// The batch itself.
+        batch: Batch,
+    },
+}
+
+/// The reason why a blob was rejected by [`BlobData::pre_validate`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MalformedBlob {
+    /// The blob is larger than [`MAX_BLOB_DATA_SIZE`].
+    #[error("Blob of {0} bytes exceeds the maximum size of {MAX_BLOB_DATA_SIZE} bytes")]
+    TooLarge(usize),
+    /// The blob ends before the encoded data does.
+    #[error("Blob is truncated")]
+    Truncated,
+    /// The blob starts with a tag that doesn't belong to a DA-submittable variant.
+    #[error("Unknown blob variant {0}")]
+    UnknownVariant(u8),
+    /// The blob contains bytes after the encoded data.
+    #[error("Blob has {0} trailing bytes")]
+    TrailingBytes(usize),
 }
 
 impl BlobData {
@@ -42,13 +73,75 @@ impl BlobData {
     pub fn new_proof(proof: Vec<u8>) -> BlobData {
         BlobData::Proof(proof)
     }
+
+    /// Sequenced batch variant constructor.
+    pub fn new_sequenced_batch(sequence_number: u64, txs: Vec<RawTx>) -> BlobData {
+        BlobData::SequencedBatch {
+            sequence_number,
+            batch: Batch { txs },
+        }
+    }
+
+    /// Cheaply checks that `bytes` is a well-formed borsh encoding of a [`BlobData::Batch`],
+    /// [`BlobData::Proof`] or [`BlobData::SequencedBatch`], without allocating anything.
+    pub fn pre_validate(bytes: &[u8]) -> Result<(), MalformedBlob> {
+        if bytes.len() > MAX_BLOB_DATA_SIZE {
+            return Err(MalformedBlob::TooLarge(bytes.len()));
+        }
+
+        match Self::skip_encoded(bytes)? {
+            [] => Ok(()),
+            rest => Err(MalformedBlob::TrailingBytes(rest.len())),
+        }
+    }
+
+    /// Walks over a borsh-encoded [`BlobData`] at the start of `bytes` and returns the remainder.
+    pub fn skip_encoded(bytes: &[u8]) -> Result<&[u8], MalformedBlob> {
+        let (tag, rest) = bytes.split_first().ok_or(MalformedBlob::Truncated)?;
+        match tag {
+            // `Batch(Batch { txs: Vec<RawTx> })`
+            0 => skip_encoded_batch(rest),
+            // `Proof(Vec<u8>)`
+            1 => {
+                let (proof_len, rest) = split_len_prefix(rest)?;
+                rest.get(proof_len..).ok_or(MalformedBlob::Truncated)
+            }
+            // `SequencedBatch { sequence_number: u64, batch: Batch }`
+            2 => {
+                let (_sequence_number, rest) = rest
+                    .split_first_chunk::<8>()
+                    .ok_or(MalformedBlob::Truncated)?;
+                skip_encoded_batch(rest)
+            }
+            tag => Err(MalformedBlob::UnknownVariant(*tag)),
+        }
+    }
+}
+
+/// Walks over a borsh-encoded [`Batch`] at the start of `bytes` and returns the remainder.
+fn skip_encoded_batch(bytes: &[u8]) -> Result<&[u8], MalformedBlob> {
+    let (txs, mut rest) = split_len_prefix(bytes)?;
+    for _ in 0..txs {
+        let (tx_len, tx_rest) = split_len_prefix(rest)?;
+        rest = tx_rest.get(tx_len..).ok_or(MalformedBlob::Truncated)?;
+    }
+    Ok(rest)
+}
+
+/// Splits the `u32` length prefix of a borsh-encoded sequence from `bytes`.
+fn split_len_prefix(bytes: &[u8]) -> Result<(usize, &[u8]), MalformedBlob> {
+    let (len, rest) = bytes
+        .split_first_chunk::<4>()
+        .ok_or(MalformedBlob::Truncated)?;
+    Ok((u32::from_le_bytes(*len) as usize, rest))
 }
 
 /// Contains blob data obtained from the DA blob together with the ID of the blob.
 #[derive(Debug, PartialEq, Clone, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct BlobDataWithId {
-    /// Raw transactions.
-    pub data: BlobData,
+    /// Raw transactions. Set to `None` for blobs from unregistered senders which failed to
+    /// decode, so that they show up as ignored in the receipts.
+    pub data: Option<BlobData>,
     /// The blob came from a registered sequencer
     pub from_registered_sequencer: bool,
     /// The ID of the batch, carried over from the DA layer. This is the hash of the blob which contained the batch.
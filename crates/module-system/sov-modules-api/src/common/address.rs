@@ -6,6 +6,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use derivative::Derivative;
 use sha2::digest::typenum::U32;
 use sha2::Digest;
+use sov_modules_macros::config_value;
 use sov_rollup_interface::crypto::PublicKey;
 use sov_rollup_interface::{BasicAddress, RollupAddress};
 
@@ -275,10 +276,7 @@ impl<H> schemars::JsonSchema for Address<H> {
     fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
         serde_json::from_value(serde_json::json!({
             "type": "string",
-            // TODO(@neysofu): this regex pattern is currently correct, but it
-            // must be updated if `Address` allows for custom prefixes, instead
-            // of hardcoding `sov`.
-            "pattern": "^sov1[a-zA-Z0-9]+$",
+            "pattern": format!("^{ADDRESS_PREFIX}1[a-zA-Z0-9]+$"),
             "description": "Address",
         }))
         .unwrap()
@@ -363,10 +361,9 @@ impl<'a, H> Arbitrary<'a> for Address<H> {
 impl<H: Send + Sync + 'static> BasicAddress for Address<H> {}
 impl<H: Send + Sync + 'static> RollupAddress for Address<H> {}
 
-// TODO(@preston-evans98): unify core and modules, then
-// enable sov-modules-macros and do this
-// #[sov_modules_macros::config_constant]
-const ADDRESS_PREFIX: &str = "sov";
+/// The human-readable prefix of the bech32 representation of [`Address`],
+/// read from the `ADDRESS_PREFIX` constant of the constants manifest.
+pub const ADDRESS_PREFIX: &str = config_value!("ADDRESS_PREFIX");
 
 #[cfg(test)]
 mod test {
@@ -390,6 +387,59 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_address_uses_configured_prefix() {
+        let address = Address::<Sha256>::from([11; 32]);
+        let rendered = address.to_string();
+
+        assert_eq!(AddressBech32::human_readable_prefix(), ADDRESS_PREFIX);
+        assert!(rendered.starts_with(&format!("{ADDRESS_PREFIX}1")));
+        assert_eq!(Address::<Sha256>::from_str(&rendered).unwrap(), address);
+    }
+
+    #[test]
+    fn test_address_prefix_is_read_from_the_manifest() {
+        for manifest in ["constants.toml", "constants.testing.toml"] {
+            let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../..")
+                .join(manifest);
+            let constants: toml::Table = std::fs::read_to_string(&path).unwrap().parse().unwrap();
+
+            assert_eq!(
+                constants["constants"]["ADDRESS_PREFIX"].as_str(),
+                Some(ADDRESS_PREFIX),
+                "The address prefix doesn't match the one of {}",
+                path.display()
+            );
+        }
+    }
+
+    // Only part of the generated API is exercised by the tests below.
+    #[allow(dead_code)]
+    mod custom_prefix {
+        crate::impl_hash32_type!(CustomAddress, CustomAddressBech32, "myroll");
+    }
+
+    #[test]
+    fn test_custom_prefix_round_trip() {
+        use custom_prefix::{CustomAddress, CustomAddressBech32};
+
+        let address = CustomAddress::from([11; 32]);
+        let rendered = address.to_string();
+        assert!(rendered.starts_with("myroll1"));
+
+        let parsed = CustomAddress::from_str(&rendered).unwrap();
+        assert_eq!(parsed, address);
+        assert_eq!(
+            CustomAddressBech32::from_str(&rendered).unwrap(),
+            address.to_bech32()
+        );
+
+        // Addresses with another prefix are rejected, even if they encode the same bytes.
+        let default_rendered = Address::<Sha256>::from([11; 32]).to_string();
+        assert!(CustomAddress::from_str(&default_rendered).is_err());
+    }
+
     #[test]
     /// Enforces that we reject the original (less secure) `bech32` encoding for our address type.
     /// Our addresses should use bech32m only.
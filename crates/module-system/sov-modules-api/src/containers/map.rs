@@ -114,7 +114,7 @@ where
     Codec::KeyCodec: StateItemCodec<K>,
     Codec::ValueCodec: StateItemCodec<V>,
 {
-    fn slot_key<Q>(&self, key: &Q) -> SlotKey
+    pub(crate) fn slot_key<Q>(&self, key: &Q) -> SlotKey
     where
         Q: ?Sized,
         Codec::KeyCodec: EncodeKeyLike<Q, K>,
@@ -211,6 +211,22 @@ where
         }))
     }
 
+    /// Returns `true` if the map contains a value for the key.
+    ///
+    /// This is equivalent to checking that [`NamespacedStateMap::get`] returns [`Some`], but
+    /// doesn't deserialize the value.
+    pub fn contains_key<Q, Reader: StateReader<N>>(
+        &self,
+        key: &Q,
+        state: &mut Reader,
+    ) -> Result<bool, Reader::Error>
+    where
+        Codec::KeyCodec: EncodeKeyLike<Q, K>,
+        Q: ?Sized,
+    {
+        Ok(state.get(&self.slot_key(key))?.is_some())
+    }
+
     /// Removes a key from the map, returning the corresponding value (or
     /// [`None`] if the key is absent).
     pub fn remove<Q, ReaderAndWriter: StateReaderAndWriter<N>>(
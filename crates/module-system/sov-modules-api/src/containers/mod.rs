@@ -3,11 +3,15 @@ mod versioned_value;
 pub(crate) mod map;
 pub(crate) mod value;
 pub(crate) mod vec;
+mod write_once_value;
 
 pub use map::{AccessoryStateMap, KernelStateMap, StateMap, StateMapError};
 pub use value::{AccessoryStateValue, KernelStateValue, StateValue, StateValueError};
 pub use vec::{AccessoryStateVec, KernelStateVec, StateVec};
 pub use versioned_value::VersionedStateValue;
+pub use write_once_value::{
+    AlreadySetError, KernelWriteOnceStateValue, NamespacedWriteOnceStateValue, WriteOnceStateValue,
+};
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,87 @@
+use sov_state::codec::BorshCodec;
+use sov_state::namespaces::{CompileTimeNamespace, Kernel, User};
+use sov_state::{Prefix, StateCodec, StateItemCodec};
+use thiserror::Error;
+
+use super::value::NamespacedStateValue;
+use crate::{StateReader, StateReaderAndWriter, StateWriter};
+
+/// Container for a single value which can only be written once: once set, the value can
+/// neither be overwritten nor removed.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct NamespacedWriteOnceStateValue<N, V, Codec = BorshCodec> {
+    inner: NamespacedStateValue<N, V, Codec>,
+}
+
+/// Error returned when writing to a [`NamespacedWriteOnceStateValue`] which is already set.
+#[derive(Debug, Error)]
+#[error("The write-once value with prefix: {0} is already set")]
+pub struct AlreadySetError(pub Prefix);
+
+/// A write-once container for a single user-space value.
+pub type WriteOnceStateValue<V, Codec = BorshCodec> = NamespacedWriteOnceStateValue<User, V, Codec>;
+/// A write-once container for a single value which is only accesible in the kernel.
+pub type KernelWriteOnceStateValue<V, Codec = BorshCodec> =
+    NamespacedWriteOnceStateValue<Kernel, V, Codec>;
+
+// Implement a new function that assumes the BorshCodec
+impl<N: CompileTimeNamespace, V> NamespacedWriteOnceStateValue<N, V>
+where
+    <BorshCodec as StateCodec>::ValueCodec: StateItemCodec<V>,
+{
+    /// Crates a new [`WriteOnceStateValue`] with the given prefix and the default
+    /// [`crate::StateItemCodec`] (i.e. [`BorshCodec`]).
+    pub fn new(prefix: Prefix) -> Self {
+        Self::with_codec(prefix, BorshCodec)
+    }
+}
+
+impl<N, V, Codec> NamespacedWriteOnceStateValue<N, V, Codec>
+where
+    Codec: StateCodec,
+    Codec::ValueCodec: StateItemCodec<V>,
+    N: CompileTimeNamespace,
+{
+    /// Creates a new [`WriteOnceStateValue`] with the given prefix and codec.
+    pub fn with_codec(prefix: Prefix, codec: Codec) -> Self {
+        Self {
+            inner: NamespacedStateValue::with_codec(prefix, codec),
+        }
+    }
+
+    /// Returns the prefix of this value.
+    pub fn prefix(&self) -> &Prefix {
+        self.inner.prefix()
+    }
+
+    /// Sets the value if it is currently unset, or returns an [`AlreadySetError`] otherwise.
+    /// The value is left untouched in the latter case.
+    pub fn set_once<ReaderAndWriter: StateReaderAndWriter<N>>(
+        &self,
+        value: &V,
+        state: &mut ReaderAndWriter,
+    ) -> Result<Result<(), AlreadySetError>, <ReaderAndWriter as StateWriter<N>>::Error> {
+        if StateReader::<N>::get(state, &self.inner.slot_key())?.is_some() {
+            return Ok(Err(AlreadySetError(self.prefix().clone())));
+        }
+
+        self.inner.set(value, state)?;
+        Ok(Ok(()))
+    }
+
+    /// Gets the value from state or returns None if the value is absent.
+    pub fn get<Reader: StateReader<N>>(
+        &self,
+        state: &mut Reader,
+    ) -> Result<Option<V>, Reader::Error> {
+        self.inner.get(state)
+    }
+}
@@ -55,11 +55,13 @@ pub trait ApplyBatchHooks<Da: DaSpec> {
     type BatchResult;
 
     /// Runs at the beginning of apply_blob, locks the sequencer bond.
+    /// `visible_slot_number` is the visible slot number at which the batch is applied.
     /// If this hook returns Err, batch is not applied
     fn begin_batch_hook(
         &self,
         _batch: &BatchWithId,
         _sender: &Da::Address,
+        _visible_slot_number: TransitionHeight,
         _state_checkpoint: &mut StateCheckpoint<Self::Spec>,
     ) -> anyhow::Result<()> {
         Ok(())
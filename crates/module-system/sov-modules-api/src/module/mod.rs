@@ -106,6 +106,38 @@ pub trait ModuleInfo {
 
     /// Returns addresses of all the other modules this module is dependent on
     fn dependencies(&self) -> Vec<&ModuleId>;
+
+    /// Returns a metrics handle scoped to this module.
+    ///
+    /// Metrics created through it are prefixed with the module name in
+    /// `snake_case` and labelled with `module="<ModuleName>"`, e.g.
+    /// `self.metrics().counter("transfers")` in the `Bank` module yields
+    /// `bank_transfers{module="Bank"}`.
+    #[cfg(feature = "native")]
+    fn metrics(&self) -> sov_metrics::MetricsNamespace {
+        let module_name = self.prefix().module_name();
+
+        sov_metrics::MetricsNamespace::new(to_snake_case(module_name))
+            .with_label("module", module_name)
+    }
+}
+
+#[cfg(feature = "native")]
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len());
+    let mut prev_is_lowercase = false;
+    for c in name.chars() {
+        if c.is_uppercase() {
+            if prev_is_lowercase {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+        prev_is_lowercase = c.is_lowercase() || c.is_ascii_digit();
+    }
+    snake_case
 }
 
 /// Event Emitter trait for a blanket implementation
@@ -45,6 +45,7 @@ impl<S: Spec> ProofSerializer for SovApiProofSerializer<S> {
             max_fee: 10_000_000,
             gas_limit: None,
             chain_id: config_value!("CHAIN_ID"),
+            valid_until_slot: None,
         };
 
         let proof_with_details = SerializeProofWithDetails {
@@ -2,7 +2,9 @@ pub mod openapi;
 pub mod state;
 mod types;
 
-use sov_rest_utils::ApiResult;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use sov_rest_utils::{json_obj, ApiResult, ErrorObject, Path, ResponseObject};
 
 use self::state::StateItemKind;
 use self::types::{ModuleObject, Namespace};
@@ -25,6 +27,26 @@ pub struct StateItemInfo {
     pub prefix: Prefix,
 }
 
+/// Builds the [`ApiState`] contents for [`HasCustomRestApi::custom_rest_api`]
+/// in derived module APIs: an owned clone of the module if it implements
+/// [`HasCustomRestApi`], or a plain reference if the blanket implementation
+/// for `&T` was picked instead.
+pub trait CustomRestApiInner<'a, T> {
+    fn from_module(module: &'a T) -> Self;
+}
+
+impl<'a, T: Clone> CustomRestApiInner<'a, T> for T {
+    fn from_module(module: &'a T) -> Self {
+        module.clone()
+    }
+}
+
+impl<'a, T> CustomRestApiInner<'a, T> for &'a T {
+    fn from_module(module: &'a T) -> Self {
+        module
+    }
+}
+
 pub fn maybe_archival_accessor<S: Spec>(
     api_state_accessor: ApiStateAccessor<S>,
     height_opt: Option<u64>,
@@ -87,6 +109,33 @@ where
         }
         .into())
     }
+
+    /// Module routers are nested under `/modules/<name>`, and take precedence
+    /// over this handler. Any request that reaches it targets a module that
+    /// doesn't exist, so we point the client to the available ones.
+    async fn module_not_found_handler(
+        State(state): State<Self>,
+        Path(params): Path<HashMap<String, String>>,
+    ) -> Response {
+        let module_name = params.get("moduleName").cloned().unwrap_or_default();
+        let mut available_modules = state.modules.keys().cloned().collect::<Vec<_>>();
+        available_modules.sort();
+
+        ResponseObject::<()> {
+            data: None,
+            errors: vec![ErrorObject {
+                status: StatusCode::NOT_FOUND,
+                title: format!("Module '{}' not found", module_name),
+                details: json_obj!({
+                    "id": module_name,
+                }),
+                retry_after: None,
+            }],
+            meta: Default::default(),
+        }
+        .with_meta("availableModules", available_modules)
+        .into_response()
+    }
 }
 
 impl<R> HasRestApi<R::Spec> for RuntimeRestApiBaseImpl<R>
@@ -96,6 +145,11 @@ where
     fn rest_api(&self, _storage: StorageReceiver<R::Spec>) -> axum::Router<()> {
         axum::Router::new()
             .route("/", get(Self::root_handler))
+            .route("/modules/:moduleName", get(Self::module_not_found_handler))
+            .route(
+                "/modules/:moduleName/*path",
+                get(Self::module_not_found_handler),
+            )
             .with_state(self.clone())
             .fallback(sov_rest_utils::errors::global_404)
     }
@@ -26,6 +26,20 @@ fn height_param() -> utoipa::openapi::path::Parameter {
     .unwrap()
 }
 
+fn proof_param() -> utoipa::openapi::path::Parameter {
+    serde_json::from_value(json!({
+        "name": "proof",
+        "in": "query",
+        "description": "If set to `1` or `true`, the response includes a Merkle proof of the value against the state root at the queried height.",
+        "required": false,
+        "schema": {
+            "type": "string",
+            "enum": ["0", "1", "false", "true"],
+        }
+    }))
+    .unwrap()
+}
+
 /// The OpenAPI paths specification for
 /// [`StateValue`](crate::containers::StateValue).
 pub fn state_value_paths() -> OpenApiPaths {
@@ -35,7 +49,7 @@ pub fn state_value_paths() -> OpenApiPaths {
             "get": {
                 "operationId": "get_state_value",
                 "summary": "Get the value of a `StateValue`.",
-                "parameters": [height_param()],
+                "parameters": [height_param(), proof_param()],
                 "responses": {
                     "200": {
                         "$ref": "#/components/responses/StateValueResponse"
@@ -55,7 +69,7 @@ pub fn state_map_paths() -> OpenApiPaths {
             "get": {
                 "operationId": "get_state_map",
                 "summary": "Get the value of a `StateMap` element.",
-                "parameters": [height_param()],
+                "parameters": [height_param(), proof_param()],
                 "responses": {
                     "200": {
                         "$ref": "#/components/responses/StateMapElementResponse"
@@ -15,19 +15,22 @@ use std::convert::Infallible;
 use std::marker::PhantomData;
 
 use axum::extract::State;
+use axum::response::Response;
 use axum::routing::get;
 use serde::Serialize;
-use sov_rest_utils::{ApiResult, Path, Query};
-use sov_state::{CompileTimeNamespace, StateCodec, StateItemCodec};
+use sov_rest_utils::{errors, ApiResult, Path, Query, ResponseObject};
+use sov_state::{
+    CompileTimeNamespace, NativeStorage, SlotKey, StateCodec, StateItemCodec, Storage,
+};
 use unwrap_infallible::UnwrapInfallible;
 
-use super::types::StateItemContents;
+use super::types::{StateItemContents, StateProof};
 use super::{
     maybe_archival_accessor, HeightQueryParam, ModuleSendSync, NamespacedStateMap,
-    NamespacedStateVec, StateItemInfo, StorageReceiver,
+    NamespacedStateVec, ProofQueryParam, StateItemInfo, StorageReceiver,
 };
 use crate::value::NamespacedStateValue;
-use crate::{ApiStateAccessor, Module, StateReader};
+use crate::{ApiStateAccessor, Module, Spec, StateReader};
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -45,6 +48,65 @@ pub struct StateItemRestApiImpl<M: Module, T> {
     pub phantom: PhantomData<T>,
 }
 
+/// Returns the version of the state a request is served at: the requested
+/// height, or the latest version of `storage`.
+fn serving_version<S: Spec>(storage: &S::Storage, height_opt: Option<u64>) -> u64 {
+    height_opt.unwrap_or_else(|| storage.version())
+}
+
+/// Wraps `data` in a [`ResponseObject`] labelled with the serving `version` in
+/// its `meta`.
+fn with_version<T>(data: T, version: u64) -> ResponseObject<T> {
+    ResponseObject::from(data).with_meta("version", version.to_string())
+}
+
+/// Generates a [`StateProof`] for `key` if the client asked for one with
+/// `?proof=1`. Proofs can't be generated for heights which are not stored yet,
+/// so those are answered with a 404.
+fn maybe_state_proof<S: Spec>(
+    storage: &S::Storage,
+    namespace: sov_state::Namespace,
+    key: SlotKey,
+    height_opt: Option<u64>,
+    proof_opt: Option<Query<ProofQueryParam>>,
+) -> Result<Option<StateProof>, Response> {
+    if !proof_opt.is_some_and(|q| q.0.proof) {
+        return Ok(None);
+    }
+
+    if let Some(height) = height_opt {
+        if height > storage.version() {
+            return Err(errors::not_found_404("State version", height));
+        }
+    }
+
+    let proof = match namespace {
+        sov_state::Namespace::User => {
+            storage.get_with_proof::<sov_state::namespaces::User>(key, height_opt)
+        }
+        sov_state::Namespace::Kernel => {
+            storage.get_with_proof::<sov_state::namespaces::Kernel>(key, height_opt)
+        }
+        sov_state::Namespace::Accessory => {
+            return Err(errors::bad_request_400(
+                "Proofs are not available for this state item",
+                "accessory state is not part of the state root",
+            ));
+        }
+    };
+
+    let version = serving_version::<S>(storage, height_opt);
+    let root = storage
+        .get_root_hash(version)
+        .map_err(errors::database_error_response_500)?;
+
+    Ok(Some(StateProof {
+        version,
+        root: format!("0x{}", hex::encode(root.as_ref())),
+        proof: serde_json::to_value(proof).map_err(errors::internal_server_error_response_500)?,
+    }))
+}
+
 pub trait StateItemRestApi {
     fn state_item_rest_api(&self) -> axum::Router<()>;
 }
@@ -77,10 +139,13 @@ where
     async fn get_state_value_route(
         State(state): State<Self>,
         height_opt: Option<Query<HeightQueryParam>>,
+        proof_opt: Option<Query<ProofQueryParam>>,
     ) -> ApiResult<StateItemContents<T, T>> {
+        let storage = state.storage.borrow().clone();
+        let height_opt = height_opt.map(|q| q.0.height);
         let mut state_accessor = maybe_archival_accessor(
-            ApiStateAccessor::<M::Spec>::new(state.storage.borrow().clone()),
-            height_opt.map(|q| q.0.height),
+            ApiStateAccessor::<M::Spec>::new(storage.clone()),
+            height_opt,
         );
 
         let state_value = NamespacedStateValue::<N, T, Codec>::with_codec(
@@ -89,7 +154,17 @@ where
         );
 
         let value = state_value.get(&mut state_accessor).unwrap_infallible();
-        Ok(StateItemContents::Value { value }.into())
+        let proof = maybe_state_proof::<M::Spec>(
+            &storage,
+            N::NAMESPACE,
+            state_value.slot_key(),
+            height_opt,
+            proof_opt,
+        )?;
+        Ok(with_version(
+            StateItemContents::Value { value, proof },
+            serving_version::<M::Spec>(&storage, height_opt),
+        ))
     }
 }
 
@@ -119,16 +194,23 @@ where
     Codec::KeyCodec: StateItemCodec<usize>,
     Codec::ValueCodec: StateItemCodec<T> + StateItemCodec<usize>,
 {
+    /// Returns the accessor and the vector to serve a request with, along
+    /// with the version of the state they are read at.
     fn checkpoint_and_vec(
         &self,
         height_opt: Option<Query<HeightQueryParam>>,
-    ) -> (ApiStateAccessor<M::Spec>, NamespacedStateVec<N, T, Codec>) {
+    ) -> (
+        ApiStateAccessor<M::Spec>,
+        NamespacedStateVec<N, T, Codec>,
+        u64,
+    ) {
+        let storage = self.storage.borrow().clone();
+        let height_opt = height_opt.map(|q| q.0.height);
+        let version = serving_version::<M::Spec>(&storage, height_opt);
         (
-            maybe_archival_accessor(
-                ApiStateAccessor::new(self.storage.borrow().clone()),
-                height_opt.map(|q| q.0.height),
-            ),
+            maybe_archival_accessor(ApiStateAccessor::new(storage), height_opt),
             NamespacedStateVec::with_codec(self.state_item_info.prefix.0.clone(), Codec::default()),
+            version,
         )
     }
 
@@ -136,10 +218,11 @@ where
         State(state): State<Self>,
         height_opt: Option<Query<HeightQueryParam>>,
     ) -> ApiResult<StateItemContents<T, T>> {
-        let (mut api_state_accessor, state_vec) = Self::checkpoint_and_vec(&state, height_opt);
+        let (mut api_state_accessor, state_vec, version) =
+            Self::checkpoint_and_vec(&state, height_opt);
 
         let length = state_vec.len(&mut api_state_accessor).unwrap_infallible();
-        Ok(StateItemContents::Vec { length }.into())
+        Ok(with_version(StateItemContents::Vec { length }, version))
     }
 
     async fn get_state_vec_item_route(
@@ -147,16 +230,19 @@ where
         Path(item_index): Path<usize>,
         height_opt: Option<Query<HeightQueryParam>>,
     ) -> ApiResult<StateItemContents<T, T>> {
-        let (mut api_state_accessor, state_vec) = Self::checkpoint_and_vec(&state, height_opt);
+        let (mut api_state_accessor, state_vec, version) =
+            Self::checkpoint_and_vec(&state, height_opt);
 
         let value = state_vec
             .get(item_index, &mut api_state_accessor)
             .unwrap_infallible();
-        Ok(StateItemContents::VecElement {
-            index: item_index,
-            value,
-        }
-        .into())
+        Ok(with_version(
+            StateItemContents::VecElement {
+                index: item_index,
+                value,
+            },
+            version,
+        ))
     }
 }
 
@@ -204,10 +290,13 @@ where
         State(state): State<Self>,
         Path(key): Path<K>,
         height_opt: Option<Query<HeightQueryParam>>,
+        proof_opt: Option<Query<ProofQueryParam>>,
     ) -> ApiResult<StateItemContents<K, V>> {
+        let storage = state.storage.borrow().clone();
+        let height_opt = height_opt.map(|q| q.0.height);
         let mut working_set = maybe_archival_accessor(
-            ApiStateAccessor::<M::Spec>::new(state.storage.borrow().clone()),
-            height_opt.map(|q| q.0.height),
+            ApiStateAccessor::<M::Spec>::new(storage.clone()),
+            height_opt,
         );
         let state_map = NamespacedStateMap::<N, K, V, Codec>::with_codec(
             state.state_item_info.prefix.0.clone(),
@@ -215,7 +304,17 @@ where
         );
 
         let value = state_map.get(&key, &mut working_set).unwrap_infallible();
-        Ok(StateItemContents::MapElement { key, value }.into())
+        let proof = maybe_state_proof::<M::Spec>(
+            &storage,
+            N::NAMESPACE,
+            state_map.slot_key(&key),
+            height_opt,
+            proof_opt,
+        )?;
+        Ok(with_version(
+            StateItemContents::MapElement { key, value, proof },
+            serving_version::<M::Spec>(&storage, height_opt),
+        ))
     }
 }
 
@@ -1,17 +1,55 @@
 use std::collections::HashMap;
 
 use serde::Serialize;
+use sov_rest_utils::json_numbers;
 
 use super::{Prefix, StateItemInfo};
 use crate::{ModuleId, ModuleInfo};
 
+/// Integers wider than JavaScript's safe range are serialized as strings,
+/// including the ones nested inside keys and values; see
+/// [`sov_rest_utils::json_numbers`].
 #[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase", untagged)]
+#[serde(rename_all = "camelCase", untagged, bound = "K: Serialize, V: Serialize")]
 pub enum StateItemContents<K, V> {
-    Value { value: Option<V> },
-    Vec { length: usize },
-    VecElement { index: usize, value: Option<V> },
-    MapElement { key: K, value: Option<V> },
+    Value {
+        #[serde(serialize_with = "json_numbers::serialize_wide_ints")]
+        value: Option<V>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        proof: Option<StateProof>,
+    },
+    Vec {
+        #[serde(with = "json_numbers::as_string")]
+        length: usize,
+    },
+    VecElement {
+        #[serde(with = "json_numbers::as_string")]
+        index: usize,
+        #[serde(serialize_with = "json_numbers::serialize_wide_ints")]
+        value: Option<V>,
+    },
+    MapElement {
+        #[serde(serialize_with = "json_numbers::serialize_wide_ints")]
+        key: K,
+        #[serde(serialize_with = "json_numbers::serialize_wide_ints")]
+        value: Option<V>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        proof: Option<StateProof>,
+    },
+}
+
+/// A Merkle proof of a state item's value (or absence), returned when a
+/// client requests it with `?proof=1`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateProof {
+    /// The version of the state the proof was generated at.
+    #[serde(with = "json_numbers::as_string")]
+    pub version: u64,
+    /// The hex-encoded state root the proof can be verified against.
+    pub root: String,
+    /// The [`sov_state::StorageProof`] itself.
+    pub proof: serde_json::Value,
 }
 
 /// Identical to [`sov_state::namespaces::Namespace`], but with a custom
@@ -59,3 +97,44 @@ impl ModuleObject {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sov_rest_utils::json_numbers::MAX_SAFE_INTEGER;
+    use sov_rest_utils::test_utils::unsafe_json_integers;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Balance {
+        amount: u64,
+        decimals: u8,
+    }
+
+    #[test]
+    fn wide_integers_are_serialized_as_strings() {
+        let amount = MAX_SAFE_INTEGER + 2;
+        let contents = StateItemContents::MapElement {
+            key: u64::MAX,
+            value: Some(Balance {
+                amount,
+                decimals: 8,
+            }),
+            proof: None,
+        };
+
+        let json = serde_json::to_value(&contents).unwrap();
+        assert_eq!(unsafe_json_integers(&json), Vec::<String>::new());
+        assert_eq!(json["key"], u64::MAX.to_string());
+        assert_eq!(json["value"]["amount"], amount.to_string());
+        assert_eq!(json["value"]["decimals"], 8);
+
+        let contents = StateItemContents::<(), u128>::VecElement {
+            index: usize::MAX,
+            value: Some(u128::MAX),
+        };
+        let json = serde_json::to_value(&contents).unwrap();
+        assert_eq!(json["index"], usize::MAX.to_string());
+        assert_eq!(json["value"], u128::MAX.to_string());
+    }
+}
@@ -32,6 +32,7 @@ use axum::extract::{FromRequestParts, State};
 use axum::routing::get;
 use serde::{Deserialize, Serialize};
 use sov_rest_utils::Query;
+use sov_state::NativeStorage;
 
 use crate::hooks::TxHooks;
 use crate::map::NamespacedStateMap;
@@ -191,6 +192,13 @@ impl<T, S: Spec> ApiState<T, S> {
         self.storage_receiver.borrow()
     }
 
+    /// Returns the version of the state this request is served at: the
+    /// `?height` requested by the client, or the latest version of the storage.
+    /// Useful to label responses, e.g. in their `meta`, and to detect staleness.
+    pub fn version(&self) -> u64 {
+        self.height.unwrap_or_else(|| self.storage().version())
+    }
+
     /// Returns a [`ApiStateAccessor`] that you can use to read state from within REST
     /// API.
     pub fn api_state_accessor(&self) -> ApiStateAccessor<S> {
@@ -228,3 +236,24 @@ where
 pub(crate) struct HeightQueryParam {
     pub height: u64,
 }
+
+/// Opts into Merkle proofs for state reads, e.g. `?proof=1` or `?proof=true`.
+#[derive(Copy, Clone, Debug, Deserialize)]
+pub(crate) struct ProofQueryParam {
+    #[serde(deserialize_with = "deserialize_flag")]
+    pub proof: bool,
+}
+
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let flag = String::deserialize(deserializer)?;
+    match flag.as_str() {
+        "1" | "true" => Ok(true),
+        "0" | "false" => Ok(false),
+        other => Err(serde::de::Error::custom(format!(
+            "invalid flag value `{other}`, expected one of `1`, `0`, `true`, `false`"
+        ))),
+    }
+}
@@ -1,7 +1,17 @@
 use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::stf::BlobResult;
 
 use crate::{KernelWorkingSet, Spec};
 
+/// The blobs selected by a [`BlobSelector`], along with the outcome of the blobs of the current
+/// slot which were not selected.
+pub struct BlobSelection<B, A> {
+    /// The blobs to process in the current slot, in execution order.
+    pub selected: Vec<(B, A)>,
+    /// The blobs of the current slot which were deferred or ignored.
+    pub unselected: Vec<BlobResult>,
+}
+
 /// BlobSelector decides which blobs to process in a current slot.
 pub trait BlobSelector<Da: DaSpec> {
     /// Spec type
@@ -18,4 +28,20 @@ pub trait BlobSelector<Da: DaSpec> {
     ) -> anyhow::Result<Vec<(Self::BlobType, Da::Address)>>
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>;
+
+    /// Same as [`BlobSelector::get_blobs_for_this_slot`], but also reports which blobs of the current
+    /// slot were deferred or ignored. The default implementation doesn't report any unselected blob.
+    fn get_blobs_for_this_slot_with_outcomes<'a, 'k, I>(
+        &self,
+        current_blobs: I,
+        state: &mut KernelWorkingSet<'k, Self::Spec>,
+    ) -> anyhow::Result<BlobSelection<Self::BlobType, Da::Address>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        Ok(BlobSelection {
+            selected: self.get_blobs_for_this_slot(current_blobs, state)?,
+            unselected: Vec::new(),
+        })
+    }
 }
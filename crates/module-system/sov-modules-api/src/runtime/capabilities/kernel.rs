@@ -2,7 +2,7 @@ use sov_rollup_interface::da::DaSpec;
 use sov_state::Storage;
 
 use super::BlobSelector;
-use crate::{BootstrapWorkingSet, Gas, KernelWorkingSet, Spec, StateCheckpoint};
+use crate::{BootstrapWorkingSet, Gas, KernelWorkingSet, Spec, StateAccessBudget, StateCheckpoint};
 
 /// The kernel is responsible for managing the inputs to the `apply_blob` method.
 /// A simple implementation will simply process all blobs in the order that they appear,
@@ -39,6 +39,10 @@ pub trait KernelSlotHooks<S: Spec, Da: DaSpec>: Kernel<S, Da> {
         pre_state_root: &<<Self::Spec as Spec>::Storage as Storage>::Root,
         state: &mut StateCheckpoint<Self::Spec>,
     ) -> <S::Gas as Gas>::Price;
+    /// Returns the gas limit of the current slot. Transactions that don't specify a gas limit are executed with it.
+    fn block_gas_limit(&self, state: &mut StateCheckpoint<Self::Spec>) -> S::Gas;
+    /// Returns the maximum number of state reads and writes that a transaction can perform in the current slot.
+    fn state_access_budget(&self, state: &mut StateCheckpoint<Self::Spec>) -> StateAccessBudget;
     /// Called at the end of a slot
     fn end_slot_hook(&self, gas_used: &S::Gas, state: &mut StateCheckpoint<Self::Spec>);
 }
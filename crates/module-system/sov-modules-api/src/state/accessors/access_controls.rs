@@ -13,7 +13,8 @@ use super::seal::CachedAccessor;
 use crate::state::traits::{AccessoryStateWriter, ProvableStateReader, ProvableStateWriter};
 use crate::{
     AccessoryDelta, AccessoryStateCheckpoint, AccessoryStateReader, GasMeter, PreExecWorkingSet,
-    Spec, StateCheckpoint, StateReader, StateWriter, TxScratchpad, WorkingSet,
+    Spec, StateAccessBudgetExceeded, StateCheckpoint, StateReader, StateWriter, TxScratchpad,
+    WorkingSet,
 };
 
 macro_rules! inner_impl_unmetered_state_reader {
@@ -127,9 +128,17 @@ impl<S: Spec, PreExecChecksMeter: GasMeter<S::Gas>> ProvableStateWriter<User>
 
 impl<S: Spec> ProvableStateReader<User> for WorkingSet<S> {
     type GU = S::Gas;
+
+    fn record_state_read(&mut self) -> Result<(), StateAccessBudgetExceeded> {
+        self.state_access.record_read()
+    }
 }
 impl<S: Spec> ProvableStateWriter<User> for WorkingSet<S> {
     type GU = S::Gas;
+
+    fn record_state_write(&mut self) -> Result<(), StateAccessBudgetExceeded> {
+        self.state_access.record_write()
+    }
 }
 
 impl<S: Spec> AccessoryStateWriter for WorkingSet<S> {}
@@ -65,6 +65,47 @@ impl<S: Spec> StateCheckpoint<S> {
     ) {
         self.delta.freeze()
     }
+
+    /// Creates an independent copy of this [`StateCheckpoint`] which records
+    /// every state access made through it.
+    ///
+    /// Forks of the same checkpoint can be used concurrently, and then merged
+    /// back into the original one with [`StateCheckpoint::merge_fork`].
+    #[cfg(feature = "native")]
+    pub fn fork(&self) -> Self {
+        Self {
+            delta: self.delta.fork(),
+        }
+    }
+
+    /// Returns `true` if `self` and `other`, both created with
+    /// [`StateCheckpoint::fork`], wrote a key that the other one read or
+    /// wrote. Forks that don't conflict with each other can be merged in any
+    /// order with the same results as executing them one after the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either checkpoint is not a fork.
+    #[cfg(feature = "native")]
+    pub fn fork_conflicts_with(&self, other: &Self) -> bool {
+        self.delta
+            .recorded_accesses()
+            .conflicts_with(other.delta.recorded_accesses())
+    }
+
+    /// Replays all the state accesses made through `fork` on top of this
+    /// checkpoint, so that the result (witness included) is the same as if
+    /// they were made on this checkpoint directly. This only holds if `fork`
+    /// doesn't conflict with any of the changes made to this checkpoint
+    /// since it was forked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fork` was not created with [`StateCheckpoint::fork`].
+    #[cfg(feature = "native")]
+    pub fn merge_fork(&mut self, fork: Self) {
+        self.delta.merge_fork(fork.delta);
+    }
 }
 
 impl<S: Spec, N: CompileTimeNamespace> CachedAccessor<N> for StateCheckpoint<S> {
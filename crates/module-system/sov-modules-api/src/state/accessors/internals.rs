@@ -1,5 +1,7 @@
 use core::fmt;
 use std::collections::HashMap;
+#[cfg(feature = "native")]
+use std::collections::HashSet;
 
 use sov_state::{
     namespaces, Accessory, IsValueCached, Namespace, ProvableStorageCache, SlotKey, SlotValue,
@@ -23,6 +25,9 @@ pub(super) struct Delta<S: Storage> {
     user_cache: ProvableStorageCache<namespaces::User>,
     accessory_writes: HashMap<SlotKey, Option<SlotValue>>,
     pub(super) version: Option<u64>,
+    /// Only set on forks, see [`Delta::fork`].
+    #[cfg(feature = "native")]
+    recorder: Option<AccessRecorder>,
 }
 
 impl<S: Storage> Delta<S> {
@@ -38,6 +43,68 @@ impl<S: Storage> Delta<S> {
             kernel_cache: Default::default(),
             accessory_writes: Default::default(),
             version,
+            #[cfg(feature = "native")]
+            recorder: None,
+        }
+    }
+
+    /// Creates an independent copy of this [`Delta`] which records every
+    /// access made through it, so that they can later be replayed on top of
+    /// the original with [`Delta::merge_fork`].
+    ///
+    /// The fork starts with an empty witness: the witness entries are
+    /// generated again on the original when the accesses are replayed.
+    #[cfg(feature = "native")]
+    pub(super) fn fork(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            witness: Default::default(),
+            user_cache: self.user_cache.clone(),
+            kernel_cache: self.kernel_cache.clone(),
+            accessory_writes: self.accessory_writes.clone(),
+            version: self.version,
+            recorder: Some(AccessRecorder::default()),
+        }
+    }
+
+    /// Returns the accesses recorded by this fork.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this [`Delta`] was not created with [`Delta::fork`].
+    #[cfg(feature = "native")]
+    pub(super) fn recorded_accesses(&self) -> &AccessRecorder {
+        self.recorder
+            .as_ref()
+            .expect("Only forked deltas record their accesses")
+    }
+
+    /// Replays the accesses recorded by `fork` on top of `self`, in the same
+    /// order in which they were made. If no other accesses conflicting with
+    /// the ones of `fork` were made on `self` since the fork, the result is
+    /// the same as if they had been made on `self` directly, witness
+    /// included.
+    #[cfg(feature = "native")]
+    pub(super) fn merge_fork(&mut self, fork: Self) {
+        let recorder = fork
+            .recorder
+            .expect("Only forked deltas can be merged back");
+
+        for (namespace, key, access) in recorder.log {
+            match access {
+                // Accessory reads don't leave any trace, so they don't need to
+                // be replayed.
+                RecordedAccess::Read if namespace == Namespace::Accessory => {}
+                RecordedAccess::Read => {
+                    self.get(namespace, &key);
+                }
+                RecordedAccess::Write(Some(value)) => {
+                    self.set(namespace, &key, value);
+                }
+                RecordedAccess::Write(None) => {
+                    self.delete(namespace, &key);
+                }
+            }
         }
     }
 
@@ -49,6 +116,7 @@ impl<S: Storage> Delta<S> {
             accessory_writes,
             witness,
             version,
+            ..
         } = self;
 
         (
@@ -68,6 +136,11 @@ impl<S: Storage> Delta<S> {
 
 impl<S: Storage> UniversalStateAccessor for Delta<S> {
     fn get(&mut self, namespace: Namespace, key: &SlotKey) -> (Option<SlotValue>, IsValueCached) {
+        #[cfg(feature = "native")]
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_read(namespace, key);
+        }
+
         match namespace {
             Namespace::User => {
                 self.user_cache
@@ -89,6 +162,11 @@ impl<S: Storage> UniversalStateAccessor for Delta<S> {
     }
 
     fn set(&mut self, namespace: Namespace, key: &SlotKey, value: SlotValue) -> IsValueCached {
+        #[cfg(feature = "native")]
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_write(namespace, key, Some(value.clone()));
+        }
+
         match namespace {
             Namespace::User => self.user_cache.set(key, value),
             Namespace::Kernel => self.kernel_cache.set(key, value),
@@ -107,6 +185,11 @@ impl<S: Storage> UniversalStateAccessor for Delta<S> {
     }
 
     fn delete(&mut self, namespace: Namespace, key: &SlotKey) -> IsValueCached {
+        #[cfg(feature = "native")]
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_write(namespace, key, None);
+        }
+
         match namespace {
             Namespace::User => self.user_cache.delete(key),
             Namespace::Kernel => self.kernel_cache.delete(key),
@@ -127,6 +210,54 @@ impl<S: Storage> fmt::Debug for Delta<S> {
     }
 }
 
+#[cfg(feature = "native")]
+#[derive(Debug)]
+enum RecordedAccess {
+    Read,
+    Write(Option<SlotValue>),
+}
+
+/// The log of the accesses made through a forked [`Delta`], together with
+/// the sets of keys read and written.
+#[cfg(feature = "native")]
+#[derive(Debug, Default)]
+pub(super) struct AccessRecorder {
+    log: Vec<(Namespace, SlotKey, RecordedAccess)>,
+    reads: HashSet<(SlotKey, Namespace)>,
+    writes: HashSet<(SlotKey, Namespace)>,
+}
+
+#[cfg(feature = "native")]
+impl AccessRecorder {
+    fn record_read(&mut self, namespace: Namespace, key: &SlotKey) {
+        let entry = (key.clone(), namespace);
+        // Only the first read of a key can reach the underlying storage; all
+        // the following ones are served from the cache.
+        if !self.writes.contains(&entry) && self.reads.insert(entry) {
+            self.log
+                .push((namespace, key.clone(), RecordedAccess::Read));
+        }
+    }
+
+    fn record_write(&mut self, namespace: Namespace, key: &SlotKey, value: Option<SlotValue>) {
+        self.writes.insert((key.clone(), namespace));
+        self.log
+            .push((namespace, key.clone(), RecordedAccess::Write(value)));
+    }
+
+    /// Returns `true` if either recorder wrote a key that the other one read
+    /// or wrote.
+    pub(super) fn conflicts_with(&self, other: &Self) -> bool {
+        let writes_into = |lhs: &Self, rhs: &Self| {
+            lhs.writes
+                .iter()
+                .any(|key| rhs.reads.contains(key) || rhs.writes.contains(key))
+        };
+
+        writes_into(self, other) || writes_into(other, self)
+    }
+}
+
 /// A delta containing *only* the accessory state.
 pub struct AccessoryDelta<S: Storage> {
     // This inner storage is never accessed inside the zkVM because reads are
@@ -14,6 +14,7 @@ use super::seal::CachedAccessor;
 use super::UniversalStateAccessor;
 use crate::module::Spec;
 use crate::state::events::TypedEvent;
+use crate::state::{StateAccessBudget, StateAccessCounter};
 use crate::transaction::{
     transaction_consumption_helper, AuthenticatedTransactionData, PriorityFeeBips,
     TransactionConsumption, TxGasMeter,
@@ -164,6 +165,7 @@ impl<S: Spec, PreExecChecksMeter: GasMeter<S::Gas>> PreExecWorkingSet<S, PreExec
             delta: RevertableWriter::new(self.inner),
             events: Default::default(),
             gas_meter,
+            state_access: Default::default(),
             max_fee,
             max_priority_fee_bips: tx.max_priority_fee_bips,
         })
@@ -219,6 +221,7 @@ impl<S: Spec> StateCheckpoint<S> {
             delta: RevertableWriter::new(stashed_working_set),
             events: Default::default(),
             gas_meter: TxGasMeter::unmetered(),
+            state_access: Default::default(),
             max_fee: 0,
             max_priority_fee_bips: PriorityFeeBips::ZERO,
         }
@@ -242,6 +245,7 @@ impl<S: Spec> StateCheckpoint<S> {
             delta: RevertableWriter::new(stashed_working_set),
             events: Default::default(),
             gas_meter: tx.gas_meter(gas_price),
+            state_access: Default::default(),
             max_fee: tx.max_fee,
             max_priority_fee_bips: tx.max_priority_fee_bips,
         }
@@ -257,6 +261,7 @@ pub struct WorkingSet<S: Spec> {
     pub(super) delta: RevertableWriter<TxScratchpad<S>>,
     events: Vec<TypedEvent>,
     gas_meter: TxGasMeter<S::Gas>,
+    pub(super) state_access: StateAccessCounter,
 
     // Gas parameters of the transaction associated with the working set
     max_fee: u64,
@@ -328,6 +333,13 @@ impl<S: Spec> WorkingSet<S> {
         self.max_fee
     }
 
+    /// Limits the number of state reads and writes that can be performed through this
+    /// [`WorkingSet`]. Accesses beyond the budget fail with [`crate::StateAccessorError::BudgetExceeded`].
+    pub fn with_state_access_budget(mut self, budget: StateAccessBudget) -> Self {
+        self.state_access = StateAccessCounter::new(budget);
+        self
+    }
+
     /// A helper function to create a new [`WorkingSet`] with a given gas price and remaining funds.
     #[cfg(test)]
     pub fn new_with_gas_meter(
@@ -344,6 +356,7 @@ impl<S: Spec> WorkingSet<S> {
             delta: RevertableWriter::new(tx_scratchpad),
             events: Default::default(),
             gas_meter: TxGasMeter::new(remaining_funds, price.clone()),
+            state_access: Default::default(),
             max_fee: 0,
             max_priority_fee_bips: PriorityFeeBips::ZERO,
         }
@@ -371,6 +384,7 @@ impl<S: Spec> WorkingSet<S> {
             delta: RevertableWriter::new(tx_scratchpad),
             events: Default::default(),
             gas_meter: TxGasMeter::unmetered(),
+            state_access: Default::default(),
             max_fee: 0,
             max_priority_fee_bips: PriorityFeeBips::ZERO,
         }
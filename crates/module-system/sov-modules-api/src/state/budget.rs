@@ -0,0 +1,99 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The maximum number of state accesses that a single transaction can perform.
+///
+/// Gas accounts for computation, but every state access also grows the witness, which
+/// makes lots of cheap reads and writes disproportionately expensive to prove. This budget
+/// bounds that cost independently of the gas price. Deletions count as writes.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct StateAccessBudget {
+    /// The maximum number of state reads.
+    pub max_reads: u64,
+    /// The maximum number of state writes.
+    pub max_writes: u64,
+}
+
+impl StateAccessBudget {
+    /// A budget that never runs out.
+    pub const UNLIMITED: Self = Self {
+        max_reads: u64::MAX,
+        max_writes: u64::MAX,
+    };
+}
+
+impl Default for StateAccessBudget {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// Raised when a transaction performs more state accesses than allowed by its
+/// [`StateAccessBudget`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum StateAccessBudgetExceeded {
+    /// The transaction tried to read more than `max_reads` values from the state.
+    #[error("The transaction exceeded its budget of {max_reads} state reads")]
+    Reads {
+        /// The maximum number of state reads.
+        max_reads: u64,
+    },
+    /// The transaction tried to write more than `max_writes` values to the state.
+    #[error("The transaction exceeded its budget of {max_writes} state writes")]
+    Writes {
+        /// The maximum number of state writes.
+        max_writes: u64,
+    },
+}
+
+/// Counts the state accesses of a transaction against its [`StateAccessBudget`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StateAccessCounter {
+    budget: StateAccessBudget,
+    reads: u64,
+    writes: u64,
+}
+
+impl StateAccessCounter {
+    pub(crate) fn new(budget: StateAccessBudget) -> Self {
+        Self {
+            budget,
+            reads: 0,
+            writes: 0,
+        }
+    }
+
+    /// Records a state read, failing if it exceeds the budget.
+    pub(crate) fn record_read(&mut self) -> Result<(), StateAccessBudgetExceeded> {
+        if self.reads >= self.budget.max_reads {
+            return Err(StateAccessBudgetExceeded::Reads {
+                max_reads: self.budget.max_reads,
+            });
+        }
+        self.reads += 1;
+        Ok(())
+    }
+
+    /// Records a state write or deletion, failing if it exceeds the budget.
+    pub(crate) fn record_write(&mut self) -> Result<(), StateAccessBudgetExceeded> {
+        if self.writes >= self.budget.max_writes {
+            return Err(StateAccessBudgetExceeded::Writes {
+                max_writes: self.budget.max_writes,
+            });
+        }
+        self.writes += 1;
+        Ok(())
+    }
+}
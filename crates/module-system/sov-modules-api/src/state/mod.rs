@@ -1,4 +1,5 @@
 mod accessors;
+mod budget;
 mod events;
 mod traits;
 
@@ -14,6 +15,8 @@ pub use accessors::{
     GenesisStateAccessor, KernelWorkingSet, PreExecWorkingSet, StateCheckpoint, TxScratchpad,
     VersionedStateReadWriter, WorkingSet,
 };
+pub(crate) use budget::StateAccessCounter;
+pub use budget::{StateAccessBudget, StateAccessBudgetExceeded};
 pub use events::TypedEvent;
 #[cfg(feature = "native")]
 pub use traits::ProvenStateAccessor;
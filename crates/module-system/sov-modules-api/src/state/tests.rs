@@ -6,7 +6,10 @@ use sov_state::{SlotKey, SlotValue, User};
 
 use super::traits::{StateReader, StateWriter};
 use crate::default_spec::DefaultSpec;
-use crate::{Gas, GasArray, GasMeter, Spec, WorkingSet};
+use crate::{
+    Gas, GasArray, GasMeter, Spec, StateAccessBudget, StateAccessBudgetExceeded,
+    StateAccessorError, WorkingSet,
+};
 
 type S = DefaultSpec<MockZkVerifier, MockZkVerifier, Native>;
 
@@ -101,3 +104,58 @@ fn test_charge_gas_set_then_retrieve() {
         "The remaining funds should be equal to the expected value, some gas should have been refunded because of the hot read"
     );
 }
+
+#[test]
+fn test_state_access_budget_reads() {
+    let gas_price = <<S as Spec>::Gas as Gas>::Price::from_slice(&[1; 2]);
+    let mut working_set =
+        create_working_set(u64::MAX, &gas_price).with_state_access_budget(StateAccessBudget {
+            max_reads: 2,
+            max_writes: 0,
+        });
+
+    for key in [b"key1", b"key2"] {
+        assert!(
+            StateReader::<User>::get(&mut working_set, &SlotKey::from_slice(key)).is_ok(),
+            "The get operation should succeed because the read budget is not exhausted"
+        );
+    }
+
+    match StateReader::<User>::get(&mut working_set, &SlotKey::from_slice(b"key3")) {
+        Err(StateAccessorError::BudgetExceeded { inner, .. }) => {
+            assert_eq!(inner, StateAccessBudgetExceeded::Reads { max_reads: 2 });
+        }
+        res => panic!(
+            "The get operation should fail because the read budget is exhausted, got {res:?}"
+        ),
+    }
+}
+
+#[test]
+fn test_state_access_budget_writes() {
+    let gas_price = <<S as Spec>::Gas as Gas>::Price::from_slice(&[1; 2]);
+    let mut working_set =
+        create_working_set(u64::MAX, &gas_price).with_state_access_budget(StateAccessBudget {
+            max_reads: 0,
+            max_writes: 2,
+        });
+
+    let key = SlotKey::from_slice(b"key");
+    assert!(
+        StateWriter::<User>::set(&mut working_set, &key, SlotValue::from("value")).is_ok(),
+        "The set operation should succeed because the write budget is not exhausted"
+    );
+    assert!(
+        StateWriter::<User>::delete(&mut working_set, &key).is_ok(),
+        "The delete operation should succeed because the write budget is not exhausted"
+    );
+
+    match StateWriter::<User>::set(&mut working_set, &key, SlotValue::from("value2")) {
+        Err(StateAccessorError::BudgetExceeded { inner, .. }) => {
+            assert_eq!(inner, StateAccessBudgetExceeded::Writes { max_writes: 2 });
+        }
+        res => panic!(
+            "The set operation should fail because the write budget is exhausted, got {res:?}"
+        ),
+    }
+}
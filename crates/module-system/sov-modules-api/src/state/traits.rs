@@ -12,6 +12,7 @@ use sov_state::{
 use thiserror::Error;
 
 use super::accessors::seal::CachedAccessor;
+use super::StateAccessBudgetExceeded;
 #[cfg(any(feature = "test-utils", feature = "evm"))]
 use crate::UnmeteredStateWrapper;
 use crate::{Gas, GasMeter, GasMeteringError, Spec};
@@ -94,8 +95,8 @@ impl<S: Spec, T> GenesisState<S> for T where
 {
 }
 
-/// The set of errors that can be raised during state accesses. For now all these errors are
-/// caused by gas metering issues, hence this error type is a wrapper around the [`GasMeteringError`].
+/// The set of errors that can be raised during state accesses. These errors are caused either by
+/// gas metering issues, wrapping a [`GasMeteringError`], or by exceeding a [`StateAccessBudget`](super::StateAccessBudget).
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum StateAccessorError<GU: Gas> {
     /// An error occurred when trying to get a value from the state.
@@ -134,6 +135,15 @@ pub enum StateAccessorError<GU: Gas> {
         inner: GasMeteringError<GU>,
         namespace: ProvableNamespace,
     },
+    /// The transaction exceeded its state access budget.
+    #[error(
+        "The state access budget was exceeded while accessing the value (key {key:?}): {inner}, namespace: {namespace:?}"
+    )]
+    BudgetExceeded {
+        key: SlotKey,
+        inner: StateAccessBudgetExceeded,
+        namespace: ProvableNamespace,
+    },
 }
 
 /// Returns the gas to charge for a decoding operation.
@@ -302,6 +312,11 @@ pub trait ProvableStateReader<N: ProvableCompileTimeNamespace>:
     CachedAccessor<N> + GasMeter<Self::GU>
 {
     type GU: Gas;
+
+    /// Counts a read against the [`StateAccessBudget`](super::StateAccessBudget) of the accessor, if it has one.
+    fn record_state_read(&mut self) -> Result<(), StateAccessBudgetExceeded> {
+        Ok(())
+    }
 }
 
 macro_rules! blanket_impl_metered_state_reader {
@@ -310,6 +325,12 @@ macro_rules! blanket_impl_metered_state_reader {
             type Error = StateAccessorError<T::GU>;
 
             fn get(&mut self, key: &SlotKey) -> Result<Option<SlotValue>, Self::Error> {
+                self.record_state_read()
+                    .map_err(|e| StateAccessorError::BudgetExceeded{
+                        key: key.clone(),
+                        inner: e,
+                        namespace: <$namespace>::PROVABLE_NAMESPACE,
+                    })?;
                 self.charge_gas(&gas_to_charge_for_read())
                     .map_err(|e| StateAccessorError::Get{
                         key: key.clone(),
@@ -401,6 +422,11 @@ pub trait ProvableStateWriter<N: ProvableCompileTimeNamespace>:
     CachedAccessor<N> + GasMeter<Self::GU>
 {
     type GU: Gas;
+
+    /// Counts a write or a deletion against the [`StateAccessBudget`](super::StateAccessBudget) of the accessor, if it has one.
+    fn record_state_write(&mut self) -> Result<(), StateAccessBudgetExceeded> {
+        Ok(())
+    }
 }
 
 macro_rules! blanket_impl_metered_state_writer {
@@ -409,6 +435,12 @@ macro_rules! blanket_impl_metered_state_writer {
             type Error = StateAccessorError<T::GU>;
 
             fn set(&mut self, key: &SlotKey, value: SlotValue) -> Result<(), Self::Error> {
+                self.record_state_write()
+                    .map_err(|e| StateAccessorError::BudgetExceeded{
+                        key: key.clone(),
+                        inner: e,
+                        namespace: <$namespace>::PROVABLE_NAMESPACE,
+                    })?;
                 self.charge_gas(&gas_to_charge_for_write())
                     .map_err(|e| StateAccessorError::Set{
                         key: key.clone(),
@@ -425,6 +457,12 @@ macro_rules! blanket_impl_metered_state_writer {
             }
 
             fn delete(&mut self, key: &SlotKey) -> Result<(), Self::Error> {
+                self.record_state_write()
+                    .map_err(|e| StateAccessorError::BudgetExceeded{
+                        key: key.clone(),
+                        inner: e,
+                        namespace: <$namespace>::PROVABLE_NAMESPACE,
+                    })?;
                 self.charge_gas(&gas_to_charge_for_delete()).
                     map_err(|e| StateAccessorError::Delete{
                         key: key.clone(),
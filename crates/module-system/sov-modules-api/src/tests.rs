@@ -178,3 +178,20 @@ fn test_default_signature_roundtrip() {
     sig.verify(&key.pub_key(), msg)
         .expect("Roundtrip verification failed");
 }
+
+#[test]
+#[cfg(feature = "native")]
+fn test_module_scoped_metrics() {
+    let bank = sov_bank::Bank::<TestSpec>::default();
+
+    let metrics = bank.metrics();
+    assert_eq!(metrics.namespace(), "bank");
+    assert_eq!(
+        metrics.const_labels().get("module").map(String::as_str),
+        Some("Bank")
+    );
+
+    let metrics = metrics.with_registry(Default::default());
+    metrics.counter("transfers").inc();
+    assert_eq!(metrics.counter("transfers").get(), 1);
+}
@@ -103,9 +103,20 @@ pub struct TxDetails<S: Spec> {
     /// If the scalar product of the gas limit and the gas price is greater than the `max_fee`, the transaction will be rejected.
     /// Then up to `gas_limit *_scalar gas_price` gas tokens can be spent on gas execution in the transaction execution - if the
     /// transaction spends more than that amount, it will run out of gas and be reverted.
+    ///
+    /// If no gas limit is provided, the transaction only commits to its `max_fee`, and the rollup executes it with
+    /// the gas limit of the current block. If `block_gas_limit *_scalar gas_price` is greater than the `max_fee`,
+    /// every dimension of the inferred limit is scaled down by the same factor so that the transaction can spend
+    /// at most `max_fee` gas tokens. The inferred limit is recorded in the transaction receipt.
+    /// The limit is not inferred from a simulation of the transaction, because native and zk execution must agree on it.
     pub gas_limit: Option<S::Gas>,
     /// The ID of the target chain.
     pub chain_id: u64,
+    /// The last slot in which the transaction can be included, if any.
+    /// Once the rollup has moved past that slot, the transaction is skipped instead of executed,
+    /// which lets wallets issue transactions that expire rather than lingering indefinitely.
+    #[serde(default)]
+    pub valid_until_slot: Option<u64>,
 }
 
 /// A Transaction object that is compatible with the module-system/sov-default-stf.
@@ -253,6 +264,7 @@ impl<S: Spec> UnsignedTransaction<S> {
                 max_fee,
                 gas_limit,
                 chain_id,
+                valid_until_slot: None,
             },
         }
     }
@@ -291,6 +303,7 @@ impl<S: Spec> From<Transaction<S>> for AuthenticatedTransactionData<S> {
             max_priority_fee_bips: tx.details.max_priority_fee_bips,
             max_fee: tx.details.max_fee,
             gas_limit: tx.details.gas_limit,
+            valid_until_slot: tx.details.valid_until_slot,
         }
     }
 }
@@ -336,8 +349,11 @@ pub struct AuthenticatedTransactionData<S: Spec> {
     pub max_priority_fee_bips: PriorityFeeBips,
     /// The maximum fee that can be paid for this transaction expressed as a the gas token amount
     pub max_fee: u64,
-    /// The estimated gas usage of the transaction
+    /// The estimated gas usage of the transaction. The STF infers it from the block gas limit
+    /// and the `max_fee` if the transaction did not provide one, see [`TxDetails::gas_limit`].
     pub gas_limit: Option<S::Gas>,
+    /// The last slot in which the transaction can be included, see [`TxDetails::valid_until_slot`].
+    pub valid_until_slot: Option<u64>,
 }
 
 impl<S: Spec> AuthenticatedTransactionData<S> {
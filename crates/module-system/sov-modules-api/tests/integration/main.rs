@@ -1,3 +1,4 @@
 mod proof_tests;
+mod rest_tests;
 mod state_tests;
 mod working_set_tests;
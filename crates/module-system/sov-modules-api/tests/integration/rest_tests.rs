@@ -0,0 +1,165 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use sov_mock_zkvm::MockZkVerifier;
+use sov_modules_api::execution_mode::Native;
+use sov_modules_api::rest::HasRestApi;
+use sov_modules_api::*;
+use sov_prover_storage_manager::SimpleStorageManager;
+use sov_state::{NativeStorage, Storage, StorageProof};
+use sov_value_setter::ValueSetter;
+use tower::ServiceExt;
+use unwrap_infallible::UnwrapInfallible;
+
+type S = sov_modules_api::default_spec::DefaultSpec<MockZkVerifier, MockZkVerifier, Native>;
+
+async fn get_json(router: axum::Router, uri: &str) -> serde_json::Value {
+    get_json_with_status(router, uri, StatusCode::OK).await
+}
+
+async fn get_json_with_status(
+    router: axum::Router,
+    uri: &str,
+    expected_status: StatusCode,
+) -> serde_json::Value {
+    let response = router
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), expected_status);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[derive(Default, sov_modules_api::macros::RuntimeRestApi)]
+struct TestRuntime<S: Spec> {
+    value_setter: ValueSetter<S>,
+}
+
+impl<S: Spec> hooks::TxHooks for TestRuntime<S> {
+    type Spec = S;
+    type TxState = WorkingSet<S>;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_state_value_rest_proof() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleStorageManager::new(tmpdir.path());
+    let storage = storage_manager.create_storage();
+    let module = ValueSetter::<S>::default();
+
+    let mut state = StateCheckpoint::<S>::new(storage.clone());
+    module.value.set(&42, &mut state).unwrap_infallible();
+    let (cache_log, _, witness) = state.freeze();
+    let (root, change_set) = storage
+        .validate_and_materialize(cache_log, &witness)
+        .expect("Native jmt validation should succeed");
+    storage_manager.commit(change_set);
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = module.rest_api(receiver);
+
+    // Proofs are opt-in.
+    let body = get_json(router.clone(), "/state/value").await;
+    assert_eq!(body["data"]["value"], 42);
+    assert!(body["data"].get("proof").is_none());
+
+    let body = get_json(router, "/state/value?proof=1").await;
+    assert_eq!(body["data"]["value"], 42);
+
+    let state_proof = &body["data"]["proof"];
+    assert_eq!(state_proof["version"], "1");
+    assert_eq!(
+        state_proof["root"],
+        format!("0x{}", hex::encode(root.as_ref()))
+    );
+
+    let proof: StorageProof<<<S as Spec>::Storage as Storage>::Proof> =
+        serde_json::from_value(state_proof["proof"].clone()).unwrap();
+    let value = module.value.verify_proof::<S>(root, proof).unwrap();
+    assert_eq!(value, Some(42));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_state_proof_at_future_height() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleStorageManager::new(tmpdir.path());
+    let storage = storage_manager.create_storage();
+    let module = ValueSetter::<S>::default();
+
+    let mut state = StateCheckpoint::<S>::new(storage.clone());
+    module.value.set(&42, &mut state).unwrap_infallible();
+    let (cache_log, _, witness) = state.freeze();
+    let (_, change_set) = storage
+        .validate_and_materialize(cache_log, &witness)
+        .expect("Native jmt validation should succeed");
+    storage_manager.commit(change_set);
+
+    let (_sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = module.rest_api(receiver);
+
+    let body = get_json_with_status(
+        router,
+        "/state/value?height=100&proof=1",
+        StatusCode::NOT_FOUND,
+    )
+    .await;
+    assert_eq!(body["errors"][0]["title"], "State version '100' not found");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_state_rest_version_meta() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = SimpleStorageManager::new(tmpdir.path());
+    let module = ValueSetter::<S>::default();
+
+    let (sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = module.rest_api(receiver);
+
+    for (value, expected_version) in [(42, 1), (43, 2)] {
+        let storage = storage_manager.create_storage();
+        let mut state = StateCheckpoint::<S>::new(storage.clone());
+        module.value.set(&value, &mut state).unwrap_infallible();
+        let (cache_log, _, witness) = state.freeze();
+        let (_, change_set) = storage
+            .validate_and_materialize(cache_log, &witness)
+            .expect("Native jmt validation should succeed");
+        storage_manager.commit(change_set);
+
+        let storage = storage_manager.create_storage();
+        assert_eq!(storage.version(), expected_version);
+        sender.send(storage).unwrap();
+
+        let body = get_json(router.clone(), "/state/value").await;
+        assert_eq!(body["data"]["value"], value);
+        assert_eq!(body["meta"]["version"], expected_version.to_string());
+    }
+
+    // Historical reads are labelled with the requested height.
+    let body = get_json(router, "/state/value?height=1").await;
+    assert_eq!(body["data"]["value"], 42);
+    assert_eq!(body["meta"]["version"], "1");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_unknown_module_rest_route() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage_manager = SimpleStorageManager::new(tmpdir.path());
+    let (_sender, receiver) = tokio::sync::watch::channel(storage_manager.create_storage());
+    let router = TestRuntime::<S>::default().rest_api(receiver);
+
+    // Registered modules are still reachable.
+    get_json(router.clone(), "/modules/value-setter").await;
+
+    for uri in ["/modules/bogus", "/modules/bogus/state/value"] {
+        let body = get_json_with_status(router.clone(), uri, StatusCode::NOT_FOUND).await;
+        assert_eq!(body["errors"][0]["title"], "Module 'bogus' not found");
+        assert_eq!(body["errors"][0]["details"]["id"], "bogus");
+        assert_eq!(
+            body["meta"]["availableModules"],
+            serde_json::json!(["value-setter"])
+        );
+    }
+}
@@ -267,6 +267,28 @@ fn test_witness_round_trip() -> Result<(), Infallible> {
     Ok(())
 }
 
+/// Test that a write-once state value can't be overwritten once set.
+#[test]
+fn test_write_once_state_value() -> Result<(), Infallible> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage::<StorageSpec>(tmpdir.path()).unwrap();
+    let state_value = WriteOnceStateValue::new(Prefix::new(vec![0]));
+
+    let mut state: StateCheckpoint<S> = StateCheckpoint::new(storage.clone());
+    assert_eq!(state_value.get(&mut state)?, None);
+    state_value
+        .set_once(&11, &mut state)?
+        .expect("The first write should succeed");
+
+    let err = state_value
+        .set_once(&22, &mut state)?
+        .expect_err("The second write should fail");
+    assert_eq!(&err.0, state_value.prefix());
+    assert_eq!(state_value.get(&mut state)?, Some(11));
+
+    Ok(())
+}
+
 /// Test that the state values with a standard working set get written to the user space
 #[test]
 fn test_state_value_user_namespace() -> Result<(), Infallible> {
@@ -402,6 +424,33 @@ fn test_state_map_user_namespace() -> Result<(), Infallible> {
     Ok(())
 }
 
+/// Test that `StateMap::contains_key` matches `StateMap::get(..).is_some()`
+#[test]
+fn test_state_map_contains_key() -> Result<(), Infallible> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let mut state: StateCheckpoint<S> = StateCheckpoint::new(storage);
+
+    let map = StateMap::<u32, Vec<u8>>::new(Prefix::new(vec![0]));
+    map.set(&1, &vec![1, 2, 3], &mut state)?;
+    map.set(&2, &vec![7; 1_000_000], &mut state)?;
+    map.set(&3, &vec![], &mut state)?;
+    map.delete(&3, &mut state)?;
+
+    for key in [1, 2, 3, 4] {
+        assert_eq!(
+            map.contains_key(&key, &mut state)?,
+            map.get(&key, &mut state)?.is_some(),
+            "contains_key doesn't match get for key {key}"
+        );
+    }
+    assert!(map.contains_key(&2, &mut state)?);
+    assert!(!map.contains_key(&3, &mut state)?);
+    assert!(!map.contains_key(&4, &mut state)?);
+
+    Ok(())
+}
+
 /// Test that the kernel state maps with a kernel working set get written to the kernel space
 #[test]
 fn test_versioned_state_value_kernel_namespace() -> Result<(), Infallible> {
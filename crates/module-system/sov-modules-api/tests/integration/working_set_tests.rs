@@ -6,7 +6,7 @@ use sov_modules_api::{KernelWorkingSet, StateCheckpoint, StateReader, StateWrite
 use sov_prover_storage_manager::new_orphan_storage;
 use sov_state::codec::BcsCodec;
 use sov_state::namespaces::{Kernel, User};
-use sov_state::{SlotKey, SlotValue};
+use sov_state::{SlotKey, SlotValue, Storage};
 
 type TestSpec = sov_modules_api::default_spec::DefaultSpec<MockZkVerifier, MockZkVerifier, Native>;
 
@@ -50,3 +50,86 @@ fn test_kernel_workingset_get() {
             .expect("This should be unfaillible")
     );
 }
+
+#[test]
+fn test_checkpoint_fork_merge_matches_sequential_execution() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let codec = BcsCodec {};
+    let storage = new_orphan_storage(tempdir.path()).unwrap();
+
+    let prefix = sov_state::Prefix::new(vec![1, 2, 3]);
+    let key = |i: u8| SlotKey::new(&prefix, &vec![i], &codec);
+    let value = |i: u8| SlotValue::new(&vec![i], &codec);
+
+    let first_batch = |state: &mut StateCheckpoint<TestSpec>| {
+        let read = StateReader::<User>::get(state, &key(0)).unwrap();
+        assert_eq!(read, Some(value(0)));
+        StateWriter::<User>::set(state, &key(1), value(1)).unwrap();
+    };
+    let second_batch = |state: &mut StateCheckpoint<TestSpec>| {
+        assert_eq!(StateReader::<User>::get(state, &key(2)).unwrap(), None);
+        StateWriter::<User>::set(state, &key(3), value(3)).unwrap();
+        StateWriter::<User>::delete(state, &key(4)).unwrap();
+    };
+
+    let mut sequential = StateCheckpoint::<TestSpec>::new(storage.clone());
+    StateWriter::<User>::set(&mut sequential, &key(0), value(0)).unwrap();
+    first_batch(&mut sequential);
+    second_batch(&mut sequential);
+
+    let mut merged = StateCheckpoint::<TestSpec>::new(storage.clone());
+    StateWriter::<User>::set(&mut merged, &key(0), value(0)).unwrap();
+    let mut first_fork = merged.fork();
+    let mut second_fork = merged.fork();
+    first_batch(&mut first_fork);
+    second_batch(&mut second_fork);
+    assert!(!first_fork.fork_conflicts_with(&second_fork));
+    merged.merge_fork(first_fork);
+    merged.merge_fork(second_fork);
+
+    let (sequential_accesses, _, sequential_witness) = sequential.freeze();
+    let (merged_accesses, _, merged_witness) = merged.freeze();
+    assert_eq!(
+        serde_json::to_value(&sequential_witness).unwrap(),
+        serde_json::to_value(&merged_witness).unwrap()
+    );
+
+    let (sequential_root, _) = storage
+        .compute_state_update(sequential_accesses, &sequential_witness)
+        .unwrap();
+    let (merged_root, _) = storage
+        .compute_state_update(merged_accesses, &merged_witness)
+        .unwrap();
+    assert_eq!(sequential_root, merged_root);
+}
+
+#[test]
+fn test_checkpoint_fork_conflicts() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let codec = BcsCodec {};
+    let storage = new_orphan_storage(tempdir.path()).unwrap();
+
+    let prefix = sov_state::Prefix::new(vec![1, 2, 3]);
+    let storage_key = SlotKey::new(&prefix, &vec![4, 5, 6], &codec);
+    let storage_value = SlotValue::new(&vec![7, 8, 9], &codec);
+
+    let checkpoint = StateCheckpoint::<TestSpec>::new(storage);
+
+    // Reads never conflict with each other.
+    let mut reader = checkpoint.fork();
+    let mut other_reader = checkpoint.fork();
+    StateReader::<User>::get(&mut reader, &storage_key).unwrap();
+    StateReader::<User>::get(&mut other_reader, &storage_key).unwrap();
+    assert!(!reader.fork_conflicts_with(&other_reader));
+
+    // A write conflicts with both reads and writes of the same key, in either
+    // order.
+    let mut writer = checkpoint.fork();
+    StateWriter::<User>::set(&mut writer, &storage_key, storage_value.clone()).unwrap();
+    assert!(reader.fork_conflicts_with(&writer));
+    assert!(writer.fork_conflicts_with(&reader));
+
+    let mut other_writer = checkpoint.fork();
+    StateWriter::<User>::set(&mut other_writer, &storage_key, storage_value).unwrap();
+    assert!(writer.fork_conflicts_with(&other_writer));
+}
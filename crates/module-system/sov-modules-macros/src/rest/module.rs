@@ -117,9 +117,12 @@ pub fn derive(tokens: DeriveInput) -> syn::Result<TokenStream> {
 
                 #(#router_nest_ops)*
 
-                let custom_router = HasCustomRestApi::<<Self as Module>::Spec>::custom_rest_api(
-                    &self, ApiState::new((&self), storage.clone()),
-                );
+                // Method call syntax, so that the module's own implementation
+                // takes precedence over the blanket one for `&T`.
+                let custom_router = self.custom_rest_api(ApiState::new(
+                    CustomRestApiInner::from_module(self),
+                    storage.clone(),
+                ));
                 router = router.nest("/", custom_router);
 
                 router
@@ -1,7 +1,9 @@
+use sov_chain_state::ChainState;
 use sov_db::ledger_db::LedgerDb;
-use sov_ledger_apis::LedgerRoutes;
+use sov_ledger_apis::{LedgerRoutes, LedgerRoutesConfig};
 use sov_modules_api::capabilities::Authenticator;
 use sov_modules_api::execution_mode::ExecutionMode;
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
 use sov_modules_api::{RuntimeEventProcessor, Spec};
 use sov_modules_stf_blueprint::{Runtime as RuntimeTrait, RuntimeEndpoints, TxReceiptContents};
 use sov_rollup_interface::da::DaSpec;
@@ -15,12 +17,18 @@ use tower_http::cors::CorsLayer;
 use crate::FullNodeBlueprint;
 
 /// Register rollup's default RPC methods and Axum router.
+///
+/// When `read_only` is set, the sequencer endpoints, which accept transactions, are omitted
+/// and requests to them get a 404 response. The ledger endpoints are configured with
+/// `ledger_routes`.
 pub fn register_endpoints<B, M, Auth>(
     storage: watch::Receiver<<B::Spec as Spec>::Storage>,
     ledger_db: &LedgerDb,
     sequencer_db: &SequencerDb,
     da_service: &B::DaService,
     sequencer: <B::DaSpec as DaSpec>::Address,
+    read_only: bool,
+    ledger_routes: LedgerRoutesConfig,
 ) -> anyhow::Result<RuntimeEndpoints>
 where
     B: FullNodeBlueprint<M> + 'static,
@@ -35,19 +43,28 @@ where
 
     // Ledger endpoint.
     {
-        let ledger_axum_router = LedgerRoutes::<
-            LedgerDb,
-            BatchSequencerOutcome,
-            TxReceiptContents,
-            <B::Runtime as RuntimeEventProcessor>::RuntimeEvent,
-        >::axum_router(ledger_db.clone(), "/ledger");
-        endpoints.axum_router = endpoints
-            .axum_router
-            .nest("/ledger", ledger_axum_router.with_state(ledger_db.clone()));
+        let ledger_axum_router =
+            LedgerRoutes::<
+                LedgerDb,
+                BatchSequencerOutcome,
+                TxReceiptContents,
+                <B::Runtime as RuntimeEventProcessor>::RuntimeEvent,
+            >::axum_router_with_config(ledger_db.clone(), "/ledger", ledger_routes);
+        // Historical state transitions live in the kernel state, but they're
+        // exposed alongside the rest of the ledger data.
+        let chain_state = ChainState::<B::Spec, B::DaSpec>::default();
+        let transitions_router =
+            chain_state.custom_rest_api(ApiState::new(chain_state.clone(), storage.clone()));
+        endpoints.axum_router = endpoints.axum_router.nest(
+            "/ledger",
+            ledger_axum_router
+                .with_state(ledger_db.clone())
+                .merge(transitions_router),
+        );
     }
 
     // Sequencer endpoints.
-    {
+    if !read_only {
         let config = FairBatchBuilderConfig {
             mempool_max_txs_count: u32::MAX as usize,
             max_batch_size_bytes: 1024 * 100,
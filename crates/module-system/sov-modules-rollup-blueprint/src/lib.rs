@@ -11,6 +11,8 @@ use sov_modules_api::execution_mode::ExecutionMode;
 use sov_modules_api::{BlobDataWithId, DaSpec, Spec};
 #[cfg(feature = "native")]
 mod endpoints;
+#[cfg(feature = "native")]
+mod metrics;
 
 pub mod pluggable_traits;
 use sov_modules_stf_blueprint::Runtime;
@@ -44,7 +46,7 @@ mod blueprint {
     use std::sync::Arc;
 
     use async_trait::async_trait;
-    use sov_db::ledger_db::LedgerDb;
+    use sov_db::ledger_db::{EventIndexAllowlist, LedgerDb};
     use sov_db::schema::{CacheDb, SchemaBatch};
     use sov_modules_api::execution_mode::ExecutionMode;
     use sov_modules_api::runtime::capabilities::Kernel;
@@ -193,6 +195,11 @@ mod blueprint {
         where
             <Self::Spec as Spec>::Storage: NativeStorage,
         {
+            // The metrics must be configured before anything updates them.
+            if let Err(error) = sov_metrics::init_metrics(&rollup_config.runner.metrics) {
+                tracing::warn!(%error, "Ignoring the metrics configuration of the rollup");
+            }
+
             let da_service = Arc::new(self.create_da_service(&rollup_config).await);
             let relative_da_genesis_block = da_service
                 .get_block_at(rollup_config.runner.genesis_height)
@@ -214,7 +221,21 @@ mod blueprint {
 
             let mut storage_manager = self.create_storage_manager(&rollup_config)?;
             let (prover_storage, ledger_state) = storage_manager.create_bootstrap_state()?;
-            let ledger_db = self.create_ledger_db(ledger_state)?;
+            let mut ledger_db = self.create_ledger_db(ledger_state)?;
+            if let Some(indexed_events) = &rollup_config.storage.indexed_events {
+                ledger_db = ledger_db
+                    .with_event_index_allowlist(EventIndexAllowlist::new(indexed_events.clone()));
+            }
+            if let Some(capacity) = rollup_config.storage.subscription_capacity {
+                ledger_db = ledger_db.with_subscription_capacity(capacity);
+            }
+
+            let metrics_ledger_db = ledger_db.clone();
+            tokio::spawn(async move {
+                if let Err(e) = crate::metrics::track_sequencer_slashes(metrics_ledger_db).await {
+                    tracing::error!(error = ?e, "Stopped tracking the sequencer slashes");
+                }
+            });
 
             let sequencer_db = SequencerDb::new(&rollup_config.storage.path)?;
 
@@ -242,7 +263,10 @@ mod blueprint {
                 &rollup_config,
             )?;
 
-            let native_stf = StfBlueprint::new();
+            let mut native_stf = StfBlueprint::new();
+            if let Some(batch_parallelism) = rollup_config.runner.batch_parallelism {
+                native_stf = native_stf.with_batch_parallelism(batch_parallelism);
+            }
 
             let proof_manager = ProofManager::new(
                 da_service.clone(),
@@ -0,0 +1,39 @@
+use sov_db::ledger_db::LedgerDb;
+use sov_modules_stf_blueprint::TxReceiptContents;
+use sov_rollup_interface::rpc::{LedgerStateProvider, QueryMode};
+use sov_sequencer_registry::BatchSequencerOutcome;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Counts the sequencer slashes recorded in the receipts of the batches committed to the ledger,
+/// and reports them through [`sov_metrics::Metrics::sequencer_slashes_total`].
+///
+/// Only the batches committed after the task is started are counted. The ledger is read again on
+/// every slot notification, so that slots missed because the subscription lagged are still counted.
+pub(crate) async fn track_sequencer_slashes(ledger_db: LedgerDb) -> anyhow::Result<()> {
+    let mut slots = ledger_db.subscribe_slots();
+    let mut next_batch_number = ledger_db.get_next_items_numbers()?.batch_number;
+
+    loop {
+        match slots.recv().await {
+            Ok(_) | Err(RecvError::Lagged(_)) => {}
+            Err(RecvError::Closed) => return Ok(()),
+        }
+
+        let committed_batch_number = ledger_db.get_next_items_numbers()?.batch_number;
+        for number in next_batch_number..committed_batch_number {
+            let batch = ledger_db
+                .get_batch_by_number::<BatchSequencerOutcome, TxReceiptContents>(
+                    number,
+                    QueryMode::Compact,
+                )
+                .await?;
+
+            if let Some(BatchSequencerOutcome::Slashed(_)) = batch.map(|batch| batch.receipt) {
+                sov_metrics::update_metrics(|metrics| {
+                    metrics.sequencer_slashes_total.inc();
+                });
+            }
+        }
+        next_batch_number = committed_batch_number;
+    }
+}
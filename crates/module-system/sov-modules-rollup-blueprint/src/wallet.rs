@@ -3,7 +3,9 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use sov_cli::wallet_state::WalletState;
+use sov_cli::workflows::batch::BatchWorkflow;
 use sov_cli::workflows::keys::KeyWorkflow;
+use sov_cli::workflows::proofs::VerifyProofWorkflow;
 use sov_cli::workflows::rpc::RpcWorkflows;
 use sov_cli::workflows::transactions::TransactionWorkflow;
 use sov_cli::{clap, wallet_dir};
@@ -24,6 +26,9 @@ enum Workflows<File: clap::Subcommand, Json: clap::Subcommand, S: Spec> {
     Keys(KeyWorkflow<S>),
     #[clap(subcommand)]
     Rpc(RpcWorkflows<S>),
+    #[clap(subcommand)]
+    Batch(BatchWorkflow<S>),
+    VerifyProof(VerifyProofWorkflow),
 }
 
 #[derive(clap::Parser)]
@@ -96,6 +101,10 @@ where
             Workflows::Rpc(inner) => {
                 inner.run(&mut wallet_state, app_dir).await?;
             }
+            Workflows::Batch(inner) => {
+                inner.run(&mut wallet_state, app_dir).await?;
+            }
+            Workflows::VerifyProof(inner) => inner.run::<Self::Spec>(std::io::stdout())?,
         }
 
         wallet_state.save(wallet_state_path)
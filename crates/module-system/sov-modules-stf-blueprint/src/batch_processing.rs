@@ -11,7 +11,7 @@ use sov_modules_api::transaction::{
 };
 use sov_modules_api::{
     BatchWithId, Context, DaSpec, DispatchCall, Error, Gas, GasArray, GasMeter, PreExecWorkingSet,
-    RawTx, Spec, StateCheckpoint, TxScratchpad, UnlimitedGasMeter, WorkingSet,
+    RawTx, Spec, StateAccessBudget, StateCheckpoint, TxScratchpad, UnlimitedGasMeter, WorkingSet,
 };
 use sov_sequencer_registry::BatchSequencerOutcome;
 use tracing::{debug, error, info, warn};
@@ -77,12 +77,15 @@ impl From<ApplyBatchError> for BatchReceipt {
 
 #[tracing::instrument(skip_all, name = "StfBlueprint::apply_batch")]
 #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_batch<S, Da, RT, K>(
     runtime: &RT,
     mut checkpoint: StateCheckpoint<S>,
     batch_with_id: BatchWithId,
     sequencer_da_address: &Da::Address,
     gas_price: &<S::Gas as Gas>::Price,
+    block_gas_limit: &S::Gas,
+    state_access_budget: &StateAccessBudget,
     height: u64,
     is_registered_sequencer: bool,
 ) -> (ApplyBatch, StateCheckpoint<S>, S::Gas)
@@ -100,8 +103,12 @@ where
     );
 
     // ApplyBlobHook: begin
-    if let Err(e) = runtime.begin_batch_hook(&batch_with_id, sequencer_da_address, &mut checkpoint)
-    {
+    if let Err(e) = runtime.begin_batch_hook(
+        &batch_with_id,
+        sequencer_da_address,
+        height,
+        &mut checkpoint,
+    ) {
         error!(
             error = %e,
             batch_id = hex::encode(batch_with_id.id),
@@ -120,6 +127,33 @@ where
 
     let raw_txs = batch_with_id.batch.txs;
 
+    // An empty batch is a valid no-op: the sequencer did no work, so it is neither rewarded nor slashed.
+    if raw_txs.is_empty() {
+        debug!(
+            batch_id = hex::encode(batch_with_id.id),
+            sequencer_da_address = %sequencer_da_address,
+            "The batch is empty. The sequencer is not rewarded"
+        );
+
+        let sequencer_outcome = BatchSequencerOutcome::NotRewardable;
+        runtime.end_batch_hook(
+            sequencer_outcome.clone(),
+            sequencer_da_address,
+            &mut checkpoint,
+        );
+
+        return (
+            Ok(BatchReceipt {
+                batch_hash: batch_with_id.id,
+                tx_receipts: Vec::new(),
+                inner: sequencer_outcome,
+                gas_price: gas_price.to_vec(),
+            }),
+            checkpoint,
+            S::Gas::zero(),
+        );
+    }
+
     let mut tx_receipts = Vec::with_capacity(raw_txs.len());
     let mut gas_used = S::Gas::zero();
     let mut accumulated_reward = SequencerReward::ZERO;
@@ -138,6 +172,8 @@ where
                 raw_tx,
                 sequencer_da_address,
                 gas_price,
+                block_gas_limit,
+                state_access_budget,
                 height,
                 tx_scratchpad,
             )
@@ -147,6 +183,8 @@ where
                 raw_tx,
                 sequencer_da_address,
                 gas_price,
+                block_gas_limit,
+                state_access_budget,
                 height,
                 tx_scratchpad,
             )
@@ -234,6 +272,7 @@ where
                                     events: Vec::new(),
                                     receipt: TxEffect::Skipped(reason),
                                     gas_used: S::Gas::zero().to_vec(),
+                                    gas_limit: S::Gas::zero().to_vec(),
                                 };
 
                                 tx_receipts.push(tx_receipt);
@@ -286,13 +325,15 @@ where
 }
 
 /// Executes the entire transaction lifecycle.
-#[allow(clippy::result_large_err)]
+#[allow(clippy::result_large_err, clippy::too_many_arguments)]
 pub fn process_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
     runtime: &R,
     raw_tx: &RawTx,
     // TODO <`https://github.com/Sovereign-Labs/sovereign-sdk-wip/issues/728`>: group constant variables in the stf-blueprint
     sequencer_da_address: &D::Address,
     gas_price: &<S::Gas as Gas>::Price,
+    block_gas_limit: &S::Gas,
+    state_access_budget: &StateAccessBudget,
     height: u64,
     scratchpad: TxScratchpad<S>,
 ) -> Result<ApplyTxResult<S>, TxProcessingError<S>> {
@@ -344,7 +385,26 @@ pub fn process_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
         };
 
     let raw_tx_hash = &tx.raw_tx_hash;
-    let tx = &tx.authenticated_tx;
+    let tx = tx.authenticated_tx;
+
+    // Check that the transaction is still within its validity window.
+    if let Err(valid_until_slot) = check_not_expired(&tx, height) {
+        // We penalize the sequencer for the fixed amount of gas that was used to execute the transaction.
+        let tx_scratchpad = runtime.capabilities().penalize_sequencer(
+            sequencer_da_address,
+            format!("transaction expired at slot {valid_until_slot}, current slot is {height}"),
+            pre_exec_working_set,
+        );
+
+        return Err(TxProcessingError {
+            tx_scratchpad,
+            reason: TxProcessingErrorReason::Expired {
+                valid_until_slot,
+                current_slot: height,
+                raw_tx_hash: *raw_tx_hash,
+            },
+        });
+    }
 
     let maybe_ctx = runtime.capabilities().resolve_context(
         &auth_data,
@@ -397,11 +457,36 @@ pub fn process_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
         });
     }
 
+    // Check that the `max_fee` can cover the base fee and the priority fee before reserving any gas.
+    if let Err((max_fee, base_fee, priority_fee)) = check_max_fee_covers_fees(&tx, gas_price) {
+        // We penalize the sequencer for the fixed amount of gas that was used to execute the transaction.
+        let tx_scratchpad = runtime.capabilities().penalize_sequencer(
+            sequencer_da_address,
+            format!(
+                "max fee {max_fee} is below the base fee {base_fee} plus the priority fee {priority_fee}"
+            ),
+            pre_exec_working_set,
+        );
+
+        return Err(TxProcessingError {
+            tx_scratchpad,
+            reason: TxProcessingErrorReason::MaxFeeBelowBaseFee {
+                max_fee,
+                base_fee,
+                priority_fee,
+                raw_tx_hash: *raw_tx_hash,
+            },
+        });
+    }
+
+    // Transactions without a gas limit are executed with the block gas limit.
+    let tx = &with_inferred_gas_limit(tx, gas_price, block_gas_limit);
+
     let working_set = match runtime
         .capabilities()
         .try_reserve_gas(tx, &ctx, pre_exec_working_set)
     {
-        Ok(working_set) => working_set,
+        Ok(working_set) => working_set.with_state_access_budget(*state_access_budget),
         Err(TryReserveGasError {
             reason,
             pre_exec_working_set,
@@ -437,6 +522,74 @@ pub fn process_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
     ))
 }
 
+/// Checks that the transaction can still be included at the given slot height.
+/// On failure, returns the last slot in which the transaction was valid.
+fn check_not_expired<S: Spec>(
+    tx: &AuthenticatedTransactionData<S>,
+    height: u64,
+) -> Result<(), u64> {
+    match tx.valid_until_slot {
+        Some(valid_until_slot) if valid_until_slot < height => Err(valid_until_slot),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the transaction's `max_fee` covers the base fee of its gas limit at the given gas price,
+/// plus the maximum priority fee applied to that base fee.
+/// Transactions without a gas limit only commit to their `max_fee`, so they always pass.
+/// On failure, returns the `(max_fee, base_fee, priority_fee)` triple.
+fn check_max_fee_covers_fees<S: Spec>(
+    tx: &AuthenticatedTransactionData<S>,
+    gas_price: &<S::Gas as Gas>::Price,
+) -> Result<(), (u64, u64, u64)> {
+    let Some(gas_limit) = &tx.gas_limit else {
+        return Ok(());
+    };
+
+    let base_fee = gas_limit.value(gas_price);
+    let priority_fee = tx.max_priority_fee_bips.apply(base_fee).unwrap_or(u64::MAX);
+    if tx.max_fee < base_fee.saturating_add(priority_fee) {
+        return Err((tx.max_fee, base_fee, priority_fee));
+    }
+
+    Ok(())
+}
+
+/// Executes transactions that don't specify a gas limit with the gas limit of the block.
+/// Since the transaction only committed to its `max_fee`, the inferred limit is scaled down
+/// so that `gas_limit *_scalar gas_price` doesn't exceed the `max_fee`.
+fn with_inferred_gas_limit<S: Spec>(
+    mut tx: AuthenticatedTransactionData<S>,
+    gas_price: &<S::Gas as Gas>::Price,
+    block_gas_limit: &S::Gas,
+) -> AuthenticatedTransactionData<S> {
+    if tx.gas_limit.is_none() {
+        let block_limit_value = block_gas_limit.value(gas_price);
+        let gas_limit = if block_limit_value > tx.max_fee {
+            // Each dimension is scaled by the same factor, rounding down.
+            let dimensions: Vec<u64> = block_gas_limit
+                .as_slice()
+                .iter()
+                .map(|dimension| {
+                    (u128::from(*dimension) * u128::from(tx.max_fee)
+                        / u128::from(block_limit_value)) as u64
+                })
+                .collect();
+            S::Gas::from_slice(&dimensions)
+        } else {
+            block_gas_limit.clone()
+        };
+        debug!(
+            ?block_gas_limit,
+            ?gas_limit,
+            "The transaction has no gas limit, inferring it from the block gas limit"
+        );
+        tx.gas_limit = Some(gas_limit);
+    }
+
+    tx
+}
+
 #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
 fn authenticate_with_cycle_count<S: Spec, Da: DaSpec, R: Runtime<S, Da>>(
     runtime: &R,
@@ -453,12 +606,14 @@ fn authenticate_with_cycle_count<S: Spec, Da: DaSpec, R: Runtime<S, Da>>(
     runtime.authenticate(raw_tx, pre_exec_working_set)
 }
 
-#[allow(clippy::result_large_err)]
+#[allow(clippy::result_large_err, clippy::too_many_arguments)]
 pub fn process_unauthorized_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
     runtime: &R,
     raw_tx: &RawTx,
     sequencer_da_address: &D::Address,
     gas_price: &<S::Gas as Gas>::Price,
+    block_gas_limit: &S::Gas,
+    state_access_budget: &StateAccessBudget,
     height: u64,
     tx_scratchpad: TxScratchpad<S>,
 ) -> Result<ApplyTxResult<S>, TxProcessingError<S>> {
@@ -480,7 +635,18 @@ pub fn process_unauthorized_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
     };
 
     let raw_tx_hash = &tx.raw_tx_hash;
-    let tx = &tx.authenticated_tx;
+    let tx = tx.authenticated_tx;
+
+    if let Err(valid_until_slot) = check_not_expired(&tx, height) {
+        return Err(TxProcessingError {
+            tx_scratchpad: pre_exec_working_set.into(),
+            reason: TxProcessingErrorReason::Expired {
+                valid_until_slot,
+                current_slot: height,
+                raw_tx_hash: *raw_tx_hash,
+            },
+        });
+    }
 
     let ctx = match runtime.capabilities().resolve_unregistered_context(
         &auth_data,
@@ -514,6 +680,18 @@ pub fn process_unauthorized_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
         });
     }
 
+    if let Err((max_fee, base_fee, priority_fee)) = check_max_fee_covers_fees(&tx, gas_price) {
+        return Err(TxProcessingError {
+            tx_scratchpad: pre_exec_working_set.into(),
+            reason: TxProcessingErrorReason::MaxFeeBelowBaseFee {
+                max_fee,
+                base_fee,
+                priority_fee,
+                raw_tx_hash: *raw_tx_hash,
+            },
+        });
+    }
+
     if let Err(e) = pre_exec_working_set.charge_gas(&forced_sequencer_registration_cost::<S>()) {
         return Err(TxProcessingError {
             tx_scratchpad: pre_exec_working_set.into(),
@@ -524,11 +702,14 @@ pub fn process_unauthorized_tx<S: Spec, D: DaSpec, R: Runtime<S, D>>(
         });
     }
 
+    // Transactions without a gas limit are executed with the block gas limit.
+    let tx = &with_inferred_gas_limit(tx, gas_price, block_gas_limit);
+
     let working_set = match runtime
         .capabilities()
         .try_reserve_gas(tx, &ctx, pre_exec_working_set)
     {
-        Ok(working_set) => working_set,
+        Ok(working_set) => working_set.with_state_access_budget(*state_access_budget),
         Err(TryReserveGasError {
             reason,
             pre_exec_working_set,
@@ -590,6 +771,11 @@ where
     Da: DaSpec,
     RT: Runtime<S, Da>,
 {
+    let gas_limit = tx
+        .gas_limit
+        .as_ref()
+        .map(GasArray::to_vec)
+        .unwrap_or_default();
     let tx_result = attempt_tx(tx, message, &ctx, runtime, &mut working_set);
     let (mut tx_scratchpad, receipt, transaction_consumption) = match tx_result {
         Ok(_) => {
@@ -603,6 +789,7 @@ where
                     events: convert_to_runtime_events::<S, RT, Da>(events),
                     receipt: TxEffect::Successful(()),
                     gas_used: transaction_consumption.base_fee().to_vec(),
+                    gas_limit,
                 },
                 transaction_consumption,
             )
@@ -625,6 +812,7 @@ where
                 events: vec![], // As in Ethereum, reverted transactions don't emit events
                 receipt: TxEffect::Reverted(e),
                 gas_used: transaction_consumption.base_fee().to_vec(),
+                gas_limit,
             };
 
             (tx_scratchpad, receipt, transaction_consumption)
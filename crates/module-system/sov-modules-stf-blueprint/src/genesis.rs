@@ -0,0 +1,74 @@
+use crate::GenesisParams;
+
+type GenesisValidator<RuntimeConfig, KernelConfig> =
+    Box<dyn Fn(&RuntimeConfig, &KernelConfig) -> anyhow::Result<()> + Send + Sync>;
+
+/// Assembles [`GenesisParams`] in code, without reading any genesis file.
+///
+/// The runtime configuration is usually composed from the configs of its modules with the
+/// `GenesisConfig::new` constructor generated for the runtime. Both the runtime and the kernel
+/// configurations must be provided before calling [`GenesisBuilder::build`], which also runs
+/// every validation registered with [`GenesisBuilder::validate_with`].
+pub struct GenesisBuilder<RuntimeConfig, KernelConfig> {
+    runtime: Option<RuntimeConfig>,
+    kernel: Option<KernelConfig>,
+    validators: Vec<GenesisValidator<RuntimeConfig, KernelConfig>>,
+}
+
+impl<RuntimeConfig, KernelConfig> Default for GenesisBuilder<RuntimeConfig, KernelConfig> {
+    fn default() -> Self {
+        Self {
+            runtime: None,
+            kernel: None,
+            validators: Vec::new(),
+        }
+    }
+}
+
+impl<RuntimeConfig, KernelConfig> GenesisBuilder<RuntimeConfig, KernelConfig> {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the runtime genesis configuration.
+    pub fn runtime(mut self, config: RuntimeConfig) -> Self {
+        self.runtime = Some(config);
+        self
+    }
+
+    /// Sets the kernel genesis configuration.
+    pub fn kernel(mut self, config: KernelConfig) -> Self {
+        self.kernel = Some(config);
+        self
+    }
+
+    /// Registers a check which is run against the complete configuration by [`GenesisBuilder::build`].
+    pub fn validate_with(
+        mut self,
+        validator: impl Fn(&RuntimeConfig, &KernelConfig) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Builds the [`GenesisParams`].
+    ///
+    /// # Errors
+    /// Fails if the runtime or the kernel configuration is missing, or if any of the
+    /// registered validations fails.
+    pub fn build(self) -> anyhow::Result<GenesisParams<RuntimeConfig, KernelConfig>> {
+        let runtime = self
+            .runtime
+            .ok_or_else(|| anyhow::anyhow!("The runtime genesis configuration is missing"))?;
+        let kernel = self
+            .kernel
+            .ok_or_else(|| anyhow::anyhow!("The kernel genesis configuration is missing"))?;
+
+        for validator in &self.validators {
+            validator(&runtime, &kernel).map_err(|e| e.context("Invalid genesis configuration"))?;
+        }
+
+        Ok(GenesisParams { runtime, kernel })
+    }
+}
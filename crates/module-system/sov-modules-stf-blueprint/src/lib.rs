@@ -4,9 +4,12 @@ mod stf_blueprint;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::TxScratchpad;
 mod batch_processing;
+mod genesis;
 #[cfg(feature = "test-utils")]
 mod utils;
+use batch_processing::ApplyBatchError;
 pub use batch_processing::{process_tx, BatchReceipt, TransactionReceipt};
+pub use genesis::GenesisBuilder;
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use risc0_cycle_macros::cycle_tracker;
 use sov_modules_api::capabilities::{AuthenticationError, HasCapabilities, RuntimeAuthenticator};
@@ -15,15 +18,18 @@ use sov_modules_api::runtime::capabilities::{Kernel, KernelSlotHooks};
 use sov_modules_api::transaction::SequencerReward;
 pub use sov_modules_api::{BatchWithId, BlobData};
 use sov_modules_api::{
-    BlobDataWithId, DaSpec, DispatchCall, Error, Gas, GasArray, Genesis, KernelWorkingSet,
+    BlobDataWithId, DaSpec, DispatchCall, Error, Gas, Genesis, KernelWorkingSet,
     RuntimeEventProcessor, Spec, StateCheckpoint, VersionedStateReadWriter, WorkingSet,
 };
 use sov_rollup_interface::common::HexHash;
 use sov_rollup_interface::da::RelevantBlobIters;
-use sov_rollup_interface::stf::{ApplySlotOutput, StateTransitionFunction};
+use sov_rollup_interface::stf::{
+    ApplySlotOutput, BlobOutcome, BlobResult, StateTransitionFunction,
+};
 use sov_sequencer_registry::BatchSequencerOutcome;
 use sov_state::storage::StateUpdate;
 use sov_state::Storage;
+use stf_blueprint::PendingBatch;
 pub use stf_blueprint::StfBlueprint;
 use thiserror::Error;
 use tracing::info;
@@ -77,6 +83,25 @@ pub enum SkippedReason {
     /// Impossible to resolve the context of the transaction.
     #[error("Impossible to resolve the context of the transaction, reason: {0}.")]
     CannotResolveContext(String),
+    /// The transaction's `max_fee` cannot cover the base fee of its gas limit at the current gas price
+    /// plus its maximum priority fee.
+    #[error("The transaction's max fee ({max_fee}) is below the base fee ({base_fee}) plus the priority fee ({priority_fee}) at the current gas price.")]
+    MaxFeeBelowBaseFee {
+        /// The maximum fee the transaction is willing to pay.
+        max_fee: u64,
+        /// The base fee of the transaction, computed as `gas_limit * gas_price`.
+        base_fee: u64,
+        /// The maximum priority fee of the transaction, applied to the base fee.
+        priority_fee: u64,
+    },
+    /// The transaction's validity window has passed.
+    #[error("The transaction expired at slot {valid_until_slot}, but the current slot is {current_slot}.")]
+    Expired {
+        /// The last slot in which the transaction could be included.
+        valid_until_slot: u64,
+        /// The slot in which the transaction was processed.
+        current_slot: u64,
+    },
 }
 
 /// The effect of a transaction using the STF blueprint.
@@ -122,6 +147,30 @@ pub enum TxProcessingErrorReason {
         /// The raw hash of the transaction that was skipped.
         raw_tx_hash: [u8; 32],
     },
+    /// The transaction was not applied because its `max_fee` does not cover the base fee plus the priority fee.
+    /// In this case, the sequencer should be charged the amount of gas used for the pre-execution checks.
+    #[error("The transaction was not applied because its max fee ({max_fee}) is below the base fee ({base_fee}) plus the priority fee ({priority_fee}), tx hash: {}.", HexHash::new(*raw_tx_hash))]
+    MaxFeeBelowBaseFee {
+        /// The maximum fee the transaction is willing to pay.
+        max_fee: u64,
+        /// The base fee of the transaction, computed as `gas_limit * gas_price`.
+        base_fee: u64,
+        /// The maximum priority fee of the transaction, applied to the base fee.
+        priority_fee: u64,
+        /// The raw hash of the transaction that was skipped.
+        raw_tx_hash: [u8; 32],
+    },
+    /// The transaction was not applied because its validity window has passed.
+    /// In this case, the sequencer should be charged the amount of gas used for the pre-execution checks.
+    #[error("The transaction was not applied because it expired at slot {valid_until_slot} (current slot: {current_slot}), tx hash: {}.", HexHash::new(*raw_tx_hash))]
+    Expired {
+        /// The last slot in which the transaction could be included.
+        valid_until_slot: u64,
+        /// The slot in which the transaction was processed.
+        current_slot: u64,
+        /// The raw hash of the transaction that was skipped.
+        raw_tx_hash: [u8; 32],
+    },
     /// The transaction was not applied because it was a duplicate.
     #[error("The transaction was not applied because it had an invalid nonce, reason: {reason}, tx hash: {}.", HexHash::new(*raw_tx_hash))]
     Nonce {
@@ -161,6 +210,30 @@ impl TryInto<(SkippedReason, [u8; 32])> for TxProcessingErrorReason {
                 reason,
                 raw_tx_hash,
             } => Ok((SkippedReason::CannotReserveGas(reason), raw_tx_hash)),
+            TxProcessingErrorReason::MaxFeeBelowBaseFee {
+                max_fee,
+                base_fee,
+                priority_fee,
+                raw_tx_hash,
+            } => Ok((
+                SkippedReason::MaxFeeBelowBaseFee {
+                    max_fee,
+                    base_fee,
+                    priority_fee,
+                },
+                raw_tx_hash,
+            )),
+            TxProcessingErrorReason::Expired {
+                valid_until_slot,
+                current_slot,
+                raw_tx_hash,
+            } => Ok((
+                SkippedReason::Expired {
+                    valid_until_slot,
+                    current_slot,
+                },
+                raw_tx_hash,
+            )),
             err => Err(anyhow::anyhow!(
                 "The transaction processing error - {err} - cannot be mapped to a SkippedReason"
             )),
@@ -176,6 +249,16 @@ pub struct TxProcessingError<S: Spec> {
     pub reason: TxProcessingErrorReason,
 }
 
+/// The outcome of [`StfBlueprint::simulate_batch`].
+#[cfg(feature = "native")]
+#[derive(Debug, Clone)]
+pub struct BatchSimulationResult {
+    /// The receipt the batch would get, which contains the outcome of each of its transactions.
+    pub receipt: BatchReceipt,
+    /// The gas used by the whole batch.
+    pub gas_used: Vec<u64>,
+}
+
 /// Genesis parameters for a blueprint
 pub struct GenesisParams<RuntimeConfig, KernelConfig> {
     /// The runtime genesis parameters
@@ -223,10 +306,13 @@ where
     }
 
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
+    #[cfg_attr(not(feature = "native"), allow(unused_variables))]
     fn end_slot(
         &self,
         storage: S::Storage,
         gas_used: &S::Gas,
+        gas_limit: &S::Gas,
+        gas_price: &<S::Gas as Gas>::Price,
         mut checkpoint: StateCheckpoint<S>,
     ) -> (
         <S::Storage as Storage>::Root,
@@ -237,6 +323,15 @@ where
         self.runtime.end_slot_hook(&mut checkpoint);
         self.kernel.end_slot_hook(gas_used, &mut checkpoint);
 
+        #[cfg(feature = "native")]
+        sov_metrics::update_metrics(|metrics| {
+            metrics.block_gas_used.set(gas_array_total(gas_used));
+            metrics.block_gas_limit.set(gas_array_total(gas_limit));
+            metrics
+                .block_base_fee_per_gas
+                .set(gas_array_total(gas_price));
+        });
+
         let (cache_log, mut accessory_delta, witness) = checkpoint.freeze();
 
         let (root_hash, mut state_update) = storage
@@ -253,6 +348,55 @@ where
 
         (root_hash, witness, change_set)
     }
+
+    /// Executes `batch` as if it were posted by `sender` and selected first in the slot
+    /// `slot_header`, on top of `pre_state`, without committing anything.
+    ///
+    /// This lets a sequencer check which transactions would succeed, revert or be skipped,
+    /// and how much gas the batch would use, before posting it to the DA layer. The batch is
+    /// executed exactly like in [`StateTransitionFunction::apply_slot`], assuming that `sender`
+    /// is a registered sequencer, so the results only differ if the state changes in between,
+    /// e.g. because other blobs are processed first.
+    #[cfg(feature = "native")]
+    pub fn simulate_batch(
+        &self,
+        pre_state: S::Storage,
+        pre_state_root: &<S::Storage as Storage>::Root,
+        slot_header: &Da::BlockHeader,
+        validity_condition: &Da::ValidityCondition,
+        batch: BatchWithId,
+        sender: &Da::Address,
+    ) -> BatchSimulationResult {
+        // The checkpoint is dropped at the end of the simulation.
+        let mut checkpoint = StateCheckpoint::new(pre_state);
+        let gas_price = self.begin_slot(
+            &mut checkpoint,
+            slot_header,
+            validity_condition,
+            pre_state_root,
+        );
+        let block_gas_limit = self.kernel.block_gas_limit(&mut checkpoint);
+        let state_access_budget = self.kernel.state_access_budget(&mut checkpoint);
+        let visible_height =
+            KernelWorkingSet::from_kernel(&self.kernel, &mut checkpoint).virtual_slot();
+
+        let (_, receipt, gas_used) = self.process_batch(
+            batch,
+            checkpoint,
+            0,
+            sender,
+            &gas_price,
+            &block_gas_limit,
+            &state_access_budget,
+            visible_height,
+            true,
+        );
+
+        BatchSimulationResult {
+            receipt,
+            gas_used: gas_used.to_vec(),
+        }
+    }
 }
 
 impl<S, RT, Da, K> StateTransitionFunction<S::InnerZkvm, S::OuterZkvm, Da>
@@ -343,6 +487,8 @@ where
             validity_condition,
             pre_state_root,
         );
+        let block_gas_limit = self.kernel.block_gas_limit(&mut checkpoint);
+        let state_access_budget = self.kernel.state_access_budget(&mut checkpoint);
 
         let mut kernel_working_set = KernelWorkingSet::from_kernel(&self.kernel, &mut checkpoint);
         let visible_height = kernel_working_set.virtual_slot();
@@ -352,10 +498,11 @@ where
             .into_iter()
             .chain(relevant_blobs.proof_blobs);
 
-        let selected_blobs = self
+        let selection = self
             .kernel
-            .get_blobs_for_this_slot(all_blobs, &mut kernel_working_set)
+            .get_blobs_for_this_slot_with_outcomes(all_blobs, &mut kernel_working_set)
             .expect("blob selection must succeed, probably serialization failed");
+        let selected_blobs = selection.selected;
 
         info!(
             blob_count = selected_blobs.len(),
@@ -366,47 +513,117 @@ where
 
         let mut proof_receipts = Vec::new();
         let mut batch_receipts = vec![];
+        let mut blob_results = Vec::new();
 
         let mut total_gas = S::Gas::zero();
-        for (blob_idx, (blob, sender)) in selected_blobs.into_iter().enumerate() {
+        // Consecutive batches are processed together, so that they can be
+        // executed in parallel if enabled. Proofs act as barriers.
+        let mut pending_batches = Vec::new();
+        let mut selected_blobs = selected_blobs.into_iter().enumerate().peekable();
+        while let Some((blob_idx, (blob, sender))) = selected_blobs.next() {
             match blob.data {
-                BlobData::Batch(batch) => {
-                    let batch_with_id = BatchWithId { batch, id: blob.id };
-
-                    let (next_checkpoint, batch_receipt, gas_used) = self.process_batch(
-                        batch_with_id,
-                        checkpoint,
+                // The sequence number only matters for the blob selection, which has
+                // already put sequenced batches in order.
+                Some(BlobData::Batch(batch) | BlobData::SequencedBatch { batch, .. }) => {
+                    pending_batches.push(PendingBatch {
+                        batch: BatchWithId { batch, id: blob.id },
                         blob_idx,
-                        &sender,
-                        &gas_price,
-                        visible_height,
-                        blob.from_registered_sequencer,
-                    );
-
-                    checkpoint = next_checkpoint;
-                    batch_receipts.push(batch_receipt);
-                    total_gas.combine(&gas_used);
+                        sender,
+                        from_registered_sequencer: blob.from_registered_sequencer,
+                    });
                 }
-                BlobData::Proof(proof) => {
+                Some(BlobData::Proof(proof)) => {
                     let (receipt, next_checkpoint) = self.process_proof(proof, checkpoint);
 
                     checkpoint = next_checkpoint;
+                    blob_results.push(BlobResult {
+                        blob_hash: blob.id,
+                        outcome: BlobOutcome::ProofReceipt {
+                            index: proof_receipts.len(),
+                        },
+                    });
                     proof_receipts.push(receipt);
                 }
+                // Blobs from unregistered senders which failed to decode.
+                None => {
+                    blob_results.push(BlobResult {
+                        blob_hash: blob.id,
+                        outcome: BlobOutcome::BatchReceipt {
+                            index: batch_receipts.len(),
+                        },
+                    });
+                    batch_receipts.push(
+                        ApplyBatchError::Ignored {
+                            hash: blob.id,
+                            reason: "malformed".to_string(),
+                        }
+                        .into(),
+                    );
+                }
+            }
+
+            let next_is_batch = matches!(
+                selected_blobs.peek(),
+                Some((_, (next_blob, _))) if matches!(
+                    next_blob.data,
+                    Some(BlobData::Batch(_) | BlobData::SequencedBatch { .. })
+                )
+            );
+            if !next_is_batch && !pending_batches.is_empty() {
+                let (next_checkpoint, results) = self.process_batches(
+                    std::mem::take(&mut pending_batches),
+                    checkpoint,
+                    &gas_price,
+                    &block_gas_limit,
+                    &state_access_budget,
+                    visible_height,
+                );
+
+                checkpoint = next_checkpoint;
+                for (batch_receipt, gas_used) in results {
+                    blob_results.push(BlobResult {
+                        blob_hash: batch_receipt.batch_hash,
+                        outcome: BlobOutcome::BatchReceipt {
+                            index: batch_receipts.len(),
+                        },
+                    });
+                    batch_receipts.push(batch_receipt);
+                    total_gas.combine(&gas_used);
+                }
             }
         }
 
-        let (state_root, witness, change_set) = self.end_slot(pre_state, &total_gas, checkpoint);
+        blob_results.extend(selection.unselected);
+
+        let (state_root, witness, change_set) = self.end_slot(
+            pre_state,
+            &total_gas,
+            &block_gas_limit,
+            &gas_price,
+            checkpoint,
+        );
         ApplySlotOutput {
             state_root,
             change_set,
             proof_receipts,
             batch_receipts,
+            blob_results,
             witness,
         }
     }
 }
 
+/// Sums all the dimensions of a [`sov_modules_api::GasArray`], saturating at [`i64::MAX`], so
+/// that it can be exported as a Prometheus gauge.
+#[cfg(feature = "native")]
+fn gas_array_total<G: sov_modules_api::GasArray>(gas: &G) -> i64 {
+    let total = gas
+        .as_slice()
+        .iter()
+        .fold(0u64, |acc, dimension| acc.saturating_add(*dimension));
+    i64::try_from(total).unwrap_or(i64::MAX)
+}
+
 /// The return type of [`Runtime::endpoints`].
 #[cfg(feature = "native")]
 pub struct RuntimeEndpoints {
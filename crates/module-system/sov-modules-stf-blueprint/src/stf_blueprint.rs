@@ -1,10 +1,14 @@
 use std::marker::PhantomData;
+#[cfg(feature = "native")]
+use std::num::NonZeroUsize;
 
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use risc0_cycle_macros::cycle_tracker;
 use sov_modules_api::capabilities::ProofProcessor;
 use sov_modules_api::runtime::capabilities::KernelSlotHooks;
-use sov_modules_api::{BatchWithId, DaSpec, Gas, ProofReceipt, Spec, StateCheckpoint, Storage};
+use sov_modules_api::{
+    BatchWithId, DaSpec, Gas, ProofReceipt, Spec, StateAccessBudget, StateCheckpoint, Storage,
+};
 use sov_rollup_interface::stf::StoredEvent;
 use tracing::{debug, info};
 
@@ -17,10 +21,23 @@ pub struct StfBlueprint<S: Spec, Da: DaSpec, RT: Runtime<S, Da>, K: KernelSlotHo
     /// The runtime includes all the modules that the rollup supports.
     pub(crate) runtime: RT,
     pub(crate) kernel: K,
+    /// The maximum number of batches executed concurrently. See
+    /// [`StfBlueprint::with_batch_parallelism`].
+    #[cfg(feature = "native")]
+    batch_parallelism: NonZeroUsize,
     phantom_context: PhantomData<S>,
     phantom_da: PhantomData<Da>,
 }
 
+/// A batch selected for execution in the current slot, waiting to be
+/// processed.
+pub(crate) struct PendingBatch<Da: DaSpec> {
+    pub(crate) batch: BatchWithId,
+    pub(crate) blob_idx: usize,
+    pub(crate) sender: Da::Address,
+    pub(crate) from_registered_sequencer: bool,
+}
+
 impl<S, Da, RT, K> Default for StfBlueprint<S, Da, RT, K>
 where
     S: Spec,
@@ -32,6 +49,8 @@ where
         Self {
             runtime: RT::default(),
             kernel: K::default(),
+            #[cfg(feature = "native")]
+            batch_parallelism: NonZeroUsize::MIN,
             phantom_context: PhantomData,
             phantom_da: PhantomData,
         }
@@ -59,6 +78,169 @@ where
         }
     }
 
+    /// Enables the parallel execution of up to `batch_parallelism` batches
+    /// at a time. A value of `1` (the default) means that batches are
+    /// executed sequentially.
+    ///
+    /// Batches are executed in chunks of `batch_parallelism`, on forks of the
+    /// same state, and their changes are then merged in the original order. As
+    /// soon as a batch reads or writes any key written by an earlier batch of
+    /// its chunk, it is executed again sequentially, together with the rest of
+    /// the chunk. The next chunk is executed in parallel again. The results are
+    /// therefore always the same as with sequential execution.
+    ///
+    /// Only available in native mode, as zkVMs are single threaded anyway.
+    #[cfg(feature = "native")]
+    pub fn with_batch_parallelism(mut self, batch_parallelism: NonZeroUsize) -> Self {
+        self.batch_parallelism = batch_parallelism;
+        self
+    }
+
+    /// Processes `batches` in order, either sequentially or in parallel
+    /// depending on the configured batch parallelism.
+    pub(crate) fn process_batches(
+        &self,
+        batches: Vec<PendingBatch<Da>>,
+        mut checkpoint: StateCheckpoint<S>,
+        gas_price: &<S::Gas as Gas>::Price,
+        block_gas_limit: &S::Gas,
+        state_access_budget: &StateAccessBudget,
+        visible_height: u64,
+    ) -> (StateCheckpoint<S>, Vec<(BatchReceipt, S::Gas)>) {
+        #[cfg(feature = "native")]
+        if self.batch_parallelism.get() > 1 && batches.len() > 1 {
+            return self.process_batches_in_parallel(
+                batches,
+                checkpoint,
+                gas_price,
+                block_gas_limit,
+                state_access_budget,
+                visible_height,
+            );
+        }
+
+        let mut results = Vec::with_capacity(batches.len());
+        for pending in batches {
+            let (next_checkpoint, batch_receipt, gas_used) = self.process_batch(
+                pending.batch,
+                checkpoint,
+                pending.blob_idx,
+                &pending.sender,
+                gas_price,
+                block_gas_limit,
+                state_access_budget,
+                visible_height,
+                pending.from_registered_sequencer,
+            );
+
+            checkpoint = next_checkpoint;
+            results.push((batch_receipt, gas_used));
+        }
+
+        (checkpoint, results)
+    }
+
+    #[cfg(feature = "native")]
+    fn process_batches_in_parallel(
+        &self,
+        batches: Vec<PendingBatch<Da>>,
+        mut checkpoint: StateCheckpoint<S>,
+        gas_price: &<S::Gas as Gas>::Price,
+        block_gas_limit: &S::Gas,
+        state_access_budget: &StateAccessBudget,
+        visible_height: u64,
+    ) -> (StateCheckpoint<S>, Vec<(BatchReceipt, S::Gas)>) {
+        let mut results = Vec::with_capacity(batches.len());
+        let mut batches = batches.into_iter();
+
+        loop {
+            let chunk: Vec<_> = batches
+                .by_ref()
+                .take(self.batch_parallelism.get())
+                .collect();
+            if chunk.is_empty() {
+                break;
+            }
+
+            let executed: Vec<_> = std::thread::scope(|scope| {
+                let handles: Vec<_> = chunk
+                    .iter()
+                    .map(|pending| {
+                        let fork = checkpoint.fork();
+                        scope.spawn(move || {
+                            let batch = BatchWithId {
+                                batch: pending.batch.batch.clone(),
+                                id: pending.batch.id,
+                            };
+                            self.process_batch(
+                                batch,
+                                fork,
+                                pending.blob_idx,
+                                &pending.sender,
+                                gas_price,
+                                block_gas_limit,
+                                state_access_budget,
+                                visible_height,
+                                pending.from_registered_sequencer,
+                            )
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap_or_else(std::panic::resume_unwind))
+                    .collect()
+            });
+
+            // Only the batches that precede the first conflict can be merged.
+            let first_conflict = (1..executed.len()).find(|&i| {
+                executed[..i]
+                    .iter()
+                    .any(|(fork, _, _)| fork.fork_conflicts_with(&executed[i].0))
+            });
+            let num_merged = first_conflict.unwrap_or(executed.len());
+
+            for (fork, batch_receipt, gas_used) in executed.into_iter().take(num_merged) {
+                checkpoint.merge_fork(fork);
+                results.push((batch_receipt, gas_used));
+            }
+
+            let conflicting = chunk.into_iter().skip(num_merged).collect::<Vec<_>>();
+            #[cfg(feature = "native")]
+            sov_metrics::update_metrics(|metrics| {
+                metrics.parallel_batches_total.inc_by(num_merged as u64);
+                metrics
+                    .parallel_batch_conflicts_total
+                    .inc_by(conflicting.len() as u64);
+            });
+            if !conflicting.is_empty() {
+                debug!(
+                    num_batches = conflicting.len(),
+                    "Conflicting state accesses between batches, falling back to sequential execution"
+                );
+                for pending in conflicting {
+                    let (next_checkpoint, batch_receipt, gas_used) = self.process_batch(
+                        pending.batch,
+                        checkpoint,
+                        pending.blob_idx,
+                        &pending.sender,
+                        gas_price,
+                        block_gas_limit,
+                        state_access_budget,
+                        visible_height,
+                        pending.from_registered_sequencer,
+                    );
+
+                    checkpoint = next_checkpoint;
+                    results.push((batch_receipt, gas_used));
+                }
+            }
+        }
+
+        (checkpoint, results)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn process_batch(
         &self,
@@ -67,6 +249,8 @@ where
         blob_idx: usize,
         sender: &Da::Address,
         gas_price: &<S::Gas as Gas>::Price,
+        block_gas_limit: &S::Gas,
+        state_access_budget: &StateAccessBudget,
         visible_height: u64,
         is_registered_sequencer: bool,
     ) -> (StateCheckpoint<S>, BatchReceipt, S::Gas) {
@@ -76,6 +260,8 @@ where
             batch,
             sender,
             gas_price,
+            block_gas_limit,
+            state_access_budget,
             visible_height,
             is_registered_sequencer,
         );
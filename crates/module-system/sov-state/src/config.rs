@@ -1,6 +1,6 @@
 //! Configuration options for [`Storage`](crate::storage::Storage) types.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Configuration options for [`ProverStorage`](crate::ProverStorage)
 /// initialization.
@@ -8,4 +8,35 @@ use std::path::PathBuf;
 pub struct Config {
     /// Path to folder where storage files will be stored.
     pub path: PathBuf,
+    /// The maximum number of competing unfinalized forks kept in memory by the storage
+    /// manager. When a new fork exceeds this limit, the forks whose tips are the oldest
+    /// are discarded, except the one of the new block. Unbounded if not set.
+    #[serde(default)]
+    pub max_unfinalized_forks: Option<usize>,
+    /// Path to the folder of the state database. Defaults to [`Config::path`].
+    #[serde(default)]
+    pub state_path: Option<PathBuf>,
+    /// Path to the folder of the accessory database. Defaults to [`Config::path`].
+    #[serde(default)]
+    pub accessory_path: Option<PathBuf>,
+    /// Path to the folder of the ledger database. Defaults to [`Config::path`].
+    #[serde(default)]
+    pub ledger_path: Option<PathBuf>,
+}
+
+impl Config {
+    /// The folder where the state database is stored.
+    pub fn state_db_path(&self) -> &Path {
+        self.state_path.as_deref().unwrap_or(&self.path)
+    }
+
+    /// The folder where the accessory database is stored.
+    pub fn accessory_db_path(&self) -> &Path {
+        self.accessory_path.as_deref().unwrap_or(&self.path)
+    }
+
+    /// The folder where the ledger database is stored.
+    pub fn ledger_db_path(&self) -> &Path {
+        self.ledger_path.as_deref().unwrap_or(&self.path)
+    }
 }
@@ -20,7 +20,7 @@ pub mod jmt {
     pub use jmt::{KeyHash, RootHash, Version};
 }
 #[cfg(feature = "native")]
-pub use prover_storage::{ProverChangeSet, ProverStorage};
+pub use prover_storage::{ProverChangeSet, ProverStorage, StateWrites};
 use sha2::digest::typenum::U32;
 use sov_rollup_interface::digest::Digest;
 pub use storage_internals::{SparseMerkleProof, StorageRoot, VisibleHash};
@@ -31,6 +31,8 @@ pub use crate::cache::*;
 pub use crate::codec::*;
 pub use crate::namespaces::*;
 pub use crate::storage::*;
+#[cfg(feature = "compact-witness")]
+pub use crate::witness::CompactWitness;
 pub use crate::witness::{ArrayWitness, Witness};
 
 /// A trait specifying the hash function and format of the witness used in
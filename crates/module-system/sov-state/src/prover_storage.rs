@@ -1,4 +1,6 @@
+use std::any::Any;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use jmt::storage::NodeBatch;
 use jmt::{JellyfishMerkleTree, KeyHash, Version};
@@ -17,7 +19,7 @@ use crate::namespaces::{
 };
 use crate::storage::{NativeStorage, SlotKey, SlotValue, StateUpdate, Storage, StorageProof};
 use crate::storage_internals::{SparseMerkleProof, StorageRoot};
-use crate::{MerkleProofSpec, Witness};
+use crate::{MerkleProofSpec, Witness, ZkStorage};
 
 /// A [`Storage`] implementation to be used by the prover in a native execution
 /// environment (outside of the zkVM).
@@ -29,6 +31,8 @@ use crate::{MerkleProofSpec, Witness};
 pub struct ProverStorage<S: MerkleProofSpec> {
     db: StateDb,
     accessory_db: AccessoryDb,
+    #[derivative(Debug = "ignore")]
+    _snapshot_lease: Option<Arc<dyn Any + Send + Sync>>,
     _phantom_hasher: PhantomData<S::Hasher>,
 }
 
@@ -38,10 +42,20 @@ impl<S: MerkleProofSpec> ProverStorage<S> {
         Self {
             db,
             accessory_db,
+            _snapshot_lease: None,
             _phantom_hasher: Default::default(),
         }
     }
 
+    /// Keeps `lease` alive for as long as this storage or any of its clones is alive.
+    ///
+    /// Storage managers use it to know when the snapshots read by the db handles
+    /// aren't used anymore and can be released.
+    pub fn with_snapshot_lease(mut self, lease: Arc<dyn Any + Send + Sync>) -> Self {
+        self._snapshot_lease = Some(lease);
+        self
+    }
+
     /// Indicates if caller should initialize underlying database with some data.
     pub fn should_init_db(db: &StateDb) -> Option<ProverChangeSet> {
         let user_init = Self::should_init::<UserNamespace>(db);
@@ -52,6 +66,8 @@ impl<S: MerkleProofSpec> ProverStorage<S> {
                 Some(ProverChangeSet {
                     state_change_set: user_init,
                     accessory_change_set: Default::default(),
+                    versions: Some((0, 0)),
+                    writes: None,
                 })
             }
             (None, None) => None,
@@ -164,6 +180,7 @@ impl<S: MerkleProofSpec> ProverStorage<S> {
         }
 
         let mut key_preimages = Vec::with_capacity(state_accesses.ordered_writes.len());
+        let writes = state_accesses.ordered_writes.clone();
 
         // Compute the JMT update from the batch of write operations.
         let batch = state_accesses
@@ -187,6 +204,7 @@ impl<S: MerkleProofSpec> ProverStorage<S> {
         let new_state_update = ProverStateUpdate {
             node_batch: tree_update.node_batch,
             key_preimages,
+            writes,
         };
 
         Ok((new_root, new_state_update))
@@ -252,11 +270,72 @@ impl<S: MerkleProofSpec> ProverStorage<S> {
         }
     }
 
+    /// Replays a state update against a previously recorded witness, the same
+    /// way [`ZkStorage`] does inside the zkVM, and returns the resulting root.
+    ///
+    /// The witness must contain the hints added by
+    /// [`Storage::compute_state_update`] on this storage, positioned so that the
+    /// next hint read is the previous user root. Fails if any read or update proof
+    /// is invalid, or if the witness was recorded against a different root than
+    /// the latest one in this storage.
+    pub fn verify_with_witness(
+        &self,
+        state_accesses: StateAccesses,
+        witness: &S::Witness,
+    ) -> anyhow::Result<StorageRoot<S>> {
+        let (prev_root, new_root) =
+            ZkStorage::<S>::new().verify_state_update(state_accesses, witness)?;
+
+        let latest_version = self.db.get_next_version() - 1;
+        let expected_prev_root = self.get_root_hash(latest_version)?;
+        anyhow::ensure!(
+            prev_root == expected_prev_root,
+            "Witness was recorded against root {:?}, but the latest root is {:?}",
+            prev_root,
+            expected_prev_root
+        );
+
+        Ok(new_root)
+    }
+
     /// Utility method for checking if storage is empty.
     /// Does not guarantees 100% that it actually is.
     pub fn is_empty(&self) -> bool {
         self.db.get_next_version() <= 1
     }
+
+    /// Materializes the [`StateWrites`] of a change set again, which gives back the same
+    /// change set as long as this storage has the same state as the one it was materialized on.
+    pub fn rematerialize(&self, writes: &StateWrites) -> anyhow::Result<ProverChangeSet> {
+        let state_accesses = StateAccesses {
+            user: OrderedReadsAndWrites {
+                ordered_reads: Vec::new(),
+                ordered_writes: writes.user.clone(),
+            },
+            kernel: OrderedReadsAndWrites {
+                ordered_reads: Vec::new(),
+                ordered_writes: writes.kernel.clone(),
+            },
+        };
+        let (_, mut state_update) =
+            self.compute_state_update(state_accesses, &S::Witness::default())?;
+        for (key, value) in &writes.accessory {
+            state_update.add_accessory_item(key.clone(), value.clone());
+        }
+
+        Ok(self.materialize_changes(&state_update))
+    }
+}
+
+/// The writes a [`ProverChangeSet`] has been materialized from, see [`ProverStorage::rematerialize`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StateWrites {
+    /// The writes to the user namespace.
+    pub user: Vec<(SlotKey, Option<SlotValue>)>,
+    /// The writes to the kernel namespace.
+    pub kernel: Vec<(SlotKey, Option<SlotValue>)>,
+    /// The writes to the accessory namespace.
+    pub accessory: Vec<(SlotKey, Option<SlotValue>)>,
 }
 
 /// Changeset extracted from [`ProverStorage`]
@@ -266,12 +345,57 @@ pub struct ProverChangeSet {
     pub state_change_set: sov_db::schema::SchemaBatch,
     /// [`sov_db::schema::SchemaBatch`] associated with non-provable accessory updates.
     pub accessory_change_set: sov_db::schema::SchemaBatch,
+    /// The first and last state versions covered by this change set,
+    /// or `None` if it isn't tied to any version.
+    versions: Option<(Version, Version)>,
+    /// The writes this change set has been materialized from, or `None` if it doesn't come
+    /// from a single state update.
+    writes: Option<StateWrites>,
+}
+
+impl ProverChangeSet {
+    /// Returns the writes this change set has been materialized from, so it can be materialized
+    /// again with [`ProverStorage::rematerialize`]. Returns `None` for merged change sets.
+    pub fn writes(&self) -> Option<&StateWrites> {
+        self.writes.as_ref()
+    }
+
+    /// Combines two change sets produced by consecutive slots into one,
+    /// so they can be committed to the database in a single write.
+    ///
+    /// `other` must have been produced on top of `self`. Provable and accessory writes are keyed
+    /// by the version they were made at, so the writes of both slots are preserved,
+    /// and where both change sets touch the same entry the operation from `other` wins.
+    ///
+    /// Returns an error if the versions of `other` don't directly follow the versions of `self`.
+    pub fn merge(mut self, other: ProverChangeSet) -> anyhow::Result<Self> {
+        self.versions = match (self.versions, other.versions) {
+            (Some((first, last)), Some((other_first, other_last))) => {
+                anyhow::ensure!(
+                    other_first == last + 1,
+                    "Change set for versions {}..={} can't be merged on top of versions {}..={}: change sets must be consecutive and must not overlap",
+                    other_first,
+                    other_last,
+                    first,
+                    last
+                );
+                Some((first, other_last))
+            }
+            (versions, None) | (None, versions) => versions,
+        };
+        self.state_change_set.merge(other.state_change_set);
+        self.accessory_change_set.merge(other.accessory_change_set);
+        // The writes of consecutive versions can't be materialized in one go.
+        self.writes = None;
+        Ok(self)
+    }
 }
 
 #[derive(Default)]
 pub struct ProverStateUpdate {
     pub(crate) node_batch: NodeBatch,
     pub key_preimages: Vec<(KeyHash, SlotKey)>,
+    pub(crate) writes: Vec<(SlotKey, Option<SlotValue>)>,
 }
 
 pub struct NamespacedStateUpdate {
@@ -347,9 +471,16 @@ impl<S: MerkleProofSpec> Storage for ProverStorage<S> {
 
         let accessory_batch = self.materialize_accessory(&state_update.accessory);
 
+        let version = self.db.get_next_version();
         ProverChangeSet {
             state_change_set: user_ns_batch,
             accessory_change_set: accessory_batch,
+            versions: Some((version, version)),
+            writes: Some(StateWrites {
+                user: state_update.user.writes.clone(),
+                kernel: state_update.kernel.writes.clone(),
+                accessory: state_update.accessory.ordered_writes.clone(),
+            }),
         }
     }
 
@@ -407,4 +538,8 @@ impl<S: MerkleProofSpec> NativeStorage for ProverStorage<S> {
 
         Ok(StorageRoot::<S>::new(user_root, kernel_root))
     }
+
+    fn version(&self) -> Version {
+        self.db.get_next_version() - 1
+    }
 }
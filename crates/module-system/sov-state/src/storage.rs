@@ -317,4 +317,8 @@ pub trait NativeStorage: Storage {
 
     /// Get the *global* root hash of the tree at the requested version
     fn get_root_hash(&self, version: Version) -> Result<Self::Root, anyhow::Error>;
+
+    /// Returns the latest version committed to this storage, i.e. the version
+    /// used by [`NativeStorage::get_with_proof`] when none is requested.
+    fn version(&self) -> Version;
 }
@@ -28,6 +28,18 @@ pub trait Witness: Default + Serialize + DeserializeOwned {
 
     /// Adds all hints from `rhs` to `self`.
     fn merge(&self, rhs: &Self);
+
+    /// Encodes the witness into the bytes passed to the zkVM.
+    ///
+    /// The default implementation uses the [`bincode`] representation of the witness.
+    fn encode(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("Witness serialization should never fail")
+    }
+
+    /// Decodes a witness from bytes produced by [`Witness::encode`].
+    fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(bincode::deserialize(bytes)?)
+    }
 }
 
 /// A [`Vec`]-based implementation of [`Witness`] with no special logic.
@@ -75,3 +87,157 @@ impl Witness for ArrayWitness {
         lhs_hints_lock.extend(rhs_hints_lock.drain(rhs_next_idx..));
     }
 }
+
+/// An [`ArrayWitness`] which is encoded with LEB128 varints instead of fixed-size
+/// integers, which is significantly more compact for witnesses made of many small hints.
+///
+/// # Example
+///
+/// ```
+/// use sov_state::{CompactWitness, Witness};
+///
+/// let witness = CompactWitness::default();
+/// witness.add_hint(1u64);
+///
+/// let decoded = CompactWitness::decode(&witness.encode()).unwrap();
+/// assert_eq!(decoded.get_hint::<u64>(), 1u64);
+/// ```
+#[cfg(feature = "compact-witness")]
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CompactWitness(ArrayWitness);
+
+#[cfg(feature = "compact-witness")]
+impl Witness for CompactWitness {
+    fn add_hint<T: BorshSerialize>(&self, hint: T) {
+        self.0.add_hint(hint);
+    }
+
+    fn get_hint<T: BorshDeserialize>(&self) -> T {
+        self.0.get_hint()
+    }
+
+    fn merge(&self, rhs: &Self) {
+        self.0.merge(&rhs.0);
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let next_idx = self.0.next_idx.load(std::sync::atomic::Ordering::SeqCst);
+        let hints = self.0.hints.lock().unwrap();
+
+        let mut bytes = Vec::new();
+        varint::write(next_idx as u64, &mut bytes);
+        varint::write(hints.len() as u64, &mut bytes);
+        for hint in hints.iter() {
+            varint::write(hint.len() as u64, &mut bytes);
+            bytes.extend_from_slice(hint);
+        }
+        bytes
+    }
+
+    fn decode(mut bytes: &[u8]) -> anyhow::Result<Self> {
+        let next_idx = varint::read_usize(&mut bytes)?;
+        let hints_count = varint::read_usize(&mut bytes)?;
+
+        let mut hints = Vec::with_capacity(hints_count.min(bytes.len()));
+        for _ in 0..hints_count {
+            let len = varint::read_usize(&mut bytes)?;
+            anyhow::ensure!(bytes.len() >= len, "Witness hint is truncated");
+            let (hint, rest) = bytes.split_at(len);
+            hints.push(hint.to_vec());
+            bytes = rest;
+        }
+        anyhow::ensure!(bytes.is_empty(), "Unexpected trailing bytes in the witness");
+
+        Ok(Self(ArrayWitness {
+            next_idx: AtomicUsize::new(next_idx),
+            hints: Mutex::new(hints),
+        }))
+    }
+}
+
+/// LEB128 encoding of unsigned integers.
+#[cfg(feature = "compact-witness")]
+mod varint {
+    pub(super) fn write(mut value: u64, bytes: &mut Vec<u8>) {
+        while value >= 0x80 {
+            bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+    }
+
+    pub(super) fn read_usize(bytes: &mut &[u8]) -> anyhow::Result<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = bytes
+                .split_first()
+                .ok_or_else(|| anyhow::anyhow!("Witness varint is truncated"))?;
+            *bytes = rest;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(usize::try_from(value)?);
+            }
+        }
+        anyhow::bail!("Witness varint is too long")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn witness_with_hints<W: Witness>(count: u64) -> W {
+        let witness = W::default();
+        for hint in 0..count {
+            witness.add_hint(hint);
+        }
+        // Consume one hint so that the read position is part of the roundtrip.
+        assert_eq!(witness.get_hint::<u64>(), 0);
+        witness
+    }
+
+    fn assert_roundtrip<W: Witness>(count: u64) -> usize {
+        let encoded = witness_with_hints::<W>(count).encode();
+        let decoded = W::decode(&encoded).unwrap();
+        for hint in 1..count {
+            assert_eq!(decoded.get_hint::<u64>(), hint);
+        }
+        encoded.len()
+    }
+
+    #[test]
+    fn array_witness_roundtrip() {
+        assert_roundtrip::<ArrayWitness>(100);
+    }
+
+    #[test]
+    #[cfg(feature = "compact-witness")]
+    fn compact_witness_roundtrip_and_size() {
+        let compact_len = assert_roundtrip::<CompactWitness>(100);
+        let default_len = assert_roundtrip::<ArrayWitness>(100);
+        assert!(
+            compact_len < default_len,
+            "The compact encoding ({} bytes) should be smaller than the default one ({} bytes)",
+            compact_len,
+            default_len
+        );
+
+        // Large lengths still roundtrip through multi-byte varints.
+        let witness = CompactWitness::default();
+        witness.add_hint(vec![7u8; 1000]);
+        let decoded = CompactWitness::decode(&witness.encode()).unwrap();
+        assert_eq!(decoded.get_hint::<Vec<u8>>(), vec![7u8; 1000]);
+    }
+
+    #[test]
+    #[cfg(feature = "compact-witness")]
+    fn compact_witness_rejects_malformed_bytes() {
+        let encoded = witness_with_hints::<CompactWitness>(3).encode();
+        assert!(CompactWitness::decode(&encoded[..encoded.len() - 1]).is_err());
+
+        let mut trailing = encoded;
+        trailing.push(0);
+        assert!(CompactWitness::decode(&trailing).is_err());
+    }
+}
@@ -87,7 +87,7 @@ impl<S: MerkleProofSpec> ZkStorage<S> {
         &self,
         state_accesses: OrderedReadsAndWrites,
         witness: &S::Witness,
-    ) -> Result<jmt::RootHash, anyhow::Error> {
+    ) -> Result<(jmt::RootHash, jmt::RootHash), anyhow::Error> {
         let prev_state_root = witness.get_hint();
 
         // For each value that's been read from the tree, verify the provided smt proof
@@ -95,7 +95,26 @@ impl<S: MerkleProofSpec> ZkStorage<S> {
 
         let new_root = jmt_verify_update::<S>(prev_state_root, state_accesses, witness);
 
-        Ok(jmt::RootHash(new_root))
+        Ok((jmt::RootHash(prev_state_root), jmt::RootHash(new_root)))
+    }
+
+    /// Verifies the state update described by `state_accesses` against the hints
+    /// in `witness`, returning both the root the witness was recorded against
+    /// and the root obtained after applying all writes, in that order.
+    pub(crate) fn verify_state_update(
+        &self,
+        state_accesses: StateAccesses,
+        witness: &S::Witness,
+    ) -> Result<(StorageRoot<S>, StorageRoot<S>), anyhow::Error> {
+        let (prev_user_root, user_root) =
+            self.compute_state_update_namespace(state_accesses.user, witness)?;
+        let (prev_kernel_root, kernel_root) =
+            self.compute_state_update_namespace(state_accesses.kernel, witness)?;
+
+        Ok((
+            StorageRoot::<S>::new(prev_user_root, prev_kernel_root),
+            StorageRoot::<S>::new(user_root, kernel_root),
+        ))
     }
 }
 
@@ -121,10 +140,9 @@ impl<S: MerkleProofSpec> Storage for ZkStorage<S> {
         state_accesses: StateAccesses,
         witness: &Self::Witness,
     ) -> Result<(Self::Root, Self::StateUpdate), anyhow::Error> {
-        let user_root = self.compute_state_update_namespace(state_accesses.user, witness)?;
-        let kernel_root = self.compute_state_update_namespace(state_accesses.kernel, witness)?;
+        let (_, new_root) = self.verify_state_update(state_accesses, witness)?;
 
-        Ok((StorageRoot::<S>::new(user_root, kernel_root), ()))
+        Ok((new_root, ()))
     }
 
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
@@ -174,4 +192,8 @@ impl<S: MerkleProofSpec> crate::storage::NativeStorage for ZkStorage<S> {
     fn get_root_hash(&self, _version: jmt::Version) -> anyhow::Result<Self::Root> {
         unimplemented!("The ZkStorage should not be used to generate merkle proofs! The NativeStorage trait is only implemented to allow for the use of the ZkStorage in tests.");
     }
+
+    fn version(&self) -> jmt::Version {
+        unimplemented!("The ZkStorage should not be used to generate merkle proofs! The NativeStorage trait is only implemented to allow for the use of the ZkStorage in tests.");
+    }
 }
@@ -1,4 +1,7 @@
 use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use serde::de::DeserializeOwned;
+use sov_bank::TokenId;
+use sov_modules_api::rest::utils::ResponseObject;
 
 /// API clients for a running Soverign rollup node.
 ///
@@ -20,15 +23,14 @@ pub struct ApiClient {
     pub sequencer: sov_sequencer_json_client::Client,
     pub ledger: sov_ledger_json_client::Client,
     pub rpc: WsClient,
+    rest_url: String,
 }
 
 impl ApiClient {
     pub async fn new(rpc_port: u16, rest_port: u16) -> anyhow::Result<Self> {
-        let sequencer = sov_sequencer_json_client::Client::new(&format!(
-            "http://127.0.0.1:{rest_port}/sequencer"
-        ));
-        let ledger =
-            sov_ledger_json_client::Client::new(&format!("http://127.0.0.1:{rest_port}/ledger"));
+        let rest_url = format!("http://127.0.0.1:{rest_port}");
+        let sequencer = sov_sequencer_json_client::Client::new(&format!("{rest_url}/sequencer"));
+        let ledger = sov_ledger_json_client::Client::new(&format!("{rest_url}/ledger"));
 
         let rpc = WsClientBuilder::default()
             .build(&format!("ws://127.0.0.1:{rpc_port}"))
@@ -38,6 +40,48 @@ impl ApiClient {
             sequencer,
             ledger,
             rpc,
+            rest_url,
         })
     }
+
+    /// `GET /ledger/slots/{slotNumber}/finalized`.
+    pub async fn get_slot_finalized(
+        &self,
+        slot_number: u64,
+    ) -> anyhow::Result<ResponseObject<bool>> {
+        self.get_rest(&format!("/ledger/slots/{slot_number}/finalized"))
+            .await
+    }
+
+    /// `GET /modules/bank/tokens/{tokenId}/total-supply`.
+    pub async fn get_token_total_supply(
+        &self,
+        token_id: &TokenId,
+    ) -> anyhow::Result<ResponseObject<u64>> {
+        self.get_rest(&format!("/modules/bank/tokens/{token_id}/total-supply"))
+            .await
+    }
+
+    /// `GET /modules/bank/tokens/{tokenId}/holder-count`.
+    pub async fn get_token_holder_count(
+        &self,
+        token_id: &TokenId,
+    ) -> anyhow::Result<ResponseObject<u64>> {
+        self.get_rest(&format!("/modules/bank/tokens/{token_id}/holder-count"))
+            .await
+    }
+
+    /// Sends a `GET` request to the REST API and deserializes the response,
+    /// regardless of its status code: errors are part of the [`ResponseObject`].
+    async fn get_rest<T: DeserializeOwned>(&self, path: &str) -> anyhow::Result<ResponseObject<T>> {
+        let response = self
+            .ledger
+            .client()
+            .get(format!("{}{}", self.rest_url, path))
+            .send()
+            .await?;
+        let body = response.bytes().await?;
+
+        Ok(serde_json::from_slice(&body)?)
+    }
 }
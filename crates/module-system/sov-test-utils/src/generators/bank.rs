@@ -202,6 +202,7 @@ pub(crate) fn create_token_tx<S: Spec>(input: &TokenCreateData<S>) -> CallMessag
         initial_balance: input.initial_balance,
         mint_to_address: input.mint_to_address.clone(),
         authorized_minters: input.authorized_minters.clone(),
+        metadata: None,
     }
 }
 
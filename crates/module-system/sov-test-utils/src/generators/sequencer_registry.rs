@@ -104,6 +104,7 @@ impl<S: Spec, Da: DaSpec> MessageGenerator for SequencerRegistryMessageGenerator
                 CallMessage::Register {
                     da_address: msg.da_address.clone(),
                     amount: msg.amount,
+                    minimum_bond: None,
                 },
                 chain_id,
                 max_priority_fee_bips,
@@ -7,6 +7,7 @@ use sha2::Digest;
 use sov_bank::utils::TokenHolder;
 use sov_bank::{Coins, TokenId};
 use sov_db::ledger_db::{LedgerDb, SlotCommit};
+use sov_db::schema::types::SlotNumber;
 use sov_db::schema::SchemaBatch;
 use sov_ledger_apis::LedgerRoutes;
 use sov_mock_da::{MockBlock, MockBlockHeader, MockDaSpec, MockHash};
@@ -38,6 +39,7 @@ pub async fn materialize_simple_ledger_db_data(
         events: events(),
         receipt: TxEffect::Successful(0),
         gas_used: vec![0, 1, u64::MAX],
+        gas_limit: vec![u64::MAX, u64::MAX, u64::MAX],
     }];
 
     slot.add_batch(BatchReceipt {
@@ -101,6 +103,27 @@ fn events() -> Vec<StoredEvent> {
     ]
 }
 
+/// Events emitted by two different modules, with overlapping keys. Useful to
+/// test module and key prefix filters.
+pub fn mixed_module_events() -> Vec<StoredEvent> {
+    let token_id =
+        TokenId::from_str("token_1rwrh8gn2py0dl4vv65twgctmlwck6esm2as9dftumcw89kqqn3nqrduss6")
+            .unwrap();
+    let bank_event = TestEvent::bank(sov_bank::event::Event::TokenFrozen {
+        token_id,
+        freezer: TokenHolder::Module(ModuleId::from([0; 32])),
+    });
+    let value_setter_event = TestEvent::value_setter(
+        <sov_value_setter::ValueSetter<TestSpec> as sov_modules_api::Module>::Event::NewValue(42),
+    );
+
+    vec![
+        StoredEvent::new(b"foo/bank", &borsh::to_vec(&bank_event).unwrap()),
+        StoredEvent::new(b"bar/bank", &borsh::to_vec(&bank_event).unwrap()),
+        StoredEvent::new(b"foo/value", &borsh::to_vec(&value_setter_event).unwrap()),
+    ]
+}
+
 pub fn materialize_complex_ledger_db_data(ledger_db: &LedgerDb) -> anyhow::Result<SchemaBatch> {
     let mut slots: Vec<SlotCommit<MockBlock, u32, TestTxReceiptContents>> =
         vec![SlotCommit::new(MockBlock {
@@ -125,6 +148,7 @@ pub fn materialize_complex_ledger_db_data(ledger_db: &LedgerDb) -> anyhow::Resul
                     events: vec![],
                     receipt: TxEffect::Successful(0),
                     gas_used: vec![0, 0],
+                    gas_limit: vec![0, 0],
                 },
                 TransactionReceipt::<TestTxReceiptContents> {
                     tx_hash: sha2::Sha256::digest(b"tx2").into(),
@@ -135,6 +159,7 @@ pub fn materialize_complex_ledger_db_data(ledger_db: &LedgerDb) -> anyhow::Resul
                     ],
                     receipt: TxEffect::Successful(1),
                     gas_used: vec![2, 3],
+                    gas_limit: vec![2, 3],
                 },
             ],
             inner: 0,
@@ -192,6 +217,7 @@ fn batch2_tx_receipts() -> Vec<TransactionReceipt<TestTxReceiptContents>> {
             events: vec![],
             receipt: TxEffect::Skipped(0),
             gas_used: vec![0, 0],
+            gas_limit: vec![0, 0],
         })
         .collect()
 }
@@ -205,6 +231,8 @@ pub enum LedgerTestServiceData {
 pub struct LedgerTestService {
     // Must be kept in scope during the test to avoid directory deletion.
     _dir: TempDir,
+    ledger_db: LedgerDb,
+    storage_manager: SimpleLedgerStorageManager,
     pub axum_handle: axum_server::Handle,
     pub axum_client: sov_ledger_json_client::Client,
 }
@@ -253,10 +281,72 @@ impl LedgerTestService {
 
         Ok(Self {
             _dir: dir,
+            ledger_db,
+            storage_manager,
             axum_handle,
             axum_client,
         })
     }
+
+    /// Processes a new slot containing a single transaction that emits
+    /// `events`, and notifies subscribers.
+    pub fn process_slot_with_events(&mut self, events: Vec<StoredEvent>) -> anyhow::Result<()> {
+        let height = self
+            .ledger_db
+            .get_head_slot()?
+            .map_or(0, |(slot_number, _)| slot_number.0 + 1);
+        let mut slot: SlotCommit<MockBlock, u32, TestTxReceiptContents> =
+            SlotCommit::new(MockBlock {
+                header: MockBlockHeader::from_height(height),
+                ..Default::default()
+            });
+        slot.add_batch(BatchReceipt {
+            batch_hash: sha2::Sha256::digest(height.to_string()).into(),
+            tx_receipts: vec![TransactionReceipt {
+                tx_hash: sha2::Sha256::digest(format!("tx-{}", height)).into(),
+                body_to_save: None,
+                events,
+                receipt: TxEffect::Successful(0),
+                gas_used: vec![0, 0],
+                gas_limit: vec![0, 0],
+            }],
+            inner: 0,
+            gas_price: vec![0, 0],
+        });
+
+        let ledger_data = self.ledger_db.materialize_slot(slot, b"state-root")?;
+        self.storage_manager.commit(ledger_data);
+        self.ledger_db.send_notifications();
+        Ok(())
+    }
+
+    /// Returns the number of the latest slot, if any.
+    pub fn head_slot_number(&self) -> anyhow::Result<Option<u64>> {
+        Ok(self
+            .ledger_db
+            .get_head_slot()?
+            .map(|(slot_number, _)| slot_number.0))
+    }
+
+    /// Marks `slot_number` as the latest finalized slot, and notifies subscribers.
+    pub fn finalize_slot(&mut self, slot_number: u64) -> anyhow::Result<()> {
+        let ledger_data = self
+            .ledger_db
+            .materialize_latest_finalize_slot(slot_number)?;
+        self.storage_manager.commit(ledger_data);
+        self.ledger_db.send_notifications();
+        Ok(())
+    }
+
+    /// Removes all the slots starting from `first_slot`, like a reorg does.
+    pub async fn rollback_slots(&mut self, first_slot: u64) -> anyhow::Result<()> {
+        let ledger_data = self
+            .ledger_db
+            .materialize_rollback(SlotNumber(first_slot))
+            .await?;
+        self.storage_manager.commit(ledger_data);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
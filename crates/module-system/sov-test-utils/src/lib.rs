@@ -31,6 +31,7 @@ pub mod ledger_db;
 pub mod logging;
 pub mod runtime;
 pub mod sequencer;
+pub mod witness_replay;
 
 pub use evm::simple_smart_contract::SimpleStorageContract;
 use sov_modules_api::PrivateKey;
@@ -112,8 +113,11 @@ pub fn simple_bank_setup(
             token_name,
             address_and_balances: vec![(sender_address, initial_balance)],
             authorized_minters: vec![],
+            metadata: None,
         },
         tokens: vec![],
+        max_tokens_per_account: None,
+        freeze_admin: None,
     };
     let mut genesis_state_accessor =
         state_checkpoint.to_genesis_state_accessor::<Bank<TestSpec>>(&bank_config);
@@ -182,6 +186,7 @@ impl<S: Spec, Mod: Module> Message<S, Mod> {
                 max_priority_fee_bips,
                 max_fee,
                 gas_limit,
+                valid_until_slot: None,
             },
             nonce,
         }
@@ -191,14 +196,7 @@ impl<S: Spec, Mod: Module> Message<S, Mod> {
         let message = Encoder::encode_call(self.content);
         Transaction::<S>::new_signed_tx(
             &self.sender_key,
-            UnsignedTransaction::new(
-                message,
-                self.details.chain_id,
-                self.details.max_priority_fee_bips,
-                self.details.max_fee,
-                self.nonce,
-                self.details.gas_limit,
-            ),
+            UnsignedTransaction::new_with_details(message, self.nonce, self.details),
         )
     }
 }
@@ -110,6 +110,11 @@ macro_rules! generate_runtime {
                             inner_code_commitment: Default::default(),
                             outer_code_commitment: Default::default(),
                             genesis_da_height: 0,
+                            state_access_budget: Default::default(),
+                            base_fee_policy: Default::default(),
+                            gas_limit_policy: Default::default(),
+                            transition_retention_window: None,
+                            admin: None,
                         }
                     }
                 }
@@ -260,6 +260,10 @@ where
         let temp_dir = tempfile::tempdir().unwrap();
         let storage_config = sov_state::config::Config {
             path: PathBuf::from(temp_dir.path()),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
         };
 
         let mut storage_manager = ProverStorageManager::<MockDaSpec, _>::new(storage_config)
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use sov_attester_incentives::{AttesterIncentives, AttesterIncentivesConfig};
-use sov_bank::{Bank, BankConfig};
+use sov_bank::{Bank, BankConfig, UnbackedBondsError};
 use sov_mock_da::{MockAddress, MockDaSpec};
 use sov_modules_api::{CryptoSpec, DaSpec, Genesis, PrivateKey, Spec};
 use sov_sequencer_registry::{SequencerConfig, SequencerRegistry};
@@ -111,6 +111,13 @@ impl<S: Spec, Da: DaSpec> From<HighLevelOptimisticGenesisConfig<S, Da>>
 }
 
 impl<S: Spec, Da: DaSpec> MinimalOptimisticGenesisConfig<S, Da> {
+    /// Checks that the sequencer and attester bonds are backed by the bank allocations.
+    pub fn validate_bonds(&self) -> Result<(), UnbackedBondsError> {
+        let mut bonds = self.sequencer_registry.genesis_bonds();
+        bonds.extend(self.attester_incentives.genesis_bonds());
+        self.bank.validate_bonds(&bonds)
+    }
+
     pub fn from_args(
         initial_attester: SimpleStakedUser<S>,
         initial_challenger: SimpleStakedUser<S>,
@@ -124,6 +131,9 @@ impl<S: Spec, Da: DaSpec> MinimalOptimisticGenesisConfig<S, Da> {
                 seq_da_address: initial_sequencer.da_address.clone(),
                 minimum_bond: initial_sequencer.bond,
                 is_preferred_sequencer: true,
+                require_preferred_sequencer: false,
+                unbonding_period: 0,
+                slash_fraction_bips: sov_sequencer_registry::MAX_SLASH_FRACTION_BIPS,
             },
             attester_incentives: AttesterIncentivesConfig {
                 minimum_attester_bond: DEFAULT_MIN_USER_BOND,
@@ -154,8 +164,11 @@ impl<S: Spec, Da: DaSpec> MinimalOptimisticGenesisConfig<S, Da> {
                             .collect()
                     },
                     authorized_minters: vec![],
+                    metadata: None,
                 },
                 tokens: vec![],
+                max_tokens_per_account: None,
+                freeze_admin: None,
             },
         }
     }
@@ -4,10 +4,12 @@ use sov_mock_da::MockDaSpec;
 use sov_mock_zkvm::MockCodeCommitment;
 use sov_modules_api::prelude::UnwrapInfallible;
 use sov_modules_api::{Address, PrivateKey, UnmeteredStateWrapper, WorkingSet};
-use sov_modules_stf_blueprint::GenesisParams;
+use sov_modules_stf_blueprint::{GenesisBuilder, GenesisParams};
 
 use super::*;
-use crate::runtime::optimistic::genesis::HighLevelOptimisticGenesisConfig;
+use crate::runtime::optimistic::genesis::{
+    HighLevelOptimisticGenesisConfig, MinimalOptimisticGenesisConfig,
+};
 use crate::runtime::{ChainStateConfig, SlotTestCase, TestRunner, TxTestCase, WorkingSetClosure};
 use crate::{
     generate_optimistic_runtime, TestPrivateKey, TestSpec, TEST_DEFAULT_USER_BALANCE,
@@ -83,6 +85,11 @@ fn run_value_setter_txs_with_assertions(
             inner_code_commitment: MockCodeCommitment::default(),
             outer_code_commitment: MockCodeCommitment::default(),
             genesis_da_height: 0,
+            state_access_budget: Default::default(),
+            base_fee_policy: Default::default(),
+            gas_limit_policy: Default::default(),
+            transition_retention_window: None,
+            admin: None,
         },
     };
     let params = GenesisParams {
@@ -133,6 +140,9 @@ fn create_test_rt_genesis_config<S: Spec, Da: DaSpec>(
             seq_da_address,
             minimum_bond: seq_stake_amount,
             is_preferred_sequencer: true,
+            require_preferred_sequencer: false,
+            unbonding_period: 0,
+            slash_fraction_bips: sov_sequencer_registry::MAX_SLASH_FRACTION_BIPS,
         },
         attester_incentives: AttesterIncentivesConfig {
             minimum_attester_bond: TEST_DEFAULT_USER_STAKE,
@@ -156,8 +166,11 @@ fn create_test_rt_genesis_config<S: Spec, Da: DaSpec>(
                     additional_accounts_vec
                 },
                 authorized_minters: vec![admin.clone()],
+                metadata: None,
             },
             tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: None,
         },
     }
 }
@@ -187,3 +200,91 @@ fn test_slot_number() {
 
     assert_eq!(runner.curr_slot_number(), 5);
 }
+
+#[test]
+fn test_genesis_builder() {
+    generate_optimistic_runtime!(TestRuntime <=);
+
+    let runtime_config = || {
+        GenesisConfig::<TestSpec, MockDaSpec>::from_minimal_config(
+            HighLevelOptimisticGenesisConfig::generate().into(),
+        )
+    };
+    let kernel_config = || BasicKernelGenesisConfig {
+        chain_state: ChainStateConfig {
+            current_time: Default::default(),
+            inner_code_commitment: MockCodeCommitment::default(),
+            outer_code_commitment: MockCodeCommitment::default(),
+            genesis_da_height: 0,
+            state_access_budget: Default::default(),
+            base_fee_policy: Default::default(),
+            gas_limit_policy: Default::default(),
+            transition_retention_window: None,
+            admin: None,
+        },
+    };
+
+    assert!(GenesisBuilder::new()
+        .runtime(runtime_config())
+        .build()
+        .is_err());
+    assert!(GenesisBuilder::new()
+        .runtime(runtime_config())
+        .kernel(kernel_config())
+        .validate_with(|_, _| anyhow::bail!("rejected"))
+        .build()
+        .is_err());
+
+    let params = GenesisBuilder::new()
+        .runtime(runtime_config())
+        .kernel(kernel_config())
+        .validate_with(|runtime: &GenesisConfig<TestSpec, MockDaSpec>, _| {
+            let sequencer = &runtime.sequencer_registry;
+            let balance = runtime
+                .bank
+                .gas_token_config
+                .address_and_balances
+                .iter()
+                .find(|(address, _)| address == &sequencer.seq_rollup_address)
+                .map_or(0, |(_, balance)| *balance);
+            anyhow::ensure!(
+                balance >= sequencer.minimum_bond,
+                "The sequencer cannot afford its bond"
+            );
+            Ok(())
+        })
+        .build()
+        .unwrap();
+
+    let mut runner = TestRunner::new_with_genesis(params, TestRuntime::default());
+    assert_eq!(runner.curr_slot_number(), 1);
+
+    runner.execute_slots::<AttesterIncentives<TestSpec, MockDaSpec>>(vec![SlotTestCase::empty()]);
+    assert_eq!(runner.curr_slot_number(), 2);
+}
+
+#[test]
+fn test_genesis_bonds_must_be_backed_by_allocations() {
+    let mut config: MinimalOptimisticGenesisConfig<TestSpec, MockDaSpec> =
+        HighLevelOptimisticGenesisConfig::generate().into();
+    config.validate_bonds().unwrap();
+
+    let sequencer = config.sequencer_registry.seq_rollup_address;
+    let allocated = config
+        .bank
+        .gas_token_config
+        .address_and_balances
+        .iter()
+        .find(|(address, _)| address == &sequencer)
+        .map_or(0, |(_, balance)| *balance);
+    config.sequencer_registry.minimum_bond = allocated + 1;
+
+    let err = config.validate_bonds().unwrap_err();
+    assert_eq!(err.unbacked.len(), 1);
+    assert_eq!(err.unbacked[0].address, sequencer.to_string());
+    assert_eq!(err.unbacked[0].allocated, allocated);
+    assert_eq!(
+        err.unbacked[0].bonds,
+        vec![("SequencerRegistry".to_owned(), allocated + 1)]
+    );
+}
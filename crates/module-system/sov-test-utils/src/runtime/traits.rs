@@ -2,7 +2,7 @@
 //! with configurable hooks.
 
 use sov_bank::{Bank, Payable};
-use sov_modules_api::hooks::{ApplyBatchHooks, TxHooks};
+use sov_modules_api::hooks::{ApplyBatchHooks, TransitionHeight, TxHooks};
 use sov_modules_api::transaction::AuthenticatedTransactionData;
 use sov_modules_api::{
     BatchWithId, Context, DaSpec, DispatchCall, Genesis, RuntimeEventProcessor, Spec,
@@ -102,10 +102,15 @@ pub trait TestRuntimeHookOverrides<S: Spec, Da: DaSpec>:
         &self,
         batch: &BatchWithId,
         sender: &Da::Address,
+        visible_slot_number: TransitionHeight,
         state_checkpoint: &mut StateCheckpoint<S>,
     ) -> anyhow::Result<()> {
-        self.sequencer_registry()
-            .begin_batch_hook(batch, sender, state_checkpoint)
+        self.sequencer_registry().begin_batch_hook(
+            batch,
+            sender,
+            visible_slot_number,
+            state_checkpoint,
+        )
     }
 
     fn end_batch_hook_override(
@@ -8,7 +8,7 @@ use sov_modules_api::capabilities::{
     ProofProcessor, RuntimeAuthenticator, RuntimeAuthorization, SequencerAuthorization,
     TryReserveGasError,
 };
-use sov_modules_api::hooks::{ApplyBatchHooks, FinalizeHook, SlotHooks, TxHooks};
+use sov_modules_api::hooks::{ApplyBatchHooks, FinalizeHook, SlotHooks, TransitionHeight, TxHooks};
 use sov_modules_api::transaction::{AuthenticatedTransactionData, TransactionConsumption};
 use sov_modules_api::{
     BatchWithId, Context, DispatchCall, EncodeCall, Gas, GasMeter, Genesis, GenesisState,
@@ -223,9 +223,10 @@ where
         &self,
         batch: &BatchWithId,
         sender: &Da::Address,
+        visible_slot_number: TransitionHeight,
         state_checkpoint: &mut StateCheckpoint<S>,
     ) -> anyhow::Result<()> {
-        self.begin_batch_hook_override(batch, sender, state_checkpoint)
+        self.begin_batch_hook_override(batch, sender, visible_slot_number, state_checkpoint)
     }
 
     fn end_batch_hook(
@@ -380,9 +381,10 @@ where
 impl<S: Spec, Da: DaSpec, T: StandardRuntime<S, Da>> HasCapabilities<S, Da>
     for TestRuntimeWrapper<S, Da, T>
 {
-    type Capabilities<'a> = Self
+    type Capabilities<'a>
+        = Self
     where
-    T: 'a,;
+        T: 'a;
     type SequencerStakeMeter = SequencerStakeMeter<S::Gas>;
 
     type AuthorizationData = AuthorizationData<S>;
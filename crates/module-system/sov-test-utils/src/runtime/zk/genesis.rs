@@ -1,4 +1,4 @@
-use sov_bank::Bank;
+use sov_bank::{Bank, UnbackedBondsError};
 use sov_modules_api::{DaSpec, Genesis, Spec};
 use sov_prover_incentives::ProverIncentives;
 use sov_sequencer_registry::SequencerRegistry;
@@ -8,3 +8,12 @@ pub struct MinimalZkGenesisConfig<S: Spec, Da: DaSpec> {
     pub prover_incentives: <ProverIncentives<S, Da> as Genesis>::Config,
     pub bank: <Bank<S> as Genesis>::Config,
 }
+
+impl<S: Spec, Da: DaSpec> MinimalZkGenesisConfig<S, Da> {
+    /// Checks that the sequencer and prover bonds are backed by the bank allocations.
+    pub fn validate_bonds(&self) -> Result<(), UnbackedBondsError> {
+        let mut bonds = self.sequencer_registry.genesis_bonds();
+        bonds.extend(self.prover_incentives.genesis_bonds());
+        self.bank.validate_bonds(&bonds)
+    }
+}
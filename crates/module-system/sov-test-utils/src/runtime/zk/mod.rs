@@ -50,6 +50,9 @@ pub fn create_genesis_config<S: Spec, Da: DaSpec>(
             seq_da_address,
             minimum_bond: seq_stake_amount,
             is_preferred_sequencer: true,
+            require_preferred_sequencer: false,
+            unbonding_period: 0,
+            slash_fraction_bips: sov_sequencer_registry::MAX_SLASH_FRACTION_BIPS,
         },
         prover_incentives: ProverIncentivesConfig {
             proving_penalty: TEST_DEFAULT_USER_STAKE / 2,
@@ -68,8 +71,11 @@ pub fn create_genesis_config<S: Spec, Da: DaSpec>(
                     additional_accounts_vec
                 },
                 authorized_minters: vec![admin.clone()],
+                metadata: None,
             },
             tokens: vec![],
+            max_tokens_per_account: None,
+            freeze_admin: None,
         },
     }
 }
@@ -84,6 +84,10 @@ where
 
         let storage_config = sov_state::config::Config {
             path: dir.path().to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
         };
         let mut storage_manager =
             ProverStorageManager::<MockDaSpec, DefaultStorageSpec<TestHasher>>::new(
@@ -108,6 +112,11 @@ where
                 inner_code_commitment: MockCodeCommitment::default(),
                 outer_code_commitment: MockCodeCommitment::default(),
                 genesis_da_height: 0,
+                state_access_budget: Default::default(),
+                base_fee_policy: Default::default(),
+                gas_limit_policy: Default::default(),
+                transition_retention_window: None,
+                admin: None,
             },
         };
         let params = GenesisParams {
@@ -167,6 +176,10 @@ impl TestSequencerSetup<TestFairBatchBuilder> {
 
         let storage_config = sov_state::config::Config {
             path: dir.path().to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
         };
         let mut storage_manager =
             ProverStorageManager::<MockDaSpec, DefaultStorageSpec<TestHasher>>::new(
@@ -191,6 +204,11 @@ impl TestSequencerSetup<TestFairBatchBuilder> {
                 inner_code_commitment: MockCodeCommitment::default(),
                 outer_code_commitment: MockCodeCommitment::default(),
                 genesis_da_height: 0,
+                state_access_budget: Default::default(),
+                base_fee_policy: Default::default(),
+                gas_limit_policy: Default::default(),
+                transition_retention_window: None,
+                admin: None,
             },
         };
         let params = GenesisParams {
@@ -0,0 +1,392 @@
+//! Native/zk parity checks based on witness replay.
+//!
+//! A slot is first executed natively against [`ProverStorage`], which records
+//! every read and the state update proofs into a witness. The same slot is then
+//! re-executed against [`ZkStorage`], consuming that witness exactly like the
+//! zkVM would, and the two resulting state roots are compared.
+
+use sov_kernels::basic::BasicKernelGenesisConfig;
+use sov_mock_da::{MockBlock, MockDaSpec};
+use sov_modules_api::{EventKey, Genesis, Spec, StateCheckpoint};
+use sov_modules_stf_blueprint::{BatchReceipt, GenesisParams, Runtime};
+use sov_prover_storage_manager::SimpleStorageManager;
+use sov_rollup_interface::stf::StateTransitionFunction;
+use sov_state::{ProverStorage, Storage, StorageRoot, ZkStorage};
+
+use crate::{TestSpec, TestStfBlueprint, TestStorageSpec, ZkTestSpec};
+
+/// State transition logic that can be executed both natively and in the zk
+/// execution mode.
+pub trait ReplayableSlot {
+    /// Applies the slot logic on top of `state` and returns the resulting checkpoint.
+    fn execute<S: Spec>(&self, state: StateCheckpoint<S>) -> StateCheckpoint<S>
+    where
+        S::Address: From<[u8; 32]>;
+}
+
+/// Executes `slot` natively on top of `storage`, replays it against the recorded
+/// witness with [`ZkStorage`], and checks that both paths produce the same root.
+///
+/// Returns the state root produced by the slot. The changes are not committed
+/// to `storage`.
+pub fn check_native_zk_parity<R: ReplayableSlot>(
+    storage: &ProverStorage<TestStorageSpec>,
+    slot: &R,
+) -> anyhow::Result<StorageRoot<TestStorageSpec>> {
+    let native_state = slot.execute(StateCheckpoint::<TestSpec>::new(storage.clone()));
+    let (native_accesses, _, witness) = native_state.freeze();
+    let (native_root, _) = storage.compute_state_update(native_accesses, &witness)?;
+
+    let zk_state = slot.execute(StateCheckpoint::<ZkTestSpec>::with_witness(
+        ZkStorage::new(),
+        witness,
+    ));
+    let (zk_accesses, _, witness) = zk_state.freeze();
+    let zk_root = storage.verify_with_witness(zk_accesses, &witness)?;
+
+    anyhow::ensure!(
+        native_root == zk_root,
+        "Native and zk execution diverged: native root {:?}, zk root {:?}",
+        native_root,
+        zk_root
+    );
+
+    Ok(native_root)
+}
+
+/// Initializes a rollup with `genesis_params` and applies `slots` on top of it with
+/// [`StfBlueprint::apply_slot`](sov_modules_stf_blueprint::StfBlueprint), once natively
+/// and once in the zk execution mode, replaying the witness recorded natively. Checks
+/// that both executions produce the same state roots, batch receipts and events, in
+/// the same order.
+///
+/// Returns the batch receipts of each slot.
+pub fn check_stf_native_zk_parity<NativeRt, ZkRt>(
+    genesis_params: GenesisParams<
+        <NativeRt as Genesis>::Config,
+        BasicKernelGenesisConfig<TestSpec, MockDaSpec>,
+    >,
+    slots: &[MockBlock],
+) -> anyhow::Result<Vec<Vec<BatchReceipt>>>
+where
+    NativeRt: Runtime<TestSpec, MockDaSpec>,
+    ZkRt: Runtime<ZkTestSpec, MockDaSpec>,
+{
+    let tmpdir = tempfile::tempdir()?;
+    let mut storage_manager = SimpleStorageManager::<TestStorageSpec>::new(tmpdir.path());
+    let native_stf = TestStfBlueprint::<NativeRt, TestSpec>::new();
+    let zk_stf = TestStfBlueprint::<ZkRt, ZkTestSpec>::new();
+
+    let (mut state_root, change_set) =
+        native_stf.init_chain(storage_manager.create_storage(), genesis_params);
+    storage_manager.commit(change_set);
+
+    let mut slot_receipts = Vec::with_capacity(slots.len());
+    for slot in slots {
+        let height = slot.header.height;
+        let native = native_stf.apply_slot(
+            &state_root,
+            storage_manager.create_storage(),
+            Default::default(),
+            &slot.header,
+            &slot.validity_cond,
+            slot.as_relevant_blobs().as_iters(),
+        );
+        let zk = zk_stf.apply_slot(
+            &state_root,
+            ZkStorage::new(),
+            native.witness,
+            &slot.header,
+            &slot.validity_cond,
+            slot.as_relevant_blobs().as_iters(),
+        );
+
+        anyhow::ensure!(
+            native.state_root == zk.state_root,
+            "Native and zk execution diverged at height {}: native root {:?}, zk root {:?}",
+            height,
+            native.state_root,
+            zk.state_root
+        );
+        anyhow::ensure!(
+            event_keys(&native.batch_receipts) == event_keys(&zk.batch_receipts),
+            "Native and zk execution emitted different events at height {}",
+            height
+        );
+        anyhow::ensure!(
+            serde_json::to_value(&native.batch_receipts)?
+                == serde_json::to_value(&zk.batch_receipts)?,
+            "Native and zk execution produced different batch receipts at height {}",
+            height
+        );
+
+        storage_manager.commit(native.change_set);
+        state_root = native.state_root;
+        slot_receipts.push(native.batch_receipts);
+    }
+
+    Ok(slot_receipts)
+}
+
+fn event_keys(batch_receipts: &[BatchReceipt]) -> Vec<&EventKey> {
+    batch_receipts
+        .iter()
+        .flat_map(|batch| &batch.tx_receipts)
+        .flat_map(|tx| tx.events.iter().map(|event| event.key()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use sov_bank::{Bank, BankConfig, CallMessage, Coins, GasTokenConfig, GAS_TOKEN_ID};
+    use sov_kernels::basic::BasicKernelGenesisConfig;
+    use sov_mock_da::{MockBlob, MockBlock, MockBlockHeader, MockDaSpec};
+    use sov_mock_zkvm::MockCodeCommitment;
+    use sov_modules_api::prelude::UnwrapInfallible;
+    use sov_modules_api::utils::generate_address;
+    use sov_modules_api::{Batch, Context, Module, PrivateKey, RawTx, Spec, StateCheckpoint};
+    use sov_modules_stf_blueprint::GenesisParams;
+    use sov_prover_storage_manager::new_orphan_storage;
+    use sov_sequencer_registry::SequencerRegistry;
+    use sov_value_setter::ValueSetter;
+
+    use super::{check_native_zk_parity, check_stf_native_zk_parity, ReplayableSlot};
+    use crate::runtime::optimistic::{create_genesis_config, TestRuntime};
+    use crate::runtime::{ChainStateConfig, MessageType};
+    use crate::{
+        new_test_blob_from_batch, TestAddress, TestPrivateKey, TestPublicKey, TestSpec, ZkTestSpec,
+        TEST_DEFAULT_USER_BALANCE, TEST_DEFAULT_USER_STAKE,
+    };
+
+    const INITIAL_BALANCE: u64 = 1000;
+    const TRANSFER_AMOUNT: u64 = 100;
+
+    struct BankTransferSlot;
+
+    impl ReplayableSlot for BankTransferSlot {
+        fn execute<S: Spec>(&self, state: StateCheckpoint<S>) -> StateCheckpoint<S>
+        where
+            S::Address: From<[u8; 32]>,
+        {
+            let bank = Bank::<S>::default();
+            let sender = generate_address::<S>("sender");
+            let receiver = generate_address::<S>("receiver");
+
+            let config = BankConfig::<S> {
+                gas_token_config: GasTokenConfig {
+                    token_name: "Token1".to_owned(),
+                    address_and_balances: vec![(sender.clone(), INITIAL_BALANCE)],
+                    authorized_minters: vec![],
+                    metadata: None,
+                },
+                tokens: vec![],
+                max_tokens_per_account: None,
+                freeze_admin: None,
+            };
+            let mut genesis_state = state.to_genesis_state_accessor::<Bank<S>>(&config);
+            bank.genesis(&config, &mut genesis_state).unwrap();
+
+            let context = Context::<S>::new(sender.clone(), Default::default(), sender, 1);
+            let mut working_set = genesis_state.checkpoint().to_working_set_unmetered();
+            bank.call(
+                CallMessage::Transfer {
+                    to: receiver.clone(),
+                    coins: Coins {
+                        amount: TRANSFER_AMOUNT,
+                        token_id: GAS_TOKEN_ID,
+                    },
+                },
+                &context,
+                &mut working_set,
+            )
+            .unwrap();
+
+            let mut state = working_set.checkpoint().0;
+            assert_eq!(
+                bank.get_balance_of(&receiver, GAS_TOKEN_ID, &mut state)
+                    .unwrap_infallible(),
+                Some(TRANSFER_AMOUNT)
+            );
+            state
+        }
+    }
+
+    #[test]
+    fn bank_transfer_native_zk_parity() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let storage = new_orphan_storage(tmpdir.path()).unwrap();
+
+        check_native_zk_parity(&storage, &BankTransferSlot).expect("Native and zk roots differ");
+    }
+
+    const SEQ_A_DA: [u8; 32] = [11; 32];
+    const SEQ_B_DA: [u8; 32] = [22; 32];
+
+    type NativeRuntime = TestRuntime<TestSpec, MockDaSpec>;
+
+    fn raw_tx<M: Module>(
+        msg: M::CallMessage,
+        key: &TestPrivateKey,
+        nonces: &mut HashMap<TestPublicKey, u64>,
+    ) -> RawTx
+    where
+        NativeRuntime: sov_modules_api::EncodeCall<M>,
+    {
+        MessageType::<M, TestSpec>::Plain(msg, key.clone()).to_raw_tx::<NativeRuntime>(nonces)
+    }
+
+    fn slot(height: u64, blobs: Vec<MockBlob>) -> MockBlock {
+        MockBlock {
+            header: MockBlockHeader::from_height(height),
+            validity_cond: Default::default(),
+            batch_blobs: blobs,
+            proof_blobs: Default::default(),
+        }
+    }
+
+    /// Runs two slots containing several batches from two sequencers, one of which registers
+    /// in the first slot, and checks that native and zk executions agree on every output.
+    #[test]
+    fn multi_sequencer_slots_native_zk_parity() {
+        let admin_key = TestPrivateKey::generate();
+        let admin = admin_key.to_address::<TestAddress>();
+        let seq_b_key = TestPrivateKey::generate();
+        let seq_b = seq_b_key.to_address::<TestAddress>();
+        let seq_a = TestPrivateKey::generate().to_address::<TestAddress>();
+        let receiver = generate_address::<TestSpec>("receiver");
+
+        let genesis_params = GenesisParams {
+            runtime: create_genesis_config::<TestSpec, MockDaSpec>(
+                admin,
+                &[(seq_b, TEST_DEFAULT_USER_BALANCE)],
+                seq_a,
+                SEQ_A_DA.into(),
+                TEST_DEFAULT_USER_STAKE,
+                "SovereignToken".to_owned(),
+                TEST_DEFAULT_USER_BALANCE,
+            ),
+            kernel: BasicKernelGenesisConfig {
+                chain_state: ChainStateConfig {
+                    current_time: Default::default(),
+                    inner_code_commitment: MockCodeCommitment::default(),
+                    outer_code_commitment: MockCodeCommitment::default(),
+                    genesis_da_height: 0,
+                    state_access_budget: Default::default(),
+                    base_fee_policy: Default::default(),
+                    gas_limit_policy: Default::default(),
+                    transition_retention_window: None,
+                    admin: None,
+                },
+            },
+        };
+
+        let mut nonces = HashMap::new();
+        let first_slot = slot(
+            1,
+            vec![
+                new_test_blob_from_batch(
+                    Batch {
+                        txs: vec![
+                            raw_tx::<ValueSetter<TestSpec>>(
+                                sov_value_setter::CallMessage::SetValue(1),
+                                &admin_key,
+                                &mut nonces,
+                            ),
+                            raw_tx::<SequencerRegistry<TestSpec, MockDaSpec>>(
+                                sov_sequencer_registry::CallMessage::Register {
+                                    da_address: SEQ_B_DA.to_vec(),
+                                    amount: TEST_DEFAULT_USER_STAKE,
+                                    minimum_bond: None,
+                                },
+                                &seq_b_key,
+                                &mut nonces,
+                            ),
+                        ],
+                    },
+                    &SEQ_A_DA,
+                    [1; 32],
+                ),
+                new_test_blob_from_batch(
+                    Batch {
+                        txs: vec![raw_tx::<Bank<TestSpec>>(
+                            CallMessage::Transfer {
+                                to: receiver,
+                                coins: Coins {
+                                    amount: TRANSFER_AMOUNT,
+                                    token_id: GAS_TOKEN_ID,
+                                },
+                            },
+                            &admin_key,
+                            &mut nonces,
+                        )],
+                    },
+                    &SEQ_A_DA,
+                    [2; 32],
+                ),
+            ],
+        );
+        let second_slot = slot(
+            2,
+            vec![
+                new_test_blob_from_batch(
+                    Batch {
+                        txs: vec![raw_tx::<ValueSetter<TestSpec>>(
+                            sov_value_setter::CallMessage::SetValue(2),
+                            &admin_key,
+                            &mut nonces,
+                        )],
+                    },
+                    &SEQ_A_DA,
+                    [3; 32],
+                ),
+                new_test_blob_from_batch(
+                    Batch {
+                        txs: vec![
+                            raw_tx::<ValueSetter<TestSpec>>(
+                                sov_value_setter::CallMessage::SetValue(3),
+                                &admin_key,
+                                &mut nonces,
+                            ),
+                            raw_tx::<Bank<TestSpec>>(
+                                CallMessage::Transfer {
+                                    to: receiver,
+                                    coins: Coins {
+                                        amount: TRANSFER_AMOUNT,
+                                        token_id: GAS_TOKEN_ID,
+                                    },
+                                },
+                                &admin_key,
+                                &mut nonces,
+                            ),
+                        ],
+                    },
+                    &SEQ_B_DA,
+                    [4; 32],
+                ),
+            ],
+        );
+
+        let receipts = check_stf_native_zk_parity::<
+            NativeRuntime,
+            TestRuntime<ZkTestSpec, MockDaSpec>,
+        >(genesis_params, &[first_slot, second_slot])
+        .expect("Native and zk executions diverged");
+
+        let batch_hashes: Vec<Vec<[u8; 32]>> = receipts
+            .iter()
+            .map(|slot| slot.iter().map(|batch| batch.batch_hash).collect())
+            .collect();
+        assert_eq!(
+            batch_hashes,
+            vec![vec![[1; 32], [2; 32]], vec![[3; 32], [4; 32]]]
+        );
+        assert_eq!(receipts[1][1].tx_receipts.len(), 2);
+        assert!(receipts
+            .iter()
+            .flatten()
+            .flat_map(|batch| &batch.tx_receipts)
+            .all(|tx| tx.receipt.is_successful()));
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// A [`hex`]-encoded 32-byte hash. Note, this is not necessarily a transaction
 /// hash, rather a generic hash.
@@ -6,6 +7,7 @@ pub type HexHash = HexString<[u8; 32]>;
 
 /// A [`serde`]-compatible newtype wrapper around [`Vec<u8>`] or other
 /// bytes-like types, which is serialized as a 0x-prefixed hex string.
+/// Parsing accepts hex strings both with and without the `0x` prefix.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::AsRef)]
 #[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
 pub struct HexString<T = Vec<u8>>(pub T);
@@ -23,6 +25,24 @@ impl<T> From<T> for HexString<T> {
     }
 }
 
+impl<T> FromStr for HexString<T>
+where
+    T: TryFrom<Vec<u8>>,
+{
+    type Err = anyhow::Error;
+
+    /// Parses a hex string. The `0x` prefix is optional, so both `0xf9b4ca` and `f9b4ca`
+    /// are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        let bytes = hex::decode(s).map_err(|e| anyhow::anyhow!("failed to decode hex: {}", e))?;
+
+        Ok(HexString(bytes.try_into().map_err(|_| {
+            anyhow::anyhow!("Invalid hex string length")
+        })?))
+    }
+}
+
 impl<T> Display for HexString<T>
 where
     T: AsRef<[u8]>,
@@ -56,22 +76,15 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let bytes = if deserializer.is_human_readable() {
+        if deserializer.is_human_readable() {
             let string = String::deserialize(deserializer)?;
-            let s = string
-                .strip_prefix("0x")
-                .ok_or_else(|| serde::de::Error::custom("Missing 0x prefix"))?;
-
-            hex::decode(s)
-                .map_err(|e| anyhow::anyhow!("failed to decode hex: {}", e))
-                .map_err(serde::de::Error::custom)?
+            string.parse().map_err(serde::de::Error::custom)
         } else {
-            Vec::<u8>::deserialize(deserializer)?
-        };
-
-        Ok(HexString(bytes.try_into().map_err(|_| {
-            serde::de::Error::custom("Invalid hex string length")
-        })?))
+            let bytes = Vec::<u8>::deserialize(deserializer)?;
+            Ok(HexString(bytes.try_into().map_err(|_| {
+                serde::de::Error::custom("Invalid hex string length")
+            })?))
+        }
     }
 }
 
@@ -99,7 +112,7 @@ pub mod hex_string_serde {
     /// Deserializes a hex string into raw bytes.
     ///
     /// Both upper and lower case characters are valid in the input string and can
-    /// even be mixed.
+    /// even be mixed. The `0x` prefix is optional.
     pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
@@ -134,4 +147,32 @@ mod tests {
             test_serialization_roundtrip_equality_json(item);
         }
     }
+
+    #[test]
+    fn hex_hash_accepts_prefixed_and_bare_hex() {
+        let bare = "f9".repeat(32);
+        let prefixed = format!("0x{}", bare);
+
+        let from_bare: HexHash = serde_json::from_value(bare.clone().into()).unwrap();
+        let from_prefixed: HexHash = serde_json::from_value(prefixed.clone().into()).unwrap();
+        assert_eq!(from_bare, HexHash::new([0xf9; 32]));
+        assert_eq!(from_prefixed, from_bare);
+
+        assert_eq!(bare.parse::<HexHash>().unwrap(), from_bare);
+        assert_eq!(prefixed.parse::<HexHash>().unwrap(), from_bare);
+
+        // The output is always 0x-prefixed.
+        assert_eq!(from_bare.to_string(), prefixed);
+        assert_eq!(
+            serde_json::to_value(from_bare).unwrap(),
+            serde_json::Value::String(prefixed)
+        );
+    }
+
+    #[test]
+    fn hex_hash_rejects_invalid_input() {
+        assert!("0x0x".parse::<HexHash>().is_err());
+        assert!("f9".parse::<HexHash>().is_err());
+        assert!("zz".repeat(32).parse::<HexHash>().is_err());
+    }
 }
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 
 use crate::common::hex_string_serde;
+use crate::da::Time;
 use crate::stf::{EventKey, StoredEvent, TxEffect, TxReceiptContents};
 use crate::zk::aggregated_proof::AggregatedProof;
 
@@ -150,6 +151,17 @@ impl Default for QueryMode {
     }
 }
 
+/// Specifies which slot to pick when looking up a slot by timestamp and no slot
+/// has that exact timestamp.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimeRounding {
+    /// The latest slot with a timestamp at or before the requested time.
+    Before,
+    /// The earliest slot with a timestamp at or after the requested time.
+    After,
+}
+
 /// The body of a response to a JSON-RPC request for a particular slot.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 #[serde(bound = "B: Serialize + DeserializeOwned, Tx: TxReceiptContents")]
@@ -253,6 +265,19 @@ pub trait LedgerStateProvider {
     /// Get the latest slot number in the ledger.
     async fn get_latest_finalized_slot_number(&self) -> Result<u64, Self::Error>;
 
+    /// Check whether a slot has been finalized, without fetching the slot itself.
+    /// Returns `None` if the slot doesn't exist.
+    async fn is_slot_finalized(&self, slot_number: u64) -> Result<Option<bool>, Self::Error>;
+
+    /// Find the slot whose DA timestamp is closest to `time`, in the direction given by
+    /// `rounding`. Returns `None` if no slot lies in that direction, i.e. if `time` is
+    /// before the first slot (when rounding before) or after the head slot (when rounding after).
+    async fn find_slot_by_time(
+        &self,
+        time: Time,
+        rounding: TimeRounding,
+    ) -> Result<Option<u64>, Self::Error>;
+
     /// Get the latest slot in the ledger.
     async fn get_head<B, T>(
         &self,
@@ -417,6 +442,19 @@ pub trait LedgerStateProvider {
     where
         E: TryFrom<(u64, StoredEvent), Error = anyhow::Error> + Send + Sync;
 
+    /// Get a page of the events of a transaction, with an optional prefix
+    /// filter. Only events numbered strictly after `after` are returned, in
+    /// ascending order and no more than `limit` of them.
+    async fn get_filtered_tx_events<E>(
+        &self,
+        txn_num: u64,
+        event_key_prefix_filter: Option<Vec<u8>>,
+        after: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<E>, Self::Error>
+    where
+        E: TryFrom<(u64, StoredEvent), Error = anyhow::Error> + Send + Sync;
+
     /// Get a single tx by number.
     async fn get_tx_by_number<T>(
         &self,
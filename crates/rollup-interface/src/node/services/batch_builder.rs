@@ -19,6 +19,11 @@ pub trait BatchBuilder {
     /// Builds a new batch out of transactions in mempool.
     /// The logic of which transactions and how many of them are included in batch is up to implementation.
     async fn get_next_blob(&mut self, height: u64) -> anyhow::Result<Vec<TxWithHash>>;
+
+    /// Estimates the gas used by a transaction if it were executed on top of the current state,
+    /// without adding it to the mempool.
+    /// Returns an error if the transaction would not be executed successfully.
+    async fn estimate_gas(&self, tx: Vec<u8>) -> anyhow::Result<Vec<u64>>;
 }
 
 /// An encoded transaction with its hash as returned by
@@ -101,6 +101,40 @@ pub enum ProofOutcome<Address, Da: DaSpec, Root> {
 type ProofReceipts<Address, Da, StateRoot, Extra> =
     Vec<ProofReceipt<Address, Da, StateRoot, Extra>>;
 
+/// What happened to a blob of the current slot during [`StateTransitionFunction::apply_slot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlobOutcome {
+    /// The blob was selected and processed into the batch receipt at `index` in
+    /// [`ApplySlotOutput::batch_receipts`].
+    BatchReceipt {
+        /// The index of the receipt.
+        index: usize,
+    },
+    /// The blob was selected and processed into the proof receipt at `index` in
+    /// [`ApplySlotOutput::proof_receipts`].
+    ProofReceipt {
+        /// The index of the receipt.
+        index: usize,
+    },
+    /// The blob was stored to be processed in a later slot.
+    Deferred,
+    /// The blob was dropped without being processed.
+    Ignored {
+        /// Why the blob was dropped.
+        reason: String,
+    },
+}
+
+/// The outcome of a single blob, see [`ApplySlotOutput::blob_results`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlobResult {
+    /// The hash of the blob.
+    pub blob_hash: [u8; 32],
+    /// What happened to the blob.
+    pub outcome: BlobOutcome,
+}
+
 /// The result of applying a slot to current state.
 pub struct ApplySlotOutput<
     InnerVm: Zkvm,
@@ -116,6 +150,10 @@ pub struct ApplySlotOutput<
     pub proof_receipts: ProofReceipts<Stf::Address, Da, Stf::StateRoot, Stf::ProofReceiptContents>,
     /// Receipt for each applied batch
     pub batch_receipts: Vec<BatchReceipt<Stf::BatchReceiptContents, Stf::TxReceiptContents>>,
+    /// The outcome of each blob handled in this slot. Blobs which were processed come first,
+    /// in execution order, followed by the blobs of the current slot which were deferred or ignored.
+    /// Processed blobs may have been deferred from earlier slots.
+    pub blob_results: Vec<BlobResult>,
     /// Witness after applying the whole block
     pub witness: Stf::Witness,
 }
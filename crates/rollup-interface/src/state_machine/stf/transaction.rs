@@ -24,6 +24,9 @@ pub struct TransactionReceipt<T: TxReceiptContents> {
     pub receipt: TxEffect<T>,
     /// Total gas incurred for this transaction.
     pub gas_used: Vec<u64>,
+    /// The gas limit the transaction was executed with. If the transaction did not
+    /// specify a gas limit, this is the limit inferred by the rollup.
+    pub gas_limit: Vec<u64>,
 }
 
 /// The outcome of a transaction.
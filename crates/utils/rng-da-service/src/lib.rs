@@ -281,6 +281,7 @@ pub fn generate_create_token_payload(start_nonce: u64) -> Vec<RawTx> {
         initial_balance: 100000000,
         mint_to_address: minter,
         authorized_minters: vec![minter],
+        metadata: None,
     };
     let enc_msg = <Runtime<TestSpec, RngDaSpec> as EncodeCall<Bank<TestSpec>>>::encode_call(msg);
     let tx = Transaction::<TestSpec>::new_signed_tx(
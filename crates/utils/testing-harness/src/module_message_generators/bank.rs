@@ -40,6 +40,7 @@ impl<S: Spec> Iterator for BankMessageGenerator<S> {
                 token_name: format!("token-{message_count}"),
                 mint_to_address: address.clone(),
                 authorized_minters: vec![address],
+                metadata: None,
             },
             account_pool_index,
             max_fee: DEFAULT_MAX_FEE,
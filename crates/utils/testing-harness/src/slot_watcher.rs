@@ -30,7 +30,9 @@ pub(crate) fn start_slot_watcher_task(
 
         loop {
             let slot_number = match slot_subscription.next().await.transpose() {
-                Ok(slot) => slot.map(|s| s.number).unwrap_or_default(),
+                Ok(slot) => slot
+                    .and_then(|s| s.number.to_u64().ok())
+                    .unwrap_or_default(),
                 Err(e) => {
                     tracing::error!(error = ?e, "Error during next slot subscription, resubscribing");
                     slot_subscription = ledger_client
@@ -46,6 +48,7 @@ pub(crate) fn start_slot_watcher_task(
                 .get_slot_by_id(
                     &ledger_api_types::IntOrHash::Variant0(slot_number),
                     Some(ledger_api_types::GetSlotByIdChildren::_0),
+                    None,
                 )
                 .await
                 .expect("should be able to get slots by id");
@@ -59,9 +62,17 @@ pub(crate) fn start_slot_watcher_task(
             );
 
             for batch in batches {
+                let ledger_api_types::BatchOrHash::Batch(batch) = batch else {
+                    tracing::warn!("Batch was not inlined in the slot response, skipping");
+                    continue;
+                };
                 let txs = &batch.txs;
                 tracing::debug!(txs = txs.len(), "Inspecting batch");
                 for tx_response in txs {
+                    let ledger_api_types::TxOrHash::Tx(tx_response) = tx_response else {
+                        tracing::warn!("Transaction was not inlined in the batch response, skipping");
+                        continue;
+                    };
                     match tx_response.receipt.result {
                         ledger_api_types::TxReceiptResult::Reverted
                         | ledger_api_types::TxReceiptResult::Skipped => {
@@ -50,6 +50,10 @@ fn rollup_bench(_bench: &mut Criterion) {
 
     let storage_config = sov_state::config::Config {
         path: rollup_config.storage.path,
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
     let mut storage_manager =
         ProverStorageManager::<MockDaSpec, TestStorageSpec>::new(storage_config)
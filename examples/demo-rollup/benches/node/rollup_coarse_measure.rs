@@ -106,6 +106,10 @@ async fn main() -> Result<(), anyhow::Error> {
 
     let storage_config = sov_state::config::Config {
         path: rollup_config.storage.path.clone(),
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
     let mut storage_manager =
         ProverStorageManager::<MockDaSpec, TestStorageSpec>::new(storage_config)
@@ -99,6 +99,8 @@ impl FullNodeBlueprint<Native> for CelestiaDemoRollup<Native> {
             sequencer_db,
             da_service,
             sequencer,
+            rollup_config.runner.read_only,
+            rollup_config.runner.ledger_routes.clone(),
         )?;
 
         // TODO: Add issue for Sequencer level RPC injection:
@@ -107,6 +109,7 @@ impl FullNodeBlueprint<Native> for CelestiaDemoRollup<Native> {
             da_service.clone(),
             storage.clone(),
             &mut endpoints.jsonrpsee_module,
+            rollup_config.runner.read_only,
         )?;
 
         Ok(endpoints)
@@ -166,6 +169,10 @@ impl FullNodeBlueprint<Native> for CelestiaDemoRollup<Native> {
     ) -> Result<Self::StorageManager, anyhow::Error> {
         let storage_config = StorageConfig {
             path: rollup_config.storage.path.clone(),
+            max_unfinalized_forks: rollup_config.storage.max_unfinalized_forks,
+            state_path: rollup_config.storage.state_path.clone(),
+            accessory_path: rollup_config.storage.accessory_path.clone(),
+            ledger_path: rollup_config.storage.ledger_path.clone(),
         };
         ProverStorageManager::new(storage_config)
     }
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use anyhow::Context as _;
 use demo_stf::authentication::EvmAuth;
-use sov_ethereum::{EthRpcConfig, GasPriceOracleConfig};
+use sov_ethereum::{EthRpcConfig, GasPriceOracleConfig, DEFAULT_MAX_LOGS_BLOCK_RANGE};
 use sov_modules_api::Spec;
 use sov_rollup_interface::services::da::DaService;
 use tokio::sync::watch;
@@ -12,6 +12,7 @@ pub(crate) fn register_ethereum<S: Spec, Da: DaService>(
     da_service: Da,
     storage: watch::Receiver<<S as Spec>::Storage>,
     methods: &mut jsonrpsee::RpcModule<()>,
+    read_only: bool,
 ) -> Result<(), anyhow::Error> {
     let eth_rpc_config = {
         let eth_signer = eth_dev_signer();
@@ -19,6 +20,8 @@ pub(crate) fn register_ethereum<S: Spec, Da: DaService>(
             min_blob_size: Some(1),
             eth_signer,
             gas_price_oracle_config: GasPriceOracleConfig::default(),
+            max_logs_block_range: DEFAULT_MAX_LOGS_BLOCK_RANGE,
+            read_only,
         }
     };
 
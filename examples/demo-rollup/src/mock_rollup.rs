@@ -93,6 +93,8 @@ impl FullNodeBlueprint<Native> for MockDemoRollup<Native> {
             sequencer_db,
             da_service,
             rollup_config.da.sender_address,
+            rollup_config.runner.read_only,
+            rollup_config.runner.ledger_routes.clone(),
         )?;
 
         // TODO: Add issue for Sequencer level RPC injection:
@@ -101,6 +103,7 @@ impl FullNodeBlueprint<Native> for MockDemoRollup<Native> {
             da_service.clone(),
             storage,
             &mut endpoints.jsonrpsee_module,
+            rollup_config.runner.read_only,
         )?;
 
         Ok(endpoints)
@@ -145,6 +148,10 @@ impl FullNodeBlueprint<Native> for MockDemoRollup<Native> {
     ) -> anyhow::Result<Self::StorageManager> {
         let storage_config = StorageConfig {
             path: rollup_config.storage.path.clone(),
+            max_unfinalized_forks: rollup_config.storage.max_unfinalized_forks,
+            state_path: rollup_config.storage.state_path.clone(),
+            accessory_path: rollup_config.storage.accessory_path.clone(),
+            ledger_path: rollup_config.storage.ledger_path.clone(),
         };
         ProverStorageManager::new(storage_config)
     }
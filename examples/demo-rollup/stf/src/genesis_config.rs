@@ -84,6 +84,12 @@ pub fn create_genesis_config<S: Spec, Da: DaSpec>(
 
     let evm_config: EvmConfig = read_json_file(&genesis_paths.evm_genesis_path)?;
 
+    // The bonds are funded from the bank allocations, so their consistency can
+    // only be checked once all the module configs are known.
+    let mut genesis_bonds = sequencer_registry_config.genesis_bonds();
+    genesis_bonds.extend(prover_incentives_config.genesis_bonds());
+    bank_config.validate_bonds(&genesis_bonds)?;
+
     Ok(GenesisConfig::new(
         bank_config,
         sequencer_registry_config,
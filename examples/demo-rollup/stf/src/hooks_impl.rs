@@ -1,4 +1,4 @@
-use sov_modules_api::hooks::{ApplyBatchHooks, FinalizeHook, SlotHooks, TxHooks};
+use sov_modules_api::hooks::{ApplyBatchHooks, FinalizeHook, SlotHooks, TransitionHeight, TxHooks};
 use sov_modules_api::{
     AccessoryStateReaderAndWriter, BatchWithId, Spec, StateCheckpoint, WorkingSet,
 };
@@ -21,11 +21,12 @@ impl<S: Spec, Da: DaSpec> ApplyBatchHooks<Da> for Runtime<S, Da> {
         &self,
         batch: &BatchWithId,
         sender: &Da::Address,
+        visible_slot_number: TransitionHeight,
         state: &mut StateCheckpoint<S>,
     ) -> anyhow::Result<()> {
         // Before executing each batch, check that the sender is registered as a sequencer
         self.sequencer_registry
-            .begin_batch_hook(batch, sender, state)
+            .begin_batch_hook(batch, sender, visible_slot_number, state)
     }
 
     fn end_batch_hook(
@@ -3,12 +3,12 @@ use std::rc::Rc;
 use sov_bank::Bank;
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::runtime::capabilities::Authenticator;
-use sov_modules_api::transaction::{Transaction, UnsignedTransaction};
+use sov_modules_api::transaction::{PriorityFeeBips, Transaction, UnsignedTransaction};
 use sov_modules_api::{EncodeCall, PrivateKey, RawTx};
 use sov_test_utils::generators::bank::BankMessageGenerator;
 use sov_test_utils::generators::sequencer_registry::SequencerRegistryMessageGenerator;
 use sov_test_utils::generators::value_setter::{ValueSetterMessage, ValueSetterMessages};
-use sov_test_utils::{MessageGenerator, TestPrivateKey};
+use sov_test_utils::{MessageGenerator, TestPrivateKey, TEST_DEFAULT_MAX_PRIORITY_FEE};
 
 use crate::authentication::ModAuth;
 use crate::runtime::Runtime;
@@ -72,6 +72,40 @@ pub fn simulate_da_with_bad_nonce(key: TestPrivateKey) -> Vec<RawTx> {
     vec![encode_with_auth(tx)]
 }
 
+pub fn simulate_da_with_max_fee(key: TestPrivateKey, max_fee: u64) -> Vec<RawTx> {
+    simulate_da_with_fees(key, max_fee, TEST_DEFAULT_MAX_PRIORITY_FEE)
+}
+
+pub fn simulate_da_with_fees(
+    key: TestPrivateKey,
+    max_fee: u64,
+    max_priority_fee_bips: PriorityFeeBips,
+) -> Vec<RawTx> {
+    let bank_generator: BankMessageGenerator<S> = BankMessageGenerator::with_minter(key);
+    let mut create_token_message = bank_generator.create_default_messages().remove(0);
+    // Overwrite the fees, keeping the default gas limit
+    create_token_message.details.max_fee = max_fee;
+    create_token_message.details.max_priority_fee_bips = max_priority_fee_bips;
+    let tx = create_token_message.to_tx::<Runtime<S, Da>>();
+    vec![encode_with_auth(tx)]
+}
+
+pub fn simulate_da_with_valid_until_slot(key: TestPrivateKey, valid_until_slot: u64) -> Vec<RawTx> {
+    let bank_generator: BankMessageGenerator<S> = BankMessageGenerator::with_minter(key);
+    let mut create_token_message = bank_generator.create_default_messages().remove(0);
+    create_token_message.details.valid_until_slot = Some(valid_until_slot);
+    let tx = create_token_message.to_tx::<Runtime<S, Da>>();
+    vec![encode_with_auth(tx)]
+}
+
+pub fn simulate_da_without_gas_limit(key: TestPrivateKey) -> Vec<RawTx> {
+    let bank_generator: BankMessageGenerator<S> = BankMessageGenerator::with_minter(key);
+    let mut create_token_message = bank_generator.create_default_messages().remove(0);
+    create_token_message.details.gas_limit = None;
+    let tx = create_token_message.to_tx::<Runtime<S, Da>>();
+    vec![encode_with_auth(tx)]
+}
+
 pub fn simulate_da_with_bad_serialization(key: TestPrivateKey) -> Vec<RawTx> {
     let bank_generator: BankMessageGenerator<S> = BankMessageGenerator::with_minter(key);
     let create_token_message = bank_generator.create_default_messages().remove(0);
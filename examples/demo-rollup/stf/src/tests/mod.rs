@@ -31,6 +31,10 @@ pub(crate) fn create_storage_manager_for_tests(
 ) -> ProverStorageManager<MockDaSpec, DefaultStorageSpec<sov_test_utils::TestHasher>> {
     let config = sov_state::config::Config {
         path: path.as_ref().to_path_buf(),
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
     ProverStorageManager::new(config).unwrap()
 }
@@ -1,4 +1,5 @@
 use std::convert::Infallible;
+use std::num::NonZeroUsize;
 use std::vec;
 
 use sov_mock_da::{MockAddress, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
@@ -105,6 +106,64 @@ fn test_demo_values_in_db() -> Result<(), Infallible> {
     Ok(())
 }
 
+/// An empty batch from a registered sequencer is a valid no-op: it produces a receipt without
+/// transaction receipts, and the sequencer is neither rewarded nor slashed.
+#[test]
+fn test_empty_batch_is_not_rewardable() -> Result<(), Infallible> {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+    let config = create_genesis_config_for_tests();
+
+    let mut storage_manager = SimpleStorageManager::new(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let stf_state = storage_manager.create_storage();
+    let (genesis_root, stf_state) = stf.init_chain(stf_state, config);
+    storage_manager.commit(stf_state);
+
+    let runtime = Runtime::<TestSpec, MockDaSpec>::default();
+    let sequencer_da_address = MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS);
+    let stake_before = runtime
+        .sequencer_registry
+        .get_sender_balance(
+            &sequencer_da_address,
+            &mut ApiStateAccessor::<TestSpec>::new(storage_manager.create_storage()),
+        )?
+        .expect("The genesis sequencer should be registered");
+
+    let blob = new_test_blob_from_batch(Batch { txs: vec![] }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+    let mut relevant_blobs = RelevantBlobs {
+        proof_blobs: Default::default(),
+        batch_blobs: vec![blob],
+    };
+
+    let block_1 = MockBlock::default().next_mock();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage_manager.create_storage(),
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        relevant_blobs.as_iters(),
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    let batch_receipt = &apply_block_result.batch_receipts[0];
+    assert_eq!(batch_receipt.inner, BatchSequencerOutcome::NotRewardable);
+    assert!(batch_receipt.tx_receipts.is_empty());
+
+    storage_manager.commit(apply_block_result.change_set);
+    let mut state = ApiStateAccessor::<TestSpec>::new(storage_manager.create_storage());
+    assert_eq!(
+        runtime
+            .sequencer_registry
+            .get_sender_balance(&sequencer_da_address, &mut state)?,
+        Some(stake_before),
+        "The sequencer stake should be left untouched"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_demo_values_in_cache() -> Result<(), Infallible> {
     let tempdir = tempfile::tempdir().unwrap();
@@ -187,6 +246,105 @@ fn test_demo_values_in_cache() -> Result<(), Infallible> {
     Ok(())
 }
 
+#[test]
+fn test_parallel_batch_execution_matches_sequential_execution() {
+    let other_sequencer: [u8; 32] = [86; 32];
+
+    // Registers `other_sequencer` in a first slot, then applies a second slot with a batch from
+    // each sequencer. The batches only touch the state of their own sequencer, so they don't
+    // conflict with each other.
+    let apply_slots = |stf: StfBlueprintTest| {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut storage_manager = SimpleStorageManager::new(tempdir.path());
+        let mut config = create_genesis_config_for_tests();
+        config.runtime.sequencer_registry.is_preferred_sequencer = false;
+        let (genesis_root, stf_state) = stf.init_chain(storage_manager.create_storage(), config);
+        storage_manager.commit(stf_state);
+
+        let private_key = read_private_keys::<TestSpec>().tx_signer.private_key;
+        let registration = new_test_blob_from_batch(
+            Batch {
+                txs: simulate_da_with_multiple_direct_registration_msg(
+                    vec![other_sequencer.to_vec()],
+                    private_key,
+                ),
+            },
+            &other_sequencer,
+            [0; 32],
+        );
+        let mut relevant_blobs = RelevantBlobs {
+            proof_blobs: Default::default(),
+            batch_blobs: vec![registration],
+        };
+
+        let block_1 = MockBlock::default().next_mock();
+        let result = stf.apply_slot(
+            &genesis_root,
+            storage_manager.create_storage(),
+            Default::default(),
+            &block_1.header,
+            &block_1.validity_cond,
+            relevant_blobs.as_iters(),
+        );
+        storage_manager.commit(result.change_set);
+
+        let mut relevant_blobs = RelevantBlobs {
+            proof_blobs: Default::default(),
+            batch_blobs: vec![
+                new_test_blob_from_batch(
+                    Batch { txs: vec![] },
+                    &MOCK_SEQUENCER_DA_ADDRESS,
+                    [1; 32],
+                ),
+                new_test_blob_from_batch(Batch { txs: vec![] }, &other_sequencer, [2; 32]),
+            ],
+        };
+
+        let block_2 = block_1.next_mock();
+        stf.apply_slot(
+            &result.state_root,
+            storage_manager.create_storage(),
+            Default::default(),
+            &block_2.header,
+            &block_2.validity_cond,
+            relevant_blobs.as_iters(),
+        )
+    };
+    let parallel_metrics = || {
+        let mut counts = (0, 0);
+        sov_metrics::update_metrics(|metrics| {
+            counts = (
+                metrics.parallel_batches_total.get(),
+                metrics.parallel_batch_conflicts_total.get(),
+            );
+        });
+        counts
+    };
+
+    let sequential = apply_slots(StfBlueprint::new());
+    let (merged_before, conflicts_before) = parallel_metrics();
+    let parallel =
+        apply_slots(StfBlueprint::new().with_batch_parallelism(NonZeroUsize::new(2).unwrap()));
+    let (merged_after, conflicts_after) = parallel_metrics();
+
+    // Both batches were executed in parallel, none of them was executed again sequentially.
+    assert_eq!(merged_after - merged_before, 2);
+    assert_eq!(conflicts_after, conflicts_before);
+
+    assert_eq!(sequential.state_root, parallel.state_root);
+    assert_eq!(2, parallel.batch_receipts.len());
+    for (sequential, parallel) in sequential
+        .batch_receipts
+        .iter()
+        .zip(parallel.batch_receipts.iter())
+    {
+        assert_eq!(sequential.batch_hash, parallel.batch_hash);
+        assert_eq!(sequential.inner, parallel.inner);
+        assert_eq!(parallel.inner, BatchSequencerOutcome::NotRewardable);
+        assert!(parallel.tx_receipts.is_empty());
+    }
+}
+
 // Ensure 1 sequencer be registered per batch
 // This test has 2 batches each submitted by unregistered sequencers, given they are in different
 // batches then both unregistered sequencers should be registered
@@ -1,10 +1,14 @@
 use std::convert::Infallible;
 
+use sov_chain_state::ChainState;
 use sov_mock_da::{MockAddress, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
 use sov_modules_api::runtime::capabilities::FatalError;
-use sov_modules_api::transaction::SequencerReward;
-use sov_modules_api::{ApiStateAccessor, Batch, PrivateKey, PublicKey, Spec, StateCheckpoint};
-use sov_modules_stf_blueprint::{SkippedReason, StfBlueprint, TxEffect};
+use sov_modules_api::transaction::{PriorityFeeBips, SequencerReward};
+use sov_modules_api::{
+    ApiStateAccessor, Batch, GasArray, PrivateKey, PublicKey, RawTx, Spec, StateAccessBudget,
+    StateCheckpoint,
+};
+use sov_modules_stf_blueprint::{BatchReceipt, SkippedReason, StfBlueprint, TxEffect};
 use sov_prover_storage_manager::ProverStorageManager;
 use sov_rollup_interface::da::RelevantBlobs;
 use sov_rollup_interface::services::da::SlotData;
@@ -13,7 +17,10 @@ use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_sequencer_registry::BatchSequencerOutcome;
 use sov_state::DefaultStorageSpec;
 use sov_test_utils::generators::bank::get_default_token_id;
-use sov_test_utils::{has_tx_events, new_test_blob_from_batch, SchemaBatch, TestHasher, TestSpec};
+use sov_test_utils::{
+    has_tx_events, new_test_blob_from_batch, SchemaBatch, TestHasher, TestSpec,
+    TEST_DEFAULT_GAS_LIMIT, TEST_DEFAULT_MAX_FEE, TEST_DEFAULT_MAX_PRIORITY_FEE,
+};
 
 use super::{
     create_genesis_config_for_tests, create_storage_manager_for_tests, read_private_keys,
@@ -22,7 +29,8 @@ use super::{
 use crate::runtime::Runtime;
 use crate::tests::da_simulation::{
     simulate_da_with_bad_nonce, simulate_da_with_bad_serialization, simulate_da_with_bad_sig,
-    simulate_da_with_revert_msg,
+    simulate_da_with_fees, simulate_da_with_max_fee, simulate_da_with_revert_msg,
+    simulate_da_with_valid_until_slot, simulate_da_without_gas_limit,
 };
 use crate::tests::StfBlueprintTest;
 
@@ -337,6 +345,234 @@ fn test_tx_bad_nonce() {
     }
 }
 
+/// Applies a slot containing a single batch with the given transactions, right after genesis.
+fn apply_batch_after_genesis(txs: Vec<RawTx>) -> BatchReceipt {
+    apply_batch_after_genesis_with_budget(txs, StateAccessBudget::default())
+}
+
+/// Same as [`apply_batch_after_genesis`], with the given per-transaction state access budget
+/// configured at genesis.
+fn apply_batch_after_genesis_with_budget(
+    txs: Vec<RawTx>,
+    state_access_budget: StateAccessBudget,
+) -> BatchReceipt {
+    let tempdir = tempfile::tempdir().unwrap();
+
+    let mut config = create_genesis_config_for_tests();
+    config.kernel.chain_state.state_access_budget = state_access_budget;
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let (stf_state, _) = storage_manager
+        .create_state_for(genesis_block.header())
+        .unwrap();
+    let (genesis_root, stf_state) = stf.init_chain(stf_state, config);
+    storage_manager
+        .save_change_set(genesis_block.header(), stf_state, SchemaBatch::new())
+        .unwrap();
+
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+
+    let mut relevant_blobs = RelevantBlobs {
+        proof_blobs: Default::default(),
+        batch_blobs: vec![blob],
+    };
+
+    let (stf_state, _) = storage_manager.create_state_for(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        stf_state,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        relevant_blobs.as_iters(),
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    apply_block_result.batch_receipts[0].clone()
+}
+
+/// Applies a single create-token transaction with the given `max_fee` on top of genesis,
+/// returning its receipt along with the base fee of the default gas limit at the slot's gas price.
+fn apply_tx_with_max_fee(max_fee: u64) -> (TxEffect, u64) {
+    apply_tx_with_fees(max_fee, TEST_DEFAULT_MAX_PRIORITY_FEE)
+}
+
+/// Same as [`apply_tx_with_max_fee`], with the given maximum priority fee.
+fn apply_tx_with_fees(max_fee: u64, max_priority_fee_bips: PriorityFeeBips) -> (TxEffect, u64) {
+    let admin_key = read_private_keys::<TestSpec>().token_deployer.private_key;
+    let batch_receipt = apply_batch_after_genesis(simulate_da_with_fees(
+        admin_key,
+        max_fee,
+        max_priority_fee_bips,
+    ));
+    assert_eq!(1, batch_receipt.tx_receipts.len());
+
+    let base_fee = TEST_DEFAULT_GAS_LIMIT
+        .iter()
+        .zip(batch_receipt.gas_price.iter())
+        .map(|(limit, price)| limit * price)
+        .sum();
+
+    (batch_receipt.tx_receipts[0].receipt.clone(), base_fee)
+}
+
+/// This test ensures that a transaction whose `max_fee` cannot cover the base fee is skipped
+/// before any gas is reserved.
+#[test]
+fn test_tx_max_fee_below_base_fee() {
+    let max_fee = 1;
+    let (receipt, base_fee) = apply_tx_with_max_fee(max_fee);
+
+    assert!(max_fee < base_fee);
+    assert_eq!(
+        receipt,
+        TxEffect::Skipped(SkippedReason::MaxFeeBelowBaseFee {
+            max_fee,
+            base_fee,
+            priority_fee: 0,
+        })
+    );
+}
+
+/// This test ensures that the priority fee is accounted for: a `max_fee` which covers the base fee
+/// alone but not the base fee plus the priority fee is skipped as well.
+#[test]
+fn test_tx_max_fee_below_base_fee_plus_priority_fee() {
+    let (_, base_fee) = apply_tx_with_max_fee(TEST_DEFAULT_MAX_FEE);
+    let max_priority_fee_bips = PriorityFeeBips::from_percentage(10);
+    let priority_fee = max_priority_fee_bips.apply(base_fee).unwrap();
+    assert!(priority_fee > 0);
+
+    let (receipt, _) = apply_tx_with_fees(base_fee, max_priority_fee_bips);
+    assert_eq!(
+        receipt,
+        TxEffect::Skipped(SkippedReason::MaxFeeBelowBaseFee {
+            max_fee: base_fee,
+            base_fee,
+            priority_fee,
+        })
+    );
+
+    let (receipt, _) = apply_tx_with_fees(base_fee + priority_fee, max_priority_fee_bips);
+    assert_eq!(receipt, TxEffect::Successful(()));
+}
+
+#[test]
+fn test_tx_max_fee_above_base_fee() {
+    let (receipt, base_fee) = apply_tx_with_max_fee(TEST_DEFAULT_MAX_FEE);
+
+    assert!(TEST_DEFAULT_MAX_FEE > base_fee);
+    assert_eq!(receipt, TxEffect::Successful(()));
+}
+
+/// Applies a single create-token transaction that is valid until `valid_until_slot` in the
+/// first slot after genesis, returning its receipt.
+fn apply_tx_with_valid_until_slot(valid_until_slot: u64) -> TxEffect {
+    let admin_key = read_private_keys::<TestSpec>().token_deployer.private_key;
+    let batch_receipt = apply_batch_after_genesis(simulate_da_with_valid_until_slot(
+        admin_key,
+        valid_until_slot,
+    ));
+    assert_eq!(1, batch_receipt.tx_receipts.len());
+    batch_receipt.tx_receipts[0].receipt.clone()
+}
+
+/// A transaction is accepted up to and including its `valid_until_slot`.
+#[test]
+fn test_tx_within_validity_window() {
+    assert_eq!(apply_tx_with_valid_until_slot(1), TxEffect::Successful(()));
+}
+
+/// This test ensures that a transaction included after its `valid_until_slot` is skipped.
+#[test]
+fn test_tx_expired() {
+    assert_eq!(
+        apply_tx_with_valid_until_slot(0),
+        TxEffect::Skipped(SkippedReason::Expired {
+            valid_until_slot: 0,
+            current_slot: 1,
+        })
+    );
+}
+
+/// This test ensures that a transaction without a gas limit is executed with the gas limit
+/// of the block, scaled down to fit its `max_fee`, and that this inferred limit is recorded in its receipt.
+#[test]
+fn test_tx_without_gas_limit_uses_block_gas_limit() {
+    let admin_key = read_private_keys::<TestSpec>().token_deployer.private_key;
+    let batch_receipt = apply_batch_after_genesis(simulate_da_without_gas_limit(admin_key));
+    assert_eq!(1, batch_receipt.tx_receipts.len());
+
+    let tx_receipt = &batch_receipt.tx_receipts[0];
+    assert_eq!(tx_receipt.receipt, TxEffect::Successful(()));
+
+    let block_gas_limit = ChainState::<TestSpec, MockDaSpec>::initial_gas_limit();
+    let block_limit_value: u64 = block_gas_limit
+        .as_slice()
+        .iter()
+        .zip(batch_receipt.gas_price.iter())
+        .map(|(limit, price)| limit * price)
+        .sum();
+    assert!(block_limit_value > TEST_DEFAULT_MAX_FEE);
+    let expected_gas_limit: Vec<u64> = block_gas_limit
+        .as_slice()
+        .iter()
+        .map(|limit| limit * TEST_DEFAULT_MAX_FEE / block_limit_value)
+        .collect();
+    assert_eq!(tx_receipt.gas_limit, expected_gas_limit);
+    let inferred_limit_value: u64 = tx_receipt
+        .gas_limit
+        .iter()
+        .zip(batch_receipt.gas_price.iter())
+        .map(|(limit, price)| limit * price)
+        .sum();
+    assert!(inferred_limit_value <= TEST_DEFAULT_MAX_FEE);
+    assert!(tx_receipt
+        .gas_used
+        .iter()
+        .zip(tx_receipt.gas_limit.iter())
+        .all(|(used, limit)| used <= limit));
+}
+
+/// This test ensures that a transaction performing more state writes than allowed by the
+/// state access budget is reverted, while one that stays within the budget succeeds.
+#[test]
+fn test_tx_state_access_budget() {
+    let admin_key = read_private_keys::<TestSpec>().token_deployer.private_key;
+
+    let batch_receipt = apply_batch_after_genesis_with_budget(
+        simulate_da_with_max_fee(admin_key.clone(), TEST_DEFAULT_MAX_FEE),
+        StateAccessBudget {
+            max_reads: 1_000,
+            max_writes: 1_000,
+        },
+    );
+    assert_eq!(1, batch_receipt.tx_receipts.len());
+    assert_eq!(
+        batch_receipt.tx_receipts[0].receipt,
+        TxEffect::Successful(())
+    );
+
+    let batch_receipt = apply_batch_after_genesis_with_budget(
+        simulate_da_with_max_fee(admin_key, TEST_DEFAULT_MAX_FEE),
+        StateAccessBudget {
+            max_writes: 1,
+            ..StateAccessBudget::UNLIMITED
+        },
+    );
+    assert_eq!(1, batch_receipt.tx_receipts.len());
+    match &batch_receipt.tx_receipts[0].receipt {
+        TxEffect::Reverted(err) => assert!(
+            format!("{err:?}").contains("exceeded its budget of 1 state writes"),
+            "Unexpected revert reason: {err:?}"
+        ),
+        receipt => panic!("The transaction should have been reverted, got {receipt:?}"),
+    }
+}
+
 #[test]
 fn test_tx_bad_serialization() -> Result<(), Infallible> {
     let tempdir = tempfile::tempdir().unwrap();
@@ -4,5 +4,6 @@ mod evm;
 mod forced_sequencer_registration;
 
 mod prover;
+mod read_only;
 
 mod test_helpers;
@@ -110,6 +110,7 @@ fn build_create_token_tx(key: &TestPrivateKey, nonce: u64) -> Transaction<TestSp
             initial_balance: 1000,
             mint_to_address: user_address,
             authorized_minters: vec![],
+            metadata: None,
         });
     let chain_id = config_value!("CHAIN_ID");
     let max_priority_fee_bips = TEST_DEFAULT_MAX_PRIORITY_FEE;
@@ -194,7 +195,8 @@ async fn send_transactions_and_wait_slot(
         .next()
         .await
         .transpose()?
-        .map(|slot| slot.number)
+        .map(|slot| slot.number.to_u64())
+        .transpose()?
         .unwrap_or_default();
 
     Ok(slot_number)
@@ -230,8 +232,8 @@ async fn assert_aggregated_proof(
 
     let proof_pub_data = &proof_response.data.public_data;
     // We test inequality because proofs are saved asynchronously in the db.
-    assert!(initial_slot <= proof_pub_data.initial_slot_number);
-    assert!(final_slot <= proof_pub_data.final_slot_number);
+    assert!(initial_slot <= proof_pub_data.initial_slot_number.to_u64()?);
+    assert!(final_slot <= proof_pub_data.final_slot_number.to_u64()?);
 
     let proof_data_info_response = client.ledger.get_latest_aggregated_proof().await?;
 
@@ -241,8 +243,16 @@ async fn assert_aggregated_proof(
                 .data
                 .public_data
                 .initial_slot_number
+                .to_u64()?
+    );
+    assert!(
+        final_slot
+            <= proof_data_info_response
+                .data
+                .public_data
+                .final_slot_number
+                .to_u64()?
     );
-    assert!(final_slot <= proof_data_info_response.data.public_data.final_slot_number);
 
     Ok(())
 }
@@ -252,8 +262,14 @@ fn assert_aggregated_proof_public_data(
     expected_final_slot_number: u64,
     pub_data: &sov_ledger_json_client::types::AggregatedProofPublicData,
 ) {
-    assert_eq!(expected_initial_slot_number, pub_data.initial_slot_number);
-    assert_eq!(expected_final_slot_number, pub_data.final_slot_number);
+    assert_eq!(
+        expected_initial_slot_number,
+        pub_data.initial_slot_number.to_u64().unwrap()
+    );
+    assert_eq!(
+        expected_final_slot_number,
+        pub_data.final_slot_number.to_u64().unwrap()
+    );
 }
 
 async fn assert_slot_finality(
@@ -266,6 +282,7 @@ async fn assert_slot_finality(
         .get_slot_by_id(
             &sov_ledger_json_client::types::IntOrHash::Variant0(slot_number),
             None,
+            None,
         )
         .await
         .unwrap();
@@ -277,6 +294,23 @@ async fn assert_slot_finality(
     );
 }
 
+async fn assert_token_rest_api(
+    client: &ApiClient,
+    token_id: TokenId,
+    expected_total_supply: u64,
+    expected_holder_count: u64,
+) -> anyhow::Result<()> {
+    let total_supply = client.get_token_total_supply(&token_id).await?;
+    assert!(total_supply.errors.is_empty());
+    assert_eq!(total_supply.data, Some(expected_total_supply));
+
+    let holder_count = client.get_token_holder_count(&token_id).await?;
+    assert!(holder_count.errors.is_empty());
+    assert_eq!(holder_count.data, Some(expected_holder_count));
+
+    Ok(())
+}
+
 async fn assert_bank_event<S: Spec>(
     client: &ApiClient,
     event_number: u64,
@@ -330,6 +364,19 @@ async fn send_test_bank_txs(test_case: TestCase, client: &ApiClient) -> Result<(
     assert_eq!(1, slot_number);
     assert_slot_finality(client, slot_number, test_case.expected_head_finality()).await;
     assert_balance(client, 1000, token_id, user_address, None).await?;
+    assert_token_rest_api(client, token_id, 1000, 1).await?;
+
+    let slot_finalized = client.get_slot_finalized(slot_number).await?;
+    assert_eq!(
+        slot_finalized.data,
+        Some(test_case.expected_head_finality() == FinalityStatus::Finalized)
+    );
+
+    // Errors are deserialized too.
+    let unknown_token = sov_bank::get_token_id::<TestSpec>("unknown", &user_address, TOKEN_SALT);
+    let total_supply = client.get_token_total_supply(&unknown_token).await?;
+    assert_eq!(total_supply.data, None);
+    assert_eq!(total_supply.errors[0].status, 404);
 
     // transfer 100 tokens. assert sender balance. height 3
     let tx = build_transfer_token_tx(&key, token_id, recipient_address, 100, 1);
@@ -337,6 +384,7 @@ async fn send_test_bank_txs(test_case: TestCase, client: &ApiClient) -> Result<(
     assert_eq!(2, slot_number);
     assert_slot_finality(client, slot_number, test_case.expected_head_finality()).await;
     assert_balance(client, 900, token_id, user_address, None).await?;
+    assert_token_rest_api(client, token_id, 1000, 2).await?;
 
     // transfer 200 tokens. assert sender balance. height 4
     let tx = build_transfer_token_tx(&key, token_id, recipient_address, 200, 2);
@@ -105,7 +105,7 @@ async fn submit_blobs_increasing_size<Da: DaSpec>() -> anyhow::Result<()> {
             .await
             .unwrap();
         let slot = slot_subscription.next().await.unwrap().unwrap();
-        println!("SLOT: {} received", slot.number);
+        println!("SLOT: {} received", slot.number.as_str());
         tokio::time::sleep(Duration::from_millis(1000)).await;
     }
 
@@ -7,6 +7,8 @@ use ethers_signers::{LocalWallet, Signer};
 use futures::future::join_all;
 use futures::stream::BoxStream;
 use futures::StreamExt;
+use jsonrpsee::core::client::Subscription;
+use sov_ethereum::GasPriceUpdate;
 use sov_kernels::basic::BasicKernelGenesisPaths;
 use sov_mock_da::{BlockProducingConfig, MockAddress, MockDaConfig};
 use sov_stf_runner::RollupProverConfig;
@@ -182,6 +184,58 @@ pub(crate) async fn set_multiple_values_check(
     Ok(())
 }
 
+/// Checks that `eth_sendRawTransactionBatch` enqueues transactions in submission order,
+/// and rejects batches with nonce gaps.
+pub(crate) async fn set_values_batch_check(
+    client: &TestClient,
+    slot_subscription: &mut BoxStream<'static, anyhow::Result<u64>>,
+    contract_address: Address,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let nonce = client.eth_get_transaction_count(client.from_addr).await;
+
+    let gapped_txs = vec![
+        client.sign_set_value(contract_address, 300, nonce).await,
+        client
+            .sign_set_value(contract_address, 301, nonce + 2)
+            .await,
+    ];
+    match client.eth_send_raw_transaction_batch(gapped_txs).await {
+        Err(jsonrpsee::core::client::Error::Call(err)) => {
+            assert_eq!(err.code(), jsonrpsee::types::error::INVALID_PARAMS_CODE);
+            let gap: sov_ethereum::NonceGap = serde_json::from_str(err.data().unwrap().get())?;
+            assert_eq!(gap.index, 1);
+            assert_eq!(gap.expected_nonce, nonce + 1);
+            assert_eq!(gap.nonce, nonce + 2);
+        }
+        other => panic!("Expected a nonce gap error, got {:?}", other),
+    }
+
+    let txs = vec![
+        client.sign_set_value(contract_address, 200, nonce).await,
+        client
+            .sign_set_value(contract_address, 201, nonce + 1)
+            .await,
+    ];
+    let tx_hashes = client.eth_send_raw_transaction_batch(txs.clone()).await?;
+    let expected_hashes = txs
+        .iter()
+        .map(|tx| ethers_core::utils::keccak256(tx).into())
+        .collect::<Vec<ethers_core::types::TxHash>>();
+    assert_eq!(tx_hashes, expected_hashes);
+
+    client.send_publish_batch_request().await;
+    let _ = slot_subscription.next().await.unwrap().unwrap();
+
+    // Nothing from the rejected batch was enqueued, and the last transaction wins.
+    assert_eq!(
+        client.eth_get_transaction_count(client.from_addr).await,
+        nonce + 2
+    );
+    assert_eq!(client.query_contract(contract_address).await?.as_u32(), 201);
+
+    Ok(())
+}
+
 /// Checks evm gas evolution.
 pub(crate) async fn gas_check(
     client: &TestClient,
@@ -208,3 +262,103 @@ pub(crate) async fn gas_check(
     assert!(latest_gas_price > initial_base_fee_per_gas);
     Ok(())
 }
+
+/// Checks that `eth_feeHistory` reports one entry per requested block, clamped to the chain's history.
+pub(crate) async fn fee_history_check(
+    client: &TestClient,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let latest_block = client.eth_get_block_by_number(None).await;
+    let latest_block_number = latest_block.number.unwrap().as_u64();
+
+    let history = client
+        .eth_fee_history(2, "latest", Some(vec![25.0, 75.0]))
+        .await;
+    assert_eq!(history.oldest_block.as_u64(), latest_block_number - 1);
+    assert_eq!(history.gas_used_ratio.len(), 2);
+    // The base fee of the next block is included as well.
+    assert_eq!(history.base_fee_per_gas.len(), 3);
+    assert_eq!(
+        history.base_fee_per_gas[1],
+        latest_block.base_fee_per_gas.unwrap()
+    );
+    assert_eq!(history.reward.len(), 2);
+    assert!(history.reward.iter().all(|rewards| rewards.len() == 2));
+
+    // Requesting more blocks than exist returns the whole chain.
+    let history = client
+        .eth_fee_history(latest_block_number + 10, "latest", None)
+        .await;
+    assert_eq!(history.oldest_block.as_u64(), 0);
+    assert_eq!(history.gas_used_ratio.len() as u64, latest_block_number + 1);
+    assert!(history.reward.is_empty());
+
+    Ok(())
+}
+
+/// Checks that `eth_getLogs` scans the requested blocks. The test contract doesn't emit events, so
+/// no logs are ever found.
+pub(crate) async fn logs_check(
+    client: &TestClient,
+    contract_address: Address,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let latest_block = client.eth_get_block_by_number(None).await;
+
+    let logs = client
+        .eth_get_logs(serde_json::json!({
+            "fromBlock": "earliest",
+            "toBlock": "latest",
+            "address": contract_address,
+        }))
+        .await?;
+    assert!(logs.is_empty());
+
+    let logs = client
+        .eth_get_logs(serde_json::json!({ "blockHash": latest_block.hash.unwrap() }))
+        .await?;
+    assert!(logs.is_empty());
+
+    match client
+        .eth_get_logs(serde_json::json!({ "fromBlock": "0x2", "toBlock": "0x1" }))
+        .await
+    {
+        Err(jsonrpsee::core::client::Error::Call(err)) => {
+            assert_eq!(err.code(), jsonrpsee::types::error::INVALID_PARAMS_CODE);
+        }
+        other => panic!("Expected an invalid block range error, got {:?}", other),
+    }
+
+    // Ranges past the head block are clamped to it instead of failing
+    let future_block = format!("{:#x}", latest_block.number.unwrap().as_u64() + 100);
+    let logs = client
+        .eth_get_logs(serde_json::json!({ "fromBlock": "earliest", "toBlock": future_block }))
+        .await?;
+    assert!(logs.is_empty());
+    let logs = client
+        .eth_get_logs(serde_json::json!({ "fromBlock": future_block, "toBlock": future_block }))
+        .await?;
+    assert!(logs.is_empty());
+
+    Ok(())
+}
+
+/// Checks that the gas price subscription emitted exactly one update per block, up to `latest_block_number`.
+pub(crate) async fn gas_price_subscription_check(
+    client: &TestClient,
+    gas_price_subscription: &mut Subscription<GasPriceUpdate>,
+    latest_block_number: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for block_number in 0..=latest_block_number {
+        let update = gas_price_subscription.next().await.unwrap()?;
+        assert_eq!(update.block_number.to::<u64>(), block_number);
+
+        let block = client
+            .eth_get_block_by_number(Some(block_number.to_string()))
+            .await;
+        assert_eq!(
+            update.base_fee.to::<u64>(),
+            block.base_fee_per_gas.unwrap_or_default().as_u64()
+        );
+    }
+
+    Ok(())
+}
@@ -69,6 +69,7 @@ async fn execute_evm_tests(client: &TestClient) -> Result<(), Box<dyn std::error
     assert!(balance > ethereum_types::U256::zero());
 
     let mut slot_subscription = client.subscribe_for_slots().await?;
+    let mut gas_price_subscription = client.subscribe_to_gas_price().await;
 
     let contract_address =
         evm_test_helper::deploy_contract_check(client, &mut slot_subscription).await?;
@@ -114,6 +115,9 @@ async fn execute_evm_tests(client: &TestClient) -> Result<(), Box<dyn std::error
     )
     .await?;
 
+    evm_test_helper::set_values_batch_check(client, &mut slot_subscription, contract_address)
+        .await?;
+
     let value = 103;
     evm_test_helper::set_value_unsigned_check(
         client,
@@ -125,6 +129,10 @@ async fn execute_evm_tests(client: &TestClient) -> Result<(), Box<dyn std::error
 
     evm_test_helper::gas_check(client, &mut slot_subscription, contract_address).await?;
 
+    evm_test_helper::fee_history_check(client).await?;
+
+    evm_test_helper::logs_check(client, contract_address).await?;
+
     let first_block = client.eth_get_block_by_number(Some("0".to_owned())).await;
     let second_block = client.eth_get_block_by_number(Some("1".to_owned())).await;
 
@@ -135,5 +143,19 @@ async fn execute_evm_tests(client: &TestClient) -> Result<(), Box<dyn std::error
         "Parent hash should be the hash of the previous block"
     );
 
+    // The gas price subscription emits once for the genesis block and once per new block
+    let latest_block_number = client
+        .eth_get_block_by_number(None)
+        .await
+        .number
+        .unwrap()
+        .as_u64();
+    evm_test_helper::gas_price_subscription_check(
+        client,
+        &mut gas_price_subscription,
+        latest_block_number,
+    )
+    .await?;
+
     Ok(())
 }
@@ -3,15 +3,16 @@ use ethers_core::abi::Address;
 use ethers_core::k256::ecdsa::SigningKey;
 use ethers_core::types::transaction::eip2718::TypedTransaction;
 use ethers_core::types::{
-    Block, Eip1559TransactionRequest, Transaction, TransactionRequest, TxHash,
+    Block, Eip1559TransactionRequest, FeeHistory, Log, Transaction, TransactionRequest, TxHash,
 };
 use ethers_middleware::SignerMiddleware;
 use ethers_providers::{Http, Middleware, PendingTransaction, Provider};
-use ethers_signers::Wallet;
+use ethers_signers::{Signer, Wallet};
 use futures::StreamExt;
-use jsonrpsee::core::client::ClientT;
+use jsonrpsee::core::client::{ClientT, Subscription, SubscriptionClientT};
 use jsonrpsee::rpc_params;
 use reth_primitives::Bytes;
+use sov_ethereum::GasPriceUpdate;
 use sov_ledger_json_client::WsSubscription;
 use sov_test_utils::{ApiClient, SimpleStorageContract, TestSpec, TEST_DEFAULT_MAX_FEE};
 
@@ -155,6 +156,34 @@ impl TestClient {
         requests
     }
 
+    /// Signs a transaction setting the value of the contract, without sending it.
+    pub(crate) async fn sign_set_value(
+        &self,
+        contract_address: H160,
+        set_arg: u32,
+        nonce: u64,
+    ) -> Bytes {
+        let req = Eip1559TransactionRequest::new()
+            .from(self.from_addr)
+            .to(contract_address)
+            .chain_id(self.chain_id)
+            .nonce(nonce)
+            .data(self.contract.set_call_data(set_arg))
+            .max_priority_fee_per_gas(10u64)
+            .max_fee_per_gas(TEST_DEFAULT_MAX_FEE)
+            .gas(GAS);
+
+        let typed_transaction = TypedTransaction::Eip1559(req);
+        let signature = self
+            .client
+            .signer()
+            .sign_transaction(&typed_transaction)
+            .await
+            .unwrap();
+
+        typed_transaction.rlp_signed(&signature).to_vec().into()
+    }
+
     pub(crate) async fn set_value(
         &self,
         contract_address: H160,
@@ -281,6 +310,16 @@ impl TestClient {
             .unwrap()
     }
 
+    pub(crate) async fn eth_send_raw_transaction_batch(
+        &self,
+        raw_txs: Vec<Bytes>,
+    ) -> Result<Vec<TxHash>, jsonrpsee::core::client::Error> {
+        self.node_client
+            .rpc
+            .request("eth_sendRawTransactionBatch", rpc_params![raw_txs])
+            .await
+    }
+
     pub(crate) async fn eth_chain_id(&self) -> u64 {
         let chain_id: ethereum_types::U64 = self
             .node_client
@@ -339,6 +378,48 @@ impl TestClient {
             .unwrap()
     }
 
+    pub(crate) async fn eth_fee_history(
+        &self,
+        block_count: u64,
+        newest_block: &str,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> FeeHistory {
+        self.node_client
+            .rpc
+            .request(
+                "eth_feeHistory",
+                rpc_params![
+                    format!("0x{:x}", block_count),
+                    newest_block,
+                    reward_percentiles
+                ],
+            )
+            .await
+            .unwrap()
+    }
+
+    pub(crate) async fn eth_get_logs(
+        &self,
+        filter: serde_json::Value,
+    ) -> Result<Vec<Log>, jsonrpsee::core::client::Error> {
+        self.node_client
+            .rpc
+            .request("eth_getLogs", rpc_params![filter])
+            .await
+    }
+
+    pub(crate) async fn subscribe_to_gas_price(&self) -> Subscription<GasPriceUpdate> {
+        self.node_client
+            .rpc
+            .subscribe(
+                "eth_subscribeGasPrice",
+                rpc_params![],
+                "eth_unsubscribeGasPrice",
+            )
+            .await
+            .unwrap()
+    }
+
     pub(crate) async fn eth_get_block_by_number(
         &self,
         block_number: Option<String>,
@@ -394,7 +475,7 @@ impl TestClient {
             .ledger
             .subscribe_slots()
             .await?
-            .map(|s| s.map(|s| s.number))
+            .map(|s| s.and_then(|s| s.number.to_u64()))
             .boxed())
     }
 
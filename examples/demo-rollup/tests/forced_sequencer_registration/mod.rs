@@ -30,6 +30,7 @@ async fn test_forced_sequencer_registration() -> Result<(), anyhow::Error> {
         },
         RollupProverConfig::Skip,
         MockDaConfig::instant_with_sender(UNREGISTERED_SENDER),
+        false,
     )
     .await;
     let da_service = rollup.runner.da_service();
@@ -91,6 +92,7 @@ fn build_register_sequencer_tx(key: &TestPrivateKey, nonce: u64) -> Transaction<
         sov_sequencer_registry::CallMessage::Register {
             da_address: UNREGISTERED_SENDER.as_ref().to_vec(),
             amount: MINIMUM_BOND,
+            minimum_bond: None,
         },
     );
     let chain_id = config_value!("CHAIN_ID");
@@ -55,6 +55,10 @@ async fn test_proof_generation() {
     let da_service = MockDaService::new(MockAddress::default());
     let storage_config = sov_state::config::Config {
         path: temp_dir.path().into(),
+        max_unfinalized_forks: None,
+        state_path: None,
+        accessory_path: None,
+        ledger_path: None,
     };
 
     let mut storage_manager =
@@ -0,0 +1,89 @@
+use demo_stf::genesis_config::GenesisPaths;
+use jsonrpsee::core::client::{ClientT, Error as ClientError};
+use jsonrpsee::rpc_params;
+use jsonrpsee::types::error::METHOD_NOT_FOUND_CODE;
+use reqwest::StatusCode;
+use serde_json::Value;
+use sov_kernels::basic::BasicKernelGenesisPaths;
+use sov_mock_da::{MockAddress, MockDaConfig};
+use sov_stf_runner::RollupProverConfig;
+use sov_test_utils::ApiClient;
+
+use crate::test_helpers::construct_rollup;
+
+/// Starts a rollup and returns the clients for its APIs, together with the URL of its REST API.
+async fn start_rollup(read_only: bool) -> anyhow::Result<(ApiClient, String)> {
+    let (rpc_port_tx, rpc_port_rx) = tokio::sync::oneshot::channel();
+    let (rest_port_tx, rest_port_rx) = tokio::sync::oneshot::channel();
+    let rollup = construct_rollup(
+        GenesisPaths::from_dir("../test-data/genesis/integration-tests"),
+        BasicKernelGenesisPaths {
+            chain_state: "../test-data/genesis/integration-tests/chain_state.json".into(),
+        },
+        RollupProverConfig::Skip,
+        MockDaConfig::instant_with_sender(MockAddress::new([0; 32])),
+        read_only,
+    )
+    .await;
+
+    tokio::spawn(async move {
+        rollup
+            .run_and_report_addr(Some(rpc_port_tx), Some(rest_port_tx))
+            .await
+            .unwrap();
+    });
+
+    let rpc_port = rpc_port_rx.await?.port();
+    let rest_port = rest_port_rx.await?.port();
+    let client = ApiClient::new(rpc_port, rest_port).await?;
+    Ok((client, format!("http://127.0.0.1:{rest_port}")))
+}
+
+async fn is_rpc_method_registered(client: &ApiClient, method: &str) -> bool {
+    // The parameters only need to be well-formed, the calls are expected to fail otherwise.
+    let params = match method {
+        "eth_sendRawTransaction" => rpc_params!["0x00"],
+        "eth_sendRawTransactionBatch" => rpc_params![["0x00"]],
+        "eth_sendTransaction" => rpc_params![serde_json::json!({})],
+        _ => rpc_params![],
+    };
+    let response: Result<Value, _> = client.rpc.request(method, params).await;
+    !matches!(response, Err(ClientError::Call(err)) if err.code() == METHOD_NOT_FOUND_CODE)
+}
+
+async fn rest_status(rest_url: &str, method: reqwest::Method, path: &str) -> StatusCode {
+    reqwest::Client::new()
+        .request(method, format!("{rest_url}{path}"))
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn write_endpoints_are_omitted_in_read_only_mode() -> anyhow::Result<()> {
+    for read_only in [true, false] {
+        let (client, rest_url) = start_rollup(read_only).await?;
+
+        for method in sov_ethereum::WRITE_METHODS {
+            assert_eq!(
+                is_rpc_method_registered(&client, method).await,
+                !read_only,
+                "{method} with read_only={read_only}"
+            );
+        }
+        assert!(is_rpc_method_registered(&client, "eth_chainId").await);
+
+        let accept_tx = rest_status(&rest_url, reqwest::Method::POST, "/sequencer/txs").await;
+        assert_eq!(accept_tx == StatusCode::NOT_FOUND, read_only);
+        let submit_batch =
+            rest_status(&rest_url, reqwest::Method::POST, "/sequencer/batches").await;
+        assert_eq!(submit_batch == StatusCode::NOT_FOUND, read_only);
+
+        let ledger_spec =
+            rest_status(&rest_url, reqwest::Method::GET, "/ledger/openapi-v3.yaml").await;
+        assert_eq!(ledger_spec, StatusCode::OK);
+    }
+    Ok(())
+}
@@ -45,6 +45,7 @@ pub async fn construct_rollup(
     kernel_genesis_paths: BasicKernelGenesisPaths,
     rollup_prover_config: RollupProverConfig,
     da_config: MockDaConfig,
+    read_only: bool,
 ) -> Rollup<MockDemoRollup<Native>, Native> {
     let temp_dir = tempfile::tempdir().unwrap();
     let temp_path = temp_dir.path();
@@ -52,6 +53,12 @@ pub async fn construct_rollup(
     let rollup_config = RollupConfig {
         storage: StorageConfig {
             path: temp_path.to_path_buf(),
+            max_unfinalized_forks: None,
+            state_path: None,
+            accessory_path: None,
+            ledger_path: None,
+            indexed_events: None,
+            subscription_capacity: None,
         },
         runner: RunnerConfig {
             genesis_height: 0,
@@ -64,6 +71,13 @@ pub async fn construct_rollup(
                 bind_host: "127.0.0.1".into(),
                 bind_port: 0,
             },
+            rpc_limits: Default::default(),
+            read_only,
+            metrics_path: None,
+            metrics: Default::default(),
+            ledger_routes: Default::default(),
+            rest_layers: Default::default(),
+            batch_parallelism: None,
         },
         da: da_config,
         proof_manager: ProofManagerConfig {
@@ -107,6 +121,7 @@ pub async fn start_rollup(
         kernel_genesis_paths,
         rollup_prover_config,
         da_config,
+        false,
     )
     .await
     .run_and_report_addr(Some(rpc_reporting_channel), Some(rest_reporting_channel))
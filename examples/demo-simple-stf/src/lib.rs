@@ -103,6 +103,7 @@ impl<InnerVm: Zkvm, OuterVm: Zkvm, Cond: ValidityCondition, Da: DaSpec>
             change_set: (),
             proof_receipts: vec![],
             batch_receipts: receipts,
+            blob_results: vec![],
             witness: (),
         }
     }